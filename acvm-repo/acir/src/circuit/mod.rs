@@ -369,34 +369,29 @@ mod tests {
 
     fn and_opcode() -> Opcode {
         Opcode::BlackBoxFuncCall(BlackBoxFuncCall::AND {
-            lhs: FunctionInput { witness: Witness(1), num_bits: 4 },
-            rhs: FunctionInput { witness: Witness(2), num_bits: 4 },
+            lhs: FunctionInput::witness(Witness(1), 4),
+            rhs: FunctionInput::witness(Witness(2), 4),
             output: Witness(3),
         })
     }
     fn range_opcode() -> Opcode {
         Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
-            input: FunctionInput { witness: Witness(1), num_bits: 8 },
+            input: FunctionInput::witness(Witness(1), 8),
         })
     }
     fn keccakf1600_opcode() -> Opcode {
-        let inputs: Box<[FunctionInput; 25]> = Box::new(std::array::from_fn(|i| FunctionInput {
-            witness: Witness(i as u32 + 1),
-            num_bits: 8,
-        }));
+        let inputs: Box<[FunctionInput; 25]> =
+            Box::new(std::array::from_fn(|i| FunctionInput::witness(Witness(i as u32 + 1), 8)));
         let outputs: Box<[Witness; 25]> = Box::new(std::array::from_fn(|i| Witness(i as u32 + 26)));
 
         Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Keccakf1600 { inputs, outputs })
     }
     fn schnorr_verify_opcode() -> Opcode {
-        let public_key_x =
-            FunctionInput { witness: Witness(1), num_bits: FieldElement::max_num_bits() };
-        let public_key_y =
-            FunctionInput { witness: Witness(2), num_bits: FieldElement::max_num_bits() };
-        let signature: Box<[FunctionInput; 64]> = Box::new(std::array::from_fn(|i| {
-            FunctionInput { witness: Witness(i as u32 + 3), num_bits: 8 }
-        }));
-        let message: Vec<FunctionInput> = vec![FunctionInput { witness: Witness(67), num_bits: 8 }];
+        let public_key_x = FunctionInput::witness(Witness(1), FieldElement::max_num_bits());
+        let public_key_y = FunctionInput::witness(Witness(2), FieldElement::max_num_bits());
+        let signature: Box<[FunctionInput; 64]> =
+            Box::new(std::array::from_fn(|i| FunctionInput::witness(Witness(i as u32 + 3), 8)));
+        let message: Vec<FunctionInput> = vec![FunctionInput::witness(Witness(67), 8)];
         let output = Witness(68);
 
         Opcode::BlackBoxFuncCall(BlackBoxFuncCall::SchnorrVerify {
@@ -1,12 +1,76 @@
 use crate::native_types::Witness;
 use crate::BlackBoxFunc;
+use acir_field::FieldElement;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-// Note: Some functions will not use all of the witness
-// So we need to supply how many bits of the witness is needed
+/// The value of a black box function input, which is either known when the circuit is built
+/// or assigned to a witness when it is executed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConstantOrWitnessEnum {
+    Constant(FieldElement),
+    Witness(Witness),
+}
+
+// Note: Some functions will not use all of the input
+// So we need to supply how many bits of the input is needed
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FunctionInput {
-    pub witness: Witness,
+    input: ConstantOrWitnessEnum,
+    num_bits: u32,
+}
+
+impl FunctionInput {
+    pub fn witness(witness: Witness, num_bits: u32) -> FunctionInput {
+        FunctionInput { input: ConstantOrWitnessEnum::Witness(witness), num_bits }
+    }
+
+    /// Returns an input with a constant value, or an error if `value` doesn't fit in `num_bits`.
+    pub fn constant(
+        value: FieldElement,
+        num_bits: u32,
+    ) -> Result<FunctionInput, InvalidInputBitSize> {
+        if value.num_bits() <= num_bits {
+            Ok(FunctionInput { input: ConstantOrWitnessEnum::Constant(value), num_bits })
+        } else {
+            Err(InvalidInputBitSize { value, num_bits })
+        }
+    }
+
+    pub fn input(self) -> ConstantOrWitnessEnum {
+        self.input
+    }
+
+    pub fn num_bits(&self) -> u32 {
+        self.num_bits
+    }
+
+    /// Returns the witness this input is assigned to, or None if it is a constant.
+    pub fn as_witness(&self) -> Option<Witness> {
+        match self.input {
+            ConstantOrWitnessEnum::Witness(witness) => Some(witness),
+            ConstantOrWitnessEnum::Constant(_) => None,
+        }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        matches!(self.input, ConstantOrWitnessEnum::Constant(_))
+    }
+}
+
+impl std::fmt::Display for FunctionInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.input {
+            ConstantOrWitnessEnum::Constant(constant) => write!(f, "({constant}")?,
+            ConstantOrWitnessEnum::Witness(witness) => write!(f, "(_{}", witness.witness_index())?,
+        }
+        write!(f, ", num_bits: {})", self.num_bits)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("the constant {value} does not fit in {num_bits} bits")]
+pub struct InvalidInputBitSize {
+    pub value: FieldElement,
     pub num_bits: u32,
 }
 
@@ -371,7 +435,7 @@ fn get_inputs_string(inputs: &[FunctionInput]) -> String {
     if should_abbreviate_inputs {
         let mut result = String::new();
         for (index, inp) in inputs.iter().enumerate() {
-            result += &format!("(_{}, num_bits: {})", inp.witness.witness_index(), inp.num_bits);
+            result += &inp.to_string();
             // Add a comma, unless it is the last entry
             if index != inputs.len() - 1 {
                 result += ", ";
@@ -384,13 +448,7 @@ fn get_inputs_string(inputs: &[FunctionInput]) -> String {
 
         let mut result = String::new();
 
-        result += &format!(
-            "(_{}, num_bits: {})...(_{}, num_bits: {})",
-            first.witness.witness_index(),
-            first.num_bits,
-            last.witness.witness_index(),
-            last.num_bits,
-        );
+        result += &format!("{first}...{last}");
 
         result
     }
@@ -486,23 +544,18 @@ mod tests {
     use super::{BlackBoxFuncCall, FunctionInput};
 
     fn keccakf1600_opcode() -> Opcode {
-        let inputs: Box<[FunctionInput; 25]> = Box::new(std::array::from_fn(|i| FunctionInput {
-            witness: Witness(i as u32 + 1),
-            num_bits: 8,
-        }));
+        let inputs: Box<[FunctionInput; 25]> =
+            Box::new(std::array::from_fn(|i| FunctionInput::witness(Witness(i as u32 + 1), 8)));
         let outputs: Box<[Witness; 25]> = Box::new(std::array::from_fn(|i| Witness(i as u32 + 26)));
 
         Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Keccakf1600 { inputs, outputs })
     }
     fn schnorr_verify_opcode() -> Opcode {
-        let public_key_x =
-            FunctionInput { witness: Witness(1), num_bits: FieldElement::max_num_bits() };
-        let public_key_y =
-            FunctionInput { witness: Witness(2), num_bits: FieldElement::max_num_bits() };
-        let signature: Box<[FunctionInput; 64]> = Box::new(std::array::from_fn(|i| {
-            FunctionInput { witness: Witness(i as u32 + 3), num_bits: 8 }
-        }));
-        let message: Vec<FunctionInput> = vec![FunctionInput { witness: Witness(67), num_bits: 8 }];
+        let public_key_x = FunctionInput::witness(Witness(1), FieldElement::max_num_bits());
+        let public_key_y = FunctionInput::witness(Witness(2), FieldElement::max_num_bits());
+        let signature: Box<[FunctionInput; 64]> =
+            Box::new(std::array::from_fn(|i| FunctionInput::witness(Witness(i as u32 + 3), 8)));
+        let message: Vec<FunctionInput> = vec![FunctionInput::witness(Witness(67), 8)];
         let output = Witness(68);
 
         Opcode::BlackBoxFuncCall(BlackBoxFuncCall::SchnorrVerify {
@@ -529,4 +582,24 @@ mod tests {
         let recovered_opcode = bincode::deserialize(&buf).unwrap();
         assert_eq!(opcode, recovered_opcode);
     }
+
+    #[test]
+    fn constant_input_serialization_roundtrip() {
+        let inputs = vec![
+            FunctionInput::constant(FieldElement::from(255u128), 8).unwrap(),
+            FunctionInput::witness(Witness(1), 8),
+        ];
+        let opcode = Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Blake2s {
+            inputs,
+            outputs: Box::new(std::array::from_fn(|i| Witness(i as u32 + 2))),
+        });
+        let buf = bincode::serialize(&opcode).unwrap();
+        let recovered_opcode = bincode::deserialize(&buf).unwrap();
+        assert_eq!(opcode, recovered_opcode);
+    }
+
+    #[test]
+    fn constant_input_must_fit_in_bit_size() {
+        assert!(FunctionInput::constant(FieldElement::from(256u128), 8).is_err());
+    }
 }
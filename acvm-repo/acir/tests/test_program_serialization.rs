@@ -61,12 +61,12 @@ fn addition_circuit() {
 fn multi_scalar_mul_circuit() {
     let multi_scalar_mul = Opcode::BlackBoxFuncCall(BlackBoxFuncCall::MultiScalarMul {
         points: vec![
-            FunctionInput { witness: Witness(1), num_bits: 128 },
-            FunctionInput { witness: Witness(2), num_bits: 128 },
+            FunctionInput::witness(Witness(1), 128),
+            FunctionInput::witness(Witness(2), 128),
         ],
         scalars: vec![
-            FunctionInput { witness: Witness(3), num_bits: 128 },
-            FunctionInput { witness: Witness(4), num_bits: 128 },
+            FunctionInput::witness(Witness(3), 128),
+            FunctionInput::witness(Witness(4), 128),
         ],
         outputs: (Witness(5), Witness(6)),
     });
@@ -83,10 +83,10 @@ fn multi_scalar_mul_circuit() {
     let bytes = Program::serialize_program(&program);
 
     let expected_serialization: Vec<u8> = vec![
-        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 85, 76, 65, 14, 0, 32, 8, 82, 179, 186, 244, 104, 159,
-        30, 45, 218, 136, 141, 33, 40, 186, 93, 76, 208, 57, 31, 93, 96, 136, 47, 250, 146, 188,
-        209, 39, 181, 131, 131, 187, 148, 110, 240, 246, 101, 38, 63, 180, 243, 97, 3, 125, 173,
-        118, 131, 153, 0, 0, 0,
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 85, 76, 65, 14, 0, 32, 8, 82, 179, 186, 244, 232, 158,
+        30, 46, 218, 136, 141, 129, 130, 186, 93, 76, 208, 233, 75, 23, 24, 50, 23, 55, 53, 232,
+        53, 111, 146, 39, 125, 7, 7, 59, 41, 221, 96, 255, 237, 76, 126, 233, 205, 135, 3, 15, 11,
+        143, 40, 169, 0, 0, 0,
     ];
 
     assert_eq!(bytes, expected_serialization)
@@ -95,7 +95,7 @@ fn multi_scalar_mul_circuit() {
 #[test]
 fn pedersen_circuit() {
     let pedersen = Opcode::BlackBoxFuncCall(BlackBoxFuncCall::PedersenCommitment {
-        inputs: vec![FunctionInput { witness: Witness(1), num_bits: FieldElement::max_num_bits() }],
+        inputs: vec![FunctionInput::witness(Witness(1), FieldElement::max_num_bits())],
         outputs: (Witness(2), Witness(3)),
         domain_separator: 0,
     });
@@ -112,27 +112,24 @@ fn pedersen_circuit() {
     let bytes = Program::serialize_program(&program);
 
     let expected_serialization: Vec<u8> = vec![
-        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 93, 74, 73, 10, 0, 0, 4, 180, 29, 252, 255, 193, 66, 40,
-        76, 77, 179, 34, 20, 36, 136, 237, 83, 245, 101, 107, 79, 65, 94, 253, 214, 217, 255, 239,
-        192, 1, 43, 124, 181, 238, 113, 0, 0, 0,
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 93, 138, 219, 10, 0, 0, 4, 67, 221, 30, 252, 255, 7, 11,
+        161, 176, 58, 109, 107, 67, 40, 73, 128, 157, 211, 245, 245, 196, 186, 83, 192, 107, 219,
+        62, 251, 255, 29, 57, 150, 69, 205, 11, 117, 0, 0, 0,
     ];
     assert_eq!(bytes, expected_serialization)
 }
 
 #[test]
 fn schnorr_verify_circuit() {
-    let public_key_x =
-        FunctionInput { witness: Witness(1), num_bits: FieldElement::max_num_bits() };
-    let public_key_y =
-        FunctionInput { witness: Witness(2), num_bits: FieldElement::max_num_bits() };
+    let public_key_x = FunctionInput::witness(Witness(1), FieldElement::max_num_bits());
+    let public_key_y = FunctionInput::witness(Witness(2), FieldElement::max_num_bits());
     let signature: [FunctionInput; 64] = (3..(3 + 64))
-        .map(|i| FunctionInput { witness: Witness(i), num_bits: 8 })
+        .map(|i| FunctionInput::witness(Witness(i), 8))
         .collect::<Vec<_>>()
         .try_into()
         .unwrap();
-    let message = ((3 + 64)..(3 + 64 + 10))
-        .map(|i| FunctionInput { witness: Witness(i), num_bits: 8 })
-        .collect();
+    let message =
+        ((3 + 64)..(3 + 64 + 10)).map(|i| FunctionInput::witness(Witness(i), 8)).collect();
     let output = Witness(3 + 64 + 10);
     let last_input = output.witness_index() - 1;
 
@@ -156,22 +153,24 @@ fn schnorr_verify_circuit() {
     let bytes = Program::serialize_program(&program);
 
     let expected_serialization: Vec<u8> = vec![
-        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 85, 210, 85, 78, 67, 81, 24, 133, 209, 226, 238, 238,
-        238, 238, 238, 165, 148, 82, 102, 193, 252, 135, 64, 232, 78, 87, 147, 114, 147, 147, 5,
-        47, 132, 252, 251, 107, 41, 212, 191, 159, 218, 107, 241, 115, 236, 226, 111, 237, 181,
-        178, 173, 246, 186, 107, 175, 157, 29, 236, 100, 23, 27, 175, 135, 189, 236, 99, 63, 7, 56,
-        200, 33, 14, 115, 132, 163, 28, 227, 56, 39, 56, 201, 41, 78, 115, 134, 179, 156, 227, 60,
-        23, 184, 200, 37, 46, 115, 133, 171, 92, 227, 58, 55, 184, 201, 45, 110, 115, 135, 187,
-        220, 227, 62, 15, 120, 200, 35, 30, 243, 132, 167, 60, 227, 57, 47, 120, 201, 43, 94, 243,
-        134, 183, 188, 227, 61, 31, 248, 200, 39, 62, 243, 133, 175, 77, 59, 230, 123, 243, 123,
-        145, 239, 44, 241, 131, 101, 126, 178, 194, 47, 86, 249, 237, 239, 86, 153, 238, 210, 92,
-        122, 75, 107, 233, 44, 141, 53, 250, 234, 241, 191, 164, 167, 180, 148, 142, 210, 80, 250,
-        73, 59, 233, 38, 205, 164, 151, 180, 146, 78, 210, 72, 250, 72, 27, 233, 34, 77, 164, 135,
-        180, 144, 14, 210, 64, 246, 95, 46, 212, 119, 207, 230, 217, 59, 91, 103, 231, 108, 156,
-        125, 183, 237, 186, 107, 207, 125, 59, 30, 218, 239, 216, 110, 167, 246, 58, 183, 211, 165,
-        125, 174, 237, 114, 107, 143, 123, 59, 60, 186, 255, 179, 187, 191, 186, 115, 209, 125, 75,
-        238, 90, 118, 207, 138, 59, 54, 110, 214, 184, 91, 161, 233, 158, 255, 190, 63, 71, 59, 68,
-        130, 233, 3, 0, 0,
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 85, 211, 103, 78, 2, 81, 24, 70, 225, 193, 6, 216, 123,
+        47, 216, 123, 239, 136, 136, 136, 136, 136, 187, 96, 255, 75, 32, 112, 194, 55, 201, 129,
+        100, 50, 79, 244, 7, 228, 222, 243, 102, 146, 254, 167, 221, 123, 50, 97, 222, 217, 120,
+        243, 116, 226, 61, 36, 15, 247, 158, 92, 120, 68, 30, 149, 199, 228, 172, 156, 147, 243,
+        242, 184, 60, 33, 79, 202, 83, 242, 180, 60, 35, 207, 202, 115, 242, 188, 188, 32, 47, 202,
+        75, 242, 178, 188, 34, 175, 202, 107, 242, 186, 188, 33, 111, 202, 91, 242, 182, 188, 35,
+        23, 228, 93, 121, 79, 222, 151, 15, 228, 67, 249, 72, 62, 150, 79, 228, 83, 249, 76, 62,
+        151, 47, 228, 75, 249, 74, 190, 150, 111, 228, 91, 249, 78, 190, 151, 31, 228, 71, 249, 73,
+        126, 150, 95, 228, 87, 185, 40, 191, 201, 37, 249, 93, 46, 203, 31, 114, 69, 254, 148, 171,
+        97, 58, 77, 226, 111, 95, 250, 127, 77, 254, 150, 235, 242, 143, 220, 144, 127, 229, 166,
+        252, 39, 183, 194, 255, 241, 253, 45, 253, 14, 182, 201, 38, 217, 34, 27, 100, 123, 233,
+        230, 242, 241, 155, 217, 20, 91, 98, 67, 108, 135, 205, 176, 21, 54, 194, 54, 216, 4, 91,
+        96, 3, 180, 79, 243, 180, 78, 227, 180, 77, 211, 180, 76, 195, 180, 75, 179, 133, 164, 223,
+        40, 109, 210, 36, 45, 210, 32, 237, 209, 28, 173, 209, 24, 109, 209, 20, 45, 209, 16, 237,
+        208, 12, 173, 208, 8, 109, 208, 4, 45, 208, 0, 119, 207, 157, 115, 215, 220, 113, 49, 238,
+        180, 20, 119, 88, 142, 59, 171, 196, 29, 85, 227, 46, 106, 113, 246, 245, 56, 235, 70, 156,
+        109, 51, 206, 50, 61, 179, 244, 220, 18, 157, 231, 192, 167, 11, 75, 28, 99, 152, 25, 5, 0,
+        0,
     ];
 
     assert_eq!(bytes, expected_serialization)
@@ -11,7 +11,7 @@ use acir::{
     circuit::{
         brillig::{Brillig, BrilligInputs, BrilligOutputs},
         directives::Directive,
-        opcodes::BlackBoxFuncCall,
+        opcodes::{BlackBoxFuncCall, FunctionInput},
         Circuit, Opcode,
     },
     native_types::{Expression, Witness, WitnessMap},
@@ -48,7 +48,7 @@ impl ConstantBackpropagationOptimizer {
             match &opcode {
                 Opcode::BlackBoxFuncCall(func_call) => {
                     required_witnesses.extend(
-                        func_call.get_inputs_vec().into_iter().map(|func_input| func_input.witness),
+                        func_call.get_inputs_vec().iter().filter_map(FunctionInput::as_witness),
                     );
                     required_witnesses.extend(func_call.get_outputs_vec());
                 }
@@ -308,8 +308,8 @@ mod tests {
             predicate: None,
         });
         let blackbox_opcode = Opcode::BlackBoxFuncCall(BlackBoxFuncCall::AND {
-            lhs: FunctionInput { witness: Witness(1), num_bits: 64 },
-            rhs: FunctionInput { witness: Witness(2), num_bits: 64 },
+            lhs: FunctionInput::witness(Witness(1), 64),
+            rhs: FunctionInput::witness(Witness(2), 64),
             output: Witness(3),
         });
 
@@ -1,8 +1,5 @@
 use acir::{
-    circuit::{
-        opcodes::{BlackBoxFuncCall, FunctionInput},
-        Circuit, Opcode,
-    },
+    circuit::{opcodes::BlackBoxFuncCall, Circuit, Opcode},
     native_types::Witness,
 };
 use std::collections::{BTreeMap, HashSet};
@@ -72,9 +69,9 @@ impl RangeOptimizer {
                     }
                 }
 
-                Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
-                    input: FunctionInput { witness, num_bits },
-                }) => Some((*witness, *num_bits)),
+                Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE { input }) => {
+                    input.as_witness().map(|witness| (witness, input.num_bits()))
+                }
 
                 _ => None,
             }) else {
@@ -101,12 +98,16 @@ impl RangeOptimizer {
         let mut new_order_list = Vec::with_capacity(order_list.len());
         let mut optimized_opcodes = Vec::with_capacity(self.circuit.opcodes.len());
         for (idx, opcode) in self.circuit.opcodes.into_iter().enumerate() {
-            let (witness, num_bits) = match &opcode {
+            let range = match &opcode {
                 Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE { input }) => {
-                    (input.witness, input.num_bits)
+                    input.as_witness().map(|witness| (witness, input.num_bits()))
                 }
-                _ => {
-                    // If its not the range opcode, add it to the opcode
+                _ => None,
+            };
+            let (witness, num_bits) = match range {
+                Some(range) => range,
+                None => {
+                    // If its not a range opcode on a witness, add it to the opcode
                     // list and continue;
                     optimized_opcodes.push(opcode);
                     new_order_list.push(order_list[idx]);
@@ -153,7 +154,7 @@ mod tests {
     fn test_circuit(ranges: Vec<(Witness, u32)>) -> Circuit {
         fn test_range_constraint(witness: Witness, num_bits: u32) -> Opcode {
             Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
-                input: FunctionInput { witness, num_bits },
+                input: FunctionInput::witness(witness, num_bits),
             })
         }
 
@@ -196,7 +197,7 @@ mod tests {
         assert_eq!(
             optimized_circuit.opcodes[0],
             Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
-                input: FunctionInput { witness: Witness(1), num_bits: 16 }
+                input: FunctionInput::witness(Witness(1), 16)
             })
         );
     }
@@ -219,13 +220,13 @@ mod tests {
         assert_eq!(
             optimized_circuit.opcodes[0],
             Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
-                input: FunctionInput { witness: Witness(1), num_bits: 16 }
+                input: FunctionInput::witness(Witness(1), 16)
             })
         );
         assert_eq!(
             optimized_circuit.opcodes[1],
             Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
-                input: FunctionInput { witness: Witness(2), num_bits: 23 }
+                input: FunctionInput::witness(Witness(2), 23)
             })
         );
     }
@@ -54,6 +54,7 @@ impl ExpressionSolver {
                             Err(OpcodeResolutionError::UnsatisfiedConstrain {
                                 opcode_location: ErrorLocation::Unresolved,
                                 payload: None,
+                                failed_opcode: None,
                             })
                         } else {
                             Ok(())
@@ -83,6 +84,7 @@ impl ExpressionSolver {
                         Err(OpcodeResolutionError::UnsatisfiedConstrain {
                             opcode_location: ErrorLocation::Unresolved,
                             payload: None,
+                            failed_opcode: None,
                         })
                     } else {
                         Ok(())
@@ -99,6 +101,7 @@ impl ExpressionSolver {
                     Err(OpcodeResolutionError::UnsatisfiedConstrain {
                         opcode_location: ErrorLocation::Unresolved,
                         payload: None,
+                        failed_opcode: None,
                     })
                 } else {
                     Ok(())
@@ -117,6 +120,7 @@ impl ExpressionSolver {
                         Err(OpcodeResolutionError::UnsatisfiedConstrain {
                             opcode_location: ErrorLocation::Unresolved,
                             payload: None,
+                            failed_opcode: None,
                         })
                     } else {
                         Ok(())
@@ -6,7 +6,7 @@ use acir::{
 
 use acvm_blackbox_solver::BigIntSolver;
 
-use crate::pwg::OpcodeResolutionError;
+use crate::pwg::{input_to_value, OpcodeResolutionError};
 
 /// Resolve BigInt opcodes by storing BigInt values (and their moduli) by their ID in the BigIntSolver
 /// - When it encounters a bigint operation opcode, it performs the operation on the stored values
@@ -27,8 +27,8 @@ impl AcvmBigIntSolver {
     ) -> Result<(), OpcodeResolutionError> {
         let bytes = inputs
             .iter()
-            .map(|input| initial_witness.get(&input.witness).unwrap().to_u128() as u8)
-            .collect::<Vec<u8>>();
+            .map(|input| Ok(input_to_value(initial_witness, *input)?.to_u128() as u8))
+            .collect::<Result<Vec<u8>, OpcodeResolutionError>>()?;
         self.bigint_solver.bigint_from_bytes(&bytes, modulus, output)?;
         Ok(())
     }
@@ -5,7 +5,7 @@ use acir::{
 };
 use acvm_blackbox_solver::{sha256compression, BlackBoxFunctionSolver, BlackBoxResolutionError};
 
-use crate::pwg::{insert_value, witness_to_value};
+use crate::pwg::{input_to_value, insert_value};
 use crate::OpcodeResolutionError;
 
 /// Attempts to solve a 256 bit hash function opcode.
@@ -32,10 +32,9 @@ fn get_hash_input(
     // Read witness assignments.
     let mut message_input = Vec::new();
     for input in inputs.iter() {
-        let witness = input.witness;
-        let num_bits = input.num_bits as usize;
+        let num_bits = input.num_bits() as usize;
 
-        let witness_assignment = witness_to_value(initial_witness, witness)?;
+        let witness_assignment = input_to_value(initial_witness, *input)?;
         let bytes = witness_assignment.fetch_nearest_bytes(num_bits);
         message_input.extend(bytes);
     }
@@ -43,15 +42,15 @@ fn get_hash_input(
     // Truncate the message if there is a `message_size` parameter given
     match message_size {
         Some(input) => {
-            let num_bytes_to_take =
-                witness_to_value(initial_witness, input.witness)?.to_u128() as usize;
+            let num_bytes_to_take = input_to_value(initial_witness, *input)?.to_u128() as usize;
 
             // If the number of bytes to take is more than the amount of bytes available
             // in the message, then we error.
             if num_bytes_to_take > message_input.len() {
                 return Err(OpcodeResolutionError::BlackBoxFunctionFailed(
                         acir::BlackBoxFunc::Keccak256,
-                        format!("the number of bytes to take from the message is more than the number of bytes in the message. {} > {}", num_bytes_to_take, message_input.len()),
+                        format!("the number of bytes to take from the message (message size input {input}) is more than the number of bytes in the message. {} > {}", num_bytes_to_take, message_input.len()),
+                        None,
                     ));
             }
             let truncated_message = message_input[0..num_bytes_to_take].to_vec();
@@ -84,7 +83,7 @@ fn to_u32_array<const N: usize>(
 ) -> Result<[u32; N], OpcodeResolutionError> {
     let mut result = [0; N];
     for (it, input) in result.iter_mut().zip(inputs) {
-        let witness_value = witness_to_value(initial_witness, input.witness)?;
+        let witness_value = input_to_value(initial_witness, *input)?;
         *it = witness_value.to_u128() as u32;
     }
     Ok(result)
@@ -123,6 +122,7 @@ pub(crate) fn solve_poseidon2_permutation_opcode(
                 inputs.len(),
                 len
             ),
+            None,
         ));
     }
     if len as usize != outputs.len() {
@@ -133,14 +133,15 @@ pub(crate) fn solve_poseidon2_permutation_opcode(
                 outputs.len(),
                 len
             ),
+            None,
         ));
     }
 
     // Read witness assignments
     let mut state = Vec::new();
     for input in inputs.iter() {
-        let witness_assignment = witness_to_value(initial_witness, input.witness)?;
-        state.push(*witness_assignment);
+        let witness_assignment = input_to_value(initial_witness, *input)?;
+        state.push(witness_assignment);
     }
 
     let state = backend.poseidon2_permutation(&state, len)?;
@@ -1,4 +1,4 @@
-use crate::pwg::{insert_value, witness_to_value};
+use crate::pwg::{input_to_value, insert_value};
 use crate::OpcodeResolutionError;
 use acir::{
     circuit::opcodes::FunctionInput,
@@ -15,11 +15,12 @@ pub(super) fn and(
     output: &Witness,
 ) -> Result<(), OpcodeResolutionError> {
     assert_eq!(
-        lhs.num_bits, rhs.num_bits,
+        lhs.num_bits(),
+        rhs.num_bits(),
         "number of bits specified for each input must be the same"
     );
-    solve_logic_opcode(initial_witness, &lhs.witness, &rhs.witness, *output, |left, right| {
-        left.and(right, lhs.num_bits)
+    solve_logic_opcode(initial_witness, lhs, rhs, *output, |left, right| {
+        left.and(right, lhs.num_bits())
     })
 }
 
@@ -32,25 +33,26 @@ pub(super) fn xor(
     output: &Witness,
 ) -> Result<(), OpcodeResolutionError> {
     assert_eq!(
-        lhs.num_bits, rhs.num_bits,
+        lhs.num_bits(),
+        rhs.num_bits(),
         "number of bits specified for each input must be the same"
     );
-    solve_logic_opcode(initial_witness, &lhs.witness, &rhs.witness, *output, |left, right| {
-        left.xor(right, lhs.num_bits)
+    solve_logic_opcode(initial_witness, lhs, rhs, *output, |left, right| {
+        left.xor(right, lhs.num_bits())
     })
 }
 
 /// Derives the rest of the witness based on the initial low level variables
 fn solve_logic_opcode(
     initial_witness: &mut WitnessMap,
-    a: &Witness,
-    b: &Witness,
+    a: &FunctionInput,
+    b: &FunctionInput,
     result: Witness,
     logic_op: impl Fn(&FieldElement, &FieldElement) -> FieldElement,
 ) -> Result<(), OpcodeResolutionError> {
-    let w_l_value = witness_to_value(initial_witness, *a)?;
-    let w_r_value = witness_to_value(initial_witness, *b)?;
-    let assignment = logic_op(w_l_value, w_r_value);
+    let w_l_value = input_to_value(initial_witness, *a)?;
+    let w_r_value = input_to_value(initial_witness, *b)?;
+    let assignment = logic_op(&w_l_value, &w_r_value);
 
     insert_value(&result, assignment, initial_witness)
 }
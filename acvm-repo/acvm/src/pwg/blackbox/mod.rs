@@ -11,7 +11,7 @@ use self::{
 };
 
 use super::{insert_value, OpcodeNotSolvable, OpcodeResolutionError};
-use crate::{pwg::witness_to_value, BlackBoxFunctionSolver};
+use crate::{pwg::input_to_value, BlackBoxFunctionSolver};
 
 mod aes128;
 pub(crate) mod bigint;
@@ -42,17 +42,23 @@ fn first_missing_assignment(
     inputs: &[FunctionInput],
 ) -> Option<Witness> {
     inputs.iter().find_map(|input| {
-        if witness_assignments.contains_key(&input.witness) {
+        let witness = input.as_witness()?;
+        if witness_assignments.contains_key(&witness) {
             None
         } else {
-            Some(input.witness)
+            Some(witness)
         }
     })
 }
 
 /// Check if all of the inputs to the function have assignments
+///
+/// Constant inputs never need an assignment
 fn contains_all_inputs(witness_assignments: &WitnessMap, inputs: &[FunctionInput]) -> bool {
-    inputs.iter().all(|input| witness_assignments.contains_key(&input.witness))
+    inputs.iter().all(|input| match input.as_witness() {
+        Some(witness) => witness_assignments.contains_key(&witness),
+        None => true,
+    })
 }
 
 pub(crate) fn solve(
@@ -99,10 +105,9 @@ pub(crate) fn solve(
         BlackBoxFuncCall::Keccakf1600 { inputs, outputs } => {
             let mut state = [0; 25];
             for (it, input) in state.iter_mut().zip(inputs.as_ref()) {
-                let witness = input.witness;
-                let num_bits = input.num_bits as usize;
+                let num_bits = input.num_bits() as usize;
                 assert_eq!(num_bits, 64);
-                let witness_assignment = witness_to_value(initial_witness, witness)?;
+                let witness_assignment = input_to_value(initial_witness, *input)?;
                 let lane = witness_assignment.try_to_u64();
                 *it = lane.unwrap();
             }
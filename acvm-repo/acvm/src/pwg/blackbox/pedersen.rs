@@ -4,7 +4,7 @@ use acir::{
 };
 
 use crate::{
-    pwg::{insert_value, witness_to_value, OpcodeResolutionError},
+    pwg::{input_to_value, insert_value, OpcodeResolutionError},
     BlackBoxFunctionSolver,
 };
 
@@ -15,9 +15,10 @@ pub(super) fn pedersen(
     domain_separator: u32,
     outputs: (Witness, Witness),
 ) -> Result<(), OpcodeResolutionError> {
-    let scalars: Result<Vec<_>, _> =
-        inputs.iter().map(|input| witness_to_value(initial_witness, input.witness)).collect();
-    let scalars: Vec<_> = scalars?.into_iter().cloned().collect();
+    let scalars: Vec<_> = inputs
+        .iter()
+        .map(|input| input_to_value(initial_witness, *input))
+        .collect::<Result<_, _>>()?;
 
     let (res_x, res_y) = backend.pedersen_commitment(&scalars, domain_separator)?;
 
@@ -34,9 +35,10 @@ pub(super) fn pedersen_hash(
     domain_separator: u32,
     output: Witness,
 ) -> Result<(), OpcodeResolutionError> {
-    let scalars: Result<Vec<_>, _> =
-        inputs.iter().map(|input| witness_to_value(initial_witness, input.witness)).collect();
-    let scalars: Vec<_> = scalars?.into_iter().cloned().collect();
+    let scalars: Vec<_> = inputs
+        .iter()
+        .map(|input| input_to_value(initial_witness, *input))
+        .collect::<Result<_, _>>()?;
 
     let res = backend.pedersen_hash(&scalars, domain_separator)?;
 
@@ -1,5 +1,5 @@
 use crate::{
-    pwg::{witness_to_value, ErrorLocation},
+    pwg::{input_to_value, ErrorLocation},
     OpcodeResolutionError,
 };
 use acir::{circuit::opcodes::FunctionInput, native_types::WitnessMap};
@@ -8,11 +8,12 @@ pub(crate) fn solve_range_opcode(
     initial_witness: &WitnessMap,
     input: &FunctionInput,
 ) -> Result<(), OpcodeResolutionError> {
-    let w_value = witness_to_value(initial_witness, input.witness)?;
-    if w_value.num_bits() > input.num_bits {
+    let w_value = input_to_value(initial_witness, *input)?;
+    if w_value.num_bits() > input.num_bits() {
         return Err(OpcodeResolutionError::UnsatisfiedConstrain {
             opcode_location: ErrorLocation::Unresolved,
             payload: None,
+            failed_opcode: None,
         });
     }
     Ok(())
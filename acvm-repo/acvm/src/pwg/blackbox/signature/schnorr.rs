@@ -1,7 +1,7 @@
 use crate::{
     pwg::{
         blackbox::utils::{to_u8_array, to_u8_vec},
-        insert_value, witness_to_value, OpcodeResolutionError,
+        input_to_value, insert_value, OpcodeResolutionError,
     },
     BlackBoxFunctionSolver,
 };
@@ -21,14 +21,14 @@ pub(crate) fn schnorr_verify(
     message: &[FunctionInput],
     output: Witness,
 ) -> Result<(), OpcodeResolutionError> {
-    let public_key_x: &FieldElement = witness_to_value(initial_witness, public_key_x.witness)?;
-    let public_key_y: &FieldElement = witness_to_value(initial_witness, public_key_y.witness)?;
+    let public_key_x: FieldElement = input_to_value(initial_witness, public_key_x)?;
+    let public_key_y: FieldElement = input_to_value(initial_witness, public_key_y)?;
 
     let signature = to_u8_array(initial_witness, signature)?;
     let message = to_u8_vec(initial_witness, message)?;
 
     let valid_signature =
-        backend.schnorr_verify(public_key_x, public_key_y, &signature, &message)?;
+        backend.schnorr_verify(&public_key_x, &public_key_y, &signature, &message)?;
 
     insert_value(&output, FieldElement::from(valid_signature), initial_witness)?;
 
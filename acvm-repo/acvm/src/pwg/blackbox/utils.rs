@@ -1,6 +1,6 @@
 use acir::{circuit::opcodes::FunctionInput, native_types::WitnessMap};
 
-use crate::pwg::{witness_to_value, OpcodeResolutionError};
+use crate::pwg::{input_to_value, OpcodeResolutionError};
 
 pub(crate) fn to_u8_array<const N: usize>(
     initial_witness: &WitnessMap,
@@ -8,7 +8,7 @@ pub(crate) fn to_u8_array<const N: usize>(
 ) -> Result<[u8; N], OpcodeResolutionError> {
     let mut result = [0; N];
     for (it, input) in result.iter_mut().zip(inputs) {
-        let witness_value_bytes = witness_to_value(initial_witness, input.witness)?.to_be_bytes();
+        let witness_value_bytes = input_to_value(initial_witness, *input)?.to_be_bytes();
         let byte = witness_value_bytes
             .last()
             .expect("Field element must be represented by non-zero amount of bytes");
@@ -23,7 +23,7 @@ pub(crate) fn to_u8_vec(
 ) -> Result<Vec<u8>, OpcodeResolutionError> {
     let mut result = Vec::with_capacity(inputs.len());
     for input in inputs {
-        let witness_value_bytes = witness_to_value(initial_witness, input.witness)?.to_be_bytes();
+        let witness_value_bytes = input_to_value(initial_witness, *input)?.to_be_bytes();
         let byte = witness_value_bytes
             .last()
             .expect("Field element must be represented by non-zero amount of bytes");
@@ -27,6 +27,7 @@ pub(crate) fn solve_directives(
                 return Err(OpcodeResolutionError::UnsatisfiedConstrain {
                     opcode_location: ErrorLocation::Unresolved,
                     payload: None,
+                    failed_opcode: None,
                 });
             }
 
@@ -1,13 +1,15 @@
 // Re-usable methods that backends can use to implement their PWG
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use acir::{
     brillig::ForeignCallResult,
     circuit::{
-        brillig::BrilligBytecode, opcodes::BlockId, AssertionPayload, ErrorSelector,
-        ExpressionOrMemory, Opcode, OpcodeLocation, RawAssertionPayload, ResolvedAssertionPayload,
-        STRING_ERROR_SELECTOR,
+        brillig::{BrilligBytecode, BrilligInputs, BrilligOutputs},
+        directives::Directive,
+        opcodes::{BlockId, ConstantOrWitnessEnum, FunctionInput},
+        AssertionPayload, ErrorSelector, ExpressionOrMemory, Opcode, OpcodeLocation,
+        RawAssertionPayload, ResolvedAssertionPayload, STRING_ERROR_SELECTOR,
     },
     native_types::{Expression, Witness, WitnessMap},
     BlackBoxFunc, FieldElement,
@@ -116,6 +118,99 @@ impl std::fmt::Display for ErrorLocation {
     }
 }
 
+/// An opcode which the ACVM failed to solve along with the values of the witnesses it references.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FailedOpcode {
+    pub opcode: Opcode,
+    /// The assigned witnesses referenced by the opcode, in ascending order.
+    ///
+    /// Witnesses which the opcode failed to solve for (e.g. black box outputs) are omitted.
+    pub witness_values: Vec<(Witness, FieldElement)>,
+}
+
+impl FailedOpcode {
+    fn new(opcode: &Opcode, witness_map: &WitnessMap) -> Self {
+        let witness_values = opcode_witnesses(opcode)
+            .into_iter()
+            .filter_map(|witness| witness_map.get(&witness).map(|value| (witness, *value)))
+            .collect();
+        FailedOpcode { opcode: opcode.clone(), witness_values }
+    }
+}
+
+impl std::fmt::Display for FailedOpcode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "opcode: {}", self.opcode)?;
+        write!(f, "witness values:")?;
+        for (witness, value) in &self.witness_values {
+            write!(f, "\n  _{} = {value}", witness.witness_index())?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns every witness which is read or written by `opcode`.
+fn opcode_witnesses(opcode: &Opcode) -> BTreeSet<Witness> {
+    fn add_expression(witnesses: &mut BTreeSet<Witness>, expr: &Expression) {
+        for (_, lhs, rhs) in &expr.mul_terms {
+            witnesses.insert(*lhs);
+            witnesses.insert(*rhs);
+        }
+        witnesses.extend(expr.linear_combinations.iter().map(|(_, witness)| *witness));
+    }
+
+    let mut witnesses = BTreeSet::new();
+    match opcode {
+        Opcode::AssertZero(expr) => add_expression(&mut witnesses, expr),
+        Opcode::BlackBoxFuncCall(call) => {
+            witnesses.extend(call.get_inputs_vec().iter().filter_map(FunctionInput::as_witness));
+            witnesses.extend(call.get_outputs_vec());
+        }
+        Opcode::Directive(Directive::ToLeRadix { a, b, .. }) => {
+            add_expression(&mut witnesses, a);
+            witnesses.extend(b.iter().copied());
+        }
+        Opcode::MemoryOp { op, predicate, .. } => {
+            add_expression(&mut witnesses, &op.operation);
+            add_expression(&mut witnesses, &op.index);
+            add_expression(&mut witnesses, &op.value);
+            if let Some(predicate) = predicate {
+                add_expression(&mut witnesses, predicate);
+            }
+        }
+        Opcode::MemoryInit { init, .. } => witnesses.extend(init.iter().copied()),
+        Opcode::BrilligCall { inputs, outputs, predicate, .. } => {
+            for input in inputs {
+                match input {
+                    BrilligInputs::Single(expr) => add_expression(&mut witnesses, expr),
+                    BrilligInputs::Array(exprs) => {
+                        exprs.iter().for_each(|expr| add_expression(&mut witnesses, expr));
+                    }
+                    BrilligInputs::MemoryArray(_) => (),
+                }
+            }
+            for output in outputs {
+                match output {
+                    BrilligOutputs::Simple(witness) => {
+                        witnesses.insert(*witness);
+                    }
+                    BrilligOutputs::Array(outputs) => witnesses.extend(outputs.iter().copied()),
+                }
+            }
+            if let Some(predicate) = predicate {
+                add_expression(&mut witnesses, predicate);
+            }
+        }
+        Opcode::Call { inputs, outputs, predicate, .. } => {
+            witnesses.extend(inputs.iter().chain(outputs).copied());
+            if let Some(predicate) = predicate {
+                add_expression(&mut witnesses, predicate);
+            }
+        }
+    }
+    witnesses
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Error)]
 pub enum OpcodeResolutionError {
     #[error("Cannot solve opcode: {0}")]
@@ -124,11 +219,15 @@ pub enum OpcodeResolutionError {
     UnsatisfiedConstrain {
         opcode_location: ErrorLocation,
         payload: Option<ResolvedAssertionPayload>,
+        /// The opcode which could not be satisfied. This is only set once the error reaches the ACVM.
+        failed_opcode: Option<Box<FailedOpcode>>,
     },
     #[error("Index out of bounds, array has size {array_size:?}, but index was {index:?}")]
     IndexOutOfBounds { opcode_location: ErrorLocation, index: u32, array_size: u32 },
+    /// A black box function could not be solved, e.g. due to malformed inputs.
+    /// The opcode which failed is only set once the error reaches the ACVM.
     #[error("Failed to solve blackbox function: {0}, reason: {1}")]
-    BlackBoxFunctionFailed(BlackBoxFunc, String),
+    BlackBoxFunctionFailed(BlackBoxFunc, String, Option<Box<FailedOpcode>>),
     #[error("Failed to solve brillig function")]
     BrilligFunctionFailed {
         call_stack: Vec<OpcodeLocation>,
@@ -144,7 +243,7 @@ impl From<BlackBoxResolutionError> for OpcodeResolutionError {
     fn from(value: BlackBoxResolutionError) -> Self {
         match value {
             BlackBoxResolutionError::Failed(func, reason) => {
-                OpcodeResolutionError::BlackBoxFunctionFailed(func, reason)
+                OpcodeResolutionError::BlackBoxFunctionFailed(func, reason, None)
             }
         }
     }
@@ -384,10 +483,15 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
                     OpcodeResolutionError::UnsatisfiedConstrain {
                         opcode_location: opcode_index,
                         payload: assertion_payload,
+                        failed_opcode,
                     } => {
                         let location = OpcodeLocation::Acir(self.instruction_pointer());
                         *opcode_index = ErrorLocation::Resolved(location);
                         *assertion_payload = self.extract_assertion_payload(location);
+                        *failed_opcode = Some(Box::new(self.failed_opcode()));
+                    }
+                    OpcodeResolutionError::BlackBoxFunctionFailed(_, _, failed_opcode) => {
+                        *failed_opcode = Some(Box::new(self.failed_opcode()));
                     }
                     // All other errors are thrown normally.
                     _ => (),
@@ -397,6 +501,11 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
         }
     }
 
+    /// Captures the opcode currently being solved along with the values of its witnesses.
+    fn failed_opcode(&self) -> FailedOpcode {
+        FailedOpcode::new(&self.opcodes[self.instruction_pointer], &self.witness_map)
+    }
+
     fn extract_assertion_payload(
         &self,
         location: OpcodeLocation,
@@ -631,6 +740,20 @@ pub fn witness_to_value(
     }
 }
 
+// Returns the value of a black box function input, which is either a constant
+// or the value assigned to its witness
+pub(crate) fn input_to_value(
+    initial_witness: &WitnessMap,
+    input: FunctionInput,
+) -> Result<FieldElement, OpcodeResolutionError> {
+    match input.input() {
+        ConstantOrWitnessEnum::Constant(value) => Ok(value),
+        ConstantOrWitnessEnum::Witness(witness) => {
+            witness_to_value(initial_witness, witness).copied()
+        }
+    }
+}
+
 // TODO: There is an issue open to decide on whether we need to get values from Expressions
 // TODO versus just getting values from Witness
 pub fn get_value(
@@ -666,6 +789,7 @@ pub fn insert_value(
         return Err(OpcodeResolutionError::UnsatisfiedConstrain {
             opcode_location: ErrorLocation::Unresolved,
             payload: None,
+            failed_opcode: None,
         });
     }
 
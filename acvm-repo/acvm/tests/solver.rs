@@ -4,14 +4,16 @@ use acir::{
     brillig::{BinaryFieldOp, HeapArray, MemoryAddress, Opcode as BrilligOpcode, ValueOrArray},
     circuit::{
         brillig::{BrilligBytecode, BrilligInputs, BrilligOutputs},
-        opcodes::{BlockId, MemOp},
+        opcodes::{BlackBoxFuncCall, BlockId, FunctionInput, MemOp},
         Opcode, OpcodeLocation,
     },
     native_types::{Expression, Witness, WitnessMap},
     FieldElement,
 };
 
-use acvm::pwg::{ACVMStatus, ErrorLocation, ForeignCallWaitInfo, OpcodeResolutionError, ACVM};
+use acvm::pwg::{
+    ACVMStatus, ErrorLocation, FailedOpcode, ForeignCallWaitInfo, OpcodeResolutionError, ACVM,
+};
 use acvm_blackbox_solver::StubbedBlackBoxSolver;
 use brillig_vm::brillig::HeapValueType;
 
@@ -545,16 +547,65 @@ fn unsatisfied_opcode_resolved() {
     let mut acvm =
         ACVM::new(&StubbedBlackBoxSolver, &opcodes, values, &unconstrained_functions, &[]);
     let solver_status = acvm.solve();
+    let failed_opcode = FailedOpcode {
+        opcode: opcodes[0].clone(),
+        witness_values: vec![
+            (a, FieldElement::from(4_i128)),
+            (b, FieldElement::from(2_i128)),
+            (c, FieldElement::from(1_i128)),
+            (d, FieldElement::from(2_i128)),
+        ],
+    };
+    assert_eq!(
+        failed_opcode.to_string(),
+        "opcode: EXPR [ (1, _0) (-1, _1) (-1, _2) (-1, _3) 0 ]\nwitness values:\n  _0 = 4\n  _1 = 2\n  _2 = 1\n  _3 = 2"
+    );
     assert_eq!(
         solver_status,
         ACVMStatus::Failure(OpcodeResolutionError::UnsatisfiedConstrain {
             opcode_location: ErrorLocation::Resolved(OpcodeLocation::Acir(0)),
-            payload: None
+            payload: None,
+            failed_opcode: Some(Box::new(failed_opcode)),
         }),
         "The first opcode is not satisfiable, expected an error indicating this"
     );
 }
 
+#[test]
+fn black_box_failure_reports_failed_opcode() {
+    let message = Witness(0);
+    let message_size = Witness(1);
+    let outputs: [Witness; 32] = std::array::from_fn(|i| Witness(i as u32 + 2));
+
+    // The message is a single byte but we ask for the first two bytes to be hashed.
+    let opcodes = vec![Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Keccak256 {
+        inputs: vec![FunctionInput::witness(message, 8)],
+        var_message_size: FunctionInput::witness(message_size, 32),
+        outputs: Box::new(outputs),
+    })];
+
+    let mut values = WitnessMap::new();
+    values.insert(message, FieldElement::from(7_i128));
+    values.insert(message_size, FieldElement::from(2_i128));
+
+    let mut acvm = ACVM::new(&StubbedBlackBoxSolver, &opcodes, values, &[], &[]);
+    let ACVMStatus::Failure(error) = acvm.solve() else {
+        panic!("Expected the black box function call to fail");
+    };
+    let OpcodeResolutionError::BlackBoxFunctionFailed(_, _, Some(failed_opcode)) = &error else {
+        panic!("Expected a black box function failure with the failed opcode, got {error:?}");
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "Failed to solve blackbox function: keccak256, reason: the number of bytes to take from the message (message size input _1) is more than the number of bytes in the message. 2 > 1"
+    );
+    assert_eq!(
+        failed_opcode.to_string(),
+        "opcode: BLACKBOX::KECCAK256 [(_0, num_bits: 8), (_1, num_bits: 32)] [ (_2,...,_33)]\nwitness values:\n  _0 = 7\n  _1 = 2"
+    );
+}
+
 #[test]
 fn unsatisfied_opcode_resolved_brillig() {
     let a = Witness(0);
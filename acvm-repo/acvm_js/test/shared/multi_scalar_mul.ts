@@ -1,8 +1,8 @@
 // See `multi_scalar_mul_circuit` integration test in `acir/tests/test_program_serialization.rs`.
 export const bytecode = Uint8Array.from([
-  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 85, 76, 65, 14, 0, 32, 8, 82, 179, 186, 244, 104, 159, 30, 45, 218, 136, 141, 33,
-  40, 186, 93, 76, 208, 57, 31, 93, 96, 136, 47, 250, 146, 188, 209, 39, 181, 131, 131, 187, 148, 110, 240, 246, 101,
-  38, 63, 180, 243, 97, 3, 125, 173, 118, 131, 153, 0, 0, 0,
+  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 85, 76, 65, 14, 0, 32, 8, 82, 179, 186, 244, 232, 158, 30, 46, 218, 136, 141, 129,
+  130, 186, 93, 76, 208, 233, 75, 23, 24, 50, 23, 55, 53, 232, 53, 111, 146, 39, 125, 7, 7, 59, 41, 221, 96, 255, 237,
+  76, 126, 233, 205, 135, 3, 15, 11, 143, 40, 169, 0, 0, 0,
 ]);
 export const initialWitnessMap = new Map([
   [1, '0x0000000000000000000000000000000000000000000000000000000000000001'],
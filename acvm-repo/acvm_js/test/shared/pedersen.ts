@@ -1,7 +1,8 @@
 // See `pedersen_circuit` integration test in `acir/tests/test_program_serialization.rs`.
 export const bytecode = Uint8Array.from([
-  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 93, 74, 73, 10, 0, 0, 4, 180, 29, 252, 255, 193, 66, 40, 76, 77, 179, 34, 20, 36,
-  136, 237, 83, 245, 101, 107, 79, 65, 94, 253, 214, 217, 255, 239, 192, 1, 43, 124, 181, 238, 113, 0, 0, 0,
+  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 93, 138, 219, 10, 0, 0, 4, 67, 221, 30, 252, 255, 7, 11, 161, 176, 58, 109, 107,
+  67, 40, 73, 128, 157, 211, 245, 245, 196, 186, 83, 192, 107, 219, 62, 251, 255, 29, 57, 150, 69, 205, 11, 117, 0, 0,
+  0,
 ]);
 
 export const initialWitnessMap = new Map([[1, '0x0000000000000000000000000000000000000000000000000000000000000001']]);
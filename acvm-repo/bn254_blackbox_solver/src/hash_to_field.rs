@@ -0,0 +1,91 @@
+use acir::FieldElement;
+
+use crate::poseidon2_permutation;
+
+/// The number of domain bytes packed into each field element of the message.
+const DOMAIN_CHUNK_BYTES: usize = 31;
+
+/// The number of field elements absorbed by each permutation of the Poseidon2 sponge.
+const RATE: usize = 3;
+
+/// Computes `std::hash::hash_to_field_with_domain(inputs, domain)` so that host code can recompute
+/// the hashes of a Noir program.
+pub fn hash_to_field_with_domain(inputs: &[FieldElement], domain: &str) -> FieldElement {
+    let message = hash_to_field_message(inputs, domain);
+
+    let two_pow_64 = FieldElement::from(1u128 << 64);
+    let iv = FieldElement::from(message.len() as u128) * two_pow_64;
+    let mut state = vec![FieldElement::zero(), FieldElement::zero(), FieldElement::zero(), iv];
+    for chunk in message.chunks(RATE) {
+        for (state_element, input) in state.iter_mut().zip(chunk) {
+            *state_element += *input;
+        }
+        state = poseidon2_permutation(&state, 4).expect("the sponge state has 4 elements");
+    }
+    state[0]
+}
+
+/// Lays out the message hashed by `hash_to_field_with_domain`: the length of `domain`, the bytes of
+/// `domain` packed big-endian into 31 byte chunks, the number of `inputs` and then the `inputs`.
+fn hash_to_field_message(inputs: &[FieldElement], domain: &str) -> Vec<FieldElement> {
+    let domain = domain.as_bytes();
+    let mut message = vec![FieldElement::from(domain.len() as u128)];
+    message.extend(domain.chunks(DOMAIN_CHUNK_BYTES).map(FieldElement::from_be_bytes_reduce));
+    message.push(FieldElement::from(inputs.len() as u128));
+    message.extend_from_slice(inputs);
+    message
+}
+
+#[cfg(test)]
+mod test {
+    use acir::FieldElement;
+
+    use super::{hash_to_field_message, hash_to_field_with_domain};
+
+    fn fields(values: &[u128]) -> Vec<FieldElement> {
+        values.iter().map(|value| FieldElement::from(*value)).collect()
+    }
+
+    #[test]
+    fn message_layout() {
+        // "noir" fits in a single chunk: 0x6e6f6972
+        let message = hash_to_field_message(&fields(&[1, 2, 3]), "noir");
+        assert_eq!(message, fields(&[4, 0x6e6f6972, 3, 1, 2, 3]));
+
+        let message = hash_to_field_message(&[], "");
+        assert_eq!(message, fields(&[0, 0]));
+
+        // 36 bytes are split into a full chunk of 31 bytes and a chunk of the remaining 5 bytes.
+        let domain = "std::hash::hash_to_field test vector";
+        let message = hash_to_field_message(&[], domain);
+        assert_eq!(message.len(), 4);
+        assert_eq!(message[0], FieldElement::from(36u128));
+        assert_eq!(message[1], FieldElement::from_be_bytes_reduce(&domain.as_bytes()[..31]));
+        assert_eq!(message[2], FieldElement::from_be_bytes_reduce(b"ector"));
+        assert_eq!(message[3], FieldElement::zero());
+    }
+
+    #[test]
+    fn matches_stdlib_test_vectors() {
+        // These were computed with this implementation, whose permutation is checked against a
+        // known answer in `poseidon2.rs`. They must be kept in sync with the test vectors of
+        // `std::hash::hash_to_field_with_domain`, which checks the Noir side against them.
+        let test_vectors = [
+            (
+                fields(&[1, 2, 3]),
+                "noir",
+                "111b594b5060f2d4446edf84796d42e829617959948c90c6f8af2743955968af",
+            ),
+            (Vec::new(), "", "0b63a53787021a4a962a452c2921b3663aff1ffd8d5510540f8e659e782956f1"),
+            (
+                fields(&[1, 2, 3]),
+                "std::hash::hash_to_field test vector",
+                "01fbae58291d7b586630c3b475fdc940e6f84b6f3d72b274aed939cfc001c493",
+            ),
+        ];
+        for (inputs, domain, expected) in test_vectors {
+            let expected = FieldElement::from_hex(expected).unwrap();
+            assert_eq!(hash_to_field_with_domain(&inputs, domain), expected, "domain: {domain:?}");
+        }
+    }
+}
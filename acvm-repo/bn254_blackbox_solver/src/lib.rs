@@ -7,12 +7,14 @@ use acvm_blackbox_solver::{BlackBoxFunctionSolver, BlackBoxResolutionError};
 
 mod embedded_curve_ops;
 mod generator;
+mod hash_to_field;
 mod pedersen;
 mod poseidon2;
 mod schnorr;
 
 use ark_ec::AffineRepr;
 pub use embedded_curve_ops::{embedded_curve_add, multi_scalar_mul};
+pub use hash_to_field::hash_to_field_with_domain;
 pub use poseidon2::poseidon2_permutation;
 
 pub struct Bn254BlackBoxSolver;
@@ -2,13 +2,13 @@ use convert_case::{Case, Casing};
 use noirc_errors::Span;
 use noirc_frontend::ast::{self, FunctionKind};
 use noirc_frontend::ast::{
-    BlockExpression, ConstrainKind, ConstrainStatement, Expression, ExpressionKind,
+    BlockExpression, ConstrainKind, ConstrainStatement, Expression, ExpressionKind, ForBounds,
     ForLoopStatement, ForRange, FunctionReturnType, Ident, Literal, NoirFunction, NoirStruct,
     Param, PathKind, Pattern, Signedness, Statement, StatementKind, UnresolvedType,
     UnresolvedTypeData, Visibility,
 };
 
-use noirc_frontend::{macros_api::FieldElement, parse_program};
+use noirc_frontend::{macros_api::FieldElement, parse_program, token::LiteralRepr};
 
 use crate::{
     chained_dep, chained_path,
@@ -712,16 +712,19 @@ fn create_loop_over(var: Expression, loop_body: Vec<Statement>) -> Statement {
 
     // `for i in 0..{ident}.len()`
     make_statement(StatementKind::For(ForLoopStatement {
-        range: ForRange::Range(
-            expression(ExpressionKind::Literal(Literal::Integer(
+        range: ForRange::Range(ForBounds {
+            start: expression(ExpressionKind::Literal(Literal::Integer(
                 FieldElement::from(i128::from(0)),
                 false,
+                LiteralRepr::Decimal,
             ))),
-            end_range_expression,
-        ),
-        identifier: ident("i"),
+            end: end_range_expression,
+            inclusive: false,
+        }),
+        pattern: Pattern::Identifier(ident("i")),
         block: for_loop_block,
         span,
+        collection: None,
     }))
 }
 
@@ -1,7 +1,7 @@
 use noirc_errors::Span;
 use noirc_frontend::ast::{
-    ItemVisibility, LetStatement, NoirFunction, NoirStruct, PathKind, TraitImplItem, TypeImpl,
-    UnresolvedTypeData, UnresolvedTypeExpression,
+    ItemVisibility, LetStatement, NoirFunction, NoirStruct, PathKind, StructField, TraitImplItem,
+    TypeImpl, UnresolvedTypeData, UnresolvedTypeExpression,
 };
 use noirc_frontend::{
     graph::CrateId,
@@ -92,8 +92,8 @@ pub fn generate_note_interface_impl(module: &mut SortedModule) -> Result<(), Azt
         let note_type_id = note_type_id(&note_type);
 
         // Automatically inject the header field if it's not present
-        let (header_field_name, _) = if let Some(existing_header) =
-            note_struct.fields.iter().find(|(_, field_type)| match &field_type.typ {
+        let header_field = if let Some(existing_header) =
+            note_struct.fields.iter().find(|field| match &field.typ.typ {
                 UnresolvedTypeData::Named(path, _, _) => {
                     path.last_segment().0.contents == "NoteHeader"
                 }
@@ -101,22 +101,25 @@ pub fn generate_note_interface_impl(module: &mut SortedModule) -> Result<(), Azt
             }) {
             existing_header.clone()
         } else {
-            let generated_header = (
-                ident("header"),
-                make_type(UnresolvedTypeData::Named(
+            let generated_header = StructField {
+                visibility: ItemVisibility::Public,
+                name: ident("header"),
+                typ: make_type(UnresolvedTypeData::Named(
                     chained_dep!("aztec", "note", "note_header", "NoteHeader"),
                     vec![],
                     false,
                 )),
-            );
+            };
             note_struct.fields.push(generated_header.clone());
             generated_header
         };
 
-        for (field_ident, field_type) in note_struct.fields.iter() {
+        let header_field_name = header_field.name;
+
+        for field in note_struct.fields.iter() {
             note_fields.push((
-                field_ident.0.contents.to_string(),
-                field_type.typ.to_string().replace("plain::", ""),
+                field.name.0.contents.to_string(),
+                field.typ.typ.to_string().replace("plain::", ""),
             ));
         }
 
@@ -664,7 +667,7 @@ pub fn inject_note_exports(
             let note_id_value = match note_id_statement {
                 HirStatement::Expression(expression_id) => {
                     match context.def_interner.expression(&expression_id) {
-                        HirExpression::Literal(HirLiteral::Integer(value, _)) => Ok(value),
+                        HirExpression::Literal(HirLiteral::Integer(value, ..)) => Ok(value),
                         _ => Err((
                             AztecMacroError::CouldNotExportStorageLayout {
                                 span: None,
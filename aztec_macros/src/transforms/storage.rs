@@ -11,7 +11,7 @@ use noirc_frontend::{
     node_interner::TraitId,
     parse_program,
     parser::SortedModule,
-    token::SecondaryAttribute,
+    token::{LiteralRepr, SecondaryAttribute},
     Type,
 };
 
@@ -152,14 +152,18 @@ pub fn generate_storage_implementation(
     let slot_zero = expression(ExpressionKind::Literal(Literal::Integer(
         FieldElement::from(i128::from(0)),
         false,
+        LiteralRepr::Decimal,
     )));
 
     let field_constructors = definition
         .fields
         .iter()
         .flat_map(|field| {
-            generate_storage_field_constructor(field, slot_zero.clone())
-                .map(|expression| (field.0.clone(), expression))
+            generate_storage_field_constructor(
+                &(field.name.clone(), field.typ.clone()),
+                slot_zero.clone(),
+            )
+            .map(|expression| (field.name.clone(), expression))
         })
         .collect();
 
@@ -360,7 +364,7 @@ pub fn assign_storage_slots(
                     context.def_interner.expression(&new_call_expression.arguments[1]);
 
                 let current_storage_slot = match slot_arg_expression {
-                    HirExpression::Literal(HirLiteral::Integer(slot, _)) => Ok(slot.to_u128()),
+                    HirExpression::Literal(HirLiteral::Integer(slot, ..)) => Ok(slot.to_u128()),
                     _ => Err((
                         AztecMacroError::CouldNotAssignStorageSlots {
                             secondary_message: Some(
@@ -419,6 +423,7 @@ pub fn assign_storage_slots(
                     *expr = HirExpression::Literal(HirLiteral::Integer(
                         FieldElement::from(new_storage_slot),
                         false,
+                        LiteralRepr::Decimal,
                     ))
                 });
 
@@ -426,6 +431,7 @@ pub fn assign_storage_slots(
                     *expr = HirExpression::Literal(HirLiteral::Integer(
                         FieldElement::from(new_storage_slot),
                         false,
+                        LiteralRepr::Decimal,
                     ))
                 });
 
@@ -451,13 +457,13 @@ pub fn generate_storage_layout(
     let mut storable_fields = vec![];
     let mut storable_fields_impl = vec![];
 
-    definition.fields.iter().enumerate().for_each(|(index, (field_ident, field_type))| {
-        storable_fields.push(format!("{}: dep::aztec::prelude::Storable<N{}>", field_ident, index));
+    definition.fields.iter().enumerate().for_each(|(index, field)| {
+        storable_fields.push(format!("{}: dep::aztec::prelude::Storable<N{}>", field.name, index));
         generic_args.push(format!("N{}", index));
         storable_fields_impl.push(format!(
             "{}: dep::aztec::prelude::Storable {{ slot: 0, typ: \"{}\" }}",
-            field_ident,
-            field_type.to_string().replace("plain::", "")
+            field.name,
+            field.typ.to_string().replace("plain::", "")
         ));
     });
 
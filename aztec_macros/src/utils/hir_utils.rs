@@ -201,7 +201,7 @@ pub fn inject_fn(
     context.def_interner.push_fn_meta(meta, func_id);
     context.def_interner.update_fn(func_id, hir_func);
 
-    let errors = type_check_func(&mut context.def_interner, func_id);
+    let errors = type_check_func(&mut context.def_interner, &context.def_maps, func_id);
 
     if !errors.is_empty() {
         return Err(MacroError {
@@ -372,7 +372,7 @@ pub fn get_global_numberic_const(
                 if let Some(let_stmt) = stmt {
                     let expression = context.def_interner.expression(&let_stmt.expression);
                     match expression {
-                        HirExpression::Literal(HirLiteral::Integer(value, _)) => {
+                        HirExpression::Literal(HirLiteral::Integer(value, ..)) => {
                             Some(value.to_u128())
                         }
                         _ => None,
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::{ops::Range, path::PathBuf};
 
+const BYTE_ORDER_MARK: char = '\u{FEFF}';
+
 // XXX: File and FileMap serve as opaque types, so that the rest of the library does not need to import the dependency
 // or worry about when we change the dep
 
@@ -104,6 +106,14 @@ impl<'a> Files<'a> for FileMap {
     }
 
     fn line_range(&self, file_id: Self::FileId, line_index: usize) -> Result<Range<usize>, Error> {
-        self.files.get(file_id.as_usize())?.line_range((), line_index)
+        let file = self.files.get(file_id.as_usize())?;
+        let mut line_range = file.line_range((), line_index)?;
+
+        // Editors don't display a byte order mark so we start the first line after it,
+        // otherwise every column on that line would be off by one.
+        if line_index == 0 && file.source().starts_with(BYTE_ORDER_MARK) {
+            line_range.start = BYTE_ORDER_MARK.len_utf8();
+        }
+        Ok(line_range)
     }
 }
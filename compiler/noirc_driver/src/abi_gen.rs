@@ -161,7 +161,7 @@ pub(super) fn value_from_hir_expression(context: &Context, expression: HirExpres
             },
             HirLiteral::Bool(value) => AbiValue::Boolean { value },
             HirLiteral::Str(value) => AbiValue::String { value },
-            HirLiteral::Integer(field, sign) => {
+            HirLiteral::Integer(field, sign, _) => {
                 AbiValue::Integer { value: field.to_string(), sign }
             }
             _ => unreachable!("Literal cannot be used in the abi"),
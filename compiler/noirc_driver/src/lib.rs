@@ -9,10 +9,13 @@ use clap::Args;
 use fm::{FileId, FileManager};
 use iter_extended::vecmap;
 use noirc_abi::{AbiParameter, AbiType, AbiValue};
-use noirc_errors::{CustomDiagnostic, FileDiagnostic};
-use noirc_evaluator::create_program;
+use noirc_errors::{CustomDiagnostic, DiagnosticKind, FileDiagnostic, Location};
 use noirc_evaluator::errors::RuntimeError;
 use noirc_evaluator::ssa::SsaProgramArtifact;
+use noirc_evaluator::{
+    create_program, SsaLogging, DEFAULT_MAX_FUNCTION_SPECIALIZATIONS, DEFAULT_MAX_SLICE_PADDING,
+    DEFAULT_WITNESS_WARNING_THRESHOLD,
+};
 use noirc_frontend::debug::build_debug_crate_file;
 use noirc_frontend::graph::{CrateId, CrateName};
 use noirc_frontend::hir::def_map::{Contract, CrateDefMap};
@@ -65,6 +68,11 @@ pub struct CompileOptions {
     #[arg(long, hide = true)]
     pub show_ssa: bool,
 
+    /// Only show the SSA after passes whose name contains the provided string.
+    /// This takes precedence over `--show-ssa` when set.
+    #[arg(long, hide = true)]
+    pub show_ssa_pass_name: Option<String>,
+
     #[arg(long, hide = true)]
     pub show_brillig: bool,
 
@@ -100,6 +108,11 @@ pub struct CompileOptions {
     #[arg(long, hide = true)]
     pub instrument_debug: bool,
 
+    /// Record the SSA instruction and monomorphized expression each ACIR opcode was generated from,
+    /// so that they can be shown by `nargo inspect`
+    #[arg(long)]
+    pub full_debug: bool,
+
     /// Force Brillig output (for step debugging)
     #[arg(long, hide = true)]
     pub force_brillig: bool,
@@ -107,6 +120,54 @@ pub struct CompileOptions {
     /// Enable the experimental elaborator pass
     #[arg(long, hide = true)]
     pub use_elaborator: bool,
+
+    /// The maximum number of elements which may be used to pad slices merged within a single function.
+    /// Defaults to 2^20.
+    #[arg(long, hide = true)]
+    pub max_slice_padding: Option<usize>,
+
+    /// Warn when a single ACIR function uses more than this many witnesses.
+    /// Defaults to 2^28.
+    #[arg(long, hide = true)]
+    pub witness_warning_threshold: Option<u32>,
+
+    /// Specialize functions which are not inlined for each distinct set of constant arguments they are called with,
+    /// rather than only for constants shared by every call.
+    #[arg(long, hide = true)]
+    pub specialize_any_literal: bool,
+
+    /// The maximum number of specialized copies made of a single function for its constant arguments.
+    /// Defaults to 8.
+    #[arg(long, hide = true)]
+    pub max_function_specializations: Option<usize>,
+
+    /// Warn about constraints which always hold when earlier constraints on the same values hold,
+    /// such as the second assertion in `assert(x < 10); assert(x < 20);`
+    #[arg(long)]
+    pub warn_redundant_constraints: bool,
+
+    /// Remove constraints which always hold when earlier constraints on the same values hold
+    #[arg(long)]
+    pub optimize_redundant_constraints: bool,
+
+    /// Warn about code which is likely to be unsound, such as dividing by a value derived from
+    /// public inputs without checking that it is non-zero
+    #[arg(long)]
+    pub lint_soundness: bool,
+
+    /// The maximum number of public inputs supported by the proving backend.
+    /// Compilation fails if `main` requires more, and warns once 80% of the limit is used.
+    #[arg(long)]
+    pub max_public_inputs: Option<u32>,
+
+    /// Don't warn about functions compiled without overflow checks using `#[no_overflow_checks]`
+    #[arg(long)]
+    pub allow_unchecked: bool,
+
+    /// Report errors within functions which can't be reached from `main` as warnings,
+    /// so that a program compiles even if it contains broken code which is never called
+    #[arg(long)]
+    pub skip_unreachable: bool,
 }
 
 fn parse_expression_width(input: &str) -> Result<ExpressionWidth, std::io::Error> {
@@ -287,13 +348,17 @@ pub fn compile_main(
     options: &CompileOptions,
     cached_program: Option<CompiledProgram>,
 ) -> CompilationResult<CompiledProgram> {
-    let (_, mut warnings) = check_crate(
-        context,
-        crate_id,
-        options.deny_warnings,
-        options.disable_macros,
-        options.use_elaborator,
-    )?;
+    let (_, mut warnings) = if options.skip_unreachable {
+        check_crate_reachable_from_main(context, crate_id, options)?
+    } else {
+        check_crate(
+            context,
+            crate_id,
+            options.deny_warnings,
+            options.disable_macros,
+            options.use_elaborator,
+        )?
+    };
 
     let main = context.get_main_function(&crate_id).ok_or_else(|| {
         // TODO(#2155): This error might be a better to exist in Nargo
@@ -308,7 +373,20 @@ pub fn compile_main(
         compile_no_check(context, options, main, cached_program, options.force_compile)
             .map_err(FileDiagnostic::from)?;
 
-    let compilation_warnings = vecmap(compiled_program.warnings.clone(), FileDiagnostic::from);
+    let mut compilation_warnings = vecmap(compiled_program.warnings.clone(), FileDiagnostic::from);
+    if !options.allow_unchecked {
+        compilation_warnings.extend(check_unchecked_functions(context));
+    }
+    if let Some(limit) = options.max_public_inputs {
+        if let Some(diagnostic) =
+            check_public_input_count(context, main, &compiled_program.abi, limit)
+        {
+            if diagnostic.diagnostic.is_error() {
+                return Err(vec![diagnostic]);
+            }
+            compilation_warnings.push(diagnostic);
+        }
+    }
     if options.deny_warnings && !compilation_warnings.is_empty() {
         return Err(compilation_warnings);
     }
@@ -322,6 +400,136 @@ pub fn compile_main(
     Ok((compiled_program, warnings))
 }
 
+/// Checks the crate for errors like [check_crate], except that errors within functions which
+/// can't be reached from `main` are reported as warnings since these functions are never compiled.
+///
+/// A warning stating how many functions were skipped is added if there are any.
+fn check_crate_reachable_from_main(
+    context: &mut Context,
+    crate_id: CrateId,
+    options: &CompileOptions,
+) -> CompilationResult<()> {
+    let mut diagnostics =
+        match check_crate(context, crate_id, false, options.disable_macros, options.use_elaborator)
+        {
+            Ok(((), warnings)) => warnings,
+            Err(errors) => errors,
+        };
+
+    if let Some(main) = context.get_main_function(&crate_id) {
+        let unreachable = context.get_unreachable_functions(&crate_id, main);
+        let locations = vecmap(&unreachable, |func_id| function_location(context, func_id));
+
+        let mut skipped_errors = 0;
+        for diagnostic in &mut diagnostics {
+            let Some(label) = diagnostic.diagnostic.secondaries.first() else {
+                continue;
+            };
+            let error_location = Location::new(label.span, diagnostic.file_id);
+            if diagnostic.diagnostic.is_error()
+                && locations.iter().any(|location| location.contains(&error_location))
+            {
+                diagnostic.diagnostic.kind = DiagnosticKind::Warning;
+                skipped_errors += 1;
+            }
+        }
+
+        if !unreachable.is_empty() {
+            let count = unreachable.len();
+            let (plural, verb) = if count == 1 { ("", "was") } else { ("s", "were") };
+            let location = context.function_meta(&main).name.location;
+            let mut diagnostic = CustomDiagnostic::simple_warning(
+                format!("{count} function{plural} unreachable from `main` {verb} skipped"),
+                "reachability is checked from here".to_string(),
+                location.span,
+            );
+            if skipped_errors > 0 {
+                let plural = if skipped_errors == 1 { "" } else { "s" };
+                diagnostic.add_note(format!(
+                    "{skipped_errors} error{plural} within them {} reported as warnings",
+                    if skipped_errors == 1 { "was" } else { "were" }
+                ));
+            }
+            diagnostics.push(diagnostic.in_file(location.file));
+        }
+    }
+
+    if has_errors(&diagnostics, options.deny_warnings) {
+        Err(diagnostics)
+    } else {
+        Ok(((), diagnostics))
+    }
+}
+
+/// The location of a function from its name to the end of its body.
+fn function_location(context: &Context, func_id: &FuncId) -> Location {
+    let name = context.function_meta(func_id).name.location;
+    let body = context.def_interner.function(func_id).as_expr();
+    let body_span = context.def_interner.expr_span(&body);
+    Location::new(name.span.merge(body_span), name.file)
+}
+
+/// Checks the number of public inputs required by `abi` against the backend's `limit`.
+///
+/// Returns an error if the limit is exceeded, or a warning if at least 80% of it is used.
+/// Either lists the parameters contributing the most public inputs.
+fn check_public_input_count(
+    context: &Context,
+    main: FuncId,
+    abi: &noirc_abi::Abi,
+    limit: u32,
+) -> Option<FileDiagnostic> {
+    let mut contributors = abi.public_input_contributors();
+    let count: u32 = contributors.iter().map(|(_, count)| count).sum();
+    // Compare `count / limit` against 80% without losing precision.
+    if u64::from(count) * 5 < u64::from(limit) * 4 {
+        return None;
+    }
+
+    let location = context.function_meta(&main).name.location;
+    let mut diagnostic = if count > limit {
+        CustomDiagnostic::simple_error(
+            format!(
+                "`main` requires {count} public inputs but the backend supports at most {limit}"
+            ),
+            "public inputs are declared by this function".to_string(),
+            location.span,
+        )
+    } else {
+        CustomDiagnostic::simple_warning(
+            format!("`main` uses {count} of the {limit} public inputs supported by the backend"),
+            "public inputs are declared by this function".to_string(),
+            location.span,
+        )
+    };
+
+    contributors.sort_by(|(_, a), (_, b)| b.cmp(a));
+    let contributors = vecmap(contributors, |(name, count)| {
+        let plural = if count == 1 { "" } else { "s" };
+        format!("`{name}` ({count} public input{plural})")
+    });
+    diagnostic.add_note(format!("Largest contributors: {}", contributors.join(", ")));
+    Some(diagnostic.in_file(location.file))
+}
+
+/// Returns a single warning listing every function compiled without overflow checks, if there are any.
+fn check_unchecked_functions(context: &Context) -> Option<FileDiagnostic> {
+    let functions = context.get_all_unchecked_functions();
+    let first = functions.first()?;
+
+    let names = vecmap(&functions, |func_id| format!("`{}`", context.function_name(func_id)));
+    let plural = if functions.len() == 1 { "" } else { "s" };
+    let location = context.function_meta(first).name.location;
+    let mut diagnostic = CustomDiagnostic::simple_warning(
+        format!("{} function{plural} compiled without overflow checks", functions.len()),
+        "marked `#[no_overflow_checks]` here".to_string(),
+        location.span,
+    );
+    diagnostic.add_note(format!("Unchecked functions: {}", names.join(", ")));
+    diagnostic.add_note("Pass `--allow-unchecked` to silence this warning".to_string());
+    Some(diagnostic.in_file(location.file))
+}
+
 /// Run the frontend to check the crate for errors then compile all contracts if there were none
 pub fn compile_contract(
     context: &mut Context,
@@ -341,6 +549,9 @@ pub fn compile_contract(
 
     let mut compiled_contracts = vec![];
     let mut errors = warnings;
+    if !options.allow_unchecked {
+        errors.extend(check_unchecked_functions(context));
+    }
 
     if contracts.len() > 1 {
         let err = CustomDiagnostic::from_message("Packages are limited to a single contract")
@@ -528,8 +739,12 @@ pub fn compile_no_check(
 
     // If user has specified that they want to see intermediate steps printed then we should
     // force compilation even if the program hasn't changed.
-    let force_compile =
-        force_compile || options.print_acir || options.show_brillig || options.show_ssa;
+    let force_compile = force_compile
+        || options.print_acir
+        || options.show_brillig
+        || options.show_ssa
+        || options.show_ssa_pass_name.is_some()
+        || options.full_debug;
 
     if !force_compile && hashes_match {
         info!("Program matches existing artifact, returning early");
@@ -537,6 +752,12 @@ pub fn compile_no_check(
     }
     let visibility = program.return_visibility;
 
+    let ssa_logging = match &options.show_ssa_pass_name {
+        Some(pass_name) => SsaLogging::Contains(pass_name.clone()),
+        None if options.show_ssa => SsaLogging::All,
+        None => SsaLogging::None,
+    };
+
     let SsaProgramArtifact {
         program,
         debug,
@@ -547,10 +768,19 @@ pub fn compile_no_check(
         error_types,
     } = create_program(
         program,
-        options.show_ssa,
+        ssa_logging,
         options.show_brillig,
         options.force_brillig,
         options.benchmark_codegen,
+        options.max_slice_padding.unwrap_or(DEFAULT_MAX_SLICE_PADDING),
+        options.witness_warning_threshold.unwrap_or(DEFAULT_WITNESS_WARNING_THRESHOLD),
+        options.specialize_any_literal,
+        options.max_function_specializations.unwrap_or(DEFAULT_MAX_FUNCTION_SPECIALIZATIONS),
+        options.warn_redundant_constraints,
+        options.optimize_redundant_constraints,
+        options.lint_soundness,
+        options.full_debug,
+        options.expression_width,
     )?;
 
     let abi = abi_gen::gen_abi(
@@ -0,0 +1,41 @@
+use acvm::FieldElement;
+use noirc_abi::{input_parser::InputValue, InputMap, MAIN_RETURN_NAME};
+
+mod common;
+
+/// The return value is kept apart from the parameters in the ABI, so parameters with names
+/// resembling [MAIN_RETURN_NAME] cannot be confused with it.
+const SOURCE: &str = "
+fn main(__return: pub Field, _return: Field, returned: Field) -> pub Field {
+    __return + _return + returned
+}
+";
+
+#[test]
+fn return_like_parameter_names_do_not_collide_with_the_return_value() {
+    let abi = common::compile(SOURCE).abi;
+
+    let names: Vec<_> = abi.parameters.iter().map(|param| param.name.as_str()).collect();
+    assert_eq!(names, ["__return", "_return", "returned"]);
+    assert!(abi.return_type.is_some());
+    assert!(!abi.param_witnesses.contains_key(MAIN_RETURN_NAME));
+    assert_eq!(abi.public_input_contributors(), vec![("__return", 1), (MAIN_RETURN_NAME, 1)]);
+
+    let inputs = InputMap::from([
+        ("__return".to_string(), InputValue::Field(1u128.into())),
+        ("_return".to_string(), InputValue::Field(2u128.into())),
+        ("returned".to_string(), InputValue::Field(3u128.into())),
+    ]);
+    let return_value = InputValue::Field(6u128.into());
+    let witness_map =
+        abi.encode(&inputs, Some(return_value.clone())).expect("inputs should encode");
+
+    let (decoded_inputs, decoded_return) = abi.decode(&witness_map).expect("witness should decode");
+    assert_eq!(decoded_inputs, inputs);
+    assert_eq!(decoded_return, Some(return_value));
+    assert_eq!(
+        decoded_inputs.get("__return"),
+        Some(&InputValue::Field(FieldElement::one())),
+        "the `__return` parameter must not be overwritten by the return value"
+    );
+}
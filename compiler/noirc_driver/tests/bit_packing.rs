@@ -0,0 +1,90 @@
+mod common;
+
+/// Compiles `source` and returns the number of opcodes in its `main` circuit.
+fn opcode_count(source: &str) -> usize {
+    common::compile(source).program.functions[0].opcodes.len()
+}
+
+/// A per-element boolean encoding of bits: an unconstrained hint for the bits of `x`, and their
+/// recomposition, which the caller must check against `x`.
+const BOOLEAN_HINT: &str = "
+    unconstrained fn bits_of(x: Field) -> [bool; 64] {
+        let bits = x.to_le_bits(64);
+        let mut result = [false; 64];
+        for i in 0..64 {
+            result[i] = bits[i] == 1;
+        }
+        result
+    }
+
+    fn recompose(bits: [bool; 64]) -> Field {
+        let mut result = 0;
+        let mut power = 1;
+        for i in 0..64 {
+            result += (bits[i] as Field) * power;
+            power *= 2;
+        }
+        result
+    }
+";
+
+#[test]
+fn packing_bits_costs_one_range_constraint_per_bit() {
+    let packed = opcode_count(
+        "fn main(bits: [u1; 64]) -> pub Field {
+            dep::std::field::pack_bits(bits)
+        }",
+    );
+    let booleans = opcode_count(&format!(
+        "fn main(bits: [bool; 64]) -> pub Field {{
+            recompose(bits)
+        }}
+        {BOOLEAN_HINT}"
+    ));
+    // Each input bit needs its own range constraint, whether it is a `u1` or a `bool`.
+    // Packing them is then a single linear combination.
+    assert_eq!(packed, 66);
+    assert_eq!(packed, booleans);
+}
+
+#[test]
+fn unpacking_bits_uses_a_single_decomposition() {
+    let unpacked = opcode_count(
+        "fn main(x: Field) -> pub Field {
+            let bits: [u1; 64] = dep::std::field::unpack_bits(x);
+            bits[3] as Field
+        }",
+    );
+    let booleans = opcode_count(&format!(
+        "fn main(x: Field) -> pub Field {{
+            let bits = bits_of(x);
+            assert(recompose(bits) == x);
+            bits[3] as Field
+        }}
+        {BOOLEAN_HINT}"
+    ));
+    assert_eq!(unpacked, 67);
+    assert_eq!(unpacked, booleans);
+}
+
+#[test]
+fn packing_unpacked_bits_does_not_constrain_them_again() {
+    let repacked = opcode_count(
+        "fn main(x: Field) -> pub Field {
+            let bits: [u1; 64] = dep::std::field::unpack_bits(x);
+            dep::std::field::pack_bits(bits)
+        }",
+    );
+    let booleans = opcode_count(&format!(
+        "fn main(x: Field) -> pub Field {{
+            let bits = bits_of(x);
+            assert(recompose(bits) == x);
+            recompose(bits)
+        }}
+        {BOOLEAN_HINT}"
+    ));
+    // The bits from the decomposition are already known to be bits, so packing them back
+    // only adds the final linear combination.
+    assert_eq!(repacked, 68);
+    assert_eq!(repacked, booleans);
+}
@@ -0,0 +1,48 @@
+use acvm::acir::circuit::{opcodes::BlackBoxFuncCall, Circuit, Opcode};
+use noirc_driver::CompiledProgram;
+
+mod common;
+
+fn main_circuit(program: &CompiledProgram) -> &Circuit {
+    &program.program.functions[0]
+}
+
+/// Returns the number of constant and witness inputs passed to the circuit's `blake2s` call.
+fn blake2s_inputs(circuit: &Circuit) -> (usize, usize) {
+    let inputs = circuit
+        .opcodes
+        .iter()
+        .find_map(|opcode| match opcode {
+            Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Blake2s { inputs, .. }) => Some(inputs),
+            _ => None,
+        })
+        .expect("circuit should call blake2s");
+    let constants = inputs.iter().filter(|input| input.is_constant()).count();
+    (constants, inputs.len() - constants)
+}
+
+#[test]
+fn constant_prefix_is_hashed_without_witnesses() {
+    let prefix: Vec<String> = (1..=32).map(|byte| byte.to_string()).collect();
+    let prefixed = common::compile(&format!(
+        "fn main(suffix: [u8; 8]) -> pub [u8; 32] {{
+            let mut input = [{}, 0, 0, 0, 0, 0, 0, 0, 0];
+            for i in 0..8 {{
+                input[32 + i] = suffix[i];
+            }}
+            dep::std::hash::blake2s(input)
+        }}",
+        prefix.join(", ")
+    ));
+    let suffix_only = common::compile(
+        "fn main(suffix: [u8; 8]) -> pub [u8; 32] { dep::std::hash::blake2s(suffix) }",
+    );
+
+    let prefixed = main_circuit(&prefixed);
+    let suffix_only = main_circuit(&suffix_only);
+    assert_eq!(blake2s_inputs(prefixed), (32, 8));
+
+    // The constant prefix adds no witnesses or opcodes to those needed to hash the suffix alone.
+    assert_eq!(prefixed.current_witness_index, suffix_only.current_witness_index);
+    assert_eq!(prefixed.opcodes.len(), suffix_only.opcodes.len());
+}
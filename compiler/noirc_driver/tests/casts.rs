@@ -0,0 +1,121 @@
+use acvm::FieldElement;
+use noirc_driver::CompiledProgram;
+
+mod common;
+
+/// Executes `main(x, flag)`, returning the values it returns or None if the circuit is not satisfied.
+fn try_execute(program: &CompiledProgram, x: u128, flag: bool) -> Option<Vec<FieldElement>> {
+    let inputs = common::inputs([("x", x.into()), ("flag", u128::from(flag).into())]);
+    common::execute(program, &inputs).map(common::to_fields)
+}
+
+fn execute(program: &CompiledProgram, x: u128, flag: bool) -> Vec<FieldElement> {
+    try_execute(program, x, flag).expect("circuit should be satisfied")
+}
+
+/// Every accepted cast between bool, Field and integers, evaluated in `runtime`.
+fn cast_program(runtime: &str) -> String {
+    format!(
+        "fn main(x: Field, flag: bool) -> pub (Field, Field, Field, Field, Field, Field) {{
+            casts(x, flag)
+        }}
+
+        {runtime} fn casts(x: Field, flag: bool) -> (Field, Field, Field, Field, Field, Field) {{
+            let narrow = x as u8;
+            let wide = narrow as u64;
+            let back = (x as u64) as u8;
+            (flag as Field, flag as u8 as Field, (flag as i8) as Field, narrow as Field, wide as Field, back as Field)
+        }}"
+    )
+}
+
+#[test]
+fn bool_casts_to_zero_or_one() {
+    for runtime in ["", "unconstrained"] {
+        let program = common::compile(&cast_program(runtime));
+        let field = FieldElement::from;
+
+        let values = execute(&program, 5, true);
+        assert_eq!(values[..3], [field(1u128), field(1u128), field(1u128)], "{runtime}");
+
+        let values = execute(&program, 5, false);
+        assert_eq!(values[..3], [field(0u128), field(0u128), field(0u128)], "{runtime}");
+    }
+}
+
+#[test]
+fn narrowing_casts_truncate() {
+    for runtime in ["", "unconstrained"] {
+        let program = common::compile(&cast_program(runtime));
+        let field = FieldElement::from;
+
+        // Values which fit are unchanged.
+        assert_eq!(
+            execute(&program, 200, true)[3..],
+            [field(200u128), field(200u128), field(200u128)]
+        );
+
+        // 300 = 256 + 44, so narrowing to a u8 keeps only the low 8 bits and widening keeps them.
+        assert_eq!(
+            execute(&program, 300, true)[3..],
+            [field(44u128), field(44u128), field(44u128)]
+        );
+    }
+}
+
+/// Converts `x` into a u8 with both a checked and a truncating cast, evaluated in `runtime`.
+fn checked_cast_program(runtime: &str) -> String {
+    format!(
+        "fn main(x: Field, flag: bool) -> pub (Field, Field) {{
+            casts(x, flag)
+        }}
+
+        {runtime} fn casts(x: Field, flag: bool) -> (Field, Field) {{
+            let checked: u8 = dep::std::assert_cast(x);
+            let from_bool: u8 = dep::std::assert_cast(flag);
+            (checked as Field + from_bool as Field, (x as u8) as Field)
+        }}"
+    )
+}
+
+#[test]
+fn checked_casts_fail_instead_of_truncating() {
+    for runtime in ["", "unconstrained"] {
+        let program = common::compile(&checked_cast_program(runtime));
+        let field = FieldElement::from;
+
+        assert_eq!(try_execute(&program, 200, true), Some(vec![field(201u128), field(200u128)]));
+        assert_eq!(try_execute(&program, 255, false), Some(vec![field(255u128), field(255u128)]));
+
+        // The truncating cast alone would accept 300 as 44.
+        assert_eq!(try_execute(&program, 300, false), None, "{runtime}");
+    }
+}
+
+#[test]
+fn checked_casts_to_signed_integers_are_rejected() {
+    let source = "fn main(x: Field) -> pub i8 { dep::std::assert_cast(x) }";
+    let errors = common::try_compile(source).expect_err("checked casts to i8 should be rejected");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].diagnostic.message,
+        "Cannot use `assert_cast` to convert Field into i8, only unsigned integers and Field are supported"
+    );
+}
+
+#[test]
+fn assert_cast_used_as_a_value_is_rejected() {
+    let source = "
+    fn main(x: Field) -> pub u8 {
+        let cast: fn(Field) -> u8 = dep::std::assert_cast;
+        cast(x)
+    }
+    ";
+    let errors = common::try_compile(source).expect_err("assert_cast should only be called");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].diagnostic.message, "`assert_cast` can only be called directly");
+
+    let span = errors[0].diagnostic.secondaries[0].span;
+    let start = span.start() as usize;
+    assert_eq!(&source[start..span.end() as usize], "dep::std::assert_cast");
+}
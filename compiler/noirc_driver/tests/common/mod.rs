@@ -0,0 +1,119 @@
+//! Helpers shared by the integration tests, which compile and execute programs written in Noir.
+//!
+//! Each integration test is compiled as a separate crate using only some of these helpers.
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use acvm::{
+    blackbox_solver::StubbedBlackBoxSolver,
+    pwg::{ACVMStatus, ACVM},
+    FieldElement,
+};
+use noirc_abi::{input_parser::InputValue, InputMap};
+use noirc_driver::{
+    compile_main, file_manager_with_stdlib, prepare_crate, CompilationResult, CompileOptions,
+    CompiledProgram,
+};
+use noirc_errors::FileDiagnostic;
+use noirc_frontend::{
+    graph::CrateId,
+    hir::{def_map::parse_file, Context},
+};
+
+/// Prepares a crate made up of `files`, the first of which is the crate root.
+pub(crate) fn prepare_files(files: &[(&str, &str)]) -> (Context<'static, 'static>, CrateId) {
+    let root = Path::new("");
+    let mut file_manager = file_manager_with_stdlib(root);
+    for (file_name, source) in files {
+        file_manager
+            .add_file_with_source(Path::new(file_name), source.to_string())
+            .expect("Adding source buffer to file manager should never fail");
+    }
+    let parsed_files = file_manager
+        .as_file_map()
+        .all_file_ids()
+        .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+        .collect();
+
+    let mut context = Context::new(file_manager, parsed_files);
+    let crate_id = prepare_crate(&mut context, Path::new(files[0].0));
+    (context, crate_id)
+}
+
+/// Prepares a crate made up of the single file `source`.
+pub(crate) fn prepare(source: &str) -> (Context<'static, 'static>, CrateId) {
+    prepare_files(&[("main.nr", source)])
+}
+
+/// Type checks a single file program.
+pub(crate) fn check(source: &str) -> CompilationResult<()> {
+    let (mut context, crate_id) = prepare(source);
+    noirc_driver::check_crate(&mut context, crate_id, false, false, false)
+}
+
+pub(crate) fn try_compile_with_options(
+    source: &str,
+    options: &CompileOptions,
+) -> CompilationResult<CompiledProgram> {
+    let (mut context, crate_id) = prepare(source);
+    compile_main(&mut context, crate_id, options, None)
+}
+
+pub(crate) fn try_compile(source: &str) -> CompilationResult<CompiledProgram> {
+    try_compile_with_options(source, &CompileOptions::default())
+}
+
+/// Compiles a single file program, returning it along with the warnings it produced.
+pub(crate) fn compile_with_options(
+    source: &str,
+    options: &CompileOptions,
+) -> (CompiledProgram, Vec<FileDiagnostic>) {
+    try_compile_with_options(source, options)
+        .unwrap_or_else(|errors| panic!("program should compile: {errors:?}\n{source}"))
+}
+
+pub(crate) fn compile(source: &str) -> CompiledProgram {
+    compile_with_options(source, &CompileOptions::default()).0
+}
+
+/// Executes `main` with `inputs`, returning the value it returns or None if the circuit is not
+/// satisfied.
+pub(crate) fn execute(program: &CompiledProgram, inputs: &InputMap) -> Option<InputValue> {
+    let initial_witness = program.abi.encode(inputs, None).expect("inputs should encode");
+
+    let circuit = &program.program.functions[0];
+    let mut acvm = ACVM::new(
+        &StubbedBlackBoxSolver,
+        &circuit.opcodes,
+        initial_witness,
+        &program.program.unconstrained_functions,
+        &circuit.assert_messages,
+    );
+    if acvm.solve() != ACVMStatus::Solved {
+        return None;
+    }
+
+    let (_, return_value) = program.abi.decode(&acvm.finalize()).expect("witness should decode");
+    Some(return_value.expect("main should return a value"))
+}
+
+/// Builds the inputs to `main` from the names of its parameters and their values.
+pub(crate) fn inputs<const N: usize>(values: [(&str, FieldElement); N]) -> InputMap {
+    values.into_iter().map(|(name, value)| (name.to_string(), InputValue::Field(value))).collect()
+}
+
+pub(crate) fn to_field(value: InputValue) -> FieldElement {
+    match value {
+        InputValue::Field(value) => value,
+        _ => panic!("Expected a field, got {value:?}"),
+    }
+}
+
+/// Flattens a returned tuple or array of fields.
+pub(crate) fn to_fields(value: InputValue) -> Vec<FieldElement> {
+    match value {
+        InputValue::Vec(values) => values.into_iter().map(to_field).collect(),
+        _ => panic!("Expected a tuple, got {value:?}"),
+    }
+}
@@ -0,0 +1,49 @@
+use noirc_driver::CompiledProgram;
+
+mod common;
+
+/// A function returning one of 64 constants, compiled as a separate circuit.
+fn round_constants() -> String {
+    let constants: Vec<String> = (0..64).map(|i| (i * 7 + 3).to_string()).collect();
+    format!(
+        "#[fold]\nfn round_constant(i: u32) -> Field {{\n    [{}][i]\n}}\n",
+        constants.join(", ")
+    )
+}
+
+/// Returns the number of opcodes of each circuit in the program, starting with `main`.
+fn circuit_opcodes(program: &CompiledProgram) -> Vec<usize> {
+    program.program.functions.iter().map(|function| function.opcodes.len()).collect()
+}
+
+#[test]
+fn table_lookup_with_constant_argument_is_evaluated() {
+    let table = round_constants();
+    let constant = common::compile(&format!(
+        "fn main(x: Field) -> pub Field {{ x * round_constant(37) }}\n{table}"
+    ));
+    let runtime = common::compile(&format!(
+        "fn main(x: Field, i: u32) -> pub Field {{ x * round_constant(i) }}\n{table}"
+    ));
+    let literal = common::compile("fn main(x: Field) -> pub Field { x * 262 }");
+
+    // The call is replaced by the constant it returns, so `round_constant` is never compiled.
+    assert_eq!(circuit_opcodes(&constant), circuit_opcodes(&literal));
+    assert_eq!(circuit_opcodes(&runtime).len(), 2);
+}
+
+#[test]
+fn function_with_constraint_is_not_evaluated() {
+    let program = common::compile(
+        "fn main(x: Field) -> pub Field { x * checked_round_constant(37) }
+
+        #[fold]
+        fn checked_round_constant(i: u32) -> Field {
+            assert(i < 64);
+            i as Field * 7 + 3
+        }",
+    );
+
+    // Constraints are left to be checked at runtime, so the circuit is still called.
+    assert_eq!(circuit_opcodes(&program).len(), 2);
+}
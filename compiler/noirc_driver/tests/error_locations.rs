@@ -0,0 +1,31 @@
+use noirc_errors::FileDiagnostic;
+
+mod common;
+
+/// Returns the source text the error's label points to.
+fn labelled_source<'a>(source: &'a str, error: &FileDiagnostic) -> &'a str {
+    let label = error.diagnostic.secondaries.first().expect("the error should have a label");
+    &source[label.span.start() as usize..label.span.end() as usize]
+}
+
+#[test]
+fn index_out_of_bounds_in_destructured_struct_points_to_index() {
+    let source = "
+    struct Foo {
+        values: [Field; 3],
+    }
+
+    fn main(x: Field) -> pub Field {
+        let Foo { values } = Foo { values: [x, x, x] };
+        values[3]
+    }
+    ";
+    let errors = common::try_compile(source).expect_err("the index is out of bounds");
+    let error = errors
+        .iter()
+        .find(|error| error.diagnostic.message.starts_with("Index out of bounds"))
+        .expect("an index out of bounds error should be reported");
+
+    assert_eq!(labelled_source(source, error), "values[3]");
+    assert!(!error.call_stack.is_empty());
+}
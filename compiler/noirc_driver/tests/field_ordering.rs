@@ -0,0 +1,62 @@
+use acvm::FieldElement;
+use noirc_driver::CompiledProgram;
+use num_bigint::BigUint;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+mod common;
+
+/// Executes `main(a, b)` and returns the values it returns.
+fn execute(program: &CompiledProgram, a: FieldElement, b: FieldElement) -> Vec<FieldElement> {
+    let inputs = common::inputs([("a", a), ("b", b)]);
+    let return_value = common::execute(program, &inputs)
+        .unwrap_or_else(|| panic!("{a} and {b} should be comparable"));
+    common::to_fields(return_value)
+}
+
+/// The canonical representative of `value` in `[0, p)`.
+fn to_biguint(value: FieldElement) -> BigUint {
+    BigUint::from_bytes_be(&value.to_be_bytes())
+}
+
+#[test]
+fn field_ordering_matches_big_integer_ordering() {
+    let program = common::compile(
+        "fn main(a: Field, b: Field) -> pub (bool, Field, Field) {
+            (dep::std::field::lt(a, b), dep::std::field::min(a, b), dep::std::field::max(a, b))
+        }",
+    );
+
+    let two_pow_128 = FieldElement::from(2u128).pow(&FieldElement::from(128u128));
+    let minus_one = -FieldElement::one();
+    let mut values = vec![
+        FieldElement::zero(),
+        FieldElement::one(),
+        two_pow_128 - FieldElement::one(),
+        two_pow_128,
+        minus_one,
+        minus_one - FieldElement::one(),
+        minus_one - two_pow_128,
+    ];
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+    values.extend((0..8).map(|_| FieldElement::from_be_bytes_reduce(&rng.gen::<[u8; 32]>())));
+
+    for &a in &values {
+        for &b in &values {
+            let is_lt = to_biguint(a) < to_biguint(b);
+            let (min, max) = if is_lt { (a, b) } else { (b, a) };
+            assert_eq!(
+                execute(&program, a, b),
+                vec![FieldElement::from(is_lt), min, max],
+                "comparing {a} with {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn field_lt_opcode_count() {
+    let program =
+        common::compile("fn main(a: Field, b: Field) -> pub bool { dep::std::field::lt(a, b) }");
+    // Documented on `std::field::lt`. Update both if the implementation changes.
+    assert_eq!(program.program.functions[0].opcodes.len(), 88);
+}
@@ -0,0 +1,40 @@
+mod common;
+
+#[test]
+fn iterates_over_array_elements() {
+    let source = "
+    fn main(values: [Field; 3]) -> pub Field {
+        let mut sum = 0;
+        for value in values {
+            sum += value;
+        }
+        sum
+    }
+    ";
+    common::check(source).expect("array loop should type check");
+}
+
+#[test]
+fn destructures_array_elements() {
+    let source = "
+    struct Point {
+        x: Field,
+        y: u8,
+    }
+
+    fn main(points: [Point; 2]) {
+        let mut total = 0;
+        for Point { x, y } in points {
+            total += x + y as Field;
+        }
+        let pairs: [(u8, bool); 2] = [(1, true), (2, false)];
+        for (value, enabled) in pairs {
+            if enabled {
+                let _: u8 = value;
+            }
+        }
+        assert(total != 0);
+    }
+    ";
+    common::check(source).expect("destructuring loop should type check");
+}
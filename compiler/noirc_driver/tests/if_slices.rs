@@ -0,0 +1,63 @@
+mod common;
+
+#[test]
+fn if_branches_of_different_lengths_merge_into_a_slice_target() {
+    let source = "
+    fn main(c: bool) -> pub Field {
+        let s: [Field] = if c { [1, 2] } else { [1, 2, 3] };
+        s[0]
+    }
+    ";
+    common::check(source).expect("branches should be converted into slices");
+}
+
+#[test]
+fn if_branches_of_different_lengths_with_an_array_target_name_both_lengths() {
+    let source = "
+    fn main(c: bool) {
+        let a: [Field; 3] = if c { [1, 2] } else { [1, 2, 3] };
+        assert(a[0] == 1);
+    }
+    ";
+    let errors = common::check(source).expect_err("an array cannot hold both branches");
+    let message = &errors[0].diagnostic.message;
+    assert_eq!(message, "Expected type [Field; 2], found type [Field; 3]");
+}
+
+#[test]
+fn if_branches_of_different_lengths_merge_into_a_slice_argument() {
+    let source = "
+    struct Lengths {}
+
+    impl Lengths {
+        fn of(_self: Self, s: [Field]) -> u64 {
+            s.len()
+        }
+    }
+
+    fn length(s: [Field]) -> u64 {
+        s.len()
+    }
+
+    fn main(c: bool) -> pub u64 {
+        let lengths = Lengths {};
+        length(if c { [1, 2] } else { [1, 2, 3] })
+            + lengths.of(if c { [1] } else { [1, 2] })
+    }
+    ";
+    common::check(source).expect("branches should be converted into slices");
+}
+
+#[test]
+fn if_branches_of_different_lengths_merge_into_a_slice_return_value() {
+    let source = "
+    fn choose(c: bool) -> [Field] {
+        if c { [1, 2] } else { [1, 2, 3] }
+    }
+
+    fn main(c: bool) -> pub Field {
+        choose(c)[0]
+    }
+    ";
+    common::check(source).expect("branches should be converted into slices");
+}
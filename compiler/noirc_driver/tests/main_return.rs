@@ -0,0 +1,116 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use acvm::{
+    acir::{circuit::Opcode, native_types::Witness},
+    FieldElement,
+};
+use noirc_abi::{
+    input_parser::{Format, InputValue},
+    AbiType, Sign, MAIN_RETURN_NAME,
+};
+
+mod common;
+
+const STRUCT_RETURN: &str = "
+    struct Output {
+        values: [Field; 2],
+        pair: (Field, u32),
+    }
+
+    fn main(x: Field, y: u32) -> pub Output {
+        Output { values: [x, x + 1], pair: (x * y as Field, y) }
+    }
+";
+
+fn field(value: u128) -> InputValue {
+    InputValue::Field(FieldElement::from(value))
+}
+
+fn field_elements(values: &[u128]) -> Vec<FieldElement> {
+    values.iter().map(|value| FieldElement::from(*value)).collect()
+}
+
+#[test]
+fn struct_return_is_described_in_abi() {
+    let program = common::compile(STRUCT_RETURN);
+    let return_type = program.abi.return_type.as_ref().expect("main should return a value");
+
+    let AbiType::Struct { path, fields } = &return_type.abi_type else {
+        panic!("expected a struct return type, found {:?}", return_type.abi_type);
+    };
+    assert_eq!(path, "Output");
+    assert_eq!(
+        fields,
+        &vec![
+            ("values".to_string(), AbiType::Array { length: 2, typ: Box::new(AbiType::Field) }),
+            (
+                "pair".to_string(),
+                AbiType::Tuple {
+                    fields: vec![
+                        AbiType::Field,
+                        AbiType::Integer { sign: Sign::Unsigned, width: 32 }
+                    ]
+                }
+            ),
+        ]
+    );
+    assert_eq!(program.abi.return_witnesses.len(), 4);
+}
+
+#[test]
+fn each_returned_component_is_constrained() {
+    let program = common::compile(STRUCT_RETURN);
+    let circuit = &program.program.functions[0];
+    let return_witnesses = &program.abi.return_witnesses;
+
+    let public_returns: BTreeSet<Witness> = circuit.return_values.0.iter().copied().collect();
+    assert_eq!(public_returns.len(), return_witnesses.len(), "return witnesses should be distinct");
+    assert_eq!(public_returns, return_witnesses.iter().copied().collect());
+
+    // Every component of the return value is tied to the computed value by a constraint.
+    for witness in return_witnesses {
+        let constrained = circuit.opcodes.iter().any(|opcode| match opcode {
+            Opcode::AssertZero(expression) => {
+                expression.linear_combinations.iter().any(|(_, term)| term == witness)
+            }
+            _ => false,
+        });
+        assert!(constrained, "return witness {witness:?} should be constrained");
+    }
+}
+
+#[test]
+fn struct_return_roundtrips_through_verifier_inputs() {
+    let program = common::compile(STRUCT_RETURN);
+    let abi = &program.abi;
+
+    let inputs = BTreeMap::from([("x".to_string(), field(3)), ("y".to_string(), field(7))]);
+    let return_value = InputValue::Struct(BTreeMap::from([
+        ("values".to_string(), InputValue::Vec(vec![field(3), field(4)])),
+        ("pair".to_string(), InputValue::Vec(vec![field(21), field(7)])),
+    ]));
+
+    // The return value is encoded component by component into the return witnesses.
+    let witness_map = abi.encode(&inputs, Some(return_value.clone())).unwrap();
+    let encoded: Vec<FieldElement> =
+        abi.return_witnesses.iter().map(|witness| witness_map[witness]).collect();
+    assert_eq!(encoded, field_elements(&[3, 4, 21, 7]));
+
+    let (_, decoded) = abi.decode(&witness_map).unwrap();
+    assert_eq!(decoded, Some(return_value.clone()));
+
+    // A `Verifier.toml` holding the return value keeps its structure.
+    let mut verifier_inputs = inputs;
+    verifier_inputs.insert(MAIN_RETURN_NAME.to_string(), return_value);
+    let toml = Format::Toml.serialize(&verifier_inputs, abi).unwrap();
+    assert_eq!(Format::Toml.parse(&toml, abi).unwrap(), verifier_inputs);
+}
+
+#[test]
+fn return_value_with_wrong_shape_is_rejected() {
+    let program = common::compile(STRUCT_RETURN);
+    let inputs = BTreeMap::from([("x".to_string(), field(3)), ("y".to_string(), field(7))]);
+    let flat_return_value = InputValue::Vec(vec![field(3), field(4), field(21), field(7)]);
+
+    assert!(program.abi.encode(&inputs, Some(flat_return_value)).is_err());
+}
@@ -0,0 +1,74 @@
+use noirc_driver::ErrorsAndWarnings;
+
+mod common;
+
+/// Type checks a crate made up of `files`, the first of which is the crate root.
+fn check_files(files: &[(&str, &str)]) -> Result<(), ErrorsAndWarnings> {
+    let (mut context, crate_id) = common::prepare_files(files);
+    noirc_driver::check_crate(&mut context, crate_id, false, false, false).map_err(|errors| {
+        // Warnings, such as for unused modules, are returned along with the errors.
+        errors
+            .into_iter()
+            .filter(|error| error.diagnostic.is_error())
+            .collect::<ErrorsAndWarnings>()
+    })?;
+    Ok(())
+}
+
+fn error_messages(errors: ErrorsAndWarnings) -> Vec<String> {
+    errors.into_iter().map(|error| error.diagnostic.message).collect()
+}
+
+#[test]
+fn resolves_nested_module_files() {
+    // Each level of the tree uses a different naming convention for its module file.
+    let files = [
+        ("src/main.nr", "mod circuits;\nfn main() -> pub Field { circuits::transfer::amount() }"),
+        ("src/circuits/mod.nr", "mod transfer;"),
+        ("src/circuits/transfer.nr", "mod limits;\npub fn amount() -> Field { limits::MAX }"),
+        ("src/circuits/transfer/limits/mod.nr", "global MAX: Field = 10;"),
+    ];
+
+    check_files(&files).map_err(error_messages).expect("module tree should resolve");
+}
+
+#[test]
+fn missing_module_file_reports_both_candidate_paths() {
+    let files = [("src/main.nr", "mod circuits;\nfn main() {}")];
+
+    let errors = error_messages(check_files(&files).unwrap_err());
+    assert_eq!(
+        errors,
+        vec!["No module `circuits` at path `src/circuits.nr` or `src/circuits/mod.nr`"]
+    );
+}
+
+#[test]
+fn module_including_itself_is_an_error() {
+    // `src/foo/foo.nr` looks for its child modules next to itself, so `mod foo;` refers back to the same file.
+    let files = [
+        ("src/main.nr", "mod foo;\nfn main() {}"),
+        ("src/foo.nr", "mod foo;"),
+        ("src/foo/foo.nr", "mod foo;"),
+    ];
+
+    let errors = error_messages(check_files(&files).unwrap_err());
+    assert_eq!(errors, vec!["Module `foo` cannot be declared within itself"]);
+}
+
+#[test]
+fn inline_module_shadowing_file_module_is_an_error() {
+    let files = [
+        ("src/main.nr", "mod helpers;\nmod helpers {}\nfn main() {}"),
+        ("src/helpers.nr", "pub fn double(x: Field) -> Field { x * 2 }"),
+    ];
+
+    let errors = check_files(&files).unwrap_err();
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let diagnostic = &errors[0].diagnostic;
+    assert_eq!(diagnostic.message, "Duplicate definitions of module with name helpers found");
+
+    // Both the file module declaration and the inline module are labelled.
+    let spans: Vec<_> = diagnostic.secondaries.iter().map(|label| label.span).collect();
+    assert_eq!(spans, vec![(4..11).into(), (17..24).into()]);
+}
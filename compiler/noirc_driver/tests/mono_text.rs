@@ -0,0 +1,177 @@
+use std::path::Path;
+
+use noirc_frontend::monomorphization::{
+    monomorphize,
+    text::{parse_program, print_program},
+};
+
+mod common;
+
+/// Type checks and monomorphizes a single file program, returning its textual form.
+fn monomorphized_text(source: String) -> String {
+    let (mut context, crate_id) = common::prepare(&source);
+    noirc_driver::check_crate(&mut context, crate_id, false, false, false)
+        .expect("program should type check");
+
+    let main = context.get_main_function(&crate_id).expect("program should have a main function");
+    let program =
+        monomorphize(main, &mut context.def_interner).expect("program should monomorphize");
+    print_program(&program)
+}
+
+/// Execution tests which between them cover every kind of monomorphized expression,
+/// along with large parts of the standard library.
+const PROGRAMS: [&str; 16] = [
+    "assert_statement",
+    "brillig_oracle",
+    "break_and_continue",
+    "closures_mut_ref",
+    "databus",
+    "debug_logs",
+    "eddsa",
+    "fold_basic",
+    "higher_order_functions",
+    "references",
+    "sha256",
+    "signed_division",
+    "slices",
+    "strings",
+    "struct",
+    "u128",
+];
+
+#[test]
+fn textual_monomorphized_programs_round_trip() {
+    let test_programs =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test_programs/execution_success");
+
+    for program in PROGRAMS {
+        let source = std::fs::read_to_string(test_programs.join(program).join("src/main.nr"))
+            .expect("test program should exist");
+        let text = monomorphized_text(source);
+        let reparsed = parse_program(&text).unwrap_or_else(|error| panic!("{program}: {error}"));
+        assert_eq!(print_program(&reparsed), text, "{program} did not round trip");
+    }
+}
+
+#[test]
+fn unchecked_functions_round_trip() {
+    let source = "fn main(x: u8) -> pub u8 { add(x, x) }
+        #[no_overflow_checks]
+        fn add(x: u8, y: u8) -> u8 { x + y }";
+    let text = monomorphized_text(source.to_string());
+    assert!(text.contains("\"add\" constrained inline unchecked"), "{text}");
+
+    let reparsed = parse_program(&text).unwrap_or_else(|error| panic!("{error}"));
+    let add = reparsed.functions.iter().find(|function| function.name == "add");
+    assert!(!add.expect("program should contain `add`").overflow_checks);
+    assert_eq!(print_program(&reparsed), text);
+}
+
+#[test]
+fn identifiers_record_their_definition_kind() {
+    let source = "global G: Field = 3;
+        fn main(x: Field) -> pub Field {
+            let y = x + G;
+            twice(y)
+        }
+        fn twice(z: Field) -> Field { z * 2 }";
+    let text = monomorphized_text(source.to_string());
+    assert!(text.contains("(ident \"x\" (param "), "{text}");
+    assert!(text.contains("(ident \"z\" (param "), "{text}");
+    assert!(text.contains("(ident \"y\" (local "), "{text}");
+    assert!(text.contains("(ident \"twice\" (function "), "{text}");
+    // Globals are inlined rather than referred to.
+    assert!(!text.contains("\"G\""), "{text}");
+    assert!(text.contains("(int 3 Field)"), "{text}");
+
+    let reparsed = parse_program(&text).unwrap_or_else(|error| panic!("{error}"));
+    assert_eq!(print_program(&reparsed), text);
+}
+
+#[test]
+fn unrelated_functions_do_not_change_stable_names() {
+    let sum = "fn sum(x: Field, y: Field) -> Field {
+            let x = x + y;
+            let x = x * 2;
+            x
+        }";
+    let before =
+        monomorphized_text(format!("fn main(x: Field) -> pub Field {{ sum(x, 1) }} {sum}"));
+    let after = monomorphized_text(format!(
+        "fn main(x: Field) -> pub Field {{
+            let y = double(x);
+            sum(y, 1)
+        }}
+        fn double(x: Field) -> Field {{ x * 2 }}
+        {sum}"
+    ));
+
+    // The text of `sum`, up to the start of the next function if there is one.
+    let sum_text = |text: &str| {
+        let start = text.find("(fn \"sum#0\"").unwrap_or_else(|| panic!("{text}"));
+        let end = text[start..].find("\n  (fn ").map_or(text.len(), |end| start + end);
+        text[start..end].trim_end().trim_end_matches(')').to_string()
+    };
+    let sum_before = sum_text(&before);
+    assert_eq!(sum_before, sum_text(&after));
+
+    // Shadowed locals are told apart by the number of earlier locals with the same name.
+    assert!(sum_before.contains("(param \"x@0\" imm \"x\" Field)"), "{sum_before}");
+    assert!(sum_before.contains("(let \"x@1\" \"x\" imm"), "{sum_before}");
+    assert!(sum_before.contains("(let \"x@2\" \"x\" imm"), "{sum_before}");
+    // The closing parentheses at the end of `sum` were trimmed along with those of the program.
+    assert!(sum_before.ends_with("(ident \"x\" (local \"x@2\") imm Field"), "{sum_before}");
+    assert!(after.contains("(ident \"double\" (function \"double#0\")"), "{after}");
+
+    let reparsed = parse_program(&after).unwrap_or_else(|error| panic!("{error}"));
+    assert_eq!(print_program(&reparsed), after);
+}
+
+#[test]
+fn generic_instantiations_are_named_after_their_types() {
+    let source = "struct Point { x: Field, y: u8 }
+        fn main(xs: [Field; 3], point: Point) -> pub Field {
+            let y = first(xs) + first([point.y, 2]) as Field;
+            y + point.x
+        }
+        fn first<T, N>(xs: [T; N]) -> T { xs[0] }";
+    let text = monomorphized_text(source.to_string());
+    assert!(text.contains("(fn \"first::<Field, 3>#0\" \"first::<Field, 3>\""), "{text}");
+    assert!(text.contains("(fn \"first::<u8, 2>#0\" \"first::<u8, 2>\""), "{text}");
+    // Structs are lowered to tuples of their fields.
+    assert!(text.contains("(param \"point@0\" imm \"point\" (tuple Field u8))"), "{text}");
+
+    let reparsed = parse_program(&text).unwrap_or_else(|error| panic!("{error}"));
+    assert_eq!(print_program(&reparsed), text);
+}
+
+#[test]
+fn numeric_ids_are_still_accepted() {
+    let source = "(program (return-visibility pub) (recursive false)
+  (fn 0 \"main\" constrained inline
+    (params (param 3 imm \"x\" Field))
+    (return Field)
+    (signature (params (pub Field)) (return Field))
+    (block
+      (let 5 \"y\" imm (ident \"x\" (param 3) imm Field))
+      (ident \"y\" (local 5) imm Field))))
+";
+    let program = parse_program(source).unwrap_or_else(|error| panic!("{error}"));
+    let text = print_program(&program);
+    assert!(
+        text.contains("(let \"y@0\" \"y\" imm (ident \"x\" (param \"x@0\") imm Field))"),
+        "{text}"
+    );
+}
+
+#[test]
+fn malformed_textual_program_reports_position() {
+    let source = "(program (return-visibility pub) (recursive false)\n  (fn 0 \"main\" pure inline";
+    let error = parse_program(source).unwrap_err();
+    assert_eq!(error.to_string(), "Unclosed `(` at byte 53");
+
+    let source = "(program (return-visibility pub) (recursive false) (fn 0 \"main\" pure inline (params) (return unit) (block)))";
+    let error = parse_program(source).unwrap_err();
+    assert_eq!(error.to_string(), "Expected `constrained` or `unconstrained` at byte 64");
+}
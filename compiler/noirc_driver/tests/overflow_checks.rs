@@ -0,0 +1,85 @@
+use acvm::FieldElement;
+use noirc_driver::{CompileOptions, CompiledProgram};
+use noirc_errors::FileDiagnostic;
+
+mod common;
+
+/// Executes `main(a, b)`, returning the values it returns or None if the circuit is not satisfied.
+fn execute(program: &CompiledProgram, a: u128, b: u128) -> Option<Vec<FieldElement>> {
+    let inputs = common::inputs([("a", a.into()), ("b", b.into())]);
+    common::execute(program, &inputs).map(common::to_fields)
+}
+
+fn arithmetic_program(attribute: &str) -> String {
+    format!(
+        "fn main(a: u8, b: u8) -> pub (Field, Field, Field) {{
+            (add(a, b) as Field, sub(a, b) as Field, mul(a, b) as Field)
+        }}
+
+        {attribute}
+        fn add(a: u8, b: u8) -> u8 {{ a + b }}
+
+        {attribute}
+        fn sub(a: u8, b: u8) -> u8 {{ a - b }}
+
+        {attribute}
+        fn mul(a: u8, b: u8) -> u8 {{ a * b }}"
+    )
+}
+
+#[test]
+fn unchecked_functions_use_fewer_opcodes() {
+    let source = |attribute| {
+        format!(
+            "fn main(a: u32, b: u32) -> pub u32 {{ mix(a, b) }}
+
+            {attribute}
+            fn mix(a: u32, b: u32) -> u32 {{ a * b + a * 3 - b }}"
+        )
+    };
+    let checked = common::compile(&source(""));
+    let unchecked = common::compile(&source("#[no_overflow_checks]"));
+
+    let checked = checked.program.functions[0].opcodes.len();
+    let unchecked = unchecked.program.functions[0].opcodes.len();
+    assert!(unchecked < checked, "expected fewer than {checked} opcodes, got {unchecked}");
+}
+
+#[test]
+fn unchecked_arithmetic_is_not_reduced_to_the_integer_type() {
+    let checked = common::compile(&arithmetic_program(""));
+    let unchecked = common::compile(&arithmetic_program("#[no_overflow_checks]"));
+
+    // Results within range are the same either way.
+    let expected = Some(vec![FieldElement::from(7u128), 3u128.into(), 10u128.into()]);
+    assert_eq!(execute(&checked, 5, 2), expected);
+    assert_eq!(execute(&unchecked, 5, 2), expected);
+
+    // Results out of range fail the checked circuit, and are left as Field results otherwise.
+    assert_eq!(execute(&checked, 200, 100), None);
+    assert_eq!(
+        execute(&unchecked, 200, 100),
+        Some(vec![FieldElement::from(300u128), 100u128.into(), 20000u128.into()])
+    );
+    assert_eq!(
+        execute(&unchecked, 1, 2),
+        Some(vec![FieldElement::from(3u128), -FieldElement::one(), 2u128.into()])
+    );
+}
+
+#[test]
+fn unchecked_functions_are_reported() {
+    let source = arithmetic_program("#[no_overflow_checks]");
+    let is_unchecked_warning = |warning: &FileDiagnostic| {
+        warning.diagnostic.message.contains("compiled without overflow checks")
+    };
+
+    let (_, warnings) = common::compile_with_options(&source, &CompileOptions::default());
+    let warnings: Vec<_> = warnings.into_iter().filter(is_unchecked_warning).collect();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].diagnostic.message, "3 functions compiled without overflow checks");
+
+    let options = CompileOptions { allow_unchecked: true, ..CompileOptions::default() };
+    let (_, warnings) = common::compile_with_options(&source, &options);
+    assert!(!warnings.iter().any(is_unchecked_warning));
+}
@@ -0,0 +1,49 @@
+use acvm::acir::circuit::{Opcode, OpcodeLocation};
+use noirc_driver::CompileOptions;
+
+mod common;
+
+const SOURCE: &str = "fn main(x: Field, y: pub Field) {
+    assert(x == y);
+}";
+
+#[test]
+fn constrain_is_traced_from_opcode_to_source() {
+    let options = CompileOptions { full_debug: true, ..CompileOptions::default() };
+    let (program, _) = common::compile_with_options(SOURCE, &options);
+    let debug_info = &program.debug[0];
+
+    let opcodes = &program.program.functions[0].opcodes;
+    let opcode_index = opcodes
+        .iter()
+        .position(|opcode| matches!(opcode, Opcode::AssertZero(_)))
+        .expect("the assertion should compile into an AssertZero opcode");
+
+    let chain = debug_info.provenance(opcode_index).expect("the opcode should have a provenance");
+    assert_eq!(chain.opcode, OpcodeLocation::Acir(opcode_index));
+
+    // SSA layer
+    assert_eq!(chain.ssa_function, "main f0");
+    assert!(chain.ssa_instruction.starts_with("constrain "), "{}", chain.ssa_instruction);
+
+    // Monomorphized layer
+    let (_, expression) = chain.expression.expect("the instruction should have an expression");
+    assert!(expression.contains("x$"), "{expression}");
+    assert!(expression.contains(" == y$"), "{expression}");
+
+    // Source layer
+    let location = *chain.locations.last().expect("the opcode should have a location");
+    let file = &program.file_map[&location.file];
+    let span = location.span.start() as usize..location.span.end() as usize;
+    assert!(file.source[span.clone()].contains("x == y"), "{}", &file.source[span]);
+}
+
+#[test]
+fn provenance_is_only_recorded_with_full_debug() {
+    let program = common::compile(SOURCE);
+    assert!(program.debug[0].provenance_index.is_none());
+    assert_eq!(program.debug[0].provenance(0), None);
+
+    let serialized = serde_json::to_string(&program.debug[0]).unwrap();
+    assert!(!serialized.contains("provenance_index"));
+}
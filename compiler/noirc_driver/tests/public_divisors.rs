@@ -0,0 +1,58 @@
+use noirc_driver::CompileOptions;
+
+mod common;
+
+/// Returns the messages of the warnings about divisors derived from public inputs, along with
+/// the source code they point at.
+fn public_divisor_warnings(source: &str) -> Vec<(String, String)> {
+    let options = CompileOptions { lint_soundness: true, ..CompileOptions::default() };
+    let (_, warnings) = common::compile_with_options(source, &options);
+    warnings
+        .into_iter()
+        .filter(|warning| warning.diagnostic.message.starts_with("Division by a value derived"))
+        .map(|warning| {
+            let span = warning.diagnostic.secondaries[0].span;
+            let code = &source[span.start() as usize..span.end() as usize];
+            (warning.diagnostic.message, code.to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn division_by_public_input_is_flagged() {
+    let source = "fn main(x: Field, y: pub Field) -> pub Field {
+        let double = y * 2;
+        x / double
+    }
+    ";
+    assert_eq!(
+        public_divisor_warnings(source),
+        vec![(
+            "Division by a value derived from public input `y` which may be zero".to_string(),
+            "x / double".to_string()
+        )]
+    );
+
+    // The lint is only run when requested.
+    let (_, warnings) = common::compile_with_options(source, &CompileOptions::default());
+    assert!(warnings.iter().all(|warning| !warning.diagnostic.message.starts_with("Division")));
+}
+
+#[test]
+fn division_by_checked_public_input_is_not_flagged() {
+    let source = "fn main(x: Field, y: pub Field) -> pub Field {
+        assert(y != 0);
+        x / y
+    }
+    ";
+    assert_eq!(public_divisor_warnings(source), vec![]);
+}
+
+#[test]
+fn division_by_private_input_is_not_flagged() {
+    let source = "fn main(x: Field, y: Field, z: pub Field) -> pub Field {
+        x / (y + z)
+    }
+    ";
+    assert_eq!(public_divisor_warnings(source), vec![]);
+}
@@ -0,0 +1,48 @@
+use noirc_driver::{CompilationResult, CompileOptions, CompiledProgram};
+
+mod common;
+
+/// Three public arrays contributing 6 public inputs between them.
+const SOURCE: &str = "
+fn main(a: pub [Field; 2], b: pub [Field; 3], c: pub [Field; 1], d: Field) {
+    assert(a[0] + b[0] + c[0] == d);
+}
+";
+
+fn compile_with_limit(max_public_inputs: u32) -> CompilationResult<CompiledProgram> {
+    let options =
+        CompileOptions { max_public_inputs: Some(max_public_inputs), ..CompileOptions::default() };
+    common::try_compile_with_options(SOURCE, &options)
+}
+
+#[test]
+fn exceeding_public_input_limit_lists_contributors() {
+    let errors = compile_with_limit(4).expect_err("6 public inputs should exceed a limit of 4");
+    assert_eq!(errors.len(), 1);
+
+    let diagnostic = &errors[0].diagnostic;
+    assert!(diagnostic.is_error());
+    assert_eq!(
+        diagnostic.to_string(),
+        "`main` requires 6 public inputs but the backend supports at most 4\n\
+         secondary: public inputs are declared by this function\n\
+         note: Largest contributors: `b` (3 public inputs), `a` (2 public inputs), `c` (1 public input)"
+    );
+}
+
+#[test]
+fn nearing_public_input_limit_warns() {
+    let (_, warnings) = compile_with_limit(7).expect("6 public inputs should fit in a limit of 7");
+    let warnings: Vec<_> = warnings
+        .iter()
+        .filter(|warning| warning.diagnostic.message.contains("public inputs"))
+        .collect();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].diagnostic.message,
+        "`main` uses 6 of the 7 public inputs supported by the backend"
+    );
+
+    let (_, warnings) = compile_with_limit(8).expect("6 public inputs should fit in a limit of 8");
+    assert!(warnings.iter().all(|warning| !warning.diagnostic.message.contains("public inputs")));
+}
@@ -0,0 +1,42 @@
+mod common;
+
+#[test]
+fn recursion_with_a_constant_bound_is_inlined() {
+    let source = "
+    fn main() -> pub Field {
+        factorial(5)
+    }
+
+    fn factorial(n: Field) -> Field {
+        if n == 0 { 1 } else { n * factorial(n - 1) }
+    }
+    ";
+    common::compile(source);
+}
+
+#[test]
+fn unbounded_mutual_recursion_names_the_cycle() {
+    let source = "
+    fn main(x: Field) -> pub Field {
+        ping(x)
+    }
+
+    fn ping(x: Field) -> Field {
+        pong(x + 1)
+    }
+
+    fn pong(x: Field) -> Field {
+        ping(x * 2)
+    }
+    ";
+    let errors = common::try_compile(source).expect_err("recursion cannot be inlined");
+    let diagnostic = &errors[0].diagnostic;
+    assert_eq!(
+        diagnostic.message,
+        "Recursive calls `ping` -> `pong` -> `ping` could not be inlined"
+    );
+
+    let span = diagnostic.secondaries[0].span;
+    let call = &source[span.start() as usize..span.end() as usize];
+    assert_eq!(call, "ping(x * 2)");
+}
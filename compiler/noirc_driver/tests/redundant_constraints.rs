@@ -0,0 +1,52 @@
+use noirc_driver::{CompileOptions, CompiledProgram};
+use noirc_errors::FileDiagnostic;
+
+mod common;
+
+const SOURCE: &str = "fn main(x: u32) {
+    assert(x < 10);
+    assert(x < 20);
+}
+";
+
+fn compile(options: &CompileOptions) -> (CompiledProgram, Vec<FileDiagnostic>) {
+    common::compile_with_options(SOURCE, options)
+}
+
+fn redundant_constraint_warnings(warnings: Vec<FileDiagnostic>) -> Vec<FileDiagnostic> {
+    warnings
+        .into_iter()
+        .filter(|warning| {
+            warning.diagnostic.message == "Constraint is implied by an earlier constraint"
+        })
+        .collect()
+}
+
+#[test]
+fn redundant_constraints_are_only_reported_when_requested() {
+    let (_, warnings) = compile(&CompileOptions::default());
+    assert!(redundant_constraint_warnings(warnings).is_empty());
+
+    let options = CompileOptions { warn_redundant_constraints: true, ..CompileOptions::default() };
+    let (_, warnings) = compile(&options);
+    let warnings = redundant_constraint_warnings(warnings);
+    assert_eq!(warnings.len(), 1);
+
+    // The warning points at the redundant constraint as well as the one which implies it.
+    let source_at = |span: noirc_errors::Span| &SOURCE[span.start() as usize..span.end() as usize];
+    let [redundant, implied_by] = warnings[0].diagnostic.secondaries.as_slice() else {
+        panic!("Expected two labels, got {:?}", warnings[0].diagnostic.secondaries);
+    };
+    assert!(source_at(redundant.span).contains("20"), "{}", source_at(redundant.span));
+    assert!(source_at(implied_by.span).contains("10"), "{}", source_at(implied_by.span));
+}
+
+#[test]
+fn redundant_constraints_are_removed_when_requested() {
+    let opcodes = |program: &CompiledProgram| program.program.functions[0].opcodes.len();
+    let (program, _) = compile(&CompileOptions::default());
+    let options =
+        CompileOptions { optimize_redundant_constraints: true, ..CompileOptions::default() };
+    let (optimized, _) = compile(&options);
+    assert!(opcodes(&optimized) < opcodes(&program));
+}
@@ -0,0 +1,99 @@
+use noirc_driver::{CompileOptions, CompiledProgram};
+
+mod common;
+
+/// Functions which only do any work when `n` is non-zero, compiled as a separate circuit or Brillig function.
+const PAD: &str = "
+#[fold]
+fn pad(x: Field, n: u32) -> Field {
+    let mut result = x;
+    if n != 0 {
+        for _ in 0..4 {
+            result = result * result + x;
+        }
+    }
+    result
+}
+
+unconstrained fn pad_unconstrained(x: Field, n: u32) -> Field {
+    let mut result = x;
+    if n != 0 {
+        for _ in 0..4 {
+            result = result * result + x;
+        }
+    }
+    result
+}
+";
+
+fn compile(main: &str, options: &CompileOptions) -> CompiledProgram {
+    common::compile_with_options(&format!("{main}\n{PAD}"), options).0
+}
+
+/// Returns the number of parameters and opcodes of each circuit called from `main`.
+fn called_circuits(program: &CompiledProgram) -> Vec<(usize, usize)> {
+    program.program.functions[1..]
+        .iter()
+        .map(|function| (function.private_parameters.len(), function.opcodes.len()))
+        .collect()
+}
+
+/// Returns the number of opcodes in each Brillig function.
+fn unconstrained_functions(program: &CompiledProgram) -> Vec<usize> {
+    program.program.unconstrained_functions.iter().map(|function| function.bytecode.len()).collect()
+}
+
+#[test]
+fn constant_shared_by_every_call_removes_branch() {
+    let options = CompileOptions::default();
+    let runtime =
+        compile("fn main(x: Field, n: u32) -> pub Field { pad(x, n) + pad(x + 1, n) }", &options);
+    let constant =
+        compile("fn main(x: Field) -> pub Field { pad(x, 0) + pad(x + 1, 0) }", &options);
+
+    let [(runtime_parameters, runtime_opcodes)] = called_circuits(&runtime)[..] else {
+        panic!("expected a single folded function");
+    };
+    let [(constant_parameters, constant_opcodes)] = called_circuits(&constant)[..] else {
+        panic!("expected a single folded function");
+    };
+
+    // `n` is substituted into `pad`, after which the branch on it is folded away.
+    // The circuit keeps its signature.
+    assert_eq!(runtime_parameters, constant_parameters);
+    assert!(
+        constant_opcodes < runtime_opcodes,
+        "specialized `pad` has {constant_opcodes} opcodes but the original has {runtime_opcodes}"
+    );
+}
+
+#[test]
+fn differing_constants_are_specialized_behind_flag() {
+    let main = "fn main(x: Field) -> pub Field {
+        pad_unconstrained(x, 0) + pad_unconstrained(x, 1) + pad_unconstrained(x, 2)
+    }";
+
+    let program = compile(main, &CompileOptions::default());
+    let [original_opcodes] = unconstrained_functions(&program)[..] else {
+        panic!("expected a single Brillig function");
+    };
+
+    let options = CompileOptions { specialize_any_literal: true, ..CompileOptions::default() };
+    let program = compile(main, &options);
+    let opcodes = unconstrained_functions(&program);
+    assert_eq!(opcodes.len(), 3);
+    // The copy for `n = 0` skips the loop entirely.
+    assert!(opcodes.iter().all(|opcodes| *opcodes <= original_opcodes));
+    assert!(opcodes.iter().any(|opcodes| *opcodes < original_opcodes));
+
+    let options = CompileOptions {
+        specialize_any_literal: true,
+        max_function_specializations: Some(2),
+        ..CompileOptions::default()
+    };
+    let program = compile(main, &options);
+    // The third call keeps using the original function.
+    let opcodes = unconstrained_functions(&program);
+    assert_eq!(opcodes.len(), 3);
+    assert!(opcodes.contains(&original_opcodes));
+}
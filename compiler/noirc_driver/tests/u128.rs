@@ -0,0 +1,177 @@
+//! Differential tests of `u128` arithmetic against Rust's `u128`, in both constrained and
+//! unconstrained code, over random inputs and the values around the type's boundaries.
+use acvm::FieldElement;
+use noirc_driver::CompiledProgram;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+mod common;
+
+/// Executes `main(a, b)`, returning the value it returns or None if the circuit is not satisfied.
+fn execute(program: &CompiledProgram, a: FieldElement, b: FieldElement) -> Option<FieldElement> {
+    let inputs = common::inputs([("a", a), ("b", b)]);
+    common::execute(program, &inputs).map(common::to_field)
+}
+
+/// A program computing `expression` from `a` and `b` in constrained code, and one computing it in
+/// unconstrained code.
+fn programs(parameters: &str, return_type: &str, expression: &str) -> [CompiledProgram; 2] {
+    let constrained = format!("fn main({parameters}) -> pub {return_type} {{ {expression} }}");
+    let unconstrained = format!(
+        "fn main({parameters}) -> pub {return_type} {{ compute(a, b) }}
+        unconstrained fn compute({parameters}) -> {return_type} {{ {expression} }}"
+    );
+    [common::compile(&constrained), common::compile(&unconstrained)]
+}
+
+/// A random `u128`, which is often much smaller than `u128::MAX` so that operations
+/// such as multiplication are also exercised without overflowing.
+fn random_u128(rng: &mut StdRng) -> u128 {
+    rng.gen::<u128>() >> rng.gen_range(0..128)
+}
+
+const BOUNDARIES: [u128; 8] =
+    [0, 1, 2, u64::MAX as u128, 1 << 64, 1 << 127, u128::MAX - 1, u128::MAX];
+
+/// Pairs of operands covering every pair of boundary values followed by random values.
+fn operands() -> Vec<(u128, u128)> {
+    let mut operands = Vec::new();
+    for a in BOUNDARIES {
+        for b in BOUNDARIES {
+            operands.push((a, b));
+        }
+    }
+    let mut rng = StdRng::seed_from_u64(0x2545_f491_4f6c_dd1d);
+    for _ in 0..64 {
+        operands.push((random_u128(&mut rng), random_u128(&mut rng)));
+    }
+    operands
+}
+
+fn check_binary_operation(operator: &str, expected: fn(u128, u128) -> Option<u128>) {
+    check_binary_operation_on(operator, operands(), expected);
+}
+
+fn check_binary_operation_on(
+    operator: &str,
+    operands: Vec<(u128, u128)>,
+    expected: fn(u128, u128) -> Option<u128>,
+) {
+    for program in programs("a: u128, b: u128", "u128", &format!("a {operator} b")) {
+        for &(a, b) in &operands {
+            let result = execute(&program, a.into(), b.into());
+            let expected = expected(a, b).map(FieldElement::from);
+            assert_eq!(result, expected, "{a} {operator} {b}");
+        }
+    }
+}
+
+#[test]
+fn arithmetic_matches_rust() {
+    check_binary_operation("+", u128::checked_add);
+    check_binary_operation("-", u128::checked_sub);
+    check_binary_operation("*", u128::checked_mul);
+
+    // Dividing by zero fails in constrained code but returns zero in unconstrained code.
+    let nonzero_divisors = operands().into_iter().filter(|(_, b)| *b != 0).collect::<Vec<_>>();
+    check_binary_operation_on("/", nonzero_divisors.clone(), u128::checked_div);
+    check_binary_operation_on("%", nonzero_divisors, u128::checked_rem);
+}
+
+#[test]
+fn wrapping_arithmetic_matches_rust() {
+    let operations = [
+        ("wrapping_add", u128::wrapping_add as fn(u128, u128) -> u128),
+        ("wrapping_sub", u128::wrapping_sub),
+        ("wrapping_mul", u128::wrapping_mul),
+    ];
+    for (function, expected) in operations {
+        let expression = format!("dep::std::{function}(a, b)");
+        for program in programs("a: u128, b: u128", "u128", &expression) {
+            for (a, b) in operands() {
+                let result = execute(&program, a.into(), b.into());
+                assert_eq!(result, Some(expected(a, b).into()), "{function}({a}, {b})");
+            }
+        }
+    }
+}
+
+#[test]
+fn bitwise_operations_match_rust() {
+    check_binary_operation("&", |a, b| Some(a & b));
+    check_binary_operation("|", |a, b| Some(a | b));
+    check_binary_operation("^", |a, b| Some(a ^ b));
+}
+
+#[test]
+fn comparisons_match_rust() {
+    let comparisons: [(&str, fn(&u128, &u128) -> bool); 4] =
+        [("<", u128::lt), ("<=", u128::le), (">", u128::gt), ("==", u128::eq)];
+    for (operator, expected) in comparisons {
+        for program in programs("a: u128, b: u128", "bool", &format!("a {operator} b")) {
+            for (a, b) in operands() {
+                let result = execute(&program, a.into(), b.into());
+                assert_eq!(result, Some(expected(&a, &b).into()), "{a} {operator} {b}");
+            }
+        }
+    }
+}
+
+#[test]
+fn shifts_match_rust() {
+    let mut rng = StdRng::seed_from_u64(0x9e37_79b9_7f4a_7c15);
+    let shifts: Vec<(u128, u8)> = operands()
+        .into_iter()
+        .map(|(a, _)| (a, rng.gen_range(0..136)))
+        .chain([(u128::MAX, 0), (u128::MAX, 127), (1, 127), (u128::MAX, 128)])
+        .collect();
+
+    for program in programs("a: u128, b: u8", "u128", "a << b") {
+        for &(a, b) in &shifts {
+            // Shifting left by the bit size or more is an error, bits shifted out are discarded.
+            let expected = (b < 128).then(|| FieldElement::from(a << b));
+            assert_eq!(execute(&program, a.into(), (b as u128).into()), expected, "{a} << {b}");
+        }
+    }
+    for program in programs("a: u128, b: u8", "u128", "a >> b") {
+        for &(a, b) in &shifts {
+            let expected = FieldElement::from(a.checked_shr(b as u32).unwrap_or(0));
+            assert_eq!(
+                execute(&program, a.into(), (b as u128).into()),
+                Some(expected),
+                "{a} >> {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn casts_truncate_to_the_target_type() {
+    for program in programs("a: u128, b: u128", "u64", "(a & b) as u64") {
+        for (a, b) in operands() {
+            let expected = FieldElement::from((a & b) as u64 as u128);
+            assert_eq!(execute(&program, a.into(), b.into()), Some(expected), "({a} & {b}) as u64");
+        }
+    }
+
+    // A `Field` keeps its lowest 128 bits when cast to a `u128`.
+    let two_pow_128 = FieldElement::from(2_u128).pow(&FieldElement::from(128_u128));
+    for program in programs("a: Field, b: Field", "u128", "(a * b) as u128") {
+        for (a, b) in operands() {
+            let value = FieldElement::from(a) * two_pow_128 + FieldElement::from(b);
+            let result = execute(&program, value, FieldElement::one());
+            assert_eq!(result, Some(FieldElement::from(value.to_u128())), "{value} as u128");
+        }
+    }
+}
+
+#[test]
+fn literals_up_to_the_maximum_are_accepted() {
+    let source = "fn main(a: u128, b: u128) -> pub u128 {
+            let max: u128 = 0xffffffffffffffffffffffffffffffff;
+            assert(max == 340282366920938463463374607431768211455);
+            max - a - b
+        }";
+    let program = common::compile(source);
+    assert_eq!(execute(&program, 1u128.into(), 2u128.into()), Some((u128::MAX - 3).into()));
+    assert_eq!(execute(&program, u128::MAX.into(), 1u128.into()), None);
+}
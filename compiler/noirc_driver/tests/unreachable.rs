@@ -0,0 +1,72 @@
+use noirc_driver::{CompilationResult, CompileOptions, CompiledProgram};
+
+mod common;
+
+/// `broken` fails to resolve and type check but is never called, unlike `double`, which is only
+/// reachable through `apply`.
+const SOURCE: &str = "
+fn main(x: Field) -> pub Field {
+    apply(double, x)
+}
+
+fn apply(f: fn(Field) -> Field, x: Field) -> Field {
+    f(x)
+}
+
+fn double(x: Field) -> Field {
+    x * 2
+}
+
+fn broken<T>(x: T) -> T {
+    let y: bool = x;
+    undefined_variable
+}
+";
+
+fn compile(source: &str, skip_unreachable: bool) -> CompilationResult<CompiledProgram> {
+    let options = CompileOptions { skip_unreachable, ..CompileOptions::default() };
+    common::try_compile_with_options(source, &options)
+}
+
+#[test]
+fn errors_in_unreachable_functions_fail_compilation_by_default() {
+    let errors = compile(SOURCE, false).expect_err("`broken` contains errors");
+    assert!(errors.iter().any(|error| error.diagnostic.is_error()));
+}
+
+#[test]
+fn errors_in_unreachable_functions_are_warnings_when_skipping_unreachable_functions() {
+    let (_, warnings) =
+        compile(SOURCE, true).expect("errors within `broken` should be reported as warnings");
+    assert!(warnings.iter().all(|warning| warning.diagnostic.is_warning()));
+    assert!(warnings
+        .iter()
+        .any(|warning| warning.diagnostic.message.contains("undefined_variable")));
+
+    let summary = warnings
+        .iter()
+        .find(|warning| warning.diagnostic.message.contains("unreachable from `main`"))
+        .expect("the skipped functions should be counted");
+    assert_eq!(
+        summary.diagnostic.to_string(),
+        "1 function unreachable from `main` was skipped\n\
+         secondary: reachability is checked from here\n\
+         note: 2 errors within them were reported as warnings"
+    );
+}
+
+#[test]
+fn errors_in_reachable_functions_are_still_reported() {
+    let source = "
+    fn main(x: Field) -> pub Field {
+        helper(x)
+    }
+
+    fn helper(x: Field) -> Field {
+        undefined_variable
+    }
+    ";
+    let errors = compile(source, true).expect_err("`helper` is called from `main`");
+    assert!(errors.iter().any(|error| error.diagnostic.is_error()
+        && error.diagnostic.message.contains("undefined_variable")));
+}
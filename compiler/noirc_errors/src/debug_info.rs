@@ -30,6 +30,10 @@ pub struct DebugFnId(pub u32);
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct DebugTypeId(pub u32);
 
+/// Identifies an expression of the monomorphized program, see [ProvenanceIndex].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct MonomorphizedExprId(pub u32);
+
 #[derive(Debug, Clone, Hash, Deserialize, Serialize)]
 pub struct DebugVariable {
     pub name: String,
@@ -100,6 +104,41 @@ pub struct DebugInfo {
     pub variables: DebugVariables,
     pub functions: DebugFunctions,
     pub types: DebugTypes,
+    /// Only generated when compiling with `--full-debug`, so that it adds nothing to the artifact otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance_index: Option<ProvenanceIndex>,
+}
+
+/// Maps each ACIR opcode of a circuit to the SSA instruction and monomorphized expression it was generated from.
+#[serde_as]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ProvenanceIndex {
+    #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
+    pub opcodes: BTreeMap<OpcodeLocation, SsaInstructionProvenance>,
+    /// The expressions referred to by `opcodes`, as printed by `--show-monomorphized`.
+    pub expressions: BTreeMap<MonomorphizedExprId, String>,
+}
+
+/// The SSA instruction an ACIR opcode was generated from.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SsaInstructionProvenance {
+    /// The name and id of the function containing the instruction, e.g. `main f0`.
+    pub function: String,
+    /// The instruction as printed by `--show-ssa`.
+    pub instruction: String,
+    /// The monomorphized expression the instruction was generated from, if it is known.
+    pub expression: Option<MonomorphizedExprId>,
+}
+
+/// Every step of the compilation which led to a single ACIR opcode, see [DebugInfo::provenance].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvenanceChain {
+    pub opcode: OpcodeLocation,
+    pub ssa_function: String,
+    pub ssa_instruction: String,
+    pub expression: Option<(MonomorphizedExprId, String)>,
+    /// The call stack of source locations, innermost last.
+    pub locations: Vec<Location>,
 }
 
 /// Holds OpCodes Counts for Acir and Brillig Opcodes
@@ -117,7 +156,7 @@ impl DebugInfo {
         functions: DebugFunctions,
         types: DebugTypes,
     ) -> Self {
-        Self { locations, variables, functions, types }
+        Self { locations, variables, functions, types, provenance_index: None }
     }
 
     /// Updates the locations map when the [`Circuit`][acvm::acir::circuit::Circuit] is modified.
@@ -134,6 +173,37 @@ impl DebugInfo {
                 self.locations.insert(new_opcode_location, source_locations.clone());
             });
         }
+
+        if let Some(provenance_index) = &mut self.provenance_index {
+            let old_opcodes = mem::take(&mut provenance_index.opcodes);
+            for (old_opcode_location, provenance) in old_opcodes {
+                update_map.new_locations(old_opcode_location).for_each(|new_opcode_location| {
+                    provenance_index.opcodes.insert(new_opcode_location, provenance.clone());
+                });
+            }
+        }
+    }
+
+    /// Follows the ACIR opcode at `opcode_index` back through the SSA instruction and monomorphized
+    /// expression it was generated from to the source code.
+    ///
+    /// Returns None if the program was not compiled with `--full-debug`, or if the opcode was not
+    /// generated by an SSA instruction (e.g. the opcodes constraining the return values).
+    pub fn provenance(&self, opcode_index: usize) -> Option<ProvenanceChain> {
+        let provenance_index = self.provenance_index.as_ref()?;
+        let opcode = OpcodeLocation::Acir(opcode_index);
+        let instruction = provenance_index.opcodes.get(&opcode)?;
+
+        let expression = instruction.expression.and_then(|id| {
+            provenance_index.expressions.get(&id).map(|expression| (id, expression.clone()))
+        });
+        Some(ProvenanceChain {
+            opcode,
+            ssa_function: instruction.function.clone(),
+            ssa_instruction: instruction.instruction.clone(),
+            expression,
+            locations: self.opcode_location(&opcode).unwrap_or_default(),
+        })
     }
 
     pub fn opcode_location(&self, loc: &OpcodeLocation) -> Option<Vec<Location>> {
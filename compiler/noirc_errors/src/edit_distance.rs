@@ -0,0 +1,28 @@
+/// Returns the Levenshtein distance between two strings, used to suggest
+/// the intended name when a name is misspelled.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous_row[j] + usize::from(a_char != *b_char);
+            row.push(substitution.min(previous_row[j + 1] + 1).min(row[j] + 1));
+        }
+        previous_row = row;
+    }
+    previous_row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::edit_distance;
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("inline", "inline"), 0);
+        assert_eq!(edit_distance("inlin", "inline"), 1);
+        assert_eq!(edit_distance("tset", "test"), 2);
+        assert_eq!(edit_distance("", "fold"), 4);
+    }
+}
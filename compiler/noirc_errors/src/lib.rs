@@ -4,8 +4,10 @@
 #![warn(clippy::semicolon_if_nothing_returned)]
 
 pub mod debug_info;
+mod edit_distance;
 mod position;
 pub mod reporter;
+pub use edit_distance::edit_distance;
 pub use position::{Location, Position, Span, Spanned};
 pub use reporter::{CustomDiagnostic, DiagnosticKind};
 
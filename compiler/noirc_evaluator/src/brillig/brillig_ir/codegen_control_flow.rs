@@ -221,4 +221,10 @@ impl BrilligContext {
             }
         });
     }
+
+    /// Emits brillig bytecode which unconditionally traps with `message`.
+    pub(crate) fn codegen_trap(&mut self, message: String) {
+        self.trap_instruction(HeapArray::default());
+        self.obj.add_assert_message_to_last_opcode(message);
+    }
 }
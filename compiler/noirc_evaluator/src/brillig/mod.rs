@@ -53,7 +53,9 @@ impl Ssa {
         let brillig_reachable_function_ids = self
             .functions
             .iter()
-            .filter_map(|(id, func)| (func.runtime() == RuntimeType::Brillig).then_some(*id))
+            .filter_map(|(id, func)| {
+                matches!(func.runtime(), RuntimeType::Brillig(_)).then_some(*id)
+            })
             .collect::<BTreeSet<_>>();
 
         let mut brillig = Brillig::default();
@@ -43,6 +43,19 @@ pub enum RuntimeError {
     UnconstrainedSliceReturnToConstrained { call_stack: CallStack },
     #[error("All `oracle` methods should be wrapped in an unconstrained fn")]
     UnconstrainedOracleReturnToConstrained { call_stack: CallStack },
+    #[error("Merging slices in this function requires more than {limit} padding elements")]
+    SlicePaddingLimitExceeded { limit: usize, call_stack: CallStack },
+    #[error("`{function}` requires more witnesses than can be indexed in an ACIR circuit")]
+    WitnessIndexOverflow { function: String, call_stack: CallStack },
+    #[error("`{function}` requires inputs of at most {expected_bit_size} bits but was given a {bit_size} bit integer")]
+    BlackBoxInputTooWide {
+        function: String,
+        expected_bit_size: u32,
+        bit_size: u32,
+        call_stack: CallStack,
+    },
+    #[error("Recursive calls {cycle} could not be inlined")]
+    RecursionLimitExceeded { cycle: String, call_stack: CallStack },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +68,12 @@ impl From<SsaReport> for FileDiagnostic {
         match error {
             SsaReport::Warning(warning) => {
                 let message = warning.to_string();
+                let implied_by = match &warning {
+                    InternalWarning::RedundantConstraint { implied_by, .. } => {
+                        implied_by.last().copied()
+                    }
+                    _ => None,
+                };
                 let (secondary_message, call_stack) = match warning {
                     InternalWarning::ReturnConstant { call_stack } => {
                         ("This variable contains a value which is constrained to be a constant. Consider removing this value as additional return values increase proving/verification time".to_string(), call_stack)
@@ -62,12 +81,26 @@ impl From<SsaReport> for FileDiagnostic {
                     InternalWarning::VerifyProof { call_stack } => {
                         ("verify_proof(...) aggregates data for the verifier, the actual verification will be done when the full proof is verified using nargo verify. nargo prove may generate an invalid proof if bad data is used as input to verify_proof".to_string(), call_stack)
                     },
+                    InternalWarning::WitnessCountThreshold { call_stack, .. } => {
+                        (format!("Most of this function's constraints are generated here. A circuit can use at most {} witnesses. {CIRCUIT_SIZE_SUGGESTION}", u32::MAX), call_stack)
+                    },
+                    InternalWarning::RedundantConstraint { call_stack, .. } => {
+                        ("This constraint always holds if the earlier constraints on the same values hold".to_string(), call_stack)
+                    },
+                    InternalWarning::UncheckedPublicDivisor { call_stack, .. } => {
+                        ("Public inputs are chosen by the verifier, who could make this divisor zero. Consider asserting that it is non-zero".to_string(), call_stack)
+                    },
                 };
                 let call_stack = vecmap(call_stack, |location| location);
                 let file_id = call_stack.last().map(|location| location.file).unwrap_or_default();
-                let location = call_stack.last().expect("Expected RuntimeError to have a location");
-                let diagnostic =
-                    Diagnostic::simple_warning(message, secondary_message, location.span);
+                let span = call_stack
+                    .last()
+                    .map_or(noirc_errors::Span::inclusive(0, 0), |location| location.span);
+                let mut diagnostic = Diagnostic::simple_warning(message, secondary_message, span);
+                if let Some(implied_by) = implied_by.filter(|location| location.file == file_id) {
+                    diagnostic
+                        .add_secondary("Implied by this constraint".to_string(), implied_by.span);
+                }
                 diagnostic.in_file(file_id).with_call_stack(call_stack)
             }
         }
@@ -80,8 +113,17 @@ pub enum InternalWarning {
     ReturnConstant { call_stack: CallStack },
     #[error("Calling std::verify_proof(...) does not verify a proof")]
     VerifyProof { call_stack: CallStack },
+    #[error("`{function}` uses more than {threshold} witnesses")]
+    WitnessCountThreshold { function: String, threshold: u32, call_stack: CallStack },
+    #[error("Constraint is implied by an earlier constraint")]
+    RedundantConstraint { call_stack: CallStack, implied_by: CallStack },
+    #[error("Division by a value derived from public input `{parameter}` which may be zero")]
+    UncheckedPublicDivisor { parameter: String, call_stack: CallStack },
 }
 
+/// Suggestions for reducing the size of a circuit which is approaching the witness limit.
+const CIRCUIT_SIZE_SUGGESTION: &str = "Consider moving this code into an unconstrained function, or into a `#[fold]` function so that it is compiled as a separate circuit.";
+
 #[derive(Debug, PartialEq, Eq, Clone, Error)]
 pub enum InternalError {
     #[error("ICE: Both expressions should have degree<=1")]
@@ -123,7 +165,11 @@ impl RuntimeError {
             | RuntimeError::NestedSlice { call_stack, .. }
             | RuntimeError::BigIntModulus { call_stack, .. }
             | RuntimeError::UnconstrainedSliceReturnToConstrained { call_stack }
-            | RuntimeError::UnconstrainedOracleReturnToConstrained { call_stack } => call_stack,
+            | RuntimeError::UnconstrainedOracleReturnToConstrained { call_stack }
+            | RuntimeError::SlicePaddingLimitExceeded { call_stack, .. }
+            | RuntimeError::WitnessIndexOverflow { call_stack, .. }
+            | RuntimeError::BlackBoxInputTooWide { call_stack, .. }
+            | RuntimeError::RecursionLimitExceeded { call_stack, .. } => call_stack,
         }
     }
 }
@@ -155,10 +201,49 @@ impl RuntimeError {
 
                 Diagnostic::simple_error(
                     primary_message,
-                    "If attempting to fetch the length of a slice, try converting to an array. Slices only use dynamic lengths.".to_string(),
+                    "Loops in constrained functions must have bounds known at compile-time. Loops with runtime bounds are allowed in unconstrained functions. If attempting to fetch the length of a slice, try converting to an array. Slices only use dynamic lengths.".to_string(),
                     location.span,
                 )
             }
+            RuntimeError::SlicePaddingLimitExceeded { .. } => {
+                let primary_message = self.to_string();
+                let span = self
+                    .call_stack()
+                    .back()
+                    .map_or(noirc_errors::Span::inclusive(0, 0), |location| location.span);
+
+                Diagnostic::simple_error(
+                    primary_message,
+                    "Slices returned from `if` expressions are padded to the largest length they could have. Consider restructuring this code so the slice is not grown inside of a loop under a condition, or using an array instead.".to_string(),
+                    span,
+                )
+            }
+            RuntimeError::WitnessIndexOverflow { .. } => {
+                let primary_message = self.to_string();
+                let span = self
+                    .call_stack()
+                    .back()
+                    .map_or(noirc_errors::Span::inclusive(0, 0), |location| location.span);
+
+                Diagnostic::simple_error(
+                    primary_message,
+                    format!("Most of this function's constraints are generated here. {CIRCUIT_SIZE_SUGGESTION}"),
+                    span,
+                )
+            }
+            RuntimeError::RecursionLimitExceeded { .. } => {
+                let primary_message = self.to_string();
+                let span = self
+                    .call_stack()
+                    .back()
+                    .map_or(noirc_errors::Span::inclusive(0, 0), |location| location.span);
+
+                Diagnostic::simple_error(
+                    primary_message,
+                    "Constrained functions are inlined into their callers, so recursion must stop after a number of calls known at compile-time. Consider making this function unconstrained.".to_string(),
+                    span,
+                )
+            }
             _ => {
                 let message = self.to_string();
                 let location =
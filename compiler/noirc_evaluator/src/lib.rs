@@ -11,4 +11,7 @@ pub mod ssa;
 
 pub mod brillig;
 
-pub use ssa::create_program;
+pub use ssa::{
+    create_program, SsaLogging, DEFAULT_MAX_FUNCTION_SPECIALIZATIONS, DEFAULT_MAX_SLICE_PADDING,
+    DEFAULT_WITNESS_WARNING_THRESHOLD,
+};
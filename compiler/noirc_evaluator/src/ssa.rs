@@ -24,6 +24,7 @@ use noirc_frontend::ast::Visibility;
 use noirc_frontend::{
     hir_def::{function::FunctionSignature, types::Type as HirType},
     monomorphization::ast::Program,
+    monomorphization::const_eval::{evaluate_constant_calls, DEFAULT_EVALUATION_FUEL},
 };
 use tracing::{span, Level};
 
@@ -38,26 +39,83 @@ pub mod ir;
 mod opt;
 pub mod ssa_gen;
 
+/// The default maximum number of elements which may be used to pad slices merged within a single function.
+pub const DEFAULT_MAX_SLICE_PADDING: usize = 1 << 20;
+
+/// The default number of witnesses in a single ACIR function after which a warning is emitted.
+pub const DEFAULT_WITNESS_WARNING_THRESHOLD: u32 = 1 << 28;
+
+/// The default maximum number of specialized copies made of a single function for its constant arguments.
+pub const DEFAULT_MAX_FUNCTION_SPECIALIZATIONS: usize = 8;
+
+/// Controls which SSA passes are printed to stdout during compilation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SsaLogging {
+    #[default]
+    None,
+    /// Print the SSA after every pass.
+    All,
+    /// Only print the SSA after passes whose name contains the given string (case insensitive).
+    Contains(String),
+}
+
+impl SsaLogging {
+    /// Returns true if the SSA should be printed after the pass with the given message.
+    fn matches(&self, msg: &str) -> bool {
+        match self {
+            SsaLogging::None => false,
+            SsaLogging::All => true,
+            SsaLogging::Contains(pass_name) => {
+                msg.to_lowercase().contains(&pass_name.to_lowercase())
+            }
+        }
+    }
+}
+
 /// Optimize the given program by converting it into SSA
 /// form and performing optimizations there. When finished,
 /// convert the final SSA into an ACIR program and return it.
 /// An ACIR program is made up of both ACIR functions
 /// and Brillig functions for unconstrained execution.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn optimize_into_acir(
     program: Program,
-    print_passes: bool,
+    ssa_logging: SsaLogging,
     print_brillig_trace: bool,
     force_brillig_output: bool,
     print_timings: bool,
+    max_slice_padding: usize,
+    witness_warning_threshold: u32,
+    specialize_any_literal: bool,
+    max_function_specializations: usize,
+    warn_redundant_constraints: bool,
+    remove_redundant_constraints: bool,
+    lint_soundness: bool,
+    full_debug: bool,
+    expression_width: ExpressionWidth,
 ) -> Result<Artifacts, RuntimeError> {
+    let mut ssa_warnings = Vec::new();
+    let public_parameters =
+        if lint_soundness { ssa_gen::public_parameter_names(&program) } else { Vec::new() };
     let ssa_gen_span = span!(Level::TRACE, "ssa_generation");
     let ssa_gen_span_guard = ssa_gen_span.enter();
-    let ssa = SsaBuilder::new(program, print_passes, force_brillig_output, print_timings)?
+    let builder =
+        SsaBuilder::new(program, ssa_logging, force_brillig_output, print_timings, full_debug)?;
+    let ssa = builder
         .run_pass(Ssa::defunctionalize, "After Defunctionalization:")
         .run_pass(Ssa::remove_paired_rc, "After Removing Paired rc_inc & rc_decs:")
-        .run_pass(Ssa::inline_functions, "After Inlining:")
+        .try_run_pass(Ssa::inline_functions, "After Inlining:")?
         // Run mem2reg with the CFG separated into blocks
         .run_pass(Ssa::mem2reg, "After Mem2Reg:")
+        .run_pass(
+            |ssa| {
+                ssa.specialize_literal_arguments(
+                    specialize_any_literal,
+                    max_function_specializations,
+                )
+            },
+            "After Specializing Literal Arguments:",
+        )
         .run_pass(Ssa::as_slice_optimization, "After `as_slice` optimization")
         .try_run_pass(Ssa::evaluate_assert_constant, "After Assert Constant:")?
         .try_run_pass(Ssa::unroll_loops_iteratively, "After Unrolling:")?
@@ -70,11 +128,31 @@ pub(crate) fn optimize_into_acir(
         // Before flattening is run, we treat functions marked with the `InlineType::NoPredicates` as an entry point.
         // This pass must come immediately following `mem2reg` as the succeeding passes
         // may create an SSA which inlining fails to handle.
-        .run_pass(Ssa::inline_functions_with_no_predicates, "After Inlining:")
-        .run_pass(Ssa::remove_if_else, "After Remove IfElse:")
+        .try_run_pass(Ssa::inline_functions_with_no_predicates, "After Inlining:")?
+        .try_run_pass(|ssa| ssa.remove_if_else(max_slice_padding), "After Remove IfElse:")?
         .run_pass(Ssa::fold_constants, "After Constant Folding:")
         .run_pass(Ssa::remove_enable_side_effects, "After EnableSideEffects removal:")
         .run_pass(Ssa::fold_constants_using_constraints, "After Constraint Folding:")
+        .run_pass(
+            |ssa| {
+                ssa.check_redundant_constraints(
+                    warn_redundant_constraints,
+                    remove_redundant_constraints,
+                    &mut ssa_warnings,
+                )
+            },
+            "After Redundant Constraint Removal:",
+        )
+        .run_pass(
+            |ssa| {
+                if lint_soundness {
+                    ssa.check_public_divisors(&public_parameters, &mut ssa_warnings)
+                } else {
+                    ssa
+                }
+            },
+            "After Checking Public Divisors:",
+        )
         .run_pass(Ssa::dead_instruction_elimination, "After Dead Instruction Elimination:")
         .run_pass(Ssa::array_set_optimization, "After Array Set Optimizations:")
         .finish();
@@ -83,7 +161,15 @@ pub(crate) fn optimize_into_acir(
 
     drop(ssa_gen_span_guard);
 
-    time("SSA to ACIR", print_timings, || ssa.into_acir(&brillig))
+    let (mut generated_acirs, generated_brillig, error_types) =
+        time("SSA to ACIR", print_timings, || {
+            ssa.into_acir(&brillig, expression_width, witness_warning_threshold)
+        })?;
+    // The program's warnings are collected from all of its circuits, so these are attached to `main`.
+    if let Some(main) = generated_acirs.first_mut() {
+        main.warnings.extend(ssa_warnings);
+    }
+    Ok((generated_acirs, generated_brillig, error_types))
 }
 
 // Helper to time SSA passes
@@ -144,13 +230,28 @@ impl SsaProgramArtifact {
 /// The output ACIR is is backend-agnostic and so must go through a transformation pass before usage in proof generation.
 #[allow(clippy::type_complexity)]
 #[tracing::instrument(level = "trace", skip_all)]
+#[allow(clippy::too_many_arguments)]
 pub fn create_program(
-    program: Program,
-    enable_ssa_logging: bool,
+    mut program: Program,
+    ssa_logging: SsaLogging,
     enable_brillig_logging: bool,
     force_brillig_output: bool,
     print_codegen_timings: bool,
+    max_slice_padding: usize,
+    witness_warning_threshold: u32,
+    specialize_any_literal: bool,
+    max_function_specializations: usize,
+    warn_redundant_constraints: bool,
+    remove_redundant_constraints: bool,
+    lint_soundness: bool,
+    full_debug: bool,
+    expression_width: ExpressionWidth,
 ) -> Result<SsaProgramArtifact, RuntimeError> {
+    // This may leave circuits uncalled, so it is done before their signatures are collected.
+    time("Evaluating Constant Calls", print_codegen_timings, || {
+        evaluate_constant_calls(&mut program, DEFAULT_EVALUATION_FUEL);
+    });
+
     let debug_variables = program.debug_variables.clone();
     let debug_types = program.debug_types.clone();
     let debug_functions = program.debug_functions.clone();
@@ -160,10 +261,19 @@ pub fn create_program(
     let recursive = program.recursive;
     let (generated_acirs, generated_brillig, error_types) = optimize_into_acir(
         program,
-        enable_ssa_logging,
+        ssa_logging,
         enable_brillig_logging,
         force_brillig_output,
         print_codegen_timings,
+        max_slice_padding,
+        witness_warning_threshold,
+        specialize_any_literal,
+        max_function_specializations,
+        warn_redundant_constraints,
+        remove_redundant_constraints,
+        lint_soundness,
+        full_debug,
+        expression_width,
     )?;
     assert_eq!(
         generated_acirs.len(),
@@ -213,6 +323,7 @@ fn convert_generated_acir_into_circuit(
     let GeneratedAcir {
         return_witnesses,
         locations,
+        provenance_index,
         input_witnesses,
         assertion_payloads: assert_messages,
         warnings,
@@ -244,6 +355,7 @@ fn convert_generated_acir_into_circuit(
         .collect();
 
     let mut debug_info = DebugInfo::new(locations, debug_variables, debug_functions, debug_types);
+    debug_info.provenance_index = provenance_index;
 
     // Perform any ACIR-level optimizations
     let (optimized_circuit, transformation_map) = acvm::compiler::optimize(circuit);
@@ -293,30 +405,31 @@ fn split_public_and_private_inputs(
         })
 }
 
-// This is just a convenience object to bundle the ssa with `print_ssa_passes` for debug printing.
+// This is just a convenience object to bundle the ssa with `ssa_logging` for debug printing.
 struct SsaBuilder {
     ssa: Ssa,
-    print_ssa_passes: bool,
+    ssa_logging: SsaLogging,
     print_codegen_timings: bool,
 }
 
 impl SsaBuilder {
     fn new(
         program: Program,
-        print_ssa_passes: bool,
+        ssa_logging: SsaLogging,
         force_brillig_runtime: bool,
         print_codegen_timings: bool,
+        full_debug: bool,
     ) -> Result<SsaBuilder, RuntimeError> {
-        let ssa = ssa_gen::generate_ssa(program, force_brillig_runtime)?;
-        Ok(SsaBuilder { print_ssa_passes, print_codegen_timings, ssa }.print("Initial SSA:"))
+        let ssa = ssa_gen::generate_ssa(program, force_brillig_runtime, full_debug)?;
+        Ok(SsaBuilder { ssa_logging, print_codegen_timings, ssa }.print("Initial SSA:"))
     }
 
     fn finish(self) -> Ssa {
         self.ssa
     }
 
-    /// Runs the given SSA pass and prints the SSA afterward if `print_ssa_passes` is true.
-    fn run_pass(mut self, pass: fn(Ssa) -> Ssa, msg: &str) -> Self {
+    /// Runs the given SSA pass and prints the SSA afterward if `ssa_logging` matches the pass.
+    fn run_pass(mut self, pass: impl FnOnce(Ssa) -> Ssa, msg: &str) -> Self {
         self.ssa = time(msg, self.print_codegen_timings, || pass(self.ssa));
         self.print(msg)
     }
@@ -324,7 +437,7 @@ impl SsaBuilder {
     /// The same as `run_pass` but for passes that may fail
     fn try_run_pass(
         mut self,
-        pass: fn(Ssa) -> Result<Ssa, RuntimeError>,
+        pass: impl FnOnce(Ssa) -> Result<Ssa, RuntimeError>,
         msg: &str,
     ) -> Result<Self, RuntimeError> {
         self.ssa = time(msg, self.print_codegen_timings, || pass(self.ssa))?;
@@ -332,9 +445,90 @@ impl SsaBuilder {
     }
 
     fn print(self, msg: &str) -> Self {
-        if self.print_ssa_passes {
+        if self.ssa_logging.matches(msg) {
             println!("{msg}\n{}", self.ssa);
         }
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use acvm::acir::{circuit::ExpressionWidth, native_types::Witness};
+    use noirc_frontend::monomorphization::text::parse_program;
+
+    use super::{
+        create_program, SsaLogging, DEFAULT_MAX_FUNCTION_SPECIALIZATIONS,
+        DEFAULT_MAX_SLICE_PADDING, DEFAULT_WITNESS_WARNING_THRESHOLD,
+    };
+
+    #[test]
+    fn ssa_logging_matches_pass_names() {
+        assert!(!SsaLogging::None.matches("After Inlining:"));
+        assert!(SsaLogging::All.matches("After Inlining:"));
+
+        let logging = SsaLogging::Contains("inlining".to_string());
+        assert!(logging.matches("After Inlining:"));
+        assert!(!logging.matches("After Mem2Reg:"));
+    }
+
+    #[test]
+    fn textual_program_signature_determines_public_inputs() {
+        let program =
+            parse_program(include_str!("ssa/ssa_gen/test_data/distinct_sum.mono")).unwrap();
+        let artifact = create_program(
+            program,
+            SsaLogging::None,
+            false,
+            false,
+            false,
+            DEFAULT_MAX_SLICE_PADDING,
+            DEFAULT_WITNESS_WARNING_THRESHOLD,
+            false,
+            DEFAULT_MAX_FUNCTION_SPECIALIZATIONS,
+            false,
+            false,
+            false,
+            false,
+            ExpressionWidth::default(),
+        )
+        .unwrap();
+
+        // Only `y` is marked as public in the signature of `main`.
+        let main = &artifact.program.functions[0];
+        assert_eq!(main.public_parameters.0.iter().collect::<Vec<_>>(), vec![&Witness(1)]);
+        assert_eq!(artifact.main_input_witnesses, vec![Witness(0), Witness(1)]);
+    }
+
+    #[test]
+    fn return_witnesses_are_separate_from_public_parameters() {
+        let program =
+            parse_program(include_str!("ssa/ssa_gen/test_data/distinct_sum.mono")).unwrap();
+        let artifact = create_program(
+            program,
+            SsaLogging::None,
+            false,
+            false,
+            false,
+            DEFAULT_MAX_SLICE_PADDING,
+            DEFAULT_WITNESS_WARNING_THRESHOLD,
+            false,
+            DEFAULT_MAX_FUNCTION_SPECIALIZATIONS,
+            false,
+            false,
+            false,
+            false,
+            ExpressionWidth::default(),
+        )
+        .unwrap();
+
+        // The sum is returned through a fresh witness, even though it is public like `y`.
+        let main = &artifact.program.functions[0];
+        let return_values: Vec<_> = main.return_values.0.iter().copied().collect();
+        assert_eq!(return_values, artifact.main_return_witnesses);
+        assert_eq!(return_values.len(), 1);
+        assert!(!main.public_parameters.0.contains(&return_values[0]));
+        assert!(!artifact.main_input_witnesses.contains(&return_values[0]));
+        assert_eq!(main.public_inputs().0.len(), 2);
+    }
+}
@@ -9,7 +9,7 @@ use crate::ssa::ir::types::Type as SsaType;
 use crate::ssa::ir::{instruction::Endian, types::NumericType};
 use acvm::acir::circuit::brillig::{BrilligInputs, BrilligOutputs};
 use acvm::acir::circuit::opcodes::{BlockId, MemOp};
-use acvm::acir::circuit::{AssertionPayload, ExpressionOrMemory, Opcode};
+use acvm::acir::circuit::{AssertionPayload, ExpressionOrMemory, ExpressionWidth, Opcode};
 use acvm::blackbox_solver;
 use acvm::brillig_vm::{MemoryValue, VMStatus, VM};
 use acvm::{
@@ -23,9 +23,13 @@ use acvm::{
 };
 use fxhash::FxHashMap as HashMap;
 use iter_extended::{try_vecmap, vecmap};
+use noirc_errors::debug_info::SsaInstructionProvenance;
 use num_bigint::BigUint;
 use std::{borrow::Cow, hash::Hash};
 
+/// The bit size of the limbs which integers are split into when their product may exceed the field modulus.
+pub(crate) const PRODUCT_LIMB_BITS: u32 = 64;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 /// High level Type descriptor for Variables.
 ///
@@ -124,13 +128,36 @@ pub(crate) struct AcirContext {
 
     /// The BigIntContext, used to generate identifiers for BigIntegers
     big_int_ctx: BigIntContext,
+
+    /// The width of the arithmetic expressions supported by the backend.
+    ///
+    /// Sums which would not fit in a single expression of this width are split up as they are
+    /// built, so that they don't need to be split by the ACVM compiler afterwards.
+    expression_width: ExpressionWidth,
 }
 
 impl AcirContext {
+    pub(crate) fn set_expression_width(&mut self, expression_width: ExpressionWidth) {
+        self.expression_width = expression_width;
+    }
+
     pub(crate) fn current_witness_index(&self) -> Witness {
         self.acir_ir.current_witness_index()
     }
 
+    pub(crate) fn witness_index_overflowed(&self) -> bool {
+        self.acir_ir.witness_index_overflowed()
+    }
+
+    pub(crate) fn largest_call_site(&self) -> CallStack {
+        self.acir_ir.largest_call_site()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_current_witness_index(&mut self, index: u32) {
+        self.acir_ir.set_current_witness_index(index);
+    }
+
     pub(crate) fn extract_witness(&self, inputs: &[AcirValue]) -> Vec<Witness> {
         inputs
             .iter()
@@ -236,6 +263,14 @@ impl AcirContext {
         self.acir_ir.call_stack = call_stack;
     }
 
+    /// Sets the SSA instruction which generated any opcodes added from now on, see `--full-debug`.
+    pub(crate) fn set_current_instruction(
+        &mut self,
+        instruction: Option<SsaInstructionProvenance>,
+    ) {
+        self.acir_ir.current_instruction = instruction;
+    }
+
     pub(crate) fn get_or_create_witness_var(
         &mut self,
         var: AcirVar,
@@ -640,8 +675,37 @@ impl AcirContext {
         let lhs_expr = self.var_to_expression(lhs)?;
         let rhs_expr = self.var_to_expression(rhs)?;
         let sum_expr = &lhs_expr + &rhs_expr;
+        if self.fits_in_one_identity(&sum_expr) {
+            return Ok(self.add_data(AcirVarData::from(sum_expr)));
+        }
 
-        Ok(self.add_data(AcirVarData::from(sum_expr)))
+        // Replacing the operand with the most terms by a witness frees up the most room,
+        // so only the other operand is replaced as well if the sum still doesn't fit.
+        let (wider, narrower) =
+            if lhs_expr.linear_combinations.len() >= rhs_expr.linear_combinations.len() {
+                (lhs, rhs)
+            } else {
+                (rhs, lhs)
+            };
+        let wider = self.get_or_create_witness_var(wider)?;
+        let wider_expr = self.var_to_expression(wider)?;
+        let narrower_expr = self.var_to_expression(narrower)?;
+        let sum_expr = &wider_expr + &narrower_expr;
+        if self.fits_in_one_identity(&sum_expr) {
+            return Ok(self.add_data(AcirVarData::from(sum_expr)));
+        }
+
+        let narrower = self.get_or_create_witness_var(narrower)?;
+        let narrower_expr = self.var_to_expression(narrower)?;
+        Ok(self.add_data(AcirVarData::from(&wider_expr + &narrower_expr)))
+    }
+
+    /// Returns true if the expression can be turned into a single opcode of the backend's width.
+    fn fits_in_one_identity(&self, expression: &Expression) -> bool {
+        match self.expression_width {
+            ExpressionWidth::Unbounded => true,
+            ExpressionWidth::Bounded { width } => expression.fits_in_one_identity(width),
+        }
     }
 
     /// Adds a new Variable to context whose value will
@@ -662,7 +726,7 @@ impl AcirContext {
     pub(crate) fn not_var(&mut self, x: AcirVar, typ: AcirType) -> Result<AcirVar, RuntimeError> {
         let bit_size = typ.bit_size();
         // Subtracting from max flips the bits
-        let max = self.add_constant((1_u128 << bit_size) - 1);
+        let max = self.add_constant(u128::MAX.checked_shr(128 - bit_size).unwrap_or(0));
         self.sub_var(max, x)
     }
 
@@ -703,6 +767,16 @@ impl AcirContext {
                 return Ok((lhs, zero));
             }
 
+            // If `rhs` is a power of two then the division is a decomposition of `lhs` into high and low bits.
+            (_, Some(rhs_const), _)
+                if rhs_const.num_bits() <= bit_size
+                    && bit_size < FieldElement::max_num_bits()
+                    && is_power_of_two(rhs_const) =>
+            {
+                let rhs_bits = rhs_const.num_bits() - 1;
+                return self.euclidean_division_by_power_of_two(lhs, rhs_bits, bit_size, predicate);
+            }
+
             // After this point, we cannot perform the division at compile-time.
             //
             // We need to check that the rhs is not zero, otherwise when executing the brillig quotient,
@@ -798,6 +872,9 @@ impl AcirContext {
             // q*b+r can overflow; we avoid this when b is constant
             if rhs_expr.is_const() {
                 avoid_overflow = true;
+            } else if bit_size <= 2 * PRODUCT_LIMB_BITS {
+                // Otherwise `b*q <= a` must fit into `bit_size` bits, so that `q*b+r` can't wrap around.
+                self.range_constrain_limb_product(rhs, quotient_var, bit_size, predicate, None)?;
             } else {
                 // we do not support unbounded division
                 unreachable!("overflow in unbounded division");
@@ -835,6 +912,117 @@ impl AcirContext {
         Ok((quotient_var, remainder_var))
     }
 
+    /// Performs euclidean division of `lhs` by `2^rhs_bits`, returning the quotient and remainder.
+    ///
+    /// As the divisor is a power of two, the remainder being less than the divisor is implied by its
+    /// range constraint. As `bit_size` is less than the field's bit size, `q * 2^rhs_bits + r` cannot overflow.
+    /// This avoids the bound constraint and overflow checks required by the general case.
+    fn euclidean_division_by_power_of_two(
+        &mut self,
+        lhs: AcirVar,
+        rhs_bits: u32,
+        bit_size: u32,
+        predicate: AcirVar,
+    ) -> Result<(AcirVar, AcirVar), RuntimeError> {
+        let divisor = FieldElement::from(2_u128).pow(&FieldElement::from(rhs_bits as u128));
+        let rhs = self.add_constant(divisor);
+        let max_q_bits = bit_size - rhs_bits;
+
+        let [q_value, r_value]: [AcirValue; 2] = self
+            .brillig_call(
+                predicate,
+                &brillig_directive::directive_quotient(bit_size + 1),
+                vec![
+                    AcirValue::Var(lhs, AcirType::unsigned(bit_size)),
+                    AcirValue::Var(rhs, AcirType::unsigned(bit_size)),
+                ],
+                vec![AcirType::unsigned(max_q_bits), AcirType::unsigned(rhs_bits)],
+                true,
+                false,
+                PLACEHOLDER_BRILLIG_INDEX,
+                Some(BrilligStdlibFunc::Quotient(bit_size + 1)),
+            )?
+            .try_into()
+            .expect("quotient only returns two values");
+        let quotient_var = q_value.into_var()?;
+        let remainder_var = r_value.into_var()?;
+
+        // Constrain `q < 2^{bit_size - rhs_bits}` and `r < 2^{rhs_bits}`.
+        self.range_constrain_var(
+            quotient_var,
+            &NumericType::Unsigned { bit_size: max_q_bits },
+            None,
+        )?;
+        self.range_constrain_var(
+            remainder_var,
+            &NumericType::Unsigned { bit_size: rhs_bits },
+            None,
+        )?;
+
+        // predicate * (lhs - (2^{rhs_bits} * q + r)) == 0
+        let rhs_constraint = self.mul_var(rhs, quotient_var)?;
+        let rhs_constraint = self.add_var(rhs_constraint, remainder_var)?;
+        let rhs_constraint = self.mul_var(rhs_constraint, predicate)?;
+
+        let lhs_constraint = self.mul_var(lhs, predicate)?;
+        self.assert_eq_var(lhs_constraint, rhs_constraint, None)?;
+
+        Ok((quotient_var, remainder_var))
+    }
+
+    /// Constrains the product of `lhs` and `rhs` to fit into `bit_size` bits when `predicate` is active,
+    /// where both operands are integers of up to 128 bits.
+    ///
+    /// Such a product can exceed the field modulus and wrap around, so it can't be range constrained directly.
+    /// Instead both operands are split into 64-bit limbs, `lhs = lhs_hi * 2^64 + lhs_lo`, so that the product is
+    /// `lhs_hi * rhs_hi * 2^128 + (lhs_hi * rhs_lo + lhs_lo * rhs_hi) * 2^64 + lhs_lo * rhs_lo`.
+    /// This fits into `bit_size <= 128` bits only if `lhs_hi * rhs_hi` is zero, in which case the remaining terms
+    /// are less than 2^194 and can be range constrained without wrapping around.
+    pub(crate) fn range_constrain_limb_product(
+        &mut self,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        bit_size: u32,
+        predicate: AcirVar,
+        message: Option<String>,
+    ) -> Result<(), RuntimeError> {
+        let operand_bits = 2 * PRODUCT_LIMB_BITS;
+        let (lhs_hi, lhs_lo) = self.euclidean_division_by_power_of_two(
+            lhs,
+            PRODUCT_LIMB_BITS,
+            operand_bits,
+            predicate,
+        )?;
+        let (rhs_hi, rhs_lo) = self.euclidean_division_by_power_of_two(
+            rhs,
+            PRODUCT_LIMB_BITS,
+            operand_bits,
+            predicate,
+        )?;
+
+        // predicate * lhs_hi * rhs_hi == 0
+        let zero = self.add_constant(FieldElement::zero());
+        let high_product = self.mul_var(lhs_hi, rhs_hi)?;
+        let high_product = self.mul_var(high_product, predicate)?;
+        self.assert_eq_var(
+            high_product,
+            zero,
+            message.clone().map(AssertionPayload::StaticString),
+        )?;
+
+        // predicate * ((lhs_hi * rhs_lo + lhs_lo * rhs_hi) * 2^64 + lhs_lo * rhs_lo) < 2^bit_size
+        let limb_base = self.add_constant(1_u128 << PRODUCT_LIMB_BITS);
+        let cross_product = self.mul_var(lhs_hi, rhs_lo)?;
+        let other_cross_product = self.mul_var(lhs_lo, rhs_hi)?;
+        let cross_product = self.add_var(cross_product, other_cross_product)?;
+        let cross_product = self.mul_var(cross_product, limb_base)?;
+        let low_product = self.mul_var(lhs_lo, rhs_lo)?;
+        let product = self.add_var(cross_product, low_product)?;
+        let product = self.mul_var(product, predicate)?;
+        self.range_constrain_var(product, &NumericType::Unsigned { bit_size }, message)?;
+        Ok(())
+    }
+
     /// Generate constraints that are satisfied iff
     /// lhs < rhs , when offset is 1, or
     /// lhs <= rhs, when offset is 0
@@ -882,7 +1070,7 @@ impl AcirContext {
 
             let bit_size = bit_size_u128(rhs_offset);
             // r = 2^bit_size - rhs_offset -1, is of bit size  'bit_size' by construction
-            let r = (1_u128 << bit_size) - rhs_offset - 1;
+            let r = u128::MAX.checked_shr(128 - bit_size).unwrap_or(0) - rhs_offset;
             // however, since it is a constant, we can compute it's actual bit size
             let r_bit_size = bit_size_u128(r);
             // witness = lhs_offset + r
@@ -1346,7 +1534,7 @@ impl AcirContext {
         };
 
         // Convert `AcirVar` to `FunctionInput`
-        let inputs = self.prepare_inputs_for_black_box_func_call(inputs)?;
+        let inputs = self.prepare_inputs_for_black_box_func_call(name, inputs)?;
         // Call Black box with `FunctionInput`
         let mut results = vecmap(&constant_outputs, |c| self.add_constant(*c));
         let outputs = self.acir_ir.call_black_box(
@@ -1371,21 +1559,54 @@ impl AcirContext {
     /// Black box function calls expect their inputs to be in a specific data structure (FunctionInput).
     ///
     /// This function will convert `AcirVar` into `FunctionInput` for a blackbox function call.
+    ///
+    /// The bit size of each input is taken from its own type. Inputs which the black box function
+    /// requires to be narrower than a field (e.g. bytes) must fit in that size: wider integers are
+    /// rejected, while fields are range constrained to it so that the call is not under-constrained.
     fn prepare_inputs_for_black_box_func_call(
         &mut self,
+        name: BlackBoxFunc,
         inputs: Vec<AcirValue>,
     ) -> Result<Vec<Vec<FunctionInput>>, RuntimeError> {
         let mut witnesses = Vec::new();
-        for input in inputs {
+        for (input_index, input) in inputs.into_iter().enumerate() {
+            let expected_bit_size = black_box_input_bit_size(name, input_index);
             let mut single_val_witnesses = Vec::new();
-            for (input, typ) in self.flatten(input)? {
-                // Intrinsics only accept Witnesses. This is not a limitation of the
-                // intrinsics, its just how we have defined things. Ideally, we allow
-                // constants too.
-                let witness_var = self.get_or_create_witness_var(input)?;
-                let witness = self.var_to_witness(witness_var)?;
-                let num_bits = typ.bit_size();
-                single_val_witnesses.push(FunctionInput { witness, num_bits });
+            for (mut input, typ) in self.flatten(input)? {
+                let mut num_bits = typ.bit_size();
+                if let Some(expected_bit_size) = expected_bit_size {
+                    match typ {
+                        AcirType::NumericType(NumericType::NativeField) => {
+                            let numeric_type =
+                                NumericType::Unsigned { bit_size: expected_bit_size };
+                            input = self.range_constrain_var(input, &numeric_type, None)?;
+                            num_bits = expected_bit_size;
+                        }
+                        _ if num_bits > expected_bit_size => {
+                            return Err(RuntimeError::BlackBoxInputTooWide {
+                                function: name.name().to_string(),
+                                expected_bit_size,
+                                bit_size: num_bits,
+                                call_stack: self.get_call_stack(),
+                            });
+                        }
+                        _ => (),
+                    }
+                }
+
+                // Constants are passed to the black box function directly rather than
+                // being assigned to a witness, as long as they fit in the input's bit size.
+                let constant_input = self.vars[&input]
+                    .as_constant()
+                    .and_then(|constant| FunctionInput::constant(constant, num_bits).ok());
+                let function_input = match constant_input {
+                    Some(function_input) => function_input,
+                    None => {
+                        let witness_var = self.get_or_create_witness_var(input)?;
+                        FunctionInput::witness(self.var_to_witness(witness_var)?, num_bits)
+                    }
+                };
+                single_val_witnesses.push(function_input);
             }
             witnesses.push(single_val_witnesses);
         }
@@ -1824,11 +2045,14 @@ impl AcirContext {
         output_count: usize,
         predicate: AcirVar,
     ) -> Result<Vec<AcirVar>, RuntimeError> {
-        let inputs = self.prepare_inputs_for_black_box_func_call(inputs)?;
-        let inputs = inputs
-            .iter()
-            .flat_map(|input| vecmap(input, |input| input.witness))
-            .collect::<Vec<_>>();
+        let mut witnesses = Vec::new();
+        for input in inputs {
+            for (input, _) in self.flatten(input)? {
+                let witness_var = self.get_or_create_witness_var(input)?;
+                witnesses.push(self.var_to_witness(witness_var)?);
+            }
+        }
+        let inputs = witnesses;
         let outputs = vecmap(0..output_count, |_| self.acir_ir.next_witness_index());
 
         // Convert `Witness` values which are now constrained to be the output of the
@@ -1977,3 +2201,25 @@ fn execute_brillig(code: &[BrilligOpcode], inputs: &[BrilligInputs]) -> Option<V
         }
     }
 }
+
+/// Returns true if `value` is of the form `2^n`.
+fn is_power_of_two(value: FieldElement) -> bool {
+    let num_bits = value.num_bits();
+    num_bits != 0
+        && value == FieldElement::from(2_u128).pow(&FieldElement::from((num_bits - 1) as u128))
+}
+
+/// Returns the bit size which each element of the given input of a black box function must fit in,
+/// or `None` if the input may be any field element.
+fn black_box_input_bit_size(name: BlackBoxFunc, input_index: usize) -> Option<u32> {
+    match (name, input_index) {
+        (BlackBoxFunc::AES128Encrypt, _)
+        | (BlackBoxFunc::EcdsaSecp256k1 | BlackBoxFunc::EcdsaSecp256r1, _)
+        | (BlackBoxFunc::SHA256 | BlackBoxFunc::Blake2s | BlackBoxFunc::Blake3, 0)
+        | (BlackBoxFunc::Keccak256, 0)
+        | (BlackBoxFunc::SchnorrVerify, 2 | 3) => Some(8),
+        (BlackBoxFunc::Sha256Compression, _) => Some(32),
+        (BlackBoxFunc::Keccakf1600, _) => Some(64),
+        _ => None,
+    }
+}
@@ -20,7 +20,12 @@ use acvm::{
     acir::{circuit::directives::Directive, native_types::Expression},
     FieldElement,
 };
+use fxhash::FxHashMap as HashMap;
 use iter_extended::vecmap;
+use noirc_errors::{
+    debug_info::{ProvenanceIndex, SsaInstructionProvenance},
+    Location,
+};
 use num_bigint::BigUint;
 
 /// Brillig calls such as for the Brillig std lib are resolved only after code generation is finished.
@@ -40,6 +45,10 @@ pub(crate) struct GeneratedAcir {
     /// Equivalent to acvm::acir::circuit::Circuit's field of the same name.
     current_witness_index: Option<u32>,
 
+    /// Set once more witnesses have been requested than can be indexed by a `u32`.
+    /// The witness index then stays at `u32::MAX` and code generation is expected to abort.
+    witness_index_overflowed: bool,
+
     /// The opcodes of which the compiled ACIR will comprise.
     opcodes: Vec<AcirOpcode>,
 
@@ -59,6 +68,13 @@ pub(crate) struct GeneratedAcir {
     /// None if we do not know the location
     pub(crate) call_stack: CallStack,
 
+    /// Correspondence between an opcode index and the SSA instruction which generated it.
+    /// Only recorded when compiling with `--full-debug`.
+    pub(crate) provenance_index: Option<ProvenanceIndex>,
+
+    /// The SSA instruction currently being processed, if its provenance is being recorded.
+    pub(crate) current_instruction: Option<SsaInstructionProvenance>,
+
     /// Correspondence between an opcode index and the error message associated with it.
     pub(crate) assertion_payloads: BTreeMap<OpcodeLocation, AssertionPayload>,
 
@@ -104,6 +120,11 @@ impl GeneratedAcir {
         if !self.call_stack.is_empty() {
             self.locations.insert(self.last_acir_opcode_location(), self.call_stack.clone());
         }
+        if let Some(instruction) = &self.current_instruction {
+            let opcode_location = self.last_acir_opcode_location();
+            let provenance_index = self.provenance_index.get_or_insert_with(Default::default);
+            provenance_index.opcodes.insert(opcode_location, instruction.clone());
+        }
     }
 
     pub(crate) fn opcodes(&self) -> &[AcirOpcode] {
@@ -116,13 +137,44 @@ impl GeneratedAcir {
 
     /// Updates the witness index counter and returns
     /// the next witness index.
+    ///
+    /// Rather than wrapping around once the witness index space is exhausted, this records the
+    /// overflow so that it can be reported through [`GeneratedAcir::witness_index_overflowed`].
     pub(crate) fn next_witness_index(&mut self) -> Witness {
-        if let Some(current_index) = self.current_witness_index {
-            self.current_witness_index.replace(current_index + 1);
-        } else {
-            self.current_witness_index = Some(0);
+        let next_index = match self.current_witness_index {
+            Some(current_index) => current_index.checked_add(1).unwrap_or_else(|| {
+                self.witness_index_overflowed = true;
+                u32::MAX
+            }),
+            None => 0,
+        };
+        self.current_witness_index = Some(next_index);
+        Witness(next_index)
+    }
+
+    /// Returns true if more witnesses have been requested than can be indexed.
+    pub(crate) fn witness_index_overflowed(&self) -> bool {
+        self.witness_index_overflowed
+    }
+
+    #[cfg(test)]
+    pub(crate) fn set_current_witness_index(&mut self, index: u32) {
+        self.current_witness_index = Some(index);
+    }
+
+    /// Returns the call stack of the outermost source location from which the most opcodes were generated.
+    ///
+    /// This is used to point users at the code responsible for the bulk of a circuit's size.
+    pub(crate) fn largest_call_site(&self) -> CallStack {
+        let mut opcode_counts: HashMap<Location, usize> = HashMap::default();
+        for location in self.locations.values().filter_map(|call_stack| call_stack.front()) {
+            *opcode_counts.entry(*location).or_default() += 1;
         }
-        Witness(self.current_witness_index.expect("ICE: current_witness_index should exist"))
+        opcode_counts
+            .into_iter()
+            .max_by_key(|(location, count)| (*count, std::cmp::Reverse(location.span.start())))
+            .map(|(location, _)| CallStack::unit(location))
+            .unwrap_or_default()
     }
 
     /// Converts [`Expression`] `expr` into a [`Witness`].
@@ -580,7 +632,7 @@ impl GeneratedAcir {
         };
 
         let constraint = AcirOpcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
-            input: FunctionInput { witness, num_bits },
+            input: FunctionInput::witness(witness, num_bits),
         });
         self.push_opcode(constraint);
 
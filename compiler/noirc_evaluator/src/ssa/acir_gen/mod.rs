@@ -4,7 +4,7 @@ mod acir_ir;
 use std::collections::{BTreeMap, HashSet};
 use std::fmt::Debug;
 
-use self::acir_ir::acir_variable::{AcirContext, AcirType, AcirVar};
+use self::acir_ir::acir_variable::{AcirContext, AcirType, AcirVar, PRODUCT_LIMB_BITS};
 use self::acir_ir::generated_acir::BrilligStdlibFunc;
 use super::function_builder::data_bus::DataBus;
 use super::ir::dfg::CallStack;
@@ -32,7 +32,7 @@ pub(crate) use acir_ir::generated_acir::GeneratedAcir;
 use noirc_frontend::monomorphization::ast::InlineType;
 
 use acvm::acir::circuit::brillig::BrilligBytecode;
-use acvm::acir::circuit::{AssertionPayload, ErrorSelector, OpcodeLocation};
+use acvm::acir::circuit::{AssertionPayload, ErrorSelector, ExpressionWidth, OpcodeLocation};
 use acvm::acir::native_types::Witness;
 use acvm::acir::BlackBoxFunc;
 use acvm::{
@@ -190,6 +190,12 @@ struct Context<'a> {
 
     data_bus: DataBus,
 
+    /// The number of witnesses after which a warning is emitted about the size of the circuit.
+    witness_warning_threshold: u32,
+
+    /// Whether the warning for exceeding `witness_warning_threshold` has already been emitted.
+    witness_warning_emitted: bool,
+
     /// Contains state that is generated and also used across ACIR functions
     shared_context: &'a mut SharedContext,
 }
@@ -281,12 +287,18 @@ pub(crate) type Artifacts =
 
 impl Ssa {
     #[tracing::instrument(level = "trace", skip_all)]
-    pub(crate) fn into_acir(self, brillig: &Brillig) -> Result<Artifacts, RuntimeError> {
+    pub(crate) fn into_acir(
+        self,
+        brillig: &Brillig,
+        expression_width: ExpressionWidth,
+        witness_warning_threshold: u32,
+    ) -> Result<Artifacts, RuntimeError> {
         let mut acirs = Vec::new();
         // TODO: can we parallelise this?
         let mut shared_context = SharedContext::default();
         for function in self.functions.values() {
-            let context = Context::new(&mut shared_context);
+            let context =
+                Context::new(&mut shared_context, expression_width, witness_warning_threshold);
             if let Some(mut generated_acir) =
                 context.convert_ssa_function(&self, function, brillig)?
             {
@@ -319,6 +331,12 @@ impl Ssa {
                     }
                 }
 
+                if let Some(expression_provenance) = &self.expression_provenance {
+                    let provenance_index =
+                        generated_acir.provenance_index.get_or_insert_with(Default::default);
+                    expression_provenance.add_expressions_to(provenance_index);
+                }
+
                 generated_acir.name = function.name().to_owned();
                 acirs.push(generated_acir);
             }
@@ -333,6 +351,12 @@ impl Ssa {
             if matches!(runtime_type, RuntimeType::Acir(_)) {
                 generate_distinct_return_witnesses(acir);
             }
+            if acir.witness_index_overflowed() {
+                return Err(RuntimeError::WitnessIndexOverflow {
+                    function: acir.name.clone(),
+                    call_stack: acir.largest_call_site(),
+                });
+            }
         }
 
         Ok((acirs, brillig, self.error_selector_to_type))
@@ -361,8 +385,13 @@ fn generate_distinct_return_witnesses(acir: &mut GeneratedAcir) {
 }
 
 impl<'a> Context<'a> {
-    fn new(shared_context: &'a mut SharedContext) -> Context<'a> {
+    fn new(
+        shared_context: &'a mut SharedContext,
+        expression_width: ExpressionWidth,
+        witness_warning_threshold: u32,
+    ) -> Context<'a> {
         let mut acir_context = AcirContext::default();
+        acir_context.set_expression_width(expression_width);
         let current_side_effects_enabled_var = acir_context.add_constant(FieldElement::one());
 
         Context {
@@ -375,6 +404,8 @@ impl<'a> Context<'a> {
             internal_mem_block_lengths: HashMap::default(),
             max_block_id: 0,
             data_bus: DataBus::default(),
+            witness_warning_threshold,
+            witness_warning_emitted: false,
             shared_context,
         }
     }
@@ -388,7 +419,7 @@ impl<'a> Context<'a> {
         match function.runtime() {
             RuntimeType::Acir(inline_type) => {
                 match inline_type {
-                    InlineType::Inline => {
+                    InlineType::Inline | InlineType::InlineAlways => {
                         if function.id() != ssa.main_id {
                             panic!("ACIR function should have been inlined earlier if not marked otherwise");
                         }
@@ -401,7 +432,7 @@ impl<'a> Context<'a> {
                 // We only want to convert entry point functions. This being `main` and those marked with `InlineType::Fold`
                 Ok(Some(self.convert_acir_main(function, ssa, brillig)?))
             }
-            RuntimeType::Brillig => {
+            RuntimeType::Brillig(_) => {
                 if function.id() == ssa.main_id {
                     Ok(Some(self.convert_brillig_main(function, brillig)?))
                 } else {
@@ -423,14 +454,51 @@ impl<'a> Context<'a> {
 
         self.data_bus = dfg.data_bus.to_owned();
         let mut warnings = Vec::new();
+        self.check_witness_count(main_func, &mut warnings)?;
         for instruction_id in entry_block.instructions() {
+            if let Some(expression_provenance) = &ssa.expression_provenance {
+                let instruction = expression_provenance.instruction(main_func, *instruction_id);
+                self.acir_context.set_current_instruction(Some(instruction));
+            }
             warnings.extend(self.convert_ssa_instruction(*instruction_id, dfg, ssa, brillig)?);
+            self.acir_context.set_current_instruction(None);
+            self.check_witness_count(main_func, &mut warnings)?;
         }
 
         warnings.extend(self.convert_ssa_return(entry_block.unwrap_terminator(), dfg)?);
+        self.check_witness_count(main_func, &mut warnings)?;
         Ok(self.acir_context.finish(input_witness, warnings))
     }
 
+    /// Aborts code generation once `function` needs more witnesses than can be indexed,
+    /// and warns the first time it grows past the configured witness threshold.
+    ///
+    /// Both point at the source location responsible for the most opcodes in the function.
+    fn check_witness_count(
+        &mut self,
+        function: &Function,
+        warnings: &mut Vec<SsaReport>,
+    ) -> Result<(), RuntimeError> {
+        if self.acir_context.witness_index_overflowed() {
+            return Err(RuntimeError::WitnessIndexOverflow {
+                function: function.name().to_owned(),
+                call_stack: self.acir_context.largest_call_site(),
+            });
+        }
+
+        if !self.witness_warning_emitted
+            && self.acir_context.current_witness_index().0 >= self.witness_warning_threshold
+        {
+            self.witness_warning_emitted = true;
+            warnings.push(SsaReport::Warning(InternalWarning::WitnessCountThreshold {
+                function: function.name().to_owned(),
+                threshold: self.witness_warning_threshold,
+                call_stack: self.acir_context.largest_call_site(),
+            }));
+        }
+        Ok(())
+    }
+
     fn convert_brillig_main(
         mut self,
         main_func: &Function,
@@ -732,7 +800,7 @@ impl<'a> Context<'a> {
                         let func = &ssa.functions[id];
                         match func.runtime() {
                             RuntimeType::Acir(inline_type) => {
-                                assert!(!matches!(inline_type, InlineType::Inline), "ICE: Got an ACIR function named {} that should have already been inlined", func.name());
+                                assert!(!matches!(inline_type, InlineType::Inline | InlineType::InlineAlways), "ICE: Got an ACIR function named {} that should have already been inlined", func.name());
 
                                 let inputs = vecmap(arguments, |arg| self.convert_value(*arg, dfg));
                                 let output_count = result_ids
@@ -756,7 +824,7 @@ impl<'a> Context<'a> {
 
                                 self.handle_ssa_call_outputs(result_ids, output_values, dfg)?;
                             }
-                            RuntimeType::Brillig => {
+                            RuntimeType::Brillig(_) => {
                                 // Check that we are not attempting to return a slice from
                                 // an unconstrained runtime to a constrained runtime
                                 for result_id in result_ids {
@@ -1823,10 +1891,9 @@ impl<'a> Context<'a> {
         match &binary_type {
             Type::Numeric(NumericType::Unsigned { bit_size })
             | Type::Numeric(NumericType::Signed { bit_size }) => {
-                // Conservative max bit size that is small enough such that two operands can be
-                // multiplied and still fit within the field modulus. This is necessary for the
-                // truncation technique: result % 2^bit_size to be valid.
-                let max_integer_bit_size = FieldElement::max_num_bits() / 2;
+                // Operands up to this size are split into limbs wherever their product could
+                // otherwise exceed the field modulus, see `check_unsigned_overflow`.
+                let max_integer_bit_size = 2 * PRODUCT_LIMB_BITS;
                 if *bit_size > max_integer_bit_size {
                     return Err(RuntimeError::UnsupportedIntegerSize {
                         num_bits: *bit_size,
@@ -1930,6 +1997,20 @@ impl<'a> Context<'a> {
             _ => return Ok(()),
         };
 
+        if op == BinaryOp::Mul && 2 * bit_size >= FieldElement::max_num_bits() {
+            // The product may have wrapped around the field modulus, in which case range
+            // constraining it would not catch the overflow.
+            let lhs = self.convert_numeric_value(lhs, dfg)?;
+            let rhs = self.convert_numeric_value(rhs, dfg)?;
+            return self.acir_context.range_constrain_limb_product(
+                lhs,
+                rhs,
+                bit_size,
+                self.current_side_effects_enabled_var,
+                Some(msg),
+            );
+        }
+
         let with_pred = self.acir_context.mul_var(result, self.current_side_effects_enabled_var)?;
         self.acir_context.range_constrain_var(
             with_pred,
@@ -2001,7 +2082,9 @@ impl<'a> Context<'a> {
                 ) {
                     // Subtractions must first have the integer modulus added before truncation can be
                     // applied. This is done in order to prevent underflow.
-                    let integer_modulus = self.acir_context.add_constant(2_u128.pow(bit_size));
+                    let integer_modulus =
+                        FieldElement::from(2_u128).pow(&(bit_size as u128).into());
+                    let integer_modulus = self.acir_context.add_constant(integer_modulus);
                     var = self.acir_context.add_var(var, integer_modulus)?;
                 }
             }
@@ -2765,26 +2848,42 @@ fn can_omit_element_sizes_array(array_typ: &Type) -> bool {
 
 #[cfg(test)]
 mod test {
-    use std::collections::BTreeMap;
+    use std::{collections::BTreeMap, rc::Rc};
 
     use acvm::{
         acir::{
-            circuit::{Opcode, OpcodeLocation},
-            native_types::Witness,
+            circuit::{
+                brillig::BrilligBytecode, opcodes::BlackBoxFuncCall, ExpressionWidth, Opcode,
+                OpcodeLocation,
+            },
+            native_types::{Witness, WitnessMap},
+            BlackBoxFunc,
         },
+        blackbox_solver::StubbedBlackBoxSolver,
+        pwg::{ACVMStatus, ACVM},
         FieldElement,
     };
     use noirc_frontend::monomorphization::ast::InlineType;
 
     use crate::{
         brillig::Brillig,
+        errors::{InternalWarning, RuntimeError, SsaReport},
         ssa::{
-            acir_gen::acir_ir::generated_acir::BrilligStdlibFunc,
+            acir_gen::acir_ir::generated_acir::{BrilligStdlibFunc, GeneratedAcir},
             function_builder::FunctionBuilder,
-            ir::{function::FunctionId, instruction::BinaryOp, map::Id, types::Type},
+            ir::{
+                function::FunctionId,
+                instruction::{BinaryOp, Intrinsic},
+                map::Id,
+                types::Type,
+            },
+            ssa_gen::Ssa,
+            DEFAULT_WITNESS_WARNING_THRESHOLD,
         },
     };
 
+    use super::{Context, SharedContext};
+
     fn build_basic_foo_with_return(
         builder: &mut FunctionBuilder,
         foo_id: FunctionId,
@@ -2800,7 +2899,7 @@ mod test {
         if let Some(inline_type) = inline_type {
             builder.new_function("foo".into(), foo_id, inline_type);
         } else {
-            builder.new_brillig_function("foo".into(), foo_id);
+            builder.new_brillig_function("foo".into(), foo_id, InlineType::default());
         }
         let foo_v0 = builder.add_parameter(Type::field());
         let foo_v1 = builder.add_parameter(Type::field());
@@ -2866,7 +2965,11 @@ mod test {
         let ssa = builder.finish();
 
         let (acir_functions, _, _) = ssa
-            .into_acir(&Brillig::default())
+            .into_acir(
+                &Brillig::default(),
+                ExpressionWidth::default(),
+                DEFAULT_WITNESS_WARNING_THRESHOLD,
+            )
             .expect("Should compile manually written SSA into ACIR");
         // Expected result:
         // main f0
@@ -2961,7 +3064,11 @@ mod test {
         let ssa = builder.finish();
 
         let (acir_functions, _, _) = ssa
-            .into_acir(&Brillig::default())
+            .into_acir(
+                &Brillig::default(),
+                ExpressionWidth::default(),
+                DEFAULT_WITNESS_WARNING_THRESHOLD,
+            )
             .expect("Should compile manually written SSA into ACIR");
         // The expected result should look very similar to the above test expect that the input witnesses of the `Call`
         // opcodes will be different. The changes can discerned from the checks below.
@@ -3051,7 +3158,11 @@ mod test {
         let ssa = builder.finish();
 
         let (acir_functions, _, _) = ssa
-            .into_acir(&Brillig::default())
+            .into_acir(
+                &Brillig::default(),
+                ExpressionWidth::default(),
+                DEFAULT_WITNESS_WARNING_THRESHOLD,
+            )
             .expect("Should compile manually written SSA into ACIR");
 
         assert_eq!(acir_functions.len(), 3, "Should have three ACIR functions");
@@ -3112,6 +3223,66 @@ mod test {
         }
     }
 
+    /// Check that a function which is not inlined has its opcodes generated only once,
+    /// rather than being duplicated at each of its call sites.
+    #[test]
+    fn fold_deduplicates_opcodes_across_call_sites() {
+        const CALL_SITES: usize = 50;
+
+        let inlined = opcodes_per_function_with_call_sites(InlineType::Inline, CALL_SITES);
+        assert_eq!(inlined.len(), 1, "Should only have a `main` ACIR function");
+
+        let folded = opcodes_per_function_with_call_sites(InlineType::Fold, CALL_SITES);
+        assert_eq!(folded.len(), 2, "Should have a `main` and a `foo` ACIR function");
+        assert_eq!(folded[0], CALL_SITES, "Should have a call opcode for each call site");
+
+        // Every call site duplicates the body of `foo` when it is inlined.
+        let folded_total: usize = folded.iter().sum();
+        assert!(
+            folded_total < inlined[0],
+            "Expected {folded_total} opcodes across all folded functions to be less than {} inlined opcodes",
+            inlined[0]
+        );
+    }
+
+    /// Returns the number of opcodes in each ACIR function generated for a `main`
+    /// which calls `foo` (with the given inline type) `call_sites` times.
+    fn opcodes_per_function_with_call_sites(
+        inline_type: InlineType,
+        call_sites: usize,
+    ) -> Vec<usize> {
+        // acir(inline) fn main f0 {
+        //     b0(v0: Field, v1: Field):
+        //       v2 = call f1(v0, v1)
+        //       ...
+        //       return
+        //     }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let main_v0 = builder.add_parameter(Type::field());
+        let main_v1 = builder.add_parameter(Type::field());
+
+        let foo_id = Id::test_new(1);
+        let foo = builder.import_function(foo_id);
+        for _ in 0..call_sites {
+            builder.insert_call(foo, vec![main_v0, main_v1], vec![Type::field()]);
+        }
+        builder.terminate_with_return(vec![]);
+
+        build_basic_foo_with_return(&mut builder, foo_id, Some(inline_type));
+
+        let ssa = builder.finish().inline_functions().unwrap();
+
+        let (acir_functions, _, _) = ssa
+            .into_acir(
+                &Brillig::default(),
+                ExpressionWidth::default(),
+                DEFAULT_WITNESS_WARNING_THRESHOLD,
+            )
+            .expect("Should compile manually written SSA into ACIR");
+        acir_functions.iter().map(|acir| acir.opcodes().len()).collect()
+    }
+
     // Test that given multiple calls to the same brillig function we generate only one bytecode
     // and the appropriate Brillig call opcodes are generated
     #[test]
@@ -3164,8 +3335,9 @@ mod test {
         let ssa = builder.finish();
         let brillig = ssa.to_brillig(false);
 
-        let (acir_functions, brillig_functions, _) =
-            ssa.into_acir(&brillig).expect("Should compile manually written SSA into ACIR");
+        let (acir_functions, brillig_functions, _) = ssa
+            .into_acir(&brillig, ExpressionWidth::default(), DEFAULT_WITNESS_WARNING_THRESHOLD)
+            .expect("Should compile manually written SSA into ACIR");
 
         assert_eq!(acir_functions.len(), 1, "Should only have a `main` ACIR function");
         assert_eq!(brillig_functions.len(), 2, "Should only have generated two Brillig functions");
@@ -3221,7 +3393,11 @@ mod test {
         // The Brillig bytecode we insert for the stdlib is hardcoded so we do not need to provide any
         // Brillig artifacts to the ACIR gen pass.
         let (acir_functions, brillig_functions, _) = ssa
-            .into_acir(&Brillig::default())
+            .into_acir(
+                &Brillig::default(),
+                ExpressionWidth::default(),
+                DEFAULT_WITNESS_WARNING_THRESHOLD,
+            )
             .expect("Should compile manually written SSA into ACIR");
 
         assert_eq!(acir_functions.len(), 1, "Should only have a `main` ACIR function");
@@ -3292,8 +3468,9 @@ mod test {
         let brillig = ssa.to_brillig(false);
         println!("{}", ssa);
 
-        let (acir_functions, brillig_functions, _) =
-            ssa.into_acir(&brillig).expect("Should compile manually written SSA into ACIR");
+        let (acir_functions, brillig_functions, _) = ssa
+            .into_acir(&brillig, ExpressionWidth::default(), DEFAULT_WITNESS_WARNING_THRESHOLD)
+            .expect("Should compile manually written SSA into ACIR");
 
         assert_eq!(acir_functions.len(), 1, "Should only have a `main` ACIR function");
         // We expect 3 brillig functions:
@@ -3380,8 +3557,9 @@ mod test {
         let brillig = ssa.to_brillig(false);
         println!("{}", ssa);
 
-        let (acir_functions, brillig_functions, _) =
-            ssa.into_acir(&brillig).expect("Should compile manually written SSA into ACIR");
+        let (acir_functions, brillig_functions, _) = ssa
+            .into_acir(&brillig, ExpressionWidth::default(), DEFAULT_WITNESS_WARNING_THRESHOLD)
+            .expect("Should compile manually written SSA into ACIR");
 
         assert_eq!(acir_functions.len(), 2, "Should only have two ACIR functions");
         // We expect 3 brillig functions:
@@ -3473,4 +3651,296 @@ mod test {
             "Should have {expected_num_normal_calls} BrilligCall opcodes to normal Brillig functions but got {num_normal_brillig_calls}"
         );
     }
+
+    /// Compiles `fn main(x: u32, y: u32) -> (u32, u32) { (x / d, x % d) }` into ACIR,
+    /// where the divisor `d` is either the constant `divisor` or the runtime value `y`.
+    fn build_division(divisor: Option<u128>) -> (GeneratedAcir, Vec<BrilligBytecode>) {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let x = builder.add_parameter(Type::unsigned(32));
+        let y = builder.add_parameter(Type::unsigned(32));
+        let divisor = match divisor {
+            Some(divisor) => builder.numeric_constant(divisor, Type::unsigned(32)),
+            None => y,
+        };
+        let quotient = builder.insert_binary(x, BinaryOp::Div, divisor);
+        let remainder = builder.insert_binary(x, BinaryOp::Mod, divisor);
+        builder.terminate_with_return(vec![quotient, remainder]);
+
+        let (mut acir_functions, brillig_functions, _) = builder
+            .finish()
+            .into_acir(
+                &Brillig::default(),
+                ExpressionWidth::default(),
+                DEFAULT_WITNESS_WARNING_THRESHOLD,
+            )
+            .expect("Should compile manually written SSA into ACIR");
+        (acir_functions.remove(0), brillig_functions)
+    }
+
+    fn execute(
+        (acir, brillig_functions): &(GeneratedAcir, Vec<BrilligBytecode>),
+        inputs: &[u128],
+    ) -> Vec<FieldElement> {
+        let initial_witness: BTreeMap<Witness, FieldElement> = acir
+            .input_witnesses
+            .iter()
+            .zip(inputs)
+            .map(|(witness, value)| (*witness, FieldElement::from(*value)))
+            .collect();
+        let mut acvm = ACVM::new(
+            &StubbedBlackBoxSolver,
+            acir.opcodes(),
+            WitnessMap::from(initial_witness),
+            brillig_functions,
+            &[],
+        );
+        assert_eq!(acvm.solve(), ACVMStatus::Solved);
+        let witness_map = acvm.finalize();
+        acir.return_witnesses.iter().map(|witness| witness_map[witness]).collect()
+    }
+
+    #[test]
+    fn division_by_power_of_two_matches_general_division() {
+        let general = build_division(None);
+
+        // A simple xorshift generator so that the inputs are reproducible.
+        let mut state: u32 = 0x9e37_79b9;
+        let mut next_input = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        for divisor_bits in [1, 8, 16, 31] {
+            let divisor = 1u128 << divisor_bits;
+            let power_of_two = build_division(Some(divisor));
+            assert!(
+                power_of_two.0.opcodes().len() < general.0.opcodes().len(),
+                "division by 2^{divisor_bits} should use fewer opcodes than general division"
+            );
+
+            for x in
+                [0, 1, u32::MAX].into_iter().chain(std::iter::repeat_with(&mut next_input).take(50))
+            {
+                let expected = [x as u128 / divisor, x as u128 % divisor].map(FieldElement::from);
+                assert_eq!(execute(&power_of_two, &[x as u128, 0]), expected);
+                assert_eq!(execute(&general, &[x as u128, divisor]), expected);
+            }
+        }
+    }
+
+    /// Compiles `fn main(x: u32, y: u32) -> u32 { x <operator> y }` into ACIR.
+    fn build_binary(operator: BinaryOp) -> (GeneratedAcir, Vec<BrilligBytecode>) {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let x = builder.add_parameter(Type::unsigned(32));
+        let y = builder.add_parameter(Type::unsigned(32));
+        let result = builder.insert_binary(x, operator, y);
+        builder.terminate_with_return(vec![result]);
+
+        let (mut acir_functions, brillig_functions, _) = builder
+            .finish()
+            .into_acir(
+                &Brillig::default(),
+                ExpressionWidth::default(),
+                DEFAULT_WITNESS_WARNING_THRESHOLD,
+            )
+            .expect("Should compile manually written SSA into ACIR");
+        (acir_functions.remove(0), brillig_functions)
+    }
+
+    #[test]
+    fn modulo_costs_the_same_as_division() {
+        let division = build_binary(BinaryOp::Div);
+        let modulo = build_binary(BinaryOp::Mod);
+
+        // Both are lowered to the same euclidean division, only returning a different result.
+        let (division_opcodes, modulo_opcodes) =
+            (division.0.opcodes().len(), modulo.0.opcodes().len());
+        assert!(
+            modulo_opcodes.abs_diff(division_opcodes) <= 1,
+            "modulo used {modulo_opcodes} opcodes but division used {division_opcodes}"
+        );
+
+        for (x, y) in [(0, 1), (7, 3), (u32::MAX, 10), (12345, 12345), (5, u32::MAX)] {
+            let (x, y) = (x as u128, y as u128);
+            assert_eq!(execute(&modulo, &[x, y]), [FieldElement::from(x % y)]);
+        }
+    }
+
+    /// Compiles `main(v0, ..., v7: Field) -> Field` returning `v0 + 2 * v1 + ... + 8 * v7 + v0 * v1`.
+    fn build_weighted_sum(
+        expression_width: ExpressionWidth,
+    ) -> (GeneratedAcir, Vec<BrilligBytecode>) {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let parameters: Vec<_> = (0..8).map(|_| builder.add_parameter(Type::field())).collect();
+
+        let mut sum = builder.insert_binary(parameters[0], BinaryOp::Mul, parameters[1]);
+        for (index, parameter) in parameters.iter().enumerate() {
+            let weight = builder.field_constant(index as u128 + 1);
+            let term = builder.insert_binary(*parameter, BinaryOp::Mul, weight);
+            sum = builder.insert_binary(sum, BinaryOp::Add, term);
+        }
+        builder.terminate_with_return(vec![sum]);
+
+        let (mut acir_functions, brillig_functions, _) = builder
+            .finish()
+            .into_acir(&Brillig::default(), expression_width, DEFAULT_WITNESS_WARNING_THRESHOLD)
+            .expect("Should compile manually written SSA into ACIR");
+        (acir_functions.remove(0), brillig_functions)
+    }
+
+    #[test]
+    fn sums_are_split_to_fit_the_expression_width() {
+        let unbounded = build_weighted_sum(ExpressionWidth::Unbounded);
+        let bounded = build_weighted_sum(ExpressionWidth::Bounded { width: 3 });
+
+        // With an unbounded width the whole sum is assigned to a witness in a single opcode.
+        assert!(bounded.0.opcodes().len() > unbounded.0.opcodes().len());
+        for opcode in bounded.0.opcodes() {
+            let Opcode::AssertZero(expression) = opcode else {
+                panic!("Expected only arithmetic opcodes, got {opcode}");
+            };
+            // Each opcode asserts that a sum fitting the width equals a fresh witness.
+            assert!(expression.linear_combinations.len() <= 4, "{expression}");
+        }
+
+        for inputs in [[0; 8], [1, 2, 3, 4, 5, 6, 7, 8], [u128::MAX, 0, 1, u128::MAX, 3, 0, 7, 1]] {
+            assert_eq!(execute(&bounded, &inputs), execute(&unbounded, &inputs));
+        }
+    }
+
+    /// Builds `main(v0: Field, v1: Field)` returning `v0 * v1 * v0 * v0 * v0`, where each
+    /// multiplication after the first requires a new witness.
+    fn build_repeated_multiplication() -> Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::field());
+
+        let mut product = builder.insert_binary(v0, BinaryOp::Mul, v1);
+        for _ in 0..3 {
+            product = builder.insert_binary(product, BinaryOp::Mul, v0);
+        }
+        builder.terminate_with_return(vec![product]);
+        builder.finish()
+    }
+
+    #[test]
+    fn witness_index_overflow_is_reported() {
+        let ssa = build_repeated_multiplication();
+        let mut shared_context = SharedContext::default();
+        let mut context = Context::new(
+            &mut shared_context,
+            ExpressionWidth::default(),
+            DEFAULT_WITNESS_WARNING_THRESHOLD,
+        );
+        // Start just short of the end of the witness index space rather than generating billions of witnesses.
+        context.acir_context.set_current_witness_index(u32::MAX - 3);
+
+        let error = context
+            .convert_ssa_function(&ssa, ssa.main(), &Brillig::default())
+            .expect_err("Witness indices should not wrap around");
+        assert!(
+            matches!(&error, RuntimeError::WitnessIndexOverflow { function, .. } if function == "main"),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn witness_count_threshold_is_reported_once() {
+        let ssa = build_repeated_multiplication();
+        let (acir_functions, _, _) = ssa
+            .into_acir(&Brillig::default(), ExpressionWidth::default(), 3)
+            .expect("Should compile manually written SSA into ACIR");
+
+        let threshold_warnings: Vec<_> = acir_functions[0]
+            .warnings
+            .iter()
+            .filter_map(|SsaReport::Warning(warning)| match warning {
+                InternalWarning::WitnessCountThreshold { function, threshold, .. } => {
+                    Some((function.as_str(), *threshold))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(threshold_warnings, vec![("main", 3)]);
+    }
+
+    /// Builds `main(v0: [T; 2]) -> [u8; 32]` returning the blake2s hash of `v0`, which the
+    /// black box function requires to be bytes.
+    fn build_blake2s(element_type: Type) -> Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let input = builder.add_parameter(Type::Array(Rc::new(vec![element_type]), 2));
+
+        let blake2s = builder.import_intrinsic_id(Intrinsic::BlackBox(BlackBoxFunc::Blake2s));
+        let hash_type = Type::Array(Rc::new(vec![Type::unsigned(8)]), 32);
+        let hash = builder.insert_call(blake2s, vec![input], vec![hash_type])[0];
+        builder.terminate_with_return(vec![hash]);
+        builder.finish()
+    }
+
+    #[test]
+    fn black_box_inputs_wider_than_expected_are_rejected() {
+        let ssa = build_blake2s(Type::unsigned(32));
+
+        let error = ssa
+            .into_acir(
+                &Brillig::default(),
+                ExpressionWidth::default(),
+                DEFAULT_WITNESS_WARNING_THRESHOLD,
+            )
+            .expect_err("A u32 should not be passed where a byte is expected");
+        assert!(
+            matches!(
+                &error,
+                RuntimeError::BlackBoxInputTooWide { function, expected_bit_size: 8, bit_size: 32, .. }
+                    if function == "blake2s"
+            ),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn field_black_box_inputs_are_range_constrained() {
+        let ssa = build_blake2s(Type::field());
+
+        let (acir_functions, _, _) = ssa
+            .into_acir(
+                &Brillig::default(),
+                ExpressionWidth::default(),
+                DEFAULT_WITNESS_WARNING_THRESHOLD,
+            )
+            .expect("Should compile manually written SSA into ACIR");
+        let opcodes = acir_functions[0].opcodes();
+
+        let range_constrained: Vec<_> = opcodes
+            .iter()
+            .filter_map(|opcode| match opcode {
+                Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE { input }) => {
+                    Some((input.as_witness().unwrap(), input.num_bits()))
+                }
+                _ => None,
+            })
+            .collect();
+        let hashed: Vec<_> = opcodes
+            .iter()
+            .find_map(|opcode| match opcode {
+                Opcode::BlackBoxFuncCall(BlackBoxFuncCall::Blake2s { inputs, .. }) => Some(inputs),
+                _ => None,
+            })
+            .expect("Expected a blake2s call")
+            .iter()
+            .map(|input| (input.as_witness().unwrap(), input.num_bits()))
+            .collect();
+
+        // Each input is constrained to be a byte before being hashed as one.
+        assert_eq!(hashed.len(), 2);
+        assert!(hashed.iter().all(|(_, num_bits)| *num_bits == 8), "{hashed:?}");
+        assert_eq!(range_constrained, hashed);
+    }
 }
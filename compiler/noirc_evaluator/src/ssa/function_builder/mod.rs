@@ -4,7 +4,7 @@ use std::{borrow::Cow, collections::BTreeMap, rc::Rc};
 
 use acvm::{acir::circuit::ErrorSelector, FieldElement};
 use noirc_errors::Location;
-use noirc_frontend::monomorphization::ast::InlineType;
+use noirc_frontend::monomorphization::ast::{Expression, InlineType};
 
 use crate::ssa::ir::{
     basic_block::BasicBlockId,
@@ -21,7 +21,7 @@ use super::{
         function::RuntimeType,
         instruction::{ConstrainError, ErrorType, InstructionId, Intrinsic},
     },
-    ssa_gen::Ssa,
+    ssa_gen::{provenance::ExpressionProvenance, Ssa},
 };
 
 /// The per-function context for each ssa function being generated.
@@ -37,6 +37,8 @@ pub(crate) struct FunctionBuilder {
     finished_functions: Vec<Function>,
     call_stack: CallStack,
     error_types: BTreeMap<ErrorSelector, ErrorType>,
+    /// Only recorded when compiling with `--full-debug`.
+    expression_provenance: Option<ExpressionProvenance>,
 }
 
 impl FunctionBuilder {
@@ -53,6 +55,7 @@ impl FunctionBuilder {
             finished_functions: Vec::new(),
             call_stack: CallStack::new(),
             error_types: BTreeMap::default(),
+            expression_provenance: None,
         }
     }
 
@@ -95,14 +98,40 @@ impl FunctionBuilder {
     }
 
     /// Finish the current function and create a new unconstrained function.
-    pub(crate) fn new_brillig_function(&mut self, name: String, function_id: FunctionId) {
-        self.new_function_with_type(name, function_id, RuntimeType::Brillig);
+    pub(crate) fn new_brillig_function(
+        &mut self,
+        name: String,
+        function_id: FunctionId,
+        inline_type: InlineType,
+    ) {
+        self.new_function_with_type(name, function_id, RuntimeType::Brillig(inline_type));
     }
 
     /// Consume the FunctionBuilder returning all the functions it has generated.
     pub(crate) fn finish(mut self) -> Ssa {
         self.finished_functions.push(self.current_function);
-        Ssa::new(self.finished_functions, self.error_types)
+        let mut ssa = Ssa::new(self.finished_functions, self.error_types);
+        ssa.expression_provenance = self.expression_provenance;
+        ssa
+    }
+
+    /// Record which monomorphized expression each instruction is generated from.
+    pub(crate) fn enable_expression_provenance(&mut self) {
+        self.expression_provenance = Some(ExpressionProvenance::default());
+    }
+
+    /// Marks the start of lowering an expression if expression provenance is being recorded.
+    pub(crate) fn enter_expression(&mut self) {
+        if let Some(provenance) = &mut self.expression_provenance {
+            provenance.enter_expression();
+        }
+    }
+
+    /// Marks the end of lowering an expression if expression provenance is being recorded.
+    pub(crate) fn exit_expression(&mut self, expression: &Expression) {
+        if let Some(provenance) = &mut self.expression_provenance {
+            provenance.exit_expression(expression);
+        }
     }
 
     /// Add a parameter to the current function with the given parameter type.
@@ -165,6 +194,9 @@ impl FunctionBuilder {
         ctrl_typevars: Option<Vec<Type>>,
     ) -> InsertInstructionResult {
         let block = self.current_block();
+        if let Some(provenance) = &mut self.expression_provenance {
+            provenance.record_instruction(self.call_stack.last());
+        }
         self.current_function.dfg.insert_instruction_and_results(
             instruction,
             block,
@@ -15,7 +15,7 @@ pub(crate) enum RuntimeType {
     // A noir function, to be compiled in ACIR and executed by ACVM
     Acir(InlineType),
     // Unconstrained function, to be compiled to brillig and executed by the Brillig VM
-    Brillig,
+    Brillig(InlineType),
 }
 
 impl RuntimeType {
@@ -26,9 +26,15 @@ impl RuntimeType {
     pub(crate) fn is_entry_point(&self) -> bool {
         match self {
             RuntimeType::Acir(inline_type) => inline_type.is_entry_point(),
-            RuntimeType::Brillig => true,
+            RuntimeType::Brillig(_) => true,
         }
     }
+
+    /// Returns whether this is an unconstrained function which should be inlined into the
+    /// unconstrained functions which call it.
+    pub(crate) fn is_inline_always_brillig(&self) -> bool {
+        matches!(self, RuntimeType::Brillig(InlineType::InlineAlways))
+    }
 }
 
 /// A function holds a list of instructions.
@@ -88,7 +94,7 @@ impl Function {
     pub(crate) fn is_no_predicates(&self) -> bool {
         match self.runtime() {
             RuntimeType::Acir(inline_type) => matches!(inline_type, InlineType::NoPredicates),
-            RuntimeType::Brillig => false,
+            RuntimeType::Brillig(_) => false,
         }
     }
 
@@ -151,7 +157,7 @@ impl std::fmt::Display for RuntimeType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             RuntimeType::Acir(inline_type) => write!(f, "acir({inline_type})"),
-            RuntimeType::Brillig => write!(f, "brillig"),
+            RuntimeType::Brillig(_) => write!(f, "brillig"),
         }
     }
 }
@@ -2,13 +2,27 @@ use iter_extended::vecmap;
 
 use super::{
     basic_block::BasicBlockId,
-    dfg::{CallStack, InsertInstructionResult},
+    dfg::{CallStack, DataFlowGraph, InsertInstructionResult},
     function::Function,
     instruction::{Instruction, InstructionId},
     value::ValueId,
 };
 use fxhash::FxHashMap as HashMap;
 
+/// What [FunctionInserter::rewrite_block] should do with each instruction of a block.
+pub(crate) enum RewriteAction {
+    /// Re-insert the instruction unchanged, apart from mapping its values.
+    Keep,
+    /// Insert this instruction in place of the original. It must have the same results.
+    Replace(Instruction),
+    /// Insert these instructions in place of the original, in order.
+    ///
+    /// The results of the original instruction are mapped to the results of the last instruction,
+    /// so only the last may require ctrl typevars. An empty list removes the original instruction,
+    /// which must then have no results.
+    ReplaceMany(Vec<Instruction>),
+}
+
 /// The FunctionInserter can be used to help modify existing Functions
 /// and map old values to new values after re-inserting optimized versions
 /// of old instructions.
@@ -94,6 +108,65 @@ impl<'f> FunctionInserter<'f> {
         }
     }
 
+    /// Push each of the given instructions, in order, to the given block.
+    ///
+    /// This is equivalent to calling [`Self::push_instruction`] on each instruction but reserves
+    /// space in the value map for all of their results up front rather than growing it per instruction.
+    pub(crate) fn push_instructions(&mut self, ids: &[InstructionId], block: BasicBlockId) {
+        let result_count: usize =
+            ids.iter().map(|id| self.function.dfg.instruction_results(*id).len()).sum();
+        self.values.reserve(result_count);
+
+        for id in ids {
+            let (instruction, call_stack) = self.map_instruction(*id);
+            self.push_instruction_value(instruction, *id, block, call_stack);
+        }
+    }
+
+    /// Re-inserts every instruction of `block`, letting `rewrite` decide what to replace each of them with.
+    ///
+    /// `rewrite` is given each instruction with its values already mapped. The results of the original
+    /// instructions are remapped to those of their replacements, as are the values in the block's terminator.
+    pub(crate) fn rewrite_block(
+        &mut self,
+        block: BasicBlockId,
+        mut rewrite: impl FnMut(InstructionId, &Instruction, &DataFlowGraph) -> RewriteAction,
+    ) {
+        let instructions = self.function.dfg[block].take_instructions();
+
+        for id in instructions {
+            let (instruction, call_stack) = self.map_instruction(id);
+            match rewrite(id, &instruction, &self.function.dfg) {
+                RewriteAction::Keep => {
+                    self.push_instruction_value(instruction, id, block, call_stack);
+                }
+                RewriteAction::Replace(replacement) => {
+                    self.push_instruction_value(replacement, id, block, call_stack);
+                }
+                RewriteAction::ReplaceMany(mut replacements) => {
+                    let Some(last) = replacements.pop() else {
+                        assert!(
+                            self.function.dfg.instruction_results(id).is_empty(),
+                            "Cannot remove an instruction whose results may be used"
+                        );
+                        continue;
+                    };
+                    for replacement in replacements {
+                        self.function.dfg.insert_instruction_and_results(
+                            replacement,
+                            block,
+                            None,
+                            call_stack.clone(),
+                        );
+                    }
+                    self.push_instruction_value(last, id, block, call_stack);
+                }
+            }
+        }
+
+        self.map_terminator_in_place(block);
+    }
+
     pub(crate) fn push_instruction_value(
         &mut self,
         instruction: Instruction,
@@ -168,3 +241,142 @@ impl<'f> FunctionInserter<'f> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{
+            instruction::{BinaryOp, Instruction, TerminatorInstruction},
+            map::Id,
+            types::Type,
+        },
+    };
+
+    use super::{FunctionInserter, RewriteAction};
+
+    #[test]
+    fn replace_many_maps_results_to_the_last_instruction() {
+        // fn main f0 {
+        //   b0(v0: Field, v1: Field):
+        //     v2 = mul v0, v1
+        //     v3 = add v2, Field 1
+        //     return v3
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::field());
+        let one = builder.field_constant(1u128);
+        let v2 = builder.insert_binary(v0, BinaryOp::Mul, v1);
+        let v3 = builder.insert_binary(v2, BinaryOp::Add, one);
+        builder.terminate_with_return(vec![v3]);
+        let mut ssa = builder.finish();
+
+        // Replace the multiplication with `constrain v0 == v1` followed by `v4 = mul v0, v0`,
+        // and the addition with a subtraction.
+        let function = ssa.main_mut();
+        let block = function.entry_block();
+        let mut inserter = FunctionInserter::new(function);
+        inserter.rewrite_block(block, |_, instruction, _| match instruction {
+            Instruction::Binary(binary) if binary.operator == BinaryOp::Mul => {
+                let constrain = Instruction::Constrain(binary.lhs, binary.rhs, None);
+                let square = Instruction::binary(BinaryOp::Mul, binary.lhs, binary.lhs);
+                RewriteAction::ReplaceMany(vec![constrain, square])
+            }
+            Instruction::Binary(binary) if binary.operator == BinaryOp::Add => {
+                RewriteAction::Replace(Instruction::binary(BinaryOp::Sub, binary.lhs, binary.rhs))
+            }
+            _ => RewriteAction::Keep,
+        });
+
+        // fn main f0 {
+        //   b0(v0: Field, v1: Field):
+        //     constrain v0 == v1
+        //     v4 = mul v0, v0
+        //     v5 = sub v4, Field 1
+        //     return v5
+        // }
+        let main = ssa.main();
+        let instructions = main.dfg[block].instructions();
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(main.dfg[instructions[0]], Instruction::Constrain(v0, v1, None));
+
+        let v4 = main.dfg.instruction_results(instructions[1])[0];
+        assert_eq!(main.dfg[instructions[1]], Instruction::binary(BinaryOp::Mul, v0, v0));
+        assert_eq!(main.dfg[instructions[2]], Instruction::binary(BinaryOp::Sub, v4, one));
+
+        let v5 = main.dfg.instruction_results(instructions[2])[0];
+        let Some(TerminatorInstruction::Return { return_values, .. }) =
+            main.dfg[block].terminator()
+        else {
+            panic!("Expected a return terminator");
+        };
+        assert_eq!(return_values, &vec![v5]);
+    }
+
+    #[test]
+    fn replace_many_with_nothing_removes_the_instruction() {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::field());
+        let one = builder.field_constant(1u128);
+        builder.insert_constrain(v0, one, None);
+        let v1 = builder.insert_binary(v0, BinaryOp::Add, one);
+        builder.terminate_with_return(vec![v1]);
+        let mut ssa = builder.finish();
+
+        let function = ssa.main_mut();
+        let block = function.entry_block();
+        let mut inserter = FunctionInserter::new(function);
+        inserter.rewrite_block(block, |_, instruction, _| match instruction {
+            Instruction::Constrain(..) => RewriteAction::ReplaceMany(Vec::new()),
+            _ => RewriteAction::Keep,
+        });
+
+        let main = ssa.main();
+        let instructions = main.dfg[block].instructions();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(main.dfg[instructions[0]], Instruction::binary(BinaryOp::Add, v0, one));
+    }
+
+    #[test]
+    fn push_instructions_maps_values_between_the_pushed_instructions() {
+        // fn main f0 {
+        //   b0(v0: Field, v1: Field):
+        //     v2 = mul v0, v1
+        //     v3 = add v2, v0
+        //     return v3
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::field());
+        let v2 = builder.insert_binary(v0, BinaryOp::Mul, v1);
+        let v3 = builder.insert_binary(v2, BinaryOp::Add, v0);
+        builder.terminate_with_return(vec![v3]);
+        let mut ssa = builder.finish();
+
+        // Copy both instructions into a new block with `v0` replaced by `v1`.
+        let function = ssa.main_mut();
+        let block = function.entry_block();
+        let instructions = function.dfg[block].instructions().to_vec();
+        let new_block = function.dfg.make_block();
+        let mut inserter = FunctionInserter::new(function);
+        inserter.map_value(v0, v1);
+        inserter.push_instructions(&instructions, new_block);
+        let new_v3 = inserter.resolve(v3);
+
+        // b1():
+        //   v4 = mul v1, v1
+        //   v5 = add v4, v1
+        let main = ssa.main();
+        let new_instructions = main.dfg[new_block].instructions();
+        assert_eq!(new_instructions.len(), 2);
+        assert_eq!(main.dfg[new_instructions[0]], Instruction::binary(BinaryOp::Mul, v1, v1));
+
+        let v4 = main.dfg.instruction_results(new_instructions[0])[0];
+        assert_eq!(main.dfg[new_instructions[1]], Instruction::binary(BinaryOp::Add, v4, v1));
+        assert_eq!(main.dfg.instruction_results(new_instructions[1]), &[new_v3]);
+    }
+}
@@ -540,7 +540,8 @@ impl Instruction {
                     // would be incorrect however since the extra bits on the field would not be flipped.
                     Value::NumericConstant { constant, typ } if typ.is_unsigned() => {
                         // As we're casting to a `u128`, we need to clear out any upper bits that the NOT fills.
-                        let value = !constant.to_u128() % (1 << typ.bit_size());
+                        let value = !constant.to_u128()
+                            & u128::MAX.checked_shr(128 - typ.bit_size()).unwrap_or(0);
                         SimplifiedTo(dfg.make_constant(value.into(), typ.clone()))
                     }
                     Value::Instruction { instruction, .. } => {
@@ -593,8 +594,9 @@ impl Instruction {
                     return SimplifiedTo(*value);
                 }
                 if let Some((numeric_constant, typ)) = dfg.get_numeric_constant_with_type(*value) {
-                    let integer_modulus = 2_u128.pow(*bit_size);
-                    let truncated = numeric_constant.to_u128() % integer_modulus;
+                    // `to_u128` keeps the lowest 128 bits, which we then mask down to `bit_size` bits.
+                    let truncated = numeric_constant.to_u128()
+                        & u128::MAX.checked_shr(128 - *bit_size).unwrap_or(0);
                     SimplifiedTo(dfg.make_constant(truncated.into(), typ))
                 } else if let Value::Instruction { instruction, .. } = &dfg[dfg.resolve(*value)] {
                     match &dfg[*instruction] {
@@ -329,7 +329,7 @@ fn eval_constant_binary_op(
             }
             let result = function(lhs, rhs)?;
             // Check for overflow
-            if result >= 2u128.pow(*bit_size) {
+            if result > u128::MAX.checked_shr(128 - *bit_size).unwrap_or(0) {
                 return None;
             }
             result.into()
@@ -372,8 +372,7 @@ fn eval_constant_binary_op(
 }
 
 fn truncate(int: u128, bit_size: u32) -> u128 {
-    let max = 2u128.pow(bit_size);
-    int % max
+    int & u128::MAX.checked_shr(128 - bit_size).unwrap_or(0)
 }
 
 impl BinaryOp {
@@ -14,7 +14,7 @@ use crate::ssa::{
         types::Type,
         value::{Value, ValueId},
     },
-    opt::flatten_cfg::value_merger::ValueMerger,
+    opt::flatten_cfg::value_merger::{SlicePadding, ValueMerger},
 };
 
 use super::{Binary, BinaryOp, Endian, Instruction, SimplifyResult};
@@ -366,14 +366,14 @@ fn simplify_slice_push_back(
     slice_sizes.insert(new_slice, slice_size / element_size);
 
     let unknown = &mut HashMap::default();
-    let mut value_merger = ValueMerger::new(dfg, block, &mut slice_sizes, unknown, None);
-
-    let new_slice = value_merger.merge_values(
-        len_not_equals_capacity,
-        len_equals_capacity,
-        set_last_slice_value,
-        new_slice,
-    );
+    // Both slices have the same size so no padding is required to merge them.
+    let mut slice_padding = SlicePadding::new(0);
+    let mut value_merger =
+        ValueMerger::new(dfg, block, &mut slice_sizes, unknown, &mut slice_padding, None);
+
+    let new_slice = value_merger
+        .merge_values(len_not_equals_capacity, len_equals_capacity, set_last_slice_value, new_slice)
+        .expect("Merging slices of the same size should not require padding");
 
     SimplifyResult::SimplifiedToMultiple(vec![new_slice_length, new_slice])
 }
@@ -253,3 +253,57 @@ fn display_constrain_error(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{instruction::BinaryOp, map::Id, types::Type},
+    };
+
+    #[test]
+    fn prints_blocks_instructions_and_terminators() {
+        // fn main(x: Field, y: Field) -> Field {
+        //     if x == y { x } else { x + y }
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::field());
+
+        let then_block = builder.insert_block();
+        let else_block = builder.insert_block();
+        let end_block = builder.insert_block();
+
+        let v2 = builder.insert_binary(v0, BinaryOp::Eq, v1);
+        builder.terminate_with_jmpif(v2, then_block, else_block);
+
+        builder.switch_to_block(then_block);
+        builder.terminate_with_jmp(end_block, vec![v0]);
+
+        builder.switch_to_block(else_block);
+        let v3 = builder.insert_binary(v0, BinaryOp::Add, v1);
+        builder.terminate_with_jmp(end_block, vec![v3]);
+
+        builder.switch_to_block(end_block);
+        let v4 = builder.add_block_parameter(end_block, Type::field());
+        builder.terminate_with_return(vec![v4]);
+
+        let ssa = builder.finish();
+        let expected = "\
+acir(inline) fn main f0 {
+  b0(v0: Field, v1: Field):
+    v2 = eq v0, v1
+    jmpif v2 then: b1, else: b2
+  b1():
+    jmp b3(v0)
+  b3(v4: Field):
+    return v4
+  b2():
+    v3 = add v0, v1
+    jmp b3(v3)
+}
+";
+        assert_eq!(ssa.to_string(), expected);
+    }
+}
@@ -32,8 +32,7 @@ impl NumericType {
     pub(crate) fn value_is_within_limits(self, field: FieldElement) -> bool {
         match self {
             NumericType::Signed { bit_size } | NumericType::Unsigned { bit_size } => {
-                let max = 2u128.pow(bit_size) - 1;
-                field <= max.into()
+                field.num_bits() <= bit_size
             }
             NumericType::NativeField => true,
         }
@@ -245,7 +245,7 @@ fn flatten_function_cfg(function: &mut Function) {
     // This pass may run forever on a brillig function.
     // Analyze will check if the predecessors have been processed and push the block to the back of
     // the queue. This loops forever if there are still any loops present in the program.
-    if let crate::ssa::ir::function::RuntimeType::Brillig = function.runtime() {
+    if let crate::ssa::ir::function::RuntimeType::Brillig(_) = function.runtime() {
         return;
     }
     let cfg = ControlFlowGraph::with_function(function);
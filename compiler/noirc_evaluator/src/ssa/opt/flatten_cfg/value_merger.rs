@@ -1,6 +1,7 @@
 use acvm::FieldElement;
 use fxhash::{FxHashMap as HashMap, FxHashSet};
 
+use crate::errors::RuntimeError;
 use crate::ssa::ir::{
     basic_block::BasicBlockId,
     dfg::{CallStack, DataFlowGraph, InsertInstructionResult},
@@ -20,6 +21,36 @@ pub(crate) struct ValueMerger<'a> {
     slice_sizes: &'a mut HashMap<ValueId, usize>,
 
     array_set_conditionals: &'a mut HashMap<ValueId, ValueId>,
+
+    slice_padding: &'a mut SlicePadding,
+}
+
+/// Limits the number of placeholder elements which may be created to pad slices being merged.
+///
+/// Slice capacities are tracked as the maximum length a slice may have and never shrink, so a slice
+/// which is conditionally pushed to many times can require an enormous amount of padding when merged.
+pub(crate) struct SlicePadding {
+    /// The number of placeholder elements created so far.
+    used: usize,
+    /// The maximum number of placeholder elements which may be created.
+    limit: usize,
+}
+
+impl SlicePadding {
+    pub(crate) fn new(limit: usize) -> Self {
+        SlicePadding { used: 0, limit }
+    }
+
+    /// Reserves `elements` placeholder elements, erroring at `call_stack` if this would exceed the limit.
+    fn reserve(&mut self, elements: usize, call_stack: CallStack) -> Result<(), RuntimeError> {
+        match self.used.checked_add(elements) {
+            Some(used) if used <= self.limit => {
+                self.used = used;
+                Ok(())
+            }
+            _ => Err(RuntimeError::SlicePaddingLimitExceeded { limit: self.limit, call_stack }),
+        }
+    }
 }
 
 impl<'a> ValueMerger<'a> {
@@ -28,9 +59,17 @@ impl<'a> ValueMerger<'a> {
         block: BasicBlockId,
         slice_sizes: &'a mut HashMap<ValueId, usize>,
         array_set_conditionals: &'a mut HashMap<ValueId, ValueId>,
+        slice_padding: &'a mut SlicePadding,
         current_condition: Option<ValueId>,
     ) -> Self {
-        ValueMerger { dfg, block, slice_sizes, array_set_conditionals, current_condition }
+        ValueMerger {
+            dfg,
+            block,
+            slice_sizes,
+            array_set_conditionals,
+            slice_padding,
+            current_condition,
+        }
     }
 
     /// Merge two values a and b from separate basic blocks to a single value.
@@ -41,22 +80,24 @@ impl<'a> ValueMerger<'a> {
     ///
     /// It is currently an error to call this function on reference or function values
     /// as it is less clear how to merge these.
+    ///
+    /// Merging slices of different lengths errors if padding the shorter slice would exceed the [SlicePadding] limit.
     pub(crate) fn merge_values(
         &mut self,
         then_condition: ValueId,
         else_condition: ValueId,
         then_value: ValueId,
         else_value: ValueId,
-    ) -> ValueId {
+    ) -> Result<ValueId, RuntimeError> {
         match self.dfg.type_of_value(then_value) {
-            Type::Numeric(_) => Self::merge_numeric_values(
+            Type::Numeric(_) => Ok(Self::merge_numeric_values(
                 self.dfg,
                 self.block,
                 then_condition,
                 else_condition,
                 then_value,
                 else_value,
-            ),
+            )),
             typ @ Type::Array(_, _) => {
                 self.merge_array_values(typ, then_condition, else_condition, then_value, else_value)
             }
@@ -134,7 +175,7 @@ impl<'a> ValueMerger<'a> {
         else_condition: ValueId,
         then_value: ValueId,
         else_value: ValueId,
-    ) -> ValueId {
+    ) -> Result<ValueId, RuntimeError> {
         let mut merged = im::Vector::new();
 
         let (element_types, len) = match &typ {
@@ -150,8 +191,8 @@ impl<'a> ValueMerger<'a> {
             then_value,
             else_value,
             actual_length,
-        ) {
-            return result;
+        )? {
+            return Ok(result);
         }
 
         for i in 0..len {
@@ -176,11 +217,11 @@ impl<'a> ValueMerger<'a> {
                     else_condition,
                     then_element,
                     else_element,
-                ));
+                )?);
             }
         }
 
-        self.dfg.make_array(merged, typ)
+        Ok(self.dfg.make_array(merged, typ))
     }
 
     fn merge_slice_values(
@@ -190,7 +231,7 @@ impl<'a> ValueMerger<'a> {
         else_condition: ValueId,
         then_value_id: ValueId,
         else_value_id: ValueId,
-    ) -> ValueId {
+    ) -> Result<ValueId, RuntimeError> {
         let mut merged = im::Vector::new();
 
         let element_types = match &typ {
@@ -214,6 +255,22 @@ impl<'a> ValueMerger<'a> {
 
         let len = then_len.max(else_len);
 
+        // Check the padding required up front so that we never start materializing an oversized slice.
+        let padding_per_element: usize = element_types.iter().map(Type::flattened_size).sum();
+        let padding = then_len.abs_diff(else_len).saturating_mul(padding_per_element);
+        if padding > 0 {
+            let (longer_slice, shorter_slice) = if then_len >= else_len {
+                (then_value_id, else_value_id)
+            } else {
+                (else_value_id, then_value_id)
+            };
+            let mut call_stack = self.dfg.get_value_call_stack(longer_slice);
+            if call_stack.is_empty() {
+                call_stack = self.dfg.get_value_call_stack(shorter_slice);
+            }
+            self.slice_padding.reserve(padding, call_stack)?;
+        }
+
         for i in 0..len {
             for (element_index, element_type) in element_types.iter().enumerate() {
                 let index_usize = i * element_types.len() + element_index;
@@ -250,11 +307,11 @@ impl<'a> ValueMerger<'a> {
                     else_condition,
                     then_element,
                     else_element,
-                ));
+                )?);
             }
         }
 
-        self.dfg.make_array(merged, typ)
+        Ok(self.dfg.make_array(merged, typ))
     }
 
     /// Construct a dummy value to be attached to the smaller of two slices being merged.
@@ -297,9 +354,11 @@ impl<'a> ValueMerger<'a> {
         then_value: ValueId,
         else_value: ValueId,
         array_length: usize,
-    ) -> Option<ValueId> {
+    ) -> Result<Option<ValueId>, RuntimeError> {
         let mut found = false;
-        let current_condition = self.current_condition?;
+        let Some(current_condition) = self.current_condition else {
+            return Ok(None);
+        };
 
         let mut current_then = then_value;
         let mut current_else = else_value;
@@ -351,7 +410,7 @@ impl<'a> ValueMerger<'a> {
             .collect();
 
         if !found || changed_indices.len() >= array_length {
-            return None;
+            return Ok(None);
         }
 
         let mut array = then_value;
@@ -373,14 +432,14 @@ impl<'a> ValueMerger<'a> {
             let else_element = get_element(else_value, typevars);
 
             let value =
-                self.merge_values(then_condition, else_condition, then_element, else_element);
+                self.merge_values(then_condition, else_condition, then_element, else_element)?;
 
             array = self.insert_array_set(array, index, value, Some(condition)).first();
         }
 
         let instruction = Instruction::EnableSideEffects { condition: current_condition };
         self.insert_instruction(instruction);
-        Some(array)
+        Ok(Some(array))
     }
 
     fn insert_instruction(&mut self, instruction: Instruction) -> InsertInstructionResult {
@@ -2,32 +2,38 @@
 //! The purpose of this pass is to inline the instructions of each function call
 //! within the function caller. If all function calls are known, there will only
 //! be a single function remaining when the pass finishes.
-use std::collections::{BTreeSet, HashSet};
-
-use iter_extended::{btree_map, vecmap};
-
-use crate::ssa::{
-    function_builder::FunctionBuilder,
-    ir::{
-        basic_block::BasicBlockId,
-        dfg::{CallStack, InsertInstructionResult},
-        function::{Function, FunctionId, RuntimeType},
-        instruction::{Instruction, InstructionId, TerminatorInstruction},
-        value::{Value, ValueId},
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use acvm::FieldElement;
+
+use iter_extended::vecmap;
+
+use crate::{
+    errors::RuntimeError,
+    ssa::{
+        function_builder::FunctionBuilder,
+        ir::{
+            basic_block::BasicBlockId,
+            dfg::{CallStack, InsertInstructionResult},
+            function::{Function, FunctionId, RuntimeType},
+            instruction::{Instruction, InstructionId, TerminatorInstruction},
+            value::{Value, ValueId},
+        },
+        ssa_gen::Ssa,
     },
-    ssa_gen::Ssa,
 };
 use fxhash::FxHashMap as HashMap;
 
 /// An arbitrary limit to the maximum number of recursive call
 /// frames at any point in time.
-const RECURSION_LIMIT: u32 = 1000;
+const RECURSION_LIMIT: usize = 1000;
 
 impl Ssa {
     /// Inline all functions within the IR.
     ///
     /// In the case of recursive functions, this will attempt
-    /// to recursively inline until the RECURSION_LIMIT is reached.
+    /// to recursively inline until the RECURSION_LIMIT is reached,
+    /// at which point an error naming the cycle of recursive calls is returned.
     ///
     /// Functions are recursively inlined into main until either we finish
     /// inlining all functions or we encounter a function whose function id is not known.
@@ -41,26 +47,43 @@ impl Ssa {
     /// Currently this is just `InlineType::NoPredicates` for which we have a flag indicating
     /// whether treating that inline functions. The default is to treat these functions as entry points.
     #[tracing::instrument(level = "trace", skip(self))]
-    pub(crate) fn inline_functions(self) -> Ssa {
+    pub(crate) fn inline_functions(self) -> Result<Ssa, RuntimeError> {
         Self::inline_functions_inner(self, true)
     }
 
     // Run the inlining pass where functions marked with `InlineType::NoPredicates` as not entry points
-    pub(crate) fn inline_functions_with_no_predicates(self) -> Ssa {
+    pub(crate) fn inline_functions_with_no_predicates(self) -> Result<Ssa, RuntimeError> {
         Self::inline_functions_inner(self, false)
     }
 
-    fn inline_functions_inner(mut self, no_predicates_is_entry_point: bool) -> Ssa {
-        self.functions = btree_map(
-            get_entry_point_functions(&self, no_predicates_is_entry_point),
-            |entry_point| {
-                let new_function =
-                    InlineContext::new(&self, entry_point, no_predicates_is_entry_point)
-                        .inline_all(&self);
-                (entry_point, new_function)
-            },
-        );
-        self
+    fn inline_functions_inner(
+        mut self,
+        no_predicates_is_entry_point: bool,
+    ) -> Result<Ssa, RuntimeError> {
+        let mut functions = BTreeMap::new();
+        for entry_point in get_entry_point_functions(&self, no_predicates_is_entry_point) {
+            let new_function =
+                InlineContext::new(&self, entry_point, entry_point, no_predicates_is_entry_point)
+                    .inline_all(&self, &BTreeMap::new())?;
+            functions.insert(entry_point, new_function);
+        }
+        self.functions = functions;
+        Ok(self)
+    }
+
+    /// Creates a copy of the entry point `source` with the id `new_id`, in which each parameter
+    /// whose index is in `constant_parameters` is replaced by the given constant.
+    ///
+    /// The replaced parameters are removed from the copy's signature, so calls to it must omit them.
+    pub(super) fn specialize_entry_point(
+        &self,
+        source: FunctionId,
+        new_id: FunctionId,
+        constant_parameters: &BTreeMap<usize, FieldElement>,
+    ) -> Function {
+        InlineContext::new(self, source, new_id, true)
+            .inline_all(self, constant_parameters)
+            .expect("Calls within an entry point should already have been inlined")
     }
 }
 
@@ -70,7 +93,10 @@ impl Ssa {
 /// Doing it this way properly handles importing instructions between functions and lets us
 /// reuse the existing API at the cost of essentially cloning each of main's instructions.
 struct InlineContext {
-    recursion_level: u32,
+    /// Each function currently being inlined, along with the length of `call_stack`
+    /// once the call to it was pushed. Its length is the current recursion level.
+    inline_stack: Vec<(FunctionId, usize)>,
+
     builder: FunctionBuilder,
 
     call_stack: CallStack,
@@ -142,17 +168,20 @@ impl InlineContext {
     /// The function being inlined into will always be the main function, although it is
     /// actually a copy that is created in case the original main is still needed from a function
     /// that could not be inlined calling it.
+    ///
+    /// The inlined function is given the id `function_id`, which is usually that of the entry point itself.
     fn new(
         ssa: &Ssa,
         entry_point: FunctionId,
+        function_id: FunctionId,
         no_predicates_is_entry_point: bool,
     ) -> InlineContext {
         let source = &ssa.functions[&entry_point];
-        let mut builder = FunctionBuilder::new(source.name().to_owned(), entry_point);
+        let mut builder = FunctionBuilder::new(source.name().to_owned(), function_id);
         builder.set_runtime(source.runtime());
         Self {
             builder,
-            recursion_level: 0,
+            inline_stack: Vec::new(),
             entry_point,
             call_stack: CallStack::new(),
             no_predicates_is_entry_point,
@@ -160,7 +189,14 @@ impl InlineContext {
     }
 
     /// Start inlining the entry point function and all functions reachable from it.
-    fn inline_all(mut self, ssa: &Ssa) -> Function {
+    ///
+    /// Parameters of the entry point with an index in `constant_parameters` are replaced by the given constants
+    /// rather than being kept as parameters of the resulting function.
+    fn inline_all(
+        mut self,
+        ssa: &Ssa,
+        constant_parameters: &BTreeMap<usize, FieldElement>,
+    ) -> Result<Function, RuntimeError> {
         let entry_point = &ssa.functions[&self.entry_point];
 
         let mut context = PerFunctionContext::new(&mut self, entry_point);
@@ -172,14 +208,17 @@ impl InlineContext {
         let entry_block = context.context.builder.current_function.entry_block();
         let original_parameters = context.source_function.parameters();
 
-        for parameter in original_parameters {
+        for (index, parameter) in original_parameters.iter().enumerate() {
             let typ = context.source_function.dfg.type_of_value(*parameter);
-            let new_parameter = context.context.builder.add_block_parameter(entry_block, typ);
-            context.values.insert(*parameter, new_parameter);
+            let new_value = match constant_parameters.get(&index) {
+                Some(constant) => context.context.builder.numeric_constant(*constant, typ),
+                None => context.context.builder.add_block_parameter(entry_block, typ),
+            };
+            context.values.insert(*parameter, new_value);
         }
 
         context.blocks.insert(context.source_function.entry_block(), entry_block);
-        context.inline_blocks(ssa);
+        context.inline_blocks(ssa)?;
         // translate databus values
         let databus = entry_point.dfg.data_bus.map_values(|t| context.translate_value(t));
 
@@ -188,7 +227,7 @@ impl InlineContext {
         assert_eq!(new_ssa.functions.len(), 1);
         let mut new_func = new_ssa.functions.pop_first().unwrap().1;
         new_func.dfg.data_bus = databus;
-        new_func
+        Ok(new_func)
     }
 
     /// Inlines a function into the current function and returns the translated return values
@@ -198,13 +237,11 @@ impl InlineContext {
         ssa: &Ssa,
         id: FunctionId,
         arguments: &[ValueId],
-    ) -> Vec<ValueId> {
-        self.recursion_level += 1;
+    ) -> Result<Vec<ValueId>, RuntimeError> {
+        self.inline_stack.push((id, self.call_stack.len()));
 
-        if self.recursion_level > RECURSION_LIMIT {
-            panic!(
-                "Attempted to recur more than {RECURSION_LIMIT} times during function inlining."
-            );
+        if self.inline_stack.len() > RECURSION_LIMIT {
+            return Err(self.recursion_limit_error(ssa));
         }
 
         let source_function = &ssa.functions[&id];
@@ -217,9 +254,29 @@ impl InlineContext {
         let current_block = context.context.builder.current_block();
         context.blocks.insert(source_function.entry_block(), current_block);
 
-        let return_values = context.inline_blocks(ssa);
-        self.recursion_level -= 1;
-        return_values
+        let return_values = context.inline_blocks(ssa)?;
+        self.inline_stack.pop();
+        Ok(return_values)
+    }
+
+    /// Builds the error for a function which recursed past the RECURSION_LIMIT.
+    ///
+    /// The error names the first cycle of calls leading back to that function and points
+    /// at the call which closed it, rather than at the call made RECURSION_LIMIT frames deep.
+    fn recursion_limit_error(&self, ssa: &Ssa) -> RuntimeError {
+        let (function, _) = *self.inline_stack.last().expect("Expected a function being inlined");
+        let mut occurrences =
+            self.inline_stack.iter().enumerate().filter(|(_, (id, _))| *id == function);
+        let (start, _) = occurrences.next().expect("Expected the function to be on the stack");
+        let (end, (_, call_stack_len)) =
+            occurrences.next().expect("Expected the function to recur");
+
+        let cycle = vecmap(&self.inline_stack[start..=end], |(id, _)| {
+            format!("`{}`", ssa.functions[id].name())
+        });
+        let mut call_stack = self.call_stack.clone();
+        call_stack.truncate(*call_stack_len);
+        RuntimeError::RecursionLimitExceeded { cycle: cycle.join(" -> "), call_stack }
     }
 }
 
@@ -323,7 +380,7 @@ impl<'function> PerFunctionContext<'function> {
     }
 
     /// Inline all reachable blocks within the source_function into the destination function.
-    fn inline_blocks(&mut self, ssa: &Ssa) -> Vec<ValueId> {
+    fn inline_blocks(&mut self, ssa: &Ssa) -> Result<Vec<ValueId>, RuntimeError> {
         let mut seen_blocks = HashSet::new();
         let mut block_queue = vec![self.source_function.entry_block()];
 
@@ -339,7 +396,7 @@ impl<'function> PerFunctionContext<'function> {
             self.context.builder.switch_to_block(translated_block_id);
 
             seen_blocks.insert(source_block_id);
-            self.inline_block_instructions(ssa, source_block_id);
+            self.inline_block_instructions(ssa, source_block_id)?;
 
             if let Some((block, values)) =
                 self.handle_terminator_instruction(source_block_id, &mut block_queue)
@@ -348,7 +405,7 @@ impl<'function> PerFunctionContext<'function> {
             }
         }
 
-        self.handle_function_returns(function_returns)
+        Ok(self.handle_function_returns(function_returns))
     }
 
     /// Handle inlining a function's possibly multiple return instructions.
@@ -384,7 +441,11 @@ impl<'function> PerFunctionContext<'function> {
 
     /// Inline each instruction in the given block into the function being inlined into.
     /// This may recurse if it finds another function to inline if a call instruction is within this block.
-    fn inline_block_instructions(&mut self, ssa: &Ssa, block_id: BasicBlockId) {
+    fn inline_block_instructions(
+        &mut self,
+        ssa: &Ssa,
+        block_id: BasicBlockId,
+    ) -> Result<(), RuntimeError> {
         let block = &self.source_function.dfg[block_id];
         for id in block.instructions() {
             match &self.source_function.dfg[*id] {
@@ -394,14 +455,21 @@ impl<'function> PerFunctionContext<'function> {
                         // If we have not already finished the flattening pass, functions marked
                         // to not have predicates should be marked as entry points unless we are inlining into brillig.
                         let entry_point = &ssa.functions[&self.context.entry_point];
+                        let inlining_into_brillig =
+                            matches!(entry_point.runtime(), RuntimeType::Brillig(_));
                         let no_predicates_is_entry_point =
                             self.context.no_predicates_is_entry_point
                                 && function.is_no_predicates()
-                                && !matches!(entry_point.runtime(), RuntimeType::Brillig);
-                        if function.runtime().is_entry_point() || no_predicates_is_entry_point {
+                                && !inlining_into_brillig;
+                        // Unconstrained functions marked `#[inline(always)]` are still called from
+                        // constrained code, but are inlined into other unconstrained functions.
+                        let is_entry_point = function.runtime().is_entry_point()
+                            && !(inlining_into_brillig
+                                && function.runtime().is_inline_always_brillig());
+                        if is_entry_point || no_predicates_is_entry_point {
                             self.push_instruction(*id);
                         } else {
-                            self.inline_function(ssa, *id, func_id, arguments);
+                            self.inline_function(ssa, *id, func_id, arguments)?;
                         }
                     }
                     None => self.push_instruction(*id),
@@ -409,6 +477,7 @@ impl<'function> PerFunctionContext<'function> {
                 _ => self.push_instruction(*id),
             }
         }
+        Ok(())
     }
 
     /// Inline a function call and remember the inlined return values in the values map
@@ -418,7 +487,7 @@ impl<'function> PerFunctionContext<'function> {
         call_id: InstructionId,
         function: FunctionId,
         arguments: &[ValueId],
-    ) {
+    ) -> Result<(), RuntimeError> {
         let old_results = self.source_function.dfg.instruction_results(call_id);
         let arguments = vecmap(arguments, |arg| self.translate_value(*arg));
 
@@ -430,7 +499,7 @@ impl<'function> PerFunctionContext<'function> {
             self.context.call_stack.push_back(location);
         }
 
-        let new_results = self.context.inline_function(ssa, function, &arguments);
+        let new_results = self.context.inline_function(ssa, function, &arguments)?;
 
         if has_location {
             self.context.call_stack.pop_back();
@@ -438,6 +507,7 @@ impl<'function> PerFunctionContext<'function> {
 
         let new_results = InsertInstructionResult::Results(call_id, &new_results);
         Self::insert_new_instruction_results(&mut self.values, old_results, new_results);
+        Ok(())
     }
 
     /// Push the given instruction from the source_function into the current block of the
@@ -569,7 +639,7 @@ mod test {
         function_builder::FunctionBuilder,
         ir::{
             basic_block::BasicBlockId,
-            instruction::{BinaryOp, Intrinsic, TerminatorInstruction},
+            instruction::{BinaryOp, Instruction, Intrinsic, TerminatorInstruction},
             map::Id,
             types::Type,
         },
@@ -602,7 +672,7 @@ mod test {
         let ssa = builder.finish();
         assert_eq!(ssa.functions.len(), 2);
 
-        let inlined = ssa.inline_functions();
+        let inlined = ssa.inline_functions().unwrap();
         assert_eq!(inlined.functions.len(), 1);
     }
 
@@ -668,7 +738,7 @@ mod test {
         let ssa = builder.finish();
         assert_eq!(ssa.functions.len(), 4);
 
-        let inlined = ssa.inline_functions();
+        let inlined = ssa.inline_functions().unwrap();
         assert_eq!(inlined.functions.len(), 1);
     }
 
@@ -742,7 +812,7 @@ mod test {
         //   b6():
         //     return Field 120
         // }
-        let inlined = ssa.inline_functions();
+        let inlined = ssa.inline_functions().unwrap();
         assert_eq!(inlined.functions.len(), 1);
 
         let main = inlined.main();
@@ -825,7 +895,7 @@ mod test {
         builder.switch_to_block(join_block);
         builder.terminate_with_return(vec![join_param]);
 
-        let ssa = builder.finish().inline_functions();
+        let ssa = builder.finish().inline_functions().unwrap();
         // Expected result:
         // fn main f3 {
         //   b0(v0: u1):
@@ -841,4 +911,80 @@ mod test {
         let main = ssa.main();
         assert_eq!(main.reachable_blocks().len(), 4);
     }
+
+    #[test]
+    fn inline_always_brillig_functions_are_only_inlined_into_brillig() {
+        // acir(inline) fn main f0 {
+        //   b0(v0: Field):
+        //     v1 = call f1(v0)
+        //     v2 = call f2(v1)
+        //     return v2
+        // }
+        // brillig fn outer f1 {
+        //   b0(v0: Field):
+        //     v1 = call f2(v0)
+        //     v2 = call f3(v1)
+        //     return v2
+        // }
+        // brillig(inline_always) fn double f2 {
+        //   b0(v0: Field):
+        //     v1 = add v0, v0
+        //     return v1
+        // }
+        // brillig fn square f3 {
+        //   b0(v0: Field):
+        //     v1 = mul v0, v0
+        //     return v1
+        // }
+        let main_id = Id::test_new(0);
+        let outer_id = Id::test_new(1);
+        let double_id = Id::test_new(2);
+        let square_id = Id::test_new(3);
+
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::field());
+        let outer = builder.import_function(outer_id);
+        let double = builder.import_function(double_id);
+        let v1 = builder.insert_call(outer, vec![v0], vec![Type::field()])[0];
+        let v2 = builder.insert_call(double, vec![v1], vec![Type::field()])[0];
+        builder.terminate_with_return(vec![v2]);
+
+        builder.new_brillig_function("outer".into(), outer_id, InlineType::default());
+        let v0 = builder.add_parameter(Type::field());
+        let double = builder.import_function(double_id);
+        let square = builder.import_function(square_id);
+        let v1 = builder.insert_call(double, vec![v0], vec![Type::field()])[0];
+        let v2 = builder.insert_call(square, vec![v1], vec![Type::field()])[0];
+        builder.terminate_with_return(vec![v2]);
+
+        builder.new_brillig_function("double".into(), double_id, InlineType::InlineAlways);
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.insert_binary(v0, BinaryOp::Add, v0);
+        builder.terminate_with_return(vec![v1]);
+
+        builder.new_brillig_function("square".into(), square_id, InlineType::default());
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.insert_binary(v0, BinaryOp::Mul, v0);
+        builder.terminate_with_return(vec![v1]);
+
+        let ssa = builder.finish().inline_functions().unwrap();
+        let calls = |name: &str| {
+            let function = ssa
+                .functions
+                .values()
+                .find(|function| function.name() == name)
+                .expect("Expected function to still exist");
+            let instructions = function.dfg[function.entry_block()].instructions();
+            instructions
+                .iter()
+                .filter(|instruction| {
+                    matches!(function.dfg[**instruction], Instruction::Call { .. })
+                })
+                .count()
+        };
+
+        // Constrained code still calls `double`, while `outer` has it inlined and only calls `square`.
+        assert_eq!(calls("main"), 2);
+        assert_eq!(calls("outer"), 1);
+    }
 }
@@ -13,9 +13,12 @@ mod die;
 pub(crate) mod flatten_cfg;
 mod inlining;
 mod mem2reg;
+mod public_divisors;
 mod rc;
+mod redundant_constraints;
 mod remove_bit_shifts;
 mod remove_enable_side_effects;
 mod remove_if_else;
 mod simplify_cfg;
+mod specialize;
 mod unrolling;
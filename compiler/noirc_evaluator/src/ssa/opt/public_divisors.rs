@@ -0,0 +1,164 @@
+//! Finds divisions in `main` whose divisor is derived only from public inputs and is never
+//! constrained to be non-zero, such as `x / y` in `fn main(x: Field, y: pub Field)`.
+//!
+//! Public inputs are chosen by the verifier, so nothing stops them from making such a divisor zero.
+//! This is a heuristic lint rather than an optimization: nothing is changed, only warnings are reported.
+//!
+//! A value is derived only from public inputs if it is a public parameter of `main`, or the result
+//! of an instruction whose operands are all constants or derived only from public inputs.
+//! A divisor is considered checked when it is constrained to be non-zero anywhere in `main`, as in
+//! `assert(y != 0)`.
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::{
+    errors::{InternalWarning, SsaReport},
+    ssa::{
+        ir::{
+            dfg::DataFlowGraph,
+            function::{Function, RuntimeType},
+            instruction::{Binary, BinaryOp, Instruction, InstructionId},
+            value::{Value, ValueId},
+        },
+        ssa_gen::Ssa,
+    },
+};
+
+impl Ssa {
+    /// Reports a warning for each division in `main` by a value derived only from public inputs
+    /// which is not constrained to be non-zero.
+    ///
+    /// `public_parameters` holds the name of the public parameter each parameter of `main` comes
+    /// from, or `None` for private parameters.
+    ///
+    /// See [`public_divisors`][self] module for more information.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub(crate) fn check_public_divisors(
+        self,
+        public_parameters: &[Option<String>],
+        warnings: &mut Vec<SsaReport>,
+    ) -> Ssa {
+        let main = self.main();
+        if matches!(main.runtime(), RuntimeType::Acir(_)) {
+            let unchecked = find_unchecked_public_divisors(main, public_parameters);
+            warnings.extend(unchecked.into_iter().map(|(division, parameter)| {
+                SsaReport::Warning(InternalWarning::UncheckedPublicDivisor {
+                    parameter,
+                    call_stack: main.dfg.get_call_stack(division),
+                })
+            }));
+        }
+        self
+    }
+}
+
+/// Returns each division in `function` whose divisor is derived only from public parameters and
+/// is not constrained to be non-zero, along with the name of a public parameter it is derived from.
+fn find_unchecked_public_divisors(
+    function: &Function,
+    public_parameters: &[Option<String>],
+) -> Vec<(InstructionId, String)> {
+    let dfg = &function.dfg;
+
+    // Maps each value derived only from public parameters to one of the parameters it is derived from.
+    let mut public_sources: HashMap<ValueId, String> = function
+        .parameters()
+        .iter()
+        .zip(public_parameters)
+        .filter_map(|(parameter, name)| Some((*parameter, name.clone()?)))
+        .collect();
+    let mut non_zero = HashSet::default();
+    let mut divisions = Vec::new();
+
+    for block in function.reachable_blocks() {
+        for instruction_id in dfg[block].instructions() {
+            let instruction = &dfg[*instruction_id];
+            match instruction {
+                Instruction::Binary(Binary {
+                    rhs,
+                    operator: BinaryOp::Div | BinaryOp::Mod,
+                    ..
+                }) => {
+                    divisions.push((*instruction_id, dfg.resolve(*rhs)));
+                }
+                Instruction::Constrain(lhs, rhs, _) => {
+                    non_zero.extend(constrained_non_zero(dfg, *lhs, *rhs));
+                }
+                _ => (),
+            }
+
+            if let Some(source) = public_source(dfg, instruction, &public_sources) {
+                for result in dfg.instruction_results(*instruction_id) {
+                    public_sources.insert(*result, source.clone());
+                }
+            }
+        }
+    }
+
+    divisions
+        .into_iter()
+        .filter(|(_, divisor)| !non_zero.contains(divisor))
+        .filter_map(|(division, divisor)| Some((division, public_sources.get(&divisor)?.clone())))
+        .collect()
+}
+
+/// Returns a public parameter the results of `instruction` are derived from, if they are derived
+/// only from public parameters.
+fn public_source(
+    dfg: &DataFlowGraph,
+    instruction: &Instruction,
+    public_sources: &HashMap<ValueId, String>,
+) -> Option<String> {
+    let mut source = None;
+    let mut only_public = true;
+    instruction.for_each_value(|value| {
+        let value = dfg.resolve(value);
+        match &dfg[value] {
+            Value::NumericConstant { .. }
+            | Value::Function(_)
+            | Value::Intrinsic(_)
+            | Value::ForeignFunction(_) => (),
+            _ => match public_sources.get(&value) {
+                Some(name) => {
+                    source.get_or_insert_with(|| name.clone());
+                }
+                None => only_public = false,
+            },
+        }
+    });
+    source.filter(|_| only_public)
+}
+
+/// Returns the value which `constrain lhs == rhs` establishes to be non-zero, if any.
+///
+/// This recognizes `constrain (eq x, 0) == u1 0`, along with any number of negations of the
+/// equality such as in `constrain (not (eq x, 0)) == u1 1`.
+fn constrained_non_zero(dfg: &DataFlowGraph, lhs: ValueId, rhs: ValueId) -> Option<ValueId> {
+    let (lhs, rhs) = (dfg.resolve(lhs), dfg.resolve(rhs));
+    let (mut condition, expected) =
+        match (dfg.get_numeric_constant(lhs), dfg.get_numeric_constant(rhs)) {
+            (None, Some(constant)) => (lhs, constant),
+            (Some(constant), None) => (rhs, constant),
+            _ => return None,
+        };
+    let mut expected_true = !expected.is_zero();
+    let is_zero = |value| dfg.get_numeric_constant(value).is_some_and(|value| value.is_zero());
+
+    while let Value::Instruction { instruction, .. } = &dfg[condition] {
+        match &dfg[*instruction] {
+            Instruction::Not(value) => {
+                condition = dfg.resolve(*value);
+                expected_true = !expected_true;
+            }
+            Instruction::Binary(Binary { lhs, rhs, operator: BinaryOp::Eq }) if !expected_true => {
+                let (lhs, rhs) = (dfg.resolve(*lhs), dfg.resolve(*rhs));
+                return match (is_zero(lhs), is_zero(rhs)) {
+                    (false, true) => Some(lhs),
+                    (true, false) => Some(rhs),
+                    _ => None,
+                };
+            }
+            _ => return None,
+        }
+    }
+    None
+}
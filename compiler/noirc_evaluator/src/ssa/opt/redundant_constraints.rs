@@ -0,0 +1,416 @@
+//! Finds constraints which are implied by earlier constraints in the same block, such as the
+//! second constraint in `assert(x < 10); assert(x < 20);`.
+//!
+//! Each block is walked in order while tracking what the constraints seen so far have established:
+//! - lower and upper bounds for unsigned integers compared against constants, and
+//! - which values have been constrained to be equal to each other, so that bounds on one of them
+//!   apply to all of them.
+//!
+//! A constraint is only reported, and optionally removed, when these facts prove that it holds
+//! whenever all earlier constraints in the block hold. Constraints of any other form are left
+//! untouched and add nothing to the facts.
+use fxhash::FxHashMap as HashMap;
+
+use crate::{
+    errors::{InternalWarning, SsaReport},
+    ssa::{
+        ir::{
+            dfg::DataFlowGraph,
+            function::Function,
+            instruction::{Binary, BinaryOp, Instruction, InstructionId},
+            types::{NumericType, Type},
+            value::{Value, ValueId},
+        },
+        ssa_gen::Ssa,
+    },
+};
+
+impl Ssa {
+    /// Reports a warning for each constraint which is implied by an earlier constraint in the same block
+    /// if `warn` is set, and removes these constraints if `remove` is set.
+    ///
+    /// See [`redundant_constraints`][self] module for more information.
+    #[tracing::instrument(level = "trace", skip(self, warnings))]
+    pub(crate) fn check_redundant_constraints(
+        mut self,
+        warn: bool,
+        remove: bool,
+        warnings: &mut Vec<SsaReport>,
+    ) -> Ssa {
+        if !warn && !remove {
+            return self;
+        }
+        for function in self.functions.values_mut() {
+            let redundant = find_redundant_constraints(function);
+            if warn {
+                warnings.extend(redundant.iter().map(|(constraint, implied_by)| {
+                    SsaReport::Warning(InternalWarning::RedundantConstraint {
+                        call_stack: function.dfg.get_call_stack(*constraint),
+                        implied_by: function.dfg.get_call_stack(*implied_by),
+                    })
+                }));
+            }
+            if remove {
+                for block in function.reachable_blocks() {
+                    function.dfg[block]
+                        .instructions_mut()
+                        .retain(|instruction| !redundant.contains_key(instruction));
+                }
+            }
+        }
+        self
+    }
+}
+
+/// Returns each constraint in `function` which is implied by earlier constraints in its block,
+/// mapped to the earlier constraint which implies it.
+fn find_redundant_constraints(function: &Function) -> HashMap<InstructionId, InstructionId> {
+    let mut redundant = HashMap::default();
+    for block in function.reachable_blocks() {
+        let mut facts = Facts::default();
+        for instruction in function.dfg[block].instructions() {
+            let Instruction::Constrain(lhs, rhs, _) = &function.dfg[*instruction] else {
+                continue;
+            };
+            let Some(fact) = Fact::from_constraint(&function.dfg, *lhs, *rhs) else {
+                continue;
+            };
+            match facts.implied_by(&fact) {
+                Some(implied_by) => {
+                    redundant.insert(*instruction, implied_by);
+                }
+                None => facts.insert(fact, *instruction),
+            }
+        }
+    }
+    redundant
+}
+
+/// What a single constraint establishes about the values it constrains.
+#[derive(Debug)]
+enum Fact {
+    /// The unsigned integer is at most the given constant.
+    AtMost(ValueId, u128),
+    /// The unsigned integer is at least the given constant.
+    AtLeast(ValueId, u128),
+    /// The unsigned integer is equal to the given constant.
+    EqualTo(ValueId, u128),
+    /// The two values are equal.
+    Equal(ValueId, ValueId),
+}
+
+impl Fact {
+    /// Returns the fact established by `constrain lhs == rhs`, if it is one which is tracked.
+    fn from_constraint(dfg: &DataFlowGraph, lhs: ValueId, rhs: ValueId) -> Option<Fact> {
+        let (lhs, rhs) = (dfg.resolve(lhs), dfg.resolve(rhs));
+        let (value, constant) = match (unsigned_constant(dfg, lhs), unsigned_constant(dfg, rhs)) {
+            (Some(_), Some(_)) => return None,
+            (None, None) => {
+                let is_constant = |value| dfg.get_numeric_constant(value).is_some();
+                return (!is_constant(lhs) && !is_constant(rhs)).then_some(Fact::Equal(lhs, rhs));
+            }
+            (Some(constant), None) => (rhs, constant),
+            (None, Some(constant)) => (lhs, constant),
+        };
+
+        Self::from_comparison(dfg, value, constant)
+            .or_else(|| is_unsigned(dfg, value).then_some(Fact::EqualTo(value, constant)))
+    }
+
+    /// Returns the bound established by constraining `value` to equal `constant`
+    /// if `value` is the result of comparing an unsigned integer against a constant.
+    fn from_comparison(dfg: &DataFlowGraph, value: ValueId, constant: u128) -> Option<Fact> {
+        let Value::Instruction { instruction, .. } = &dfg[value] else {
+            return None;
+        };
+        let Instruction::Binary(Binary { lhs, rhs, operator: BinaryOp::Lt }) = &dfg[*instruction]
+        else {
+            return None;
+        };
+        let (lhs, rhs) = (dfg.resolve(*lhs), dfg.resolve(*rhs));
+        let holds = match constant {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        match (unsigned_constant(dfg, lhs), unsigned_constant(dfg, rhs), holds) {
+            // `lhs < bound`
+            (None, Some(bound), true) => Some(Fact::AtMost(lhs, bound.checked_sub(1)?)),
+            // `lhs >= bound`
+            (None, Some(bound), false) => Some(Fact::AtLeast(lhs, bound)),
+            // `bound < rhs`
+            (Some(bound), None, true) => Some(Fact::AtLeast(rhs, bound.checked_add(1)?)),
+            // `bound >= rhs`
+            (Some(bound), None, false) => Some(Fact::AtMost(rhs, bound)),
+            _ => None,
+        }
+    }
+}
+
+/// Returns true if `value` is an unsigned integer, which are the only values bounds are tracked for.
+fn is_unsigned(dfg: &DataFlowGraph, value: ValueId) -> bool {
+    matches!(dfg.type_of_value(value), Type::Numeric(NumericType::Unsigned { .. }))
+}
+
+fn unsigned_constant(dfg: &DataFlowGraph, value: ValueId) -> Option<u128> {
+    if !is_unsigned(dfg, value) {
+        return None;
+    }
+    dfg.get_numeric_constant(value)?.try_into_u128()
+}
+
+/// A bound on a value along with the constraint which established it.
+#[derive(Debug, Clone, Copy)]
+struct Bound {
+    value: u128,
+    source: InstructionId,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Bounds {
+    lower: Option<Bound>,
+    upper: Option<Bound>,
+}
+
+impl Bounds {
+    fn raise_lower(&mut self, value: u128, source: InstructionId) {
+        if self.lower.map_or(true, |lower| value > lower.value) {
+            self.lower = Some(Bound { value, source });
+        }
+    }
+
+    fn lower_upper(&mut self, value: u128, source: InstructionId) {
+        if self.upper.map_or(true, |upper| value < upper.value) {
+            self.upper = Some(Bound { value, source });
+        }
+    }
+
+    /// Returns the value and the constraint which established it if these bounds only allow a single value.
+    fn exact(&self) -> Option<(u128, InstructionId)> {
+        match (self.lower, self.upper) {
+            (Some(lower), Some(upper)) if lower.value == upper.value => {
+                Some((upper.value, upper.source))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The facts established by the constraints seen so far in a block.
+#[derive(Default)]
+struct Facts {
+    /// Links each value constrained to be equal to another value towards the representative of its class.
+    equal_to: HashMap<ValueId, ValueId>,
+    /// The constraint which last joined each class of equal values, indexed by its representative.
+    equality_sources: HashMap<ValueId, InstructionId>,
+    /// The bounds on each class of equal values, indexed by its representative.
+    bounds: HashMap<ValueId, Bounds>,
+}
+
+impl Facts {
+    fn representative(&self, mut value: ValueId) -> ValueId {
+        while let Some(next) = self.equal_to.get(&value) {
+            value = *next;
+        }
+        value
+    }
+
+    fn bounds(&self, value: ValueId) -> Bounds {
+        self.bounds.get(&self.representative(value)).copied().unwrap_or_default()
+    }
+
+    /// Returns the earlier constraint which implies `fact`, if any.
+    fn implied_by(&self, fact: &Fact) -> Option<InstructionId> {
+        match fact {
+            Fact::AtMost(value, bound) => {
+                self.bounds(*value).upper.filter(|upper| upper.value <= *bound).map(|b| b.source)
+            }
+            Fact::AtLeast(value, bound) => {
+                self.bounds(*value).lower.filter(|lower| lower.value >= *bound).map(|b| b.source)
+            }
+            Fact::EqualTo(value, constant) => self
+                .bounds(*value)
+                .exact()
+                .filter(|(exact, _)| exact == constant)
+                .map(|(_, source)| source),
+            Fact::Equal(lhs, rhs) => {
+                let (lhs, rhs) = (self.representative(*lhs), self.representative(*rhs));
+                if lhs == rhs {
+                    return self.equality_sources.get(&lhs).copied();
+                }
+                match (self.bounds(lhs).exact(), self.bounds(rhs).exact()) {
+                    (Some((lhs, _)), Some((rhs, source))) if lhs == rhs => Some(source),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Records the fact established by the `source` constraint.
+    fn insert(&mut self, fact: Fact, source: InstructionId) {
+        match fact {
+            Fact::AtMost(value, bound) => {
+                let value = self.representative(value);
+                self.bounds.entry(value).or_default().lower_upper(bound, source);
+            }
+            Fact::AtLeast(value, bound) => {
+                let value = self.representative(value);
+                self.bounds.entry(value).or_default().raise_lower(bound, source);
+            }
+            Fact::EqualTo(value, constant) => {
+                let value = self.representative(value);
+                let bounds = self.bounds.entry(value).or_default();
+                bounds.raise_lower(constant, source);
+                bounds.lower_upper(constant, source);
+            }
+            Fact::Equal(lhs, rhs) => {
+                let (lhs, rhs) = (self.representative(lhs), self.representative(rhs));
+                self.equal_to.insert(rhs, lhs);
+                self.equality_sources.insert(lhs, source);
+                // The bounds of both classes now apply to the joined class.
+                if let Some(rhs_bounds) = self.bounds.remove(&rhs) {
+                    let bounds = self.bounds.entry(lhs).or_default();
+                    if let Some(lower) = rhs_bounds.lower {
+                        bounds.raise_lower(lower.value, lower.source);
+                    }
+                    if let Some(upper) = rhs_bounds.upper {
+                        bounds.lower_upper(upper.value, upper.source);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{
+            instruction::{BinaryOp, Instruction},
+            map::Id,
+            types::Type,
+        },
+        ssa_gen::Ssa,
+    };
+
+    /// Runs the pass over `ssa`, returning the number of warnings and the constraints which remain.
+    fn check(ssa: Ssa, remove: bool) -> (usize, usize) {
+        let mut warnings = Vec::new();
+        let ssa = ssa.check_redundant_constraints(true, remove, &mut warnings);
+        let main = ssa.main();
+        let constraints = main.dfg[main.entry_block()]
+            .instructions()
+            .iter()
+            .filter(|instruction| matches!(main.dfg[**instruction], Instruction::Constrain(..)))
+            .count();
+        (warnings.len(), constraints)
+    }
+
+    #[test]
+    fn interval_implied_by_earlier_bound() {
+        // fn main f0 {
+        //   b0(v0: u32):
+        //     v1 = lt v0, u32 10
+        //     constrain v1 == u1 1
+        //     v2 = lt v0, u32 20
+        //     constrain v2 == u1 1
+        //     v3 = lt u32 2, v0
+        //     constrain v3 == u1 1
+        //     v4 = lt v0, u32 2
+        //     constrain v4 == u1 0
+        //     return
+        // }
+        let build = || {
+            let mut builder = FunctionBuilder::new("main".into(), Id::test_new(0));
+            let v0 = builder.add_parameter(Type::unsigned(32));
+            let one = builder.numeric_constant(1u128, Type::bool());
+            let zero = builder.numeric_constant(0u128, Type::bool());
+            let cases: [(Option<u128>, Option<u128>, _); 4] = [
+                (None, Some(10), one),
+                (None, Some(20), one),
+                (Some(2), None, one),
+                (None, Some(2), zero),
+            ];
+            for (lhs, rhs, holds) in cases {
+                let mut operand = |constant: Option<u128>| match constant {
+                    Some(constant) => builder.numeric_constant(constant, Type::unsigned(32)),
+                    None => v0,
+                };
+                let (lhs, rhs) = (operand(lhs), operand(rhs));
+                let comparison = builder.insert_binary(lhs, BinaryOp::Lt, rhs);
+                builder.insert_constrain(comparison, holds, None);
+            }
+            builder.terminate_with_return(vec![]);
+            builder.finish()
+        };
+
+        // `v0 < 20` is implied by `v0 < 10`, and `v0 >= 2` is implied by `2 < v0`.
+        assert_eq!(check(build(), false), (2, 4));
+        assert_eq!(check(build(), true), (2, 2));
+    }
+
+    #[test]
+    fn bounds_propagate_through_equalities() {
+        // fn main f0 {
+        //   b0(v0: u32, v1: u32, v2: u32):
+        //     constrain v0 == v1
+        //     v3 = lt v0, u32 10
+        //     constrain v3 == u1 1
+        //     v4 = lt v1, u32 20
+        //     constrain v4 == u1 1
+        //     constrain v1 == v2
+        //     constrain v2 == v0
+        //     return
+        // }
+        let build = || {
+            let mut builder = FunctionBuilder::new("main".into(), Id::test_new(0));
+            let v0 = builder.add_parameter(Type::unsigned(32));
+            let v1 = builder.add_parameter(Type::unsigned(32));
+            let v2 = builder.add_parameter(Type::unsigned(32));
+            let one = builder.numeric_constant(1u128, Type::bool());
+            let ten = builder.numeric_constant(10u128, Type::unsigned(32));
+            let twenty = builder.numeric_constant(20u128, Type::unsigned(32));
+            builder.insert_constrain(v0, v1, None);
+            let v3 = builder.insert_binary(v0, BinaryOp::Lt, ten);
+            builder.insert_constrain(v3, one, None);
+            let v4 = builder.insert_binary(v1, BinaryOp::Lt, twenty);
+            builder.insert_constrain(v4, one, None);
+            builder.insert_constrain(v1, v2, None);
+            builder.insert_constrain(v2, v0, None);
+            builder.terminate_with_return(vec![]);
+            builder.finish()
+        };
+
+        // `v1 < 20` follows from `v0 == v1` and `v0 < 10`, and `v2 == v0` from `v0 == v1 == v2`.
+        assert_eq!(check(build(), false), (2, 5));
+        assert_eq!(check(build(), true), (2, 3));
+    }
+
+    #[test]
+    fn constraints_which_are_not_implied_are_kept() {
+        // fn main f0 {
+        //   b0(v0: u32, v1: u32):
+        //     v2 = lt v0, u32 20
+        //     constrain v2 == u1 1
+        //     v3 = lt v0, u32 10
+        //     constrain v3 == u1 1
+        //     v4 = lt v1, u32 5
+        //     constrain v4 == u1 1
+        //     return
+        // }
+        let mut builder = FunctionBuilder::new("main".into(), Id::test_new(0));
+        let v0 = builder.add_parameter(Type::unsigned(32));
+        let v1 = builder.add_parameter(Type::unsigned(32));
+        let one = builder.numeric_constant(1u128, Type::bool());
+        for (value, bound) in [(v0, 20u128), (v0, 10), (v1, 5)] {
+            let bound = builder.numeric_constant(bound, Type::unsigned(32));
+            let comparison = builder.insert_binary(value, BinaryOp::Lt, bound);
+            builder.insert_constrain(comparison, one, None);
+        }
+        builder.terminate_with_return(vec![]);
+
+        // Each constraint is tighter than, or unrelated to, the ones before it.
+        assert_eq!(check(builder.finish(), true), (0, 3));
+    }
+}
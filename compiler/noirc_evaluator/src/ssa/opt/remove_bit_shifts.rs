@@ -30,7 +30,7 @@ impl Ssa {
 /// The structure of this pass is simple:
 /// Go through each block and re-insert all instructions.
 fn remove_bit_shifts(function: &mut Function) {
-    if let RuntimeType::Brillig = function.runtime() {
+    if let RuntimeType::Brillig(_) = function.runtime() {
         return;
     }
 
@@ -103,11 +103,9 @@ impl Context<'_> {
 
             let (rhs_bit_size_pow_2, overflows) = 2_u128.overflowing_pow(bit_shift_size);
             if overflows {
-                assert!(bit_size < 128, "ICE - shift left with big integers are not supported");
-                if bit_size < 128 {
-                    let zero = self.numeric_constant(FieldElement::zero(), typ);
-                    return InsertInstructionResult::SimplifiedTo(zero).first();
-                }
+                // The shift is by at least 128 bits, which shifts every bit out of the integer.
+                let zero = self.numeric_constant(FieldElement::zero(), typ);
+                return InsertInstructionResult::SimplifiedTo(zero).first();
             }
             let pow = self.numeric_constant(FieldElement::from(rhs_bit_size_pow_2), typ.clone());
 
@@ -129,6 +127,8 @@ impl Context<'_> {
 
         if max_bit <= bit_size {
             self.insert_binary(lhs, BinaryOp::Mul, pow)
+        } else if 2 * bit_size >= FieldElement::max_num_bits() {
+            self.insert_wide_wrapping_mul(lhs, pow, bit_size)
         } else {
             let lhs_field = self.insert_cast(lhs, Type::field());
             let pow_field = self.insert_cast(pow, Type::field());
@@ -138,6 +138,35 @@ impl Context<'_> {
         }
     }
 
+    /// Insert ssa instructions which compute `lhs * pow` truncated to `bit_size` bits, for integers
+    /// so wide that the product may exceed the field modulus.
+    ///
+    /// `lhs` is split into a low and a high half, each of which can be multiplied by `pow < 2^bit_size`
+    /// without wrapping around and truncated separately.
+    fn insert_wide_wrapping_mul(&mut self, lhs: ValueId, pow: ValueId, bit_size: u32) -> ValueId {
+        let typ = self.function.dfg.type_of_value(lhs);
+        let half_bit_size = bit_size / 2;
+        let half_base = self.field_constant(FieldElement::from(1_u128 << half_bit_size));
+
+        let lhs = self.insert_cast(lhs, Type::field());
+        let pow = self.insert_cast(pow, Type::field());
+        let low = self.insert_truncate(lhs, half_bit_size, bit_size);
+        let high = self.insert_binary(lhs, BinaryOp::Sub, low);
+        let high = self.insert_binary(high, BinaryOp::Div, half_base);
+
+        // low * pow mod 2^bit_size
+        let low = self.insert_binary(low, BinaryOp::Mul, pow);
+        let low = self.insert_truncate(low, bit_size, bit_size + half_bit_size);
+        // high * 2^half_bit_size * pow mod 2^bit_size
+        let high = self.insert_binary(high, BinaryOp::Mul, pow);
+        let high = self.insert_truncate(high, half_bit_size, bit_size + half_bit_size);
+        let high = self.insert_binary(high, BinaryOp::Mul, half_base);
+
+        let result = self.insert_binary(low, BinaryOp::Add, high);
+        let result = self.insert_truncate(result, bit_size, bit_size + 1);
+        self.insert_cast(result, typ)
+    }
+
     /// Insert ssa instructions which computes lhs >> rhs by doing lhs/2^rhs
     pub(crate) fn insert_shift_right(
         &mut self,
@@ -150,6 +179,9 @@ impl Context<'_> {
         // we can safely cast to unsigned because overflow_checks prevent bit-shift with a negative value
         let rhs_unsigned = self.insert_cast(rhs, Type::unsigned(bit_size));
         let pow = self.pow(base, rhs_unsigned);
+        if 2 * bit_size >= FieldElement::max_num_bits() {
+            return self.insert_wide_shift_right(lhs, rhs, pow, bit_size);
+        }
         // We need at least one more bit for the case where rhs == bit_size
         let div_type = Type::unsigned(bit_size + 1);
         let casted_lhs = self.insert_cast(lhs, div_type.clone());
@@ -159,6 +191,36 @@ impl Context<'_> {
         self.insert_cast(div_result, lhs_typ)
     }
 
+    /// Insert ssa instructions which compute `lhs >> rhs`, given `pow = 2^rhs`, for integers so wide
+    /// that dividing by `2^bit_size` would need an integer type larger than ACIR supports.
+    ///
+    /// Instead `lhs` is divided by one when `rhs >= bit_size`, and the quotient is then zeroed.
+    fn insert_wide_shift_right(
+        &mut self,
+        lhs: ValueId,
+        rhs: ValueId,
+        pow: ValueId,
+        bit_size: u32,
+    ) -> ValueId {
+        let lhs_typ = self.function.dfg.type_of_value(lhs);
+        let bit_size_var =
+            self.numeric_constant(FieldElement::from(bit_size as u128), Type::unsigned(8));
+        let in_range = self.insert_binary(rhs, BinaryOp::Lt, bit_size_var);
+        let in_range = self.insert_cast(in_range, Type::field());
+
+        // divisor = in_range ? pow : 1
+        let one = self.field_constant(FieldElement::one());
+        let pow_minus_one = self.insert_binary(pow, BinaryOp::Sub, one);
+        let divisor = self.insert_binary(in_range, BinaryOp::Mul, pow_minus_one);
+        let divisor = self.insert_binary(divisor, BinaryOp::Add, one);
+        let divisor = self.insert_cast(divisor, lhs_typ.clone());
+
+        let quotient = self.insert_binary(lhs, BinaryOp::Div, divisor);
+        let quotient = self.insert_cast(quotient, Type::field());
+        let result = self.insert_binary(quotient, BinaryOp::Mul, in_range);
+        self.insert_cast(result, lhs_typ)
+    }
+
     /// Computes lhs^rhs via square&multiply, using the bits decomposition of rhs
     /// Pseudo-code of the computation:
     /// let mut r = 1;
@@ -17,6 +17,7 @@ use crate::ssa::{
         basic_block::BasicBlockId,
         dfg::DataFlowGraph,
         function::Function,
+        function_inserter::{FunctionInserter, RewriteAction},
         instruction::{BinaryOp, Instruction, Intrinsic},
         value::Value,
     },
@@ -38,13 +39,17 @@ fn remove_enable_side_effects(function: &mut Function) {
     let mut context = Context::default();
     context.block_queue.push(function.entry_block());
 
+    // The same inserter is used for every block so that values used in later blocks are mapped
+    // to the re-inserted results of the blocks which define them.
+    let mut inserter = FunctionInserter::new(function);
+
     while let Some(block) = context.block_queue.pop() {
         if context.visited_blocks.contains(&block) {
             continue;
         }
 
         context.visited_blocks.insert(block);
-        context.remove_enable_side_effects_in_block(function, block);
+        context.remove_enable_side_effects_in_block(&mut inserter, block);
     }
 }
 
@@ -57,52 +62,46 @@ struct Context {
 impl Context {
     fn remove_enable_side_effects_in_block(
         &mut self,
-        function: &mut Function,
+        inserter: &mut FunctionInserter,
         block: BasicBlockId,
     ) {
-        let instructions = function.dfg[block].take_instructions();
-
         let mut last_side_effects_enabled_instruction = None;
 
-        let mut new_instructions = Vec::with_capacity(instructions.len());
-        for instruction_id in instructions {
-            let instruction = &function.dfg[instruction_id];
-
+        inserter.rewrite_block(block, |_, instruction, dfg| {
             // If we run into another `Instruction::EnableSideEffects` before encountering any
             // instructions with side effects then we can drop the instruction we're holding and
             // continue with the new `Instruction::EnableSideEffects`.
             if let Instruction::EnableSideEffects { condition } = instruction {
                 // If we're seeing an `enable_side_effects u1 1` then we want to insert it immediately.
                 // This is because we want to maximize the effect it will have.
-                if function
-                    .dfg
+                if dfg
                     .get_numeric_constant(*condition)
                     .map_or(false, |condition| condition.is_one())
                 {
-                    new_instructions.push(instruction_id);
                     last_side_effects_enabled_instruction = None;
-                    continue;
+                    return RewriteAction::Keep;
                 }
 
-                last_side_effects_enabled_instruction = Some(instruction_id);
-                continue;
+                last_side_effects_enabled_instruction = Some(instruction.clone());
+                return RewriteAction::ReplaceMany(Vec::new());
             }
 
             // If we hit an instruction which is affected by the side effects var then we must insert the
             // `Instruction::EnableSideEffects` before we insert this new instruction.
-            if Self::responds_to_side_effects_var(&function.dfg, instruction) {
-                if let Some(enable_side_effects_instruction_id) =
+            if Self::responds_to_side_effects_var(dfg, instruction) {
+                if let Some(enable_side_effects_instruction) =
                     last_side_effects_enabled_instruction.take()
                 {
-                    new_instructions.push(enable_side_effects_instruction_id);
+                    return RewriteAction::ReplaceMany(vec![
+                        enable_side_effects_instruction,
+                        instruction.clone(),
+                    ]);
                 }
             }
-            new_instructions.push(instruction_id);
-        }
-
-        *function.dfg[block].instructions_mut() = new_instructions;
+            RewriteAction::Keep
+        });
 
-        self.block_queue.extend(function.dfg[block].successors());
+        self.block_queue.extend(inserter.function.dfg[block].successors());
     }
 
     fn responds_to_side_effects_var(dfg: &DataFlowGraph, instruction: &Instruction) -> bool {
@@ -3,6 +3,7 @@ use std::collections::hash_map::Entry;
 use acvm::FieldElement;
 use fxhash::FxHashMap as HashMap;
 
+use crate::errors::RuntimeError;
 use crate::ssa::ir::value::ValueId;
 use crate::ssa::{
     ir::{
@@ -12,37 +13,36 @@ use crate::ssa::{
         types::Type,
         value::Value,
     },
-    opt::flatten_cfg::value_merger::ValueMerger,
+    opt::flatten_cfg::value_merger::{SlicePadding, ValueMerger},
     Ssa,
 };
 
 impl Ssa {
-    /// This pass removes `inc_rc` and `dec_rc` instructions
-    /// as long as there are no `array_set` instructions to an array
-    /// of the same type in between.
+    /// This pass replaces each `if_else` instruction left by flattening with
+    /// the instructions required to merge its `then` and `else` values.
     ///
-    /// Note that this pass is very conservative since the array_set
-    /// instruction does not need to be to the same array. This is because
-    /// the given array may alias another array (e.g. function parameters or
-    /// a `load`ed array from a reference).
+    /// Merging slices of different lengths pads the shorter slice with placeholder elements.
+    /// Compilation fails if any function would need more than `max_slice_padding` of these.
     #[tracing::instrument(level = "trace", skip(self))]
-    pub(crate) fn remove_if_else(mut self) -> Ssa {
+    pub(crate) fn remove_if_else(mut self, max_slice_padding: usize) -> Result<Ssa, RuntimeError> {
         for function in self.functions.values_mut() {
             // This should match the check in flatten_cfg
-            if let crate::ssa::ir::function::RuntimeType::Brillig = function.runtime() {
+            if let crate::ssa::ir::function::RuntimeType::Brillig(_) = function.runtime() {
                 continue;
             }
 
-            Context::default().remove_if_else(function);
+            Context::new(max_slice_padding).remove_if_else(function)?;
         }
-        self
+        Ok(self)
     }
 }
 
-#[derive(Default)]
 struct Context {
     slice_sizes: HashMap<ValueId, usize>,
 
+    // Tracks the placeholder elements used to pad slices in the current function.
+    slice_padding: SlicePadding,
+
     // Maps array_set result -> element that was overwritten by that instruction.
     // Used to undo array_sets while merging values
     prev_array_set_elem_values: HashMap<ValueId, ValueId>,
@@ -52,7 +52,16 @@ struct Context {
 }
 
 impl Context {
-    fn remove_if_else(&mut self, function: &mut Function) {
+    fn new(max_slice_padding: usize) -> Self {
+        Context {
+            slice_sizes: HashMap::default(),
+            slice_padding: SlicePadding::new(max_slice_padding),
+            prev_array_set_elem_values: HashMap::default(),
+            array_set_conditionals: HashMap::default(),
+        }
+    }
+
+    fn remove_if_else(&mut self, function: &mut Function) -> Result<(), RuntimeError> {
         let block = function.entry_block();
         let instructions = function.dfg[block].take_instructions();
         let mut current_conditional = function.dfg.make_constant(FieldElement::one(), Type::bool());
@@ -73,6 +82,7 @@ impl Context {
                         block,
                         &mut self.slice_sizes,
                         &mut self.array_set_conditionals,
+                        &mut self.slice_padding,
                         Some(current_conditional),
                     );
 
@@ -81,7 +91,7 @@ impl Context {
                         else_condition,
                         then_value,
                         else_value,
-                    );
+                    )?;
 
                     let _typ = function.dfg.type_of_value(value);
                     let results = function.dfg.instruction_results(instruction);
@@ -135,6 +145,7 @@ impl Context {
                 }
             }
         }
+        Ok(())
     }
 
     fn get_or_find_capacity(&mut self, dfg: &DataFlowGraph, value: ValueId) -> usize {
@@ -235,3 +246,84 @@ fn slice_capacity_change(
         | Intrinsic::AsWitness => SizeChange::None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::{
+        errors::RuntimeError,
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{
+                instruction::{Instruction, Intrinsic},
+                map::Id,
+                types::Type,
+            },
+            DEFAULT_MAX_SLICE_PADDING,
+        },
+    };
+
+    /// Builds a function which merges a slice made from an array of `len` fields with an empty slice:
+    ///
+    /// fn main f0 {
+    ///   b0(v0: u1, v1: [Field; len]):
+    ///     v3, v4 = call as_slice(v1)
+    ///     v5 = not v0
+    ///     v7 = if v0 then v4 else if v5 then []
+    ///     return v7
+    /// }
+    fn merge_with_empty_slice(len: usize) -> crate::ssa::Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+
+        let element_types = Rc::new(vec![Type::field()]);
+        let slice_type = Type::Slice(element_types.clone());
+
+        let v0 = builder.add_parameter(Type::bool());
+        let v1 = builder.add_parameter(Type::Array(element_types, len));
+
+        let as_slice = builder.import_intrinsic_id(Intrinsic::AsSlice);
+        let results =
+            builder.insert_call(as_slice, vec![v1], vec![Type::length_type(), slice_type.clone()]);
+        let v4 = results[1];
+
+        let v5 = builder.insert_not(v0);
+        let empty_slice = builder.array_constant(im::Vector::new(), slice_type.clone());
+
+        let if_else = Instruction::IfElse {
+            then_condition: v0,
+            then_value: v4,
+            else_condition: v5,
+            else_value: empty_slice,
+        };
+        let v7 = builder.insert_instruction(if_else, Some(vec![slice_type])).first();
+        builder.terminate_with_return(vec![v7]);
+
+        builder.finish()
+    }
+
+    #[test]
+    fn merges_slices_within_padding_limit() {
+        let ssa = merge_with_empty_slice(4);
+        assert!(ssa.remove_if_else(4).is_ok());
+    }
+
+    #[test]
+    fn errors_when_slice_padding_exceeds_limit() {
+        let ssa = merge_with_empty_slice(4);
+        let result = ssa.remove_if_else(3);
+        assert!(matches!(result, Err(RuntimeError::SlicePaddingLimitExceeded { limit: 3, .. })));
+    }
+
+    #[test]
+    fn errors_instead_of_materializing_huge_slice_padding() {
+        // Padding the empty slice would require 2^30 placeholder elements.
+        let ssa = merge_with_empty_slice(1 << 30);
+        let result = ssa.remove_if_else(DEFAULT_MAX_SLICE_PADDING);
+        assert!(matches!(
+            result,
+            Err(RuntimeError::SlicePaddingLimitExceeded { limit: DEFAULT_MAX_SLICE_PADDING, .. })
+        ));
+    }
+}
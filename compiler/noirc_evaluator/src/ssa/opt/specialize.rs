@@ -0,0 +1,323 @@
+//! This module defines a pass which specializes functions for the constant arguments they are called with.
+//!
+//! Functions which are kept separate in the final program, namely Brillig functions and ACIR functions
+//! marked with `#[fold]`, are not inlined into their callers. A constant argument such as the zero in
+//! `pad(x, 0)` therefore remains a runtime parameter of `pad`, which prevents any folding which depends on it.
+//!
+//! When every call to such a function passes the same constant for a parameter, this pass substitutes the
+//! constant for that parameter within the function. Brillig functions are replaced by a copy in which the
+//! parameter is removed from the signature. ACIR functions keep their signature, as each of them becomes a
+//! circuit in the final program whose inputs are described by the signature of the original Noir function.
+//!
+//! Optionally, a copy of a Brillig function can instead be made for each distinct set of constant arguments
+//! which it is called with. The number of copies made of each function is capped to guard against code size blowup.
+use std::collections::{BTreeMap, BTreeSet};
+
+use acvm::FieldElement;
+use fxhash::FxHashMap as HashMap;
+
+use crate::ssa::{
+    ir::{
+        function::{Function, FunctionId, RuntimeType},
+        instruction::{Instruction, InstructionId},
+        value::Value,
+    },
+    ssa_gen::Ssa,
+};
+
+/// The constant arguments of a call, keyed by the index of the parameter they are passed for.
+type ConstantArguments = BTreeMap<usize, FieldElement>;
+
+/// A call to a function which may be specialized.
+struct CallSite {
+    caller: FunctionId,
+    instruction: InstructionId,
+    constants: ConstantArguments,
+}
+
+impl Ssa {
+    /// Specializes functions which are not inlined for the constant arguments they are called with.
+    ///
+    /// A parameter is only specialized if every call passes the same constant for it, unless
+    /// `specialize_any_literal` is set. In that case a separate copy of each Brillig function is made for
+    /// each distinct set of constant arguments, with at most `max_specializations` copies made per function.
+    /// Calls with any remaining sets of constant arguments keep calling the original function.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn specialize_literal_arguments(
+        mut self,
+        specialize_any_literal: bool,
+        max_specializations: usize,
+    ) -> Ssa {
+        let mut specialized_functions = BTreeSet::new();
+
+        for (callee, mut call_sites) in self.collect_call_sites() {
+            // Recursive functions would keep calling the original function from within their copies.
+            if call_sites.iter().any(|call_site| call_site.caller == callee) {
+                continue;
+            }
+
+            let callee_function = self.functions.get_mut(&callee).expect("callee should exist");
+            if let RuntimeType::Acir(_) = callee_function.runtime() {
+                substitute_parameters(callee_function, &shared_constants(&call_sites));
+                continue;
+            }
+
+            if !specialize_any_literal {
+                let shared_constants = shared_constants(&call_sites);
+                for call_site in &mut call_sites {
+                    call_site.constants = shared_constants.clone();
+                }
+            }
+
+            let mut specializations: BTreeMap<ConstantArguments, Vec<CallSite>> = BTreeMap::new();
+            for call_site in call_sites {
+                if !call_site.constants.is_empty() {
+                    specializations.entry(call_site.constants.clone()).or_default().push(call_site);
+                }
+            }
+
+            // Prefer the specializations which replace the most calls.
+            let mut specializations: Vec<_> = specializations.into_iter().collect();
+            specializations.sort_by_key(|(_, call_sites)| std::cmp::Reverse(call_sites.len()));
+
+            for (constants, call_sites) in specializations.into_iter().take(max_specializations) {
+                let new_id = self.next_id.next();
+                let function = self.specialize_entry_point(callee, new_id, &constants);
+                self.functions.insert(new_id, function);
+
+                for call_site in call_sites {
+                    self.redirect_call(call_site, new_id);
+                }
+                specialized_functions.insert(callee);
+            }
+        }
+
+        if !specialized_functions.is_empty() {
+            // Functions whose calls have all been redirected to their copies are no longer needed.
+            let called_functions: BTreeSet<_> = self.collect_call_sites().into_keys().collect();
+            for function in specialized_functions.difference(&called_functions) {
+                self.functions.remove(function);
+            }
+        }
+        self
+    }
+
+    /// Collects every direct call to a function which is not inlined into its callers, keyed by the function being called.
+    fn collect_call_sites(&self) -> BTreeMap<FunctionId, Vec<CallSite>> {
+        let mut call_sites: BTreeMap<FunctionId, Vec<CallSite>> = BTreeMap::new();
+
+        for (caller, function) in &self.functions {
+            let dfg = &function.dfg;
+            for block in function.reachable_blocks() {
+                for instruction in dfg[block].instructions() {
+                    let Instruction::Call { func, arguments } = &dfg[*instruction] else {
+                        continue;
+                    };
+                    let Value::Function(callee) = dfg[dfg.resolve(*func)] else {
+                        continue;
+                    };
+                    let is_entry_point = self
+                        .functions
+                        .get(&callee)
+                        .is_some_and(|callee| callee.runtime().is_entry_point());
+                    if callee == self.main_id || !is_entry_point {
+                        continue;
+                    }
+
+                    let constants = arguments
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, argument)| {
+                            dfg.get_numeric_constant(*argument).map(|constant| (index, constant))
+                        })
+                        .collect();
+                    call_sites.entry(callee).or_default().push(CallSite {
+                        caller: *caller,
+                        instruction: *instruction,
+                        constants,
+                    });
+                }
+            }
+        }
+        call_sites
+    }
+
+    /// Replaces the target of the call at `call_site` with its specialization `new_id`, dropping the
+    /// arguments which have been substituted into it.
+    fn redirect_call(&mut self, call_site: CallSite, new_id: FunctionId) {
+        let caller = self.functions.get_mut(&call_site.caller).expect("caller should exist");
+        let Instruction::Call { arguments, .. } = &caller.dfg[call_site.instruction] else {
+            unreachable!("call sites should only contain call instructions");
+        };
+        let arguments = arguments
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !call_site.constants.contains_key(index))
+            .map(|(_, argument)| *argument)
+            .collect();
+
+        let func = caller.dfg.import_function(new_id);
+        caller.dfg[call_site.instruction] = Instruction::Call { func, arguments };
+    }
+}
+
+/// Replaces each use of the parameters of `function` which have a known constant by that constant.
+/// The parameters themselves are left unused.
+fn substitute_parameters(function: &mut Function, constants: &ConstantArguments) {
+    let parameters = function.parameters().to_vec();
+    let substitutions: HashMap<_, _> = constants
+        .iter()
+        .map(|(index, constant)| {
+            let typ = function.dfg.type_of_value(parameters[*index]);
+            (parameters[*index], function.dfg.make_constant(*constant, typ))
+        })
+        .collect();
+    if substitutions.is_empty() {
+        return;
+    }
+
+    let substitute = |value| *substitutions.get(&value).unwrap_or(&value);
+    for block in function.reachable_blocks() {
+        for instruction in function.dfg[block].instructions().to_vec() {
+            function.dfg[instruction] = function.dfg[instruction].map_values(substitute);
+        }
+        let terminator = function.dfg[block].unwrap_terminator().map_values(substitute);
+        function.dfg[block].set_terminator(terminator);
+    }
+}
+
+/// Returns the constant arguments which are passed for the same parameter by every call site.
+fn shared_constants(call_sites: &[CallSite]) -> ConstantArguments {
+    let Some((first, rest)) = call_sites.split_first() else {
+        return ConstantArguments::new();
+    };
+    let mut constants = first.constants.clone();
+    for call_site in rest {
+        constants.retain(|index, constant| call_site.constants.get(index) == Some(constant));
+    }
+    constants
+}
+
+#[cfg(test)]
+mod test {
+    use acvm::FieldElement;
+    use noirc_frontend::monomorphization::ast::InlineType;
+
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{
+            function::{Function, RuntimeType},
+            instruction::{BinaryOp, Instruction},
+            map::Id,
+            types::Type,
+            value::Value,
+        },
+        ssa_gen::Ssa,
+    };
+
+    /// Builds a program whose main function calls the Brillig function
+    /// `pad(x: Field, n: Field) -> bool { x == n }` once with each of the given second arguments.
+    ///
+    /// `None` passes main's parameter rather than a constant.
+    fn calls_to_pad(second_arguments: &[Option<u128>]) -> Ssa {
+        let main_id = Id::test_new(0);
+        let pad_id = Id::test_new(1);
+
+        let mut builder = FunctionBuilder::new("main".into(), main_id);
+        let v0 = builder.add_parameter(Type::field());
+        let pad = builder.import_function(pad_id);
+        for argument in second_arguments {
+            let argument = match argument {
+                Some(constant) => builder.field_constant(*constant),
+                None => v0,
+            };
+            builder.insert_call(pad, vec![v0, argument], vec![Type::bool()]);
+        }
+        builder.terminate_with_return(vec![]);
+
+        builder.new_brillig_function("pad".into(), pad_id, InlineType::default());
+        let x = builder.add_parameter(Type::field());
+        let n = builder.add_parameter(Type::field());
+        let equal = builder.insert_binary(x, BinaryOp::Eq, n);
+        builder.terminate_with_return(vec![equal]);
+
+        builder.finish()
+    }
+
+    /// Returns the functions called by main along with the number of arguments passed to each.
+    fn calls_in_main(ssa: &Ssa) -> Vec<(&Function, usize)> {
+        let main = ssa.main();
+        let instructions = main.dfg[main.entry_block()].instructions();
+        instructions
+            .iter()
+            .map(|instruction| match &main.dfg[*instruction] {
+                Instruction::Call { func, arguments } => match main.dfg[*func] {
+                    Value::Function(id) => (&ssa.functions[&id], arguments.len()),
+                    _ => unreachable!("main should only call functions"),
+                },
+                _ => unreachable!("main should only contain calls"),
+            })
+            .collect()
+    }
+
+    /// Returns the constant compared against `x` by the given specialization of `pad`.
+    fn padding_constant(pad: &Function) -> FieldElement {
+        let instructions = pad.dfg[pad.entry_block()].instructions();
+        match &pad.dfg[instructions[0]] {
+            Instruction::Binary(binary) => pad.dfg.get_numeric_constant(binary.rhs).unwrap(),
+            _ => unreachable!("pad should start with a comparison"),
+        }
+    }
+
+    #[test]
+    fn specializes_constant_shared_by_every_call() {
+        let ssa = calls_to_pad(&[Some(0), Some(0)]).specialize_literal_arguments(false, 8);
+
+        // The original `pad` is replaced by a single specialization taking only `x`.
+        assert_eq!(ssa.functions.len(), 2);
+        let calls = calls_in_main(&ssa);
+        assert_eq!(calls.len(), 2);
+        for (pad, arguments) in &calls {
+            assert_eq!(*arguments, 1);
+            assert_eq!(pad.id(), calls[0].0.id());
+            assert!(matches!(pad.runtime(), RuntimeType::Brillig(_)));
+            assert_eq!(pad.parameters().len(), 1);
+            assert_eq!(padding_constant(pad), FieldElement::zero());
+        }
+    }
+
+    #[test]
+    fn differing_constants_are_only_specialized_when_requested() {
+        let ssa = calls_to_pad(&[Some(0), Some(1)]).specialize_literal_arguments(false, 8);
+        assert_eq!(ssa.functions.len(), 2);
+        assert!(calls_in_main(&ssa)
+            .iter()
+            .all(|(pad, arguments)| { pad.id() == Id::test_new(1) && *arguments == 2 }));
+
+        let ssa = calls_to_pad(&[Some(0), Some(1), None]).specialize_literal_arguments(true, 8);
+        let calls = calls_in_main(&ssa);
+        assert_eq!(calls[0].1, 1);
+        assert_eq!(padding_constant(calls[0].0), FieldElement::zero());
+        assert_eq!(calls[1].1, 1);
+        assert_eq!(padding_constant(calls[1].0), FieldElement::one());
+        // The call passing a runtime value still requires the original function.
+        assert_eq!(calls[2].0.id(), Id::test_new(1));
+        assert_eq!(calls[2].1, 2);
+        assert_eq!(ssa.functions.len(), 4);
+    }
+
+    #[test]
+    fn specializations_are_capped_per_function() {
+        let ssa = calls_to_pad(&[Some(2), Some(1), Some(1), Some(0)])
+            .specialize_literal_arguments(true, 1);
+
+        // Only the constant passed by the most calls is specialized.
+        assert_eq!(ssa.functions.len(), 3);
+        let calls = calls_in_main(&ssa);
+        assert_eq!(calls[0].0.id(), Id::test_new(1));
+        assert_eq!(calls[1].1, 1);
+        assert_eq!(padding_constant(calls[1].0), FieldElement::one());
+        assert_eq!(calls[2].0.id(), calls[1].0.id());
+        assert_eq!(calls[3].0.id(), Id::test_new(1));
+    }
+}
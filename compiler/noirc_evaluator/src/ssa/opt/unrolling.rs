@@ -75,7 +75,7 @@ impl Ssa {
             // Loop unrolling in brillig can lead to a code explosion currently. This can
             // also be true for ACIR, but we have no alternative to unrolling in ACIR.
             // Brillig also generally prefers smaller code rather than faster code.
-            if function.runtime() == RuntimeType::Brillig {
+            if matches!(function.runtime(), RuntimeType::Brillig(_)) {
                 continue;
             }
 
@@ -461,20 +461,20 @@ impl<'f> LoopIteration<'f> {
 
     fn inline_instructions_from_block(&mut self) {
         let source_block = &self.dfg()[self.source_block];
-        let instructions = source_block.instructions().to_vec();
+        let mut instructions = source_block.instructions().to_vec();
+
+        // Skip reference count instructions since they are only used for brillig, and brillig code is not unrolled
+        instructions.retain(|instruction| {
+            !matches!(
+                self.dfg()[*instruction],
+                Instruction::IncrementRc { .. } | Instruction::DecrementRc { .. }
+            )
+        });
 
         // We cannot directly append each instruction since we need to substitute any
         // instances of the induction variable or any values that were changed as a result
         // of the new induction variable value.
-        for instruction in instructions {
-            // Skip reference count instructions since they are only used for brillig, and brillig code is not unrolled
-            if !matches!(
-                self.dfg()[instruction],
-                Instruction::IncrementRc { .. } | Instruction::DecrementRc { .. }
-            ) {
-                self.inserter.push_instruction(instruction, self.insert_block);
-            }
-        }
+        self.inserter.push_instructions(&instructions, self.insert_block);
         let mut terminator = self.dfg()[self.source_block]
             .unwrap_terminator()
             .clone()
@@ -43,6 +43,9 @@ pub(super) struct FunctionContext<'a> {
     /// These are ordered such that an inner loop is at the end of the vector and
     /// outer loops are at the beginning. When a loop is finished, it is popped.
     loops: Vec<Loop>,
+
+    /// False if the current function is marked `#[no_overflow_checks]`.
+    overflow_checks: bool,
 }
 
 /// Shared context for all functions during ssa codegen. This is the only
@@ -79,7 +82,8 @@ pub(super) struct SharedContext {
 #[derive(Copy, Clone)]
 pub(super) struct Loop {
     pub(super) loop_entry: BasicBlockId,
-    pub(super) loop_index: ValueId,
+    /// The index of a `for` loop, which is passed to `loop_entry`. `while` loops have no index.
+    pub(super) loop_index: Option<ValueId>,
     pub(super) loop_end: BasicBlockId,
 }
 
@@ -100,6 +104,7 @@ impl<'a> FunctionContext<'a> {
         function_name: String,
         parameters: &Parameters,
         runtime: RuntimeType,
+        overflow_checks: bool,
         shared_context: &'a SharedContext,
     ) -> Self {
         let function_id = shared_context
@@ -110,7 +115,8 @@ impl<'a> FunctionContext<'a> {
         let mut builder = FunctionBuilder::new(function_name, function_id);
         builder.set_runtime(runtime);
         let definitions = HashMap::default();
-        let mut this = Self { definitions, builder, shared_context, loops: Vec::new() };
+        let mut this =
+            Self { definitions, builder, shared_context, loops: Vec::new(), overflow_checks };
         this.add_parameters_to_scope(parameters);
         this
     }
@@ -123,10 +129,11 @@ impl<'a> FunctionContext<'a> {
     pub(super) fn new_function(&mut self, id: IrFunctionId, func: &ast::Function) {
         self.definitions.clear();
         if func.unconstrained {
-            self.builder.new_brillig_function(func.name.clone(), id);
+            self.builder.new_brillig_function(func.name.clone(), id, func.inline_type);
         } else {
             self.builder.new_function(func.name.clone(), id, func.inline_type);
         }
+        self.overflow_checks = func.overflow_checks;
         self.add_parameters_to_scope(&func.parameters);
     }
 
@@ -510,6 +517,17 @@ impl<'a> FunctionContext<'a> {
             std::mem::swap(&mut lhs, &mut rhs);
         }
 
+        if !self.overflow_checks
+            && matches!(
+                operator,
+                BinaryOpKind::Add | BinaryOpKind::Subtract | BinaryOpKind::Multiply
+            )
+        {
+            if let Some(result) = self.insert_unchecked_binary(lhs, op, rhs, location) {
+                return result.into();
+            }
+        }
+
         let mut result = self.builder.set_location(location).insert_binary(lhs, op, rhs);
 
         // Check for integer overflow
@@ -529,6 +547,31 @@ impl<'a> FunctionContext<'a> {
         result.into()
     }
 
+    /// Inserts an integer `+`, `-` or `*` without any overflow checks, for functions marked
+    /// `#[no_overflow_checks]`.
+    ///
+    /// The operation is performed on the operands cast to Fields and the result is cast back to
+    /// the integer type without being truncated, so no range constraints are generated for it.
+    /// Returns None if the operands are not sized integers, in which case there is nothing to skip.
+    fn insert_unchecked_binary(
+        &mut self,
+        lhs: ValueId,
+        operator: BinaryOp,
+        rhs: ValueId,
+        location: Location,
+    ) -> Option<ValueId> {
+        let typ = self.builder.type_of_value(lhs);
+        if !matches!(typ, Type::Numeric(NumericType::Signed { .. } | NumericType::Unsigned { .. }))
+        {
+            return None;
+        }
+
+        let lhs = self.builder.set_location(location).insert_cast(lhs, Type::field());
+        let rhs = self.builder.insert_cast(rhs, Type::field());
+        let result = self.builder.insert_binary(lhs, operator, rhs);
+        Some(self.builder.insert_cast(result, typ))
+    }
+
     /// Inserts a call instruction at the end of the current block and returns the results
     /// of the call.
     ///
@@ -913,7 +956,7 @@ impl<'a> FunctionContext<'a> {
     pub(crate) fn enter_loop(
         &mut self,
         loop_entry: BasicBlockId,
-        loop_index: ValueId,
+        loop_index: Option<ValueId>,
         loop_end: BasicBlockId,
     ) {
         self.loops.push(Loop { loop_entry, loop_index, loop_end });
@@ -1,5 +1,6 @@
 pub(crate) mod context;
 mod program;
+pub(crate) mod provenance;
 mod value;
 
 pub(crate) use program::Ssa;
@@ -32,12 +33,33 @@ use super::{
     },
 };
 
+/// Returns the name of the public parameter of `main` which each parameter of the generated SSA
+/// `main` comes from, or `None` for SSA parameters coming from private parameters.
+///
+/// A single parameter of `main` may be flattened into several SSA parameters, e.g. for tuples.
+pub(crate) fn public_parameter_names(program: &Program) -> Vec<Option<String>> {
+    let visibilities =
+        program.main_function_signature.0.iter().map(|(_, _, visibility)| visibility);
+    program
+        .main()
+        .parameters
+        .iter()
+        .zip(visibilities)
+        .flat_map(|((_, _, name, typ), visibility)| {
+            let name = (*visibility == Visibility::Public).then(|| name.clone());
+            vec![name; FunctionContext::map_type(typ, |_| ()).count_leaves()]
+        })
+        .collect()
+}
+
 /// Generates SSA for the given monomorphized program.
 ///
 /// This function will generate the SSA but does not perform any optimizations on it.
+/// With `full_debug`, the monomorphized expression each instruction was generated from is recorded.
 pub(crate) fn generate_ssa(
     program: Program,
     force_brillig_runtime: bool,
+    full_debug: bool,
 ) -> Result<Ssa, RuntimeError> {
     // see which parameter has call_data/return_data attribute
     let is_databus = DataBusBuilder::is_databus(&program.main_function_signature);
@@ -56,12 +78,16 @@ pub(crate) fn generate_ssa(
         main.name.clone(),
         &main.parameters,
         if force_brillig_runtime || main.unconstrained {
-            RuntimeType::Brillig
+            RuntimeType::Brillig(main.inline_type)
         } else {
             RuntimeType::Acir(main.inline_type)
         },
+        main.overflow_checks,
         &context,
     );
+    if full_debug {
+        function_context.builder.enable_expression_provenance();
+    }
 
     // Generate the call_data bus from the relevant parameters. We create it *before* processing the function body
     let call_data = function_context.builder.call_data_bus(is_databus);
@@ -130,6 +156,13 @@ impl<'a> FunctionContext<'a> {
     }
 
     fn codegen_expression(&mut self, expr: &Expression) -> Result<Values, RuntimeError> {
+        self.builder.enter_expression();
+        let result = self.codegen_expression_inner(expr);
+        self.builder.exit_expression(expr);
+        result
+    }
+
+    fn codegen_expression_inner(&mut self, expr: &Expression) -> Result<Values, RuntimeError> {
         match expr {
             Expression::Ident(ident) => Ok(self.codegen_ident(ident)),
             Expression::Literal(literal) => self.codegen_literal(literal),
@@ -139,6 +172,7 @@ impl<'a> FunctionContext<'a> {
             Expression::Index(index) => self.codegen_index(index),
             Expression::Cast(cast) => self.codegen_cast(cast),
             Expression::For(for_expr) => self.codegen_for(for_expr),
+            Expression::While(while_expr) => self.codegen_while(while_expr),
             Expression::If(if_expr) => self.codegen_if(if_expr),
             Expression::Tuple(tuple) => self.codegen_tuple(tuple),
             Expression::ExtractTupleField(tuple, index) => {
@@ -217,7 +251,7 @@ impl<'a> FunctionContext<'a> {
                     _ => unreachable!("ICE: unexpected slice literal type, got {}", array.typ),
                 })
             }
-            ast::Literal::Integer(value, typ, location) => {
+            ast::Literal::Integer(value, typ, location, _) => {
                 self.builder.set_location(*location);
                 let typ = Self::convert_non_tuple_type(typ);
                 self.checked_numeric_constant(*value, typ).map(Into::into)
@@ -456,6 +490,23 @@ impl<'a> FunctionContext<'a> {
         let lhs = self.codegen_non_tuple_expression(&cast.lhs)?;
         let typ = Self::convert_non_tuple_type(&cast.r#type);
 
+        if cast.checked {
+            // Constrain the value to fit into the target type instead of truncating it.
+            self.builder.set_location(cast.location);
+            let bit_size = typ.bit_size();
+            if bit_size < self.builder.type_of_value(lhs).bit_size() {
+                let message = format!("value does not fit into {}", cast.r#type);
+                self.builder.insert_range_check(lhs, bit_size, Some(message));
+            }
+            return Ok(self.builder.insert_cast(lhs, typ).into());
+        }
+
+        // A bool is already 0 or 1 so it fits into any integer or field without a truncation.
+        // Casts to bool from any other type are rejected by the type checker.
+        if cast.from == ast::Type::Bool {
+            self.builder.set_location(cast.location);
+            return Ok(self.builder.insert_cast(lhs, typ).into());
+        }
         Ok(self.insert_safe_cast(lhs, typ, cast.location).into())
     }
 
@@ -487,7 +538,7 @@ impl<'a> FunctionContext<'a> {
 
         // Remember the blocks and variable used in case there are break/continue instructions
         // within the loop which need to jump to them.
-        self.enter_loop(loop_entry, loop_index, loop_end);
+        self.enter_loop(loop_entry, Some(loop_index), loop_end);
 
         self.builder.set_location(for_expr.start_range_location);
         let start_index = self.codegen_non_tuple_expression(&for_expr.start_range)?;
@@ -523,6 +574,42 @@ impl<'a> FunctionContext<'a> {
         Ok(Self::unit_value())
     }
 
+    /// Codegens a while loop, creating three new blocks in the process.
+    /// The return value of a while loop is always a unit literal.
+    ///
+    /// For example, the loop `while cond { body }` is codegen'd as:
+    ///
+    ///   br loop_entry()
+    /// loop_entry():
+    ///   v0 = ... codegen cond ...
+    ///   brif v0, then: loop_body, else: loop_end
+    /// loop_body():
+    ///   v1 = ... codegen body ...
+    ///   br loop_entry()
+    /// loop_end():
+    ///   ... This is the current insert point after codegen_while finishes ...
+    fn codegen_while(&mut self, while_expr: &ast::While) -> Result<Values, RuntimeError> {
+        let loop_entry = self.builder.insert_block();
+        let loop_body = self.builder.insert_block();
+        let loop_end = self.builder.insert_block();
+
+        self.enter_loop(loop_entry, None, loop_end);
+        self.builder.terminate_with_jmp(loop_entry, Vec::new());
+
+        // The condition is re-evaluated on every iteration
+        self.builder.switch_to_block(loop_entry);
+        let condition = self.codegen_non_tuple_expression(&while_expr.condition)?;
+        self.builder.terminate_with_jmpif(condition, loop_body, loop_end);
+
+        self.builder.switch_to_block(loop_body);
+        self.codegen_expression(&while_expr.block)?;
+        self.builder.terminate_with_jmp(loop_entry, Vec::new());
+
+        self.builder.switch_to_block(loop_end);
+        self.exit_loop();
+        Ok(Self::unit_value())
+    }
+
     /// Codegens an if expression, handling the case of what to do if there is no 'else'.
     ///
     /// For example, the expression `if cond { a } else { b }` is codegen'd as:
@@ -718,6 +805,8 @@ impl<'a> FunctionContext<'a> {
         let lhs = self.extract_current_value(&assign.lvalue)?;
         let rhs = self.codegen_expression(&assign.expression)?;
 
+        // Set the location here for the reference count increments and stores of the assignment
+        self.builder.set_location(assign.location);
         rhs.clone().for_each(|value| {
             let value = value.eval(self);
             self.builder.increment_array_reference_count(value);
@@ -742,8 +831,40 @@ impl<'a> FunctionContext<'a> {
         let loop_ = self.current_loop();
 
         // Must remember to increment i before jumping
-        let new_loop_index = self.make_offset(loop_.loop_index, 1);
-        self.builder.terminate_with_jmp(loop_.loop_entry, vec![new_loop_index]);
+        let arguments = match loop_.loop_index {
+            Some(loop_index) => vec![self.make_offset(loop_index, 1)],
+            None => Vec::new(),
+        };
+        self.builder.terminate_with_jmp(loop_.loop_entry, arguments);
         Self::unit_value()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noirc_frontend::monomorphization::text::parse_program;
+
+    use super::generate_ssa;
+
+    #[test]
+    fn generates_ssa_from_textual_program() {
+        let program = parse_program(include_str!("test_data/distinct_sum.mono")).unwrap();
+        let ssa = generate_ssa(program, false, false).unwrap();
+        let expected = "\
+acir(inline) fn main f0 {
+  b0(v0: Field, v1: Field):
+    v2 = eq v0, v1
+    v3 = not v2
+    constrain v2 == u1 0
+    v7 = call f1(v0, v1)
+    return v7
+}
+acir(inline) fn sum f1 {
+  b0(v0: Field, v1: Field):
+    v2 = add v0, v1
+    return v2
+}
+";
+        assert_eq!(ssa.to_string(), expected);
+    }
+}
@@ -7,6 +7,8 @@ use crate::ssa::ir::{
     function::{Function, FunctionId, RuntimeType},
     map::AtomicCounter,
 };
+
+use super::provenance::ExpressionProvenance;
 use noirc_frontend::hir_def::types::Type as HirType;
 
 /// Contains the entire SSA representation of the program.
@@ -20,6 +22,8 @@ pub(crate) struct Ssa {
     /// as the final program artifact will be a list of only entry point functions.
     pub(crate) entry_point_to_generated_index: BTreeMap<FunctionId, u32>,
     pub(crate) error_selector_to_type: BTreeMap<ErrorSelector, HirType>,
+    /// Only recorded when compiling with `--full-debug`.
+    pub(crate) expression_provenance: Option<ExpressionProvenance>,
 }
 
 impl Ssa {
@@ -44,7 +48,7 @@ impl Ssa {
                     let runtime = func.runtime();
                     match func.runtime() {
                         RuntimeType::Acir(_) => runtime.is_entry_point() || func.id() == main_id,
-                        RuntimeType::Brillig => false,
+                        RuntimeType::Brillig(_) => false,
                     }
                 })
                 .enumerate(),
@@ -57,6 +61,7 @@ impl Ssa {
             next_id: AtomicCounter::starting_after(max_id),
             entry_point_to_generated_index,
             error_selector_to_type: error_types,
+            expression_provenance: None,
         }
     }
 
@@ -0,0 +1,91 @@
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap, HashSet},
+    fmt::Display,
+};
+
+use noirc_errors::{
+    debug_info::{MonomorphizedExprId, ProvenanceIndex, SsaInstructionProvenance},
+    Location,
+};
+use noirc_frontend::monomorphization::ast::Expression;
+
+use crate::ssa::ir::{
+    function::Function, instruction::InstructionId, printer::display_instruction,
+};
+
+/// Records which monomorphized expression each SSA instruction was generated from.
+///
+/// Instructions are re-created by most SSA passes, so rather than tracking instruction ids this
+/// maps the innermost location of an instruction's call stack to the expression which was being
+/// lowered when an instruction was first inserted with that location. Call stacks are preserved by
+/// every pass, so the instructions left after optimization can still be traced back to an expression.
+#[derive(Debug, Default)]
+pub(crate) struct ExpressionProvenance {
+    next_id: u32,
+    /// The expressions currently being lowered, innermost last.
+    current: Vec<MonomorphizedExprId>,
+    locations: HashMap<Location, MonomorphizedExprId>,
+    /// Expressions which generated an instruction but have not been rendered yet.
+    pending: HashSet<MonomorphizedExprId>,
+    expressions: BTreeMap<MonomorphizedExprId, String>,
+}
+
+impl ExpressionProvenance {
+    /// Starts lowering a new expression, nested in the current one.
+    pub(crate) fn enter_expression(&mut self) {
+        self.current.push(MonomorphizedExprId(self.next_id));
+        self.next_id += 1;
+    }
+
+    /// Finishes lowering the current expression, rendering it if any instruction was attributed to it.
+    pub(crate) fn exit_expression(&mut self, expression: &Expression) {
+        let id = self.current.pop().expect("ICE: exited more expressions than were entered");
+        if self.pending.remove(&id) {
+            self.expressions.insert(id, expression.to_string());
+        }
+    }
+
+    /// Attributes an instruction inserted with the given call stack to the current expression.
+    pub(crate) fn record_instruction(&mut self, innermost_location: Option<&Location>) {
+        let (Some(location), Some(id)) = (innermost_location, self.current.last()) else {
+            return;
+        };
+        if let Entry::Vacant(entry) = self.locations.entry(*location) {
+            entry.insert(*id);
+            self.pending.insert(*id);
+        }
+    }
+
+    /// Describes an instruction of the final SSA for the provenance of the opcodes generated from it.
+    pub(crate) fn instruction(
+        &self,
+        function: &Function,
+        instruction: InstructionId,
+    ) -> SsaInstructionProvenance {
+        let call_stack = function.dfg.get_call_stack(instruction);
+        let expression = call_stack.last().and_then(|location| self.locations.get(location));
+        SsaInstructionProvenance {
+            function: format!("{} {}", function.name(), function.id()),
+            instruction: DisplayInstruction(function, instruction).to_string().trim().to_string(),
+            expression: expression.copied(),
+        }
+    }
+
+    /// Adds the expressions referred to by the opcodes of `index`.
+    pub(crate) fn add_expressions_to(&self, index: &mut ProvenanceIndex) {
+        for provenance in index.opcodes.values() {
+            let Some(id) = provenance.expression else { continue };
+            if let Some(expression) = self.expressions.get(&id) {
+                index.expressions.insert(id, expression.clone());
+            }
+        }
+    }
+}
+
+struct DisplayInstruction<'f>(&'f Function, InstructionId);
+
+impl Display for DisplayInstruction<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        display_instruction(self.0, self.1, f)
+    }
+}
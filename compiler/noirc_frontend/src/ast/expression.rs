@@ -2,10 +2,10 @@ use std::borrow::Cow;
 use std::fmt::Display;
 
 use crate::ast::{
-    Ident, ItemVisibility, Path, Pattern, Recoverable, Statement, StatementKind,
-    UnresolvedTraitConstraint, UnresolvedType, UnresolvedTypeData, Visibility,
+    Ident, IntegerBitSize, ItemVisibility, Path, Pattern, Recoverable, Signedness, Statement,
+    StatementKind, UnresolvedTraitConstraint, UnresolvedType, UnresolvedTypeData, Visibility,
 };
-use crate::token::{Attributes, Token};
+use crate::token::{Attributes, LiteralRepr, Token};
 use acvm::FieldElement;
 use iter_extended::vecmap;
 use noirc_errors::{Span, Spanned};
@@ -55,12 +55,21 @@ impl ExpressionKind {
         }
     }
 
+    /// Negating an integer literal produces a negative literal rather than a prefix expression,
+    /// so that `-1` can be used wherever a literal is expected.
+    ///
+    /// This is done whatever the whitespace between the `-` and the literal, so `- 1` is the same
+    /// literal as `-1`. In `a -1` the `-` is still a subtraction, as the parser only treats it as a
+    /// prefix operator at the start of a term.
     pub fn prefix(operator: UnaryOp, rhs: Expression) -> ExpressionKind {
         match (operator, &rhs) {
             (
                 UnaryOp::Minus,
-                Expression { kind: ExpressionKind::Literal(Literal::Integer(field, sign)), .. },
-            ) => ExpressionKind::Literal(Literal::Integer(*field, !sign)),
+                Expression {
+                    kind: ExpressionKind::Literal(Literal::Integer(field, sign, repr)),
+                    ..
+                },
+            ) => ExpressionKind::Literal(Literal::Integer(*field, !sign, *repr)),
             _ => ExpressionKind::Prefix(Box::new(PrefixExpression { operator, rhs })),
         }
     }
@@ -88,7 +97,7 @@ impl ExpressionKind {
     }
 
     pub fn integer(contents: FieldElement) -> ExpressionKind {
-        ExpressionKind::Literal(Literal::Integer(contents, false))
+        ExpressionKind::Literal(Literal::Integer(contents, false, LiteralRepr::Decimal))
     }
 
     pub fn boolean(contents: bool) -> ExpressionKind {
@@ -107,6 +116,17 @@ impl ExpressionKind {
         ExpressionKind::Literal(Literal::FmtStr(contents))
     }
 
+    /// Lowers a byte string such as `b"ab"` to an array literal of its bytes, `[97 as u8, 98 as u8]`.
+    pub fn byte_string(bytes: Vec<u8>, span: Span) -> ExpressionKind {
+        let u8_type = UnresolvedTypeData::Integer(Signedness::Unsigned, IntegerBitSize::Eight);
+        let elements = vecmap(bytes, |byte| {
+            let byte =
+                Expression::new(ExpressionKind::integer(FieldElement::from(byte as u128)), span);
+            Expression::cast(byte, u8_type.with_span(span), span)
+        });
+        ExpressionKind::Literal(Literal::Array(ArrayLiteral::Standard(elements)))
+    }
+
     pub fn constructor((type_name, fields): (Path, Vec<(Ident, Expression)>)) -> ExpressionKind {
         ExpressionKind::Constructor(Box::new(ConstructorExpression { type_name, fields }))
     }
@@ -123,7 +143,7 @@ impl ExpressionKind {
         };
 
         match literal {
-            Literal::Integer(integer, _) => Some(*integer),
+            Literal::Integer(integer, ..) => Some(*integer),
             _ => None,
         }
     }
@@ -343,7 +363,7 @@ pub enum Literal {
     Array(ArrayLiteral),
     Slice(ArrayLiteral),
     Bool(bool),
-    Integer(FieldElement, /*sign*/ bool), // false for positive integer and true for negative
+    Integer(FieldElement, /*sign*/ bool, LiteralRepr), // false for positive integer and true for negative
     Str(String),
     RawStr(String, u8),
     FmtStr(String),
@@ -544,11 +564,13 @@ impl Display for Literal {
                 write!(f, "&[{repeated_element}; {length}]")
             }
             Literal::Bool(boolean) => write!(f, "{}", if *boolean { "true" } else { "false" }),
-            Literal::Integer(integer, sign) => {
+            Literal::Integer(integer, sign, repr) => {
                 if *sign {
-                    write!(f, "-{}", integer.to_u128())
-                } else {
-                    write!(f, "{}", integer.to_u128())
+                    write!(f, "-")?;
+                }
+                match repr.written_form() {
+                    Some(written_form) => write!(f, "{written_form}"),
+                    None => write!(f, "{}", integer.to_u128()),
                 }
             }
             Literal::Str(string) => write!(f, "\"{string}\""),
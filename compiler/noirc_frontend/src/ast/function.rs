@@ -119,6 +119,8 @@ impl From<FunctionDefinition> for NoirFunction {
             Some(FunctionAttribute::Recursive) => FunctionKind::Recursive,
             Some(FunctionAttribute::Fold) => FunctionKind::Normal,
             Some(FunctionAttribute::NoPredicates) => FunctionKind::Normal,
+            Some(FunctionAttribute::InlineAlways) => FunctionKind::Normal,
+            Some(FunctionAttribute::InlineNever) => FunctionKind::Normal,
             None => FunctionKind::Normal,
         };
 
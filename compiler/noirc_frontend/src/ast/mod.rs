@@ -35,11 +35,12 @@ pub enum IntegerBitSize {
     Sixteen,
     ThirtyTwo,
     SixtyFour,
+    HundredTwentyEight,
 }
 
 impl IntegerBitSize {
     pub fn allowed_sizes() -> Vec<Self> {
-        vec![Self::One, Self::Eight, Self::ThirtyTwo, Self::SixtyFour]
+        vec![Self::One, Self::Eight, Self::ThirtyTwo, Self::SixtyFour, Self::HundredTwentyEight]
     }
 }
 
@@ -52,6 +53,7 @@ impl From<IntegerBitSize> for u32 {
             Sixteen => 16,
             ThirtyTwo => 32,
             SixtyFour => 64,
+            HundredTwentyEight => 128,
         }
     }
 }
@@ -69,6 +71,7 @@ impl TryFrom<u32> for IntegerBitSize {
             16 => Ok(Sixteen),
             32 => Ok(ThirtyTwo),
             64 => Ok(SixtyFour),
+            128 => Ok(HundredTwentyEight),
             _ => Err(InvalidIntegerBitSizeError(value)),
         }
     }
@@ -261,6 +264,9 @@ impl UnresolvedTypeData {
     ) -> Result<UnresolvedTypeData, InvalidIntegerBitSizeError> {
         use {IntType::*, UnresolvedTypeData::Integer};
         match token {
+            // Only unsigned 128-bit integers are supported: the two's complement arithmetic used
+            // for signed integers needs twice their bit size to fit within the field.
+            Signed(128) => Err(InvalidIntegerBitSizeError(128)),
             Signed(num_bits) => {
                 Ok(Integer(Signedness::Signed, IntegerBitSize::try_from(num_bits)?))
             }
@@ -307,11 +313,23 @@ impl UnresolvedTypeExpression {
 
     fn from_expr_helper(expr: Expression) -> Result<UnresolvedTypeExpression, Expression> {
         match expr.kind {
-            ExpressionKind::Literal(Literal::Integer(int, sign)) => {
-                assert!(!sign, "Negative literal is not allowed here");
-                match int.try_to_u64() {
-                    Some(int) => Ok(UnresolvedTypeExpression::Constant(int, expr.span)),
-                    None => Err(expr),
+            ExpressionKind::Literal(Literal::Integer(int, sign, _)) => {
+                let Some(int) = int.try_to_u64() else {
+                    return Err(expr);
+                };
+                let constant = UnresolvedTypeExpression::Constant(int, expr.span);
+                if sign {
+                    // A negative literal is treated the same as negating any other type expression.
+                    let lhs = Box::new(UnresolvedTypeExpression::Constant(0, expr.span));
+                    let op = BinaryTypeOperator::Subtraction;
+                    Ok(UnresolvedTypeExpression::BinaryOperation(
+                        lhs,
+                        op,
+                        Box::new(constant),
+                        expr.span,
+                    ))
+                } else {
+                    Ok(constant)
                 }
             }
             ExpressionKind::Variable(path, _) => Ok(UnresolvedTypeExpression::Variable(path)),
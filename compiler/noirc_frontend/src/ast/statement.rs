@@ -6,8 +6,8 @@ use iter_extended::vecmap;
 use noirc_errors::{Span, Spanned};
 
 use super::{
-    BlockExpression, Expression, ExpressionKind, IndexExpression, MemberAccessExpression,
-    MethodCallExpression, UnresolvedType,
+    BinaryOp, BinaryOpKind, BlockExpression, Expression, ExpressionKind, IndexExpression,
+    InfixExpression, Literal, MemberAccessExpression, MethodCallExpression, UnresolvedType,
 };
 use crate::lexer::token::SpannedToken;
 use crate::macros_api::SecondaryAttribute;
@@ -21,6 +21,11 @@ use crate::token::Token;
 /// for an identifier that already failed to parse.
 pub const ERROR_IDENT: &str = "$error";
 
+/// Counter used to generate unique names when desugaring
+/// code in the parser requires the creation of fresh variables.
+/// The parser is stateless so this is a static global instead.
+static UNIQUE_NAME_COUNTER: AtomicU32 = AtomicU32::new(0);
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Statement {
     pub kind: StatementKind,
@@ -37,6 +42,7 @@ pub enum StatementKind {
     Expression(Expression),
     Assign(AssignStatement),
     For(ForLoopStatement),
+    While(WhileStatement),
     Break,
     Continue,
     /// This statement should be executed at compile-time
@@ -75,7 +81,6 @@ impl StatementKind {
 
         match self {
             StatementKind::Let(_)
-            | StatementKind::Constrain(_)
             | StatementKind::Assign(_)
             | StatementKind::Semi(_)
             | StatementKind::Break
@@ -87,13 +92,21 @@ impl StatementKind {
                 }
                 self
             }
+            // Assertions evaluate to `()` so, as with expressions, the semicolon
+            // may be omitted when the assertion ends a block.
+            StatementKind::Constrain(_) => {
+                if semi.is_none() && !last_statement_in_block {
+                    emit_error(missing_semicolon);
+                }
+                self
+            }
             StatementKind::Comptime(mut statement) => {
                 *statement =
                     statement.add_semicolon(semi, span, last_statement_in_block, emit_error);
                 StatementKind::Comptime(statement)
             }
-            // A semicolon on a for loop is optional and does nothing
-            StatementKind::For(_) => self,
+            // A semicolon on a loop is optional and does nothing
+            StatementKind::For(_) | StatementKind::While(_) => self,
 
             StatementKind::Expression(expr) => {
                 match (&expr.kind, semi, last_statement_in_block) {
@@ -136,28 +149,19 @@ impl StatementKind {
         })
     }
 
-    /// Create a Statement::Assign value, desugaring any combined operators like += if needed.
+    /// Create a Statement::Assign value, keeping any combined operator like += to be desugared
+    /// during name resolution. See [`AssignStatement::desugar_compound_operator`].
     pub fn assign(
         lvalue: LValue,
         operator: Token,
-        mut expression: Expression,
+        expression: Expression,
         span: Span,
     ) -> StatementKind {
-        // Desugar `a <op>= b` to `a = a <op> b`. This relies on the evaluation of `a` having no side effects,
-        // which is currently enforced by the restricted syntax of LValues.
-        if operator != Token::Assign {
-            let lvalue_expr = lvalue.as_expression();
+        let operator = (operator != Token::Assign).then(|| {
             let error_msg = "Token passed to Statement::assign is not a binary operator";
-
-            let infix = crate::ast::InfixExpression {
-                lhs: lvalue_expr,
-                operator: operator.try_into_binary_op(span).expect(error_msg),
-                rhs: expression,
-            };
-            expression = Expression::new(ExpressionKind::Infix(Box::new(infix)), span);
-        }
-
-        StatementKind::Assign(AssignStatement { lvalue, expression })
+            operator.try_into_binary_op(span).expect(error_msg)
+        });
+        StatementKind::Assign(AssignStatement { lvalue, operator, expression })
     }
 }
 
@@ -291,6 +295,8 @@ pub enum PathKind {
     Crate,
     Dep,
     Plain,
+    /// A path starting with `self::`, which is resolved relative to the current module.
+    SelfModule,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -439,9 +445,44 @@ pub struct LetStatement {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct AssignStatement {
     pub lvalue: LValue,
+    /// The operator of a compound assignment such as `a += b`,
+    /// in which case `expression` is only its right hand side `b`.
+    pub operator: Option<BinaryOp>,
     pub expression: Expression,
 }
 
+impl AssignStatement {
+    /// Desugars a compound assignment `a <op>= b` to `a = a <op> b`, leaving other assignments
+    /// as they are. As `a` is evaluated twice, any index into it which may have side effects is
+    /// first bound to a fresh variable so it is only evaluated once:
+    ///
+    /// {
+    ///     let fresh = index;
+    ///     a[fresh] = a[fresh] <op> b;
+    /// };
+    pub(crate) fn desugar_compound_operator(self, span: Span) -> StatementKind {
+        let Some(operator) = self.operator else {
+            return StatementKind::Assign(self);
+        };
+
+        let mut index_bindings = Vec::new();
+        let lvalue = self.lvalue.bind_index_expressions(&mut index_bindings);
+
+        let infix = InfixExpression { lhs: lvalue.as_expression(), operator, rhs: self.expression };
+        let expression = Expression::new(ExpressionKind::Infix(Box::new(infix)), span);
+        let assign = StatementKind::Assign(AssignStatement { lvalue, operator: None, expression });
+
+        if index_bindings.is_empty() {
+            return assign;
+        }
+
+        let mut statements = index_bindings;
+        statements.push(Statement { kind: assign, span });
+        let block = ExpressionKind::Block(BlockExpression { statements });
+        StatementKind::Semi(Expression::new(block, span))
+    }
+}
+
 /// Represents an Ast form that can be assigned to
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum LValue {
@@ -532,6 +573,48 @@ impl LValue {
         Expression::new(kind, span)
     }
 
+    /// Replaces each index expression of this lvalue which may have side effects with a fresh
+    /// variable, pushing the `let` statements defining those variables in evaluation order.
+    fn bind_index_expressions(self, bindings: &mut Vec<Statement>) -> LValue {
+        match self {
+            LValue::Ident(_) => self,
+            LValue::MemberAccess { object, field_name, span } => {
+                let object = Box::new(object.bind_index_expressions(bindings));
+                LValue::MemberAccess { object, field_name, span }
+            }
+            LValue::Index { array, index, span } => {
+                let array = Box::new(array.bind_index_expressions(bindings));
+                let index = match index.kind {
+                    ExpressionKind::Variable(..)
+                    | ExpressionKind::Literal(Literal::Integer(..)) => index,
+                    _ => {
+                        let next_unique_id = UNIQUE_NAME_COUNTER.fetch_add(1, Ordering::Relaxed);
+                        let index_span = index.span;
+                        let index_ident = Ident::new(format!("$index{next_unique_id}"), index_span);
+
+                        bindings.push(Statement {
+                            kind: StatementKind::Let(LetStatement {
+                                pattern: Pattern::Identifier(index_ident.clone()),
+                                r#type: UnresolvedType::unspecified(),
+                                expression: index,
+                                comptime: false,
+                                attributes: vec![],
+                            }),
+                            span: index_span,
+                        });
+
+                        let path = Path::from_ident(index_ident);
+                        Expression::new(ExpressionKind::Variable(path, None), index_span)
+                    }
+                };
+                LValue::Index { array, index, span }
+            }
+            LValue::Dereference(lvalue, span) => {
+                LValue::Dereference(Box::new(lvalue.bind_index_expressions(bindings)), span)
+            }
+        }
+    }
+
     pub fn span(&self) -> Span {
         match self {
             LValue::Ident(ident) => ident.span(),
@@ -542,9 +625,39 @@ impl LValue {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ForBounds {
+    pub start: Expression,
+    pub end: Expression,
+    /// True for `start..=end`, which includes `end` in the range.
+    pub inclusive: bool,
+}
+
+impl ForBounds {
+    /// Returns the bounds of the equivalent half-open range `start..end`,
+    /// desugaring `start..=end` into `start..end + 1`.
+    ///
+    /// An inclusive range ending at the maximum value of its type therefore overflows
+    /// in the same way `end + 1` would.
+    pub(crate) fn into_half_open(self) -> (Expression, Expression) {
+        if !self.inclusive {
+            return (self.start, self.end);
+        }
+
+        let span = self.end.span;
+        let one = Expression::new(ExpressionKind::integer(FieldElement::one()), span);
+        let end = ExpressionKind::Infix(Box::new(InfixExpression {
+            lhs: self.end,
+            operator: Spanned::from(span, BinaryOpKind::Add),
+            rhs: one,
+        }));
+        (self.start, Expression::new(end, span))
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum ForRange {
-    Range(/*start:*/ Expression, /*end:*/ Expression),
+    Range(ForBounds),
     Array(Expression),
 }
 
@@ -559,17 +672,14 @@ impl ForRange {
     ///         ...
     ///     }
     /// }
+    ///
+    /// `elem` may be any pattern, allowing each element to be destructured.
     pub(crate) fn into_for(
         self,
-        identifier: Ident,
+        pattern: Pattern,
         block: Expression,
         for_loop_span: Span,
     ) -> Statement {
-        /// Counter used to generate unique names when desugaring
-        /// code in the parser requires the creation of fresh variables.
-        /// The parser is stateless so this is a static global instead.
-        static UNIQUE_NAME_COUNTER: AtomicU32 = AtomicU32::new(0);
-
         match self {
             ForRange::Range(..) => {
                 unreachable!()
@@ -580,9 +690,10 @@ impl ForRange {
                 let start_range = Expression::new(start_range, array_span);
 
                 let next_unique_id = UNIQUE_NAME_COUNTER.fetch_add(1, Ordering::Relaxed);
-                let array_name = format!("$i{next_unique_id}");
+                let array_name = format!("$collection{next_unique_id}");
                 let array_span = array.span;
                 let array_ident = Ident::new(array_name, array_span);
+                let collection = array_ident.clone();
 
                 // let fresh1 = array;
                 let let_array = Statement {
@@ -630,7 +741,7 @@ impl ForRange {
                 // let elem = array[i];
                 let let_elem = Statement {
                     kind: StatementKind::Let(LetStatement {
-                        pattern: Pattern::Identifier(identifier),
+                        pattern,
                         r#type: UnresolvedType::unspecified(),
                         expression: Expression::new(loop_element, array_span),
                         comptime: false,
@@ -649,10 +760,15 @@ impl ForRange {
                 let new_block = Expression::new(ExpressionKind::Block(new_block), block_span);
                 let for_loop = Statement {
                     kind: StatementKind::For(ForLoopStatement {
-                        identifier: fresh_identifier,
-                        range: ForRange::Range(start_range, end_range),
+                        pattern: Pattern::Identifier(fresh_identifier),
+                        range: ForRange::Range(ForBounds {
+                            start: start_range,
+                            end: end_range,
+                            inclusive: false,
+                        }),
                         block: new_block,
                         span: for_loop_span,
+                        collection: Some(collection),
                     }),
                     span: for_loop_span,
                 };
@@ -669,10 +785,24 @@ impl ForRange {
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ForLoopStatement {
-    pub identifier: Ident,
+    /// The pattern bound on each iteration. This is always an identifier for range loops,
+    /// whereas the elements of an array may be destructured.
+    pub pattern: Pattern,
     pub range: ForRange,
     pub block: Expression,
     pub span: Span,
+    /// The variable holding the collection iterated over, if this loop was desugared from
+    /// a `for elem in collection` loop. Its upper bound is then the length of that collection.
+    pub collection: Option<Ident>,
+}
+
+/// A `while condition { ... }` loop. These are only allowed in unconstrained functions
+/// since the number of iterations is not known at compile-time.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WhileStatement {
+    pub condition: Expression,
+    pub block: Expression,
+    pub span: Span,
 }
 
 impl Display for StatementKind {
@@ -683,6 +813,7 @@ impl Display for StatementKind {
             StatementKind::Expression(expression) => expression.fmt(f),
             StatementKind::Assign(assign) => assign.fmt(f),
             StatementKind::For(for_loop) => for_loop.fmt(f),
+            StatementKind::While(while_loop) => while_loop.fmt(f),
             StatementKind::Break => write!(f, "break"),
             StatementKind::Continue => write!(f, "continue"),
             StatementKind::Comptime(statement) => write!(f, "comptime {}", statement.kind),
@@ -706,7 +837,12 @@ impl Display for ConstrainStatement {
 
 impl Display for AssignStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} = {}", self.lvalue, self.expression)
+        match &self.operator {
+            Some(operator) => {
+                write!(f, "{} {}= {}", self.lvalue, operator.contents, self.expression)
+            }
+            None => write!(f, "{} = {}", self.lvalue, self.expression),
+        }
     }
 }
 
@@ -736,6 +872,7 @@ impl Display for PathKind {
             PathKind::Crate => write!(f, "crate"),
             PathKind::Dep => write!(f, "dep"),
             PathKind::Plain => write!(f, "plain"),
+            PathKind::SelfModule => write!(f, "self"),
         }
     }
 }
@@ -770,10 +907,21 @@ impl Display for Pattern {
 impl Display for ForLoopStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let range = match &self.range {
-            ForRange::Range(start, end) => format!("{start}..{end}"),
+            ForRange::Range(ForBounds { start, end, inclusive: false }) => {
+                format!("{start}..{end}")
+            }
+            ForRange::Range(ForBounds { start, end, inclusive: true }) => {
+                format!("{start}..={end}")
+            }
             ForRange::Array(expr) => expr.to_string(),
         };
 
-        write!(f, "for {} in {range} {}", self.identifier, self.block)
+        write!(f, "for {} in {range} {}", self.pattern, self.block)
+    }
+}
+
+impl Display for WhileStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "while {} {}", self.condition, self.block)
     }
 }
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::ast::{Ident, UnresolvedGenerics, UnresolvedType};
+use crate::ast::{Ident, ItemVisibility, UnresolvedGenerics, UnresolvedType};
 use crate::token::SecondaryAttribute;
 
 use iter_extended::vecmap;
@@ -12,16 +12,25 @@ pub struct NoirStruct {
     pub name: Ident,
     pub attributes: Vec<SecondaryAttribute>,
     pub generics: UnresolvedGenerics,
-    pub fields: Vec<(Ident, UnresolvedType)>,
+    pub fields: Vec<StructField>,
     pub span: Span,
 }
 
+/// A single field of a struct declaration, e.g. `pub x: Field`.
+/// Fields are private to the module defining the struct unless declared `pub` or `pub(crate)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructField {
+    pub visibility: ItemVisibility,
+    pub name: Ident,
+    pub typ: UnresolvedType,
+}
+
 impl NoirStruct {
     pub fn new(
         name: Ident,
         attributes: Vec<SecondaryAttribute>,
         generics: Vec<Ident>,
-        fields: Vec<(Ident, UnresolvedType)>,
+        fields: Vec<StructField>,
         span: Span,
     ) -> NoirStruct {
         NoirStruct { name, attributes, generics, fields, span }
@@ -35,10 +44,21 @@ impl Display for NoirStruct {
 
         writeln!(f, "struct {}{} {{", self.name, generics)?;
 
-        for (name, typ) in self.fields.iter() {
-            writeln!(f, "    {name}: {typ},")?;
+        for field in self.fields.iter() {
+            writeln!(f, "    {field},")?;
         }
 
         write!(f, "}}")
     }
 }
+
+impl Display for StructField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.visibility {
+            ItemVisibility::Public => write!(f, "pub ")?,
+            ItemVisibility::PublicCrate => write!(f, "pub(crate) ")?,
+            ItemVisibility::Private => (),
+        }
+        write!(f, "{}: {}", self.name, self.typ)
+    }
+}
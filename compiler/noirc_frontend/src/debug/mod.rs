@@ -3,6 +3,7 @@ use crate::{
     ast,
     ast::{Path, PathKind},
     parser::{Item, ItemKind},
+    token::LiteralRepr,
 };
 use noirc_errors::debug_info::{DebugFnId, DebugFunction};
 use noirc_errors::{Span, Spanned};
@@ -340,6 +341,7 @@ impl DebugInstrumenter {
         ast::Statement {
             kind: ast::StatementKind::Assign(ast::AssignStatement {
                 lvalue: assign_stmt.lvalue.clone(),
+                operator: None,
                 expression: ast::Expression {
                     kind: ast::ExpressionKind::Block(ast::BlockExpression {
                         statements: vec![
@@ -420,24 +422,26 @@ impl DebugInstrumenter {
     }
 
     fn walk_for(&mut self, for_stmt: &mut ast::ForLoopStatement) {
-        let var_name = &for_stmt.identifier.0.contents;
-        let var_id = self.insert_var(var_name);
+        let vars = pattern_vars(&for_stmt.pattern);
+        let var_ids: Vec<_> = vars.iter().map(|(id, _)| self.insert_var(&id.0.contents)).collect();
 
-        let set_stmt = build_assign_var_stmt(var_id, id_expr(&for_stmt.identifier));
-        let drop_stmt = build_drop_var_stmt(var_id, Span::empty(for_stmt.span.end()));
+        let set_stmts = vars
+            .iter()
+            .zip(&var_ids)
+            .map(|((id, _), var_id)| build_assign_var_stmt(*var_id, id_expr(id)));
+        let drop_stmts = var_ids
+            .iter()
+            .map(|var_id| build_drop_var_stmt(*var_id, Span::empty(for_stmt.span.end())));
 
         self.walk_expr(&mut for_stmt.block);
+        let mut statements: Vec<_> = set_stmts.collect();
+        statements.push(ast::Statement {
+            kind: ast::StatementKind::Semi(for_stmt.block.clone()),
+            span: for_stmt.block.span,
+        });
+        statements.extend(drop_stmts);
         for_stmt.block = ast::Expression {
-            kind: ast::ExpressionKind::Block(ast::BlockExpression {
-                statements: vec![
-                    set_stmt,
-                    ast::Statement {
-                        kind: ast::StatementKind::Semi(for_stmt.block.clone()),
-                        span: for_stmt.block.span,
-                    },
-                    drop_stmt,
-                ],
-            }),
+            kind: ast::ExpressionKind::Block(ast::BlockExpression { statements }),
             span: for_stmt.span,
         };
     }
@@ -447,6 +451,10 @@ impl DebugInstrumenter {
             ast::StatementKind::Let(let_stmt) => {
                 *stmt = self.walk_let_statement(let_stmt, &stmt.span);
             }
+            ast::StatementKind::Assign(assign_stmt) if assign_stmt.operator.is_some() => {
+                stmt.kind = assign_stmt.clone().desugar_compound_operator(stmt.span);
+                self.walk_statement(stmt);
+            }
             ast::StatementKind::Assign(assign_stmt) => {
                 *stmt = self.walk_assign_statement(assign_stmt, &stmt.span);
             }
@@ -459,6 +467,10 @@ impl DebugInstrumenter {
             ast::StatementKind::For(ref mut for_stmt) => {
                 self.walk_for(for_stmt);
             }
+            ast::StatementKind::While(while_stmt) => {
+                self.walk_expr(&mut while_stmt.condition);
+                self.walk_expr(&mut while_stmt.block);
+            }
             _ => {} // Constrain, Error
         }
     }
@@ -718,14 +730,22 @@ fn id_expr(id: &ast::Ident) -> ast::Expression {
 
 fn uint_expr(x: u128, span: Span) -> ast::Expression {
     ast::Expression {
-        kind: ast::ExpressionKind::Literal(ast::Literal::Integer(x.into(), false)),
+        kind: ast::ExpressionKind::Literal(ast::Literal::Integer(
+            x.into(),
+            false,
+            LiteralRepr::Decimal,
+        )),
         span,
     }
 }
 
 fn sint_expr(x: i128, span: Span) -> ast::Expression {
     ast::Expression {
-        kind: ast::ExpressionKind::Literal(ast::Literal::Integer(x.abs().into(), x < 0)),
+        kind: ast::ExpressionKind::Literal(ast::Literal::Integer(
+            x.abs().into(),
+            x < 0,
+            LiteralRepr::Decimal,
+        )),
         span,
     }
 }
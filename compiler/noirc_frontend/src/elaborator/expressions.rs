@@ -34,9 +34,20 @@ use super::Elaborator;
 
 impl<'context> Elaborator<'context> {
     pub(super) fn elaborate_expression(&mut self, expr: Expression) -> (ExprId, Type) {
+        self.elaborate_expression_with_target_type(expr, None)
+    }
+
+    /// Elaborates `expr`, where `target_type` is the type it is expected to have, if known.
+    pub(super) fn elaborate_expression_with_target_type(
+        &mut self,
+        expr: Expression,
+        target_type: Option<&Type>,
+    ) -> (ExprId, Type) {
         let (hir_expr, typ) = match expr.kind {
             ExpressionKind::Literal(literal) => self.elaborate_literal(literal, expr.span),
-            ExpressionKind::Block(block) => self.elaborate_block(block),
+            ExpressionKind::Block(block) => {
+                self.elaborate_block_with_target_type(block, target_type)
+            }
             ExpressionKind::Prefix(prefix) => self.elaborate_prefix(*prefix),
             ExpressionKind::Index(index) => self.elaborate_index(*index),
             ExpressionKind::Call(call) => self.elaborate_call(*call, expr.span),
@@ -47,7 +58,7 @@ impl<'context> Elaborator<'context> {
             }
             ExpressionKind::Cast(cast) => self.elaborate_cast(*cast, expr.span),
             ExpressionKind::Infix(infix) => return self.elaborate_infix(*infix, expr.span),
-            ExpressionKind::If(if_) => self.elaborate_if(*if_),
+            ExpressionKind::If(if_) => self.elaborate_if(*if_, target_type),
             ExpressionKind::Variable(variable, generics) => {
                 let generics = generics.map(|option_inner| {
                     option_inner.into_iter().map(|generic| self.resolve_type(generic)).collect()
@@ -56,7 +67,9 @@ impl<'context> Elaborator<'context> {
             }
             ExpressionKind::Tuple(tuple) => self.elaborate_tuple(tuple),
             ExpressionKind::Lambda(lambda) => self.elaborate_lambda(*lambda),
-            ExpressionKind::Parenthesized(expr) => return self.elaborate_expression(*expr),
+            ExpressionKind::Parenthesized(expr) => {
+                return self.elaborate_expression_with_target_type(*expr, target_type)
+            }
             ExpressionKind::Quote(quote) => self.elaborate_quote(quote),
             ExpressionKind::Comptime(comptime) => self.elaborate_comptime_block(comptime),
             ExpressionKind::Error => (HirExpression::Error, Type::Error),
@@ -68,12 +81,23 @@ impl<'context> Elaborator<'context> {
     }
 
     pub(super) fn elaborate_block(&mut self, block: BlockExpression) -> (HirExpression, Type) {
+        self.elaborate_block_with_target_type(block, None)
+    }
+
+    /// Elaborates `block`, where `target_type` is the type its final expression is expected to have.
+    pub(super) fn elaborate_block_with_target_type(
+        &mut self,
+        block: BlockExpression,
+        target_type: Option<&Type>,
+    ) -> (HirExpression, Type) {
         self.push_scope();
         let mut block_type = Type::Unit;
-        let mut statements = Vec::with_capacity(block.statements.len());
+        let statement_count = block.statements.len();
+        let mut statements = Vec::with_capacity(statement_count);
 
         for (i, statement) in block.statements.into_iter().enumerate() {
-            let (id, stmt_type) = self.elaborate_statement(statement);
+            let target_type = if i + 1 == statement_count { target_type } else { None };
+            let (id, stmt_type) = self.elaborate_statement_with_target_type(statement, target_type);
             statements.push(id);
 
             if let HirStatement::Semi(expr) = self.interner.statement(&id) {
@@ -100,8 +124,8 @@ impl<'context> Elaborator<'context> {
         match literal {
             Literal::Unit => (Lit(HirLiteral::Unit), Type::Unit),
             Literal::Bool(b) => (Lit(HirLiteral::Bool(b)), Type::Bool),
-            Literal::Integer(integer, sign) => {
-                let int = HirLiteral::Integer(integer, sign);
+            Literal::Integer(integer, sign, repr) => {
+                let int = HirLiteral::Integer(integer, sign, repr);
                 (Lit(int), self.polymorphic_integer_or_field())
             }
             Literal::Str(str) | Literal::RawStr(str, _) => {
@@ -262,11 +286,13 @@ impl<'context> Elaborator<'context> {
 
     fn elaborate_call(&mut self, call: CallExpression, span: Span) -> (HirExpression, Type) {
         let (func, func_type) = self.elaborate_expression(*call.func);
+        let parameter_types = func_type.function_parameter_types();
 
         let mut arguments = Vec::with_capacity(call.arguments.len());
-        let args = vecmap(call.arguments, |arg| {
+        let args = vecmap(call.arguments.into_iter().enumerate(), |(i, arg)| {
             let span = arg.span;
-            let (arg, typ) = self.elaborate_expression(arg);
+            let (arg, typ) =
+                self.elaborate_expression_with_target_type(arg, parameter_types.get(i));
             arguments.push(arg);
             (typ, arg, span)
         });
@@ -291,9 +317,11 @@ impl<'context> Elaborator<'context> {
             Some(method_ref) => {
                 // Automatically add `&mut` if the method expects a mutable reference and
                 // the object is not already one.
+                let mut parameter_types = Vec::new();
                 if let HirMethodReference::FuncId(func_id) = &method_ref {
                     if *func_id != FuncId::dummy_id() {
                         let function_type = self.interner.function_meta(func_id).typ.clone();
+                        parameter_types = function_type.function_parameter_types();
 
                         self.try_add_mutable_reference_to_object(
                             &function_type,
@@ -310,9 +338,11 @@ impl<'context> Elaborator<'context> {
 
                 function_args.push((object_type.clone(), object, object_span));
 
-                for arg in method_call.arguments {
+                for (i, arg) in method_call.arguments.into_iter().enumerate() {
                     let span = arg.span;
-                    let (arg, typ) = self.elaborate_expression(arg);
+                    // The first parameter of the method is the object
+                    let target_type = parameter_types.get(i + 1);
+                    let (arg, typ) = self.elaborate_expression_with_target_type(arg, target_type);
                     arguments.push(arg);
                     function_args.push((typ, arg, span));
                 }
@@ -404,6 +434,7 @@ impl<'context> Elaborator<'context> {
             if unseen_fields.contains(&field_name) {
                 unseen_fields.remove(&field_name);
                 seen_fields.insert(field_name.clone());
+                self.check_struct_field_visibility(&struct_type.borrow(), &field_name);
 
                 self.unify_with_coercions(&field_type, expected_type, resolved, || {
                     TypeCheckError::TypeMismatch {
@@ -508,7 +539,11 @@ impl<'context> Elaborator<'context> {
         (expr_id, typ)
     }
 
-    fn elaborate_if(&mut self, if_expr: IfExpression) -> (HirExpression, Type) {
+    fn elaborate_if(
+        &mut self,
+        if_expr: IfExpression,
+        target_type: Option<&Type>,
+    ) -> (HirExpression, Type) {
         let expr_span = if_expr.condition.span;
         let (condition, cond_type) = self.elaborate_expression(if_expr.condition);
         let (consequence, mut ret_type) = self.elaborate_expression(if_expr.consequence);
@@ -523,7 +558,7 @@ impl<'context> Elaborator<'context> {
             let expr_span = alternative.span;
             let (else_, else_type) = self.elaborate_expression(alternative);
 
-            self.unify(&ret_type, &else_type, || {
+            let make_error = || {
                 let err = TypeCheckError::TypeMismatch {
                     expected_typ: ret_type.to_string(),
                     expr_typ: else_type.to_string(),
@@ -539,7 +574,18 @@ impl<'context> Elaborator<'context> {
                 };
 
                 err.add_context(context)
-            });
+            };
+            let mut errors = Vec::new();
+            ret_type = ret_type.unify_if_branches(
+                &else_type,
+                target_type,
+                consequence,
+                else_,
+                self.interner,
+                &mut errors,
+                make_error,
+            );
+            self.errors.extend(errors.into_iter().map(|error| (error.into(), self.file)));
             else_
         });
 
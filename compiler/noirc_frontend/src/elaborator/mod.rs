@@ -290,7 +290,9 @@ impl<'context> Elaborator<'context> {
             }
             FunctionKind::Normal | FunctionKind::Recursive => {
                 let block_span = function.def.span;
-                let (block, body_type) = self.elaborate_block(function.def.body);
+                let return_type = func_meta.return_type();
+                let (block, body_type) =
+                    self.elaborate_block_with_target_type(function.def.body, Some(return_type));
                 let expr_id = self.intern_expr(block, block_span);
                 self.interner.push_expr_type(expr_id, body_type.clone());
                 (HirFunction::unchecked_from_expr(expr_id), body_type)
@@ -430,7 +432,8 @@ impl<'context> Elaborator<'context> {
     fn resolve_trait_by_path(&mut self, path: Path) -> Option<TraitId> {
         let path_resolver = StandardPathResolver::new(self.module_id());
 
-        let error = match path_resolver.resolve(self.def_maps, path.clone()) {
+        let usage_tracker = &mut self.interner.usage_tracker;
+        let error = match path_resolver.resolve(self.def_maps, path.clone(), usage_tracker) {
             Ok(PathResolution { module_def_id: ModuleDefId::TraitId(trait_id), error }) => {
                 if let Some(error) = error {
                     self.push_err(error);
@@ -622,6 +625,7 @@ impl<'context> Elaborator<'context> {
             trait_constraints: self.resolve_trait_constraints(&func.def.where_clause),
             is_entry_point,
             has_inline_attribute,
+            overflow_checks: !attributes.has_no_overflow_checks(),
         }
     }
 
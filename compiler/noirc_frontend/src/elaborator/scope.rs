@@ -46,7 +46,8 @@ impl<'context> Elaborator<'context> {
 
     pub(super) fn resolve_path(&mut self, path: Path) -> Result<ModuleDefId, ResolverError> {
         let resolver = StandardPathResolver::new(self.module_id());
-        let path_resolution = resolver.resolve(self.def_maps, path)?;
+        let path_resolution =
+            resolver.resolve(self.def_maps, path, &mut self.interner.usage_tracker)?;
 
         if let Some(error) = path_resolution.error {
             self.push_err(error);
@@ -1,38 +1,44 @@
-use noirc_errors::{Location, Span};
+use noirc_errors::{Location, Span, Spanned};
 
 use crate::{
-    ast::{AssignStatement, ConstrainStatement, LValue},
+    ast::{AssignStatement, ConstrainStatement, Ident, LValue, WhileStatement},
     hir::{
-        resolution::errors::ResolverError,
+        resolution::{check_struct_field_visibility, errors::ResolverError},
         type_check::{Source, TypeCheckError},
     },
     hir_def::{
         expr::HirIdent,
         stmt::{
             HirAssignStatement, HirConstrainStatement, HirForStatement, HirLValue, HirLetStatement,
+            HirWhileStatement,
         },
     },
     macros_api::{
         ForLoopStatement, ForRange, HirStatement, LetStatement, Statement, StatementKind,
     },
     node_interner::{DefinitionId, DefinitionKind, StmtId},
-    Type,
+    StructType, Type,
 };
 
 use super::Elaborator;
 
 impl<'context> Elaborator<'context> {
-    fn elaborate_statement_value(&mut self, statement: Statement) -> (HirStatement, Type) {
+    fn elaborate_statement_value(
+        &mut self,
+        statement: Statement,
+        target_type: Option<&Type>,
+    ) -> (HirStatement, Type) {
         match statement.kind {
             StatementKind::Let(let_stmt) => self.elaborate_let(let_stmt),
             StatementKind::Constrain(constrain) => self.elaborate_constrain(constrain),
-            StatementKind::Assign(assign) => self.elaborate_assign(assign),
+            StatementKind::Assign(assign) => self.elaborate_assign(assign, statement.span),
             StatementKind::For(for_stmt) => self.elaborate_for(for_stmt),
+            StatementKind::While(while_stmt) => self.elaborate_while(while_stmt),
             StatementKind::Break => self.elaborate_jump(true, statement.span),
             StatementKind::Continue => self.elaborate_jump(false, statement.span),
             StatementKind::Comptime(statement) => self.elaborate_comptime(*statement),
             StatementKind::Expression(expr) => {
-                let (expr, typ) = self.elaborate_expression(expr);
+                let (expr, typ) = self.elaborate_expression_with_target_type(expr, target_type);
                 (HirStatement::Expression(expr), typ)
             }
             StatementKind::Semi(expr) => {
@@ -44,8 +50,18 @@ impl<'context> Elaborator<'context> {
     }
 
     pub(super) fn elaborate_statement(&mut self, statement: Statement) -> (StmtId, Type) {
+        self.elaborate_statement_with_target_type(statement, None)
+    }
+
+    /// Elaborates `statement`, where `target_type` is the type it is expected to have
+    /// if it is an expression, if known.
+    pub(super) fn elaborate_statement_with_target_type(
+        &mut self,
+        statement: Statement,
+        target_type: Option<&Type>,
+    ) -> (StmtId, Type) {
         let span = statement.span;
-        let (hir_statement, typ) = self.elaborate_statement_value(statement);
+        let (hir_statement, typ) = self.elaborate_statement_value(statement, target_type);
         let id = self.interner.push_stmt(hir_statement);
         self.interner.push_stmt_location(id, span, self.file);
         (id, typ)
@@ -53,9 +69,11 @@ impl<'context> Elaborator<'context> {
 
     pub(super) fn elaborate_let(&mut self, let_stmt: LetStatement) -> (HirStatement, Type) {
         let expr_span = let_stmt.expression.span;
-        let (expression, expr_type) = self.elaborate_expression(let_stmt.expression);
-        let definition = DefinitionKind::Local(Some(expression));
         let annotated_type = self.resolve_type(let_stmt.r#type);
+        let target_type = (annotated_type != Type::Error).then_some(&annotated_type);
+        let (expression, expr_type) =
+            self.elaborate_expression_with_target_type(let_stmt.expression, target_type);
+        let definition = DefinitionKind::Local(Some(expression));
 
         // First check if the LHS is unspecified
         // If so, then we give it the same type as the expression
@@ -103,7 +121,16 @@ impl<'context> Elaborator<'context> {
         (HirStatement::Constrain(HirConstrainStatement(expr_id, self.file, msg)), Type::Unit)
     }
 
-    pub(super) fn elaborate_assign(&mut self, assign: AssignStatement) -> (HirStatement, Type) {
+    pub(super) fn elaborate_assign(
+        &mut self,
+        assign: AssignStatement,
+        statement_span: Span,
+    ) -> (HirStatement, Type) {
+        if assign.operator.is_some() {
+            let kind = assign.desugar_compound_operator(statement_span);
+            return self.elaborate_statement_value(Statement { kind, span: statement_span }, None);
+        }
+
         let span = assign.expression.span;
         let (expression, expr_type) = self.elaborate_expression(assign.expression);
         let (lvalue, lvalue_type, mutable) = self.elaborate_lvalue(assign.lvalue, span);
@@ -128,12 +155,12 @@ impl<'context> Elaborator<'context> {
 
     pub(super) fn elaborate_for(&mut self, for_loop: ForLoopStatement) -> (HirStatement, Type) {
         let (start, end) = match for_loop.range {
-            ForRange::Range(start, end) => (start, end),
+            ForRange::Range(bounds) => bounds.into_half_open(),
             ForRange::Array(_) => {
                 let for_stmt =
-                    for_loop.range.into_for(for_loop.identifier, for_loop.block, for_loop.span);
+                    for_loop.range.into_for(for_loop.pattern, for_loop.block, for_loop.span);
 
-                return self.elaborate_statement_value(for_stmt);
+                return self.elaborate_statement_value(for_stmt, None);
             }
         };
 
@@ -141,8 +168,23 @@ impl<'context> Elaborator<'context> {
         let end_span = end.span;
 
         let (start_range, start_range_type) = self.elaborate_expression(start);
+
+        // A loop over a collection is bounded by its length. Report a collection which can't be iterated
+        // over directly, and give it an error type so that its missing `len` method and indexing it
+        // aren't reported as well.
+        let collection = for_loop.collection.map(|collection| {
+            let (collection, _) = self.find_variable_or_default(&collection);
+            let collection_type = self.interner.definition_type(collection.id);
+            if !collection_type.is_iterable() {
+                let span = collection.location.span;
+                self.push_err(TypeCheckError::NotIterable { typ: collection_type, span });
+                self.interner.push_definition_type(collection.id, Type::Error);
+            }
+            collection
+        });
+
         let (end_range, end_range_type) = self.elaborate_expression(end);
-        let (identifier, block) = (for_loop.identifier, for_loop.block);
+        let (identifier, block) = (for_loop.pattern.into_ident(), for_loop.block);
 
         self.nested_loops += 1;
         self.push_scope();
@@ -175,12 +217,38 @@ impl<'context> Elaborator<'context> {
         self.pop_scope();
         self.nested_loops -= 1;
 
-        let statement =
-            HirStatement::For(HirForStatement { start_range, end_range, block, identifier });
+        let statement = HirStatement::For(HirForStatement {
+            start_range,
+            end_range,
+            block,
+            identifier,
+            collection,
+        });
 
         (statement, Type::Unit)
     }
 
+    pub(super) fn elaborate_while(&mut self, while_loop: WhileStatement) -> (HirStatement, Type) {
+        if !self.in_unconstrained_fn {
+            self.push_err(ResolverError::WhileInConstrainedFn { span: while_loop.span });
+        }
+
+        let expr_span = while_loop.condition.span;
+        let (condition, condition_type) = self.elaborate_expression(while_loop.condition);
+
+        self.unify(&condition_type, &Type::Bool, || TypeCheckError::TypeMismatch {
+            expected_typ: Type::Bool.to_string(),
+            expr_typ: condition_type.to_string(),
+            expr_span,
+        });
+
+        self.nested_loops += 1;
+        let (block, _block_type) = self.elaborate_expression(while_loop.block);
+        self.nested_loops -= 1;
+
+        (HirStatement::While(HirWhileStatement { condition, block }), Type::Unit)
+    }
+
     fn elaborate_jump(&mut self, is_break: bool, span: noirc_errors::Span) -> (HirStatement, Type) {
         if !self.in_unconstrained_fn {
             self.push_err(ResolverError::JumpInConstrainedFn { is_break, span });
@@ -335,6 +403,20 @@ impl<'context> Elaborator<'context> {
         }
     }
 
+    /// Issue an error if the given field of `struct_type` is private and is
+    /// being used outside of the module which defines the struct.
+    pub(super) fn check_struct_field_visibility(
+        &mut self,
+        struct_type: &StructType,
+        field: &Ident,
+    ) {
+        let module = self.module_id();
+        if let Err(error) = check_struct_field_visibility(self.def_maps, module, struct_type, field)
+        {
+            self.push_err(error);
+        }
+    }
+
     /// Type checks a field access, adding dereference operators as necessary
     pub(super) fn check_field_access(
         &mut self,
@@ -349,6 +431,8 @@ impl<'context> Elaborator<'context> {
             Type::Struct(s, args) => {
                 let s = s.borrow();
                 if let Some((field, index)) = s.get_field(field_name, args) {
+                    let field_name = Ident(Spanned::from(span, field_name.to_string()));
+                    self.check_struct_field_visibility(&s, &field_name);
                     return Some((field, index));
                 }
             }
@@ -12,7 +12,7 @@ use crate::{
             import::PathResolution,
             resolver::{verify_mutable_reference, SELF_TYPE_NAME},
         },
-        type_check::{Source, TypeCheckError},
+        type_check::{aliasing::check_argument_aliasing, Source, TypeCheckError},
     },
     hir_def::{
         expr::{
@@ -27,7 +27,8 @@ use crate::{
         UnaryOp, UnresolvedType, UnresolvedTypeData,
     },
     node_interner::{DefinitionKind, ExprId, GlobalId, TraitId, TraitImplKind, TraitMethodId},
-    Generics, Shared, StructType, Type, TypeAlias, TypeBinding, TypeVariable, TypeVariableKind,
+    BinaryTypeOperator, Generics, Shared, StructType, Type, TypeAlias, TypeBinding, TypeVariable,
+    TypeVariableKind,
 };
 
 use super::Elaborator;
@@ -306,18 +307,39 @@ impl<'context> Elaborator<'context> {
     pub(super) fn convert_expression_type(&mut self, length: UnresolvedTypeExpression) -> Type {
         match length {
             UnresolvedTypeExpression::Variable(path) => {
-                self.lookup_generic_or_global_type(&path).unwrap_or_else(|| {
+                if let Some(typ) = self.lookup_generic_or_global_type(&path) {
+                    return typ;
+                }
+
+                // Give a more specific error if this refers to a local variable, whose value
+                // is only known at runtime.
+                let local_variable = path.as_ident().filter(|ident| {
+                    self.scopes.current_scope_tree().find(&ident.0.contents).is_some()
+                });
+                if let Some(ident) = local_variable {
+                    let name = ident.0.contents.clone();
+                    self.push_err(ResolverError::NonConstantArrayLength {
+                        name,
+                        span: ident.span(),
+                    });
+                } else {
                     self.push_err(ResolverError::NoSuchNumericTypeVariable { path });
-                    Type::Constant(0)
-                })
+                }
+                Type::Constant(0)
             }
             UnresolvedTypeExpression::Constant(int, _) => Type::Constant(int),
-            UnresolvedTypeExpression::BinaryOperation(lhs, op, rhs, _) => {
+            UnresolvedTypeExpression::BinaryOperation(lhs, op, rhs, span) => {
                 let (lhs_span, rhs_span) = (lhs.span(), rhs.span());
                 let lhs = self.convert_expression_type(*lhs);
                 let rhs = self.convert_expression_type(*rhs);
 
                 match (lhs, rhs) {
+                    (Type::Constant(lhs), Type::Constant(rhs))
+                        if op == BinaryTypeOperator::Subtraction && rhs > lhs =>
+                    {
+                        self.push_err(ResolverError::NegativeArrayLength { span });
+                        Type::Constant(0)
+                    }
                     (Type::Constant(lhs), Type::Constant(rhs)) => {
                         Type::Constant(op.function()(lhs, rhs))
                     }
@@ -491,7 +513,7 @@ impl<'context> Elaborator<'context> {
         }
 
         match self.interner.expression(&rhs) {
-            HirExpression::Literal(HirLiteral::Integer(int, false)) => {
+            HirExpression::Literal(HirLiteral::Integer(int, false, _)) => {
                 int.try_into_u128().ok_or(Some(ResolverError::IntegerTooLarge { span }))
             }
             HirExpression::Ident(ident, _) => {
@@ -506,7 +528,10 @@ impl<'context> Elaborator<'context> {
                             Err(Some(ResolverError::InvalidArrayLengthExpr { span }))
                         }
                     }
-                    _ => Err(Some(ResolverError::InvalidArrayLengthExpr { span })),
+                    _ => {
+                        let span = self.interner.expr_span(&rhs);
+                        Err(Some(ResolverError::InvalidArrayLengthExpr { span }))
+                    }
                 }
             }
             HirExpression::Infix(infix) => {
@@ -532,7 +557,10 @@ impl<'context> Elaborator<'context> {
                     BinaryOpKind::Modulo => Ok(lhs % rhs),
                 }
             }
-            _other => Err(Some(ResolverError::InvalidArrayLengthExpr { span })),
+            _other => {
+                let span = self.interner.expr_span(&rhs);
+                Err(Some(ResolverError::InvalidArrayLengthExpr { span }))
+            }
         }
     }
 
@@ -542,16 +570,15 @@ impl<'context> Elaborator<'context> {
         let expr = self.interner.expression(rhs_expr);
         let span = self.interner.expr_span(rhs_expr);
         match expr {
-            HirExpression::Literal(HirLiteral::Integer(value, false)) => {
-                let v = value.to_u128();
+            HirExpression::Literal(HirLiteral::Integer(value, false, repr)) => {
                 if let Type::Integer(_, bit_count) = annotated_type {
                     let bit_count: u32 = (*bit_count).into();
-                    let max = 1 << bit_count;
-                    if v >= max {
+                    if value.num_bits() > bit_count {
+                        let max = u128::MAX >> (128 - bit_count);
                         self.push_err(TypeCheckError::OverflowingAssignment {
-                            expr: value,
+                            expr: repr.written_form().unwrap_or_else(|| value.to_string()),
                             ty: annotated_type.clone(),
-                            range: format!("0..={}", max - 1),
+                            range: format!("0..={max}"),
                             span,
                         });
                     };
@@ -724,6 +751,7 @@ impl<'context> Elaborator<'context> {
         fn_params: &[Type],
         fn_ret: &Type,
         callsite_args: &[(Type, ExprId, Span)],
+        parameter_spans: &[Span],
         span: Span,
     ) -> Type {
         if fn_params.len() != callsite_args.len() {
@@ -735,12 +763,20 @@ impl<'context> Elaborator<'context> {
             return Type::Error;
         }
 
-        for (param, (arg, _, arg_span)) in fn_params.iter().zip(callsite_args) {
-            self.unify(arg, param, || TypeCheckError::TypeMismatch {
+        for (index, (param, (arg, _, arg_span))) in fn_params.iter().zip(callsite_args).enumerate()
+        {
+            let mut errors = Vec::new();
+            arg.unify(param, &mut errors, || TypeCheckError::TypeMismatch {
                 expected_typ: param.to_string(),
                 expr_typ: arg.to_string(),
                 expr_span: *arg_span,
             });
+            for mut error in errors {
+                if let Some(parameter_span) = parameter_spans.get(index) {
+                    error = error.with_expected_span(*parameter_span);
+                }
+                self.push_err(error);
+            }
         }
 
         fn_ret.clone()
@@ -750,6 +786,7 @@ impl<'context> Elaborator<'context> {
         &mut self,
         function: Type,
         args: Vec<(Type, ExprId, Span)>,
+        parameter_spans: &[Span],
         span: Span,
     ) -> Type {
         // Could do a single unification for the entire function type, but matching beforehand
@@ -757,7 +794,7 @@ impl<'context> Elaborator<'context> {
         match function {
             Type::TypeVariable(binding, TypeVariableKind::Normal) => {
                 if let TypeBinding::Bound(typ) = &*binding.borrow() {
-                    return self.bind_function_type(typ.clone(), args, span);
+                    return self.bind_function_type(typ.clone(), args, parameter_spans, span);
                 }
 
                 let ret = self.interner.next_type_variable();
@@ -773,7 +810,7 @@ impl<'context> Elaborator<'context> {
             // The closure env is ignored on purpose: call arguments never place
             // constraints on closure environments.
             Type::Function(parameters, ret, _env) => {
-                self.bind_function_type_impl(&parameters, &ret, &args, span)
+                self.bind_function_type_impl(&parameters, &ret, &args, parameter_spans, span)
             }
             Type::Error => Type::Error,
             found => {
@@ -784,12 +821,12 @@ impl<'context> Elaborator<'context> {
     }
 
     pub(super) fn check_cast(&mut self, from: Type, to: &Type, span: Span) -> Type {
-        match from.follow_bindings() {
-            Type::Integer(..)
+        let from = match from.follow_bindings() {
+            from @ (Type::Integer(..)
             | Type::FieldElement
             | Type::TypeVariable(_, TypeVariableKind::IntegerOrField)
             | Type::TypeVariable(_, TypeVariableKind::Integer)
-            | Type::Bool => (),
+            | Type::Bool) => from,
 
             Type::TypeVariable(_, _) => {
                 self.push_err(TypeCheckError::TypeAnnotationsNeeded { span });
@@ -800,12 +837,18 @@ impl<'context> Elaborator<'context> {
                 self.push_err(TypeCheckError::InvalidCast { from, span });
                 return Type::Error;
             }
-        }
+        };
 
+        // Booleans may be cast to integers and fields, but the reverse would have to pick
+        // an interpretation for values other than 0 and 1 so it must be written as a comparison.
         match to {
             Type::Integer(sign, bits) => Type::Integer(*sign, *bits),
             Type::FieldElement => Type::FieldElement,
-            Type::Bool => Type::Bool,
+            Type::Bool if from == Type::Bool => Type::Bool,
+            Type::Bool => {
+                self.push_err(TypeCheckError::CastToBool { from, span });
+                Type::Error
+            }
             Type::Error => Type::Error,
             _ => {
                 self.push_err(TypeCheckError::UnsupportedCast { span });
@@ -1234,7 +1277,12 @@ impl<'context> Elaborator<'context> {
             }
         }
 
-        let return_type = self.bind_function_type(func_type, args, span);
+        for error in check_argument_aliasing(self.interner, &args) {
+            self.push_err(error);
+        }
+
+        let parameter_spans = self.interner.function_parameter_spans(call.func, self.file);
+        let return_type = self.bind_function_type(func_type, args, &parameter_spans, span);
 
         // Check that we are not passing a slice from an unconstrained runtime to a constrained runtime
         if is_current_func_constrained && is_unconstrained_call {
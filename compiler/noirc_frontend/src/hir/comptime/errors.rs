@@ -14,6 +14,7 @@ pub enum InterpreterError {
     ErrorNodeEncountered { location: Location },
     NonFunctionCalled { value: Value, location: Location },
     NonBoolUsedInIf { value: Value, location: Location },
+    NonBoolUsedInWhile { value: Value, location: Location },
     NonBoolUsedInConstrain { value: Value, location: Location },
     FailingConstraint { message: Option<Value>, location: Location },
     NoMethodFound { name: String, typ: Type, location: Location },
@@ -67,6 +68,7 @@ impl InterpreterError {
             | InterpreterError::ErrorNodeEncountered { location, .. }
             | InterpreterError::NonFunctionCalled { location, .. }
             | InterpreterError::NonBoolUsedInIf { location, .. }
+            | InterpreterError::NonBoolUsedInWhile { location, .. }
             | InterpreterError::NonBoolUsedInConstrain { location, .. }
             | InterpreterError::FailingConstraint { location, .. }
             | InterpreterError::NoMethodFound { location, .. }
@@ -146,6 +148,11 @@ impl<'a> From<&'a InterpreterError> for CustomDiagnostic {
                 let secondary = "If conditions must be a boolean value".to_string();
                 CustomDiagnostic::simple_error(msg, secondary, location.span)
             }
+            InterpreterError::NonBoolUsedInWhile { value, location } => {
+                let msg = format!("Expected a `bool` but found `{}`", value.get_type());
+                let secondary = "While conditions must be a boolean value".to_string();
+                CustomDiagnostic::simple_error(msg, secondary, location.span)
+            }
             InterpreterError::NonBoolUsedInConstrain { value, location } => {
                 let msg = format!("Expected a `bool` but found `{}`", value.get_type());
                 CustomDiagnostic::simple_error(msg, String::new(), location.span)
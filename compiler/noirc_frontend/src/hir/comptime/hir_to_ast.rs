@@ -3,10 +3,10 @@ use noirc_errors::{Span, Spanned};
 
 use crate::ast::{
     ArrayLiteral, AssignStatement, BlockExpression, CallExpression, CastExpression, ConstrainKind,
-    ConstructorExpression, ExpressionKind, ForLoopStatement, ForRange, Ident, IfExpression,
-    IndexExpression, InfixExpression, LValue, Lambda, LetStatement, Literal,
+    ConstructorExpression, ExpressionKind, ForBounds, ForLoopStatement, ForRange, Ident,
+    IfExpression, IndexExpression, InfixExpression, LValue, Lambda, LetStatement, Literal,
     MemberAccessExpression, MethodCallExpression, Path, Pattern, PrefixExpression, UnresolvedType,
-    UnresolvedTypeData, UnresolvedTypeExpression,
+    UnresolvedTypeData, UnresolvedTypeExpression, WhileStatement,
 };
 use crate::ast::{ConstrainStatement, Expression, Statement, StatementKind};
 use crate::hir_def::expr::{HirArrayLiteral, HirBlockExpression, HirExpression, HirIdent};
@@ -14,6 +14,7 @@ use crate::hir_def::stmt::{HirLValue, HirPattern, HirStatement};
 use crate::hir_def::types::Type;
 use crate::macros_api::HirLiteral;
 use crate::node_interner::{ExprId, NodeInterner, StmtId};
+use crate::token::LiteralRepr;
 
 // TODO:
 // - Full path for idents & types
@@ -49,17 +50,25 @@ impl StmtId {
                 StatementKind::Constrain(ConstrainStatement(expr, message, ConstrainKind::Assert))
             }
             HirStatement::Assign(assign) => StatementKind::Assign(AssignStatement {
+                operator: None,
                 lvalue: assign.lvalue.into_ast(interner),
                 expression: assign.expression.to_ast(interner),
             }),
             HirStatement::For(for_stmt) => StatementKind::For(ForLoopStatement {
-                identifier: for_stmt.identifier.to_ast(interner),
-                range: ForRange::Range(
-                    for_stmt.start_range.to_ast(interner),
-                    for_stmt.end_range.to_ast(interner),
-                ),
+                pattern: Pattern::Identifier(for_stmt.identifier.to_ast(interner)),
+                range: ForRange::Range(ForBounds {
+                    start: for_stmt.start_range.to_ast(interner),
+                    end: for_stmt.end_range.to_ast(interner),
+                    inclusive: false,
+                }),
                 block: for_stmt.block.to_ast(interner),
                 span,
+                collection: for_stmt.collection.map(|collection| collection.to_ast(interner)),
+            }),
+            HirStatement::While(while_stmt) => StatementKind::While(WhileStatement {
+                condition: while_stmt.condition.to_ast(interner),
+                block: while_stmt.block.to_ast(interner),
+                span,
             }),
             HirStatement::Break => StatementKind::Break,
             HirStatement::Continue => StatementKind::Continue,
@@ -100,8 +109,8 @@ impl ExprId {
             HirExpression::Literal(HirLiteral::Bool(value)) => {
                 ExpressionKind::Literal(Literal::Bool(value))
             }
-            HirExpression::Literal(HirLiteral::Integer(value, sign)) => {
-                ExpressionKind::Literal(Literal::Integer(value, sign))
+            HirExpression::Literal(HirLiteral::Integer(value, sign, repr)) => {
+                ExpressionKind::Literal(Literal::Integer(value, sign, repr))
             }
             HirExpression::Literal(HirLiteral::Str(string)) => {
                 ExpressionKind::Literal(Literal::Str(string))
@@ -349,7 +358,7 @@ impl HirArrayLiteral {
                 let repeated_element = Box::new(repeated_element.to_ast(interner));
                 let length = match length {
                     Type::Constant(length) => {
-                        let literal = Literal::Integer((length as u128).into(), false);
+                        let literal = Literal::Integer((length as u128).into(), false, LiteralRepr::Decimal);
                         let kind = ExpressionKind::Literal(literal);
                         Box::new(Expression::new(kind, span))
                     }
@@ -17,7 +17,7 @@ use crate::{
         },
         stmt::{
             HirAssignStatement, HirConstrainStatement, HirForStatement, HirLValue, HirLetStatement,
-            HirPattern,
+            HirPattern, HirWhileStatement,
         },
     },
     macros_api::{HirExpression, HirLiteral, HirStatement, NodeInterner},
@@ -367,7 +367,7 @@ impl<'a> Interpreter<'a> {
         match literal {
             HirLiteral::Unit => Ok(Value::Unit),
             HirLiteral::Bool(value) => Ok(Value::Bool(value)),
-            HirLiteral::Integer(value, is_negative) => {
+            HirLiteral::Integer(value, is_negative, _) => {
                 self.evaluate_integer(value, is_negative, id)
             }
             HirLiteral::Str(string) => Ok(Value::String(Rc::new(string))),
@@ -427,7 +427,15 @@ impl<'a> Interpreter<'a> {
                     let value = if is_negative { 0u64.wrapping_sub(value) } else { value };
                     Ok(Value::U64(value))
                 }
-                (Signedness::Signed, IntegerBitSize::One) => {
+                (Signedness::Unsigned, IntegerBitSize::HundredTwentyEight) => {
+                    let value: u128 = value.try_into_u128().ok_or(
+                        InterpreterError::IntegerOutOfRangeForType { value, typ, location },
+                    )?;
+                    let value = if is_negative { 0u128.wrapping_sub(value) } else { value };
+                    Ok(Value::U128(value))
+                }
+                (Signedness::Signed, IntegerBitSize::One)
+                | (Signedness::Signed, IntegerBitSize::HundredTwentyEight) => {
                     return Err(InterpreterError::TypeUnsupported { typ, location });
                 }
                 (Signedness::Signed, IntegerBitSize::Eight) => {
@@ -532,6 +540,7 @@ impl<'a> Interpreter<'a> {
                 Value::U16(value) => Ok(Value::U16(0 - value)),
                 Value::U32(value) => Ok(Value::U32(0 - value)),
                 Value::U64(value) => Ok(Value::U64(0 - value)),
+                Value::U128(value) => Ok(Value::U128(0 - value)),
                 value => {
                     let location = self.interner.expr_location(&id);
                     let operator = "minus";
@@ -548,6 +557,7 @@ impl<'a> Interpreter<'a> {
                 Value::U16(value) => Ok(Value::U16(!value)),
                 Value::U32(value) => Ok(Value::U32(!value)),
                 Value::U64(value) => Ok(Value::U64(!value)),
+                Value::U128(value) => Ok(Value::U128(!value)),
                 value => {
                     let location = self.interner.expr_location(&id);
                     Err(InterpreterError::InvalidValueForUnary { value, location, operator: "not" })
@@ -586,6 +596,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs + rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs + rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs + rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs + rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "+" })
@@ -601,6 +612,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs - rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs - rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs - rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs - rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "-" })
@@ -616,6 +628,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs * rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs * rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs * rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs * rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "*" })
@@ -631,6 +644,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs / rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs / rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs / rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs / rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "/" })
@@ -646,6 +660,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs == rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "==" })
@@ -661,6 +676,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs != rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "!=" })
@@ -676,6 +692,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs < rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "<" })
@@ -691,6 +708,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs <= rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "<=" })
@@ -706,6 +724,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs > rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: ">" })
@@ -721,6 +740,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs >= rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: ">=" })
@@ -736,6 +756,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs & rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs & rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs & rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs & rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "&" })
@@ -751,6 +772,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs | rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs | rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs | rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs | rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "|" })
@@ -766,6 +788,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs ^ rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs ^ rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs ^ rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs ^ rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "^" })
@@ -780,6 +803,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs >> rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs >> rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs >> rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs >> rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: ">>" })
@@ -794,6 +818,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs << rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs << rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs << rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs << rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "<<" })
@@ -808,6 +833,7 @@ impl<'a> Interpreter<'a> {
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs % rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs % rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs % rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs % rhs)),
                 (lhs, rhs) => {
                     let location = self.interner.expr_location(&id);
                     Err(InvalidValuesForBinary { lhs, rhs, location, operator: "%" })
@@ -854,6 +880,7 @@ impl<'a> Interpreter<'a> {
             Value::U16(value) => value as usize,
             Value::U32(value) => value as usize,
             Value::U64(value) => value as usize,
+            Value::U128(value) => value as usize,
             value => {
                 return Err(InterpreterError::NonIntegerUsedAsIndex { value, location });
             }
@@ -965,6 +992,7 @@ impl<'a> Interpreter<'a> {
             Value::U16(value) => ((value as u128).into(), false),
             Value::U32(value) => ((value as u128).into(), false),
             Value::U64(value) => ((value as u128).into(), false),
+            Value::U128(value) => (value.into(), false),
             Value::I8(value) => signed_int_to_field!(value),
             Value::I16(value) => signed_int_to_field!(value),
             Value::I32(value) => signed_int_to_field!(value),
@@ -1011,7 +1039,11 @@ impl<'a> Interpreter<'a> {
                 (Signedness::Unsigned, IntegerBitSize::SixtyFour) => {
                     cast_to_int!(lhs, to_u128, u64, U64)
                 }
-                (Signedness::Signed, IntegerBitSize::One) => {
+                (Signedness::Unsigned, IntegerBitSize::HundredTwentyEight) => {
+                    cast_to_int!(lhs, to_u128, u128, U128)
+                }
+                (Signedness::Signed, IntegerBitSize::One)
+                | (Signedness::Signed, IntegerBitSize::HundredTwentyEight) => {
                     let location = self.interner.expr_location(&id);
                     Err(InterpreterError::TypeUnsupported { typ: cast.r#type, location })
                 }
@@ -1083,6 +1115,7 @@ impl<'a> Interpreter<'a> {
             HirStatement::Constrain(constrain) => self.evaluate_constrain(constrain),
             HirStatement::Assign(assign) => self.evaluate_assign(assign),
             HirStatement::For(for_) => self.evaluate_for(for_),
+            HirStatement::While(while_) => self.evaluate_while(while_),
             HirStatement::Break => self.evaluate_break(statement),
             HirStatement::Continue => self.evaluate_continue(statement),
             HirStatement::Expression(expression) => self.evaluate(expression),
@@ -1207,7 +1240,7 @@ impl<'a> Interpreter<'a> {
     }
 
     fn evaluate_for(&mut self, for_: HirForStatement) -> IResult<Value> {
-        // i128 can store all values from i8 - u64
+        // i128 can store all values from i8 - u64, and any u128 range short enough to iterate
         let get_index = |this: &mut Self, expr| -> IResult<(_, fn(_) -> _)> {
             match this.evaluate(expr)? {
                 Value::I8(value) => Ok((value as i128, |i| Value::I8(i as i8))),
@@ -1218,6 +1251,14 @@ impl<'a> Interpreter<'a> {
                 Value::U16(value) => Ok((value as i128, |i| Value::U16(i as u16))),
                 Value::U32(value) => Ok((value as i128, |i| Value::U32(i as u32))),
                 Value::U64(value) => Ok((value as i128, |i| Value::U64(i as u64))),
+                Value::U128(value) => match i128::try_from(value) {
+                    Ok(value) => Ok((value, |i| Value::U128(i as u128))),
+                    Err(_) => {
+                        let item = "u128 loop bounds above i128::MAX";
+                        let location = this.interner.expr_location(&expr);
+                        Err(InterpreterError::Unimplemented { item, location })
+                    }
+                },
                 value => {
                     let location = this.interner.expr_location(&expr);
                     Err(InterpreterError::NonIntegerUsedInLoop { value, location })
@@ -1246,6 +1287,36 @@ impl<'a> Interpreter<'a> {
         Ok(Value::Unit)
     }
 
+    fn evaluate_while(&mut self, while_: HirWhileStatement) -> IResult<Value> {
+        let was_in_loop = std::mem::replace(&mut self.in_loop, true);
+
+        loop {
+            let condition = match self.evaluate(while_.condition)? {
+                Value::Bool(value) => value,
+                value => {
+                    let location = self.interner.expr_location(&while_.condition);
+                    return Err(InterpreterError::NonBoolUsedInWhile { value, location });
+                }
+            };
+            if !condition {
+                break;
+            }
+
+            self.push_scope();
+            let result = self.evaluate(while_.block);
+            self.pop_scope();
+
+            match result {
+                Ok(_) | Err(InterpreterError::Continue) => (),
+                Err(InterpreterError::Break) => break,
+                Err(other) => return Err(other),
+            }
+        }
+
+        self.in_loop = was_in_loop;
+        Ok(Value::Unit)
+    }
+
     fn evaluate_break(&mut self, id: StmtId) -> IResult<Value> {
         if self.in_loop {
             Err(InterpreterError::Break)
@@ -17,7 +17,7 @@ use crate::{
             HirIdent, HirIfExpression, HirIndexExpression, HirInfixExpression, HirLambda,
             HirMethodCallExpression,
         },
-        stmt::HirForStatement,
+        stmt::{HirForStatement, HirWhileStatement},
     },
     macros_api::{HirExpression, HirLiteral, HirStatement},
     node_interner::{DefinitionKind, ExprId, FuncId, GlobalId, StmtId},
@@ -141,7 +141,7 @@ impl<'interner> Interpreter<'interner> {
                 }
             },
             HirLiteral::Bool(_)
-            | HirLiteral::Integer(_, _)
+            | HirLiteral::Integer(..)
             | HirLiteral::Str(_)
             | HirLiteral::FmtStr(_, _)
             | HirLiteral::Unit => Ok(()),
@@ -222,6 +222,7 @@ impl<'interner> Interpreter<'interner> {
             HirStatement::Constrain(constrain) => self.scan_expression(constrain.0),
             HirStatement::Assign(assign) => self.scan_expression(assign.expression),
             HirStatement::For(for_) => self.scan_for(for_),
+            HirStatement::While(while_) => self.scan_while(while_),
             HirStatement::Break => Ok(()),
             HirStatement::Continue => Ok(()),
             HirStatement::Expression(expression) => self.scan_expression(expression),
@@ -247,6 +248,14 @@ impl<'interner> Interpreter<'interner> {
         Ok(())
     }
 
+    fn scan_while(&mut self, while_: HirWhileStatement) -> IResult<()> {
+        self.scan_expression(while_.condition)?;
+        self.push_scope();
+        self.scan_expression(while_.block)?;
+        self.pop_scope();
+        Ok(())
+    }
+
     fn inline_expression(&mut self, value: Value, expr: ExprId) -> IResult<()> {
         let location = self.interner.expr_location(&expr);
         let new_expr = value.into_expression(self.interner, location)?;
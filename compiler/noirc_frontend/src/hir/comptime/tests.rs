@@ -148,6 +148,31 @@ fn for_loop_with_continue() {
     assert_eq!(result, Value::U64(11));
 }
 
+#[test]
+fn u128_arithmetic() {
+    let program = "unconstrained fn main() -> pub u128 {
+        let max: u128 = 340282366920938463463374607431768211455;
+        let mut x = (max / 18446744073709551616) * 3 + (max as Field as u128 & 0xff);
+        for i in 0 .. 3 {
+            x += i;
+        }
+        assert(x < max);
+        x
+    }";
+    let result = interpret(program, vec!["main".into()]);
+    assert_eq!(result, Value::U128(u64::MAX as u128 * 3 + 0xff + 3));
+}
+
+#[test]
+fn u128_loop_bounds_above_i128_max_are_unimplemented() {
+    let program = "unconstrained fn main() {
+        let start: u128 = 170141183460469231731687303715884105728;
+        for _ in start .. start + 2 {}
+    }";
+    let result = interpret_expect_error(program, vec!["main".into()]);
+    assert!(matches!(result, InterpreterError::Unimplemented { .. }), "{result:?}");
+}
+
 #[test]
 fn assert() {
     let program = "fn main() {
@@ -10,6 +10,7 @@ use crate::{
     hir_def::expr::{HirArrayLiteral, HirConstructorExpression, HirIdent, HirLambda, ImplKind},
     macros_api::{HirExpression, HirLiteral, NodeInterner},
     node_interner::{ExprId, FuncId},
+    token::LiteralRepr,
     Shared, Type,
 };
 use rustc_hash::FxHashMap as HashMap;
@@ -29,6 +30,7 @@ pub enum Value {
     U16(u16),
     U32(u32),
     U64(u64),
+    U128(u128),
     String(Rc<String>),
     Function(FuncId, Type),
     Closure(HirLambda, Vec<Value>, Type),
@@ -54,6 +56,9 @@ impl Value {
             Value::U16(_) => Type::Integer(Signedness::Unsigned, IntegerBitSize::Sixteen),
             Value::U32(_) => Type::Integer(Signedness::Unsigned, IntegerBitSize::ThirtyTwo),
             Value::U64(_) => Type::Integer(Signedness::Unsigned, IntegerBitSize::SixtyFour),
+            Value::U128(_) => {
+                Type::Integer(Signedness::Unsigned, IntegerBitSize::HundredTwentyEight)
+            }
             Value::String(value) => {
                 let length = Type::Constant(value.len() as u64);
                 Type::String(Box::new(length))
@@ -84,43 +89,58 @@ impl Value {
         let expression = match self {
             Value::Unit => HirExpression::Literal(HirLiteral::Unit),
             Value::Bool(value) => HirExpression::Literal(HirLiteral::Bool(value)),
-            Value::Field(value) => HirExpression::Literal(HirLiteral::Integer(value, false)),
+            Value::Field(value) => {
+                HirExpression::Literal(HirLiteral::Integer(value, false, LiteralRepr::Decimal))
+            }
             Value::I8(value) => {
                 let negative = value < 0;
                 let value = value.abs();
                 let value = (value as u128).into();
-                HirExpression::Literal(HirLiteral::Integer(value, negative))
+                HirExpression::Literal(HirLiteral::Integer(value, negative, LiteralRepr::Decimal))
             }
             Value::I16(value) => {
                 let negative = value < 0;
                 let value = value.abs();
                 let value = (value as u128).into();
-                HirExpression::Literal(HirLiteral::Integer(value, negative))
+                HirExpression::Literal(HirLiteral::Integer(value, negative, LiteralRepr::Decimal))
             }
             Value::I32(value) => {
                 let negative = value < 0;
                 let value = value.abs();
                 let value = (value as u128).into();
-                HirExpression::Literal(HirLiteral::Integer(value, negative))
+                HirExpression::Literal(HirLiteral::Integer(value, negative, LiteralRepr::Decimal))
             }
             Value::I64(value) => {
                 let negative = value < 0;
                 let value = value.abs();
                 let value = (value as u128).into();
-                HirExpression::Literal(HirLiteral::Integer(value, negative))
-            }
-            Value::U8(value) => {
-                HirExpression::Literal(HirLiteral::Integer((value as u128).into(), false))
-            }
-            Value::U16(value) => {
-                HirExpression::Literal(HirLiteral::Integer((value as u128).into(), false))
-            }
-            Value::U32(value) => {
-                HirExpression::Literal(HirLiteral::Integer((value as u128).into(), false))
-            }
-            Value::U64(value) => {
-                HirExpression::Literal(HirLiteral::Integer((value as u128).into(), false))
-            }
+                HirExpression::Literal(HirLiteral::Integer(value, negative, LiteralRepr::Decimal))
+            }
+            Value::U8(value) => HirExpression::Literal(HirLiteral::Integer(
+                (value as u128).into(),
+                false,
+                LiteralRepr::Decimal,
+            )),
+            Value::U16(value) => HirExpression::Literal(HirLiteral::Integer(
+                (value as u128).into(),
+                false,
+                LiteralRepr::Decimal,
+            )),
+            Value::U32(value) => HirExpression::Literal(HirLiteral::Integer(
+                (value as u128).into(),
+                false,
+                LiteralRepr::Decimal,
+            )),
+            Value::U64(value) => HirExpression::Literal(HirLiteral::Integer(
+                (value as u128).into(),
+                false,
+                LiteralRepr::Decimal,
+            )),
+            Value::U128(value) => HirExpression::Literal(HirLiteral::Integer(
+                value.into(),
+                false,
+                LiteralRepr::Decimal,
+            )),
             Value::String(value) => HirExpression::Literal(HirLiteral::Str(unwrap_rc(value))),
             Value::Function(id, _typ) => {
                 let id = interner.function_definition_id(id);
@@ -7,6 +7,7 @@ use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleId};
 use crate::hir::resolution::errors::ResolverError;
 
 use crate::hir::resolution::import::{resolve_import, ImportDirective, PathResolution};
+use crate::hir::resolution::usage_tracker::UsageTracker;
 use crate::hir::resolution::{
     collect_impls, collect_trait_impls, path_resolver, resolve_free_functions, resolve_globals,
     resolve_impls, resolve_structs, resolve_trait_by_path, resolve_trait_impls, resolve_traits,
@@ -25,6 +26,7 @@ use crate::ast::{
     NoirTypeAlias, Path, PathKind, UnresolvedGenerics, UnresolvedTraitConstraint, UnresolvedType,
 };
 use crate::parser::{ParserError, SortedModule};
+use crate::token::SecondaryAttribute;
 use fm::FileId;
 use iter_extended::vecmap;
 use noirc_errors::{CustomDiagnostic, Span};
@@ -62,6 +64,7 @@ impl UnresolvedFunctions {
         &mut self,
         def_maps: &BTreeMap<CrateId, CrateDefMap>,
         crate_id: CrateId,
+        usage_tracker: &mut UsageTracker,
     ) -> Vec<DefCollectorErrorKind> {
         let mut errors = Vec::new();
 
@@ -69,8 +72,8 @@ impl UnresolvedFunctions {
             let module = ModuleId { krate: crate_id, local_id: *local_id };
 
             for bound in &mut func.def.where_clause {
-                match resolve_trait_by_path(def_maps, module, bound.trait_bound.trait_path.clone())
-                {
+                let trait_path = bound.trait_bound.trait_path.clone();
+                match resolve_trait_by_path(def_maps, module, trait_path, usage_tracker) {
                     Ok((trait_id, warning)) => {
                         bound.trait_bound.trait_id = Some(trait_id);
                         if let Some(warning) = warning {
@@ -299,7 +302,8 @@ impl DefCollector {
 
         // Resolve unresolved imports collected from the crate, one by one.
         for collected_import in std::mem::take(&mut def_collector.imports) {
-            match resolve_import(crate_id, &collected_import, &context.def_maps) {
+            let usage_tracker = &mut context.def_interner.usage_tracker;
+            match resolve_import(crate_id, &collected_import, &context.def_maps, usage_tracker) {
                 Ok(resolved_import) => {
                     if let Some(error) = resolved_import.error {
                         errors.push((
@@ -312,19 +316,30 @@ impl DefCollector {
                     let current_def_map = context.def_maps.get_mut(&crate_id).unwrap();
 
                     let name = resolved_import.name;
+                    let mut imported = false;
                     for ns in resolved_import.resolved_namespace.iter_defs() {
                         let result = current_def_map.modules[resolved_import.module_scope.0]
                             .import(name.clone(), ns, resolved_import.is_prelude);
 
-                        if let Err((first_def, second_def)) = result {
-                            let err = DefCollectorErrorKind::Duplicate {
-                                typ: DuplicateType::Import,
-                                first_def,
-                                second_def,
-                            };
-                            errors.push((err.into(), root_file_id));
+                        match result {
+                            Ok(()) => imported = true,
+                            Err((first_def, second_def)) => {
+                                let err = DefCollectorErrorKind::Duplicate {
+                                    typ: DuplicateType::Import,
+                                    first_def,
+                                    second_def,
+                                };
+                                errors.push((err.into(), root_file_id));
+                            }
                         }
                     }
+
+                    // Prelude imports are implicit, so they are never reported as unused
+                    if imported && !resolved_import.is_prelude {
+                        let module =
+                            ModuleId { krate: crate_id, local_id: resolved_import.module_scope };
+                        context.def_interner.usage_tracker.add_import(module, name);
+                    }
                 }
                 Err(error) => {
                     let current_def_map = context.def_maps.get(&crate_id).unwrap();
@@ -338,7 +353,8 @@ impl DefCollector {
         if use_elaborator {
             let mut more_errors = Elaborator::elaborate(context, crate_id, def_collector.items);
             more_errors.append(&mut errors);
-            return errors;
+            more_errors.extend(check_unused_items(context, crate_id));
+            return more_errors;
         }
 
         let mut resolved_module = ResolvedModule { errors, ..Default::default() };
@@ -428,19 +444,70 @@ impl DefCollector {
         let cycles_present = !cycle_errors.is_empty();
         resolved_module.errors.extend(cycle_errors);
 
-        resolved_module.type_check(context);
+        resolved_module.type_check(context, crate_id);
 
         if !cycles_present {
             resolved_module.evaluate_comptime(&mut context.def_interner);
         }
 
+        resolved_module.errors.extend(check_unused_items(context, crate_id));
         resolved_module.errors
     }
 }
 
+/// Reports the imports and `mod` declarations of the crate which were never used.
+///
+/// Imports can be allowed to go unused with `#![allow(unused_imports)]` in the module importing
+/// them or in one of its parents. Modules are only reported in binary crates since the modules
+/// of a library may be used by its dependents.
+fn check_unused_items(context: &mut Context, crate_id: CrateId) -> Vec<(CompilationError, FileId)> {
+    if crate_id.is_stdlib() {
+        return Vec::new();
+    }
+
+    let mut errors = Vec::new();
+    let has_main = context.get_main_function(&crate_id).is_some();
+    let def_map = &context.def_maps[&crate_id];
+    let usage_tracker = &mut context.def_interner.usage_tracker;
+
+    for (module, ident) in usage_tracker.take_unused_imports(crate_id) {
+        if !is_lint_allowed(def_map, module.local_id, "unused_imports") {
+            let error = ResolverError::UnusedImport { ident };
+            errors.push((error.into(), def_map.file_id(module.local_id)));
+        }
+    }
+
+    let unused_modules = usage_tracker.take_unused_modules(crate_id);
+    if has_main {
+        for (module, ident) in unused_modules {
+            let file_id = def_map.modules[module.local_id.0].location.file;
+            errors.push((ResolverError::UnusedModule { ident }.into(), file_id));
+        }
+    }
+
+    errors
+}
+
+/// Whether the lint is allowed by an inner attribute of the module or of one of its parents.
+fn is_lint_allowed(def_map: &CrateDefMap, mut module: LocalModuleId, lint: &str) -> bool {
+    loop {
+        let module_data = &def_map.modules[module.0];
+        let allowed = module_data.attributes.iter().any(
+            |attribute| matches!(attribute, SecondaryAttribute::Allow(allowed) if allowed == lint),
+        );
+        if allowed {
+            return true;
+        }
+        match module_data.parent {
+            Some(parent) => module = parent,
+            None => return false,
+        }
+    }
+}
+
 fn inject_prelude(
     crate_id: CrateId,
-    context: &Context,
+    context: &mut Context,
     crate_root: LocalModuleId,
     collected_imports: &mut Vec<ImportDirective>,
 ) {
@@ -460,6 +527,7 @@ fn inject_prelude(
             &context.def_maps,
             ModuleId { krate: crate_id, local_id: crate_root },
             path,
+            &mut context.def_interner.usage_tracker,
         ) {
             assert!(error.is_none(), "Tried to add private item to prelude");
             let module_id = module_def_id.as_module().expect("std::prelude should be a module");
@@ -496,34 +564,47 @@ fn filter_literal_globals(
 }
 
 impl ResolvedModule {
-    fn type_check(&mut self, context: &mut Context) {
-        self.type_check_globals(&mut context.def_interner);
-        self.type_check_functions(&mut context.def_interner);
-        self.type_check_trait_impl_function(&mut context.def_interner);
+    fn type_check(&mut self, context: &mut Context, crate_id: CrateId) {
+        self.type_check_globals(&mut context.def_interner, crate_id, &context.def_maps);
+        self.type_check_functions(&mut context.def_interner, &context.def_maps);
+        self.type_check_trait_impl_function(&mut context.def_interner, &context.def_maps);
     }
 
-    fn type_check_globals(&mut self, interner: &mut NodeInterner) {
+    fn type_check_globals(
+        &mut self,
+        interner: &mut NodeInterner,
+        crate_id: CrateId,
+        def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    ) {
         for (file_id, global_id) in self.globals.iter() {
-            for error in TypeChecker::check_global(*global_id, interner) {
+            for error in TypeChecker::check_global(*global_id, crate_id, interner, def_maps) {
                 self.errors.push((error.into(), *file_id));
             }
         }
     }
 
-    fn type_check_functions(&mut self, interner: &mut NodeInterner) {
+    fn type_check_functions(
+        &mut self,
+        interner: &mut NodeInterner,
+        def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    ) {
         for (file, func) in self.functions.iter() {
-            for error in type_check_func(interner, *func) {
+            for error in type_check_func(interner, def_maps, *func) {
                 self.errors.push((error.into(), *file));
             }
         }
     }
 
-    fn type_check_trait_impl_function(&mut self, interner: &mut NodeInterner) {
+    fn type_check_trait_impl_function(
+        &mut self,
+        interner: &mut NodeInterner,
+        def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    ) {
         for (file, func) in self.trait_impl_functions.iter() {
             for error in check_trait_impl_method_matches_declaration(interner, *func) {
                 self.errors.push((error.into(), *file));
             }
-            for error in type_check_func(interner, *func) {
+            for error in type_check_func(interner, def_maps, *func) {
                 self.errors.push((error.into(), *file));
             }
         }
@@ -15,6 +15,7 @@ use crate::{
     macros_api::MacroProcessor,
     node_interner::{FunctionModifiers, TraitId, TypeAliasId},
     parser::{SortedModule, SortedSubModule},
+    token::SecondaryAttribute,
 };
 
 use super::{
@@ -50,6 +51,21 @@ pub fn collect_defs(
     let mut collector = ModCollector { def_collector, file_id, module_id };
     let mut errors: Vec<(CompilationError, FileId)> = vec![];
 
+    collector.def_collector.def_map.modules[module_id.0].attributes = ast.inner_attributes;
+
+    // Impls and entry points are used without being referenced by path, so the module
+    // containing them is never reported as unused
+    let has_implicit_uses = !ast.impls.is_empty()
+        || !ast.trait_impls.is_empty()
+        || ast.functions.iter().any(|function| {
+            let attributes = function.attributes();
+            attributes.is_test_function()
+                || attributes.secondary.contains(&SecondaryAttribute::Export)
+        });
+    if has_implicit_uses {
+        collector.mark_module_and_parents_as_referenced(context, crate_id);
+    }
+
     // First resolve the module declarations
     for decl in ast.module_decls {
         errors.extend(collector.parse_module_declaration(
@@ -524,6 +540,10 @@ impl<'a> ModCollector<'a> {
         for submodule in submodules {
             match self.push_child_module(&submodule.name, file_id, true, submodule.is_contract) {
                 Ok(child) => {
+                    if !submodule.is_contract {
+                        let module = ModuleId { krate: crate_id, local_id: child };
+                        context.def_interner.usage_tracker.add_module(module, submodule.name);
+                    }
                     errors.extend(collect_defs(
                         self.def_collector,
                         submodule.contents,
@@ -556,10 +576,13 @@ impl<'a> ModCollector<'a> {
         let child_file_id =
             match find_module(&context.file_manager, self.file_id, &mod_decl.ident.0.contents) {
                 Ok(child_file_id) => child_file_id,
-                Err(expected_path) => {
+                Err((expected_path, alternative_path)) => {
                     let mod_name = mod_decl.ident.clone();
-                    let err =
-                        DefCollectorErrorKind::UnresolvedModuleDecl { mod_name, expected_path };
+                    let err = DefCollectorErrorKind::UnresolvedModuleDecl {
+                        mod_name,
+                        expected_path,
+                        alternative_path,
+                    };
                     errors.push((err.into(), self.file_id));
                     return errors;
                 }
@@ -567,6 +590,16 @@ impl<'a> ModCollector<'a> {
 
         let location = Location { file: self.file_id, span: mod_decl.ident.span() };
 
+        if self.is_file_of_enclosing_module(child_file_id) {
+            let path = context.file_manager.path(child_file_id).expect("File must exist");
+            let error = DefCollectorErrorKind::ModuleCycle {
+                mod_name: mod_decl.ident.clone(),
+                path: path.to_string_lossy().to_string(),
+            };
+            errors.push((error.into(), location.file));
+            return errors;
+        }
+
         if let Some(old_location) = context.visited_files.get(&child_file_id) {
             let error = DefCollectorErrorKind::ModuleAlreadyPartOfCrate {
                 mod_name: mod_decl.ident.clone(),
@@ -612,6 +645,8 @@ impl<'a> ModCollector<'a> {
         // Add module into def collector and get a ModuleId
         match self.push_child_module(&mod_decl.ident, child_file_id, true, false) {
             Ok(child_mod_id) => {
+                let module = ModuleId { krate: crate_id, local_id: child_mod_id };
+                context.def_interner.usage_tracker.add_module(module, mod_decl.ident.clone());
                 errors.extend(collect_defs(
                     self.def_collector,
                     ast,
@@ -629,6 +664,32 @@ impl<'a> ModCollector<'a> {
         errors
     }
 
+    fn mark_module_and_parents_as_referenced(&self, context: &mut Context, krate: CrateId) {
+        let modules = &self.def_collector.def_map.modules;
+        let mut module_id = Some(self.module_id);
+        while let Some(local_id) = module_id {
+            context
+                .def_interner
+                .usage_tracker
+                .mark_module_as_referenced(ModuleId { krate, local_id });
+            module_id = modules[local_id.0].parent;
+        }
+    }
+
+    /// Returns true if `file_id` is the file of the current module or of any module which contains it.
+    fn is_file_of_enclosing_module(&self, file_id: FileId) -> bool {
+        let modules = &self.def_collector.def_map.modules;
+        let mut module_id = Some(self.module_id);
+        while let Some(id) = module_id {
+            let module = &modules[id.0];
+            if module.location.file == file_id {
+                return true;
+            }
+            module_id = module.parent;
+        }
+        false
+    }
+
     /// Add a child module to the current def_map.
     /// On error this returns None and pushes to `errors`
     fn push_child_module(
@@ -677,11 +738,15 @@ impl<'a> ModCollector<'a> {
     }
 }
 
+/// Finds the file for the module `mod_name` declared in `anchor`.
+///
+/// A module may either be defined in `mod_name.nr` or in `mod_name/mod.nr`.
+/// If neither file exists then both of the paths which were tried are returned.
 fn find_module(
     file_manager: &FileManager,
     anchor: FileId,
     mod_name: &str,
-) -> Result<FileId, String> {
+) -> Result<FileId, (String, String)> {
     let anchor_path = file_manager
         .path(anchor)
         .expect("File must exist in file manager in order for us to be resolving its imports.")
@@ -690,16 +755,25 @@ fn find_module(
 
     // if `anchor` is a `main.nr`, `lib.nr`, `mod.nr` or `{mod_name}.nr`, we check siblings of
     // the anchor at `base/mod_name.nr`.
-    let candidate = if should_check_siblings_for_module(&anchor_path, anchor_dir) {
-        anchor_dir.join(format!("{mod_name}.{FILE_EXTENSION}"))
+    let base = if should_check_siblings_for_module(&anchor_path, anchor_dir) {
+        anchor_dir.to_path_buf()
     } else {
         // Otherwise, we check for children of the anchor at `base/anchor/mod_name.nr`
-        anchor_path.join(format!("{mod_name}.{FILE_EXTENSION}"))
+        anchor_path
     };
 
+    let candidate = base.join(format!("{mod_name}.{FILE_EXTENSION}"));
+    let alternative_candidate = base.join(mod_name).join(format!("mod.{FILE_EXTENSION}"));
+
     file_manager
         .name_to_id(candidate.clone())
-        .ok_or_else(|| candidate.as_os_str().to_string_lossy().to_string())
+        .or_else(|| file_manager.name_to_id(alternative_candidate.clone()))
+        .ok_or_else(|| {
+            (
+                candidate.as_os_str().to_string_lossy().to_string(),
+                alternative_candidate.as_os_str().to_string_lossy().to_string(),
+            )
+        })
 }
 
 /// Returns true if a module's child modules are expected to be in the same directory.
@@ -27,7 +27,7 @@ pub enum DefCollectorErrorKind {
     #[error("duplicate {typ} found in namespace")]
     Duplicate { typ: DuplicateType, first_def: Ident, second_def: Ident },
     #[error("unresolved import")]
-    UnresolvedModuleDecl { mod_name: Ident, expected_path: String },
+    UnresolvedModuleDecl { mod_name: Ident, expected_path: String, alternative_path: String },
     #[error("path resolution error")]
     PathResolutionError(PathResolutionError),
     #[error("Non-struct type used in impl")]
@@ -60,6 +60,8 @@ pub enum DefCollectorErrorKind {
     ModuleAlreadyPartOfCrate { mod_name: Ident, span: Span },
     #[error("Module was originally declared here")]
     ModuleOriginallyDefined { mod_name: Ident, span: Span },
+    #[error("Module declaration would include its own file")]
+    ModuleCycle { mod_name: Ident, path: String },
     #[error(
         "Either the type or the trait must be from the same crate as the trait implementation"
     )]
@@ -119,12 +121,12 @@ impl<'a> From<&'a DefCollectorErrorKind> for Diagnostic {
                     diag
                 }
             }
-            DefCollectorErrorKind::UnresolvedModuleDecl { mod_name, expected_path } => {
+            DefCollectorErrorKind::UnresolvedModuleDecl { mod_name, expected_path, alternative_path } => {
                 let span = mod_name.0.span();
                 let mod_name = &mod_name.0.contents;
 
                 Diagnostic::simple_error(
-                    format!("No module `{mod_name}` at path `{expected_path}`"),
+                    format!("No module `{mod_name}` at path `{expected_path}` or `{alternative_path}`"),
                     String::new(),
                     span,
                 )
@@ -220,6 +222,16 @@ impl<'a> From<&'a DefCollectorErrorKind> for Diagnostic {
                 let secondary = String::new();
                 Diagnostic::simple_error(message, secondary, *span)
             }
+            DefCollectorErrorKind::ModuleCycle { mod_name, path } => {
+                let span = mod_name.0.span();
+                let mod_name = &mod_name.0.contents;
+
+                Diagnostic::simple_error(
+                    format!("Module `{mod_name}` cannot be declared within itself"),
+                    format!("`{path}` is already being compiled as this module or one of its parents"),
+                    span,
+                )
+            }
             DefCollectorErrorKind::TraitImplOrphaned { span } => Diagnostic::simple_error(
                 "Orphaned trait implementation".into(),
                 "Either the type or the trait must be from the same crate as the trait implementation".into(),
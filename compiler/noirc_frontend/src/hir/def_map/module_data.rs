@@ -5,6 +5,7 @@ use noirc_errors::Location;
 use super::{ItemScope, LocalModuleId, ModuleDefId, ModuleId, PerNs};
 use crate::ast::{Ident, ItemVisibility};
 use crate::node_interner::{FuncId, GlobalId, StructId, TraitId, TypeAliasId};
+use crate::token::SecondaryAttribute;
 
 /// Contains the actual contents of a module: its parent (if one exists),
 /// children, and scope with all definitions defined within the scope.
@@ -24,6 +25,9 @@ pub struct ModuleData {
 
     /// True if this module is a `contract Foo { ... }` module containing contract functions
     pub is_contract: bool,
+
+    /// Inner attributes applying to the whole module, such as `#![allow(unused_imports)]`
+    pub attributes: Vec<SecondaryAttribute>,
 }
 
 impl ModuleData {
@@ -35,6 +39,7 @@ impl ModuleData {
             definitions: ItemScope::default(),
             location,
             is_contract,
+            attributes: Vec::new(),
         }
     }
 
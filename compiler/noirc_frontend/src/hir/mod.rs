@@ -1,6 +1,7 @@
 pub mod comptime;
 pub mod def_collector;
 pub mod def_map;
+pub mod reachability;
 pub mod resolution;
 pub mod scope;
 pub mod type_check;
@@ -246,6 +247,38 @@ impl Context<'_, '_> {
             .collect()
     }
 
+    /// Returns every function marked `#[no_overflow_checks]` in any crate, sorted by name.
+    pub fn get_all_unchecked_functions(&self) -> Vec<FuncId> {
+        let mut functions: Vec<_> = self
+            .def_interner
+            .func_meta
+            .iter()
+            .filter(|(_, meta)| !meta.overflow_checks)
+            .map(|(func_id, _)| *func_id)
+            .collect();
+        functions.sort_by_key(|func_id| self.function_name(func_id));
+        functions
+    }
+
+    /// Returns every function with a body defined in `crate_id` which can't be reached from `main`,
+    /// sorted by name.
+    pub fn get_unreachable_functions(&self, crate_id: &CrateId, main: FuncId) -> Vec<FuncId> {
+        let reachable = reachability::reachable_functions(&self.def_interner, main);
+        let mut functions: Vec<_> = self
+            .def_interner
+            .func_meta
+            .iter()
+            .filter(|(func_id, meta)| {
+                meta.has_body
+                    && !reachable.contains(func_id)
+                    && self.def_interner.function_module(**func_id).krate == *crate_id
+            })
+            .map(|(func_id, _)| *func_id)
+            .collect();
+        functions.sort_by_key(|func_id| self.function_name(func_id));
+        functions
+    }
+
     /// Return a Vec of all `contract` declarations in the source code and the functions they contain
     pub fn get_all_contracts(&self, crate_id: &CrateId) -> Vec<Contract> {
         self.def_map(crate_id)
@@ -0,0 +1,153 @@
+//! Finds the functions which may be called when a program is executed from `main`.
+//!
+//! The analysis walks the Hir of each reachable function, following every identifier which
+//! refers to a function. It errs on the side of reachability: trait impl methods are only
+//! selected during monomorphization so they are all assumed to be reachable, as are comptime
+//! functions and any function referenced from a global.
+use std::collections::HashSet;
+
+use crate::{
+    hir_def::{
+        expr::{HirArrayLiteral, HirExpression, HirLiteral},
+        stmt::{HirLValue, HirStatement},
+    },
+    node_interner::{DefinitionKind, ExprId, FuncId, NodeInterner, StmtId},
+};
+
+/// Returns every function which may be reached from `main`, including `main` itself.
+pub fn reachable_functions(interner: &NodeInterner, main: FuncId) -> HashSet<FuncId> {
+    let mut reachability = Reachability { interner, reachable: HashSet::new(), queue: Vec::new() };
+
+    reachability.push_function(main);
+    for (func_id, meta) in &interner.func_meta {
+        if meta.trait_impl.is_some() || interner.function_modifiers(func_id).is_comptime {
+            reachability.push_function(*func_id);
+        }
+    }
+    for global in interner.get_all_globals() {
+        reachability.scan_statement(global.let_statement);
+    }
+
+    while let Some(func_id) = reachability.queue.pop() {
+        if interner.function_meta(&func_id).has_body {
+            reachability.scan_expression(interner.function(&func_id).as_expr());
+        }
+    }
+    reachability.reachable
+}
+
+struct Reachability<'interner> {
+    interner: &'interner NodeInterner,
+    reachable: HashSet<FuncId>,
+
+    /// Functions found to be reachable whose bodies have not been scanned yet
+    queue: Vec<FuncId>,
+}
+
+impl<'interner> Reachability<'interner> {
+    fn push_function(&mut self, func_id: FuncId) {
+        if self.interner.try_function_meta(&func_id).is_some() && self.reachable.insert(func_id) {
+            self.queue.push(func_id);
+        }
+    }
+
+    fn scan_expressions(&mut self, exprs: impl IntoIterator<Item = ExprId>) {
+        for expr in exprs {
+            self.scan_expression(expr);
+        }
+    }
+
+    fn scan_expression(&mut self, expr: ExprId) {
+        match self.interner.expression(&expr) {
+            HirExpression::Ident(ident, _) => {
+                // Identifiers which failed to resolve have no definition.
+                let definition = self.interner.try_definition(ident.id);
+                if let Some(DefinitionKind::Function(func_id)) = definition.map(|info| &info.kind) {
+                    self.push_function(*func_id);
+                }
+            }
+            HirExpression::Literal(literal) => self.scan_literal(literal),
+            HirExpression::Block(block) | HirExpression::Comptime(block) => {
+                for statement in block.statements {
+                    self.scan_statement(statement);
+                }
+            }
+            HirExpression::Prefix(prefix) => self.scan_expression(prefix.rhs),
+            HirExpression::Infix(infix) => self.scan_expressions([infix.lhs, infix.rhs]),
+            HirExpression::Index(index) => self.scan_expressions([index.collection, index.index]),
+            HirExpression::Constructor(constructor) => {
+                self.scan_expressions(constructor.fields.into_iter().map(|(_, field)| field));
+            }
+            HirExpression::MemberAccess(member_access) => self.scan_expression(member_access.lhs),
+            HirExpression::Call(call) => {
+                self.scan_expression(call.func);
+                self.scan_expressions(call.arguments);
+            }
+            HirExpression::MethodCall(method_call) => {
+                self.scan_expression(method_call.object);
+                self.scan_expressions(method_call.arguments);
+            }
+            HirExpression::Cast(cast) => self.scan_expression(cast.lhs),
+            HirExpression::If(if_) => {
+                self.scan_expressions([if_.condition, if_.consequence]);
+                self.scan_expressions(if_.alternative);
+            }
+            HirExpression::Tuple(fields) => self.scan_expressions(fields),
+            HirExpression::Lambda(lambda) => self.scan_expression(lambda.body),
+            HirExpression::Quote(_) | HirExpression::Unquote(_) | HirExpression::Error => (),
+        }
+    }
+
+    fn scan_literal(&mut self, literal: HirLiteral) {
+        match literal {
+            HirLiteral::Array(elements) | HirLiteral::Slice(elements) => match elements {
+                HirArrayLiteral::Standard(elements) => self.scan_expressions(elements),
+                HirArrayLiteral::Repeated { repeated_element, length: _ } => {
+                    self.scan_expression(repeated_element);
+                }
+            },
+            HirLiteral::FmtStr(_, captures) => self.scan_expressions(captures),
+            HirLiteral::Bool(_)
+            | HirLiteral::Integer(..)
+            | HirLiteral::Str(_)
+            | HirLiteral::Unit => (),
+        }
+    }
+
+    fn scan_statement(&mut self, statement: StmtId) {
+        match self.interner.statement(&statement) {
+            HirStatement::Let(let_) => self.scan_expression(let_.expression),
+            HirStatement::Constrain(constrain) => {
+                self.scan_expression(constrain.0);
+                self.scan_expressions(constrain.2);
+            }
+            HirStatement::Assign(assign) => {
+                self.scan_lvalue(assign.lvalue);
+                self.scan_expression(assign.expression);
+            }
+            HirStatement::For(for_) => {
+                self.scan_expressions([for_.start_range, for_.end_range, for_.block]);
+            }
+            HirStatement::While(while_) => {
+                self.scan_expressions([while_.condition, while_.block]);
+            }
+            HirStatement::Expression(expr) | HirStatement::Semi(expr) => {
+                self.scan_expression(expr);
+            }
+            HirStatement::Comptime(statement) => self.scan_statement(statement),
+            HirStatement::Break | HirStatement::Continue | HirStatement::Error => (),
+        }
+    }
+
+    fn scan_lvalue(&mut self, lvalue: HirLValue) {
+        match lvalue {
+            HirLValue::Ident(..) => (),
+            HirLValue::MemberAccess { object, .. } => self.scan_lvalue(*object),
+            HirLValue::Index { array, index, .. } => {
+                self.scan_lvalue(*array);
+                self.scan_expression(index);
+            }
+            HirLValue::Dereference { lvalue, .. } => self.scan_lvalue(*lvalue),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 pub use noirc_errors::Span;
-use noirc_errors::{CustomDiagnostic as Diagnostic, FileDiagnostic};
+use noirc_errors::{CustomDiagnostic as Diagnostic, FileDiagnostic, Location};
 use thiserror::Error;
 
 use crate::{ast::Ident, parser::ParserError, Type};
@@ -20,6 +20,10 @@ pub enum ResolverError {
     DuplicateDefinition { name: String, first_span: Span, second_span: Span },
     #[error("Unused variable")]
     UnusedVariable { ident: Ident },
+    #[error("Unused import")]
+    UnusedImport { ident: Ident },
+    #[error("Unused module")]
+    UnusedModule { ident: Ident },
     #[error("Could not find variable in this scope")]
     VariableNotDeclared { name: String, span: Span },
     #[error("path is not an identifier")]
@@ -46,14 +50,25 @@ pub enum ResolverError {
     MissingRhsExpr { name: String, span: Span },
     #[error("Expression invalid in an array length context")]
     InvalidArrayLengthExpr { span: Span },
+    #[error("Variable used in an array length context is not known at compile time")]
+    NonConstantArrayLength { name: String, span: Span },
     #[error("Integer too large to be evaluated in an array length context")]
     IntegerTooLarge { span: Span },
+    #[error("Array length expression evaluates to a negative number")]
+    NegativeArrayLength { span: Span },
     #[error("No global or generic type parameter found with the given name")]
     NoSuchNumericTypeVariable { path: crate::ast::Path },
     #[error("Closures cannot capture mutable variables")]
     CapturedMutableVariable { span: Span },
     #[error("Test functions are not allowed to have any parameters")]
     TestFunctionHasParameters { span: Span },
+    #[error("Field {field} of struct {struct_name} is private")]
+    PrivateStructField {
+        field: Ident,
+        struct_name: String,
+        declaration: Location,
+        use_file: fm::FileId,
+    },
     #[error("Only struct types can be used in constructor expressions")]
     NonStructUsedInConstructor { typ: Type, span: Span },
     #[error("Only struct types can have generics")]
@@ -86,6 +101,8 @@ pub enum ResolverError {
     JumpInConstrainedFn { is_break: bool, span: Span },
     #[error("break/continue are only allowed within loops")]
     JumpOutsideLoop { is_break: bool, span: Span },
+    #[error("while loops are only allowed in unconstrained functions")]
+    WhileInConstrainedFn { span: Span },
     #[error("Only `comptime` globals can be mutable")]
     MutableGlobal { span: Span },
     #[error("Self-referential structs are not supported")]
@@ -126,6 +143,24 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     ident.span(),
                 )
             }
+            ResolverError::UnusedImport { ident } => {
+                let name = &ident.0.contents;
+
+                Diagnostic::simple_warning(
+                    format!("unused import {name}"),
+                    "unused import ".to_string(),
+                    ident.span(),
+                )
+            }
+            ResolverError::UnusedModule { ident } => {
+                let name = &ident.0.contents;
+
+                Diagnostic::simple_warning(
+                    format!("module {name} is never used"),
+                    "unused module ".to_string(),
+                    ident.span(),
+                )
+            }
             ResolverError::VariableNotDeclared { name, span } => Diagnostic::simple_error(
                 format!("cannot find `{name}` in this scope "),
                 "not found in this scope".to_string(),
@@ -236,11 +271,35 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                 "Array-length expressions can only have simple integer operations and any variables used must be global constants".into(),
                 *span,
             ),
+            ResolverError::NonConstantArrayLength { name, span } => Diagnostic::simple_error(
+                format!("`{name}` is not known at compile time and cannot be used as an array length"),
+                "Array lengths may only use integer literals, global constants and numeric generics".into(),
+                *span,
+            ),
+            ResolverError::PrivateStructField { field, struct_name, declaration, use_file } => {
+                let mut diag = Diagnostic::simple_error(
+                    format!("Field `{field}` of struct `{struct_name}` is private"),
+                    "Private fields can only be used in the module which defines the struct".into(),
+                    field.span(),
+                );
+                if declaration.file == *use_file {
+                    diag.add_secondary(
+                        "field declared here without `pub`".to_string(),
+                        declaration.span,
+                    );
+                }
+                diag
+            }
             ResolverError::IntegerTooLarge { span } => Diagnostic::simple_error(
                 "Integer too large to be evaluated to an array-length".into(),
                 "Array-lengths may be a maximum size of usize::MAX, including intermediate calculations".into(),
                 *span,
             ),
+            ResolverError::NegativeArrayLength { span } => Diagnostic::simple_error(
+                "Array length expression evaluates to a negative number".into(),
+                "Array lengths and numeric generics cannot be negative".into(),
+                *span,
+            ),
             ResolverError::NoSuchNumericTypeVariable { path } => Diagnostic::simple_error(
                 format!("Cannot find a global or generic type parameter named `{path}`"),
                 "Only globals or generic type parameters are allowed to be used as an array type's length".to_string(),
@@ -348,6 +407,13 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     *span,
                 )
             },
+            ResolverError::WhileInConstrainedFn { span } => {
+                Diagnostic::simple_error(
+                    "while loops are only allowed in unconstrained functions".into(),
+                    "Constrained code must always have a known number of loop iterations".into(),
+                    *span,
+                )
+            },
             ResolverError::MutableGlobal { span } => {
                 Diagnostic::simple_error(
                     "Only `comptime` globals may be mutable".into(),
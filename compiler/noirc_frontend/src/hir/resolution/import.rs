@@ -7,8 +7,10 @@ use std::collections::BTreeMap;
 
 use crate::ast::{Ident, ItemVisibility, Path, PathKind};
 use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleDefId, ModuleId, PerNs};
+use crate::node_interner::StructId;
 
 use super::errors::ResolverError;
+use super::usage_tracker::UsageTracker;
 
 #[derive(Debug, Clone)]
 pub struct ImportDirective {
@@ -83,20 +85,30 @@ impl<'a> From<&'a PathResolutionError> for CustomDiagnostic {
     }
 }
 
+/// Resolves the path of an import, marking every name it goes through as referenced.
 pub fn resolve_import(
     crate_id: CrateId,
     import_directive: &ImportDirective,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    usage_tracker: &mut UsageTracker,
 ) -> Result<ResolvedImport, PathResolutionError> {
     let allow_contracts =
         allow_referencing_contracts(def_maps, crate_id, import_directive.module_id);
 
     let module_scope = import_directive.module_id;
+    let importing_module = ModuleId { krate: crate_id, local_id: module_scope };
     let NamespaceResolution {
         module_id: resolved_module,
         namespace: resolved_namespace,
         mut error,
-    } = resolve_path_to_ns(import_directive, crate_id, crate_id, def_maps, allow_contracts)?;
+    } = resolve_path_to_ns(
+        import_directive,
+        crate_id,
+        importing_module,
+        def_maps,
+        allow_contracts,
+        usage_tracker,
+    )?;
 
     let name = resolve_path_name(import_directive);
 
@@ -140,9 +152,10 @@ fn allow_referencing_contracts(
 fn resolve_path_to_ns(
     import_directive: &ImportDirective,
     crate_id: CrateId,
-    importing_crate: CrateId,
+    importing_module: ModuleId,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     allow_contracts: bool,
+    usage_tracker: &mut UsageTracker,
 ) -> NamespaceResolutionResult {
     let import_path = &import_directive.path.segments;
     let def_map = &def_maps[&crate_id];
@@ -152,10 +165,11 @@ fn resolve_path_to_ns(
             // Resolve from the root of the crate
             resolve_path_from_crate_root(
                 crate_id,
-                importing_crate,
+                importing_module,
                 import_path,
                 def_maps,
                 allow_contracts,
+                usage_tracker,
             )
         }
         crate::ast::PathKind::Dep => resolve_external_dep(
@@ -163,18 +177,21 @@ fn resolve_path_to_ns(
             import_directive,
             def_maps,
             allow_contracts,
-            importing_crate,
+            importing_module,
+            usage_tracker,
         ),
-        crate::ast::PathKind::Plain => {
+        // `self::` paths are resolved in the same way as plain paths, the prefix just makes this explicit.
+        crate::ast::PathKind::Plain | crate::ast::PathKind::SelfModule => {
             // Plain paths are only used to import children modules. It's possible to allow import of external deps, but maybe this distinction is better?
             // In Rust they can also point to external Dependencies, if no children can be found with the specified name
             resolve_name_in_module(
                 crate_id,
-                importing_crate,
+                importing_module,
                 import_path,
                 import_directive.module_id,
                 def_maps,
                 allow_contracts,
+                usage_tracker,
             )
         }
     }
@@ -182,30 +199,34 @@ fn resolve_path_to_ns(
 
 fn resolve_path_from_crate_root(
     crate_id: CrateId,
-    importing_crate: CrateId,
+    importing_module: ModuleId,
 
     import_path: &[Ident],
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     allow_contracts: bool,
+    usage_tracker: &mut UsageTracker,
 ) -> NamespaceResolutionResult {
     resolve_name_in_module(
         crate_id,
-        importing_crate,
+        importing_module,
         import_path,
         def_maps[&crate_id].root,
         def_maps,
         allow_contracts,
+        usage_tracker,
     )
 }
 
 fn resolve_name_in_module(
     krate: CrateId,
-    importing_crate: CrateId,
+    importing_module: ModuleId,
     import_path: &[Ident],
     starting_mod: LocalModuleId,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     allow_contracts: bool,
+    usage_tracker: &mut UsageTracker,
 ) -> NamespaceResolutionResult {
+    let importing_crate = importing_module.krate;
     let def_map = &def_maps[&krate];
     let mut current_mod_id = ModuleId { krate, local_id: starting_mod };
     let mut current_mod = &def_map.modules[current_mod_id.local_id.0];
@@ -225,6 +246,7 @@ fn resolve_name_in_module(
     if current_ns.is_none() {
         return Err(PathResolutionError::Unresolved(first_segment.clone()));
     }
+    mark_as_referenced(def_maps, usage_tracker, importing_module, current_mod_id, first_segment);
 
     let mut warning: Option<PathResolutionError> = None;
     for (last_segment, current_segment) in import_path.iter().zip(import_path.iter().skip(1)) {
@@ -271,12 +293,45 @@ fn resolve_name_in_module(
         if current_mod.is_contract && !allow_contracts {
             return Err(PathResolutionError::ExternalContractUsed(current_segment.clone()));
         }
+        mark_as_referenced(
+            def_maps,
+            usage_tracker,
+            importing_module,
+            current_mod_id,
+            current_segment,
+        );
         current_ns = found_ns;
     }
 
     Ok(NamespaceResolution { module_id: current_mod_id, namespace: current_ns, error: warning })
 }
 
+/// Records that `name` was found in `module` while resolving a path from `importing_module`.
+///
+/// This uses any import which introduced `name`, as well as `module` and its parents unless
+/// they also contain `importing_module`, as modules are only used if referenced from outside.
+fn mark_as_referenced(
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    usage_tracker: &mut UsageTracker,
+    importing_module: ModuleId,
+    module: ModuleId,
+    name: &Ident,
+) {
+    usage_tracker.mark_name_as_referenced(module, name);
+
+    let def_map = &def_maps[&module.krate];
+    let mut current = Some(module.local_id);
+    while let Some(local_id) = current {
+        if module.krate == importing_module.krate
+            && module_descendent_of_target(def_map, local_id, importing_module.local_id)
+        {
+            break;
+        }
+        usage_tracker.mark_module_as_referenced(ModuleId { krate: module.krate, local_id });
+        current = def_map.modules[local_id.0].parent;
+    }
+}
+
 fn resolve_path_name(import_directive: &ImportDirective) -> Ident {
     match &import_directive.alias {
         None => import_directive.path.segments.last().unwrap().clone(),
@@ -289,7 +344,8 @@ fn resolve_external_dep(
     directive: &ImportDirective,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     allow_contracts: bool,
-    importing_crate: CrateId,
+    importing_module: ModuleId,
+    usage_tracker: &mut UsageTracker,
 ) -> NamespaceResolutionResult {
     // Use extern_prelude to get the dep
     let path = &directive.path.segments;
@@ -316,7 +372,37 @@ fn resolve_external_dep(
         is_prelude: false,
     };
 
-    resolve_path_to_ns(&dep_directive, dep_module.krate, importing_crate, def_maps, allow_contracts)
+    resolve_path_to_ns(
+        &dep_directive,
+        dep_module.krate,
+        importing_module,
+        def_maps,
+        allow_contracts,
+        usage_tracker,
+    )
+}
+
+// Returns true if a field of the given struct with the given visibility can be referenced from `current_module`.
+// Private fields are visible within the module defining the struct and any of its child modules.
+pub(crate) fn can_reference_struct_field(
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    current_module: ModuleId,
+    struct_id: StructId,
+    visibility: ItemVisibility,
+) -> bool {
+    // Each struct is given its own module as a child of the module in which it is defined.
+    let struct_module = struct_id.module_id();
+    let defining_module = def_maps[&struct_module.krate].modules[struct_module.local_id.0]
+        .parent
+        .map_or(struct_module, |local_id| ModuleId { krate: struct_module.krate, local_id });
+
+    can_reference_module_id(
+        def_maps,
+        current_module.krate,
+        current_module.local_id,
+        defining_module,
+        visibility,
+    )
 }
 
 // Issue an error if the given private function is being called from a non-child module, or
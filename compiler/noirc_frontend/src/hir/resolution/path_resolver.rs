@@ -1,4 +1,5 @@
 use super::import::{resolve_import, ImportDirective, PathResolution, PathResolutionResult};
+use super::usage_tracker::UsageTracker;
 use crate::ast::Path;
 use std::collections::BTreeMap;
 
@@ -11,6 +12,7 @@ pub trait PathResolver {
         &self,
         def_maps: &BTreeMap<CrateId, CrateDefMap>,
         path: Path,
+        usage_tracker: &mut UsageTracker,
     ) -> PathResolutionResult;
 
     fn local_module_id(&self) -> LocalModuleId;
@@ -34,8 +36,9 @@ impl PathResolver for StandardPathResolver {
         &self,
         def_maps: &BTreeMap<CrateId, CrateDefMap>,
         path: Path,
+        usage_tracker: &mut UsageTracker,
     ) -> PathResolutionResult {
-        resolve_path(def_maps, self.module_id, path)
+        resolve_path(def_maps, self.module_id, path, usage_tracker)
     }
 
     fn local_module_id(&self) -> LocalModuleId {
@@ -53,11 +56,12 @@ pub fn resolve_path(
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     module_id: ModuleId,
     path: Path,
+    usage_tracker: &mut UsageTracker,
 ) -> PathResolutionResult {
     // lets package up the path into an ImportDirective and resolve it using that
     let import =
         ImportDirective { module_id: module_id.local_id, path, alias: None, is_prelude: false };
-    let resolved_import = resolve_import(module_id.krate, &import, def_maps)?;
+    let resolved_import = resolve_import(module_id.krate, &import, def_maps, usage_tracker)?;
 
     let namespace = resolved_import.resolved_namespace;
     let id =
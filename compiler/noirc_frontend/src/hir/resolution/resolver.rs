@@ -35,12 +35,17 @@ use crate::ast::{
 use crate::graph::CrateId;
 use crate::hir::def_map::{ModuleDefId, TryFromModuleDefId, MAIN_FUNCTION};
 use crate::hir::{def_map::CrateDefMap, resolution::path_resolver::PathResolver};
-use crate::hir_def::stmt::{HirAssignStatement, HirForStatement, HirLValue, HirPattern};
+use crate::hir_def::stmt::{
+    HirAssignStatement, HirForStatement, HirLValue, HirPattern, HirWhileStatement,
+};
 use crate::node_interner::{
     DefinitionId, DefinitionKind, DependencyId, ExprId, FuncId, GlobalId, NodeInterner, StmtId,
     StructId, TraitId, TraitImplId, TraitMethodId, TypeAliasId,
 };
-use crate::{Generics, Shared, StructType, Type, TypeAlias, TypeVariable, TypeVariableKind};
+use crate::{
+    BinaryTypeOperator, Generics, Shared, StructField, StructType, Type, TypeAlias, TypeVariable,
+    TypeVariableKind,
+};
 use fm::FileId;
 use iter_extended::vecmap;
 use noirc_errors::{Location, Span, Spanned};
@@ -53,6 +58,7 @@ use crate::hir_def::{
     stmt::{HirConstrainStatement, HirLetStatement, HirStatement},
 };
 
+use super::check_struct_field_visibility;
 use super::errors::{PubPosition, ResolverError};
 use super::import::PathResolution;
 
@@ -757,7 +763,8 @@ impl<'a> Resolver<'a> {
         }
 
         // If we cannot find a local generic of the same name, try to look up a global
-        match self.path_resolver.resolve(self.def_maps, path.clone()) {
+        let usage_tracker = &mut self.interner.usage_tracker;
+        match self.path_resolver.resolve(self.def_maps, path.clone(), usage_tracker) {
             Ok(PathResolution { module_def_id: ModuleDefId::GlobalId(id), error }) => {
                 if let Some(current_item) = self.current_item {
                     self.interner.add_global_dependency(current_item, id);
@@ -795,18 +802,39 @@ impl<'a> Resolver<'a> {
     fn convert_expression_type(&mut self, length: UnresolvedTypeExpression) -> Type {
         match length {
             UnresolvedTypeExpression::Variable(path) => {
-                self.lookup_generic_or_global_type(&path).unwrap_or_else(|| {
+                if let Some(typ) = self.lookup_generic_or_global_type(&path) {
+                    return typ;
+                }
+
+                // Give a more specific error if this refers to a local variable, whose value
+                // is only known at runtime.
+                let local_variable = path.as_ident().filter(|ident| {
+                    self.scopes.current_scope_tree().find(&ident.0.contents).is_some()
+                });
+                if let Some(ident) = local_variable {
+                    let name = ident.0.contents.clone();
+                    self.push_err(ResolverError::NonConstantArrayLength {
+                        name,
+                        span: ident.span(),
+                    });
+                } else {
                     self.push_err(ResolverError::NoSuchNumericTypeVariable { path });
-                    Type::Constant(0)
-                })
+                }
+                Type::Constant(0)
             }
             UnresolvedTypeExpression::Constant(int, _) => Type::Constant(int),
-            UnresolvedTypeExpression::BinaryOperation(lhs, op, rhs, _) => {
+            UnresolvedTypeExpression::BinaryOperation(lhs, op, rhs, span) => {
                 let (lhs_span, rhs_span) = (lhs.span(), rhs.span());
                 let lhs = self.convert_expression_type(*lhs);
                 let rhs = self.convert_expression_type(*rhs);
 
                 match (lhs, rhs) {
+                    (Type::Constant(lhs), Type::Constant(rhs))
+                        if op == BinaryTypeOperator::Subtraction && rhs > lhs =>
+                    {
+                        self.push_err(ResolverError::NegativeArrayLength { span });
+                        Type::Constant(0)
+                    }
                     (Type::Constant(lhs), Type::Constant(rhs)) => {
                         Type::Constant(op.function()(lhs, rhs))
                     }
@@ -949,7 +977,7 @@ impl<'a> Resolver<'a> {
         mut self,
         unresolved: NoirStruct,
         struct_id: StructId,
-    ) -> (Generics, Vec<(Ident, Type)>, Vec<ResolverError>) {
+    ) -> (Generics, Vec<StructField>, Vec<ResolverError>) {
         let generics = self.add_generics(&unresolved.generics);
 
         // Check whether the struct definition has globals in the local module and add them to the scope
@@ -958,7 +986,11 @@ impl<'a> Resolver<'a> {
         self.current_item = Some(DependencyId::Struct(struct_id));
 
         self.resolving_ids.insert(struct_id);
-        let fields = vecmap(unresolved.fields, |(ident, typ)| (ident, self.resolve_type(typ)));
+        let fields = vecmap(unresolved.fields, |field| StructField {
+            visibility: field.visibility,
+            name: field.name,
+            typ: self.resolve_type(field.typ),
+        });
         self.resolving_ids.remove(&struct_id);
 
         (generics, fields, self.errors)
@@ -1104,6 +1136,7 @@ impl<'a> Resolver<'a> {
             trait_constraints: self.resolve_trait_constraints(&func.def.where_clause),
             is_entry_point: self.is_entry_point_function(func),
             has_inline_attribute,
+            overflow_checks: !attributes.has_no_overflow_checks(),
         }
     }
 
@@ -1306,6 +1339,10 @@ impl<'a> Resolver<'a> {
                 HirStatement::Expression(self.resolve_expression(expr))
             }
             StatementKind::Semi(expr) => HirStatement::Semi(self.resolve_expression(expr)),
+            StatementKind::Assign(assign_stmt) if assign_stmt.operator.is_some() => {
+                let kind = assign_stmt.desugar_compound_operator(span);
+                self.resolve_stmt(kind, span)
+            }
             StatementKind::Assign(assign_stmt) => {
                 let identifier = self.resolve_lvalue(assign_stmt.lvalue);
                 let expression = self.resolve_expression(assign_stmt.expression);
@@ -1314,10 +1351,14 @@ impl<'a> Resolver<'a> {
             }
             StatementKind::For(for_loop) => {
                 match for_loop.range {
-                    ForRange::Range(start_range, end_range) => {
+                    ForRange::Range(bounds) => {
+                        let (start_range, end_range) = bounds.into_half_open();
                         let start_range = self.resolve_expression(start_range);
                         let end_range = self.resolve_expression(end_range);
-                        let (identifier, block) = (for_loop.identifier, for_loop.block);
+                        let collection = for_loop
+                            .collection
+                            .map(|collection| self.find_variable_or_default(&collection).0);
+                        let (identifier, block) = (for_loop.pattern.into_ident(), for_loop.block);
 
                         self.nested_loops += 1;
 
@@ -1340,15 +1381,28 @@ impl<'a> Resolver<'a> {
                             end_range,
                             block,
                             identifier,
+                            collection,
                         })
                     }
                     range @ ForRange::Array(_) => {
                         let for_stmt =
-                            range.into_for(for_loop.identifier, for_loop.block, for_loop.span);
+                            range.into_for(for_loop.pattern, for_loop.block, for_loop.span);
                         self.resolve_stmt(for_stmt.kind, for_loop.span)
                     }
                 }
             }
+            StatementKind::While(while_loop) => {
+                if !self.in_unconstrained_fn {
+                    self.push_err(ResolverError::WhileInConstrainedFn { span });
+                }
+                let condition = self.resolve_expression(while_loop.condition);
+
+                self.nested_loops += 1;
+                let block = self.resolve_expression(while_loop.block);
+                self.nested_loops -= 1;
+
+                HirStatement::While(HirWhileStatement { condition, block })
+            }
             StatementKind::Break => {
                 self.check_break_continue(true, span);
                 HirStatement::Break
@@ -1469,7 +1523,7 @@ impl<'a> Resolver<'a> {
                 Literal::Slice(array_literal) => {
                     HirLiteral::Slice(self.resolve_array_literal(array_literal))
                 }
-                Literal::Integer(integer, sign) => HirLiteral::Integer(integer, sign),
+                Literal::Integer(integer, sign, repr) => HirLiteral::Integer(integer, sign, repr),
                 Literal::Str(str) => HirLiteral::Str(str),
                 Literal::RawStr(str, _) => HirLiteral::Str(str),
                 Literal::FmtStr(str) => self.resolve_fmt_str_literal(str, expr.span),
@@ -1765,6 +1819,14 @@ impl<'a> Resolver<'a> {
             if unseen_fields.contains(&field) {
                 unseen_fields.remove(&field);
                 seen_fields.insert(field.clone());
+
+                let module = self.path_resolver.module_id();
+                let struct_type = &struct_type.borrow();
+                if let Err(error) =
+                    check_struct_field_visibility(self.def_maps, module, struct_type, &field)
+                {
+                    self.push_err(error);
+                }
             } else if seen_fields.contains(&field) {
                 // duplicate field
                 self.push_err(ResolverError::DuplicateField { field: field.clone() });
@@ -1986,7 +2048,8 @@ impl<'a> Resolver<'a> {
     }
 
     fn resolve_path(&mut self, path: Path) -> Result<ModuleDefId, ResolverError> {
-        let path_resolution = self.path_resolver.resolve(self.def_maps, path)?;
+        let usage_tracker = &mut self.interner.usage_tracker;
+        let path_resolution = self.path_resolver.resolve(self.def_maps, path, usage_tracker)?;
 
         if let Some(error) = path_resolution.error {
             self.push_err(error.into());
@@ -2049,7 +2112,7 @@ impl<'a> Resolver<'a> {
         }
 
         match self.interner.expression(&rhs) {
-            HirExpression::Literal(HirLiteral::Integer(int, false)) => {
+            HirExpression::Literal(HirLiteral::Integer(int, false, _)) => {
                 int.try_into_u128().ok_or(Some(ResolverError::IntegerTooLarge { span }))
             }
             HirExpression::Ident(ident, _) => {
@@ -2064,7 +2127,10 @@ impl<'a> Resolver<'a> {
                             Err(Some(ResolverError::InvalidArrayLengthExpr { span }))
                         }
                     }
-                    _ => Err(Some(ResolverError::InvalidArrayLengthExpr { span })),
+                    _ => {
+                        let span = self.interner.expr_span(&rhs);
+                        Err(Some(ResolverError::InvalidArrayLengthExpr { span }))
+                    }
                 }
             }
             HirExpression::Infix(infix) => {
@@ -2090,7 +2156,10 @@ impl<'a> Resolver<'a> {
                     BinaryOpKind::Modulo => Ok(lhs % rhs),
                 }
             }
-            _other => Err(Some(ResolverError::InvalidArrayLengthExpr { span })),
+            _other => {
+                let span = self.interner.expr_span(&rhs);
+                Err(Some(ResolverError::InvalidArrayLengthExpr { span }))
+            }
         }
     }
 
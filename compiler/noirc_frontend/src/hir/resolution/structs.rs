@@ -2,20 +2,24 @@ use std::collections::BTreeMap;
 
 use fm::FileId;
 use iter_extended::vecmap;
+use noirc_errors::Location;
 
-use crate::ast::Ident;
 use crate::{
+    ast::Ident,
     graph::CrateId,
     hir::{
         def_collector::dc_crate::{CompilationError, UnresolvedStruct},
-        def_map::ModuleId,
+        def_map::{CrateDefMap, ModuleId},
         Context,
     },
     node_interner::StructId,
-    Generics, Type,
+    Generics, StructField, StructType,
 };
 
-use super::{errors::ResolverError, path_resolver::StandardPathResolver, resolver::Resolver};
+use super::{
+    errors::ResolverError, import::can_reference_struct_field, path_resolver::StandardPathResolver,
+    resolver::Resolver,
+};
 
 /// Create the mappings from TypeId -> StructType
 /// so that expressions can access the fields of structs
@@ -71,7 +75,7 @@ fn resolve_struct_fields(
     krate: CrateId,
     type_id: StructId,
     unresolved: UnresolvedStruct,
-) -> (Generics, Vec<(Ident, Type)>, Vec<ResolverError>) {
+) -> (Generics, Vec<StructField>, Vec<ResolverError>) {
     let path_resolver =
         StandardPathResolver::new(ModuleId { local_id: unresolved.module_id, krate });
     let file_id = unresolved.file_id;
@@ -81,3 +85,28 @@ fn resolve_struct_fields(
 
     (generics, fields, errors)
 }
+
+/// Issue an error if `field` of `struct_type` is private and is being used
+/// outside of the module which defines the struct.
+pub(crate) fn check_struct_field_visibility(
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    current_module: ModuleId,
+    struct_type: &StructType,
+    field: &Ident,
+) -> Result<(), ResolverError> {
+    let Some(declaration) = struct_type.get_field_declaration(&field.0.contents) else {
+        return Ok(());
+    };
+
+    if can_reference_struct_field(def_maps, current_module, struct_type.id, declaration.visibility)
+    {
+        Ok(())
+    } else {
+        Err(ResolverError::PrivateStructField {
+            field: field.clone(),
+            struct_name: struct_type.name.to_string(),
+            declaration: Location::new(declaration.name.span(), struct_type.location.file),
+            use_file: current_module.module(def_maps).location.file,
+        })
+    }
+}
@@ -26,6 +26,7 @@ use super::{
     path_resolver::{PathResolver, StandardPathResolver},
     resolver::Resolver,
     take_errors,
+    usage_tracker::UsageTracker,
 };
 
 /// Create the mappings from TypeId -> TraitType
@@ -275,22 +276,26 @@ fn collect_trait_impl(
     let mut errors: Vec<(CompilationError, FileId)> = vec![];
     let unresolved_type = trait_impl.object_type.clone();
     let module = ModuleId { local_id: trait_impl.module_id, krate: crate_id };
-    trait_impl.trait_id =
-        match resolve_trait_by_path(def_maps, module, trait_impl.trait_path.clone()) {
-            Ok((trait_id, warning)) => {
-                if let Some(warning) = warning {
-                    errors.push((
-                        DefCollectorErrorKind::PathResolutionError(warning).into(),
-                        trait_impl.file_id,
-                    ));
-                }
-                Some(trait_id)
+    trait_impl.trait_id = match resolve_trait_by_path(
+        def_maps,
+        module,
+        trait_impl.trait_path.clone(),
+        &mut interner.usage_tracker,
+    ) {
+        Ok((trait_id, warning)) => {
+            if let Some(warning) = warning {
+                errors.push((
+                    DefCollectorErrorKind::PathResolutionError(warning).into(),
+                    trait_impl.file_id,
+                ));
             }
-            Err(error) => {
-                errors.push((error.into(), trait_impl.file_id));
-                None
-            }
-        };
+            Some(trait_id)
+        }
+        Err(error) => {
+            errors.push((error.into(), trait_impl.file_id));
+            None
+        }
+    };
 
     if let Some(trait_id) = trait_impl.trait_id {
         errors
@@ -373,10 +378,11 @@ pub(crate) fn resolve_trait_by_path(
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     module: ModuleId,
     path: Path,
+    usage_tracker: &mut UsageTracker,
 ) -> Result<(TraitId, Option<PathResolutionError>), DefCollectorErrorKind> {
     let path_resolver = StandardPathResolver::new(module);
 
-    match path_resolver.resolve(def_maps, path.clone()) {
+    match path_resolver.resolve(def_maps, path.clone(), usage_tracker) {
         Ok(PathResolution { module_def_id: ModuleDefId::TraitId(trait_id), error }) => {
             Ok((trait_id, error))
         }
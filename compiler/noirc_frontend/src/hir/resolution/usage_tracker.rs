@@ -0,0 +1,67 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::Ident;
+use crate::graph::CrateId;
+use crate::hir::def_map::ModuleId;
+
+/// Tracks the imports and `mod` declarations which are never referenced during name resolution
+/// so that they can be reported as unused once a crate has been resolved.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    /// The names introduced into each module by imports which have not been referenced yet.
+    unused_imports: HashMap<ModuleId, HashSet<Ident>>,
+    /// Modules declared with `mod` with no item referenced from outside of them yet.
+    unused_modules: HashMap<ModuleId, Ident>,
+}
+
+impl UsageTracker {
+    pub(crate) fn add_import(&mut self, module: ModuleId, name: Ident) {
+        self.unused_imports.entry(module).or_default().insert(name);
+    }
+
+    pub(crate) fn add_module(&mut self, module: ModuleId, name: Ident) {
+        self.unused_modules.insert(module, name);
+    }
+
+    /// Marks `name` as used in `module`, which uses the import introducing it if there is one.
+    pub(crate) fn mark_name_as_referenced(&mut self, module: ModuleId, name: &Ident) {
+        if let Some(names) = self.unused_imports.get_mut(&module) {
+            names.remove(name);
+        }
+    }
+
+    pub(crate) fn mark_module_as_referenced(&mut self, module: ModuleId) {
+        self.unused_modules.remove(&module);
+    }
+
+    /// Removes the imports of the given crate which were never referenced, returning them
+    /// along with the module they were imported into, in source order.
+    pub(crate) fn take_unused_imports(&mut self, krate: CrateId) -> Vec<(ModuleId, Ident)> {
+        let modules: Vec<_> =
+            self.unused_imports.keys().filter(|module| module.krate == krate).copied().collect();
+        let mut unused_imports: Vec<_> = modules
+            .into_iter()
+            .flat_map(|module| {
+                let names = self.unused_imports.remove(&module).unwrap_or_default();
+                names.into_iter().map(move |name| (module, name))
+            })
+            .collect();
+        unused_imports.sort_by_key(|(_, name)| name.span().start());
+        unused_imports
+    }
+
+    /// Removes the modules of the given crate which were never referenced from outside of them.
+    pub(crate) fn take_unused_modules(&mut self, krate: CrateId) -> Vec<(ModuleId, Ident)> {
+        let mut unused_modules = Vec::new();
+        self.unused_modules.retain(|module, name| {
+            if module.krate == krate {
+                unused_modules.push((*module, name.clone()));
+                false
+            } else {
+                true
+            }
+        });
+        unused_modules.sort_by_key(|(_, name)| name.span().start());
+        unused_modules
+    }
+}
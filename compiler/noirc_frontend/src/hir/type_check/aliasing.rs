@@ -0,0 +1,69 @@
+//! Rejects calls which pass a mutable reference along with another argument derived from the
+//! same variable, such as `foo(&mut array, array[0])` or `foo(&mut point.x, point.y)`.
+//!
+//! Whether the other argument observes writes made through the reference is easy to get wrong
+//! once the program is flattened into constraints, so these calls are conservatively rejected.
+//! Only arguments which are the variable itself or projections of it (field accesses, indexing,
+//! dereferences and `&mut`) are considered to be derived from it. This could later be relaxed
+//! to only reject calls where the callee writes through the reference.
+use noirc_errors::Span;
+
+use crate::ast::UnaryOp;
+use crate::hir_def::expr::HirExpression;
+use crate::node_interner::{DefinitionId, ExprId, NodeInterner};
+use crate::Type;
+
+use super::errors::TypeCheckError;
+
+/// Returns an error for each pair of arguments where one is a mutable reference
+/// and both are derived from the same variable.
+pub(crate) fn check_argument_aliasing(
+    interner: &NodeInterner,
+    arguments: &[(Type, ExprId, Span)],
+) -> Vec<TypeCheckError> {
+    let arguments: Vec<_> = arguments
+        .iter()
+        .map(|(typ, argument, span)| {
+            let is_reference = matches!(typ.follow_bindings(), Type::MutableReference(_));
+            (is_reference, root_variable(interner, *argument), *span)
+        })
+        .collect();
+
+    let mut errors = Vec::new();
+    for (i, (is_reference, root, span)) in arguments.iter().enumerate() {
+        let Some(root) = root else {
+            continue;
+        };
+        for (other_is_reference, other_root, other_span) in &arguments[i + 1..] {
+            if other_root.as_ref() != Some(root) || !(*is_reference || *other_is_reference) {
+                continue;
+            }
+            let (reference_span, argument_span) =
+                if *is_reference { (*span, *other_span) } else { (*other_span, *span) };
+            errors.push(TypeCheckError::AliasedMutableReferenceArgument {
+                name: interner.definition_name(*root).to_string(),
+                reference_span,
+                argument_span,
+            });
+        }
+    }
+    errors
+}
+
+/// Returns the variable `expression` is a projection of, if any.
+fn root_variable(interner: &NodeInterner, expression: ExprId) -> Option<DefinitionId> {
+    match interner.expression(&expression) {
+        HirExpression::Ident(ident, _) => Some(ident.id),
+        HirExpression::Index(index) => root_variable(interner, index.collection),
+        HirExpression::MemberAccess(access) => root_variable(interner, access.lhs),
+        HirExpression::Prefix(prefix)
+            if matches!(
+                prefix.operator,
+                UnaryOp::MutableReference | UnaryOp::Dereference { .. }
+            ) =>
+        {
+            root_variable(interner, prefix.rhs)
+        }
+        _ => None,
+    }
+}
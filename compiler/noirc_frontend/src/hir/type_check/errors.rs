@@ -1,4 +1,3 @@
-use acvm::FieldElement;
 use noirc_errors::CustomDiagnostic as Diagnostic;
 use noirc_errors::Span;
 use thiserror::Error;
@@ -32,12 +31,25 @@ pub enum Source {
 pub enum TypeCheckError {
     #[error("Operator {op:?} cannot be used in a {place:?}")]
     OpCannotBeUsed { op: HirBinaryOp, place: &'static str, span: Span },
-    #[error("The literal `{expr:?}` cannot fit into `{ty}` which has range `{range}`")]
-    OverflowingAssignment { expr: FieldElement, ty: Type, range: String, span: Span },
+    #[error("The literal `{expr}` cannot fit into `{ty}` which has range `{range}`")]
+    OverflowingAssignment { expr: String, ty: Type, range: String, span: Span },
     #[error("Type {typ:?} cannot be used in a {place:?}")]
     TypeCannotBeUsed { typ: Type, place: &'static str, span: Span },
     #[error("Expected type {expected_typ:?} is not the same as {expr_typ:?}")]
     TypeMismatch { expected_typ: String, expr_typ: String, expr_span: Span },
+    #[error("Array length mismatch: expected {expected_length}, found {found_length}")]
+    ArrayLengthMismatch {
+        expected_length: Type,
+        found_length: Type,
+        /// Where the array is within the mismatched types, e.g. `.hashpath`, or empty if the
+        /// mismatched types are the arrays themselves.
+        path: String,
+        expected_typ: String,
+        expr_typ: String,
+        span: Span,
+        /// The type which the expected length comes from, if known.
+        expected_span: Option<Span>,
+    },
     #[error("Expected type {expected} is not the same as {actual}")]
     TypeMismatchWithSource { expected: Type, actual: Type, span: Span, source: Source },
     #[error("Expected {expected:?} found {found:?}")]
@@ -56,12 +68,16 @@ pub enum TypeCheckError {
     GenericCountMismatch { item: String, expected: usize, found: usize, span: Span },
     #[error("Only integer and Field types may be casted to")]
     UnsupportedCast { span: Span },
+    #[error("Cannot cast {from} to bool")]
+    CastToBool { from: Type, span: Span },
     #[error("Index {index} is out of bounds for this tuple {lhs_type} of length {length}")]
     TupleIndexOutOfBounds { index: usize, lhs_type: Type, length: usize, span: Span },
     #[error("Variable {name} must be mutable to be assigned to")]
     VariableMustBeMutable { name: String, span: Span },
     #[error("No method named '{method_name}' found for type '{object_type}'")]
     UnresolvedMethodCall { method_name: String, object_type: Type, span: Span },
+    #[error("Values of type {typ} cannot be iterated over")]
+    NotIterable { typ: Type, span: Span },
     #[error("Integers must have the same signedness LHS is {sign_x:?}, RHS is {sign_y:?}")]
     IntegerSignedness { sign_x: Signedness, sign_y: Signedness, span: Span },
     #[error("Integers must have the same bit width LHS is {bit_width_x}, RHS is {bit_width_y}")]
@@ -127,6 +143,10 @@ pub enum TypeCheckError {
         "Cannot pass a mutable reference from a unconstrained runtime to an constrained runtime"
     )]
     UnconstrainedReferenceToConstrained { span: Span },
+    #[error(
+        "Cannot pass a mutable reference to `{name}` along with another argument derived from it"
+    )]
+    AliasedMutableReferenceArgument { name: String, reference_span: Span, argument_span: Span },
     #[error("Slices cannot be returned from an unconstrained runtime to a constrained runtime")]
     UnconstrainedSliceReturnToConstrained { span: Span },
     #[error("Slices must have constant length")]
@@ -149,6 +169,16 @@ impl TypeCheckError {
     pub fn add_context(self, ctx: &'static str) -> Self {
         TypeCheckError::Context { err: Box::new(self), ctx }
     }
+
+    /// Points an array length mismatch, whose expected type has no known origin, at `span`.
+    pub(crate) fn with_expected_span(mut self, span: Span) -> Self {
+        if let TypeCheckError::ArrayLengthMismatch { expected_span: expected_span @ None, .. } =
+            &mut self
+        {
+            *expected_span = Some(span);
+        }
+        self
+    }
 }
 
 impl<'a> From<&'a TypeCheckError> for Diagnostic {
@@ -159,6 +189,11 @@ impl<'a> From<&'a TypeCheckError> for Diagnostic {
                 String::new(),
                 *span,
             ),
+            TypeCheckError::NotIterable { typ, span } => Diagnostic::simple_error(
+                format!("Values of type {typ} cannot be iterated over"),
+                "A for loop can only iterate over a range, an array or a slice".into(),
+                *span,
+            ),
             TypeCheckError::Context { err, ctx } => {
                 let mut diag = Diagnostic::from(err.as_ref());
                 diag.add_note(ctx.to_string());
@@ -176,6 +211,19 @@ impl<'a> From<&'a TypeCheckError> for Diagnostic {
                     *expr_span,
                 )
             }
+            TypeCheckError::ArrayLengthMismatch { expected_length, found_length, path, expected_typ, expr_typ, span, expected_span } => {
+                let secondary = if path.is_empty() {
+                    format!("this array has length {found_length}")
+                } else {
+                    format!("the array at `{path}` in this value has length {found_length}")
+                };
+                let mut diagnostic = Diagnostic::simple_error(error.to_string(), secondary, *span);
+                if let Some(expected_span) = expected_span {
+                    diagnostic.add_secondary(format!("expected length {expected_length} due to this"), *expected_span);
+                }
+                diagnostic.add_note(format!("expected type {expected_typ}, found type {expr_typ}"));
+                diagnostic
+            }
             TypeCheckError::TraitMethodParameterTypeMismatch { method_name, expected_typ, actual_typ, parameter_index, parameter_span } => {
                 Diagnostic::simple_error(
                     format!("Parameter #{parameter_index} of method `{method_name}` must be of type {expected_typ}, not {actual_typ}"),
@@ -244,11 +292,25 @@ impl<'a> From<&'a TypeCheckError> for Diagnostic {
             | TypeCheckError::InvalidShiftSize { span } => {
                 Diagnostic::simple_error(error.to_string(), String::new(), *span)
             }
+            TypeCheckError::AliasedMutableReferenceArgument { name, reference_span, argument_span } => {
+                let mut diagnostic = Diagnostic::simple_error(
+                    error.to_string(),
+                    "mutable reference passed here".to_string(),
+                    *reference_span,
+                );
+                diagnostic.add_secondary(format!("`{name}` is also used here"), *argument_span);
+                diagnostic
+            }
             TypeCheckError::PublicReturnType { typ, span } => Diagnostic::simple_error(
                 "Functions cannot declare a public return type".to_string(),
                 format!("return type is {typ}"),
                 *span,
             ),
+            TypeCheckError::CastToBool { span, .. } => Diagnostic::simple_error(
+                error.to_string(),
+                "Compare against zero instead, e.g. `x != 0`".to_string(),
+                *span,
+            ),
             TypeCheckError::TypeAnnotationsNeeded { span } => Diagnostic::simple_error(
                 "Expression type is ambiguous".to_string(),
                 "Type must be known at this point".to_string(),
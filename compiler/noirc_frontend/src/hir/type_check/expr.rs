@@ -1,10 +1,13 @@
 use iter_extended::vecmap;
-use noirc_errors::Span;
+use noirc_errors::{Span, Spanned};
 
-use crate::ast::{BinaryOpKind, IntegerBitSize, UnaryOp};
+use crate::ast::{BinaryOpKind, Ident, IntegerBitSize, UnaryOp};
 use crate::macros_api::Signedness;
 use crate::{
-    hir::{resolution::resolver::verify_mutable_reference, type_check::errors::Source},
+    hir::{
+        resolution::{check_struct_field_visibility, resolver::verify_mutable_reference},
+        type_check::errors::Source,
+    },
     hir_def::{
         expr::{
             self, HirArrayLiteral, HirBinaryOp, HirBlockExpression, HirExpression, HirIdent,
@@ -13,10 +16,10 @@ use crate::{
         types::Type,
     },
     node_interner::{DefinitionKind, ExprId, FuncId, TraitId, TraitImplKind, TraitMethodId},
-    TypeBinding, TypeBindings, TypeVariableKind,
+    StructType, TypeBinding, TypeBindings, TypeVariableKind,
 };
 
-use super::{errors::TypeCheckError, TypeChecker};
+use super::{aliasing::check_argument_aliasing, errors::TypeCheckError, TypeChecker};
 
 impl<'interner> TypeChecker<'interner> {
     fn check_if_deprecated(&mut self, expr: &ExprId) {
@@ -128,7 +131,7 @@ impl<'interner> TypeChecker<'interner> {
                     }
                 }
                 HirLiteral::Bool(_) => Type::Bool,
-                HirLiteral::Integer(_, _) => self.polymorphic_integer_or_field(),
+                HirLiteral::Integer(..) => self.polymorphic_integer_or_field(),
                 HirLiteral::Str(string) => {
                     let len = Type::Constant(string.len() as u64);
                     Type::String(Box::new(len))
@@ -187,9 +190,10 @@ impl<'interner> TypeChecker<'interner> {
                 self.check_if_deprecated(&call_expr.func);
 
                 let function = self.check_expression(&call_expr.func);
+                let parameter_types = function.function_parameter_types();
 
-                let args = vecmap(&call_expr.arguments, |arg| {
-                    let typ = self.check_expression(arg);
+                let args = vecmap(call_expr.arguments.iter().enumerate(), |(i, arg)| {
+                    let typ = self.check_expression_with_target_type(arg, parameter_types.get(i));
                     (typ, *arg, self.interner.expr_span(arg))
                 });
 
@@ -205,8 +209,14 @@ impl<'interner> TypeChecker<'interner> {
                     }
                 }
 
+                self.errors.extend(check_argument_aliasing(self.interner, &args));
+
                 let span = self.interner.expr_span(expr_id);
-                let return_type = self.bind_function_type(function, args, span);
+                let parameter_spans = self.current_function.map_or(Vec::new(), |func| {
+                    let file = self.interner.function_meta(&func).location.file;
+                    self.interner.function_parameter_spans(call_expr.func, file)
+                });
+                let return_type = self.bind_function_type(function, args, &parameter_spans, span);
 
                 // Check that we are not passing a slice from an unconstrained runtime to a constrained runtime
                 if is_current_func_constrained && is_unconstrained_call {
@@ -288,7 +298,7 @@ impl<'interner> TypeChecker<'interner> {
                 let span = self.interner.expr_span(&prefix_expr.rhs);
                 self.type_check_prefix_operand(&prefix_expr.operator, &rhs_type, span)
             }
-            HirExpression::If(if_expr) => self.check_if_expr(&if_expr, expr_id),
+            HirExpression::If(if_expr) => self.check_if_expr(&if_expr, expr_id, None),
             HirExpression::Constructor(constructor) => self.check_constructor(constructor, expr_id),
             HirExpression::MemberAccess(access) => self.check_member_access(access, *expr_id),
             HirExpression::Error => Type::Error,
@@ -333,12 +343,48 @@ impl<'interner> TypeChecker<'interner> {
         typ
     }
 
+    /// Type checks `expr_id`, where `target_type` is the type it is expected to have, if known.
+    pub(crate) fn check_expression_with_target_type(
+        &mut self,
+        expr_id: &ExprId,
+        target_type: Option<&Type>,
+    ) -> Type {
+        match self.interner.expression(expr_id) {
+            HirExpression::If(if_expr) => {
+                let typ = self.check_if_expr(&if_expr, expr_id, target_type);
+                self.interner.push_expr_type(*expr_id, typ.clone());
+                typ
+            }
+            HirExpression::Block(block) => {
+                let typ = self.check_block_with_target_type(block, target_type);
+                self.interner.push_expr_type(*expr_id, typ.clone());
+                typ
+            }
+            _ => self.check_expression(expr_id),
+        }
+    }
+
     fn check_block(&mut self, block: HirBlockExpression) -> Type {
+        self.check_block_with_target_type(block, None)
+    }
+
+    /// Type checks `block`, where `target_type` is the type its final expression is expected to have.
+    fn check_block_with_target_type(
+        &mut self,
+        block: HirBlockExpression,
+        target_type: Option<&Type>,
+    ) -> Type {
         let mut block_type = Type::Unit;
 
         let statements = block.statements();
         for (i, stmt) in statements.iter().enumerate() {
-            let expr_type = self.check_statement(stmt);
+            let is_last = i + 1 == statements.len();
+            let expr_type = match self.interner.statement(stmt) {
+                crate::hir_def::stmt::HirStatement::Expression(expr) if is_last => {
+                    self.check_expression_with_target_type(&expr, target_type)
+                }
+                _ => self.check_statement(stmt),
+            };
 
             if let crate::hir_def::stmt::HirStatement::Semi(expr) = self.interner.statement(stmt) {
                 let inner_expr_type = self.interner.id_type(expr);
@@ -350,7 +396,7 @@ impl<'interner> TypeChecker<'interner> {
                 });
             }
 
-            if i + 1 == statements.len() {
+            if is_last {
                 block_type = expr_type;
             }
         }
@@ -662,12 +708,12 @@ impl<'interner> TypeChecker<'interner> {
     }
 
     fn check_cast(&mut self, from: Type, to: Type, span: Span) -> Type {
-        match from.follow_bindings() {
-            Type::Integer(..)
+        let from = match from.follow_bindings() {
+            from @ (Type::Integer(..)
             | Type::FieldElement
             | Type::TypeVariable(_, TypeVariableKind::IntegerOrField)
             | Type::TypeVariable(_, TypeVariableKind::Integer)
-            | Type::Bool => (),
+            | Type::Bool) => from,
 
             Type::TypeVariable(_, _) => {
                 self.errors.push(TypeCheckError::TypeAnnotationsNeeded { span });
@@ -678,12 +724,18 @@ impl<'interner> TypeChecker<'interner> {
                 self.errors.push(TypeCheckError::InvalidCast { from, span });
                 return Type::Error;
             }
-        }
+        };
 
+        // Booleans may be cast to integers and fields, but the reverse would have to pick
+        // an interpretation for values other than 0 and 1 so it must be written as a comparison.
         match to {
             Type::Integer(sign, bits) => Type::Integer(sign, bits),
             Type::FieldElement => Type::FieldElement,
-            Type::Bool => Type::Bool,
+            Type::Bool if from == Type::Bool => Type::Bool,
+            Type::Bool => {
+                self.errors.push(TypeCheckError::CastToBool { from, span });
+                Type::Error
+            }
             Type::Error => Type::Error,
             _ => {
                 self.errors.push(TypeCheckError::UnsupportedCast { span });
@@ -692,7 +744,12 @@ impl<'interner> TypeChecker<'interner> {
         }
     }
 
-    fn check_if_expr(&mut self, if_expr: &expr::HirIfExpression, expr_id: &ExprId) -> Type {
+    fn check_if_expr(
+        &mut self,
+        if_expr: &expr::HirIfExpression,
+        expr_id: &ExprId,
+        target_type: Option<&Type>,
+    ) -> Type {
         let cond_type = self.check_expression(&if_expr.condition);
         let then_type = self.check_expression(&if_expr.consequence);
 
@@ -710,7 +767,7 @@ impl<'interner> TypeChecker<'interner> {
                 let else_type = self.check_expression(&alternative);
 
                 let expr_span = self.interner.expr_span(expr_id);
-                self.unify(&then_type, &else_type, || {
+                let make_error = || {
                     let err = TypeCheckError::TypeMismatch {
                         expected_typ: then_type.to_string(),
                         expr_typ: else_type.to_string(),
@@ -726,9 +783,16 @@ impl<'interner> TypeChecker<'interner> {
                     };
 
                     err.add_context(context)
-                });
-
-                then_type
+                };
+                then_type.unify_if_branches(
+                    &else_type,
+                    target_type,
+                    if_expr.consequence,
+                    alternative,
+                    self.interner,
+                    &mut self.errors,
+                    make_error,
+                )
             }
         }
     }
@@ -772,6 +836,22 @@ impl<'interner> TypeChecker<'interner> {
         Type::Struct(typ, generics)
     }
 
+    /// Issue an error if the given field of `struct_type` is private and is
+    /// being accessed outside of the module which defines the struct.
+    fn check_struct_field_visibility(&mut self, struct_type: &StructType, field: &str, span: Span) {
+        let module = match (self.current_function, self.current_global_module) {
+            (Some(current_function), _) => self.interner.function_module(current_function),
+            (None, Some(global_module)) => global_module,
+            (None, None) => return,
+        };
+        let field = Ident(Spanned::from(span, field.to_string()));
+        if let Err(error) =
+            check_struct_field_visibility(self.def_maps, module, struct_type, &field)
+        {
+            self.errors.push(TypeCheckError::ResolverError(error));
+        }
+    }
+
     fn check_member_access(&mut self, mut access: expr::HirMemberAccess, expr_id: ExprId) -> Type {
         let lhs_type = self.check_expression(&access.lhs).follow_bindings();
         let span = self.interner.expr_span(&expr_id);
@@ -834,6 +914,7 @@ impl<'interner> TypeChecker<'interner> {
             Type::Struct(s, args) => {
                 let s = s.borrow();
                 if let Some((field, index)) = s.get_field(field_name, args) {
+                    self.check_struct_field_visibility(&s, field_name, span);
                     return Some((field, index));
                 }
             }
@@ -1059,6 +1140,7 @@ impl<'interner> TypeChecker<'interner> {
         fn_params: &[Type],
         fn_ret: &Type,
         callsite_args: &[(Type, ExprId, Span)],
+        parameter_spans: &[Span],
         span: Span,
     ) -> Type {
         if fn_params.len() != callsite_args.len() {
@@ -1070,12 +1152,20 @@ impl<'interner> TypeChecker<'interner> {
             return Type::Error;
         }
 
-        for (param, (arg, _, arg_span)) in fn_params.iter().zip(callsite_args) {
-            self.unify(arg, param, || TypeCheckError::TypeMismatch {
+        for (index, (param, (arg, _, arg_span))) in fn_params.iter().zip(callsite_args).enumerate()
+        {
+            let mut errors = Vec::new();
+            arg.unify(param, &mut errors, || TypeCheckError::TypeMismatch {
                 expected_typ: param.to_string(),
                 expr_typ: arg.to_string(),
                 expr_span: *arg_span,
             });
+            for mut error in errors {
+                if let Some(parameter_span) = parameter_spans.get(index) {
+                    error = error.with_expected_span(*parameter_span);
+                }
+                self.errors.push(error);
+            }
         }
 
         fn_ret.clone()
@@ -1085,6 +1175,7 @@ impl<'interner> TypeChecker<'interner> {
         &mut self,
         function: Type,
         args: Vec<(Type, ExprId, Span)>,
+        parameter_spans: &[Span],
         span: Span,
     ) -> Type {
         // Could do a single unification for the entire function type, but matching beforehand
@@ -1092,7 +1183,7 @@ impl<'interner> TypeChecker<'interner> {
         match function {
             Type::TypeVariable(binding, TypeVariableKind::Normal) => {
                 if let TypeBinding::Bound(typ) = &*binding.borrow() {
-                    return self.bind_function_type(typ.clone(), args, span);
+                    return self.bind_function_type(typ.clone(), args, parameter_spans, span);
                 }
 
                 let ret = self.interner.next_type_variable();
@@ -1107,7 +1198,7 @@ impl<'interner> TypeChecker<'interner> {
             }
             // ignoring env for subtype on purpose
             Type::Function(parameters, ret, _env) => {
-                self.bind_function_type_impl(&parameters, &ret, &args, span)
+                self.bind_function_type_impl(&parameters, &ret, &args, parameter_spans, span)
             }
             Type::Error => Type::Error,
             found => {
@@ -7,14 +7,18 @@
 //! Although this algorithm features inference via TypeVariables, there is no generalization step
 //! as all functions are required to give their full signatures. Closures are inferred but are
 //! never generalized and thus cannot be used polymorphically.
+pub(crate) mod aliasing;
 mod errors;
 mod expr;
 mod stmt;
 
 pub use errors::TypeCheckError;
 use noirc_errors::Span;
+use std::collections::BTreeMap;
 
 use crate::{
+    graph::CrateId,
+    hir::def_map::{CrateDefMap, ModuleId},
     hir_def::{
         expr::HirExpression,
         function::{Param, Parameters},
@@ -29,9 +33,13 @@ pub use self::errors::Source;
 
 pub struct TypeChecker<'interner> {
     interner: &'interner mut NodeInterner,
+    def_maps: &'interner BTreeMap<CrateId, CrateDefMap>,
     errors: Vec<TypeCheckError>,
     current_function: Option<FuncId>,
 
+    /// The module which defines the global being checked, if a global is being checked.
+    current_global_module: Option<ModuleId>,
+
     /// Trait constraints are collected during type checking until they are
     /// verified at the end of a function. This is because constraints arise
     /// on each variable, but it is only until function calls when the types
@@ -46,14 +54,18 @@ pub struct TypeChecker<'interner> {
 
 /// Type checks a function and assigns the
 /// appropriate types to expressions in a side table
-pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Vec<TypeCheckError> {
+pub fn type_check_func(
+    interner: &mut NodeInterner,
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    func_id: FuncId,
+) -> Vec<TypeCheckError> {
     let meta = interner.function_meta(&func_id);
     let declared_return_type = meta.return_type().clone();
     let can_ignore_ret = meta.can_ignore_return_type();
 
     let function_body_id = &interner.function(&func_id).as_expr();
 
-    let mut type_checker = TypeChecker::new(interner);
+    let mut type_checker = TypeChecker::new(interner, def_maps);
     type_checker.current_function = Some(func_id);
 
     let meta = type_checker.interner.function_meta(&func_id);
@@ -88,7 +100,8 @@ pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Vec<Type
         type_checker.bind_pattern(&param.0, param.1);
     }
 
-    let function_last_type = type_checker.check_function_body(function_body_id);
+    let function_last_type =
+        type_checker.check_function_body(function_body_id, &declared_return_type);
     // Check declared return type and actual return type
     if !can_ignore_ret {
         let (expr_span, empty_function) = function_info(type_checker.interner, function_body_id);
@@ -354,32 +367,44 @@ fn check_function_type_matches_expected_type(
 }
 
 impl<'interner> TypeChecker<'interner> {
-    fn new(interner: &'interner mut NodeInterner) -> Self {
+    fn new(
+        interner: &'interner mut NodeInterner,
+        def_maps: &'interner BTreeMap<CrateId, CrateDefMap>,
+    ) -> Self {
         Self {
             interner,
+            def_maps,
             errors: Vec::new(),
             trait_constraints: Vec::new(),
             type_variables: Vec::new(),
             current_function: None,
+            current_global_module: None,
         }
     }
 
-    fn check_function_body(&mut self, body: &ExprId) -> Type {
-        self.check_expression(body)
+    fn check_function_body(&mut self, body: &ExprId, return_type: &Type) -> Type {
+        self.check_expression_with_target_type(body, Some(return_type))
     }
 
     pub fn check_global(
         id: GlobalId,
+        crate_id: CrateId,
         interner: &'interner mut NodeInterner,
+        def_maps: &'interner BTreeMap<CrateId, CrateDefMap>,
     ) -> Vec<TypeCheckError> {
+        let global = interner.get_global(id);
+        let statement = global.let_statement;
+        let module = ModuleId { krate: crate_id, local_id: global.local_id };
+
         let mut this = Self {
             interner,
+            def_maps,
             errors: Vec::new(),
             trait_constraints: Vec::new(),
             type_variables: Vec::new(),
             current_function: None,
+            current_global_module: Some(module),
         };
-        let statement = this.interner.get_global(id).let_statement;
         this.check_statement(&statement);
         this.errors
     }
@@ -445,6 +470,7 @@ pub mod test {
     use crate::hir::resolution::import::{
         PathResolution, PathResolutionError, PathResolutionResult,
     };
+    use crate::hir::resolution::usage_tracker::UsageTracker;
     use crate::hir_def::expr::HirIdent;
     use crate::hir_def::stmt::HirLetStatement;
     use crate::hir_def::stmt::HirPattern::Identifier;
@@ -550,10 +576,11 @@ pub mod test {
             direct_generics: Vec::new(),
             is_entry_point: true,
             has_inline_attribute: false,
+            overflow_checks: true,
         };
         interner.push_fn_meta(func_meta, func_id);
 
-        let errors = super::type_check_func(&mut interner, func_id);
+        let errors = super::type_check_func(&mut interner, &BTreeMap::new(), func_id);
         assert!(errors.is_empty());
     }
 
@@ -665,6 +692,7 @@ pub mod test {
             &self,
             _def_maps: &BTreeMap<CrateId, CrateDefMap>,
             path: Path,
+            _usage_tracker: &mut UsageTracker,
         ) -> PathResolutionResult {
             // Not here that foo::bar and hello::foo::bar would fetch the same thing
             let name = path.segments.last().unwrap();
@@ -757,7 +785,7 @@ pub mod test {
         let mut errors = Vec::new();
 
         for function in func_ids.values() {
-            errors.extend(super::type_check_func(&mut interner, *function));
+            errors.extend(super::type_check_func(&mut interner, &def_maps, *function));
         }
 
         assert_eq!(
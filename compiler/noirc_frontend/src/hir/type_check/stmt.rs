@@ -5,7 +5,7 @@ use crate::ast::UnaryOp;
 use crate::hir_def::expr::{HirExpression, HirIdent, HirLiteral};
 use crate::hir_def::stmt::{
     HirAssignStatement, HirConstrainStatement, HirForStatement, HirLValue, HirLetStatement,
-    HirPattern, HirStatement,
+    HirPattern, HirStatement, HirWhileStatement,
 };
 use crate::hir_def::types::Type;
 use crate::node_interner::{DefinitionId, ExprId, StmtId};
@@ -51,6 +51,7 @@ impl<'interner> TypeChecker<'interner> {
             HirStatement::Constrain(constrain_stmt) => self.check_constrain_stmt(constrain_stmt),
             HirStatement::Assign(assign_stmt) => self.check_assign_stmt(assign_stmt, stmt_id),
             HirStatement::For(for_loop) => self.check_for_loop(for_loop),
+            HirStatement::While(while_loop) => self.check_while_loop(while_loop),
             HirStatement::Comptime(statement) => return self.check_statement(&statement),
             HirStatement::Break | HirStatement::Continue | HirStatement::Error => (),
         }
@@ -59,6 +60,19 @@ impl<'interner> TypeChecker<'interner> {
 
     fn check_for_loop(&mut self, for_loop: HirForStatement) {
         let start_range_type = self.check_expression(&for_loop.start_range);
+
+        // A loop over a collection is bounded by its length. Report a collection which can't be iterated
+        // over directly, and give it an error type so that its missing `len` method and indexing it
+        // aren't reported as well.
+        if let Some(collection) = &for_loop.collection {
+            let collection_type = self.interner.definition_type(collection.id);
+            if !collection_type.is_iterable() {
+                let span = collection.location.span;
+                self.errors.push(TypeCheckError::NotIterable { typ: collection_type, span });
+                self.interner.push_definition_type(collection.id, Type::Error);
+            }
+        }
+
         let end_range_type = self.check_expression(&for_loop.end_range);
 
         let start_span = self.interner.expr_span(&for_loop.start_range);
@@ -85,6 +99,19 @@ impl<'interner> TypeChecker<'interner> {
         self.check_expression(&for_loop.block);
     }
 
+    fn check_while_loop(&mut self, while_loop: HirWhileStatement) {
+        let condition_type = self.check_expression(&while_loop.condition);
+        let expr_span = self.interner.expr_span(&while_loop.condition);
+
+        self.unify(&condition_type, &Type::Bool, || TypeCheckError::TypeMismatch {
+            expected_typ: Type::Bool.to_string(),
+            expr_typ: condition_type.to_string(),
+            expr_span,
+        });
+
+        self.check_expression(&while_loop.block);
+    }
+
     /// Associate a given HirPattern with the given Type, and remember
     /// this association in the NodeInterner.
     pub(crate) fn bind_pattern(&mut self, pattern: &HirPattern, typ: Type) {
@@ -329,7 +356,8 @@ impl<'interner> TypeChecker<'interner> {
     /// the type of the declaration is inferred to match the RHS.
     fn check_declaration(&mut self, rhs_expr: ExprId, annotated_type: Type) -> Type {
         // Type check the expression on the RHS
-        let expr_type = self.check_expression(&rhs_expr);
+        let target_type = (annotated_type != Type::Error).then_some(&annotated_type);
+        let expr_type = self.check_expression_with_target_type(&rhs_expr, target_type);
 
         // First check if the LHS is unspecified
         // If so, then we give it the same type as the expression
@@ -360,16 +388,15 @@ impl<'interner> TypeChecker<'interner> {
         let expr = self.interner.expression(rhs_expr);
         let span = self.interner.expr_span(rhs_expr);
         match expr {
-            HirExpression::Literal(HirLiteral::Integer(value, false)) => {
-                let v = value.to_u128();
+            HirExpression::Literal(HirLiteral::Integer(value, false, repr)) => {
                 if let Type::Integer(_, bit_count) = annotated_type {
                     let bit_count: u32 = (*bit_count).into();
-                    let max = 1 << bit_count;
-                    if v >= max {
+                    if value.num_bits() > bit_count {
+                        let max = u128::MAX >> (128 - bit_count);
                         self.errors.push(TypeCheckError::OverflowingAssignment {
-                            expr: value,
+                            expr: repr.written_form().unwrap_or_else(|| value.to_string()),
                             ty: annotated_type.clone(),
-                            range: format!("0..={}", max - 1),
+                            range: format!("0..={max}"),
                             span,
                         });
                     };
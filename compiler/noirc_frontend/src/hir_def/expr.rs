@@ -4,6 +4,7 @@ use noirc_errors::Location;
 
 use crate::ast::{BinaryOp, BinaryOpKind, Ident, UnaryOp};
 use crate::node_interner::{DefinitionId, ExprId, FuncId, NodeInterner, StmtId, TraitMethodId};
+use crate::token::LiteralRepr;
 use crate::Shared;
 
 use super::stmt::HirPattern;
@@ -107,7 +108,7 @@ pub enum HirLiteral {
     Array(HirArrayLiteral),
     Slice(HirArrayLiteral),
     Bool(bool),
-    Integer(FieldElement, bool), //true for negative integer and false for positive
+    Integer(FieldElement, bool, LiteralRepr), //true for negative integer and false for positive
     Str(String),
     FmtStr(String, Vec<ExprId>),
     Unit,
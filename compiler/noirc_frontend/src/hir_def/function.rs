@@ -127,6 +127,10 @@ pub struct FuncMeta {
     /// that indicates it should be inlined differently than the default (inline everything).
     /// For example, such as `fold` (never inlined) or `no_predicates` (inlined after flattening)
     pub has_inline_attribute: bool,
+
+    /// False if this function is marked `#[no_overflow_checks]`, in which case integer
+    /// `+`, `-` and `*` within its body are not constrained to stay within their type's range.
+    pub overflow_checks: bool,
 }
 
 impl FuncMeta {
@@ -16,6 +16,7 @@ pub enum HirStatement {
     Constrain(HirConstrainStatement),
     Assign(HirAssignStatement),
     For(HirForStatement),
+    While(HirWhileStatement),
     Break,
     Continue,
     Expression(ExprId),
@@ -48,6 +49,15 @@ pub struct HirForStatement {
     pub start_range: ExprId,
     pub end_range: ExprId,
     pub block: ExprId,
+    /// The variable holding the collection iterated over, if this loop was desugared from
+    /// a `for elem in collection` loop.
+    pub collection: Option<HirIdent>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HirWhileStatement {
+    pub condition: ExprId,
+    pub block: ExprId,
 }
 
 /// Corresponds to `lvalue = expression;` in the source code
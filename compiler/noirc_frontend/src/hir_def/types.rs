@@ -6,8 +6,8 @@ use std::{
 };
 
 use crate::{
-    ast::IntegerBitSize,
-    hir::type_check::TypeCheckError,
+    ast::{FunctionReturnType, IntegerBitSize},
+    hir::type_check::{Source, TypeCheckError},
     node_interner::{ExprId, NodeInterner, TraitId, TypeAliasId},
 };
 use iter_extended::vecmap;
@@ -15,7 +15,7 @@ use noirc_errors::{Location, Span};
 use noirc_printable_type::PrintableType;
 
 use crate::{
-    ast::{Ident, Signedness},
+    ast::{Ident, ItemVisibility, Signedness},
     node_interner::StructId,
 };
 
@@ -165,9 +165,13 @@ impl Type {
         }
     }
 
+    /// True if this type is a slice or internally contains a slice, including within the
+    /// elements of an array.
     pub(crate) fn contains_slice(&self) -> bool {
         match self {
             Type::Slice(_) => true,
+            Type::Array(_, elem) => elem.contains_slice(),
+            Type::Alias(alias, generics) => alias.borrow().get_type(generics).contains_slice(),
             Type::Struct(struct_typ, generics) => {
                 let fields = struct_typ.borrow().get_fields(generics);
                 for field in fields.iter() {
@@ -209,12 +213,20 @@ pub struct StructType {
     /// Fields are ordered and private, they should only
     /// be accessed through get_field(), get_fields(), or instantiate()
     /// since these will handle applying generic arguments to fields as well.
-    fields: Vec<(Ident, Type)>,
+    fields: Vec<StructField>,
 
     pub generics: Generics,
     pub location: Location,
 }
 
+/// A resolved field of a struct type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructField {
+    pub visibility: ItemVisibility,
+    pub name: Ident,
+    pub typ: Type,
+}
+
 /// Corresponds to generic lists such as `<T, U>` in the source program.
 pub type Generics = Vec<TypeVariable>;
 
@@ -236,7 +248,7 @@ impl StructType {
         name: Ident,
 
         location: Location,
-        fields: Vec<(Ident, Type)>,
+        fields: Vec<StructField>,
         generics: Generics,
     ) -> StructType {
         StructType { id, fields, name, location, generics }
@@ -246,7 +258,7 @@ impl StructType {
     /// fields are resolved strictly after the struct itself is initially
     /// created. Therefore, this method is used to set the fields once they
     /// become known.
-    pub fn set_fields(&mut self, fields: Vec<(Ident, Type)>) {
+    pub fn set_fields(&mut self, fields: Vec<StructField>) {
         assert!(self.fields.is_empty());
         self.fields = fields;
     }
@@ -259,8 +271,8 @@ impl StructType {
     pub fn get_field(&self, field_name: &str, generic_args: &[Type]) -> Option<(Type, usize)> {
         assert_eq!(self.generics.len(), generic_args.len());
 
-        self.fields.iter().enumerate().find(|(_, (name, _))| name.0.contents == field_name).map(
-            |(i, (_, typ))| {
+        self.fields.iter().enumerate().find(|(_, field)| field.name.0.contents == field_name).map(
+            |(i, StructField { typ, .. })| {
                 let substitutions = self
                     .generics
                     .iter()
@@ -274,6 +286,9 @@ impl StructType {
     }
 
     /// Returns all the fields of this type, after being applied to the given generic arguments.
+    ///
+    /// Fields are returned in the order they are declared in the source program. This order is
+    /// the memory layout of the struct once it is lowered to a tuple, and its order in the ABI.
     pub fn get_fields(&self, generic_args: &[Type]) -> Vec<(String, Type)> {
         assert_eq!(self.generics.len(), generic_args.len());
 
@@ -284,14 +299,19 @@ impl StructType {
             .map(|(old, new)| (old.id(), (old.clone(), new.clone())))
             .collect();
 
-        vecmap(&self.fields, |(name, typ)| {
-            let name = name.0.contents.clone();
-            (name, typ.substitute(&substitutions))
+        vecmap(&self.fields, |field| {
+            let name = field.name.0.contents.clone();
+            (name, field.typ.substitute(&substitutions))
         })
     }
 
     pub fn field_names(&self) -> BTreeSet<Ident> {
-        self.fields.iter().map(|(name, _)| name.clone()).collect()
+        self.fields.iter().map(|field| field.name.clone()).collect()
+    }
+
+    /// Returns the declaration of the field with the given name, if any.
+    pub fn get_field_declaration(&self, field_name: &str) -> Option<&StructField> {
+        self.fields.iter().find(|field| field.name.0.contents == field_name)
     }
 
     /// True if the given index is the same index as a generic type of this struct
@@ -299,7 +319,7 @@ impl StructType {
     /// This is needed because we infer type kinds in Noir and don't have extensive kind checking.
     pub fn generic_is_numeric(&self, index_of_generic: usize) -> bool {
         let target_id = self.generics[index_of_generic].0;
-        self.fields.iter().any(|(_, field)| field.contains_numeric_typevar(target_id))
+        self.fields.iter().any(|field| field.typ.contains_numeric_typevar(target_id))
     }
 
     /// Instantiate this struct type, returning a Vec of the new generic args (in
@@ -685,6 +705,20 @@ impl Type {
         }
     }
 
+    /// True if this type can be iterated over by a `for elem in collection` loop.
+    ///
+    /// Unbound type variables are accepted, as an error requesting a type annotation is issued for them instead.
+    pub(crate) fn is_iterable(&self) -> bool {
+        match self.follow_bindings() {
+            Type::Array(..)
+            | Type::Slice(_)
+            | Type::TypeVariable(_, TypeVariableKind::Normal)
+            | Type::Error => true,
+            Type::MutableReference(element) => element.is_iterable(),
+            _ => false,
+        }
+    }
+
     /// True if this type can be used as a parameter to `main` or a contract function.
     /// This is only false for unsized types like slices or slices that do not make sense
     /// as a program input such as named generics or mutable references.
@@ -955,6 +989,16 @@ impl std::fmt::Display for TypeBinding {
 
 pub struct UnificationError;
 
+/// Two types which only differ in the length of an array within them.
+#[derive(Debug)]
+pub struct ArrayLengthMismatch {
+    /// Where the array is within the types, e.g. `.hashpath` or `.0[_]`, or empty if the types
+    /// are the arrays themselves.
+    pub path: String,
+    pub expected: Type,
+    pub found: Type,
+}
+
 impl Type {
     /// Try to bind a MaybeConstant variable to self, succeeding if self is a Constant,
     /// MaybeConstant, or type variable. If successful, the binding is placed in the
@@ -1160,7 +1204,7 @@ impl Type {
                 // Commit any type bindings on success
                 Self::apply_type_bindings(bindings);
             }
-            Err(UnificationError) => errors.push(make_error()),
+            Err(UnificationError) => errors.push(self.refine_type_mismatch(expected, make_error())),
         }
     }
 
@@ -1343,13 +1387,123 @@ impl Type {
 
         if let Err(UnificationError) = self.try_unify(expected, &mut bindings) {
             if !self.try_array_to_slice_coercion(expected, expression, interner) {
-                errors.push(make_error());
+                errors.push(self.refine_type_mismatch(expected, make_error()));
             }
         } else {
             Type::apply_type_bindings(bindings);
         }
     }
 
+    /// Replaces a type mismatch between `self` and `expected` with an array length mismatch if
+    /// the types only differ in the length of an array, which is easy to miss when both types
+    /// are printed in full.
+    fn refine_type_mismatch(&self, expected: &Type, error: TypeCheckError) -> TypeCheckError {
+        let Some(mismatch) = self.array_length_mismatch(expected) else {
+            return error;
+        };
+        let ArrayLengthMismatch { path, expected: expected_length, found: found_length } = mismatch;
+
+        match error {
+            TypeCheckError::TypeMismatch { expected_typ, expr_typ, expr_span } => {
+                TypeCheckError::ArrayLengthMismatch {
+                    expected_length,
+                    found_length,
+                    path,
+                    expected_typ,
+                    expr_typ,
+                    span: expr_span,
+                    expected_span: None,
+                }
+            }
+            TypeCheckError::TypeMismatchWithSource {
+                expected,
+                actual,
+                source: Source::Return(return_type, expr_span),
+                ..
+            } => {
+                let return_type_span = match return_type {
+                    FunctionReturnType::Default(span) => span,
+                    FunctionReturnType::Ty(typ) => typ.span.unwrap(),
+                };
+                TypeCheckError::ArrayLengthMismatch {
+                    expected_length,
+                    found_length,
+                    path,
+                    expected_typ: expected.to_string(),
+                    expr_typ: actual.to_string(),
+                    span: expr_span,
+                    expected_span: Some(return_type_span),
+                }
+            }
+            error => error,
+        }
+    }
+
+    /// If `self` only fails to unify with `expected` because an array within them has a
+    /// different length, returns the first such array.
+    pub fn array_length_mismatch(&self, expected: &Type) -> Option<ArrayLengthMismatch> {
+        let mut mismatches = Vec::new();
+        self.find_array_length_mismatches(expected, String::new(), &mut mismatches).ok()?;
+        mismatches.into_iter().next()
+    }
+
+    /// Pushes each array within `self` and `expected` whose lengths don't unify to `mismatches`.
+    /// Fails if the types differ in any other way.
+    fn find_array_length_mismatches(
+        &self,
+        expected: &Type,
+        path: String,
+        mismatches: &mut Vec<ArrayLengthMismatch>,
+    ) -> Result<(), UnificationError> {
+        if self.try_unify(expected, &mut TypeBindings::new()).is_ok() {
+            return Ok(());
+        }
+
+        match (self.follow_bindings(), expected.follow_bindings()) {
+            (Type::Array(found, element), Type::Array(expected_length, expected_element)) => {
+                let element_path = format!("{path}[_]");
+                element.find_array_length_mismatches(
+                    &expected_element,
+                    element_path,
+                    mismatches,
+                )?;
+                if found.try_unify(&expected_length, &mut TypeBindings::new()).is_err() {
+                    let expected = *expected_length;
+                    mismatches.push(ArrayLengthMismatch { path, expected, found: *found });
+                }
+                Ok(())
+            }
+            (Type::Tuple(elements), Type::Tuple(expected_elements))
+                if elements.len() == expected_elements.len() =>
+            {
+                for (index, (element, expected_element)) in
+                    elements.iter().zip(&expected_elements).enumerate()
+                {
+                    let element_path = format!("{path}.{index}");
+                    element.find_array_length_mismatches(
+                        expected_element,
+                        element_path,
+                        mismatches,
+                    )?;
+                }
+                Ok(())
+            }
+            (Type::Struct(struct_type, args), Type::Struct(expected_struct, expected_args))
+                if struct_type == expected_struct && args.len() == expected_args.len() =>
+            {
+                let struct_type = struct_type.borrow();
+                let fields = struct_type.get_fields(&args);
+                let expected_fields = struct_type.get_fields(&expected_args);
+                for ((name, field), (_, expected_field)) in fields.iter().zip(&expected_fields) {
+                    let field_path = format!("{path}.{name}");
+                    field.find_array_length_mismatches(expected_field, field_path, mismatches)?;
+                }
+                Ok(())
+            }
+            _ => Err(UnificationError),
+        }
+    }
+
     /// Try to apply the array to slice coercion to this given type pair and expression.
     /// If self can be converted to target this way, do so and return true to indicate success.
     fn try_array_to_slice_coercion(
@@ -1374,6 +1528,61 @@ impl Type {
         false
     }
 
+    /// Returns the parameter types of this type if it is a function, or an empty list otherwise.
+    pub(crate) fn function_parameter_types(&self) -> Vec<Type> {
+        match self.follow_bindings() {
+            Type::Function(parameters, ..) => parameters,
+            Type::Forall(_, typ) => typ.function_parameter_types(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Unifies the types of the two branches of an `if` expression, returning the type of the
+    /// whole expression.
+    ///
+    /// If the branches are arrays of the same element type but different lengths and the `if`
+    /// expression is expected to be a slice, both branches are coerced into slices, e.g.
+    /// `let s: [Field] = if c { [1, 2] } else { [1, 2, 3] };`. A slice is expected when the `if`
+    /// is the value of a `let` annotated with a slice type, an argument to a slice parameter or
+    /// the returned value of a function returning a slice.
+    #[allow(clippy::too_many_arguments)]
+    pub fn unify_if_branches(
+        &self,
+        else_type: &Type,
+        target_type: Option<&Type>,
+        consequence: ExprId,
+        alternative: ExprId,
+        interner: &mut NodeInterner,
+        errors: &mut Vec<TypeCheckError>,
+        make_error: impl FnOnce() -> TypeCheckError,
+    ) -> Type {
+        let mut bindings = TypeBindings::new();
+        if self.try_unify(else_type, &mut bindings).is_ok() {
+            Type::apply_type_bindings(bindings);
+            return self.clone();
+        }
+
+        let then_type = self.follow_bindings();
+        let else_type = else_type.follow_bindings();
+        let target_is_slice =
+            matches!(target_type.map(Type::follow_bindings), Some(Type::Slice(_)));
+        if let (true, Type::Array(_, then_element), Type::Array(_, else_element)) =
+            (target_is_slice, &then_type, &else_type)
+        {
+            let mut bindings = TypeBindings::new();
+            if then_element.try_unify(else_element, &mut bindings).is_ok() {
+                Type::apply_type_bindings(bindings);
+                let slice = Type::Slice(then_element.clone());
+                convert_array_expression_to_slice(consequence, then_type, slice.clone(), interner);
+                convert_array_expression_to_slice(alternative, else_type, slice.clone(), interner);
+                return slice;
+            }
+        }
+
+        errors.push(make_error());
+        self.clone()
+    }
+
     /// Apply the given type bindings, making them permanently visible for each
     /// clone of each type variable bound.
     pub fn apply_type_bindings(bindings: TypeBindings) {
@@ -1407,8 +1616,8 @@ impl Type {
             // only to have to call .into_iter again afterward. Trying to elide
             // collecting to a Vec leads to us dropping the temporary Ref before
             // the iterator is returned
-            Type::Struct(def, args) => vecmap(&def.borrow().fields, |(name, _)| {
-                let name = &name.0.contents;
+            Type::Struct(def, args) => vecmap(&def.borrow().fields, |field| {
+                let name = &field.name.0.contents;
                 let typ = def.borrow().get_field(name, args).unwrap().0;
                 (name.clone(), typ)
             }),
@@ -15,6 +15,10 @@ pub enum LexerErrorKind {
     NotADoubleChar { span: Span, found: Token },
     #[error("Invalid integer literal, {:?} is not a integer", found)]
     InvalidIntegerLiteral { span: Span, found: String },
+    #[error("Expected digits after {prefix} in integer literal")]
+    MissingIntegerDigits { span: Span, prefix: String },
+    #[error("Integer literal {found} is larger than the field modulus")]
+    IntegerLiteralTooLarge { span: Span, found: String },
     #[error("{:?} is not a valid attribute", found)]
     MalformedFuncAttribute { span: Span, found: String },
     #[error("Logical and used instead of bitwise and")]
@@ -27,6 +31,10 @@ pub enum LexerErrorKind {
         "'\\{escaped}' is not a valid escape sequence. Use '\\' for a literal backslash character."
     )]
     InvalidEscape { escaped: char, span: Span },
+    #[error("'{escape}' is not a valid byte escape")]
+    InvalidByteEscape { escape: String, span: Span },
+    #[error("Identifier `{name}` mixes characters from the {first} and {second} scripts")]
+    MixedScriptIdentifier { span: Span, name: String, first: &'static str, second: &'static str },
 }
 
 impl From<LexerErrorKind> for ParserError {
@@ -42,14 +50,24 @@ impl LexerErrorKind {
             LexerErrorKind::UnexpectedCharacter { span, .. } => *span,
             LexerErrorKind::NotADoubleChar { span, .. } => *span,
             LexerErrorKind::InvalidIntegerLiteral { span, .. } => *span,
+            LexerErrorKind::MissingIntegerDigits { span, .. } => *span,
+            LexerErrorKind::IntegerLiteralTooLarge { span, .. } => *span,
             LexerErrorKind::MalformedFuncAttribute { span, .. } => *span,
             LexerErrorKind::LogicalAnd { span } => *span,
             LexerErrorKind::UnterminatedBlockComment { span } => *span,
             LexerErrorKind::UnterminatedStringLiteral { span } => *span,
             LexerErrorKind::InvalidEscape { span, .. } => *span,
+            LexerErrorKind::InvalidByteEscape { span, .. } => *span,
+            LexerErrorKind::MixedScriptIdentifier { span, .. } => *span,
         }
     }
 
+    /// Mixed-script identifiers are accepted but reported so that look-alike names can be spotted.
+    /// They can be rejected with `--deny-warnings`.
+    pub fn is_warning(&self) -> bool {
+        matches!(self, LexerErrorKind::MixedScriptIdentifier { .. })
+    }
+
     fn parts(&self) -> (String, String, Span) {
         match self {
             LexerErrorKind::UnexpectedCharacter {
@@ -77,6 +95,16 @@ impl LexerErrorKind {
                 format!(" {found} is not an integer"),
                 *span,
             ),
+            LexerErrorKind::MissingIntegerDigits { span, prefix } => (
+                "Invalid integer literal".to_string(),
+                format!("Expected digits after {prefix}"),
+                *span,
+            ),
+            LexerErrorKind::IntegerLiteralTooLarge { span, .. } => (
+                self.to_string(),
+                "Hexadecimal, octal and binary literals are not reduced modulo the field".to_string(),
+                *span,
+            ),
             LexerErrorKind::MalformedFuncAttribute { span, found } => (
                 "Malformed function attribute".to_string(),
                 format!(" {found} is not a valid attribute"),
@@ -92,6 +120,16 @@ impl LexerErrorKind {
                 ("Unterminated string literal".to_string(), "Unterminated string literal".to_string(), *span),
             LexerErrorKind::InvalidEscape { escaped, span } =>
                 (format!("'\\{escaped}' is not a valid escape sequence. Use '\\' for a literal backslash character."), "Invalid escape sequence".to_string(), *span),
+            LexerErrorKind::InvalidByteEscape { span, .. } => (
+                self.to_string(),
+                "Byte escapes need two hex digits, and can only be above \\x7F in byte strings".to_string(),
+                *span,
+            ),
+            LexerErrorKind::MixedScriptIdentifier { span, .. } => (
+                self.to_string(),
+                "Letters from different scripts can look identical, so this name may be mistaken for another".to_string(),
+                *span,
+            ),
         }
     }
 }
@@ -99,7 +137,11 @@ impl LexerErrorKind {
 impl<'a> From<&'a LexerErrorKind> for Diagnostic {
     fn from(error: &'a LexerErrorKind) -> Diagnostic {
         let (primary, secondary, span) = error.parts();
-        Diagnostic::simple_error(primary, secondary, span)
+        if error.is_warning() {
+            Diagnostic::simple_warning(primary, secondary, span)
+        } else {
+            Diagnostic::simple_error(primary, secondary, span)
+        }
     }
 }
 
@@ -3,12 +3,15 @@ use crate::token::{Attribute, DocStyle};
 use super::{
     errors::LexerErrorKind,
     token::{
-        token_to_borrowed_token, BorrowedToken, IntType, Keyword, SpannedToken, Token, Tokens,
+        token_to_borrowed_token, BorrowedToken, IntType, Keyword, LiteralRepr, SpannedToken, Token,
+        Tokens,
     },
 };
 use acvm::FieldElement;
 use noirc_errors::{Position, Span};
 use std::str::CharIndices;
+use unicode_normalization::UnicodeNormalization;
+use unicode_xid::UnicodeXID;
 
 /// The job of the lexer is to transform an iterator of characters (`char_iter`)
 /// into an iterator of `SpannedToken`. Each `Token` corresponds roughly to 1 word or operator.
@@ -19,6 +22,8 @@ pub struct Lexer<'a> {
     done: bool,
     skip_comments: bool,
     skip_whitespaces: bool,
+    /// A warning about the last token, returned by the following call to `next`.
+    pending_warning: Option<LexerErrorKind>,
 }
 
 pub type SpannedTokenResult = Result<SpannedToken, LexerErrorKind>;
@@ -55,12 +60,34 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn new(source: &'a str) -> Self {
-        Lexer {
+        let mut lexer = Lexer {
             chars: source.char_indices(),
             position: 0,
             done: false,
             skip_comments: true,
             skip_whitespaces: true,
+            pending_warning: None,
+        };
+        lexer.skip_preamble(source);
+        lexer
+    }
+
+    /// Skips an optional byte order mark followed by an optional `#!` shebang line at the start of `source`.
+    ///
+    /// No tokens are emitted for the skipped characters but they still count towards the spans
+    /// of all following tokens so that these match byte offsets within the file.
+    fn skip_preamble(&mut self, source: &str) {
+        let mut rest = source;
+        if let Some(stripped) = rest.strip_prefix('\u{FEFF}') {
+            self.next_char();
+            rest = stripped;
+        }
+
+        // `#![` is not treated as a shebang so that it may still be used for inner attributes.
+        if rest.starts_with("#!") && !rest.starts_with("#![") {
+            while self.peek_char().is_some_and(|ch| ch != '\n') {
+                self.next_char();
+            }
         }
     }
 
@@ -144,8 +171,11 @@ impl<'a> Lexer<'a> {
             Some('"') => self.eat_string_literal(),
             Some('f') => self.eat_format_string_or_alpha_numeric(),
             Some('r') => self.eat_raw_string_or_alpha_numeric(),
+            Some('b') => self.eat_byte_string_or_alpha_numeric(),
             Some('#') => self.eat_attribute(),
-            Some(ch) if ch.is_ascii_alphanumeric() || ch == '_' => self.eat_alpha_numeric(ch),
+            Some(ch) if ch.is_ascii_alphanumeric() || ch == '_' || ch.is_xid_start() => {
+                self.eat_alpha_numeric(ch)
+            }
             Some(ch) => {
                 // We don't report invalid tokens in the source as errors until parsing to
                 // avoid reporting the error twice. See the note on Token::Invalid's documentation for details.
@@ -185,7 +215,20 @@ impl<'a> Lexer<'a> {
     fn glue(&mut self, prev_token: Token) -> SpannedTokenResult {
         let spanned_prev_token = prev_token.clone().into_single_span(self.position);
         match prev_token {
-            Token::Dot => self.single_double_peek_token('.', prev_token, Token::DoubleDot),
+            Token::Dot => {
+                let start = self.position;
+                if self.peek_char_is('.') {
+                    self.next_char();
+                    if self.peek_char_is('=') {
+                        self.next_char();
+                        Ok(Token::DoubleDotEqual.into_span(start, start + 2))
+                    } else {
+                        Ok(Token::DoubleDot.into_span(start, start + 1))
+                    }
+                } else {
+                    Ok(spanned_prev_token)
+                }
+            }
             Token::Less => {
                 let start = self.position;
                 if self.peek_char_is('=') {
@@ -268,6 +311,7 @@ impl<'a> Lexer<'a> {
         match initial_char {
             'A'..='Z' | 'a'..='z' | '_' => Ok(self.eat_word(initial_char)?),
             '0'..='9' => self.eat_digit(initial_char),
+            ch if ch.is_xid_start() => Ok(self.eat_word(initial_char)?),
             _ => Err(LexerErrorKind::UnexpectedCharacter {
                 span: Span::single_char(self.position),
                 found: initial_char.into(),
@@ -279,6 +323,11 @@ impl<'a> Lexer<'a> {
     fn eat_attribute(&mut self) -> SpannedTokenResult {
         let start = self.position;
 
+        let is_inner = self.peek_char_is('!');
+        if is_inner {
+            self.next_char();
+        }
+
         if !self.peek_char_is('[') {
             return Err(LexerErrorKind::UnexpectedCharacter {
                 span: Span::single_char(self.position),
@@ -301,9 +350,19 @@ impl<'a> Lexer<'a> {
 
         let end = self.position;
 
-        let attribute = Attribute::lookup_attribute(&word, Span::inclusive(start, end))?;
+        let span = Span::inclusive(start, end);
+        let attribute = Attribute::lookup_attribute(&word, span)?;
+        if !is_inner {
+            return Ok(attribute.into_span(start, end));
+        }
 
-        Ok(attribute.into_span(start, end))
+        // Only secondary attributes can apply to a whole module
+        match attribute {
+            Token::Attribute(Attribute::Secondary(attribute)) => {
+                Ok(Token::InnerAttribute(attribute).into_span(start, end))
+            }
+            _ => Err(LexerErrorKind::MalformedFuncAttribute { span, found: word }),
+        }
     }
 
     //XXX(low): Can increase performance if we use iterator semantic and utilize some of the methods on String. See below
@@ -311,12 +370,15 @@ impl<'a> Lexer<'a> {
     fn eat_word(&mut self, initial_char: char) -> SpannedTokenResult {
         let start = self.position;
 
-        let word = self.eat_while(Some(initial_char), |ch| {
-            ch.is_ascii_alphabetic() || ch.is_numeric() || ch == '_'
-        });
+        let word = self.eat_while(Some(initial_char), |ch| ch.is_xid_continue());
 
         let end = self.position;
 
+        // Identifiers are compared in Normalization Form C so that names which look the same
+        // refer to the same item, however they were encoded. This must happen before keywords
+        // are looked up.
+        let word = if word.is_ascii() { word } else { word.nfc().collect() };
+
         // Check if word either an identifier or a keyword
         if let Some(keyword_token) = Keyword::lookup_keyword(&word) {
             return Ok(keyword_token.into_span(start, end));
@@ -332,6 +394,12 @@ impl<'a> Lexer<'a> {
         }
 
         // Else it is just an identifier
+        if let Some((first, second)) = mixed_scripts(&word) {
+            let span = Span::inclusive(start, end);
+            let name = word.clone();
+            self.pending_warning =
+                Some(LexerErrorKind::MixedScriptIdentifier { span, name, first, second });
+        }
         let ident_token = Token::Ident(word);
         Ok(ident_token.into_span(start, end))
     }
@@ -340,7 +408,7 @@ impl<'a> Lexer<'a> {
         let start = self.position;
 
         let integer_str = self.eat_while(Some(initial_char), |ch| {
-            ch.is_ascii_digit() | ch.is_ascii_hexdigit() | (ch == 'x') | (ch == '_')
+            ch.is_ascii_digit() | ch.is_ascii_hexdigit() | (ch == 'x') | (ch == 'o') | (ch == '_')
         });
 
         let end = self.position;
@@ -360,56 +428,159 @@ impl<'a> Lexer<'a> {
             });
         }
 
+        let repr = LiteralRepr::from_source(&integer_str);
+        let (prefix, radix) = match integer_str.get(..2) {
+            Some(prefix @ "0x") => (Some(prefix), 16),
+            Some(prefix @ "0o") => (Some(prefix), 8),
+            Some(prefix @ "0b") => (Some(prefix), 2),
+            _ => (None, 10),
+        };
+
         // Underscores needs to be stripped out before the literal can be converted to a `FieldElement.
-        let integer_str = integer_str.replace('_', "");
+        let digits = integer_str[prefix.map_or(0, str::len)..].replace('_', "");
+        if let (Some(prefix), true) = (prefix, digits.is_empty()) {
+            return Err(LexerErrorKind::MissingIntegerDigits {
+                span: Span::inclusive(start, start + 1),
+                prefix: prefix.to_string(),
+            });
+        }
+        if !digits.chars().all(|digit| digit.is_digit(radix)) {
+            return Err(LexerErrorKind::InvalidIntegerLiteral {
+                span: Span::inclusive(start, end),
+                found: integer_str,
+            });
+        }
 
-        let integer = match FieldElement::try_from_str(&integer_str) {
-            None => {
-                return Err(LexerErrorKind::InvalidIntegerLiteral {
-                    span: Span::inclusive(start, end),
-                    found: integer_str,
-                })
-            }
-            Some(integer) => integer,
-        };
+        // Prefixed literals are never reduced modulo the field, so values which are too large are
+        // rejected. Decimal literals are still reduced, which the standard library relies on to
+        // detect the field in use.
+        let digits = digits.trim_start_matches('0');
+        let modulus = FieldElement::modulus().to_str_radix(radix);
+        if prefix.is_some()
+            && (digits.len(), digits.to_ascii_lowercase()) >= (modulus.len(), modulus)
+        {
+            return Err(LexerErrorKind::IntegerLiteralTooLarge {
+                span: Span::inclusive(start, end),
+                found: integer_str,
+            });
+        }
+
+        let radix_element = FieldElement::from(radix as u128);
+        let integer = digits.chars().fold(FieldElement::zero(), |integer, digit| {
+            let digit = digit.to_digit(radix).expect("digits were checked above");
+            integer * radix_element + FieldElement::from(digit as u128)
+        });
 
-        let integer_token = Token::Int(integer);
+        let integer_token = Token::Int(integer, repr);
         Ok(integer_token.into_span(start, end))
     }
 
     fn eat_string_literal(&mut self) -> SpannedTokenResult {
         let start = self.position;
-        let mut string = String::new();
-
-        while let Some(next) = self.next_char() {
-            let char = match next {
-                '"' => break,
-                '\\' => match self.next_char() {
-                    Some('r') => '\r',
-                    Some('n') => '\n',
-                    Some('t') => '\t',
-                    Some('0') => '\0',
-                    Some('"') => '"',
-                    Some('\\') => '\\',
-                    Some(escaped) => {
-                        let span = Span::inclusive(start, self.position);
-                        return Err(LexerErrorKind::InvalidEscape { escaped, span });
-                    }
-                    None => {
-                        let span = Span::inclusive(start, self.position);
-                        return Err(LexerErrorKind::UnterminatedStringLiteral { span });
+        let bytes = self.eat_string_contents(start, false)?;
+        let string = String::from_utf8(bytes).expect("escapes in strings are always ASCII");
+
+        let str_literal_token = Token::Str(string);
+
+        let end = self.position;
+        Ok(str_literal_token.into_span(start, end))
+    }
+
+    // Like format strings, the leading `b` is captured in the Span
+    fn eat_byte_string(&mut self) -> SpannedTokenResult {
+        let start = self.position;
+
+        self.next_char(); // Advance past the opening quote
+        let bytes = self.eat_string_contents(start, true)?;
+
+        let byte_str_token = Token::ByteStr(bytes);
+
+        let end = self.position;
+        Ok(byte_str_token.into_span(start, end))
+    }
+
+    fn eat_byte_string_or_alpha_numeric(&mut self) -> SpannedTokenResult {
+        if self.peek_char_is('"') {
+            self.eat_byte_string()
+        } else {
+            self.eat_alpha_numeric('b')
+        }
+    }
+
+    /// Eats the contents of a string literal which starts at `start`, up to and including its
+    /// closing quote, returning its bytes once escape sequences have been replaced.
+    ///
+    /// Byte escapes such as `\x41` may only be above `\x7F` in byte strings, as strings must be valid UTF-8.
+    fn eat_string_contents(
+        &mut self,
+        start: u32,
+        is_byte_string: bool,
+    ) -> Result<Vec<u8>, LexerErrorKind> {
+        let mut bytes = Vec::new();
+
+        loop {
+            let byte = match self.next_char() {
+                Some('"') => return Ok(bytes),
+                Some('\\') => {
+                    let escape_start = self.position;
+                    match self.next_char() {
+                        Some('r') => b'\r',
+                        Some('n') => b'\n',
+                        Some('t') => b'\t',
+                        Some('0') => b'\0',
+                        Some('"') => b'"',
+                        Some('\\') => b'\\',
+                        Some('x') => self.eat_byte_escape(escape_start, is_byte_string)?,
+                        Some(escaped) => {
+                            let span = Span::inclusive(escape_start, self.position);
+                            return Err(LexerErrorKind::InvalidEscape { escaped, span });
+                        }
+                        None => {
+                            let span = Span::inclusive(start, self.position);
+                            return Err(LexerErrorKind::UnterminatedStringLiteral { span });
+                        }
                     }
-                },
-                other => other,
+                }
+                Some(other) => {
+                    let mut buffer = [0; 4];
+                    bytes.extend_from_slice(other.encode_utf8(&mut buffer).as_bytes());
+                    continue;
+                }
+                None => {
+                    let span = Span::inclusive(start, self.position);
+                    return Err(LexerErrorKind::UnterminatedStringLiteral { span });
+                }
             };
 
-            string.push(char);
+            bytes.push(byte);
         }
+    }
 
-        let str_literal_token = Token::Str(string);
+    /// Eats the two hex digits of a byte escape such as `\x41`, where the `\` is at `escape_start`.
+    fn eat_byte_escape(
+        &mut self,
+        escape_start: u32,
+        is_byte_string: bool,
+    ) -> Result<u8, LexerErrorKind> {
+        let mut digits = String::new();
+        while digits.len() < 2 {
+            match self.peek_char() {
+                Some(digit) if digit.is_ascii_hexdigit() => {
+                    self.next_char();
+                    digits.push(digit);
+                }
+                _ => break,
+            }
+        }
 
-        let end = self.position;
-        Ok(str_literal_token.into_span(start, end))
+        let max_byte = if is_byte_string { u8::MAX } else { 0x7F };
+        match u8::from_str_radix(&digits, 16) {
+            Ok(byte) if digits.len() == 2 && byte <= max_byte => Ok(byte),
+            _ => Err(LexerErrorKind::InvalidByteEscape {
+                escape: format!("\\x{digits}"),
+                span: Span::inclusive(escape_start, self.position),
+            }),
+        }
     }
 
     // This differs from `eat_string_literal` in that we want the leading `f` to be captured in the Span
@@ -572,7 +743,8 @@ impl<'a> Lexer<'a> {
             }
             Ok(Token::BlockComment(content, doc_style).into_span(start, self.position))
         } else {
-            let span = Span::inclusive(start, self.position);
+            // Point at the opening `/*` rather than the end of the file, which is where the error is noticed.
+            let span = Span::inclusive(start, start + 1);
             Err(LexerErrorKind::UnterminatedBlockComment { span })
         }
     }
@@ -593,7 +765,9 @@ impl<'a> Iterator for Lexer<'a> {
     type Item = SpannedTokenResult;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.done {
+        if let Some(warning) = self.pending_warning.take() {
+            Some(Err(warning))
+        } else if self.done {
             None
         } else {
             Some(self.next_token())
@@ -601,14 +775,100 @@ impl<'a> Iterator for Lexer<'a> {
     }
 }
 
+/// Returns the first two scripts used by the letters of `identifier`, if it uses more than one.
+///
+/// Mixing scripts allows names which look identical to others, e.g. `pаram` using a Cyrillic `а`.
+/// Han, Hiragana and Katakana are treated as a single script as Japanese mixes them.
+fn mixed_scripts(identifier: &str) -> Option<(&'static str, &'static str)> {
+    if identifier.is_ascii() {
+        return None;
+    }
+    let mut scripts = identifier.chars().filter_map(script);
+    let first = scripts.next()?;
+    let second = scripts.find(|script| *script != first)?;
+    Some((first, second))
+}
+
+/// The script of a letter, for the scripts most likely to be confused with one another.
+/// Returns None for digits, `_` and any characters from other scripts.
+fn script(ch: char) -> Option<&'static str> {
+    let script = match ch {
+        'a'..='z' | 'A'..='Z' | '\u{C0}'..='\u{24F}' | '\u{1E00}'..='\u{1EFF}' => "Latin",
+        '\u{370}'..='\u{3FF}' | '\u{1F00}'..='\u{1FFF}' => "Greek",
+        '\u{400}'..='\u{52F}' => "Cyrillic",
+        '\u{530}'..='\u{58F}' => "Armenian",
+        '\u{590}'..='\u{5FF}' => "Hebrew",
+        '\u{600}'..='\u{6FF}' => "Arabic",
+        '\u{3040}'..='\u{30FF}' | '\u{4E00}'..='\u{9FFF}' => "Han",
+        '\u{AC00}'..='\u{D7AF}' => "Hangul",
+        _ => return None,
+    };
+    Some(script)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::token::{FunctionAttribute, SecondaryAttribute, TestScope};
 
+    #[test]
+    fn skips_byte_order_mark_and_shebang() {
+        let input = "\u{FEFF}#!/usr/bin/env nargo\nfn main";
+
+        let tokens: Vec<_> = Lexer::new(input)
+            .map(|token| token.unwrap())
+            .map(|token| (token.to_span(), token.into_token()))
+            .collect();
+        let fn_start = input.find("fn").unwrap() as u32;
+        let main_start = input.find("main").unwrap() as u32;
+        assert_eq!(
+            tokens,
+            vec![
+                (Span::inclusive(fn_start, fn_start + 1), Token::Keyword(Keyword::Fn)),
+                (Span::inclusive(main_start, main_start + 3), Token::Ident("main".to_string())),
+                (Span::single_char(main_start + 3), Token::EOF),
+            ]
+        );
+    }
+
+    #[test]
+    fn unicode_identifiers_are_normalized() {
+        // A precomposed `é`, then `e` followed by a combining acute accent.
+        let input = "pr\u{e9}image pre\u{301}image";
+        let tokens: Vec<_> = Lexer::new(input).map(|token| token.unwrap().into_token()).collect();
+        let expected = Token::Ident("pr\u{e9}image".to_string());
+        assert_eq!(tokens, vec![expected.clone(), expected, Token::EOF]);
+    }
+
+    #[test]
+    fn identifiers_mixing_scripts_are_reported() {
+        // The second identifier contains a Cyrillic `а`, the last one is entirely Greek.
+        let input = "param p\u{430}ram \u{3c0}\u{3b1}\u{3c1}\u{3ac}\u{3bc}";
+        let (tokens, errors) = Lexer::lex(input);
+        assert_eq!(tokens.0.len(), 4);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].is_warning());
+        assert!(matches!(
+            &errors[0],
+            LexerErrorKind::MixedScriptIdentifier { name, first: "Latin", second: "Cyrillic", .. }
+                if name == "p\u{430}ram"
+        ));
+    }
+
+    #[test]
+    fn shebang_is_only_skipped_at_start_of_source() {
+        let input = "fn main\n#!/usr/bin/env nargo";
+        assert!(Lexer::new(input).any(|token| token.is_err()));
+
+        // A byte order mark is not a valid token anywhere else either.
+        let input = "fn \u{FEFF}main";
+        let tokens: Vec<_> = Lexer::new(input).map(|token| token.unwrap().into_token()).collect();
+        assert!(tokens.contains(&Token::Invalid('\u{FEFF}')));
+    }
+
     #[test]
     fn test_single_double_char() {
-        let input = "! != + ( ) { } [ ] | , ; : :: < <= > >= & - -> . .. % / * = == << >>";
+        let input = "! != + ( ) { } [ ] | , ; : :: < <= > >= & - -> . .. ..= % / * = == << >>";
 
         let expected = vec![
             Token::Bang,
@@ -634,6 +894,7 @@ mod tests {
             Token::Arrow,
             Token::Dot,
             Token::DoubleDot,
+            Token::DoubleDotEqual,
             Token::Percent,
             Token::Slash,
             Token::Star,
@@ -674,6 +935,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn inner_attribute() {
+        let input = r#"#![allow(unused_imports)]"#;
+        let mut lexer = Lexer::new(input);
+
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::InnerAttribute(SecondaryAttribute::Allow("unused_imports".to_string()))
+        );
+
+        // Function attributes cannot apply to a module
+        let mut lexer = Lexer::new("#![test]");
+        assert!(lexer.next_token().is_err());
+    }
+
     #[test]
     fn test_attribute_with_common_punctuation() {
         let input =
@@ -760,6 +1037,24 @@ mod tests {
         assert_eq!(token.token(), &Token::Attribute(Attribute::Function(FunctionAttribute::Fold)));
     }
 
+    #[test]
+    fn inline_attributes() {
+        let input = r#"#[inline(always)] #[inline(never)]"#;
+
+        let mut lexer = Lexer::new(input);
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::Attribute(Attribute::Function(FunctionAttribute::InlineAlways))
+        );
+
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::Attribute(Attribute::Function(FunctionAttribute::InlineNever))
+        );
+    }
+
     #[test]
     fn contract_library_method_attribute() {
         let input = r#"#[contract_library_method]"#;
@@ -772,6 +1067,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn no_overflow_checks_attribute() {
+        let input = r#"#[no_overflow_checks]"#;
+        let mut lexer = Lexer::new(input);
+
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::Attribute(Attribute::Secondary(SecondaryAttribute::NoOverflowChecks))
+        );
+    }
+
     #[test]
     fn test_attribute_with_valid_scope() {
         let input = r#"#[test(should_fail)]"#;
@@ -830,7 +1137,7 @@ mod tests {
             Token::IntType(IntType::Signed(108)),
             Token::IntType(IntType::Unsigned(104)),
             Token::Dot,
-            Token::Int(5_i128.into()),
+            Token::Int(5_i128.into(), LiteralRepr::Decimal),
         ];
 
         let mut lexer = Lexer::new(input);
@@ -874,6 +1181,17 @@ mod tests {
         assert!(token.is_err());
     }
 
+    #[test]
+    fn unterminated_block_comment_points_at_opening() {
+        let input = "let x = 5;\n/* outer /* inner */\nlet y = 6;\n";
+
+        let (_, errors) = Lexer::lex(input);
+        let [LexerErrorKind::UnterminatedBlockComment { span }] = errors.as_slice() else {
+            panic!("Expected an unterminated block comment error, got {errors:?}");
+        };
+        assert_eq!(&input[span.start() as usize..span.end() as usize], "/*");
+    }
+
     #[test]
     fn test_comment() {
         let input = "// hello
@@ -884,7 +1202,7 @@ mod tests {
             Token::Keyword(Keyword::Let),
             Token::Ident("x".to_string()),
             Token::Assign,
-            Token::Int(FieldElement::from(5_i128)),
+            Token::Int(FieldElement::from(5_i128), LiteralRepr::Decimal),
         ];
 
         let mut lexer = Lexer::new(input);
@@ -906,7 +1224,7 @@ mod tests {
             Token::Keyword(Keyword::Let),
             Token::Ident("x".to_string()),
             Token::Assign,
-            Token::Int(FieldElement::from(5_i128)),
+            Token::Int(FieldElement::from(5_i128), LiteralRepr::Decimal),
         ];
 
         let mut lexer = Lexer::new(input);
@@ -954,7 +1272,7 @@ mod tests {
             Token::Keyword(Keyword::Let),
             Token::Ident("x".to_string()),
             Token::Assign,
-            Token::Int(FieldElement::from(5_i128)),
+            Token::Int(FieldElement::from(5_i128), LiteralRepr::Decimal),
         ];
 
         let mut lexer = Lexer::new(input);
@@ -963,6 +1281,28 @@ mod tests {
             assert_eq!(first_lexer_output, token);
         }
     }
+
+    #[test]
+    fn test_block_comment_edge_cases() {
+        let input = "let/* spans
+        multiple \" lines ' with */x/* \"*/=5/**/;";
+
+        let expected = vec![
+            Token::Keyword(Keyword::Let),
+            Token::Ident("x".to_string()),
+            Token::Assign,
+            Token::Int(FieldElement::from(5_i128), LiteralRepr::Decimal),
+            Token::Semicolon,
+            Token::EOF,
+        ];
+
+        let mut lexer = Lexer::new(input);
+        for token in expected.into_iter() {
+            let first_lexer_output = lexer.next_token().unwrap();
+            assert_eq!(first_lexer_output, token);
+        }
+    }
+
     #[test]
     fn test_eat_string_literal() {
         let input = "let _word = \"hello\"";
@@ -984,11 +1324,20 @@ mod tests {
     #[test]
     fn test_eat_integer_literals() {
         let test_cases: Vec<(&str, Token)> = vec![
-            ("0x05", Token::Int(5_i128.into())),
-            ("5", Token::Int(5_i128.into())),
-            ("0x1234_5678", Token::Int(0x1234_5678_u128.into())),
-            ("0x_01", Token::Int(0x1_u128.into())),
-            ("1_000_000", Token::Int(1_000_000_u128.into())),
+            ("0x05", Token::Int(5_i128.into(), LiteralRepr::from_source("0x05"))),
+            ("5", Token::Int(5_i128.into(), LiteralRepr::Decimal)),
+            (
+                "0x1234_5678",
+                Token::Int(0x1234_5678_u128.into(), LiteralRepr::from_source("0x1234_5678")),
+            ),
+            ("0x_01", Token::Int(0x1_u128.into(), LiteralRepr::from_source("0x_01"))),
+            ("1_000_000", Token::Int(1_000_000_u128.into(), LiteralRepr::Decimal)),
+            ("0o17", Token::Int(0o17_u128.into(), LiteralRepr::from_source("0o17"))),
+            (
+                "0b1010_0101",
+                Token::Int(0b1010_0101_u128.into(), LiteralRepr::from_source("0b1010_0101")),
+            ),
+            ("0xAbC", Token::Int(0xabc_u128.into(), LiteralRepr::from_source("0xAbC"))),
         ];
 
         for (input, expected_token) in test_cases {
@@ -1012,6 +1361,116 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_reject_integer_prefix_without_digits() {
+        for input in ["0x", "0o", "0b", "0x + 1"] {
+            let mut lexer = Lexer::new(input);
+            match lexer.next_token() {
+                Err(LexerErrorKind::MissingIntegerDigits { span, prefix }) => {
+                    assert_eq!(prefix, &input[..2]);
+                    assert_eq!((span.start(), span.end()), (0, 2));
+                }
+                other => panic!("expected {input} to be missing digits, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_reject_invalid_digits_for_radix() {
+        for input in ["0b102", "0o8", "0o1_9"] {
+            let mut lexer = Lexer::new(input);
+            let token = lexer.next_token();
+            assert!(
+                matches!(token, Err(LexerErrorKind::InvalidIntegerLiteral { .. })),
+                "expected {input} to throw error"
+            );
+        }
+    }
+
+    #[test]
+    fn test_reject_integer_literals_exceeding_the_field_modulus() {
+        let modulus = "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+        let max = "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000";
+
+        let mut lexer = Lexer::new(max);
+        let token = lexer.next_token().unwrap();
+        assert_eq!(token.token(), &Token::Int(-FieldElement::one(), LiteralRepr::from_source(max)));
+
+        let binary_modulus = format!("0b{}", FieldElement::modulus().to_str_radix(2));
+        for input in [
+            modulus,
+            "0x1_0000000000000000000000000000000000000000000000000000000000000000",
+            &binary_modulus,
+        ] {
+            let mut lexer = Lexer::new(input);
+            let token = lexer.next_token();
+            assert!(
+                matches!(token, Err(LexerErrorKind::IntegerLiteralTooLarge { .. })),
+                "expected {input} to be too large, got {token:?}"
+            );
+        }
+
+        // Decimal literals are reduced modulo the field.
+        let decimal_modulus = FieldElement::modulus().to_str_radix(10);
+        let mut lexer = Lexer::new(&decimal_modulus);
+        let token = lexer.next_token().unwrap();
+        assert_eq!(token.token(), &Token::Int(FieldElement::zero(), LiteralRepr::Decimal));
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        let input = r#""a\x41\n\t\"\\\x7f" b"\x00\xff\"\\a" b bar"#;
+        let expected = vec![
+            Token::Str("aA\n\t\"\\\x7f".to_string()),
+            Token::ByteStr(vec![0x00, 0xff, b'"', b'\\', b'a']),
+            Token::Ident("b".to_string()),
+            Token::Ident("bar".to_string()),
+            Token::EOF,
+        ];
+        let mut lexer = Lexer::new(input);
+
+        for token in expected.into_iter() {
+            let got = lexer.next_token().unwrap();
+            assert_eq!(got, token);
+        }
+    }
+
+    #[test]
+    fn test_invalid_string_escapes_point_at_the_escape() {
+        let test_cases = [(r#""a\x80""#, (2, 6)), (r#""\x4""#, (1, 4)), (r#"b"\xg0""#, (2, 4))];
+
+        for (input, expected_span) in test_cases {
+            let mut lexer = Lexer::new(input);
+            match lexer.next_token() {
+                Err(LexerErrorKind::InvalidByteEscape { span, .. }) => {
+                    assert_eq!((span.start(), span.end()), expected_span, "{input}");
+                }
+                other => panic!("expected an invalid byte escape in {input}, got {other:?}"),
+            }
+        }
+
+        let mut lexer = Lexer::new(r#""ab\q""#);
+        match lexer.next_token() {
+            Err(LexerErrorKind::InvalidEscape { escaped: 'q', span }) => {
+                assert_eq!((span.start(), span.end()), (3, 5));
+            }
+            other => panic!("expected an invalid escape, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_unterminated_strings() {
+        for input in [r#""abc"#, r#"b"abc"#, r#""abc\""#] {
+            let mut lexer = Lexer::new(input);
+            match lexer.next_token() {
+                Err(LexerErrorKind::UnterminatedStringLiteral { span }) => {
+                    assert_eq!(span.start(), 0, "{input}");
+                }
+                other => panic!("expected {input} to be unterminated, got {other:?}"),
+            }
+        }
+    }
+
     #[test]
     fn test_span() {
         let input = "let x = 5";
@@ -1040,7 +1499,8 @@ mod tests {
 
         // Int position
         let int_position = whitespace_position + 1;
-        let int_token = Token::Int(5_i128.into()).into_single_span(int_position);
+        let int_token =
+            Token::Int(5_i128.into(), LiteralRepr::Decimal).into_single_span(int_position);
 
         let expected = vec![let_token, ident_token, assign_token, int_token];
         let mut lexer = Lexer::new(input);
@@ -1068,14 +1528,14 @@ mod tests {
             Token::Keyword(Keyword::Let),
             Token::Ident("five".to_string()),
             Token::Assign,
-            Token::Int(5_i128.into()),
+            Token::Int(5_i128.into(), LiteralRepr::Decimal),
             Token::Semicolon,
             Token::Keyword(Keyword::Let),
             Token::Ident("ten".to_string()),
             Token::Colon,
             Token::Keyword(Keyword::Field),
             Token::Assign,
-            Token::Int(10_i128.into()),
+            Token::Int(10_i128.into(), LiteralRepr::Decimal),
             Token::Semicolon,
             Token::Keyword(Keyword::Let),
             Token::Ident("mul".to_string()),
@@ -1101,7 +1561,7 @@ mod tests {
             Token::Ident("ten".to_string()),
             Token::RightParen,
             Token::Equal,
-            Token::Int(50_i128.into()),
+            Token::Int(50_i128.into(), LiteralRepr::Decimal),
             Token::Semicolon,
             Token::Keyword(Keyword::Assert),
             Token::LeftParen,
@@ -1109,7 +1569,7 @@ mod tests {
             Token::Plus,
             Token::Ident("five".to_string()),
             Token::Equal,
-            Token::Int(15_i128.into()),
+            Token::Int(15_i128.into(), LiteralRepr::Decimal),
             Token::RightParen,
             Token::Semicolon,
             Token::EOF,
@@ -1213,8 +1673,12 @@ mod tests {
                             }
 
                             Err(LexerErrorKind::InvalidIntegerLiteral { .. })
+                            | Err(LexerErrorKind::IntegerLiteralTooLarge { .. })
+                            | Err(LexerErrorKind::MissingIntegerDigits { .. })
                             | Err(LexerErrorKind::UnexpectedCharacter { .. })
-                            | Err(LexerErrorKind::UnterminatedBlockComment { .. }) => {
+                            | Err(LexerErrorKind::UnterminatedBlockComment { .. })
+                            | Err(LexerErrorKind::UnterminatedStringLiteral { .. })
+                            | Err(LexerErrorKind::InvalidByteEscape { .. }) => {
                                 expected_token_found = true;
                             }
                             Err(err) => {
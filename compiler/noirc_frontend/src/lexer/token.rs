@@ -1,6 +1,12 @@
 use acvm::FieldElement;
 use noirc_errors::{Position, Span, Spanned};
-use std::{fmt, iter::Map, vec::IntoIter};
+use std::{
+    collections::HashSet,
+    fmt,
+    iter::Map,
+    sync::{Mutex, OnceLock},
+    vec::IntoIter,
+};
 
 use crate::lexer::errors::LexerErrorKind;
 
@@ -12,15 +18,17 @@ use crate::lexer::errors::LexerErrorKind;
 #[derive(PartialEq, Eq, Hash, Debug, Clone, PartialOrd, Ord)]
 pub enum BorrowedToken<'input> {
     Ident(&'input str),
-    Int(FieldElement),
+    Int(FieldElement, LiteralRepr),
     Bool(bool),
     Str(&'input str),
     /// the u8 is the number of hashes, i.e. r###..
     RawStr(&'input str, u8),
     FmtStr(&'input str),
+    ByteStr(&'input [u8]),
     Keyword(Keyword),
     IntType(IntType),
     Attribute(Attribute),
+    InnerAttribute(SecondaryAttribute),
     LineComment(&'input str, Option<DocStyle>),
     BlockComment(&'input str, Option<DocStyle>),
     /// <
@@ -57,6 +65,8 @@ pub enum BorrowedToken<'input> {
     Dot,
     /// ..
     DoubleDot,
+    /// ..=
+    DoubleDotEqual,
     /// (
     LeftParen,
     /// )
@@ -104,15 +114,19 @@ pub enum BorrowedToken<'input> {
 #[derive(PartialEq, Eq, Hash, Debug, Clone, PartialOrd, Ord)]
 pub enum Token {
     Ident(String),
-    Int(FieldElement),
+    Int(FieldElement, LiteralRepr),
     Bool(bool),
     Str(String),
     /// the u8 is the number of hashes, i.e. r###..
     RawStr(String, u8),
     FmtStr(String),
+    /// A byte string such as `b"abc"`, holding its bytes once escape sequences have been replaced
+    ByteStr(Vec<u8>),
     Keyword(Keyword),
     IntType(IntType),
     Attribute(Attribute),
+    /// An attribute applying to the module it is written in, e.g. `#![allow(unused_imports)]`
+    InnerAttribute(SecondaryAttribute),
     LineComment(String, Option<DocStyle>),
     BlockComment(String, Option<DocStyle>),
     /// <
@@ -149,6 +163,8 @@ pub enum Token {
     Dot,
     /// ..
     DoubleDot,
+    /// ..=
+    DoubleDotEqual,
     /// (
     LeftParen,
     /// )
@@ -196,13 +212,15 @@ pub enum Token {
 pub fn token_to_borrowed_token(token: &Token) -> BorrowedToken<'_> {
     match token {
         Token::Ident(ref s) => BorrowedToken::Ident(s),
-        Token::Int(n) => BorrowedToken::Int(*n),
+        Token::Int(n, repr) => BorrowedToken::Int(*n, *repr),
         Token::Bool(b) => BorrowedToken::Bool(*b),
         Token::Str(ref b) => BorrowedToken::Str(b),
         Token::FmtStr(ref b) => BorrowedToken::FmtStr(b),
         Token::RawStr(ref b, hashes) => BorrowedToken::RawStr(b, *hashes),
+        Token::ByteStr(ref b) => BorrowedToken::ByteStr(b),
         Token::Keyword(k) => BorrowedToken::Keyword(*k),
         Token::Attribute(ref a) => BorrowedToken::Attribute(a.clone()),
+        Token::InnerAttribute(ref a) => BorrowedToken::InnerAttribute(a.clone()),
         Token::LineComment(ref s, _style) => BorrowedToken::LineComment(s, *_style),
         Token::BlockComment(ref s, _style) => BorrowedToken::BlockComment(s, *_style),
         Token::IntType(ref i) => BorrowedToken::IntType(i.clone()),
@@ -223,6 +241,7 @@ pub fn token_to_borrowed_token(token: &Token) -> BorrowedToken<'_> {
         Token::ShiftRight => BorrowedToken::ShiftRight,
         Token::Dot => BorrowedToken::Dot,
         Token::DoubleDot => BorrowedToken::DoubleDot,
+        Token::DoubleDotEqual => BorrowedToken::DoubleDotEqual,
         Token::LeftParen => BorrowedToken::LeftParen,
         Token::RightParen => BorrowedToken::RightParen,
         Token::LeftBrace => BorrowedToken::LeftBrace,
@@ -304,7 +323,10 @@ impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Token::Ident(ref s) => write!(f, "{s}"),
-            Token::Int(n) => write!(f, "{}", n.to_u128()),
+            Token::Int(n, repr) => match repr.written_form() {
+                Some(written_form) => write!(f, "{written_form}"),
+                None => write!(f, "{}", n.to_u128()),
+            },
             Token::Bool(b) => write!(f, "{b}"),
             Token::Str(ref b) => write!(f, "{b}"),
             Token::FmtStr(ref b) => write!(f, "f{b}"),
@@ -312,8 +334,19 @@ impl fmt::Display for Token {
                 let h: String = std::iter::once('#').cycle().take(hashes as usize).collect();
                 write!(f, "r{h}\"{b}\"{h}")
             }
+            Token::ByteStr(ref b) => {
+                let escaped: String = b
+                    .iter()
+                    .flat_map(|byte| std::ascii::escape_default(*byte))
+                    .map(char::from)
+                    .collect();
+                write!(f, "b\"{escaped}\"")
+            }
             Token::Keyword(k) => write!(f, "{k}"),
             Token::Attribute(ref a) => write!(f, "{a}"),
+            Token::InnerAttribute(ref a) => {
+                write!(f, "#!{}", a.to_string().trim_start_matches('#'))
+            }
             Token::LineComment(ref s, _style) => write!(f, "//{s}"),
             Token::BlockComment(ref s, _style) => write!(f, "/*{s}*/"),
             Token::IntType(ref i) => write!(f, "{i}"),
@@ -334,6 +367,7 @@ impl fmt::Display for Token {
             Token::ShiftRight => write!(f, ">>"),
             Token::Dot => write!(f, "."),
             Token::DoubleDot => write!(f, ".."),
+            Token::DoubleDotEqual => write!(f, "..="),
             Token::LeftParen => write!(f, "("),
             Token::RightParen => write!(f, ")"),
             Token::LeftBrace => write!(f, "{{"),
@@ -364,6 +398,7 @@ pub enum TokenKind {
     Literal,
     Keyword,
     Attribute,
+    InnerAttribute,
 }
 
 impl fmt::Display for TokenKind {
@@ -374,6 +409,7 @@ impl fmt::Display for TokenKind {
             TokenKind::Literal => write!(f, "literal"),
             TokenKind::Keyword => write!(f, "keyword"),
             TokenKind::Attribute => write!(f, "attribute"),
+            TokenKind::InnerAttribute => write!(f, "inner attribute"),
         }
     }
 }
@@ -382,13 +418,15 @@ impl Token {
     pub fn kind(&self) -> TokenKind {
         match *self {
             Token::Ident(_) => TokenKind::Ident,
-            Token::Int(_)
+            Token::Int(..)
             | Token::Bool(_)
             | Token::Str(_)
             | Token::RawStr(..)
-            | Token::FmtStr(_) => TokenKind::Literal,
+            | Token::FmtStr(_)
+            | Token::ByteStr(_) => TokenKind::Literal,
             Token::Keyword(_) => TokenKind::Keyword,
             Token::Attribute(_) => TokenKind::Attribute,
+            Token::InnerAttribute(_) => TokenKind::InnerAttribute,
             ref tok => TokenKind::Token(tok.clone()),
         }
     }
@@ -437,6 +475,88 @@ impl Token {
     }
 }
 
+/// How an integer literal was written in the source.
+///
+/// This has no effect on the value of the literal. It is kept so that diagnostics and printed
+/// programs can show a literal such as `0xff` the way it was written, rather than as `255`.
+///
+/// It is not carried past the monomorphized AST. SSA constants only hold their value, so the
+/// ACIR printed by `--print-acir` shows every constant in decimal.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, PartialOrd, Ord, Default)]
+pub enum LiteralRepr {
+    /// A decimal literal, or one which was not written in the source.
+    #[default]
+    Decimal,
+    /// A hexadecimal literal, holding its digits as written after the `0x` prefix.
+    Hex(InternedStr),
+    /// An octal literal, holding its digits as written after the `0o` prefix.
+    Octal(InternedStr),
+    /// A binary literal, holding its digits as written after the `0b` prefix.
+    Binary(InternedStr),
+}
+
+impl LiteralRepr {
+    /// Returns the representation of an integer literal written as `literal`.
+    pub fn from_source(literal: &str) -> LiteralRepr {
+        if let Some(digits) = literal.strip_prefix("0x") {
+            LiteralRepr::Hex(InternedStr::new(digits))
+        } else if let Some(digits) = literal.strip_prefix("0o") {
+            LiteralRepr::Octal(InternedStr::new(digits))
+        } else if let Some(digits) = literal.strip_prefix("0b") {
+            LiteralRepr::Binary(InternedStr::new(digits))
+        } else {
+            LiteralRepr::Decimal
+        }
+    }
+
+    /// Returns the literal as it was written, if it needs to be displayed differently to a decimal number.
+    pub fn written_form(&self) -> Option<String> {
+        match self {
+            LiteralRepr::Decimal => None,
+            LiteralRepr::Hex(digits) => Some(format!("0x{digits}")),
+            LiteralRepr::Octal(digits) => Some(format!("0o{digits}")),
+            LiteralRepr::Binary(digits) => Some(format!("0b{digits}")),
+        }
+    }
+}
+
+/// A string which is only stored once, however many times it is interned.
+///
+/// Interned strings are never freed, so this should only be used for strings
+/// which are likely to be repeated, such as the digits of literals.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct InternedStr(&'static str);
+
+impl InternedStr {
+    pub fn new(string: &str) -> InternedStr {
+        static STRINGS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+        let mut strings = STRINGS.get_or_init(Default::default).lock().expect("interner poisoned");
+        if let Some(interned) = strings.get(string) {
+            return InternedStr(interned);
+        }
+        let interned: &'static str = Box::leak(string.to_owned().into_boxed_str());
+        strings.insert(interned);
+        InternedStr(interned)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Debug for InternedStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.0, f)
+    }
+}
+
+impl fmt::Display for InternedStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
 #[derive(PartialEq, Eq, Hash, Debug, Clone, PartialOrd, Ord)]
 pub enum IntType {
     Unsigned(u32), // u32 = Unsigned(32)
@@ -554,6 +674,11 @@ impl Attributes {
         matches!(self.function, Some(FunctionAttribute::Test(_)))
     }
 
+    /// Returns true if one of the secondary attributes is `no_overflow_checks`
+    pub fn has_no_overflow_checks(&self) -> bool {
+        self.secondary.iter().any(|attribute| attribute == &SecondaryAttribute::NoOverflowChecks)
+    }
+
     /// True if these attributes mean the given function is an entry point function if it was
     /// defined within a contract. Note that this does not check if the function is actually part
     /// of a contract.
@@ -645,6 +770,8 @@ impl Attribute {
             ["test"] => Attribute::Function(FunctionAttribute::Test(TestScope::None)),
             ["recursive"] => Attribute::Function(FunctionAttribute::Recursive),
             ["fold"] => Attribute::Function(FunctionAttribute::Fold),
+            ["inline", "always"] => Attribute::Function(FunctionAttribute::InlineAlways),
+            ["inline", "never"] => Attribute::Function(FunctionAttribute::InlineNever),
             ["no_predicates"] => Attribute::Function(FunctionAttribute::NoPredicates),
             ["test", name] => {
                 validate(name)?;
@@ -666,6 +793,11 @@ impl Attribute {
             }
             ["abi", tag] => Attribute::Secondary(SecondaryAttribute::Abi(tag.to_string())),
             ["export"] => Attribute::Secondary(SecondaryAttribute::Export),
+            ["no_overflow_checks"] => Attribute::Secondary(SecondaryAttribute::NoOverflowChecks),
+            ["allow", lint] => {
+                validate(lint)?;
+                Attribute::Secondary(SecondaryAttribute::Allow(lint.to_string()))
+            }
             ["deprecated", name] => {
                 if !name.starts_with('"') && !name.ends_with('"') {
                     return Err(LexerErrorKind::MalformedFuncAttribute {
@@ -699,6 +831,8 @@ pub enum FunctionAttribute {
     Recursive,
     Fold,
     NoPredicates,
+    InlineAlways,
+    InlineNever,
 }
 
 impl FunctionAttribute {
@@ -728,8 +862,10 @@ impl FunctionAttribute {
         matches!(self, FunctionAttribute::Foreign(_) | FunctionAttribute::Builtin(_))
     }
 
+    /// Check whether the function should be compiled into its own ACIR circuit.
+    /// `#[inline(never)]` is an alias for `#[fold]`.
     pub fn is_foldable(&self) -> bool {
-        matches!(self, FunctionAttribute::Fold)
+        matches!(self, FunctionAttribute::Fold | FunctionAttribute::InlineNever)
     }
 
     /// Check whether we have an `inline` attribute
@@ -750,6 +886,8 @@ impl fmt::Display for FunctionAttribute {
             FunctionAttribute::Recursive => write!(f, "#[recursive]"),
             FunctionAttribute::Fold => write!(f, "#[fold]"),
             FunctionAttribute::NoPredicates => write!(f, "#[no_predicates]"),
+            FunctionAttribute::InlineAlways => write!(f, "#[inline(always)]"),
+            FunctionAttribute::InlineNever => write!(f, "#[inline(never)]"),
         }
     }
 }
@@ -765,9 +903,14 @@ pub enum SecondaryAttribute {
     // the entry point.
     ContractLibraryMethod,
     Export,
+    // Integer `+`, `-` and `*` in this function are performed without
+    // the constraints checking that they do not overflow.
+    NoOverflowChecks,
     Field(String),
     Custom(String),
     Abi(String),
+    // Silences the warnings of the given kind, e.g. `unused_imports`.
+    Allow(String),
 }
 
 impl fmt::Display for SecondaryAttribute {
@@ -780,8 +923,10 @@ impl fmt::Display for SecondaryAttribute {
             SecondaryAttribute::Custom(ref k) => write!(f, "#[{k}]"),
             SecondaryAttribute::ContractLibraryMethod => write!(f, "#[contract_library_method]"),
             SecondaryAttribute::Export => write!(f, "#[export]"),
+            SecondaryAttribute::NoOverflowChecks => write!(f, "#[no_overflow_checks]"),
             SecondaryAttribute::Field(ref k) => write!(f, "#[field({k})]"),
             SecondaryAttribute::Abi(ref k) => write!(f, "#[abi({k})]"),
+            SecondaryAttribute::Allow(ref k) => write!(f, "#[allow({k})]"),
         }
     }
 }
@@ -796,6 +941,8 @@ impl AsRef<str> for FunctionAttribute {
             FunctionAttribute::Recursive => "",
             FunctionAttribute::Fold => "",
             FunctionAttribute::NoPredicates => "",
+            FunctionAttribute::InlineAlways => "",
+            FunctionAttribute::InlineNever => "",
         }
     }
 }
@@ -807,9 +954,11 @@ impl AsRef<str> for SecondaryAttribute {
             SecondaryAttribute::Deprecated(None) => "",
             SecondaryAttribute::Custom(string)
             | SecondaryAttribute::Field(string)
-            | SecondaryAttribute::Abi(string) => string,
+            | SecondaryAttribute::Abi(string)
+            | SecondaryAttribute::Allow(string) => string,
             SecondaryAttribute::ContractLibraryMethod => "",
             SecondaryAttribute::Export => "",
+            SecondaryAttribute::NoOverflowChecks => "",
         }
     }
 }
@@ -57,8 +57,8 @@ pub mod macros_api {
         Statement, UnresolvedType, UnresolvedTypeData, Visibility,
     };
     pub use crate::ast::{
-        ForLoopStatement, ForRange, FunctionDefinition, ImportStatement, NoirStruct, Param,
-        PrefixExpression, Signedness, StatementKind, TypeImpl, UnaryOp,
+        ForBounds, ForLoopStatement, ForRange, FunctionDefinition, ImportStatement, NoirStruct,
+        Param, PrefixExpression, Signedness, StatementKind, TypeImpl, UnaryOp,
     };
     pub use crate::hir::{def_map::ModuleDefId, Context as HirContext};
     pub use crate::{StructType, Type};
@@ -8,7 +8,7 @@ use noirc_errors::{
 use crate::hir_def::function::FunctionSignature;
 use crate::{
     ast::{BinaryOpKind, IntegerBitSize, Signedness, Visibility},
-    token::{Attributes, FunctionAttribute},
+    token::{Attributes, FunctionAttribute, LiteralRepr},
 };
 
 use super::HirType;
@@ -33,6 +33,7 @@ pub enum Expression {
     Index(Index),
     Cast(Cast),
     For(For),
+    While(While),
     If(If),
     Tuple(Vec<Expression>),
     ExtractTupleField(Box<Expression>, usize),
@@ -57,6 +58,20 @@ pub enum Definition {
     Oracle(String),
 }
 
+/// Distinguishes the locals an [Ident] may refer to, so that later passes do not need to
+/// re-derive whether a value is a parameter of the function it is used in.
+///
+/// Globals are inlined by the monomorphizer so no ident refers to them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DefinitionKind {
+    /// A parameter of the enclosing function, including the environment of a closure.
+    Parameter,
+    /// A local defined by a `let`, a for loop or the monomorphizer itself.
+    Local,
+    /// Any non-local [Definition].
+    Function,
+}
+
 /// ID of a local definition, e.g. from a let binding or
 /// function parameter that should be compiled before it is referenced.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -70,6 +85,7 @@ pub struct FuncId(pub u32);
 pub struct Ident {
     pub location: Option<Location>,
     pub definition: Definition,
+    pub kind: DefinitionKind,
     pub mutable: bool,
     pub name: String,
     pub typ: Type,
@@ -89,11 +105,18 @@ pub struct For {
     pub end_range_location: Location,
 }
 
+#[derive(Debug, Clone, Hash)]
+pub struct While {
+    pub condition: Box<Expression>,
+    pub block: Box<Expression>,
+}
+
 #[derive(Debug, Clone, Hash)]
 pub enum Literal {
     Array(ArrayLiteral),
     Slice(ArrayLiteral),
-    Integer(FieldElement, Type, Location),
+    /// The value of the literal, its type and location, and how it was written in the source.
+    Integer(FieldElement, Type, Location, LiteralRepr),
     Bool(bool),
     Unit,
     Str(String),
@@ -135,7 +158,11 @@ pub struct If {
 #[derive(Debug, Clone, Hash)]
 pub struct Cast {
     pub lhs: Box<Expression>,
+    /// The type being cast from, which is the type of `lhs`.
+    pub from: Type,
     pub r#type: Type,
+    /// Whether the value must already fit into `r#type`, failing otherwise, rather than being truncated.
+    pub checked: bool,
     pub location: Location,
 }
 
@@ -185,6 +212,7 @@ pub struct Let {
 pub struct Assign {
     pub lvalue: LValue,
     pub expression: Box<Expression>,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
@@ -222,14 +250,19 @@ pub enum InlineType {
     /// This attribute is unsafe and can cause a function whose logic relies on predicates from
     /// the flattening pass to fail.
     NoPredicates,
+    /// Functions marked `#[inline(always)]`. Constrained functions are always inlined, so for them
+    /// this is the same as `Inline`. Unconstrained functions marked with it are also inlined into
+    /// the unconstrained functions which call them, rather than being called.
+    InlineAlways,
 }
 
 impl From<&Attributes> for InlineType {
     fn from(attributes: &Attributes) -> Self {
         attributes.function.as_ref().map_or(InlineType::default(), |func_attribute| {
             match func_attribute {
-                FunctionAttribute::Fold => InlineType::Fold,
+                FunctionAttribute::Fold | FunctionAttribute::InlineNever => InlineType::Fold,
                 FunctionAttribute::NoPredicates => InlineType::NoPredicates,
+                FunctionAttribute::InlineAlways => InlineType::InlineAlways,
                 _ => InlineType::default(),
             }
         })
@@ -242,6 +275,7 @@ impl InlineType {
             InlineType::Inline => false,
             InlineType::Fold => true,
             InlineType::NoPredicates => false,
+            InlineType::InlineAlways => false,
         }
     }
 }
@@ -252,6 +286,7 @@ impl std::fmt::Display for InlineType {
             InlineType::Inline => write!(f, "inline"),
             InlineType::Fold => write!(f, "fold"),
             InlineType::NoPredicates => write!(f, "no_predicates"),
+            InlineType::InlineAlways => write!(f, "inline_always"),
         }
     }
 }
@@ -268,6 +303,10 @@ pub struct Function {
     pub unconstrained: bool,
     pub inline_type: InlineType,
     pub func_sig: FunctionSignature,
+
+    /// False if integer arithmetic in this function's body is not checked for overflow.
+    /// Lambdas are always checked, even when defined in a function which is not.
+    pub overflow_checks: bool,
 }
 
 /// Compared to hir_def::types::Type, this monomorphized Type has:
@@ -380,8 +419,9 @@ impl std::ops::IndexMut<FuncId> for Program {
 
 impl std::fmt::Display for Program {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut printer = super::printer::AstPrinter::for_program(self);
         for function in &self.functions {
-            super::printer::AstPrinter::default().print_function(function, f)?;
+            printer.print_function(function, f)?;
         }
         Ok(())
     }
@@ -0,0 +1,691 @@
+//! Evaluates calls to side-effect-free functions made with constant arguments at compile time,
+//! replacing each such call with a literal of the value it returns.
+//!
+//! This lets helpers such as lookup tables, which are written as ordinary functions, cost nothing
+//! at runtime when they are called with constants. Only a subset of the language is interpreted:
+//! fields, unsigned integers, booleans, arrays and tuples. A call is left as it is if the function
+//! (or any function it calls) asserts anything, calls a builtin, low-level or oracle function,
+//! uses references, or fails at runtime, so that any failure is still reported during execution.
+//! Each call may only evaluate a limited number of expressions, after which it is also left as it is.
+use std::collections::{HashMap, HashSet};
+
+use acvm::FieldElement;
+
+use crate::ast::{BinaryOpKind, IntegerBitSize, Signedness, UnaryOp};
+use crate::token::LiteralRepr;
+
+use super::ast::{
+    ArrayLiteral, Call, Definition, Expression, FuncId, Function, Ident, LValue, Literal, LocalId,
+    Program, Type,
+};
+use noirc_errors::Location;
+
+/// The default number of expressions which may be evaluated for a single call before giving up on it.
+pub const DEFAULT_EVALUATION_FUEL: usize = 100_000;
+
+/// The maximum depth of nested calls which are evaluated, which bounds the stack used by recursive functions.
+const MAX_CALL_DEPTH: usize = 64;
+
+/// Replaces each call to a side-effect-free function with constant arguments in `program`
+/// by the literal it evaluates to, evaluating at most `fuel` expressions for each call.
+pub fn evaluate_constant_calls(program: &mut Program, fuel: usize) {
+    // Calls are evaluated against the functions as they were before any call was replaced.
+    let functions = program.functions.clone();
+    let mut evaluator = Evaluator {
+        functions: &functions,
+        pure_functions: HashMap::new(),
+        fuel_per_call: fuel,
+        fuel: 0,
+        call_depth: 0,
+    };
+    for function in &mut program.functions {
+        evaluator.replace_constant_calls(&mut function.body);
+    }
+
+    // A circuit whose calls were all evaluated is no longer compiled, so its signature is dropped.
+    let called = called_functions(program);
+    program.function_signatures = program
+        .functions
+        .iter()
+        .filter(|function| {
+            function.id == Program::main_id()
+                || (function.inline_type.is_entry_point() && called.contains(&function.id))
+        })
+        .map(|function| function.func_sig.clone())
+        .collect();
+}
+
+/// Returns the functions which are still called, directly or indirectly, from `main`.
+fn called_functions(program: &Program) -> HashSet<FuncId> {
+    let mut called = HashSet::from([Program::main_id()]);
+    let mut queue = vec![Program::main_id()];
+    while let Some(id) = queue.pop() {
+        let mut visit = |expression: &Expression| {
+            if let Expression::Ident(Ident { definition: Definition::Function(id), .. }) =
+                expression
+            {
+                if called.insert(*id) {
+                    queue.push(*id);
+                }
+            }
+        };
+        visit_expressions(&program[id].body, &mut visit);
+    }
+    called
+}
+
+/// Calls `f` on `expression` and each expression within it.
+fn visit_expressions(expression: &Expression, f: &mut impl FnMut(&Expression)) {
+    f(expression);
+    for_each_child(expression, &mut |child| visit_expressions(child, f));
+}
+
+#[derive(Debug, Clone)]
+enum Value {
+    Field(FieldElement),
+    Unsigned(u128, IntegerBitSize),
+    Bool(bool),
+    Unit,
+    /// The elements of an array, along with the type of the array itself.
+    Array(Vec<Value>, Type),
+    Tuple(Vec<Value>),
+}
+
+impl Value {
+    fn into_expression(self, location: Location) -> Expression {
+        let literal = match self {
+            Value::Field(value) => {
+                Literal::Integer(value, Type::Field, location, LiteralRepr::Decimal)
+            }
+            Value::Unsigned(value, bit_size) => Literal::Integer(
+                value.into(),
+                Type::Integer(Signedness::Unsigned, bit_size),
+                location,
+                LiteralRepr::Decimal,
+            ),
+            Value::Bool(value) => Literal::Bool(value),
+            Value::Unit => Literal::Unit,
+            Value::Array(elements, typ) => {
+                let contents =
+                    elements.into_iter().map(|element| element.into_expression(location)).collect();
+                Literal::Array(ArrayLiteral { contents, typ })
+            }
+            Value::Tuple(fields) => {
+                let fields = fields.into_iter().map(|field| field.into_expression(location));
+                return Expression::Tuple(fields.collect());
+            }
+        };
+        Expression::Literal(literal)
+    }
+
+    fn to_index(&self) -> Result<usize, Interrupt> {
+        let index = match self {
+            Value::Unsigned(value, _) => *value,
+            Value::Field(value) if value.fits_in_u128() => value.to_u128(),
+            _ => return Err(Interrupt::Unsupported),
+        };
+        usize::try_from(index).map_err(|_| Interrupt::Unsupported)
+    }
+}
+
+/// The reasons the evaluation of an expression may stop before it produces a value.
+#[derive(Debug)]
+enum Interrupt {
+    Break,
+    Continue,
+    /// The expression uses something which cannot be evaluated at compile time,
+    /// or would fail when executed.
+    Unsupported,
+    OutOfFuel,
+}
+
+type EvaluationResult<T = Value> = Result<T, Interrupt>;
+
+/// The values of the locals of the function being evaluated.
+type Scope = HashMap<LocalId, Value>;
+
+struct Evaluator<'a> {
+    functions: &'a [Function],
+    /// Caches whether each function only contains expressions without side effects.
+    pure_functions: HashMap<FuncId, bool>,
+    fuel_per_call: usize,
+    fuel: usize,
+    call_depth: usize,
+}
+
+impl Evaluator<'_> {
+    fn replace_constant_calls(&mut self, expression: &mut Expression) {
+        for_each_child_mut(expression, &mut |child| self.replace_constant_calls(child));
+
+        if let Expression::Call(call) = expression {
+            let location = call.location;
+            if let Some(value) = self.evaluate_constant_call(call) {
+                *expression = value.into_expression(location);
+            }
+        }
+    }
+
+    fn evaluate_constant_call(&mut self, call: &Call) -> Option<Value> {
+        let Expression::Ident(Ident { definition: Definition::Function(id), .. }) =
+            call.func.as_ref()
+        else {
+            return None;
+        };
+        if !self.is_pure(*id) {
+            return None;
+        }
+
+        self.fuel = self.fuel_per_call;
+        // Arguments may only be constants, so they are evaluated without any locals in scope.
+        let mut scope = Scope::new();
+        let arguments: EvaluationResult<Vec<_>> =
+            call.arguments.iter().map(|argument| self.evaluate(argument, &mut scope)).collect();
+
+        match arguments.and_then(|arguments| self.call(*id, arguments)) {
+            Ok(value) => Some(value),
+            Err(Interrupt::OutOfFuel) => {
+                let functions = self.functions;
+                let name = &functions[id.0 as usize].name;
+                tracing::debug!(
+                    "call to `{name}` was not evaluated at compile time as it took more than {} steps",
+                    self.fuel_per_call
+                );
+                None
+            }
+            Err(_) => None,
+        }
+    }
+
+    fn is_pure(&mut self, id: FuncId) -> bool {
+        let functions = self.functions;
+        let function = &functions[id.0 as usize];
+        *self.pure_functions.entry(id).or_insert_with(|| {
+            let has_reference_parameter = function
+                .parameters
+                .iter()
+                .any(|(_, _, _, typ)| matches!(typ, Type::MutableReference(_)));
+            !has_reference_parameter && has_no_side_effects(&function.body)
+        })
+    }
+
+    fn call(&mut self, id: FuncId, arguments: Vec<Value>) -> EvaluationResult {
+        let functions = self.functions;
+        let function = &functions[id.0 as usize];
+        if !self.is_pure(id)
+            || function.parameters.len() != arguments.len()
+            || self.call_depth == MAX_CALL_DEPTH
+        {
+            return Err(Interrupt::Unsupported);
+        }
+
+        let mut scope: Scope =
+            function.parameters.iter().map(|(id, ..)| *id).zip(arguments).collect();
+
+        self.call_depth += 1;
+        let result = self.evaluate(&function.body, &mut scope);
+        self.call_depth -= 1;
+
+        match result {
+            Err(Interrupt::Break | Interrupt::Continue) => Err(Interrupt::Unsupported),
+            result => result,
+        }
+    }
+
+    fn evaluate(&mut self, expression: &Expression, scope: &mut Scope) -> EvaluationResult {
+        if self.fuel == 0 {
+            return Err(Interrupt::OutOfFuel);
+        }
+        self.fuel -= 1;
+
+        match expression {
+            Expression::Ident(ident) => match &ident.definition {
+                Definition::Local(id) => scope.get(id).cloned().ok_or(Interrupt::Unsupported),
+                _ => Err(Interrupt::Unsupported),
+            },
+            Expression::Literal(literal) => self.evaluate_literal(literal, scope),
+            Expression::Block(expressions) => {
+                let mut result = Value::Unit;
+                for expression in expressions {
+                    result = self.evaluate(expression, scope)?;
+                }
+                Ok(result)
+            }
+            Expression::Unary(unary) => {
+                let rhs = self.evaluate(&unary.rhs, scope)?;
+                evaluate_unary(unary.operator, rhs)
+            }
+            Expression::Binary(binary) => {
+                let lhs = self.evaluate(&binary.lhs, scope)?;
+                let rhs = self.evaluate(&binary.rhs, scope)?;
+                evaluate_binary(binary.operator, lhs, rhs)
+            }
+            Expression::Index(index) => {
+                let collection = self.evaluate(&index.collection, scope)?;
+                let index = self.evaluate(&index.index, scope)?.to_index()?;
+                match collection {
+                    Value::Array(mut elements, _) if index < elements.len() => {
+                        Ok(elements.swap_remove(index))
+                    }
+                    _ => Err(Interrupt::Unsupported),
+                }
+            }
+            Expression::Cast(cast) => {
+                let lhs = self.evaluate(&cast.lhs, scope)?;
+                evaluate_cast(lhs, &cast.r#type, cast.checked)
+            }
+            Expression::For(for_expr) => {
+                let start = self.evaluate(&for_expr.start_range, scope)?.to_index()?;
+                let end = self.evaluate(&for_expr.end_range, scope)?.to_index()?;
+                for index in start..end {
+                    let index = match &for_expr.index_type {
+                        Type::Field => Value::Field(index.into()),
+                        Type::Integer(Signedness::Unsigned, bit_size) => {
+                            Value::Unsigned(index as u128, *bit_size)
+                        }
+                        _ => return Err(Interrupt::Unsupported),
+                    };
+                    scope.insert(for_expr.index_variable, index);
+                    match self.evaluate(&for_expr.block, scope) {
+                        Ok(_) | Err(Interrupt::Continue) => (),
+                        Err(Interrupt::Break) => break,
+                        Err(interrupt) => return Err(interrupt),
+                    }
+                }
+                Ok(Value::Unit)
+            }
+            Expression::While(while_expr) => {
+                loop {
+                    match self.evaluate(&while_expr.condition, scope)? {
+                        Value::Bool(true) => (),
+                        Value::Bool(false) => break,
+                        _ => return Err(Interrupt::Unsupported),
+                    }
+                    match self.evaluate(&while_expr.block, scope) {
+                        Ok(_) | Err(Interrupt::Continue) => (),
+                        Err(Interrupt::Break) => break,
+                        Err(interrupt) => return Err(interrupt),
+                    }
+                }
+                Ok(Value::Unit)
+            }
+            Expression::If(if_expr) => match self.evaluate(&if_expr.condition, scope)? {
+                Value::Bool(true) => self.evaluate(&if_expr.consequence, scope),
+                Value::Bool(false) => match &if_expr.alternative {
+                    Some(alternative) => self.evaluate(alternative, scope),
+                    None => Ok(Value::Unit),
+                },
+                _ => Err(Interrupt::Unsupported),
+            },
+            Expression::Tuple(fields) => {
+                let fields = fields.iter().map(|field| self.evaluate(field, scope));
+                Ok(Value::Tuple(fields.collect::<EvaluationResult<_>>()?))
+            }
+            Expression::ExtractTupleField(tuple, index) => match self.evaluate(tuple, scope)? {
+                Value::Tuple(mut fields) if *index < fields.len() => Ok(fields.swap_remove(*index)),
+                _ => Err(Interrupt::Unsupported),
+            },
+            Expression::Call(call) => {
+                let Expression::Ident(Ident { definition: Definition::Function(id), .. }) =
+                    call.func.as_ref()
+                else {
+                    return Err(Interrupt::Unsupported);
+                };
+                let arguments =
+                    call.arguments.iter().map(|argument| self.evaluate(argument, scope));
+                let arguments = arguments.collect::<EvaluationResult<_>>()?;
+                self.call(*id, arguments)
+            }
+            Expression::Let(let_statement) => {
+                let value = self.evaluate(&let_statement.expression, scope)?;
+                scope.insert(let_statement.id, value);
+                Ok(Value::Unit)
+            }
+            Expression::Assign(assign) => {
+                let value = self.evaluate(&assign.expression, scope)?;
+                self.assign(&assign.lvalue, value, scope)?;
+                Ok(Value::Unit)
+            }
+            Expression::Semi(expression) => {
+                self.evaluate(expression, scope)?;
+                Ok(Value::Unit)
+            }
+            Expression::Break => Err(Interrupt::Break),
+            Expression::Continue => Err(Interrupt::Continue),
+            Expression::Constrain(..) => Err(Interrupt::Unsupported),
+        }
+    }
+
+    fn evaluate_literal(&mut self, literal: &Literal, scope: &mut Scope) -> EvaluationResult {
+        match literal {
+            Literal::Integer(value, Type::Field, ..) => Ok(Value::Field(*value)),
+            Literal::Integer(value, Type::Integer(Signedness::Unsigned, bit_size), ..) => {
+                let value = value.try_to_u64().ok_or(Interrupt::Unsupported)?;
+                fit_to_bit_size(value.into(), *bit_size)
+            }
+            Literal::Bool(value) => Ok(Value::Bool(*value)),
+            Literal::Unit => Ok(Value::Unit),
+            Literal::Array(array) => {
+                let elements = array.contents.iter().map(|element| self.evaluate(element, scope));
+                Ok(Value::Array(elements.collect::<EvaluationResult<_>>()?, array.typ.clone()))
+            }
+            _ => Err(Interrupt::Unsupported),
+        }
+    }
+
+    fn assign(&mut self, lvalue: &LValue, value: Value, scope: &mut Scope) -> EvaluationResult<()> {
+        match lvalue {
+            LValue::Ident(Ident { definition: Definition::Local(id), .. }) => {
+                scope.insert(*id, value);
+                Ok(())
+            }
+            LValue::Index { array, index, .. } => {
+                let index = self.evaluate(index, scope)?.to_index()?;
+                match self.evaluate_lvalue(array, scope)? {
+                    Value::Array(mut elements, typ) if index < elements.len() => {
+                        elements[index] = value;
+                        self.assign(array, Value::Array(elements, typ), scope)
+                    }
+                    _ => Err(Interrupt::Unsupported),
+                }
+            }
+            LValue::MemberAccess { object, field_index } => {
+                match self.evaluate_lvalue(object, scope)? {
+                    Value::Tuple(mut fields) if *field_index < fields.len() => {
+                        fields[*field_index] = value;
+                        self.assign(object, Value::Tuple(fields), scope)
+                    }
+                    _ => Err(Interrupt::Unsupported),
+                }
+            }
+            LValue::Ident(_) | LValue::Dereference { .. } => Err(Interrupt::Unsupported),
+        }
+    }
+
+    fn evaluate_lvalue(&mut self, lvalue: &LValue, scope: &mut Scope) -> EvaluationResult {
+        match lvalue {
+            LValue::Ident(Ident { definition: Definition::Local(id), .. }) => {
+                scope.get(id).cloned().ok_or(Interrupt::Unsupported)
+            }
+            LValue::Index { array, index, .. } => {
+                let index = self.evaluate(index, scope)?.to_index()?;
+                match self.evaluate_lvalue(array, scope)? {
+                    Value::Array(mut elements, _) if index < elements.len() => {
+                        Ok(elements.swap_remove(index))
+                    }
+                    _ => Err(Interrupt::Unsupported),
+                }
+            }
+            LValue::MemberAccess { object, field_index } => {
+                match self.evaluate_lvalue(object, scope)? {
+                    Value::Tuple(mut fields) if *field_index < fields.len() => {
+                        Ok(fields.swap_remove(*field_index))
+                    }
+                    _ => Err(Interrupt::Unsupported),
+                }
+            }
+            LValue::Ident(_) | LValue::Dereference { .. } => Err(Interrupt::Unsupported),
+        }
+    }
+}
+
+/// Returns the unsigned integer `value`, or fails if it does not fit into `bit_size` bits.
+fn fit_to_bit_size(value: u128, bit_size: IntegerBitSize) -> EvaluationResult {
+    if value <= max_value(bit_size) {
+        Ok(Value::Unsigned(value, bit_size))
+    } else {
+        Err(Interrupt::Unsupported)
+    }
+}
+
+/// The largest unsigned integer with `bit_size` bits.
+fn max_value(bit_size: IntegerBitSize) -> u128 {
+    u128::MAX >> (128 - u32::from(bit_size))
+}
+
+fn evaluate_unary(operator: UnaryOp, rhs: Value) -> EvaluationResult {
+    match (operator, rhs) {
+        (UnaryOp::Minus, Value::Field(value)) => Ok(Value::Field(-value)),
+        (UnaryOp::Not, Value::Bool(value)) => Ok(Value::Bool(!value)),
+        (UnaryOp::Not, Value::Unsigned(value, bit_size)) => {
+            Ok(Value::Unsigned(max_value(bit_size) ^ value, bit_size))
+        }
+        _ => Err(Interrupt::Unsupported),
+    }
+}
+
+/// Evaluates a binary operation, failing on any overflow as it is either an error at runtime
+/// or wraps in a function without overflow checks.
+fn evaluate_binary(operator: BinaryOpKind, lhs: Value, rhs: Value) -> EvaluationResult {
+    use BinaryOpKind::*;
+    match (lhs, rhs) {
+        (Value::Field(lhs), Value::Field(rhs)) => match operator {
+            Add => Ok(Value::Field(lhs + rhs)),
+            Subtract => Ok(Value::Field(lhs - rhs)),
+            Multiply => Ok(Value::Field(lhs * rhs)),
+            Divide if !rhs.is_zero() => Ok(Value::Field(lhs / rhs)),
+            Equal => Ok(Value::Bool(lhs == rhs)),
+            NotEqual => Ok(Value::Bool(lhs != rhs)),
+            _ => Err(Interrupt::Unsupported),
+        },
+        (Value::Unsigned(lhs, bit_size), Value::Unsigned(rhs, _)) => {
+            let bits = u32::from(bit_size) as u128;
+            let result = match operator {
+                Add => lhs.checked_add(rhs).ok_or(Interrupt::Unsupported)?,
+                Subtract => lhs.checked_sub(rhs).ok_or(Interrupt::Unsupported)?,
+                Multiply => lhs.checked_mul(rhs).ok_or(Interrupt::Unsupported)?,
+                Divide => lhs.checked_div(rhs).ok_or(Interrupt::Unsupported)?,
+                Modulo => lhs.checked_rem(rhs).ok_or(Interrupt::Unsupported)?,
+                And => lhs & rhs,
+                Or => lhs | rhs,
+                Xor => lhs ^ rhs,
+                ShiftRight if rhs < bits => lhs >> rhs,
+                ShiftLeft if rhs < bits => lhs << rhs,
+                Equal => return Ok(Value::Bool(lhs == rhs)),
+                NotEqual => return Ok(Value::Bool(lhs != rhs)),
+                Less => return Ok(Value::Bool(lhs < rhs)),
+                LessEqual => return Ok(Value::Bool(lhs <= rhs)),
+                Greater => return Ok(Value::Bool(lhs > rhs)),
+                GreaterEqual => return Ok(Value::Bool(lhs >= rhs)),
+                ShiftRight | ShiftLeft => return Err(Interrupt::Unsupported),
+            };
+            fit_to_bit_size(result, bit_size)
+        }
+        (Value::Bool(lhs), Value::Bool(rhs)) => match operator {
+            And => Ok(Value::Bool(lhs & rhs)),
+            Or => Ok(Value::Bool(lhs | rhs)),
+            Xor | NotEqual => Ok(Value::Bool(lhs != rhs)),
+            Equal => Ok(Value::Bool(lhs == rhs)),
+            _ => Err(Interrupt::Unsupported),
+        },
+        _ => Err(Interrupt::Unsupported),
+    }
+}
+
+fn evaluate_cast(value: Value, typ: &Type, checked: bool) -> EvaluationResult {
+    let field = match value {
+        Value::Field(value) => value,
+        Value::Unsigned(value, _) => value.into(),
+        Value::Bool(value) => value.into(),
+        _ => return Err(Interrupt::Unsupported),
+    };
+    match typ {
+        Type::Field => Ok(Value::Field(field)),
+        Type::Integer(Signedness::Unsigned, bit_size) => {
+            let bytes = field.to_be_bytes();
+            let mut low_bytes = [0u8; 16];
+            low_bytes.copy_from_slice(&bytes[bytes.len() - 16..]);
+            let truncated = u128::from_be_bytes(low_bytes) & max_value(*bit_size);
+            if checked && FieldElement::from(truncated) != field {
+                return Err(Interrupt::Unsupported);
+            }
+            Ok(Value::Unsigned(truncated, *bit_size))
+        }
+        Type::Bool if field.is_zero() || field.is_one() => Ok(Value::Bool(field.is_one())),
+        _ => Err(Interrupt::Unsupported),
+    }
+}
+
+/// Returns true if evaluating `expression` can have no effect other than producing a value.
+fn has_no_side_effects(expression: &Expression) -> bool {
+    let pure = match expression {
+        Expression::Ident(ident) => {
+            matches!(ident.definition, Definition::Local(_) | Definition::Function(_))
+        }
+        Expression::Unary(unary) => {
+            !matches!(unary.operator, UnaryOp::MutableReference | UnaryOp::Dereference { .. })
+        }
+        Expression::Assign(assign) => !lvalue_has_dereference(&assign.lvalue),
+        Expression::Constrain(..) => false,
+        _ => true,
+    };
+    let mut children_are_pure = true;
+    for_each_child(expression, &mut |child| children_are_pure &= has_no_side_effects(child));
+    pure && children_are_pure
+}
+
+fn lvalue_has_dereference(lvalue: &LValue) -> bool {
+    match lvalue {
+        LValue::Ident(_) => false,
+        LValue::Index { array, .. } => lvalue_has_dereference(array),
+        LValue::MemberAccess { object, .. } => lvalue_has_dereference(object),
+        LValue::Dereference { .. } => true,
+    }
+}
+
+fn for_each_child(expression: &Expression, f: &mut impl FnMut(&Expression)) {
+    match expression {
+        Expression::Ident(_) | Expression::Break | Expression::Continue => (),
+        Expression::Literal(literal) => match literal {
+            Literal::Array(array) | Literal::Slice(array) => array.contents.iter().for_each(f),
+            Literal::FmtStr(_, _, captures) => f(captures),
+            Literal::Integer(..) | Literal::Bool(_) | Literal::Unit | Literal::Str(_) => (),
+        },
+        Expression::Block(expressions) | Expression::Tuple(expressions) => {
+            expressions.iter().for_each(f);
+        }
+        Expression::Unary(unary) => f(&unary.rhs),
+        Expression::Binary(binary) => {
+            f(&binary.lhs);
+            f(&binary.rhs);
+        }
+        Expression::Index(index) => {
+            f(&index.collection);
+            f(&index.index);
+        }
+        Expression::Cast(cast) => f(&cast.lhs),
+        Expression::For(for_expr) => {
+            f(&for_expr.start_range);
+            f(&for_expr.end_range);
+            f(&for_expr.block);
+        }
+        Expression::While(while_expr) => {
+            f(&while_expr.condition);
+            f(&while_expr.block);
+        }
+        Expression::If(if_expr) => {
+            f(&if_expr.condition);
+            f(&if_expr.consequence);
+            if let Some(alternative) = &if_expr.alternative {
+                f(alternative);
+            }
+        }
+        Expression::ExtractTupleField(tuple, _) => f(tuple),
+        Expression::Call(call) => {
+            f(&call.func);
+            call.arguments.iter().for_each(f);
+        }
+        Expression::Let(let_statement) => f(&let_statement.expression),
+        Expression::Constrain(condition, _, message) => {
+            f(condition);
+            if let Some(message) = message {
+                f(&message.0);
+            }
+        }
+        Expression::Assign(assign) => {
+            for_each_lvalue_index(&assign.lvalue, f);
+            f(&assign.expression);
+        }
+        Expression::Semi(expression) => f(expression),
+    }
+}
+
+fn for_each_lvalue_index(lvalue: &LValue, f: &mut impl FnMut(&Expression)) {
+    match lvalue {
+        LValue::Ident(_) => (),
+        LValue::Index { array, index, .. } => {
+            for_each_lvalue_index(array, f);
+            f(index);
+        }
+        LValue::MemberAccess { object, .. } => for_each_lvalue_index(object, f),
+        LValue::Dereference { reference, .. } => for_each_lvalue_index(reference, f),
+    }
+}
+
+fn for_each_child_mut(expression: &mut Expression, f: &mut impl FnMut(&mut Expression)) {
+    match expression {
+        Expression::Ident(_) | Expression::Break | Expression::Continue => (),
+        Expression::Literal(literal) => match literal {
+            Literal::Array(array) | Literal::Slice(array) => array.contents.iter_mut().for_each(f),
+            Literal::FmtStr(_, _, captures) => f(captures),
+            Literal::Integer(..) | Literal::Bool(_) | Literal::Unit | Literal::Str(_) => (),
+        },
+        Expression::Block(expressions) | Expression::Tuple(expressions) => {
+            expressions.iter_mut().for_each(f);
+        }
+        Expression::Unary(unary) => f(&mut unary.rhs),
+        Expression::Binary(binary) => {
+            f(&mut binary.lhs);
+            f(&mut binary.rhs);
+        }
+        Expression::Index(index) => {
+            f(&mut index.collection);
+            f(&mut index.index);
+        }
+        Expression::Cast(cast) => f(&mut cast.lhs),
+        Expression::For(for_expr) => {
+            f(&mut for_expr.start_range);
+            f(&mut for_expr.end_range);
+            f(&mut for_expr.block);
+        }
+        Expression::While(while_expr) => {
+            f(&mut while_expr.condition);
+            f(&mut while_expr.block);
+        }
+        Expression::If(if_expr) => {
+            f(&mut if_expr.condition);
+            f(&mut if_expr.consequence);
+            if let Some(alternative) = &mut if_expr.alternative {
+                f(alternative);
+            }
+        }
+        Expression::ExtractTupleField(tuple, _) => f(tuple),
+        Expression::Call(call) => {
+            f(&mut call.func);
+            call.arguments.iter_mut().for_each(f);
+        }
+        Expression::Let(let_statement) => f(&mut let_statement.expression),
+        Expression::Constrain(condition, _, message) => {
+            f(condition);
+            if let Some(message) = message {
+                f(&mut message.0);
+            }
+        }
+        Expression::Assign(assign) => {
+            for_each_lvalue_index_mut(&mut assign.lvalue, f);
+            f(&mut assign.expression);
+        }
+        Expression::Semi(expression) => f(expression),
+    }
+}
+
+fn for_each_lvalue_index_mut(lvalue: &mut LValue, f: &mut impl FnMut(&mut Expression)) {
+    match lvalue {
+        LValue::Ident(_) => (),
+        LValue::Index { array, index, .. } => {
+            for_each_lvalue_index_mut(array, f);
+            f(index);
+        }
+        LValue::MemberAccess { object, .. } => for_each_lvalue_index_mut(object, f),
+        LValue::Dereference { reference, .. } => for_each_lvalue_index_mut(reference, f),
+    }
+}
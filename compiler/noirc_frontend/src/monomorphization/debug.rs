@@ -6,6 +6,7 @@ use noirc_printable_type::PrintableType;
 use crate::debug::{SourceFieldId, SourceVarId};
 use crate::hir_def::expr::*;
 use crate::node_interner::ExprId;
+use crate::token::LiteralRepr;
 
 use super::ast::{Expression, Ident};
 use super::{MonomorphizationError, Monomorphizer};
@@ -38,10 +39,10 @@ impl<'interner> Monomorphizer<'interner> {
     pub(super) fn patch_debug_instrumentation_call(
         &mut self,
         call: &HirCallExpression,
+        original_func: &Expression,
         arguments: &mut [Expression],
     ) -> Result<(), MonomorphizationError> {
-        let original_func = Box::new(self.expr(call.func)?);
-        if let Expression::Ident(Ident { name, .. }) = original_func.as_ref() {
+        if let Expression::Ident(Ident { name, .. }) = original_func {
             if name == "__debug_var_assign" {
                 self.patch_debug_var_assign(call, arguments)?;
             } else if name == "__debug_var_drop" {
@@ -67,7 +68,8 @@ impl<'interner> Monomorphizer<'interner> {
     ) -> Result<(), MonomorphizationError> {
         let hir_arguments = vecmap(&call.arguments, |id| self.interner.expression(id));
         let var_id_arg = hir_arguments.get(DEBUG_VAR_ID_ARG_SLOT);
-        let Some(HirExpression::Literal(HirLiteral::Integer(source_var_id, _))) = var_id_arg else {
+        let Some(HirExpression::Literal(HirLiteral::Integer(source_var_id, ..))) = var_id_arg
+        else {
             unreachable!("Missing source_var_id in __debug_var_assign call");
         };
 
@@ -92,7 +94,8 @@ impl<'interner> Monomorphizer<'interner> {
     ) -> Result<(), MonomorphizationError> {
         let hir_arguments = vecmap(&call.arguments, |id| self.interner.expression(id));
         let var_id_arg = hir_arguments.get(DEBUG_VAR_ID_ARG_SLOT);
-        let Some(HirExpression::Literal(HirLiteral::Integer(source_var_id, _))) = var_id_arg else {
+        let Some(HirExpression::Literal(HirLiteral::Integer(source_var_id, ..))) = var_id_arg
+        else {
             unreachable!("Missing source_var_id in __debug_var_drop call");
         };
         // update variable ID for tracked drops (ie. when the var goes out of scope)
@@ -120,7 +123,8 @@ impl<'interner> Monomorphizer<'interner> {
     ) -> Result<(), MonomorphizationError> {
         let hir_arguments = vecmap(&call.arguments, |id| self.interner.expression(id));
         let var_id_arg = hir_arguments.get(DEBUG_VAR_ID_ARG_SLOT);
-        let Some(HirExpression::Literal(HirLiteral::Integer(source_var_id, _))) = var_id_arg else {
+        let Some(HirExpression::Literal(HirLiteral::Integer(source_var_id, ..))) = var_id_arg
+        else {
             unreachable!("Missing source_var_id in __debug_member_assign call");
         };
         // update variable member assignments
@@ -133,7 +137,7 @@ impl<'interner> Monomorphizer<'interner> {
             .clone();
         let mut cursor_type = &var_type;
         for i in 0..arity {
-            if let Some(HirExpression::Literal(HirLiteral::Integer(fe_i, i_neg))) =
+            if let Some(HirExpression::Literal(HirLiteral::Integer(fe_i, i_neg, _))) =
                 hir_arguments.get(DEBUG_MEMBER_FIELD_INDEX_ARG_SLOT + i)
             {
                 let index = fe_i.to_i128().unsigned_abs();
@@ -152,7 +156,7 @@ impl<'interner> Monomorphizer<'interner> {
 
                     cursor_type = element_type_at_index(cursor_type, field_index);
                     let index_id = self.interner.push_expr(HirExpression::Literal(
-                        HirLiteral::Integer(field_index.into(), false),
+                        HirLiteral::Integer(field_index.into(), false, LiteralRepr::Decimal),
                     ));
                     self.interner.push_expr_type(index_id, crate::Type::FieldElement);
                     self.interner.push_expr_location(
@@ -181,7 +185,8 @@ impl<'interner> Monomorphizer<'interner> {
     }
 
     fn intern_var_id(&mut self, var_id: DebugVarId, location: &Location) -> ExprId {
-        let var_id_literal = HirLiteral::Integer((var_id.0 as u128).into(), false);
+        let var_id_literal =
+            HirLiteral::Integer((var_id.0 as u128).into(), false, LiteralRepr::Decimal);
         let expr_id = self.interner.push_expr(HirExpression::Literal(var_id_literal));
         self.interner.push_expr_type(expr_id, crate::Type::FieldElement);
         self.interner.push_expr_location(expr_id, location.span, location.file);
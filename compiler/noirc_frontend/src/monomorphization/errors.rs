@@ -1,7 +1,10 @@
+use iter_extended::vecmap;
 use thiserror::Error;
 
 use noirc_errors::{CustomDiagnostic, FileDiagnostic, Location};
 
+use crate::node_interner::FuncId;
+
 #[derive(Debug, Error)]
 pub enum MonomorphizationError {
     #[error("Length of generic array could not be determined.")]
@@ -9,13 +12,94 @@ pub enum MonomorphizationError {
 
     #[error("Type annotations needed")]
     TypeAnnotationsNeeded { location: Location },
+
+    #[error("Cannot use `assert_cast` to convert {from} into {to}, only unsigned integers and Field are supported")]
+    InvalidCheckedCast { from: String, to: String, location: Location },
+
+    #[error("`assert_cast` can only be called directly")]
+    UncalledCheckedCast { location: Location },
+
+    /// A local variable was referenced before the monomorphizer defined it, which is a compiler bug.
+    #[error("Internal Compiler Error: local variable `{name}` is used before it is defined")]
+    UndefinedLocal { name: String, location: Location },
+
+    /// An error which occurred while monomorphizing an instantiation of a generic function.
+    /// `instantiations` is ordered from the outermost instantiation to the innermost one.
+    #[error("{error}")]
+    InInstantiation { error: Box<MonomorphizationError>, instantiations: Vec<Instantiation> },
+
+    /// A generic function instantiates itself, directly or through other generic functions, with
+    /// different generics on every recursive call so that monomorphizing it would never finish.
+    /// `cycle` names each generic function along the recursion, starting and ending with the same one.
+    #[error("Recursive calls to `{}` instantiate it with a different type every time", cycle[0])]
+    RecursiveInstantiation {
+        cycle: Vec<String>,
+        /// The bindings of the instantiation which was rejected.
+        bindings: String,
+        /// The recursive call.
+        location: Location,
+        /// The call which first instantiated the function.
+        first_location: Location,
+    },
+}
+
+/// A generic function being instantiated with a particular set of generics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instantiation {
+    pub function: FuncId,
+    pub function_name: String,
+    /// The bindings of the function's generics, rendered as e.g. `T = [Field; 3], N = 3`.
+    pub bindings: String,
+    /// The location of the call which required this instantiation.
+    pub location: Location,
 }
 
 impl MonomorphizationError {
     fn location(&self) -> Location {
         match self {
             MonomorphizationError::UnknownArrayLength { location }
-            | MonomorphizationError::TypeAnnotationsNeeded { location } => *location,
+            | MonomorphizationError::TypeAnnotationsNeeded { location }
+            | MonomorphizationError::InvalidCheckedCast { location, .. }
+            | MonomorphizationError::UncalledCheckedCast { location }
+            | MonomorphizationError::UndefinedLocal { location, .. }
+            | MonomorphizationError::RecursiveInstantiation { location, .. } => *location,
+            MonomorphizationError::InInstantiation { error, .. } => error.location(),
+        }
+    }
+
+    /// Attaches the chain of generic instantiations which led to this error.
+    /// The chain is left out of recursive instantiation errors, which describe the cycle instead.
+    pub(super) fn in_instantiations(self, instantiations: Vec<Instantiation>) -> Self {
+        if instantiations.is_empty()
+            || matches!(self, MonomorphizationError::RecursiveInstantiation { .. })
+        {
+            return self;
+        }
+        MonomorphizationError::InInstantiation { error: Box::new(self), instantiations }
+    }
+
+    /// The label shown under the error's location, if any.
+    fn secondary_message(&self) -> String {
+        match self {
+            MonomorphizationError::TypeAnnotationsNeeded { .. } => {
+                "Could not infer the type of this expression, consider adding a type annotation"
+                    .to_string()
+            }
+            MonomorphizationError::UncalledCheckedCast { .. } => {
+                "It is not a function which can be stored or passed as a value".to_string()
+            }
+            MonomorphizationError::InInstantiation { error, .. } => error.secondary_message(),
+            MonomorphizationError::RecursiveInstantiation { cycle, bindings, .. } => {
+                format!("This call instantiates `{}` with {bindings}", cycle[cycle.len() - 1])
+            }
+            _ => String::new(),
+        }
+    }
+
+    fn instantiations(&self) -> &[Instantiation] {
+        match self {
+            MonomorphizationError::InInstantiation { instantiations, .. } => instantiations,
+            _ => &[],
         }
     }
 }
@@ -23,7 +107,12 @@ impl MonomorphizationError {
 impl From<MonomorphizationError> for FileDiagnostic {
     fn from(error: MonomorphizationError) -> FileDiagnostic {
         let location = error.location();
-        let call_stack = vec![location];
+        // Show the calls which required each instantiation before the error itself.
+        let mut call_stack = vecmap(error.instantiations(), |instantiation| instantiation.location);
+        if let MonomorphizationError::RecursiveInstantiation { first_location, .. } = &error {
+            call_stack.push(*first_location);
+        }
+        call_stack.push(location);
         let diagnostic = error.into_diagnostic();
         diagnostic.in_file(location.file).with_call_stack(call_stack)
     }
@@ -32,8 +121,21 @@ impl From<MonomorphizationError> for FileDiagnostic {
 impl MonomorphizationError {
     fn into_diagnostic(self) -> CustomDiagnostic {
         let message = self.to_string();
+        let secondary = self.secondary_message();
         let location = self.location();
 
-        CustomDiagnostic::simple_error(message, String::new(), location.span)
+        let mut diagnostic = CustomDiagnostic::simple_error(message, secondary, location.span);
+        for Instantiation { function_name, bindings, .. } in self.instantiations().iter().rev() {
+            diagnostic.add_note(format!("while instantiating `{function_name}` with {bindings}"));
+        }
+        if let MonomorphizationError::RecursiveInstantiation { cycle, first_location, .. } = &self {
+            if first_location.file == location.file {
+                let message = format!("`{}` is first instantiated here", cycle[0]);
+                diagnostic.add_secondary(message, first_location.span);
+            }
+            let cycle = cycle.join("` -> `");
+            diagnostic.add_note(format!("the recursion `{cycle}` never reaches a fixed type"));
+        }
+        diagnostic
     }
 }
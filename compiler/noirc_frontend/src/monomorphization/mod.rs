@@ -18,7 +18,7 @@ use crate::{
         types,
     },
     node_interner::{self, DefinitionKind, NodeInterner, StmtId, TraitImplKind, TraitMethodId},
-    token::FunctionAttribute,
+    token::{FunctionAttribute, LiteralRepr},
     Type, TypeBinding, TypeBindings, TypeVariable, TypeVariableKind,
 };
 use acvm::FieldElement;
@@ -34,20 +34,32 @@ use self::ast::InlineType;
 use self::debug_types::DebugTypeTracker;
 use self::{
     ast::{Definition, FuncId, Function, LocalId, Program},
-    errors::MonomorphizationError,
+    errors::{Instantiation, MonomorphizationError},
 };
 
 pub mod ast;
+pub mod const_eval;
 mod debug;
 pub mod debug_types;
 pub mod errors;
 pub mod printer;
+pub mod text;
 
 struct LambdaContext {
     env_ident: ast::Ident,
     captures: Vec<HirCapturedVar>,
 }
 
+/// A function waiting to be monomorphized, as a tuple of:
+/// (old_id, new_monomorphized_id, any type bindings to apply, the trait method if old_id is from a trait impl,
+/// the chain of generic instantiations which required this function)
+type QueuedFunction =
+    (node_interner::FuncId, FuncId, TypeBindings, Option<TraitMethodId>, Vec<Instantiation>);
+
+/// How many times a generic function may appear in its own chain of instantiations before we
+/// assume it recursively instantiates itself with ever-growing types and would never finish.
+const MAX_RECURSIVE_INSTANTIATIONS: usize = 16;
+
 /// The context struct for the monomorphization pass.
 ///
 /// This struct holds the FIFO queue of functions to monomorphize, which is added to
@@ -66,11 +78,14 @@ struct Monomorphizer<'interner> {
     /// duplicated during monomorphization. Doing so would allow them to be used polymorphically
     /// but would also cause them to be re-evaluated which is a performance trap that would
     /// confuse users.
-    locals: HashMap<node_interner::DefinitionId, LocalId>,
+    locals: HashMap<node_interner::DefinitionId, (LocalId, ast::DefinitionKind)>,
+
+    /// Queue of functions to monomorphize next.
+    queue: VecDeque<QueuedFunction>,
 
-    /// Queue of functions to monomorphize next each item in the queue is a tuple of:
-    /// (old_id, new_monomorphized_id, any type bindings to apply, the trait method if old_id is from a trait impl)
-    queue: VecDeque<(node_interner::FuncId, FuncId, TypeBindings, Option<TraitMethodId>)>,
+    /// The chain of generic instantiations which required the function currently being monomorphized.
+    /// This is attached to any errors so that users can tell which call site triggered them.
+    instantiations: Vec<Instantiation>,
 
     /// When a function finishes being monomorphized, the monomorphized ast::Function is
     /// stored here along with its FuncId.
@@ -122,12 +137,16 @@ pub fn monomorphize_debug(
     let function_sig = monomorphizer.compile_main(main)?;
 
     while !monomorphizer.queue.is_empty() {
-        let (next_fn_id, new_id, bindings, trait_method) = monomorphizer.queue.pop_front().unwrap();
+        let (next_fn_id, new_id, bindings, trait_method, instantiations) =
+            monomorphizer.queue.pop_front().unwrap();
         monomorphizer.locals.clear();
+        monomorphizer.instantiations = instantiations;
 
         perform_instantiation_bindings(&bindings);
         let impl_bindings = monomorphizer.perform_impl_bindings(trait_method, next_fn_id);
-        monomorphizer.function(next_fn_id, new_id)?;
+        monomorphizer.function(next_fn_id, new_id).map_err(|error| {
+            error.in_instantiations(std::mem::take(&mut monomorphizer.instantiations))
+        })?;
         undo_instantiation_bindings(impl_bindings);
         undo_instantiation_bindings(bindings);
     }
@@ -169,6 +188,7 @@ impl<'interner> Monomorphizer<'interner> {
             functions: HashMap::new(),
             locals: HashMap::new(),
             queue: VecDeque::new(),
+            instantiations: Vec::new(),
             finished_functions: BTreeMap::new(),
             next_local_id: 0,
             next_function_id: 0,
@@ -192,8 +212,11 @@ impl<'interner> Monomorphizer<'interner> {
         ast::FuncId(id)
     }
 
-    fn lookup_local(&mut self, id: node_interner::DefinitionId) -> Option<Definition> {
-        self.locals.get(&id).copied().map(Definition::Local)
+    fn lookup_local(
+        &mut self,
+        id: node_interner::DefinitionId,
+    ) -> Option<(Definition, ast::DefinitionKind)> {
+        self.locals.get(&id).map(|(local_id, kind)| (Definition::Local(*local_id), *kind))
     }
 
     fn lookup_function(
@@ -203,15 +226,15 @@ impl<'interner> Monomorphizer<'interner> {
         typ: &HirType,
         turbofish_generics: Vec<HirType>,
         trait_method: Option<TraitMethodId>,
-    ) -> Definition {
+    ) -> Result<Definition, MonomorphizationError> {
         let typ = typ.follow_bindings();
         match self
             .functions
             .get(&id)
             .and_then(|inner_map| inner_map.get(&(typ.clone(), turbofish_generics.clone())))
         {
-            Some(id) => Definition::Function(*id),
-            None => {
+            Some(id) => Ok(Definition::Function(*id)),
+            None => Ok({
                 // Function has not been monomorphized yet
                 let attributes = self.interner.function_attributes(&id);
                 match self.interner.function_meta(&id).kind {
@@ -230,8 +253,13 @@ impl<'interner> Monomorphizer<'interner> {
                         Definition::Builtin(opcode)
                     }
                     FunctionKind::Normal => {
-                        let id =
-                            self.queue_function(id, expr_id, typ, turbofish_generics, trait_method);
+                        let id = self.queue_function(
+                            id,
+                            expr_id,
+                            typ,
+                            turbofish_generics,
+                            trait_method,
+                        )?;
                         Definition::Function(id)
                     }
                     FunctionKind::Oracle => {
@@ -249,12 +277,17 @@ impl<'interner> Monomorphizer<'interner> {
                         unreachable!("Only main can be specified as recursive, which should already be checked");
                     }
                 }
-            }
+            }),
         }
     }
 
-    fn define_local(&mut self, id: node_interner::DefinitionId, new_id: LocalId) {
-        self.locals.insert(id, new_id);
+    fn define_local(
+        &mut self,
+        id: node_interner::DefinitionId,
+        new_id: LocalId,
+        kind: ast::DefinitionKind,
+    ) {
+        self.locals.insert(id, (new_id, kind));
     }
 
     /// Prerequisite: typ = typ.follow_bindings()
@@ -275,6 +308,7 @@ impl<'interner> Monomorphizer<'interner> {
         let new_main_id = self.next_function_id();
         assert_eq!(new_main_id, Program::main_id());
         self.function(main_id, new_main_id)?;
+        self.constrain_bounded_vec_parameters(main_id, new_main_id)?;
         self.return_location =
             self.interner.function(&main_id).block(self.interner).statements().last().and_then(
                 |x| match self.interner.statement(x) {
@@ -286,6 +320,52 @@ impl<'interner> Monomorphizer<'interner> {
         Ok(main_meta.function_signature())
     }
 
+    /// The length of a `BoundedVec` passed to `main` is chosen by the prover, so it is constrained
+    /// to be at most the maximum length of the `BoundedVec` before the body of `main` is run.
+    fn constrain_bounded_vec_parameters(
+        &mut self,
+        main_id: node_interner::FuncId,
+        new_main_id: FuncId,
+    ) -> Result<(), MonomorphizationError> {
+        let meta = self.interner.function_meta(&main_id).clone();
+        let mut constraints = Vec::new();
+
+        for (pattern, typ, _) in &meta.parameters.0 {
+            let mut pattern = pattern;
+            while let HirPattern::Mutable(inner, _) = pattern {
+                pattern = inner.as_ref();
+            }
+            let HirPattern::Identifier(ident) = pattern else { continue };
+            let Some((len_index, len_type, max_len)) = bounded_vec_length(typ) else { continue };
+            let Some(vec) = self.local_ident(ident)? else { continue };
+
+            let location = ident.location;
+            let len = ast::Expression::ExtractTupleField(
+                Box::new(ast::Expression::Ident(vec)),
+                len_index,
+            );
+            let len_type = Self::convert_type(&len_type, location)?;
+            let max_len = FieldElement::from(max_len as u128);
+            let max_len = ast::Literal::Integer(max_len, len_type, location, LiteralRepr::Decimal);
+            let in_bounds = ast::Expression::Binary(ast::Binary {
+                lhs: Box::new(len),
+                operator: crate::ast::BinaryOpKind::LessEqual,
+                rhs: Box::new(ast::Expression::Literal(max_len)),
+                location,
+            });
+            constraints.push(ast::Expression::Constrain(Box::new(in_bounds), location, None));
+        }
+
+        if !constraints.is_empty() {
+            let main =
+                self.finished_functions.get_mut(&new_main_id).expect("main was just monomorphized");
+            let body = std::mem::replace(&mut main.body, ast::Expression::Block(Vec::new()));
+            constraints.push(body);
+            main.body = ast::Expression::Block(constraints);
+        }
+        Ok(())
+    }
+
     fn function(
         &mut self,
         f: node_interner::FuncId,
@@ -306,7 +386,7 @@ impl<'interner> Monomorphizer<'interner> {
         func_sig.1 = func_sig.1.map(|return_type| return_type.follow_bindings());
 
         let modifiers = self.interner.function_modifiers(&f);
-        let name = self.interner.function_name(&f).to_owned();
+        let name = self.instantiated_name(f, &meta);
 
         let body_expr_id = self.interner.function(&f).as_expr();
         let body_return_type = self.interner.id_type(body_expr_id);
@@ -332,12 +412,30 @@ impl<'interner> Monomorphizer<'interner> {
             unconstrained,
             inline_type,
             func_sig,
+            overflow_checks: meta.overflow_checks,
         };
 
         self.push_function(id, function);
         Ok(())
     }
 
+    /// The name of `f` followed by the types its generics are currently bound to,
+    /// e.g. `foo::<Field, 3>`, so that each instantiation of a generic function can be told apart.
+    fn instantiated_name(&self, f: node_interner::FuncId, meta: &FuncMeta) -> String {
+        let name = self.interner.function_name(&f);
+        if meta.direct_generics.is_empty() {
+            return name.to_owned();
+        }
+
+        let generics = vecmap(&meta.direct_generics, |(generic, type_variable)| {
+            match &*type_variable.borrow() {
+                TypeBinding::Bound(binding) => binding.follow_bindings().to_string(),
+                TypeBinding::Unbound(_) => generic.to_string(),
+            }
+        });
+        format!("{name}::<{}>", generics.join(", "))
+    }
+
     fn push_function(&mut self, id: FuncId, function: ast::Function) {
         let existing = self.finished_functions.insert(id, function);
         assert!(existing.is_none());
@@ -369,18 +467,18 @@ impl<'interner> Monomorphizer<'interner> {
                 let name = definition.name.clone();
                 let typ = Self::convert_type(typ, ident.location)?;
                 new_params.push((new_id, definition.mutable, name, typ));
-                self.define_local(ident.id, new_id);
+                self.define_local(ident.id, new_id, ast::DefinitionKind::Parameter);
             }
             HirPattern::Mutable(pattern, _) => self.parameter(pattern, typ, new_params)?,
-            HirPattern::Tuple(fields, _) => {
-                let tuple_field_types = unwrap_tuple_type(typ);
+            HirPattern::Tuple(fields, location) => {
+                let tuple_field_types = unwrap_tuple_type(typ, *location)?;
 
                 for (field, typ) in fields.iter().zip(tuple_field_types) {
                     self.parameter(field, &typ, new_params)?;
                 }
             }
-            HirPattern::Struct(_, fields, _) => {
-                let struct_field_types = unwrap_struct_type(typ);
+            HirPattern::Struct(_, fields, location) => {
+                let struct_field_types = unwrap_struct_type(typ, *location)?;
                 assert_eq!(struct_field_types.len(), fields.len());
 
                 let mut fields =
@@ -408,7 +506,22 @@ impl<'interner> Monomorphizer<'interner> {
         use ast::Literal::*;
 
         let expr = match self.interner.expression(&expr) {
-            HirExpression::Ident(ident, generics) => self.ident(ident, expr, generics)?,
+            HirExpression::Ident(ident, generics) => {
+                let location = ident.location;
+                let ident = self.ident(ident, expr, generics)?;
+                // Calls to `assert_cast` are lowered by `function_call`, which is the only place
+                // its identifier is allowed.
+                if let ast::Expression::Ident(ast::Ident {
+                    definition: Definition::Builtin(name),
+                    ..
+                }) = &ident
+                {
+                    if name.as_str() == "assert_cast" {
+                        return Err(MonomorphizationError::UncalledCheckedCast { location });
+                    }
+                }
+                ident
+            }
             HirExpression::Literal(HirLiteral::Str(contents)) => Literal(Str(contents)),
             HirExpression::Literal(HirLiteral::FmtStr(contents, idents)) => {
                 let fields = try_vecmap(idents, |ident| self.expr(ident))?;
@@ -419,22 +532,25 @@ impl<'interner> Monomorphizer<'interner> {
                 ))
             }
             HirExpression::Literal(HirLiteral::Bool(value)) => Literal(Bool(value)),
-            HirExpression::Literal(HirLiteral::Integer(value, sign)) => {
+            HirExpression::Literal(HirLiteral::Integer(value, sign, repr)) => {
                 let location = self.interner.id_location(expr);
                 let typ = Self::convert_type(&self.interner.id_type(expr), location)?;
 
+                // Negative literals are converted to a different value so they can't keep their original form.
                 if sign {
+                    let decimal = LiteralRepr::Decimal;
                     match typ {
-                        ast::Type::Field => Literal(Integer(-value, typ, location)),
+                        ast::Type::Field => Literal(Integer(-value, typ, location, decimal)),
                         ast::Type::Integer(_, bit_size) => {
                             let bit_size: u32 = bit_size.into();
-                            let base = 1_u128 << bit_size;
-                            Literal(Integer(FieldElement::from(base) - value, typ, location))
+                            let base = FieldElement::from(2_u128)
+                                .pow(&FieldElement::from(bit_size as u128));
+                            Literal(Integer(base - value, typ, location, decimal))
                         }
                         _ => unreachable!("Integer literal must be numeric"),
                     }
                 } else {
-                    Literal(Integer(value, typ, location))
+                    Literal(Integer(value, typ, location, repr))
                 }
             }
             HirExpression::Literal(HirLiteral::Array(array)) => match array {
@@ -507,9 +623,11 @@ impl<'interner> Monomorphizer<'interner> {
 
             HirExpression::Cast(cast) => {
                 let location = self.interner.expr_location(&expr);
+                let from = Self::convert_type(&self.interner.id_type(cast.lhs), location)?;
                 let typ = Self::convert_type(&cast.r#type, location)?;
                 let lhs = Box::new(self.expr(cast.lhs)?);
-                ast::Expression::Cast(ast::Cast { lhs, r#type: typ, location })
+                let checked = false;
+                ast::Expression::Cast(ast::Cast { lhs, from, r#type: typ, checked, location })
             }
 
             HirExpression::If(if_expr) => {
@@ -531,6 +649,7 @@ impl<'interner> Monomorphizer<'interner> {
 
             HirExpression::Lambda(lambda) => self.lambda(lambda, expr)?,
 
+            // Method calls are replaced by calls to the resolved function during type checking
             HirExpression::MethodCall(hir_method_call) => {
                 unreachable!("Encountered HirExpression::MethodCall during monomorphization {hir_method_call:?}")
             }
@@ -578,11 +697,53 @@ impl<'interner> Monomorphizer<'interner> {
             MonomorphizationError::UnknownArrayLength { location }
         })?;
 
-        let contents = try_vecmap(0..length, |_| self.expr(repeated_element))?;
-        if is_slice {
-            Ok(ast::Expression::Literal(ast::Literal::Slice(ast::ArrayLiteral { contents, typ })))
+        let element = self.expr(repeated_element)?;
+
+        // The element is evaluated once and then copied into each position of the array.
+        // Anything other than a plain variable or literal is bound to a local first so that
+        // any side effects it has (e.g. a function call) only happen once.
+        let mut block_expressions = Vec::new();
+        let element = if matches!(
+            element,
+            ast::Expression::Ident(_)
+                | ast::Expression::Literal(ast::Literal::Integer(..) | ast::Literal::Bool(_))
+        ) {
+            element
         } else {
-            Ok(ast::Expression::Literal(ast::Literal::Array(ast::ArrayLiteral { contents, typ })))
+            let local_id = self.next_local_id();
+            let name = "$repeated".to_string();
+            let element_location = self.interner.expr_location(&repeated_element);
+            let element_type = self.interner.id_type(repeated_element);
+
+            block_expressions.push(ast::Expression::Let(ast::Let {
+                id: local_id,
+                mutable: false,
+                name: name.clone(),
+                expression: Box::new(element),
+            }));
+
+            ast::Expression::Ident(ast::Ident {
+                location: Some(element_location),
+                definition: Definition::Local(local_id),
+                kind: ast::DefinitionKind::Local,
+                mutable: false,
+                name,
+                typ: Self::convert_type(&element_type, element_location)?,
+            })
+        };
+
+        let contents = vecmap(0..length, |_| element.clone());
+        let array = if is_slice {
+            ast::Expression::Literal(ast::Literal::Slice(ast::ArrayLiteral { contents, typ }))
+        } else {
+            ast::Expression::Literal(ast::Literal::Array(ast::ArrayLiteral { contents, typ }))
+        };
+
+        if block_expressions.is_empty() {
+            Ok(array)
+        } else {
+            block_expressions.push(array);
+            Ok(ast::Expression::Block(block_expressions))
         }
     }
 
@@ -617,14 +778,20 @@ impl<'interner> Monomorphizer<'interner> {
                     .map(Box::new);
                 Ok(ast::Expression::Constrain(Box::new(expr), location, assert_message))
             }
-            HirStatement::Assign(assign) => self.assign(assign),
+            HirStatement::Assign(assign) => {
+                self.assign(assign, self.interner.statement_location(id))
+            }
             HirStatement::For(for_loop) => {
                 self.is_range_loop = true;
                 let start = self.expr(for_loop.start_range)?;
                 let end = self.expr(for_loop.end_range)?;
                 self.is_range_loop = false;
                 let index_variable = self.next_local_id();
-                self.define_local(for_loop.identifier.id, index_variable);
+                self.define_local(
+                    for_loop.identifier.id,
+                    index_variable,
+                    ast::DefinitionKind::Local,
+                );
 
                 let block = Box::new(self.expr(for_loop.block)?);
                 let index_location = for_loop.identifier.location;
@@ -642,6 +809,11 @@ impl<'interner> Monomorphizer<'interner> {
                     block,
                 }))
             }
+            HirStatement::While(while_loop) => {
+                let condition = Box::new(self.expr(while_loop.condition)?);
+                let block = Box::new(self.expr(while_loop.block)?);
+                Ok(ast::Expression::While(ast::While { condition, block }))
+            }
             HirStatement::Expression(expr) => self.expr(expr),
             HirStatement::Semi(expr) => {
                 self.expr(expr).map(|expr| ast::Expression::Semi(Box::new(expr)))
@@ -670,7 +842,7 @@ impl<'interner> Monomorphizer<'interner> {
         id: node_interner::ExprId,
     ) -> Result<ast::Expression, MonomorphizationError> {
         let typ = self.interner.id_type(id);
-        let field_types = unwrap_struct_type(&typ);
+        let field_types = unwrap_struct_type(&typ, self.interner.expr_location(&id))?;
 
         let field_type_map = btree_map(&field_types, |x| x.clone());
 
@@ -705,8 +877,10 @@ impl<'interner> Monomorphizer<'interner> {
             });
 
             let definition = Definition::Local(id);
+            let kind = ast::DefinitionKind::Local;
             let mutable = false;
-            ast::Expression::Ident(ast::Ident { definition, mutable, location: None, name, typ })
+            let location = None;
+            ast::Expression::Ident(ast::Ident { definition, kind, mutable, location, name, typ })
         });
 
         // Finally we can return the created Tuple from the new block
@@ -731,7 +905,7 @@ impl<'interner> Monomorphizer<'interner> {
         match pattern {
             HirPattern::Identifier(ident) => {
                 let new_id = self.next_local_id();
-                self.define_local(ident.id, new_id);
+                self.define_local(ident.id, new_id, ast::DefinitionKind::Local);
                 let definition = self.interner.definition(ident.id);
 
                 Ok(ast::Expression::Let(ast::Let {
@@ -742,12 +916,12 @@ impl<'interner> Monomorphizer<'interner> {
                 }))
             }
             HirPattern::Mutable(pattern, _) => self.unpack_pattern(*pattern, value, typ),
-            HirPattern::Tuple(patterns, _) => {
-                let fields = unwrap_tuple_type(typ);
+            HirPattern::Tuple(patterns, location) => {
+                let fields = unwrap_tuple_type(typ, location)?;
                 self.unpack_tuple_pattern(value, patterns.into_iter().zip(fields))
             }
-            HirPattern::Struct(_, patterns, _) => {
-                let fields = unwrap_struct_type(typ);
+            HirPattern::Struct(_, patterns, location) => {
+                let fields = unwrap_struct_type(typ, location)?;
                 assert_eq!(patterns.len(), fields.len());
 
                 let mut patterns =
@@ -782,12 +956,19 @@ impl<'interner> Monomorphizer<'interner> {
             let location = field_pattern.location();
             let mutable = false;
             let definition = Definition::Local(fresh_id);
+            let kind = ast::DefinitionKind::Local;
             let name = i.to_string();
             let typ = Self::convert_type(&field_type, location)?;
 
             let location = Some(location);
-            let new_rhs =
-                ast::Expression::Ident(ast::Ident { location, mutable, definition, name, typ });
+            let new_rhs = ast::Expression::Ident(ast::Ident {
+                location,
+                mutable,
+                definition,
+                kind,
+                name,
+                typ,
+            });
 
             let new_rhs = ast::Expression::ExtractTupleField(Box::new(new_rhs), i);
             let new_expr = self.unpack_pattern(field_pattern, new_rhs, &field_type)?;
@@ -828,12 +1009,13 @@ impl<'interner> Monomorphizer<'interner> {
         let name = definition.name.clone();
         let mutable = definition.mutable;
 
-        let Some(definition) = self.lookup_local(ident.id) else {
+        let Some((definition, kind)) = self.lookup_local(ident.id) else {
             return Ok(None);
         };
 
         let typ = Self::convert_type(&self.interner.definition_type(ident.id), ident.location)?;
-        Ok(Some(ast::Ident { location: Some(ident.location), mutable, definition, name, typ }))
+        let location = Some(ident.location);
+        Ok(Some(ast::Ident { location, mutable, definition, kind, name, typ }))
     }
 
     fn ident(
@@ -860,9 +1042,11 @@ impl<'interner> Monomorphizer<'interner> {
                     &typ,
                     generics.unwrap_or_default(),
                     None,
-                );
+                )?;
                 let typ = Self::convert_type(&typ, ident.location)?;
-                let ident = ast::Ident { location, mutable, definition, name, typ: typ.clone() };
+                let kind = ast::DefinitionKind::Function;
+                let ident =
+                    ast::Ident { location, mutable, definition, kind, name, typ: typ.clone() };
                 let ident_expression = ast::Expression::Ident(ident);
                 if self.is_function_closure_type(&typ) {
                     ast::Expression::Tuple(vec![
@@ -886,15 +1070,20 @@ impl<'interner> Monomorphizer<'interner> {
             }
             DefinitionKind::Local(_) => match self.lookup_captured_expr(ident.id) {
                 Some(expr) => expr,
-                None => {
-                    let ident = self.local_ident(&ident)?.unwrap();
-                    ast::Expression::Ident(ident)
-                }
+                None => match self.local_ident(&ident)? {
+                    Some(ident) => ast::Expression::Ident(ident),
+                    None => {
+                        let name = self.interner.definition_name(ident.id).to_string();
+                        let location = ident.location;
+                        return Err(MonomorphizationError::UndefinedLocal { name, location });
+                    }
+                },
             },
             DefinitionKind::GenericType(type_variable) => {
                 let value = match &*type_variable.borrow() {
                     TypeBinding::Unbound(_) => {
-                        unreachable!("Unbound type variable used in expression")
+                        let location = ident.location;
+                        return Err(MonomorphizationError::TypeAnnotationsNeeded { location });
                     }
                     TypeBinding::Bound(binding) => binding.evaluate_to_u64().unwrap_or_else(|| {
                         panic!("Non-numeric type variable used in expression expecting a value")
@@ -904,7 +1093,12 @@ impl<'interner> Monomorphizer<'interner> {
                 let value = FieldElement::from(value as u128);
                 let location = self.interner.id_location(expr_id);
                 let typ = Self::convert_type(&typ, ident.location)?;
-                ast::Expression::Literal(ast::Literal::Integer(value, typ, location))
+                ast::Expression::Literal(ast::Literal::Integer(
+                    value,
+                    typ,
+                    location,
+                    LiteralRepr::Decimal,
+                ))
             }
         };
 
@@ -1086,7 +1280,7 @@ impl<'interner> Monomorphizer<'interner> {
         };
 
         let func_id =
-            match self.lookup_function(func_id, expr_id, &function_type, vec![], Some(method)) {
+            match self.lookup_function(func_id, expr_id, &function_type, vec![], Some(method))? {
                 Definition::Function(func_id) => func_id,
                 _ => unreachable!(),
             };
@@ -1096,8 +1290,9 @@ impl<'interner> Monomorphizer<'interner> {
 
         Ok(ast::Expression::Ident(ast::Ident {
             definition: Definition::Function(func_id),
+            kind: ast::DefinitionKind::Function,
             mutable: false,
-            location: None,
+            location: Some(location),
             name: the_trait.methods[method.method_index].name.0.contents.clone(),
             typ: Self::convert_type(&function_type, location)?,
         }))
@@ -1108,11 +1303,15 @@ impl<'interner> Monomorphizer<'interner> {
         call: HirCallExpression,
         id: node_interner::ExprId,
     ) -> Result<ast::Expression, MonomorphizationError> {
-        let original_func = Box::new(self.expr(call.func)?);
+        let original_func = match self.interner.expression(&call.func) {
+            HirExpression::Ident(ident, generics) => self.ident(ident, call.func, generics)?,
+            _ => self.expr(call.func)?,
+        };
+        let original_func = Box::new(original_func);
         let mut arguments = try_vecmap(&call.arguments, |id| self.expr(*id))?;
         let hir_arguments = vecmap(&call.arguments, |id| self.interner.expression(id));
 
-        self.patch_debug_instrumentation_call(&call, &mut arguments)?;
+        self.patch_debug_instrumentation_call(&call, &original_func, &mut arguments)?;
 
         let return_type = self.interner.id_type(id);
         let location = self.interner.expr_location(&id);
@@ -1129,6 +1328,11 @@ impl<'interner> Monomorphizer<'interner> {
                     self.append_printable_type_info(&hir_arguments[1], &mut arguments);
                 }
             }
+            if let Definition::Builtin(name) = &ident.definition {
+                if name.as_str() == "assert_cast" {
+                    return self.checked_cast(arguments, call.arguments[0], return_type, location);
+                }
+            }
         }
 
         let mut block_expressions = vec![];
@@ -1151,8 +1355,9 @@ impl<'interner> Monomorphizer<'interner> {
             block_expressions.push(let_stmt);
 
             let extracted_func = ast::Expression::Ident(ast::Ident {
-                location: None,
+                location: Some(location),
                 definition: Definition::Local(local_id),
+                kind: ast::DefinitionKind::Local,
                 mutable: false,
                 name: "tmp".to_string(),
                 typ: Self::convert_type(&self.interner.id_type(call.func), location)?,
@@ -1240,6 +1445,30 @@ impl<'interner> Monomorphizer<'interner> {
         arguments.push(ast::Expression::Literal(ast::Literal::Str(abi_as_string)));
     }
 
+    /// Lowers a call to `std::assert_cast` into a cast which fails if the value does not fit into
+    /// the target type, rather than truncating it.
+    fn checked_cast(
+        &self,
+        mut arguments: Vec<ast::Expression>,
+        argument: node_interner::ExprId,
+        to: ast::Type,
+        location: Location,
+    ) -> Result<ast::Expression, MonomorphizationError> {
+        let from = Self::convert_type(&self.interner.id_type(argument), location)?;
+
+        let is_unsigned_or_field = |typ: &ast::Type| {
+            matches!(typ, ast::Type::Field | ast::Type::Integer(Signedness::Unsigned, _))
+        };
+        let from_is_valid = is_unsigned_or_field(&from) || from == ast::Type::Bool;
+        if !from_is_valid || !is_unsigned_or_field(&to) {
+            let (from, to) = (from.to_string(), to.to_string());
+            return Err(MonomorphizationError::InvalidCheckedCast { from, to, location });
+        }
+
+        let lhs = Box::new(arguments.pop().expect("assert_cast takes a single argument"));
+        Ok(ast::Expression::Cast(ast::Cast { lhs, from, r#type: to, checked: true, location }))
+    }
+
     /// Try to evaluate certain builtin functions (currently only 'array_len' and field modulus methods)
     /// at their call site.
     /// NOTE: Evaluating at the call site means we cannot track aliased functions.
@@ -1261,7 +1490,8 @@ impl<'interner> Monomorphizer<'interner> {
                         let bits = (FieldElement::max_num_bits() as u128).into();
                         let typ =
                             ast::Type::Integer(Signedness::Unsigned, IntegerBitSize::SixtyFour);
-                        Some(ast::Expression::Literal(ast::Literal::Integer(bits, typ, location)))
+                        let bits = ast::Literal::Integer(bits, typ, location, LiteralRepr::Decimal);
+                        Some(ast::Expression::Literal(bits))
                     }
                     "zeroed" => {
                         let location = self.interner.expr_location(expr_id);
@@ -1301,7 +1531,13 @@ impl<'interner> Monomorphizer<'interner> {
         let int_type = Type::Integer(crate::ast::Signedness::Unsigned, arr_elem_bits);
 
         let bytes_as_expr = vecmap(bytes, |byte| {
-            Expression::Literal(Literal::Integer((byte as u128).into(), int_type.clone(), location))
+            let byte = (byte as u128).into();
+            Expression::Literal(Literal::Integer(
+                byte,
+                int_type.clone(),
+                location,
+                LiteralRepr::Decimal,
+            ))
         });
 
         let typ = Type::Array(bytes_as_expr.len() as u64, Box::new(int_type));
@@ -1317,14 +1553,50 @@ impl<'interner> Monomorphizer<'interner> {
         function_type: HirType,
         turbofish_generics: Vec<HirType>,
         trait_method: Option<TraitMethodId>,
-    ) -> FuncId {
+    ) -> Result<FuncId, MonomorphizationError> {
         let new_id = self.next_function_id();
         self.define_function(id, function_type.clone(), turbofish_generics, new_id);
 
         let bindings = self.interner.get_instantiation_bindings(expr_id);
         let bindings = self.follow_bindings(bindings);
-        self.queue.push_back((id, new_id, bindings, trait_method));
-        new_id
+
+        let mut instantiations = self.instantiations.clone();
+        if let Some(instantiation) = self.instantiation(id, expr_id, &bindings) {
+            let previous = instantiations.iter().filter(|previous| previous.function == id);
+            if previous.count() >= MAX_RECURSIVE_INSTANTIATIONS {
+                return Err(recursive_instantiation(&instantiations, instantiation));
+            }
+            instantiations.push(instantiation);
+        }
+
+        self.queue.push_back((id, new_id, bindings, trait_method, instantiations));
+        Ok(new_id)
+    }
+
+    /// Describes the instantiation of `id` at the call `expr_id` for error reporting.
+    /// Returns `None` if the function has no generics of its own.
+    fn instantiation(
+        &self,
+        id: node_interner::FuncId,
+        expr_id: node_interner::ExprId,
+        bindings: &TypeBindings,
+    ) -> Option<Instantiation> {
+        let meta = self.interner.function_meta(&id);
+        let generics = meta.direct_generics.iter().filter_map(|(name, type_variable)| {
+            let (_, typ) = bindings.get(&type_variable.id())?;
+            Some(format!("{name} = {typ}"))
+        });
+        let bindings = generics.collect::<Vec<_>>().join(", ");
+        if bindings.is_empty() {
+            return None;
+        }
+
+        Some(Instantiation {
+            function: id,
+            function_name: self.interner.function_name(&id).to_owned(),
+            bindings,
+            location: self.interner.expr_location(&expr_id),
+        })
     }
 
     /// Follow any type variable links within the given TypeBindings to produce
@@ -1347,10 +1619,11 @@ impl<'interner> Monomorphizer<'interner> {
     fn assign(
         &mut self,
         assign: HirAssignStatement,
+        location: Location,
     ) -> Result<ast::Expression, MonomorphizationError> {
         let expression = Box::new(self.expr(assign.expression)?);
         let lvalue = self.lvalue(assign.lvalue)?;
-        Ok(ast::Expression::Assign(ast::Assign { expression, lvalue }))
+        Ok(ast::Expression::Assign(ast::Assign { expression, lvalue, location }))
     }
 
     fn lvalue(&mut self, lvalue: HirLValue) -> Result<ast::LValue, MonomorphizationError> {
@@ -1425,6 +1698,7 @@ impl<'interner> Monomorphizer<'interner> {
             unconstrained,
             inline_type: InlineType::default(),
             func_sig: FunctionSignature::default(),
+            overflow_checks: true,
         };
         self.push_function(id, function);
 
@@ -1434,8 +1708,9 @@ impl<'interner> Monomorphizer<'interner> {
         let name = lambda_name.to_owned();
         Ok(ast::Expression::Ident(ast::Ident {
             definition: Definition::Function(id),
+            kind: ast::DefinitionKind::Function,
             mutable: false,
-            location: None,
+            location: Some(location),
             name,
             typ,
         }))
@@ -1510,14 +1785,14 @@ impl<'interner> Monomorphizer<'interner> {
             expression: Box::new(env_tuple),
         });
 
-        let location = None; // TODO: This should match the location of the lambda expression
         let mutable = true;
         let definition = Definition::Local(env_local_id);
 
         let env_ident = ast::Ident {
-            location,
+            location: Some(location),
             mutable,
             definition,
+            kind: ast::DefinitionKind::Parameter,
             name: env_name.to_string(),
             typ: env_typ.clone(),
         };
@@ -1531,8 +1806,9 @@ impl<'interner> Monomorphizer<'interner> {
             ast::Type::Function(parameter_types, Box::new(ret_type), Box::new(env_typ.clone()));
         let lambda_fn = ast::Expression::Ident(ast::Ident {
             definition: Definition::Function(id),
+            kind: ast::DefinitionKind::Function,
             mutable: false,
-            location: None, // TODO: This should match the location of the lambda expression
+            location: Some(location),
             name: name.clone(),
             typ: lambda_fn_typ.clone(),
         });
@@ -1551,6 +1827,7 @@ impl<'interner> Monomorphizer<'interner> {
             unconstrained,
             inline_type: InlineType::default(),
             func_sig: FunctionSignature::default(),
+            overflow_checks: true,
         };
         self.push_function(id, function);
 
@@ -1568,9 +1845,10 @@ impl<'interner> Monomorphizer<'interner> {
         let closure_definition = Definition::Local(block_local_id);
 
         let closure_ident = ast::Expression::Ident(ast::Ident {
-            location,
+            location: Some(location),
             mutable: false,
             definition: closure_definition,
+            kind: ast::DefinitionKind::Local,
             name: block_ident_name.to_string(),
             typ: ast::Type::Tuple(vec![env_typ, lambda_fn_typ]),
         });
@@ -1589,7 +1867,9 @@ impl<'interner> Monomorphizer<'interner> {
         match typ {
             ast::Type::Field | ast::Type::Integer(..) => {
                 let typ = typ.clone();
-                ast::Expression::Literal(ast::Literal::Integer(0_u128.into(), typ, location))
+                let zero =
+                    ast::Literal::Integer(0_u128.into(), typ, location, LiteralRepr::Decimal);
+                ast::Expression::Literal(zero)
             }
             ast::Type::Bool => ast::Expression::Literal(ast::Literal::Bool(false)),
             ast::Type::Unit => ast::Expression::Literal(ast::Literal::Unit),
@@ -1676,13 +1956,15 @@ impl<'interner> Monomorphizer<'interner> {
             unconstrained,
             inline_type: InlineType::default(),
             func_sig: FunctionSignature::default(),
+            overflow_checks: true,
         };
         self.push_function(id, function);
 
         ast::Expression::Ident(ast::Ident {
             definition: Definition::Function(id),
+            kind: ast::DefinitionKind::Function,
             mutable: false,
-            location: None,
+            location: Some(location),
             name: lambda_name.to_owned(),
             typ: ast::Type::Function(
                 parameter_types.to_owned(),
@@ -1744,7 +2026,12 @@ impl<'interner> Monomorphizer<'interner> {
                 let operator =
                     if matches!(operator.kind, Less | Greater) { Equal } else { NotEqual };
 
-                let int_value = ast::Literal::Integer(ordering_value, ast::Type::Field, location);
+                let int_value = ast::Literal::Integer(
+                    ordering_value,
+                    ast::Type::Field,
+                    location,
+                    LiteralRepr::Decimal,
+                );
                 let rhs = Box::new(ast::Expression::Literal(int_value));
                 let lhs = Box::new(ast::Expression::ExtractTupleField(Box::new(result), 0));
 
@@ -1797,20 +2084,71 @@ impl<'interner> Monomorphizer<'interner> {
     }
 }
 
-fn unwrap_tuple_type(typ: &HirType) -> Vec<HirType> {
+fn unwrap_tuple_type(
+    typ: &HirType,
+    location: Location,
+) -> Result<Vec<HirType>, MonomorphizationError> {
     match typ.follow_bindings() {
-        HirType::Tuple(fields) => fields.clone(),
+        HirType::Tuple(fields) => Ok(fields.clone()),
+        HirType::TypeVariable(..) => Err(MonomorphizationError::TypeAnnotationsNeeded { location }),
         other => unreachable!("unwrap_tuple_type: expected tuple, found {:?}", other),
     }
 }
 
-fn unwrap_struct_type(typ: &HirType) -> Vec<(String, HirType)> {
+/// Returns the fields of a struct type in declaration order, which is the order
+/// of the elements of the tuple the struct is lowered to.
+fn unwrap_struct_type(
+    typ: &HirType,
+    location: Location,
+) -> Result<Vec<(String, HirType)>, MonomorphizationError> {
     match typ.follow_bindings() {
-        HirType::Struct(def, args) => def.borrow().get_fields(&args),
+        HirType::Struct(def, args) => Ok(def.borrow().get_fields(&args)),
+        HirType::TypeVariable(..) => Err(MonomorphizationError::TypeAnnotationsNeeded { location }),
         other => unreachable!("unwrap_struct_type: expected struct, found {:?}", other),
     }
 }
 
+/// If `typ` is the standard library's `BoundedVec`, returns the index and type of its `len` field
+/// along with its maximum length.
+fn bounded_vec_length(typ: &HirType) -> Option<(usize, HirType, u64)> {
+    let HirType::Struct(def, args) = typ.follow_bindings() else {
+        return None;
+    };
+    let def = def.borrow();
+    if def.name.0.contents != "BoundedVec" || !def.id.krate().is_stdlib() {
+        return None;
+    }
+
+    let fields = def.get_fields(&args);
+    let max_len = fields.iter().find_map(|(name, typ)| match typ.follow_bindings() {
+        HirType::Array(length, _) if name == "storage" => length.evaluate_to_u64(),
+        _ => None,
+    })?;
+    let len_index = fields.iter().position(|(name, _)| name == "len")?;
+    Some((len_index, fields[len_index].1.clone(), max_len))
+}
+
+/// Builds the error for `instantiation` recurring too often in the chain of `instantiations` which
+/// led to it, naming the generic functions along the most recent iteration of the cycle.
+fn recursive_instantiation(
+    instantiations: &[Instantiation],
+    instantiation: Instantiation,
+) -> MonomorphizationError {
+    let function = instantiation.function;
+    let first = instantiations.iter().find(|previous| previous.function == function);
+    let first = first.expect("the instantiation should already be in the chain");
+    let start = instantiations.iter().rposition(|previous| previous.function == function).unwrap();
+
+    let mut cycle = vecmap(&instantiations[start..], |previous| previous.function_name.clone());
+    cycle.push(instantiation.function_name);
+    MonomorphizationError::RecursiveInstantiation {
+        cycle,
+        bindings: instantiation.bindings,
+        location: instantiation.location,
+        first_location: first.location,
+    }
+}
+
 fn perform_instantiation_bindings(bindings: &TypeBindings) {
     for (var, binding) in bindings.values() {
         var.force_bind(binding.clone());
@@ -1,26 +1,153 @@
 //! This module implements printing of the monomorphized AST, for debugging purposes.
+//!
+//! Locals and functions are printed with their [StableNames] where these are known, so that the
+//! output of two versions of a program can be diffed.
 
-use super::ast::{Definition, Expression, Function, LValue};
+use super::ast::{Definition, Expression, FuncId, Function, LValue, Literal, LocalId, Program};
 use iter_extended::vecmap;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
+/// Names for the locals and functions of a monomorphized program which, unlike their ids, don't
+/// change when unrelated code is added to or removed from the program.
+///
+/// Locals are named `name@k`, where `k` is the number of locals with the same name defined
+/// before it in the same function. Parameters are defined first, followed by `let` and `for`
+/// bindings in the order they appear.
+///
+/// Functions are named `name#k`, where `k` is the number of functions with the same name before
+/// it in the program, such as other instantiations of the same generic function.
+#[derive(Default)]
+pub(super) struct StableNames {
+    functions: HashMap<FuncId, String>,
+    locals: HashMap<LocalId, String>,
+}
+
+impl StableNames {
+    pub(super) fn for_program(program: &Program) -> Self {
+        let mut counts = HashMap::new();
+        let functions = program
+            .functions
+            .iter()
+            .map(|function| (function.id, disambiguate(&mut counts, &function.name, '#')))
+            .collect();
+        StableNames { functions, locals: HashMap::new() }
+    }
+
+    /// Names the locals of `function`, replacing those of any previous function.
+    pub(super) fn enter_function(&mut self, function: &Function) {
+        let mut counts = HashMap::new();
+        self.locals.clear();
+        for (id, _, name, _) in &function.parameters {
+            self.locals.insert(*id, disambiguate(&mut counts, name, '@'));
+        }
+        self.name_locals(&function.body, &mut counts);
+    }
+
+    pub(super) fn function(&self, id: FuncId) -> Option<&str> {
+        self.functions.get(&id).map(String::as_str)
+    }
+
+    pub(super) fn local(&self, id: LocalId) -> Option<&str> {
+        self.locals.get(&id).map(String::as_str)
+    }
+
+    fn name_locals(&mut self, expression: &Expression, counts: &mut HashMap<String, usize>) {
+        match expression {
+            Expression::Let(let_expr) => {
+                self.locals.insert(let_expr.id, disambiguate(counts, &let_expr.name, '@'));
+                self.name_locals(&let_expr.expression, counts);
+            }
+            Expression::For(for_expr) => {
+                let name = disambiguate(counts, &for_expr.index_name, '@');
+                self.locals.insert(for_expr.index_variable, name);
+                let parts = [&for_expr.start_range, &for_expr.end_range, &for_expr.block];
+                self.name_locals_in(parts.map(|part| part.as_ref()), counts);
+            }
+            Expression::Ident(_) | Expression::Break | Expression::Continue => (),
+            Expression::Literal(Literal::Array(array) | Literal::Slice(array)) => {
+                self.name_locals_in(&array.contents, counts);
+            }
+            Expression::Literal(Literal::FmtStr(_, _, fields)) => self.name_locals(fields, counts),
+            Expression::Literal(_) => (),
+            Expression::Block(expressions) | Expression::Tuple(expressions) => {
+                self.name_locals_in(expressions, counts);
+            }
+            Expression::Unary(unary) => self.name_locals(&unary.rhs, counts),
+            Expression::Binary(binary) => {
+                self.name_locals_in([binary.lhs.as_ref(), binary.rhs.as_ref()], counts);
+            }
+            Expression::Index(index) => {
+                self.name_locals_in([index.collection.as_ref(), index.index.as_ref()], counts);
+            }
+            Expression::Cast(cast) => self.name_locals(&cast.lhs, counts),
+            Expression::While(while_expr) => {
+                let parts = [while_expr.condition.as_ref(), while_expr.block.as_ref()];
+                self.name_locals_in(parts, counts);
+            }
+            Expression::If(if_expr) => {
+                self.name_locals_in(
+                    [if_expr.condition.as_ref(), if_expr.consequence.as_ref()],
+                    counts,
+                );
+                self.name_locals_in(if_expr.alternative.as_deref(), counts);
+            }
+            Expression::ExtractTupleField(expression, _) | Expression::Semi(expression) => {
+                self.name_locals(expression, counts);
+            }
+            Expression::Call(call) => {
+                self.name_locals(&call.func, counts);
+                self.name_locals_in(&call.arguments, counts);
+            }
+            Expression::Constrain(condition, _, message) => {
+                self.name_locals(condition, counts);
+                self.name_locals_in(message.as_deref().map(|(message, _)| message), counts);
+            }
+            Expression::Assign(assign) => self.name_locals(&assign.expression, counts),
+        }
+    }
+
+    fn name_locals_in<'a>(
+        &mut self,
+        expressions: impl IntoIterator<Item = &'a Expression>,
+        counts: &mut HashMap<String, usize>,
+    ) {
+        for expression in expressions {
+            self.name_locals(expression, counts);
+        }
+    }
+}
+
+/// Appends to `name` the number of times it was seen before, separated by `separator`.
+fn disambiguate(counts: &mut HashMap<String, usize>, name: &str, separator: char) -> String {
+    let count = counts.entry(name.to_string()).or_default();
+    let name = format!("{name}{separator}{count}");
+    *count += 1;
+    name
+}
+
 #[derive(Default)]
 pub struct AstPrinter {
     indent_level: u32,
+    names: StableNames,
 }
 
 impl AstPrinter {
+    /// Creates a printer which prints the functions of `program` with their stable names.
+    pub fn for_program(program: &Program) -> Self {
+        AstPrinter { indent_level: 0, names: StableNames::for_program(program) }
+    }
+
     pub fn print_function(&mut self, function: &Function, f: &mut Formatter) -> std::fmt::Result {
+        self.names.enter_function(function);
         let params = vecmap(&function.parameters, |(id, mutable, name, typ)| {
-            format!("{}{}$l{}: {}", if *mutable { "mut " } else { "" }, name, id.0, typ)
+            let name = self.local_name(*id, name);
+            format!("{}{}: {}", if *mutable { "mut " } else { "" }, name, typ)
         })
         .join(", ");
 
-        write!(
-            f,
-            "fn {}$f{}({}) -> {} {{",
-            function.name, function.id.0, params, function.return_type
-        )?;
+        let name = self.function_name(function.id, &function.name);
+        write!(f, "fn {}({}) -> {} {{", name, params, function.return_type)?;
         self.indent_level += 1;
         self.print_expr_expect_block(&function.body, f)?;
         self.indent_level -= 1;
@@ -30,9 +157,7 @@ impl AstPrinter {
 
     pub fn print_expr(&mut self, expr: &Expression, f: &mut Formatter) -> std::fmt::Result {
         match expr {
-            Expression::Ident(ident) => {
-                write!(f, "{}${}", ident.name, ident.definition)
-            }
+            Expression::Ident(ident) => write!(f, "{}", self.ident_name(ident)),
             Expression::Literal(literal) => self.print_literal(literal, f),
             Expression::Block(exprs) => self.print_block(exprs, f),
             Expression::Unary(unary) => self.print_unary(unary, f),
@@ -43,12 +168,18 @@ impl AstPrinter {
                 self.print_expr(&index.index, f)?;
                 write!(f, "]")
             }
+            Expression::Cast(cast) if cast.checked => {
+                write!(f, "assert_cast::<{}>(", cast.r#type)?;
+                self.print_expr(&cast.lhs, f)?;
+                write!(f, ")")
+            }
             Expression::Cast(cast) => {
                 write!(f, "(")?;
                 self.print_expr(&cast.lhs, f)?;
                 write!(f, " as {})", cast.r#type)
             }
             Expression::For(for_expr) => self.print_for(for_expr, f),
+            Expression::While(while_expr) => self.print_while(while_expr, f),
             Expression::If(if_expr) => self.print_if(if_expr, f),
             Expression::Tuple(tuple) => self.print_tuple(tuple, f),
             Expression::ExtractTupleField(expr, index) => {
@@ -57,7 +188,7 @@ impl AstPrinter {
             }
             Expression::Call(call) => self.print_call(call, f),
             Expression::Let(let_expr) => {
-                write!(f, "let {}${} = ", let_expr.name, let_expr.id.0)?;
+                write!(f, "let {} = ", self.local_name(let_expr.id, &let_expr.name))?;
                 self.print_expr(&let_expr.expression, f)
             }
             Expression::Constrain(expr, ..) => {
@@ -78,6 +209,28 @@ impl AstPrinter {
         }
     }
 
+    fn local_name(&self, id: LocalId, name: &str) -> String {
+        match self.names.local(id) {
+            Some(stable_name) => stable_name.to_string(),
+            None => format!("{name}$l{}", id.0),
+        }
+    }
+
+    fn function_name(&self, id: FuncId, name: &str) -> String {
+        match self.names.function(id) {
+            Some(stable_name) => stable_name.to_string(),
+            None => format!("{name}$f{}", id.0),
+        }
+    }
+
+    fn ident_name(&self, ident: &super::ast::Ident) -> String {
+        match &ident.definition {
+            Definition::Local(id) => self.local_name(*id, &ident.name),
+            Definition::Function(id) => self.function_name(*id, &ident.name),
+            other => format!("{}${other}", ident.name),
+        }
+    }
+
     fn next_line(&mut self, f: &mut Formatter) -> std::fmt::Result {
         writeln!(f)?;
         for _ in 0..self.indent_level {
@@ -102,7 +255,10 @@ impl AstPrinter {
                 self.print_comma_separated(&array.contents, f)?;
                 write!(f, "]")
             }
-            super::ast::Literal::Integer(x, _, _) => x.fmt(f),
+            super::ast::Literal::Integer(x, _, _, repr) => match repr.written_form() {
+                Some(written_form) => write!(f, "{written_form}"),
+                None => x.fmt(f),
+            },
             super::ast::Literal::Bool(x) => x.fmt(f),
             super::ast::Literal::Str(s) => s.fmt(f),
             super::ast::Literal::FmtStr(s, _, _) => {
@@ -194,7 +350,8 @@ impl AstPrinter {
         for_expr: &super::ast::For,
         f: &mut Formatter,
     ) -> Result<(), std::fmt::Error> {
-        write!(f, "for {}${} in ", for_expr.index_name, for_expr.index_variable.0)?;
+        let index_name = self.local_name(for_expr.index_variable, &for_expr.index_name);
+        write!(f, "for {index_name} in ")?;
         self.print_expr(&for_expr.start_range, f)?;
         write!(f, " .. ")?;
         self.print_expr(&for_expr.end_range, f)?;
@@ -207,6 +364,22 @@ impl AstPrinter {
         write!(f, "}}")
     }
 
+    fn print_while(
+        &mut self,
+        while_expr: &super::ast::While,
+        f: &mut Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        write!(f, "while ")?;
+        self.print_expr(&while_expr.condition, f)?;
+        write!(f, " {{")?;
+
+        self.indent_level += 1;
+        self.print_expr_expect_block(&while_expr.block, f)?;
+        self.indent_level -= 1;
+        self.next_line(f)?;
+        write!(f, "}}")
+    }
+
     fn print_if(
         &mut self,
         if_expr: &super::ast::If,
@@ -268,7 +441,7 @@ impl AstPrinter {
 
     fn print_lvalue(&mut self, lvalue: &LValue, f: &mut Formatter) -> std::fmt::Result {
         match lvalue {
-            LValue::Ident(ident) => write!(f, "{}${}", ident.name, ident.definition),
+            LValue::Ident(ident) => write!(f, "{}", self.ident_name(ident)),
             LValue::Index { array, index, .. } => {
                 self.print_lvalue(array, f)?;
                 write!(f, "[")?;
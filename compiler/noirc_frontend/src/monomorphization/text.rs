@@ -0,0 +1,1193 @@
+//! A stable textual format for the monomorphized AST.
+//!
+//! Unlike the [printer][super::printer], which produces Rust-like output for debugging, this
+//! format can be parsed back into a [Program] so that later compiler passes can be exercised
+//! on hand-written or previously dumped programs without going through the frontend.
+//!
+//! Programs are written as s-expressions:
+//!
+//! ```text
+//! (program (return-visibility pub) (recursive false)
+//!   (fn "main#0" "main" constrained inline
+//!     (params (param "x@0" imm "x" Field))
+//!     (return Field)
+//!     (signature (params (priv Field)) (return Field))
+//!     (block
+//!       (binary + (ident "x" (param "x@0") imm Field) (int 1 Field)))))
+//! ```
+//!
+//! Locals which are parameters of the enclosing function are written as `(param <id>)` rather
+//! than `(local <id>)`.
+//!
+//! Locals and functions are written with their [stable names][StableNames], such as `"x@0"` and
+//! `"main#0"`, rather than their numeric ids, so that adding unrelated code to a program does
+//! not change how the rest of it is printed. Numeric ids are still accepted by the parser, but a
+//! function must not mix numeric ids and stable names for its locals.
+//!
+//! Casts are written as `(cast <from> <to> <expression>)` with both the source and target types,
+//! or with a `checked-cast` tag if they fail rather than truncate.
+//!
+//! Functions compiled without overflow checks have `unchecked` written after their inline type.
+//!
+//! Source locations and debug information are not part of the format and are replaced with
+//! dummy values when a program is parsed. The types of assert message payloads are written as
+//! monomorphized types, so struct payloads are reloaded as tuples. Printing a parsed program
+//! reproduces the text it was parsed from.
+
+use std::collections::HashMap;
+
+use acvm::FieldElement;
+use iter_extended::{try_vecmap, vecmap};
+use noirc_errors::Location;
+use thiserror::Error;
+
+use crate::ast::{BinaryOpKind, IntegerBitSize, Signedness, UnaryOp, Visibility};
+use crate::hir_def::expr::HirIdent;
+use crate::hir_def::function::{FunctionSignature, Param};
+use crate::hir_def::stmt::HirPattern;
+use crate::node_interner::DefinitionId;
+use crate::token::LiteralRepr;
+
+use super::ast::{
+    ArrayLiteral, Assign, Binary, Call, Cast, Definition, DefinitionKind, Expression, For, FuncId,
+    Function, Ident, If, Index, InlineType, LValue, Let, Literal, LocalId, Program, Type, Unary,
+    While,
+};
+use super::printer::StableNames;
+use super::{HirType, Monomorphizer};
+
+/// Renders `program` in the textual format understood by [parse_program].
+pub fn print_program(program: &Program) -> String {
+    let mut printer = Printer::default();
+    printer.program(program);
+    printer.output
+}
+
+/// Parses a program previously rendered by [print_program].
+pub fn parse_program(source: &str) -> Result<Program, TextParseError> {
+    let program = Reader::new(source).read_document()?;
+    program_from_sexpr(&program)
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("{message} at byte {position}")]
+pub struct TextParseError {
+    pub message: String,
+    pub position: usize,
+}
+
+#[derive(Default)]
+struct Printer {
+    output: String,
+    indent: usize,
+    names: StableNames,
+}
+
+impl Printer {
+    fn program(&mut self, program: &Program) {
+        self.names = StableNames::for_program(program);
+        self.output.push_str(&format!(
+            "(program (return-visibility {}) (recursive {})",
+            program.return_visibility, program.recursive
+        ));
+        self.indent += 1;
+        for function in &program.functions {
+            self.newline();
+            self.function(function);
+        }
+        self.indent -= 1;
+        self.output.push_str(")\n");
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.names.enter_function(function);
+        let constrained = if function.unconstrained { "unconstrained" } else { "constrained" };
+        self.output.push_str(&format!(
+            "(fn {} {:?} {} {}",
+            self.function_id(function.id),
+            function.name,
+            constrained,
+            function.inline_type
+        ));
+        if !function.overflow_checks {
+            self.output.push_str(" unchecked");
+        }
+        self.indent += 1;
+
+        self.newline();
+        let parameters = vecmap(&function.parameters, |(id, mutable, name, typ)| {
+            let id = self.local_id(*id);
+            format!(" (param {id} {} {name:?} {})", mutability(*mutable), print_type(typ))
+        });
+        self.output.push_str(&format!("(params{})", parameters.concat()));
+
+        self.newline();
+        self.output.push_str(&format!("(return {})", print_type(&function.return_type)));
+
+        if has_entry_point_signature(function) {
+            self.newline();
+            self.output.push_str(&print_signature(&function.func_sig));
+        }
+
+        self.newline();
+        self.expression(&function.body);
+        self.indent -= 1;
+        self.output.push(')');
+    }
+
+    fn expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Ident(ident) => self.output.push_str(&self.ident(ident)),
+            Expression::Literal(literal) => self.literal(literal),
+            Expression::Block(expressions) => {
+                self.output.push_str("(block");
+                self.indent += 1;
+                for expression in expressions {
+                    self.newline();
+                    self.expression(expression);
+                }
+                self.indent -= 1;
+                self.output.push(')');
+            }
+            Expression::Unary(unary) => {
+                let operator = print_unary_operator(unary.operator);
+                self.output
+                    .push_str(&format!("(unary {operator} {} ", print_type(&unary.result_type)));
+                self.expression(&unary.rhs);
+                self.output.push(')');
+            }
+            Expression::Binary(binary) => {
+                self.output.push_str(&format!("(binary {} ", binary.operator));
+                self.expression(&binary.lhs);
+                self.output.push(' ');
+                self.expression(&binary.rhs);
+                self.output.push(')');
+            }
+            Expression::Index(index) => {
+                self.output.push_str(&format!("(index {} ", print_type(&index.element_type)));
+                self.expression(&index.collection);
+                self.output.push(' ');
+                self.expression(&index.index);
+                self.output.push(')');
+            }
+            Expression::Cast(cast) => {
+                let tag = if cast.checked { "checked-cast" } else { "cast" };
+                self.output.push_str(&format!(
+                    "({tag} {} {} ",
+                    print_type(&cast.from),
+                    print_type(&cast.r#type)
+                ));
+                self.expression(&cast.lhs);
+                self.output.push(')');
+            }
+            Expression::For(for_expr) => {
+                self.output.push_str(&format!(
+                    "(for {} {:?} {} ",
+                    self.local_id(for_expr.index_variable),
+                    for_expr.index_name,
+                    print_type(&for_expr.index_type)
+                ));
+                self.expression(&for_expr.start_range);
+                self.output.push(' ');
+                self.expression(&for_expr.end_range);
+                self.output.push(' ');
+                self.expression(&for_expr.block);
+                self.output.push(')');
+            }
+            Expression::While(while_expr) => {
+                self.output.push_str("(while ");
+                self.expression(&while_expr.condition);
+                self.output.push(' ');
+                self.expression(&while_expr.block);
+                self.output.push(')');
+            }
+            Expression::If(if_expr) => {
+                self.output.push_str(&format!("(if {} ", print_type(&if_expr.typ)));
+                self.expression(&if_expr.condition);
+                self.output.push(' ');
+                self.expression(&if_expr.consequence);
+                if let Some(alternative) = &if_expr.alternative {
+                    self.output.push(' ');
+                    self.expression(alternative);
+                }
+                self.output.push(')');
+            }
+            Expression::Tuple(elements) => self.list("tuple", elements),
+            Expression::ExtractTupleField(tuple, index) => {
+                self.output.push_str(&format!("(field {index} "));
+                self.expression(tuple);
+                self.output.push(')');
+            }
+            Expression::Call(call) => {
+                self.output.push_str(&format!("(call {} ", print_type(&call.return_type)));
+                self.expression(&call.func);
+                for argument in &call.arguments {
+                    self.output.push(' ');
+                    self.expression(argument);
+                }
+                self.output.push(')');
+            }
+            Expression::Let(let_expr) => {
+                self.output.push_str(&format!(
+                    "(let {} {:?} {} ",
+                    self.local_id(let_expr.id),
+                    let_expr.name,
+                    mutability(let_expr.mutable)
+                ));
+                self.expression(&let_expr.expression);
+                self.output.push(')');
+            }
+            Expression::Constrain(condition, _, message) => {
+                self.output.push_str("(constrain ");
+                self.expression(condition);
+                if let Some(message) = message {
+                    let (message, message_type) = message.as_ref();
+                    self.output.push(' ');
+                    self.expression(message);
+                    let message_type = Monomorphizer::convert_type(message_type, Location::dummy())
+                        .expect("Assert message types should be known after monomorphization");
+                    self.output.push_str(&format!(" {}", print_type(&message_type)));
+                }
+                self.output.push(')');
+            }
+            Expression::Assign(assign) => {
+                self.output.push_str("(assign ");
+                self.lvalue(&assign.lvalue);
+                self.output.push(' ');
+                self.expression(&assign.expression);
+                self.output.push(')');
+            }
+            Expression::Semi(expression) => {
+                self.output.push_str("(semi ");
+                self.expression(expression);
+                self.output.push(')');
+            }
+            Expression::Break => self.output.push_str("break"),
+            Expression::Continue => self.output.push_str("continue"),
+        }
+    }
+
+    fn literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::Array(array) => self.array("array", array),
+            Literal::Slice(array) => self.array("slice", array),
+            Literal::Integer(value, typ, ..) => {
+                self.output.push_str(&format!("(int {} {})", print_field(*value), print_type(typ)));
+            }
+            Literal::Bool(value) => self.output.push_str(&value.to_string()),
+            Literal::Unit => self.output.push_str("unit"),
+            Literal::Str(string) => self.output.push_str(&format!("(str {string:?})")),
+            Literal::FmtStr(string, length, fields) => {
+                self.output.push_str(&format!("(fmtstr {string:?} {length} "));
+                self.expression(fields);
+                self.output.push(')');
+            }
+        }
+    }
+
+    fn array(&mut self, tag: &str, array: &ArrayLiteral) {
+        self.output.push_str(&format!("({tag} {}", print_type(&array.typ)));
+        for element in &array.contents {
+            self.output.push(' ');
+            self.expression(element);
+        }
+        self.output.push(')');
+    }
+
+    fn list(&mut self, tag: &str, expressions: &[Expression]) {
+        self.output.push_str(&format!("({tag}"));
+        for expression in expressions {
+            self.output.push(' ');
+            self.expression(expression);
+        }
+        self.output.push(')');
+    }
+
+    fn lvalue(&mut self, lvalue: &LValue) {
+        match lvalue {
+            LValue::Ident(ident) => self.output.push_str(&self.ident(ident)),
+            LValue::Index { array, index, element_type, location: _ } => {
+                self.output.push_str(&format!("(index {} ", print_type(element_type)));
+                self.lvalue(array);
+                self.output.push(' ');
+                self.expression(index);
+                self.output.push(')');
+            }
+            LValue::MemberAccess { object, field_index } => {
+                self.output.push_str(&format!("(member {field_index} "));
+                self.lvalue(object);
+                self.output.push(')');
+            }
+            LValue::Dereference { reference, element_type } => {
+                self.output.push_str(&format!("(deref {} ", print_type(element_type)));
+                self.lvalue(reference);
+                self.output.push(')');
+            }
+        }
+    }
+
+    fn newline(&mut self) {
+        self.output.push('\n');
+        self.output.push_str(&"  ".repeat(self.indent));
+    }
+
+    fn ident(&self, ident: &Ident) -> String {
+        let definition = match &ident.definition {
+            Definition::Local(id) if ident.kind == DefinitionKind::Parameter => {
+                format!("(param {})", self.local_id(*id))
+            }
+            Definition::Local(id) => format!("(local {})", self.local_id(*id)),
+            Definition::Function(id) => format!("(function {})", self.function_id(*id)),
+            Definition::Builtin(name) => format!("(builtin {name:?})"),
+            Definition::LowLevel(name) => format!("(lowlevel {name:?})"),
+            Definition::Oracle(name) => format!("(oracle {name:?})"),
+        };
+        format!(
+            "(ident {:?} {definition} {} {})",
+            ident.name,
+            mutability(ident.mutable),
+            print_type(&ident.typ)
+        )
+    }
+
+    /// The quoted stable name of a local, or its numeric id if it has none.
+    fn local_id(&self, id: LocalId) -> String {
+        match self.names.local(id) {
+            Some(name) => format!("{name:?}"),
+            None => id.0.to_string(),
+        }
+    }
+
+    /// The quoted stable name of a function, or its numeric id if it has none.
+    fn function_id(&self, id: FuncId) -> String {
+        match self.names.function(id) {
+            Some(name) => format!("{name:?}"),
+            None => id.0.to_string(),
+        }
+    }
+}
+
+/// Only `main` and the other entry points keep their original signature, as these are the
+/// only signatures read by later passes.
+fn has_entry_point_signature(function: &Function) -> bool {
+    function.id == Program::main_id() || function.inline_type.is_entry_point()
+}
+
+fn print_signature((parameters, return_type): &FunctionSignature) -> String {
+    let parameters = vecmap(parameters, |(_, typ, visibility)| {
+        format!(" ({visibility} {})", print_type(&convert_signature_type(typ)))
+    });
+    let return_type = return_type
+        .as_ref()
+        .map(|typ| format!(" (return {})", print_type(&convert_signature_type(typ))))
+        .unwrap_or_default();
+    format!("(signature (params{}){return_type})", parameters.concat())
+}
+
+fn convert_signature_type(typ: &HirType) -> Type {
+    Monomorphizer::convert_type(typ, Location::dummy())
+        .expect("Entry point signatures should be fully known after monomorphization")
+}
+
+fn print_type(typ: &Type) -> String {
+    match typ {
+        Type::Field => "Field".to_string(),
+        Type::Array(length, element) => format!("(array {length} {})", print_type(element)),
+        Type::Integer(Signedness::Unsigned, bits) => format!("u{bits}"),
+        Type::Integer(Signedness::Signed, bits) => format!("i{bits}"),
+        Type::Bool => "bool".to_string(),
+        Type::String(length) => format!("(str {length})"),
+        Type::FmtString(length, fields) => format!("(fmtstr {length} {})", print_type(fields)),
+        Type::Unit => "unit".to_string(),
+        Type::Tuple(elements) => {
+            let elements = vecmap(elements, |element| format!(" {}", print_type(element)));
+            format!("(tuple{})", elements.concat())
+        }
+        Type::Slice(element) => format!("(slice {})", print_type(element)),
+        Type::MutableReference(element) => format!("(ref {})", print_type(element)),
+        Type::Function(arguments, return_type, env) => {
+            let arguments = vecmap(arguments, print_type).join(" ");
+            format!("(fn ({arguments}) {} {})", print_type(return_type), print_type(env))
+        }
+    }
+}
+
+fn print_unary_operator(operator: UnaryOp) -> &'static str {
+    match operator {
+        UnaryOp::Minus => "-",
+        UnaryOp::Not => "!",
+        UnaryOp::MutableReference => "&mut",
+        UnaryOp::Dereference { implicitly_added: false } => "*",
+        UnaryOp::Dereference { implicitly_added: true } => "*implicit",
+    }
+}
+
+/// Integers which fit in a u128 are written in decimal to keep hand-written programs readable.
+fn print_field(value: FieldElement) -> String {
+    match value.try_into_u128() {
+        Some(value) => value.to_string(),
+        None => format!("0x{}", value.to_hex()),
+    }
+}
+
+fn mutability(mutable: bool) -> &'static str {
+    if mutable {
+        "mut"
+    } else {
+        "imm"
+    }
+}
+
+#[derive(Clone)]
+enum SExpr {
+    Atom(String, usize),
+    Str(String, usize),
+    List(Vec<SExpr>, usize),
+}
+
+impl SExpr {
+    fn position(&self) -> usize {
+        match self {
+            SExpr::Atom(_, position) | SExpr::Str(_, position) | SExpr::List(_, position) => {
+                *position
+            }
+        }
+    }
+}
+
+type ParseResult<T> = Result<T, TextParseError>;
+
+fn error<T>(position: usize, message: impl Into<String>) -> ParseResult<T> {
+    Err(TextParseError { message: message.into(), position })
+}
+
+struct Reader<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(source: &'a str) -> Self {
+        Reader { source, position: 0 }
+    }
+
+    fn read_document(&mut self) -> ParseResult<SExpr> {
+        let sexpr = self.read()?;
+        self.skip_whitespace();
+        if self.position != self.source.len() {
+            return error(self.position, "Unexpected input after the program");
+        }
+        Ok(sexpr)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let next = self.peek()?;
+        self.position += next.len_utf8();
+        Some(next)
+    }
+
+    /// Skips whitespace and `;` line comments.
+    fn skip_whitespace(&mut self) {
+        while let Some(next) = self.peek() {
+            if next == ';' {
+                while self.peek().is_some_and(|next| next != '\n') {
+                    self.bump();
+                }
+            } else if next.is_whitespace() {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn read(&mut self) -> ParseResult<SExpr> {
+        self.skip_whitespace();
+        let start = self.position;
+        match self.peek() {
+            None => error(start, "Unexpected end of input"),
+            Some('(') => {
+                self.bump();
+                let mut elements = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(')') => {
+                            self.bump();
+                            return Ok(SExpr::List(elements, start));
+                        }
+                        None => return error(start, "Unclosed `(`"),
+                        Some(_) => elements.push(self.read()?),
+                    }
+                }
+            }
+            Some(')') => error(start, "Unexpected `)`"),
+            Some('"') => {
+                self.bump();
+                self.read_string(start).map(|string| SExpr::Str(string, start))
+            }
+            Some(_) => {
+                while self.peek().is_some_and(|next| {
+                    !next.is_whitespace() && !matches!(next, '(' | ')' | '"' | ';')
+                }) {
+                    self.bump();
+                }
+                Ok(SExpr::Atom(self.source[start..self.position].to_string(), start))
+            }
+        }
+    }
+
+    /// Reads the rest of a string literal written with Rust's debug escaping.
+    fn read_string(&mut self, start: usize) -> ParseResult<String> {
+        let mut string = String::new();
+        loop {
+            let escape_start = self.position;
+            match self.bump() {
+                None => return error(start, "Unterminated string"),
+                Some('"') => return Ok(string),
+                Some('\\') => {
+                    let escaped = match self.bump() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('0') => '\0',
+                        Some(char @ ('\\' | '"' | '\'')) => char,
+                        Some('u') => self.read_unicode_escape(escape_start)?,
+                        _ => return error(escape_start, "Invalid escape sequence"),
+                    };
+                    string.push(escaped);
+                }
+                Some(char) => string.push(char),
+            }
+        }
+    }
+
+    fn read_unicode_escape(&mut self, escape_start: usize) -> ParseResult<char> {
+        if self.bump() != Some('{') {
+            return error(escape_start, "Invalid unicode escape");
+        }
+        let digits_start = self.position;
+        while self.peek().is_some_and(|next| next != '}') {
+            self.bump();
+        }
+        let digits = &self.source[digits_start..self.position];
+        if self.bump() != Some('}') {
+            return error(escape_start, "Invalid unicode escape");
+        }
+        match u32::from_str_radix(digits, 16).ok().and_then(char::from_u32) {
+            Some(char) => Ok(char),
+            None => error(escape_start, "Invalid unicode escape"),
+        }
+    }
+}
+
+fn atom<'a>(sexpr: &'a SExpr, expected: &str) -> ParseResult<&'a str> {
+    match sexpr {
+        SExpr::Atom(atom, _) => Ok(atom),
+        _ => error(sexpr.position(), format!("Expected {expected}")),
+    }
+}
+
+fn string(sexpr: &SExpr, expected: &str) -> ParseResult<String> {
+    match sexpr {
+        SExpr::Str(string, _) => Ok(string.clone()),
+        _ => error(sexpr.position(), format!("Expected {expected} string")),
+    }
+}
+
+fn number<T: std::str::FromStr>(sexpr: &SExpr, expected: &str) -> ParseResult<T> {
+    let text = atom(sexpr, expected)?;
+    text.parse().or_else(|_| error(sexpr.position(), format!("Expected {expected}")))
+}
+
+fn boolean(sexpr: &SExpr) -> ParseResult<bool> {
+    match atom(sexpr, "`true` or `false`")? {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => error(sexpr.position(), "Expected `true` or `false`"),
+    }
+}
+
+fn mutable(sexpr: &SExpr) -> ParseResult<bool> {
+    match atom(sexpr, "`mut` or `imm`")? {
+        "mut" => Ok(true),
+        "imm" => Ok(false),
+        _ => error(sexpr.position(), "Expected `mut` or `imm`"),
+    }
+}
+
+/// Splits a list into its leading tag atom and the remaining elements.
+fn tagged(sexpr: &SExpr) -> ParseResult<Option<(&str, &[SExpr])>> {
+    match sexpr {
+        SExpr::List(elements, position) => match elements.split_first() {
+            Some((SExpr::Atom(tag, _), rest)) => Ok(Some((tag, rest))),
+            _ => error(*position, "Expected a list starting with a tag"),
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Expects a list of the form `(tag elements...)` and returns its elements.
+fn expect_tagged<'a>(sexpr: &'a SExpr, tag: &str) -> ParseResult<&'a [SExpr]> {
+    match tagged(sexpr)? {
+        Some((found, rest)) if found == tag => Ok(rest),
+        _ => error(sexpr.position(), format!("Expected `({tag} ...)`")),
+    }
+}
+
+fn arguments<'a, const N: usize>(
+    sexpr: &SExpr,
+    tag: &str,
+    rest: &'a [SExpr],
+) -> ParseResult<&'a [SExpr; N]> {
+    rest.try_into().or_else(|_| {
+        error(
+            sexpr.position(),
+            format!("`{tag}` expects {N} arguments but {} were given", rest.len()),
+        )
+    })
+}
+
+fn program_from_sexpr(sexpr: &SExpr) -> ParseResult<Program> {
+    let rest = expect_tagged(sexpr, "program")?;
+    let [return_visibility, recursive, functions @ ..] = rest else {
+        return error(sexpr.position(), "Expected the program's return visibility and recursion");
+    };
+
+    let [visibility] = arguments(
+        return_visibility,
+        "return-visibility",
+        expect_tagged(return_visibility, "return-visibility")?,
+    )?;
+    let return_visibility = parse_visibility(visibility)?;
+    let [flag] = arguments(recursive, "recursive", expect_tagged(recursive, "recursive")?)?;
+    let recursive = boolean(flag)?;
+
+    let functions = resolve_stable_names(functions)?;
+    let functions = try_vecmap(&functions, function_from_sexpr)?;
+    let Some(main) = functions.first().filter(|main| main.id == Program::main_id()) else {
+        return error(sexpr.position(), "Expected `main` to be the first function");
+    };
+    let main_function_signature = main.func_sig.clone();
+    let function_signatures = functions
+        .iter()
+        .filter(|function| has_entry_point_signature(function))
+        .map(|function| function.func_sig.clone())
+        .collect();
+
+    Ok(Program::new(
+        functions,
+        function_signatures,
+        main_function_signature,
+        None,
+        return_visibility,
+        recursive,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    ))
+}
+
+/// Replaces the stable names of locals and functions with numeric ids, so that the rest of the
+/// parser only has to deal with the latter.
+///
+/// A function named by its stable name gets its position in the program as its id, and the locals
+/// named by their stable names in each function are numbered in the order they first appear.
+fn resolve_stable_names(functions: &[SExpr]) -> ParseResult<Vec<SExpr>> {
+    let mut function_ids = HashMap::new();
+    for (index, function) in functions.iter().enumerate() {
+        if let Some(("fn", [SExpr::Str(name, _), ..])) = tagged(function)? {
+            function_ids.insert(name.clone(), index);
+        }
+    }
+    try_vecmap(functions, |function| resolve_names_in(function, &function_ids, &mut HashMap::new()))
+}
+
+fn resolve_names_in(
+    sexpr: &SExpr,
+    function_ids: &HashMap<String, usize>,
+    local_ids: &mut HashMap<String, usize>,
+) -> ParseResult<SExpr> {
+    let SExpr::List(elements, position) = sexpr else {
+        return Ok(sexpr.clone());
+    };
+    let id = match elements.as_slice() {
+        [SExpr::Atom(tag, _), SExpr::Str(name, name_position), ..] => match tag.as_str() {
+            "fn" | "function" => match function_ids.get(name) {
+                Some(id) => Some((*id, *name_position)),
+                None => return error(*name_position, format!("Unknown function `{name}`")),
+            },
+            "param" | "local" | "let" | "for" => {
+                let next_id = local_ids.len();
+                Some((*local_ids.entry(name.clone()).or_insert(next_id), *name_position))
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let mut elements =
+        try_vecmap(elements, |element| resolve_names_in(element, function_ids, local_ids))?;
+    if let Some((id, name_position)) = id {
+        elements[1] = SExpr::Atom(id.to_string(), name_position);
+    }
+    Ok(SExpr::List(elements, *position))
+}
+
+fn function_from_sexpr(sexpr: &SExpr) -> ParseResult<Function> {
+    let rest = expect_tagged(sexpr, "fn")?;
+    let mut rest: Vec<&SExpr> = rest.iter().collect();
+    let overflow_checks = !matches!(rest.get(4), Some(SExpr::Atom(atom, _)) if atom == "unchecked");
+    if !overflow_checks {
+        rest.remove(4);
+    }
+    let signature = match rest.as_slice() {
+        [_, _, _, _, _, _, signature, _] => Some(*signature),
+        [_, _, _, _, _, _, _] => None,
+        _ => return error(sexpr.position(), "Malformed function"),
+    };
+
+    let id = FuncId(number(rest[0], "function id")?);
+    let name = string(rest[1], "function name")?;
+    let unconstrained = match atom(rest[2], "`constrained` or `unconstrained`")? {
+        "constrained" => false,
+        "unconstrained" => true,
+        _ => return error(rest[2].position(), "Expected `constrained` or `unconstrained`"),
+    };
+    let inline_type = match atom(rest[3], "inline type")? {
+        "inline" => InlineType::Inline,
+        "fold" => InlineType::Fold,
+        "no_predicates" => InlineType::NoPredicates,
+        "inline_always" => InlineType::InlineAlways,
+        _ => return error(rest[3].position(), "Unknown inline type"),
+    };
+
+    let parameters = try_vecmap(expect_tagged(rest[4], "params")?, |parameter| {
+        let rest = expect_tagged(parameter, "param")?;
+        let [id, mutable_flag, name, typ] = arguments(parameter, "param", rest)?;
+        Ok((
+            LocalId(number(id, "local id")?),
+            mutable(mutable_flag)?,
+            string(name, "parameter name")?,
+            type_from_sexpr(typ)?,
+        ))
+    })?;
+
+    let [return_type] = arguments(rest[5], "return", expect_tagged(rest[5], "return")?)?;
+    let return_type = type_from_sexpr(return_type)?;
+
+    let func_sig = match signature {
+        Some(signature) => signature_from_sexpr(signature)?,
+        None => FunctionSignature::default(),
+    };
+    let body = expression_from_sexpr(rest.last().expect("Function arity was checked above"))?;
+
+    Ok(Function {
+        id,
+        name,
+        parameters,
+        body,
+        return_type,
+        unconstrained,
+        inline_type,
+        func_sig,
+        overflow_checks,
+    })
+}
+
+fn signature_from_sexpr(sexpr: &SExpr) -> ParseResult<FunctionSignature> {
+    let rest = expect_tagged(sexpr, "signature")?;
+    let (parameters, return_type) = match rest {
+        [parameters] => (parameters, None),
+        [parameters, return_type] => (parameters, Some(return_type)),
+        _ => return error(sexpr.position(), "Malformed signature"),
+    };
+
+    let parameters = try_vecmap(expect_tagged(parameters, "params")?, |parameter| {
+        let SExpr::List(elements, _) = parameter else {
+            return error(parameter.position(), "Expected `(<visibility> <type>)`");
+        };
+        let [visibility, typ] = arguments(parameter, "parameter", elements)?;
+        let visibility = parse_visibility(visibility)?;
+        let pattern = HirPattern::Identifier(HirIdent::non_trait_method(
+            DefinitionId::dummy_id(),
+            Location::dummy(),
+        ));
+        Ok::<Param, _>((pattern, to_hir_type(&type_from_sexpr(typ)?), visibility))
+    })?;
+
+    let return_type = match return_type {
+        Some(return_type) => {
+            let [typ] = arguments(return_type, "return", expect_tagged(return_type, "return")?)?;
+            Some(to_hir_type(&type_from_sexpr(typ)?))
+        }
+        None => None,
+    };
+    Ok((parameters, return_type))
+}
+
+fn parse_visibility(sexpr: &SExpr) -> ParseResult<Visibility> {
+    match atom(sexpr, "visibility")? {
+        "pub" => Ok(Visibility::Public),
+        "priv" => Ok(Visibility::Private),
+        "databus" => Ok(Visibility::DataBus),
+        _ => error(sexpr.position(), "Unknown visibility"),
+    }
+}
+
+fn type_from_sexpr(sexpr: &SExpr) -> ParseResult<Type> {
+    let Some((tag, rest)) = tagged(sexpr)? else {
+        let name = atom(sexpr, "type")?;
+        return match name {
+            "Field" => Ok(Type::Field),
+            "bool" => Ok(Type::Bool),
+            "unit" => Ok(Type::Unit),
+            _ => integer_type(name).map_or_else(|| error(sexpr.position(), "Unknown type"), Ok),
+        };
+    };
+
+    Ok(match tag {
+        "array" => {
+            let [length, element] = arguments(sexpr, tag, rest)?;
+            Type::Array(number(length, "array length")?, Box::new(type_from_sexpr(element)?))
+        }
+        "slice" => {
+            let [element] = arguments(sexpr, tag, rest)?;
+            Type::Slice(Box::new(type_from_sexpr(element)?))
+        }
+        "str" => {
+            let [length] = arguments(sexpr, tag, rest)?;
+            Type::String(number(length, "string length")?)
+        }
+        "fmtstr" => {
+            let [length, fields] = arguments(sexpr, tag, rest)?;
+            Type::FmtString(number(length, "string length")?, Box::new(type_from_sexpr(fields)?))
+        }
+        "tuple" => Type::Tuple(try_vecmap(rest, type_from_sexpr)?),
+        "ref" => {
+            let [element] = arguments(sexpr, tag, rest)?;
+            Type::MutableReference(Box::new(type_from_sexpr(element)?))
+        }
+        "fn" => {
+            let [arguments_list, return_type, env] = arguments(sexpr, tag, rest)?;
+            let SExpr::List(argument_types, _) = arguments_list else {
+                return error(arguments_list.position(), "Expected a list of argument types");
+            };
+            Type::Function(
+                try_vecmap(argument_types, type_from_sexpr)?,
+                Box::new(type_from_sexpr(return_type)?),
+                Box::new(type_from_sexpr(env)?),
+            )
+        }
+        _ => return error(sexpr.position(), format!("Unknown type `{tag}`")),
+    })
+}
+
+fn integer_type(name: &str) -> Option<Type> {
+    let (signedness, bits) = if let Some(bits) = name.strip_prefix('u') {
+        (Signedness::Unsigned, bits)
+    } else {
+        (Signedness::Signed, name.strip_prefix('i')?)
+    };
+    let bits = IntegerBitSize::try_from(bits.parse::<u32>().ok()?).ok()?;
+    Some(Type::Integer(signedness, bits))
+}
+
+/// Recovers a frontend type for a monomorphized type. Structs have already been replaced
+/// with tuples so this is only used where a type's shape is all that matters.
+fn to_hir_type(typ: &Type) -> HirType {
+    match typ {
+        Type::Field => HirType::FieldElement,
+        Type::Array(length, element) => {
+            HirType::Array(Box::new(HirType::Constant(*length)), Box::new(to_hir_type(element)))
+        }
+        Type::Integer(signedness, bits) => HirType::Integer(*signedness, *bits),
+        Type::Bool => HirType::Bool,
+        Type::String(length) => HirType::String(Box::new(HirType::Constant(*length))),
+        Type::FmtString(length, fields) => {
+            HirType::FmtString(Box::new(HirType::Constant(*length)), Box::new(to_hir_type(fields)))
+        }
+        Type::Unit => HirType::Unit,
+        Type::Tuple(elements) => HirType::Tuple(vecmap(elements, to_hir_type)),
+        Type::Slice(element) => HirType::Slice(Box::new(to_hir_type(element))),
+        Type::MutableReference(element) => {
+            HirType::MutableReference(Box::new(to_hir_type(element)))
+        }
+        Type::Function(arguments, return_type, env) => HirType::Function(
+            vecmap(arguments, to_hir_type),
+            Box::new(to_hir_type(return_type)),
+            Box::new(to_hir_type(env)),
+        ),
+    }
+}
+
+fn ident_from_sexpr(sexpr: &SExpr, rest: &[SExpr]) -> ParseResult<Ident> {
+    let [name, definition, mutable_flag, typ] = arguments(sexpr, "ident", rest)?;
+    let Some((kind, definition_rest)) = tagged(definition)? else {
+        return error(definition.position(), "Expected a definition");
+    };
+    let [value] = arguments(definition, kind, definition_rest)?;
+    let local = |value: &SExpr| number(value, "local id").map(|id| Definition::Local(LocalId(id)));
+    let (definition, kind) = match kind {
+        "param" => (local(value)?, DefinitionKind::Parameter),
+        "local" => (local(value)?, DefinitionKind::Local),
+        "function" => {
+            (Definition::Function(FuncId(number(value, "function id")?)), DefinitionKind::Function)
+        }
+        "builtin" => {
+            (Definition::Builtin(string(value, "builtin name")?), DefinitionKind::Function)
+        }
+        "lowlevel" => (
+            Definition::LowLevel(string(value, "low level function name")?),
+            DefinitionKind::Function,
+        ),
+        "oracle" => (Definition::Oracle(string(value, "oracle name")?), DefinitionKind::Function),
+        _ => return error(definition.position(), format!("Unknown definition kind `{kind}`")),
+    };
+    Ok(Ident {
+        location: None,
+        definition,
+        kind,
+        mutable: mutable(mutable_flag)?,
+        name: string(name, "identifier name")?,
+        typ: type_from_sexpr(typ)?,
+    })
+}
+
+fn boxed(sexpr: &SExpr) -> ParseResult<Box<Expression>> {
+    expression_from_sexpr(sexpr).map(Box::new)
+}
+
+fn expression_from_sexpr(sexpr: &SExpr) -> ParseResult<Expression> {
+    let Some((tag, rest)) = tagged(sexpr)? else {
+        return match atom(sexpr, "expression")? {
+            "true" => Ok(Expression::Literal(Literal::Bool(true))),
+            "false" => Ok(Expression::Literal(Literal::Bool(false))),
+            "unit" => Ok(Expression::Literal(Literal::Unit)),
+            "break" => Ok(Expression::Break),
+            "continue" => Ok(Expression::Continue),
+            _ => error(sexpr.position(), "Unknown expression"),
+        };
+    };
+    let location = Location::dummy();
+
+    Ok(match tag {
+        "ident" => Expression::Ident(ident_from_sexpr(sexpr, rest)?),
+        "int" => {
+            let [value, typ] = arguments(sexpr, tag, rest)?;
+            let text = atom(value, "integer")?;
+            let Some(value) = FieldElement::try_from_str(text) else {
+                return error(value.position(), "Invalid integer");
+            };
+            let typ = type_from_sexpr(typ)?;
+            Expression::Literal(Literal::Integer(value, typ, location, LiteralRepr::Decimal))
+        }
+        "str" => {
+            let [value] = arguments(sexpr, tag, rest)?;
+            Expression::Literal(Literal::Str(string(value, "string literal")?))
+        }
+        "fmtstr" => {
+            let [value, length, fields] = arguments(sexpr, tag, rest)?;
+            Expression::Literal(Literal::FmtStr(
+                string(value, "format string")?,
+                number(length, "field count")?,
+                boxed(fields)?,
+            ))
+        }
+        "array" | "slice" => {
+            let Some((typ, contents)) = rest.split_first() else {
+                return error(sexpr.position(), format!("`{tag}` expects a type"));
+            };
+            let array = ArrayLiteral {
+                contents: try_vecmap(contents, expression_from_sexpr)?,
+                typ: type_from_sexpr(typ)?,
+            };
+            if tag == "array" {
+                Expression::Literal(Literal::Array(array))
+            } else {
+                Expression::Literal(Literal::Slice(array))
+            }
+        }
+        "block" => Expression::Block(try_vecmap(rest, expression_from_sexpr)?),
+        "unary" => {
+            let [operator, result_type, rhs] = arguments(sexpr, tag, rest)?;
+            let operator = match atom(operator, "unary operator")? {
+                "-" => UnaryOp::Minus,
+                "!" => UnaryOp::Not,
+                "&mut" => UnaryOp::MutableReference,
+                "*" => UnaryOp::Dereference { implicitly_added: false },
+                "*implicit" => UnaryOp::Dereference { implicitly_added: true },
+                _ => return error(operator.position(), "Unknown unary operator"),
+            };
+            Expression::Unary(Unary {
+                operator,
+                rhs: boxed(rhs)?,
+                result_type: type_from_sexpr(result_type)?,
+                location,
+            })
+        }
+        "binary" => {
+            let [operator, lhs, rhs] = arguments(sexpr, tag, rest)?;
+            let operator = binary_operator(operator)?;
+            Expression::Binary(Binary { lhs: boxed(lhs)?, operator, rhs: boxed(rhs)?, location })
+        }
+        "index" => {
+            let [element_type, collection, index] = arguments(sexpr, tag, rest)?;
+            Expression::Index(Index {
+                collection: boxed(collection)?,
+                index: boxed(index)?,
+                element_type: type_from_sexpr(element_type)?,
+                location,
+            })
+        }
+        "cast" | "checked-cast" => {
+            let [from, typ, lhs] = arguments(sexpr, tag, rest)?;
+            Expression::Cast(Cast {
+                lhs: boxed(lhs)?,
+                from: type_from_sexpr(from)?,
+                r#type: type_from_sexpr(typ)?,
+                checked: tag == "checked-cast",
+                location,
+            })
+        }
+        "for" => {
+            let [index_variable, index_name, index_type, start, end, block] =
+                arguments(sexpr, tag, rest)?;
+            Expression::For(For {
+                index_variable: LocalId(number(index_variable, "local id")?),
+                index_name: string(index_name, "loop index name")?,
+                index_type: type_from_sexpr(index_type)?,
+                start_range: boxed(start)?,
+                end_range: boxed(end)?,
+                block: boxed(block)?,
+                start_range_location: location,
+                end_range_location: location,
+            })
+        }
+        "while" => {
+            let [condition, block] = arguments(sexpr, tag, rest)?;
+            Expression::While(While { condition: boxed(condition)?, block: boxed(block)? })
+        }
+        "if" => {
+            let (typ, condition, consequence, alternative) = match rest {
+                [typ, condition, consequence] => (typ, condition, consequence, None),
+                [typ, condition, consequence, alternative] => {
+                    (typ, condition, consequence, Some(boxed(alternative)?))
+                }
+                _ => return error(sexpr.position(), "`if` expects 3 or 4 arguments"),
+            };
+            Expression::If(If {
+                condition: boxed(condition)?,
+                consequence: boxed(consequence)?,
+                alternative,
+                typ: type_from_sexpr(typ)?,
+            })
+        }
+        "tuple" => Expression::Tuple(try_vecmap(rest, expression_from_sexpr)?),
+        "field" => {
+            let [index, tuple] = arguments(sexpr, tag, rest)?;
+            Expression::ExtractTupleField(boxed(tuple)?, number(index, "field index")?)
+        }
+        "call" => {
+            let [return_type, func, arguments @ ..] = rest else {
+                return error(sexpr.position(), "`call` expects a return type and a function");
+            };
+            Expression::Call(Call {
+                func: boxed(func)?,
+                arguments: try_vecmap(arguments, expression_from_sexpr)?,
+                return_type: type_from_sexpr(return_type)?,
+                location,
+            })
+        }
+        "let" => {
+            let [id, name, mutable_flag, expression] = arguments(sexpr, tag, rest)?;
+            Expression::Let(Let {
+                id: LocalId(number(id, "local id")?),
+                mutable: mutable(mutable_flag)?,
+                name: string(name, "variable name")?,
+                expression: boxed(expression)?,
+            })
+        }
+        "constrain" => match rest {
+            [condition] => Expression::Constrain(boxed(condition)?, location, None),
+            [condition, message, message_type] => {
+                let message_type = to_hir_type(&type_from_sexpr(message_type)?);
+                let message = Box::new((expression_from_sexpr(message)?, message_type));
+                Expression::Constrain(boxed(condition)?, location, Some(message))
+            }
+            _ => return error(sexpr.position(), "`constrain` expects 1 or 3 arguments"),
+        },
+        "assign" => {
+            let [lvalue, expression] = arguments(sexpr, tag, rest)?;
+            Expression::Assign(Assign {
+                lvalue: lvalue_from_sexpr(lvalue)?,
+                expression: boxed(expression)?,
+                location,
+            })
+        }
+        "semi" => {
+            let [expression] = arguments(sexpr, tag, rest)?;
+            Expression::Semi(boxed(expression)?)
+        }
+        _ => return error(sexpr.position(), format!("Unknown expression `{tag}`")),
+    })
+}
+
+fn binary_operator(sexpr: &SExpr) -> ParseResult<BinaryOpKind> {
+    Ok(match atom(sexpr, "binary operator")? {
+        "+" => BinaryOpKind::Add,
+        "-" => BinaryOpKind::Subtract,
+        "*" => BinaryOpKind::Multiply,
+        "/" => BinaryOpKind::Divide,
+        "==" => BinaryOpKind::Equal,
+        "!=" => BinaryOpKind::NotEqual,
+        "<" => BinaryOpKind::Less,
+        "<=" => BinaryOpKind::LessEqual,
+        ">" => BinaryOpKind::Greater,
+        ">=" => BinaryOpKind::GreaterEqual,
+        "&" => BinaryOpKind::And,
+        "|" => BinaryOpKind::Or,
+        "^" => BinaryOpKind::Xor,
+        ">>" => BinaryOpKind::ShiftRight,
+        "<<" => BinaryOpKind::ShiftLeft,
+        "%" => BinaryOpKind::Modulo,
+        _ => return error(sexpr.position(), "Unknown binary operator"),
+    })
+}
+
+fn lvalue_from_sexpr(sexpr: &SExpr) -> ParseResult<LValue> {
+    let Some((tag, rest)) = tagged(sexpr)? else {
+        return error(sexpr.position(), "Expected an lvalue");
+    };
+    Ok(match tag {
+        "ident" => LValue::Ident(ident_from_sexpr(sexpr, rest)?),
+        "index" => {
+            let [element_type, array, index] = arguments(sexpr, tag, rest)?;
+            LValue::Index {
+                array: Box::new(lvalue_from_sexpr(array)?),
+                index: boxed(index)?,
+                element_type: type_from_sexpr(element_type)?,
+                location: Location::dummy(),
+            }
+        }
+        "member" => {
+            let [field_index, object] = arguments(sexpr, tag, rest)?;
+            LValue::MemberAccess {
+                object: Box::new(lvalue_from_sexpr(object)?),
+                field_index: number(field_index, "field index")?,
+            }
+        }
+        "deref" => {
+            let [element_type, reference] = arguments(sexpr, tag, rest)?;
+            LValue::Dereference {
+                reference: Box::new(lvalue_from_sexpr(reference)?),
+                element_type: type_from_sexpr(element_type)?,
+            }
+        }
+        _ => return error(sexpr.position(), format!("Unknown lvalue `{tag}`")),
+    })
+}
@@ -19,6 +19,7 @@ use crate::hir::def_map::{LocalModuleId, ModuleId};
 
 use crate::ast::{BinaryOpKind, FunctionDefinition, ItemVisibility};
 use crate::hir::resolution::errors::ResolverError;
+use crate::hir::resolution::usage_tracker::UsageTracker;
 use crate::hir_def::stmt::HirLetStatement;
 use crate::hir_def::traits::TraitImpl;
 use crate::hir_def::traits::{Trait, TraitConstraint};
@@ -171,6 +172,9 @@ pub struct NodeInterner {
 
     /// Stores the [Location] of a [Type] reference
     pub(crate) type_ref_locations: Vec<(Type, Location)>,
+
+    /// Tracks which imports and modules are referenced, to warn about unused ones
+    pub(crate) usage_tracker: UsageTracker,
 }
 
 /// A dependency in the dependency graph may be a type or a definition.
@@ -499,6 +503,7 @@ impl Default for NodeInterner {
             primitive_methods: HashMap::new(),
             type_alias_ref: Vec::new(),
             type_ref_locations: Vec::new(),
+            usage_tracker: UsageTracker::default(),
         };
 
         // An empty block expression is used often, we add this into the `node` on startup
@@ -839,6 +844,25 @@ impl NodeInterner {
         self.func_meta.get(func_id)
     }
 
+    /// Returns the spans of the parameters of the function which the expression `func` names,
+    /// or nothing if it doesn't name a function defined in `file`.
+    pub fn function_parameter_spans(&self, func: ExprId, file: FileId) -> Vec<Span> {
+        let HirExpression::Ident(ident, _) = self.expression(&func) else {
+            return Vec::new();
+        };
+        let Some(DefinitionKind::Function(func_id)) =
+            self.try_definition(ident.id).map(|definition| &definition.kind)
+        else {
+            return Vec::new();
+        };
+        match self.try_function_meta(func_id) {
+            Some(meta) if meta.location.file == file => {
+                vecmap(&meta.parameters.0, |(pattern, _, _)| pattern.span())
+            }
+            _ => Vec::new(),
+        }
+    }
+
     pub fn function_ident(&self, func_id: &FuncId) -> crate::ast::Ident {
         let name = self.function_name(func_id).to_owned();
         let span = self.function_meta(func_id).name.location.span;
@@ -19,13 +19,15 @@ pub enum ParserErrorReason {
     #[error("Expected a ; separating these two statements")]
     MissingSeparatingSemi,
     #[error("constrain keyword is deprecated")]
-    ConstrainDeprecated,
+    ConstrainDeprecated { condition: Span },
     #[error("Expression is invalid in an array-length type: '{0}'. Only unsigned integer constants, globals, generics, +, -, *, /, and % may be used in this context.")]
     InvalidArrayLengthExpression(Expression),
     #[error("Early 'return' is unsupported")]
     EarlyReturn,
     #[error("Patterns aren't allowed in a trait's function declarations")]
     PatternInTraitFunctionParameter,
+    #[error("Patterns aren't allowed as the index of a range loop")]
+    PatternInRangeLoop,
     #[error("Modifiers are ignored on a trait impl method")]
     TraitImplFunctionModifiers,
     #[error("comptime keyword is deprecated")]
@@ -34,16 +36,18 @@ pub enum ParserErrorReason {
     DistinctDeprecated,
     #[error("{0} are experimental and aren't fully supported yet")]
     ExperimentalFeature(&'static str),
-    #[error(
-        "Multiple primary attributes found. Only one function attribute is allowed per function"
-    )]
-    MultipleFunctionAttributesFound,
-    #[error("A function attribute cannot be placed on a struct")]
-    NoFunctionAttributesAllowedOnStruct,
+    #[error("Unknown attribute `{name}`")]
+    UnknownAttribute { name: String, suggestion: &'static str },
+    #[error("`{attribute}` cannot be applied to {item}")]
+    AttributeNotAllowed { attribute: String, item: &'static str },
+    #[error("`{attribute}` conflicts with `{previous}`")]
+    ConflictingAttributes { attribute: String, previous: String, previous_span: Span },
     #[error("Assert statements can only accept string literals")]
     AssertMessageNotString,
     #[error("Integer bit size {0} isn't supported")]
     InvalidBitSize(u32),
+    #[error("`=` is not an expression operator; did you mean `==`?")]
+    AssignmentInExpression,
     #[error("{0}")]
     Lexer(LexerErrorKind),
 }
@@ -105,7 +109,29 @@ impl ParserError {
     }
 
     pub fn is_warning(&self) -> bool {
-        matches!(self.reason(), Some(ParserErrorReason::ExperimentalFeature(_)))
+        match self.reason() {
+            Some(
+                ParserErrorReason::ExperimentalFeature(_)
+                | ParserErrorReason::ConstrainDeprecated { .. }
+                | ParserErrorReason::UnknownAttribute { .. },
+            ) => true,
+            Some(ParserErrorReason::Lexer(error)) => error.is_warning(),
+            _ => false,
+        }
+    }
+
+    /// Returns a machine-applicable fix for this error, if there is one.
+    ///
+    /// The fix is a list of edits, each replacing the source code within a span with the given text.
+    pub fn suggestion(&self) -> Option<Vec<(Span, String)>> {
+        match self.reason()? {
+            // `constrain E` is rewritten to `assert(E)`
+            ParserErrorReason::ConstrainDeprecated { condition } => Some(vec![
+                (Span::from(self.span.start()..condition.start()), "assert(".to_string()),
+                (Span::empty(condition.end()), ")".to_string()),
+            ]),
+            _ => None,
+        }
     }
 }
 
@@ -145,7 +171,7 @@ impl<'a> From<&'a ParserError> for Diagnostic {
         match &error.reason {
             Some(reason) => {
                 match reason {
-                    ParserErrorReason::ConstrainDeprecated => Diagnostic::simple_error(
+                    ParserErrorReason::ConstrainDeprecated { .. } => Diagnostic::simple_warning(
                         "Use of deprecated keyword 'constrain'".into(),
                         "The 'constrain' keyword is deprecated. Please use the 'assert' function instead.".into(),
                         error.span,
@@ -173,6 +199,25 @@ impl<'a> From<&'a ParserError> for Diagnostic {
                     ParserErrorReason::ExpectedPatternButFoundType(ty) => {
                         Diagnostic::simple_error("Expected a ; separating these two statements".into(), format!("{ty} is a type and cannot be used as a variable name"), error.span)
                     }
+                    ParserErrorReason::AssignmentInExpression => Diagnostic::simple_error(
+                        reason.to_string(),
+                        "Use `==` to compare two values. Assignments are only allowed as statements".into(),
+                        error.span,
+                    ),
+                    ParserErrorReason::UnknownAttribute { suggestion, .. } => {
+                        Diagnostic::simple_warning(
+                            reason.to_string(),
+                            format!("did you mean `#[{suggestion}]`?"),
+                            error.span,
+                        )
+                    }
+                    ParserErrorReason::ConflictingAttributes { previous, previous_span, .. } => {
+                        let mut diagnostic =
+                            Diagnostic::simple_error(reason.to_string(), String::new(), error.span);
+                        let message = format!("`{previous}` specified here");
+                        diagnostic.add_secondary(message, *previous_span);
+                        diagnostic
+                    }
                     ParserErrorReason::Lexer(error) => error.into(),
                     other => {
                         Diagnostic::simple_error(format!("{other}"), String::new(), error.span)
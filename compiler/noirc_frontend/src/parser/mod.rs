@@ -15,7 +15,7 @@ use crate::ast::{
     Expression, Ident, ImportStatement, LetStatement, ModuleDeclaration, NoirFunction, NoirStruct,
     NoirTrait, NoirTraitImpl, NoirTypeAlias, Recoverable, StatementKind, TypeImpl, UseTree,
 };
-use crate::token::{Keyword, Token};
+use crate::token::{Keyword, SecondaryAttribute, Token};
 
 use chumsky::prelude::*;
 use chumsky::primitive::Container;
@@ -36,6 +36,7 @@ pub(crate) enum TopLevelStatement {
     TypeAlias(NoirTypeAlias),
     SubModule(ParsedSubModule),
     Global(LetStatement),
+    InnerAttribute(SecondaryAttribute),
     Error,
 }
 
@@ -196,11 +197,53 @@ fn parameter_name_recovery<T: Recoverable + Clone>() -> impl NoirParser<T> {
     try_skip_until([Colon, RightParen, Comma], [RightParen, Comma])
 }
 
+/// Recovery strategy for top level statements: If a top level statement fails to parse, skip
+/// until the start of the next item (such as a `fn`, `use` or `mod`) or the end of the enclosing
+/// module. Braced blocks are skipped as a whole so that an item can't be mistaken to start inside
+/// of them. If a block is never closed, its opening brace is skipped on its own instead, so a
+/// missing `}` only produces a single error.
 fn top_level_statement_recovery() -> impl NoirParser<TopLevelStatement> {
-    none_of([Token::Semicolon, Token::RightBrace, Token::EOF])
-        .repeated()
-        .ignore_then(one_of([Token::Semicolon]))
-        .map(|_| TopLevelStatement::Error)
+    use Token::*;
+    let braced = recursive(|braced| {
+        braced
+            .or(none_of([LeftBrace, RightBrace, EOF]).ignored())
+            .repeated()
+            .delimited_by(just(LeftBrace), just(RightBrace))
+            .ignored()
+    });
+
+    // The statement which failed may itself start with any number of item keywords and attributes,
+    // e.g. `#[test] pub fn`, which are skipped before looking for the start of the next item.
+    let first = braced.clone().or(none_of([RightBrace, EOF]).ignored());
+    let item_start = filter(is_item_start).ignored();
+    let rest = braced
+        .or(filter(|token: &Token| !is_item_start(token) && !matches!(token, RightBrace | EOF))
+            .ignored());
+
+    first.then(item_start.repeated()).then(rest.repeated()).map(|_| TopLevelStatement::Error)
+}
+
+/// Returns true if `token` can begin a top level statement.
+fn is_item_start(token: &Token) -> bool {
+    match token {
+        Token::Keyword(keyword) => matches!(
+            keyword,
+            Keyword::Fn
+                | Keyword::Use
+                | Keyword::Mod
+                | Keyword::Struct
+                | Keyword::Trait
+                | Keyword::Impl
+                | Keyword::Global
+                | Keyword::Type
+                | Keyword::Contract
+                | Keyword::Pub
+                | Keyword::Unconstrained
+                | Keyword::Comptime
+        ),
+        Token::Attribute(_) | Token::InnerAttribute(_) => true,
+        _ => false,
+    }
 }
 
 /// Force the given parser to succeed, logging any errors it had
@@ -224,10 +267,17 @@ pub struct SortedModule {
 
     /// Full submodules as in `mod foo { ... definitions ... }`
     pub submodules: Vec<SortedSubModule>,
+
+    /// Attributes applying to the whole module like `#![allow(unused_imports)]`
+    pub inner_attributes: Vec<SecondaryAttribute>,
 }
 
 impl std::fmt::Display for SortedModule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for attribute in &self.inner_attributes {
+            writeln!(f, "{}", Token::InnerAttribute(attribute.clone()))?;
+        }
+
         for decl in &self.module_decls {
             writeln!(f, "{decl};")?;
         }
@@ -286,6 +336,7 @@ impl ParsedModule {
                 ItemKind::Global(global) => module.push_global(global),
                 ItemKind::ModuleDecl(mod_name) => module.push_module_decl(mod_name),
                 ItemKind::Submodules(submodule) => module.push_submodule(submodule.into_sorted()),
+                ItemKind::InnerAttribute(attribute) => module.inner_attributes.push(attribute),
             }
         }
 
@@ -311,6 +362,7 @@ pub enum ItemKind {
     Global(LetStatement),
     ModuleDecl(ModuleDeclaration),
     Submodules(ParsedSubModule),
+    InnerAttribute(SecondaryAttribute),
 }
 
 /// A submodule defined via `mod name { contents }` in some larger file.
@@ -393,12 +445,27 @@ impl SortedModule {
     }
 }
 
+/// The precedence of binary operators, from the loosest to the tightest binding:
+///
+/// | Precedence    | Operators                 |
+/// | ------------- | ------------------------- |
+/// | `Lowest`      | `==` `!=`                 |
+/// | `Or`          | `\|`                      |
+/// | `Xor`         | `^`                       |
+/// | `And`         | `&`                       |
+/// | `LessGreater` | `<` `<=` `>` `>=`         |
+/// | `Shift`       | `<<` `>>`                 |
+/// | `Sum`         | `+` `-`                   |
+/// | `Product`     | `*` `/` `%`               |
+///
+/// Unlike Rust, the bitwise operators bind more loosely than comparisons so that they can be used
+/// as non short-circuiting logical operators, e.g. `a < b | c < d` is `(a < b) | (c < d)`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd)]
 pub enum Precedence {
     Lowest,
     Or,
-    And,
     Xor,
+    And,
     LessGreater,
     Shift,
     Sum,
@@ -483,6 +550,7 @@ impl std::fmt::Display for TopLevelStatement {
             TopLevelStatement::TypeAlias(t) => t.fmt(f),
             TopLevelStatement::SubModule(s) => s.fmt(f),
             TopLevelStatement::Global(c) => c.fmt(f),
+            TopLevelStatement::InnerAttribute(a) => Token::InnerAttribute(a.clone()).fmt(f),
             TopLevelStatement::Error => write!(f, "error"),
         }
     }
@@ -33,10 +33,11 @@ use super::{
 };
 use super::{spanned, Item, ItemKind};
 use crate::ast::{
-    BinaryOp, BinaryOpKind, BlockExpression, ForLoopStatement, ForRange, Ident, IfExpression,
-    InfixExpression, LValue, Literal, ModuleDeclaration, NoirTypeAlias, Param, Path, Pattern,
-    Recoverable, Statement, TraitBound, TypeImpl, UnaryRhsMemberAccess, UnresolvedTraitConstraint,
-    UnresolvedTypeExpression, UseTree, UseTreeKind, Visibility,
+    BinaryOp, BinaryOpKind, BlockExpression, ForBounds, ForLoopStatement, ForRange, Ident,
+    IfExpression, InfixExpression, LValue, Literal, ModuleDeclaration, NoirTypeAlias, Param, Path,
+    Pattern, Recoverable, Statement, TraitBound, TypeImpl, UnaryRhsMemberAccess,
+    UnresolvedTraitConstraint, UnresolvedTypeExpression, UseTree, UseTreeKind, Visibility,
+    WhileStatement,
 };
 use crate::ast::{
     Expression, ExpressionKind, LetStatement, StatementKind, UnresolvedType, UnresolvedTypeData,
@@ -182,6 +183,7 @@ fn module() -> impl NoirParser<ParsedModule> {
                     TopLevelStatement::TypeAlias(t) => push_item(ItemKind::TypeAlias(t)),
                     TopLevelStatement::SubModule(s) => push_item(ItemKind::Submodules(s)),
                     TopLevelStatement::Global(c) => push_item(ItemKind::Global(c)),
+                    TopLevelStatement::InnerAttribute(a) => push_item(ItemKind::InnerAttribute(a)),
                     TopLevelStatement::Error => (),
                 }
                 program
@@ -197,6 +199,7 @@ fn module() -> impl NoirParser<ParsedModule> {
 ///                    | module_declaration
 ///                    | use_statement
 ///                    | global_declaration
+///                    | inner_attribute
 fn top_level_statement(
     module_parser: impl NoirParser<ParsedModule>,
 ) -> impl NoirParser<TopLevelStatement> {
@@ -212,6 +215,7 @@ fn top_level_statement(
         module_declaration().then_ignore(force(just(Token::Semicolon))),
         use_statement().then_ignore(force(just(Token::Semicolon))),
         global_declaration().then_ignore(force(just(Token::Semicolon))),
+        attributes::inner_attribute().map(TopLevelStatement::InnerAttribute),
     ))
     .recover_via(top_level_statement_recovery())
 }
@@ -243,9 +247,12 @@ fn global_declaration() -> impl NoirParser<TopLevelStatement> {
     let p = then_commit_ignore(p, just(Token::Assign));
     let p = then_commit(p, expression());
     p.validate(
-        |(((((attributes, comptime), mutable), mut pattern), r#type), expression), span, emit| {
-            let global_attributes =
-                attributes::validate_secondary_attributes(attributes, span, emit);
+        |(((((attributes, comptime), mutable), mut pattern), r#type), expression), _span, emit| {
+            let global_attributes = attributes::validate_secondary_attributes(
+                attributes,
+                attributes::AttributeTarget::Global,
+                emit,
+            );
 
             // Only comptime globals are allowed to be mutable, but we always parse the `mut`
             // and throw the error in name resolution.
@@ -497,6 +504,7 @@ where
             declaration(expr_parser.clone()),
             assignment(expr_parser.clone()),
             for_loop(expr_no_constructors.clone(), statement.clone()),
+            while_loop(expr_no_constructors.clone(), statement.clone()),
             break_statement(),
             continue_statement(),
             return_statement(expr_parser.clone()),
@@ -989,15 +997,43 @@ where
             allow_constructors,
         );
 
-        next_expr
+        let expr = next_expr
             .clone()
             .then(then_commit(operator_with_precedence(precedence), next_expr).repeated())
             .foldl(create_infix_expression)
-            .boxed()
-            .labelled(ParsingRuleLabel::Expression)
+            .boxed();
+
+        if precedence == Precedence::Lowest && !is_type_expression {
+            expr.clone()
+                .then(assignment_in_expression(expr).or_not())
+                .map(|(lhs, rhs)| match rhs {
+                    Some(rhs) => create_infix_expression(lhs, rhs),
+                    None => lhs,
+                })
+                .boxed()
+                .labelled(ParsingRuleLabel::Expression)
+        } else {
+            expr.labelled(ParsingRuleLabel::Expression)
+        }
     }
 }
 
+/// Parses an `=` following an expression, which is most likely a typo for `==`.
+///
+/// Assignments are statements which are parsed before any expression, so an `=` is never valid here.
+/// It is reported as an error and parsed as `==` so that checking the rest of the program can continue.
+fn assignment_in_expression<'a, P>(expr_parser: P) -> impl NoirParser<(BinaryOp, Expression)> + 'a
+where
+    P: ExprParser + 'a,
+{
+    just(Token::Assign)
+        .validate(|_, span, emit| {
+            emit(ParserError::with_reason(ParserErrorReason::AssignmentInExpression, span));
+            Spanned::from(span, BinaryOpKind::Equal)
+        })
+        .then(expr_parser)
+}
+
 fn create_infix_expression(lhs: Expression, (operator, rhs): (BinaryOp, Expression)) -> Expression {
     let span = lhs.span.merge(rhs.span);
     let infix = Box::new(InfixExpression { lhs, operator, rhs });
@@ -1127,10 +1163,12 @@ where
 {
     recursive(|if_parser| {
         let if_block = block_expr(statement.clone());
+        let else_block = keyword(Keyword::Else).ignore_then(block_expr(statement));
         // The else block could also be an `else if` block, in which case we must recursively parse it.
-        let else_block = block_expr(statement).or(if_parser.map_with_span(|kind, span| {
+        let else_if = keyword(Keyword::Else).ignore_then(if_parser).map_with_span(|kind, span| {
             // Wrap the inner `if` expression in a block expression.
             // i.e. rewrite the sugared form `if cond1 {} else if cond2 {}` as `if cond1 {} else { if cond2 {} }`.
+            // The span starts at `else` so errors in later branches point at the `else if` they belong to.
             let if_expression = Expression::new(kind, span);
             let desugared_else = BlockExpression {
                 statements: vec![Statement {
@@ -1139,12 +1177,12 @@ where
                 }],
             };
             Expression::new(ExpressionKind::Block(desugared_else), span)
-        }));
+        });
 
         keyword(Keyword::If)
             .ignore_then(expr_no_constructors)
             .then(if_block)
-            .then(keyword(Keyword::Else).ignore_then(else_block).or_not())
+            .then(else_if.or(else_block).or_not())
             .map(|((condition, consequence), alternative)| {
                 ExpressionKind::If(Box::new(IfExpression { condition, consequence, alternative }))
             })
@@ -1157,25 +1195,55 @@ where
     S: NoirParser<StatementKind> + 'a,
 {
     keyword(Keyword::For)
-        .ignore_then(ident())
+        .ignore_then(pattern())
         .then_ignore(keyword(Keyword::In))
         .then(for_range(expr_no_constructors))
+        .validate(|(pattern, range), _span, emit| match (pattern, &range) {
+            // The elements of an array may be destructured but the index of a range loop may not.
+            (
+                pattern @ (Pattern::Tuple(..) | Pattern::Struct(..) | Pattern::Mutable(..)),
+                ForRange::Range(..),
+            ) => {
+                let span = pattern.span();
+                emit(ParserError::with_reason(ParserErrorReason::PatternInRangeLoop, span));
+                (Pattern::error(span), range)
+            }
+            (pattern, _) => (pattern, range),
+        })
         .then(block_expr(statement))
-        .map_with_span(|((identifier, range), block), span| {
-            StatementKind::For(ForLoopStatement { identifier, range, block, span })
+        .map_with_span(|((pattern, range), block), span| {
+            StatementKind::For(ForLoopStatement { pattern, range, block, span, collection: None })
         })
 }
 
-/// The 'range' of a for loop. Either an actual range `start .. end` or an array expression.
+fn while_loop<'a, P, S>(
+    expr_no_constructors: P,
+    statement: S,
+) -> impl NoirParser<StatementKind> + 'a
+where
+    P: ExprParser + 'a,
+    S: NoirParser<StatementKind> + 'a,
+{
+    keyword(Keyword::While)
+        .ignore_then(expr_no_constructors)
+        .then(block_expr(statement))
+        .map_with_span(|(condition, block), span| {
+            StatementKind::While(WhileStatement { condition, block, span })
+        })
+}
+
+/// The 'range' of a for loop. Either an actual range `start .. end`, an inclusive range
+/// `start ..= end` or an array expression.
 fn for_range<P>(expr_no_constructors: P) -> impl NoirParser<ForRange>
 where
     P: ExprParser,
 {
+    let range_operator = just(Token::DoubleDot).to(false).or(just(Token::DoubleDotEqual).to(true));
     expr_no_constructors
         .clone()
-        .then_ignore(just(Token::DoubleDot))
+        .then(range_operator)
         .then(expr_no_constructors.clone())
-        .map(|(start, end)| ForRange::Range(start, end))
+        .map(|((start, inclusive), end)| ForRange::Range(ForBounds { start, end, inclusive }))
         .or(expr_no_constructors.map(ForRange::Array))
 }
 
@@ -1325,7 +1393,7 @@ where
 
 fn field_name() -> impl NoirParser<Ident> {
     ident().or(token_kind(TokenKind::Literal).validate(|token, span, emit| match token {
-        Token::Int(_) => Ident::from(Spanned::from(span, token.to_string())),
+        Token::Int(..) => Ident::from(Spanned::from(span, token.to_string())),
         other => {
             emit(ParserError::with_reason(ParserErrorReason::ExpectedFieldName(other), span));
             Ident::error(span)
@@ -1356,6 +1424,7 @@ mod test {
     use super::test_helpers::*;
     use super::*;
     use crate::ast::ArrayLiteral;
+    use crate::BinaryTypeOperator;
 
     #[test]
     fn parse_infix() {
@@ -1364,6 +1433,58 @@ mod test {
         parse_all_failing(expression(), vec!["y ! x"]);
     }
 
+    #[test]
+    fn parse_bitwise_and_shift_precedence() {
+        let cases = [
+            ("a << 2 + 1", "(a << (2 + 1))"),
+            ("a >> b * c", "(a >> (b * c))"),
+            ("a + b & c", "((a + b) & c)"),
+            ("a | b ^ c & d", "(a | (b ^ (c & d)))"),
+            ("a << b < c >> d", "((a << b) < (c >> d))"),
+            ("a < b | c < d", "((a < b) | (c < d))"),
+            ("a & b == c", "((a & b) == c)"),
+        ];
+        for (src, expected) in cases {
+            let expression = parse_with(expression(), src).unwrap().to_string();
+            assert_eq!(expression.replace("plain::", ""), expected, "{src}");
+        }
+    }
+
+    #[test]
+    fn parse_modulo_precedence() {
+        let cases = [
+            ("a + b % c", "(a + (b % c))"),
+            ("a % b * c", "((a % b) * c)"),
+            ("a * b % c", "((a * b) % c)"),
+            ("a % b == 0", "((a % b) == 0)"),
+        ];
+        for (src, expected) in cases {
+            let expression = parse_with(expression(), src).unwrap().to_string();
+            assert_eq!(expression.replace("plain::", ""), expected, "{src}");
+        }
+    }
+
+    #[test]
+    fn expression_spans_cover_the_whole_expression() {
+        let src = "foo(a, b)[i] as u8 + b * c";
+        let source_of = |expression: &Expression| {
+            &src[expression.span.start() as usize..expression.span.end() as usize]
+        };
+
+        let expression = parse_with(expression(), src).unwrap();
+        assert_eq!(source_of(&expression), src);
+        let ExpressionKind::Infix(sum) = &expression.kind else { panic!("Expected an infix") };
+        assert_eq!(source_of(&sum.rhs), "b * c");
+
+        assert_eq!(source_of(&sum.lhs), "foo(a, b)[i] as u8");
+        let ExpressionKind::Cast(cast) = &sum.lhs.kind else { panic!("Expected a cast") };
+        assert_eq!(source_of(&cast.lhs), "foo(a, b)[i]");
+        let ExpressionKind::Index(index) = &cast.lhs.kind else { panic!("Expected an index") };
+        assert_eq!(source_of(&index.collection), "foo(a, b)");
+        let ExpressionKind::Call(call) = &index.collection.kind else { panic!("Expected a call") };
+        assert_eq!(source_of(&call.func), "foo");
+    }
+
     #[test]
     fn parse_function_call() {
         let valid = vec![
@@ -1457,6 +1578,44 @@ mod test {
         parse_all(type_expression(), vec!["(123)", "123", "(1 + 1)", "(1 + (1))"]);
     }
 
+    #[test]
+    fn parse_negative_literal() {
+        // Whitespace between the `-` and the literal is allowed, as with any prefix operator.
+        for src in ["-1", "- 1"] {
+            let expr = parse_with(expression(), src).unwrap();
+            let ExpressionKind::Literal(Literal::Integer(value, true, _)) = expr.kind else {
+                panic!("expected `{src}` to be a negative literal but got {expr}");
+            };
+            assert_eq!(value, 1_u128.into());
+        }
+
+        for src in ["a - 1", "a -1"] {
+            let expr = parse_with(expression(), src).unwrap();
+            let ExpressionKind::Infix(infix) = expr.kind else {
+                panic!("expected `{src}` to be a subtraction but got {expr}");
+            };
+            assert_eq!(infix.operator.contents, BinaryOpKind::Subtract);
+            assert!(matches!(
+                infix.rhs.kind,
+                ExpressionKind::Literal(Literal::Integer(_, false, _))
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_negative_literal_type_expression() {
+        let expr = parse_with(type_expression(), "-1").unwrap();
+        assert!(matches!(
+            expr,
+            UnresolvedTypeExpression::BinaryOperation(lhs, BinaryTypeOperator::Subtraction, rhs, _)
+                if matches!(*lhs, UnresolvedTypeExpression::Constant(0, _))
+                    && matches!(*rhs, UnresolvedTypeExpression::Constant(1, _))
+        ));
+
+        // These parse, but are rejected once the negative length is evaluated.
+        parse_all(expression(), vec!["foo::<-1>()", "foo::<u32, -1>(x)", "[0; -1]"]);
+    }
+
     #[test]
     fn parse_array_sugar() {
         let valid = vec!["[0;7]", "[(1, 2); 4]", "[0;Four]", "[2;1+3-a]"];
@@ -1572,15 +1731,45 @@ mod test {
     fn parse_for_loop() {
         parse_all(
             for_loop(expression_no_constructors(expression()), fresh_statement()),
-            vec!["for i in x+y..z {}", "for i in 0..100 { foo; bar }"],
+            vec![
+                "for i in x+y..z {}",
+                "for i in 0..100 { foo; bar }",
+                "for i in 0..=100 { foo; bar }",
+                "for i in 0..=len - 1 {}",
+                "for x in array { foo; bar }",
+                "for (a, b) in pairs {}",
+                "for Point { x, y } in points {}",
+            ],
         );
 
         parse_all_failing(
             for_loop(expression_no_constructors(expression()), fresh_statement()),
             vec![
-                "for 1 in x+y..z {}",  // Cannot have a literal as the loop identifier
-                "for i in 0...100 {}", // Only '..' is supported, there are no inclusive ranges yet
-                "for i in 0..=100 {}", // Only '..' is supported, there are no inclusive ranges yet
+                "for 1 in x+y..z {}",     // Cannot have a literal as the loop identifier
+                "for i in 0...100 {}",    // Only '..' and '..=' are range operators
+                "for (a, b) in 0..10 {}", // The index of a range loop cannot be destructured
+                "for (a, b) in 0..=10 {}",
+            ],
+        );
+    }
+
+    #[test]
+    fn parse_while_loop() {
+        parse_all(
+            while_loop(expression_no_constructors(expression()), fresh_statement()),
+            vec![
+                "while i < 10 { i += 1; }",
+                "while true {}",
+                "while x == y { while y < z { foo; } bar }",
+            ],
+        );
+
+        parse_all_failing(
+            while_loop(expression_no_constructors(expression()), fresh_statement()),
+            vec![
+                "while { }",               // A condition is required
+                "while i < 10",            // As is a body
+                "while Foo { x } == y {}", // Constructors are not allowed in the condition
             ],
         );
     }
@@ -1615,6 +1804,143 @@ mod test {
         );
     }
 
+    /// Returns the `if` expression an `else if` was desugared into, if `alternative` is one.
+    fn else_if(alternative: &Expression) -> Option<(&IfExpression, Span)> {
+        let ExpressionKind::Block(block) = &alternative.kind else {
+            return None;
+        };
+        let [Statement { kind: StatementKind::Expression(expression), .. }] =
+            block.statements.as_slice()
+        else {
+            return None;
+        };
+        match &expression.kind {
+            ExpressionKind::If(if_expr) => Some((if_expr, expression.span)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn parse_else_if_chains() {
+        let src = "if a { 1 } else if b { 2 } else if c { 3 } else { 4 }";
+        let ExpressionKind::If(first) =
+            parse_with(if_expr(expression_no_constructors(expression()), fresh_statement()), src)
+                .unwrap()
+        else {
+            panic!("Expected an if expression");
+        };
+
+        let (second, second_span) = else_if(first.alternative.as_ref().unwrap()).unwrap();
+        let second_src = &src[second_span.start() as usize..second_span.end() as usize];
+        assert_eq!(second_src, "else if b { 2 } else if c { 3 } else { 4 }");
+
+        let (third, third_span) = else_if(second.alternative.as_ref().unwrap()).unwrap();
+        let third_src = &src[third_span.start() as usize..third_span.end() as usize];
+        assert_eq!(third_src, "else if c { 3 } else { 4 }");
+
+        let last = third.alternative.as_ref().unwrap();
+        assert!(matches!(last.kind, ExpressionKind::Block(_)));
+        assert!(else_if(last).is_none());
+
+        let src = "if a { 1 } else if b { 2 } else if c { 3 }";
+        let ExpressionKind::If(first) =
+            parse_with(if_expr(expression_no_constructors(expression()), fresh_statement()), src)
+                .unwrap()
+        else {
+            panic!("Expected an if expression");
+        };
+        let (second, _) = else_if(first.alternative.as_ref().unwrap()).unwrap();
+        let (third, third_span) = else_if(second.alternative.as_ref().unwrap()).unwrap();
+        assert_eq!(&src[third_span.start() as usize..], "else if c { 3 }");
+        assert!(third.alternative.is_none());
+    }
+
+    #[test]
+    fn assignment_in_expression_suggests_equality() {
+        let (expr, errors) = parse_recover(
+            if_expr(expression_no_constructors(expression()), fresh_statement()),
+            "if x = 5 { 1 } else { 2 }",
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "`=` is not an expression operator; did you mean `==`?");
+        assert_eq!(errors[0].secondaries[0].span, Span::single_char(5));
+
+        // The condition is recovered as an equality.
+        let ExpressionKind::If(if_expr) = expr.unwrap() else {
+            panic!("Expected an if expression");
+        };
+        let ExpressionKind::Infix(condition) = &if_expr.condition.kind else {
+            panic!("Expected an infix condition, got {}", if_expr.condition);
+        };
+        assert_eq!(condition.operator.contents, BinaryOpKind::Equal);
+
+        let errors = parse_all_failing(expression(), vec!["foo(x = 5)", "(a = b) & c"]);
+        assert!(
+            errors.iter().all(|error| error.message.contains("did you mean `==`?")),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn assignments_are_statements() {
+        let statements = parse_all(
+            fresh_statement(),
+            vec!["x = 5", "x.y = a == b", "a[i] = 5", "*x = 5", "x += 1", "x >>= 1"],
+        );
+        for statement in statements {
+            assert!(matches!(statement, StatementKind::Assign(..)), "{statement}");
+        }
+    }
+
+    #[test]
+    fn compound_assignments_keep_their_operator() {
+        let statements = parse_all(
+            fresh_statement(),
+            vec!["a[i] += 1", "s.field -= 1", "s.values[0] *= 1", "*x /= 1"],
+        );
+        let operators = [
+            BinaryOpKind::Add,
+            BinaryOpKind::Subtract,
+            BinaryOpKind::Multiply,
+            BinaryOpKind::Divide,
+        ];
+        for (statement, operator) in statements.into_iter().zip(operators) {
+            let StatementKind::Assign(assign) = statement else {
+                panic!("Expected an assignment, found {statement}");
+            };
+            assert_eq!(assign.operator.map(|operator| operator.contents), Some(operator));
+            assert!(matches!(assign.expression.kind, ExpressionKind::Literal(_)));
+        }
+    }
+
+    #[test]
+    fn compound_assignment_evaluates_index_once() {
+        let statement = parse_with(fresh_statement(), "s.values[f()] += 1").unwrap();
+        let StatementKind::Assign(assign) = statement else {
+            panic!("Expected an assignment, found {statement}");
+        };
+        let statement = assign.desugar_compound_operator(Span::default());
+        let StatementKind::Semi(Expression { kind: ExpressionKind::Block(block), .. }) = statement
+        else {
+            panic!("Expected a block, found {statement}");
+        };
+        assert_eq!(block.statements.len(), 2);
+
+        let StatementKind::Let(let_index) = &block.statements[0].kind else {
+            panic!("Expected the index to be bound by a let statement");
+        };
+        assert_eq!(let_index.expression.to_string(), "plain::f()");
+        let index = let_index.pattern.name_ident();
+
+        let StatementKind::Assign(assign) = &block.statements[1].kind else {
+            panic!("Expected an assignment");
+        };
+        assert_eq!(assign.operator, None);
+        let index = format!("plain::{index}");
+        assert_eq!(assign.lvalue.to_string(), format!("s.values[{index}]"));
+        assert_eq!(assign.expression.to_string(), format!("((plain::s.values)[{index}] + 1)"));
+    }
+
     #[test]
     fn parse_module_declaration() {
         parse_with(module_declaration(), "mod foo").unwrap();
@@ -1843,4 +2169,102 @@ mod test {
 
         check_cases_with_errors(&cases[..], block(fresh_statement()));
     }
+
+    #[test]
+    fn parse_program_with_byte_order_mark_and_shebang() {
+        let src = "\u{FEFF}#!/usr/bin/env nargo\nfn main() {}";
+        let (module, errors) = parse_program(src);
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(module.items.len(), 1);
+    }
+
+    #[test]
+    fn parse_error_after_byte_order_mark_maps_to_editor_column() {
+        use fm::codespan_files::Files;
+
+        let src = "\u{FEFF}fn main() { let x = ; }";
+        let (_, errors) = parse_program(src);
+        let error = errors.first().expect("expected a parser error");
+
+        // The span is a byte offset into the file, including the byte order mark.
+        let error_start = error.span().start() as usize;
+        assert_eq!(error_start, src.find(';').unwrap());
+
+        // Editors hide the byte order mark so the `;` is displayed in the 21st column of the first line.
+        let mut file_map = fm::FileMap::default();
+        let file_id =
+            file_map.add_file(std::path::PathBuf::from("main.nr").into(), src.to_string());
+        let location = file_map.location(file_id, error_start).unwrap();
+        assert_eq!((location.line_number, location.column_number), (1, 21));
+    }
+
+    fn function_names(module: &ParsedModule) -> Vec<&str> {
+        module
+            .items
+            .iter()
+            .filter_map(|item| match &item.kind {
+                ItemKind::Function(function) => Some(function.name()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_program_reports_errors_in_separate_items() {
+        let src = "
+            fn 1() {
+                let x = 1;
+            }
+
+            fn first() -> Field {
+                1
+            }
+
+            struct Foo Bar {
+                x: Field,
+            }
+
+            fn second() {}
+
+            use 2;
+
+            fn main() {}
+        ";
+        let (module, errors) = parse_program(src);
+        assert_eq!(errors.len(), 3, "{errors:?}");
+        assert_eq!(function_names(&module), vec!["first", "second", "main"]);
+    }
+
+    #[test]
+    fn missing_closing_brace_is_reported_once() {
+        let src = "
+            fn broken() {
+                let x = 1;
+                if x == 1 {
+                    assert(x != 2);
+                }
+
+            fn main() {}
+        ";
+        let (module, errors) = parse_program(src);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(function_names(&module), vec!["main"]);
+    }
+
+    #[test]
+    fn recovery_stops_before_comptime_items() {
+        let src = "
+            fn 1() {}
+
+            comptime fn second() {}
+        ";
+        let (module, errors) = parse_program(src);
+        // One error for the malformed function and one for the experimental `comptime`
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        let ItemKind::Function(function) = &module.items.last().unwrap().kind else {
+            panic!("Expected a function");
+        };
+        assert_eq!(function.name(), "second");
+        assert!(function.def.is_comptime);
+    }
 }
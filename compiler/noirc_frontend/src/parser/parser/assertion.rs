@@ -20,10 +20,10 @@ where
         keyword(Keyword::Constrain).labelled(ParsingRuleLabel::Statement),
         expr_parser,
     )
-    .map(|expr| StatementKind::Constrain(ConstrainStatement(expr, None, ConstrainKind::Constrain)))
-    .validate(|expr, span, emit| {
-        emit(ParserError::with_reason(ParserErrorReason::ConstrainDeprecated, span));
-        expr
+    .validate(|expr: Expression, span, emit| {
+        let reason = ParserErrorReason::ConstrainDeprecated { condition: expr.span };
+        emit(ParserError::with_reason(reason, span));
+        StatementKind::Constrain(ConstrainStatement(expr, None, ConstrainKind::Constrain))
     })
 }
 
@@ -75,9 +75,10 @@ mod test {
     use super::*;
     use crate::{
         ast::Literal,
+        parse_program,
         parser::parser::{
             expression,
-            test_helpers::{parse_all, parse_all_failing, parse_with},
+            test_helpers::{parse_all, parse_with},
         },
     };
 
@@ -115,20 +116,37 @@ mod test {
         // The first (inner) `==` is a predicate which returns 0/1
         // The outer layer is an infix `==` which is
         // associated with the Constrain statement
-        let errors = parse_all_failing(
-            constrain(expression()),
-            vec![
-                "constrain ((x + y) == k) + z == y",
-                "constrain (x + !y) == y",
-                "constrain (x ^ y) == y",
-                "constrain (x ^ y) == (y + m)",
-                "constrain x + x ^ x == y | m",
-            ],
-        );
-        assert_eq!(errors.len(), 5);
-        assert!(errors
-            .iter()
-            .all(|err| { err.is_error() && err.to_string().contains("deprecated") }));
+        let sources = vec![
+            "constrain ((x + y) == k) + z == y",
+            "constrain (x + !y) == y",
+            "constrain (x ^ y) == y",
+            "constrain (x ^ y) == (y + m)",
+            "constrain x + x ^ x == y | m",
+        ];
+        for src in sources {
+            let errors = parse_with(constrain(expression()), src).unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert!(errors[0].is_warning() && errors[0].to_string().contains("deprecated"));
+        }
+    }
+
+    /// The deprecation warning for `constrain` carries a fix rewriting it to `assert`
+    #[test]
+    fn constrain_deprecation_suggests_assert() {
+        let src = "fn main(x: Field) { constrain x + 1 == 2; }";
+        let (_, errors) = parse_program(src);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].is_warning());
+
+        let suggestion = errors[0].suggestion().expect("expected a suggested fix");
+        let mut fixed = src.to_string();
+        for (span, replacement) in suggestion.iter().rev() {
+            fixed.replace_range(span.start() as usize..span.end() as usize, replacement);
+        }
+        assert_eq!(fixed, "fn main(x: Field) { assert(x + 1 == 2); }");
+
+        let (_, errors) = parse_program(&fixed);
+        assert!(errors.is_empty());
     }
 
     /// This is the standard way to declare an assert statement
@@ -1,44 +1,203 @@
 use chumsky::Parser;
-use noirc_errors::Span;
+use noirc_errors::{edit_distance, Span};
 
 use crate::{
     macros_api::SecondaryAttribute,
     parser::{NoirParser, ParserError, ParserErrorReason},
-    token::{Attribute, Attributes, Token, TokenKind},
+    token::{Attribute, Attributes, FunctionAttribute, Token, TokenKind},
 };
 
 use super::primitives::token_kind;
 
-fn attribute() -> impl NoirParser<Attribute> {
-    token_kind(TokenKind::Attribute).map(|token| match token {
-        Token::Attribute(attribute) => attribute,
+fn attribute() -> impl NoirParser<(Attribute, Span)> {
+    token_kind(TokenKind::Attribute).map_with_span(|token, span| match token {
+        Token::Attribute(attribute) => (attribute, span),
         _ => unreachable!("Parser should have already errored due to token not being an attribute"),
     })
 }
 
-pub(super) fn attributes() -> impl NoirParser<Vec<Attribute>> {
+/// inner_attribute: '#![' attribute ']'
+pub(super) fn inner_attribute() -> impl NoirParser<SecondaryAttribute> {
+    token_kind(TokenKind::InnerAttribute).map(|token| match token {
+        Token::InnerAttribute(attribute) => attribute,
+        _ => unreachable!(
+            "Parser should have already errored due to token not being an inner attribute"
+        ),
+    })
+}
+
+pub(super) fn attributes() -> impl NoirParser<Vec<(Attribute, Span)>> {
     attribute().repeated()
 }
 
+/// The kinds of items which attributes can be placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AttributeTarget {
+    Function,
+    Struct,
+    Global,
+}
+
+impl AttributeTarget {
+    fn description(self) -> &'static str {
+        match self {
+            AttributeTarget::Function => "a function",
+            AttributeTarget::Struct => "a struct",
+            AttributeTarget::Global => "a global",
+        }
+    }
+}
+
+/// Describes where an attribute may be used.
+struct AttributeSpec {
+    name: &'static str,
+    /// Primary attributes change how a function is compiled, so only one may be given.
+    primary: bool,
+    targets: &'static [AttributeTarget],
+    /// Whether the attribute may be given more than once on the same item.
+    repeatable: bool,
+}
+
+const FUNCTIONS: &[AttributeTarget] = &[AttributeTarget::Function];
+const ALL_ITEMS: &[AttributeTarget] =
+    &[AttributeTarget::Function, AttributeTarget::Struct, AttributeTarget::Global];
+
+const fn primary(name: &'static str) -> AttributeSpec {
+    AttributeSpec { name, primary: true, targets: FUNCTIONS, repeatable: false }
+}
+
+const fn secondary(
+    name: &'static str,
+    targets: &'static [AttributeTarget],
+    repeatable: bool,
+) -> AttributeSpec {
+    AttributeSpec { name, primary: false, targets, repeatable }
+}
+
+/// Every attribute known to the compiler.
+const ATTRIBUTES: &[AttributeSpec] = &[
+    primary("foreign"),
+    primary("builtin"),
+    primary("oracle"),
+    primary("test"),
+    primary("recursive"),
+    primary("fold"),
+    primary("no_predicates"),
+    primary("inline"),
+    secondary("deprecated", FUNCTIONS, false),
+    secondary("contract_library_method", FUNCTIONS, false),
+    secondary("export", FUNCTIONS, false),
+    secondary("no_overflow_checks", FUNCTIONS, false),
+    secondary("field", FUNCTIONS, false),
+    secondary("abi", ALL_ITEMS, true),
+    secondary("allow", ALL_ITEMS, true),
+];
+
+/// Custom attributes are left for macros to interpret, so they are accepted anywhere.
+const CUSTOM: AttributeSpec = secondary("custom", ALL_ITEMS, true);
+
+fn spec(name: &str) -> &'static AttributeSpec {
+    ATTRIBUTES.iter().find(|spec| spec.name == name).expect("ICE: unknown attribute name")
+}
+
+fn attribute_spec(attribute: &Attribute) -> &'static AttributeSpec {
+    match attribute {
+        Attribute::Function(attribute) => spec(match attribute {
+            FunctionAttribute::Foreign(_) => "foreign",
+            FunctionAttribute::Builtin(_) => "builtin",
+            FunctionAttribute::Oracle(_) => "oracle",
+            FunctionAttribute::Test(_) => "test",
+            FunctionAttribute::Recursive => "recursive",
+            FunctionAttribute::Fold => "fold",
+            FunctionAttribute::NoPredicates => "no_predicates",
+            FunctionAttribute::InlineAlways | FunctionAttribute::InlineNever => "inline",
+        }),
+        Attribute::Secondary(attribute) => match attribute {
+            SecondaryAttribute::Deprecated(_) => spec("deprecated"),
+            SecondaryAttribute::ContractLibraryMethod => spec("contract_library_method"),
+            SecondaryAttribute::Export => spec("export"),
+            SecondaryAttribute::NoOverflowChecks => spec("no_overflow_checks"),
+            SecondaryAttribute::Field(_) => spec("field"),
+            SecondaryAttribute::Abi(_) => spec("abi"),
+            SecondaryAttribute::Allow(_) => spec("allow"),
+            SecondaryAttribute::Custom(_) => &CUSTOM,
+        },
+    }
+}
+
+/// Checks the attributes of an item once it has been parsed, against the [ATTRIBUTES] table.
+///
+/// Attributes placed on the wrong kind of item, or conflicting with an earlier attribute, are
+/// reported as errors and dropped. Custom attributes whose name is close to that of a known
+/// attribute are reported as likely typos but are otherwise kept for macros to interpret.
+pub(super) struct AttributeValidator {
+    target: AttributeTarget,
+}
+
+impl AttributeValidator {
+    pub(super) fn new(target: AttributeTarget) -> Self {
+        AttributeValidator { target }
+    }
+
+    pub(super) fn validate(
+        &self,
+        attributes: Vec<(Attribute, Span)>,
+        emit: &mut dyn FnMut(ParserError),
+    ) -> Vec<Attribute> {
+        let mut valid: Vec<(Attribute, Span)> = Vec::new();
+
+        for (attribute, span) in attributes {
+            let spec = attribute_spec(&attribute);
+
+            if let Attribute::Secondary(SecondaryAttribute::Custom(contents)) = &attribute {
+                if let Some(suggestion) = misspelled_attribute(contents) {
+                    let name = custom_attribute_name(contents).to_string();
+                    let reason = ParserErrorReason::UnknownAttribute { name, suggestion };
+                    emit(ParserError::with_reason(reason, span));
+                }
+            }
+
+            if !spec.targets.contains(&self.target) {
+                let reason = ParserErrorReason::AttributeNotAllowed {
+                    attribute: attribute.to_string(),
+                    item: self.target.description(),
+                };
+                emit(ParserError::with_reason(reason, span));
+                continue;
+            }
+
+            let conflict = valid.iter().find(|(previous, _)| {
+                let previous_spec = attribute_spec(previous);
+                (spec.primary && previous_spec.primary)
+                    || (spec.name == previous_spec.name && !spec.repeatable)
+            });
+            if let Some((previous, previous_span)) = conflict {
+                let reason = ParserErrorReason::ConflictingAttributes {
+                    attribute: attribute.to_string(),
+                    previous: previous.to_string(),
+                    previous_span: *previous_span,
+                };
+                emit(ParserError::with_reason(reason, span));
+                continue;
+            }
+
+            valid.push((attribute, span));
+        }
+
+        valid.into_iter().map(|(attribute, _)| attribute).collect()
+    }
+}
+
 pub(super) fn validate_attributes(
-    attributes: Vec<Attribute>,
-    span: Span,
+    attributes: Vec<(Attribute, Span)>,
     emit: &mut dyn FnMut(ParserError),
 ) -> Attributes {
     let mut primary = None;
     let mut secondary = Vec::new();
 
-    for attribute in attributes {
+    for attribute in AttributeValidator::new(AttributeTarget::Function).validate(attributes, emit) {
         match attribute {
-            Attribute::Function(attr) => {
-                if primary.is_some() {
-                    emit(ParserError::with_reason(
-                        ParserErrorReason::MultipleFunctionAttributesFound,
-                        span,
-                    ));
-                }
-                primary = Some(attr);
-            }
+            Attribute::Function(attr) => primary = Some(attr),
             Attribute::Secondary(attr) => secondary.push(attr),
         }
     }
@@ -47,23 +206,143 @@ pub(super) fn validate_attributes(
 }
 
 pub(super) fn validate_secondary_attributes(
-    attributes: Vec<Attribute>,
-    span: Span,
+    attributes: Vec<(Attribute, Span)>,
+    target: AttributeTarget,
     emit: &mut dyn FnMut(ParserError),
 ) -> Vec<SecondaryAttribute> {
-    let mut struct_attributes = vec![];
-
-    for attribute in attributes {
-        match attribute {
-            Attribute::Function(..) => {
-                emit(ParserError::with_reason(
-                    ParserErrorReason::NoFunctionAttributesAllowedOnStruct,
-                    span,
-                ));
+    let attributes = AttributeValidator::new(target).validate(attributes, emit);
+    attributes
+        .into_iter()
+        .filter_map(|attribute| match attribute {
+            Attribute::Secondary(attr) => Some(attr),
+            Attribute::Function(_) => {
+                unreachable!("function attributes are only valid on functions")
             }
-            Attribute::Secondary(attr) => struct_attributes.push(attr),
+        })
+        .collect()
+}
+
+/// The name of a custom attribute, without its arguments, e.g. `aztec` for `#[aztec(private)]`.
+fn custom_attribute_name(contents: &str) -> &str {
+    contents.split('(').next().unwrap_or(contents).trim()
+}
+
+/// Returns the name of the known attribute a custom attribute was most likely meant to be.
+fn misspelled_attribute(contents: &str) -> Option<&'static str> {
+    let name = custom_attribute_name(contents);
+    if ATTRIBUTES.iter().any(|spec| spec.name == name) {
+        return None;
+    }
+
+    let max_distance = (name.len() + 2) / 3;
+    ATTRIBUTES
+        .iter()
+        .map(|spec| (edit_distance(name, spec.name), spec.name))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, known)| known)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::{parse_program, ItemKind, ParsedModule};
+
+    fn reasons(src: &str) -> Vec<ParserErrorReason> {
+        let (_, errors) = parse_program(src);
+        errors.into_iter().filter_map(|error| error.reason().cloned()).collect()
+    }
+
+    fn function_attributes(module: &ParsedModule) -> &Attributes {
+        match &module.items[0].kind {
+            ItemKind::Function(function) => function.attributes(),
+            _ => panic!("expected a function"),
         }
     }
 
-    struct_attributes
+    #[test]
+    fn accepts_fully_attributed_function() {
+        let src = "
+        #[inline(never)]
+        #[deprecated]
+        #[no_overflow_checks]
+        #[abi(functions)]
+        #[allow(unused_variables)]
+        #[aztec(private)]
+        fn foo() {}
+        ";
+        let (module, errors) = parse_program(src);
+        assert!(errors.is_empty(), "{errors:?}");
+
+        let attributes = function_attributes(&module);
+        assert_eq!(attributes.function, Some(FunctionAttribute::InlineNever));
+        assert_eq!(attributes.secondary.len(), 5);
+    }
+
+    #[test]
+    fn warns_about_misspelled_attributes() {
+        let (_, errors) =
+            parse_program("#[inlin(always)] fn foo() {} #[aztec(private)] fn bar() {}");
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].is_warning());
+        assert_eq!(
+            errors[0].reason(),
+            Some(&ParserErrorReason::UnknownAttribute {
+                name: "inlin".to_string(),
+                suggestion: "inline"
+            })
+        );
+        assert_eq!(errors[0].span(), Span::from(0..16));
+    }
+
+    #[test]
+    fn rejects_attributes_on_the_wrong_item() {
+        let src = "#[test] struct Foo {} #[export] global BAR = 1;";
+        let (_, errors) = parse_program(src);
+        assert_eq!(errors.len(), 2, "{errors:?}");
+
+        assert_eq!(
+            errors[0].reason(),
+            Some(&ParserErrorReason::AttributeNotAllowed {
+                attribute: "#[test]".to_string(),
+                item: "a struct"
+            })
+        );
+        assert_eq!(errors[0].span(), Span::from(0..7));
+        assert_eq!(
+            errors[1].reason(),
+            Some(&ParserErrorReason::AttributeNotAllowed {
+                attribute: "#[export]".to_string(),
+                item: "a global"
+            })
+        );
+        assert_eq!(errors[1].span(), Span::from(22..31));
+    }
+
+    #[test]
+    fn rejects_conflicting_attributes() {
+        let src = "#[inline(always)] #[inline(never)] fn foo() {}";
+        let (module, errors) = parse_program(src);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(
+            errors[0].reason(),
+            Some(&ParserErrorReason::ConflictingAttributes {
+                attribute: "#[inline(never)]".to_string(),
+                previous: "#[inline(always)]".to_string(),
+                previous_span: Span::from(0..17),
+            })
+        );
+        assert_eq!(errors[0].span(), Span::from(18..34));
+
+        // The first attribute is kept
+        let attributes = function_attributes(&module);
+        assert_eq!(attributes.function, Some(FunctionAttribute::InlineAlways));
+    }
+
+    #[test]
+    fn rejects_repeated_attributes() {
+        let reasons = reasons("#[export] #[export] fn foo() {} #[abi(a)] #[abi(b)] fn bar() {}");
+        assert_eq!(reasons.len(), 1, "{reasons:?}");
+        assert!(matches!(reasons[0], ParserErrorReason::ConflictingAttributes { .. }));
+    }
 }
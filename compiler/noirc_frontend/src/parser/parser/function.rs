@@ -2,38 +2,39 @@ use super::{
     attributes::{attributes, validate_attributes},
     block, fresh_statement, ident, keyword, maybe_comp_time, nothing, optional_visibility,
     parameter_name_recovery, parameter_recovery, parenthesized, parse_type, pattern,
-    self_parameter, where_clause, NoirParser,
+    self_parameter, trait_bounds, where_clause, NoirParser,
 };
 use crate::parser::labels::ParsingRuleLabel;
 use crate::parser::spanned;
 use crate::token::{Keyword, Token};
 use crate::{
     ast::{
-        FunctionDefinition, FunctionReturnType, Ident, ItemVisibility, NoirFunction, Param,
-        Visibility,
+        FunctionDefinition, FunctionReturnType, Ident, ItemVisibility, NoirFunction, Param, Path,
+        UnresolvedTraitConstraint, UnresolvedTypeData, Visibility,
     },
     parser::{ParserError, ParserErrorReason},
 };
 
 use chumsky::prelude::*;
 
-/// function_definition: attribute function_modifiers 'fn' ident generics '(' function_parameters ')' function_return_type block
-///                      function_modifiers 'fn' ident generics '(' function_parameters ')' function_return_type block
+/// function_definition: attribute function_modifiers 'fn' ident generics_with_bounds '(' function_parameters ')' function_return_type block
+///                      function_modifiers 'fn' ident generics_with_bounds '(' function_parameters ')' function_return_type block
 pub(super) fn function_definition(allow_self: bool) -> impl NoirParser<NoirFunction> {
     attributes()
         .then(function_modifiers())
         .then_ignore(keyword(Keyword::Fn))
         .then(ident())
-        .then(generics())
+        .then(generics_with_bounds())
         .then(parenthesized(function_parameters(allow_self)))
         .then(function_return_type())
         .then(where_clause())
         .then(spanned(block(fresh_statement())))
-        .validate(|(((args, ret), where_clause), (body, body_span)), span, emit| {
-            let ((((attributes, modifiers), name), generics), parameters) = args;
+        .validate(|(((args, ret), mut where_clause), (body, body_span)), _span, emit| {
+            let ((((attributes, modifiers), name), (generics, mut constraints)), parameters) = args;
+            constraints.append(&mut where_clause);
 
             // Validate collected attributes, filtering them into function and secondary variants
-            let attributes = validate_attributes(attributes, span, emit);
+            let attributes = validate_attributes(attributes, emit);
             FunctionDefinition {
                 span: body_span,
                 name,
@@ -44,7 +45,7 @@ pub(super) fn function_definition(allow_self: bool) -> impl NoirParser<NoirFunct
                 generics,
                 parameters,
                 body,
-                where_clause,
+                where_clause: constraints,
                 return_type: ret.1,
                 return_visibility: ret.0,
             }
@@ -53,7 +54,7 @@ pub(super) fn function_definition(allow_self: bool) -> impl NoirParser<NoirFunct
 }
 
 /// visibility_modifier: 'pub(crate)'? 'pub'? ''
-fn visibility_modifier() -> impl NoirParser<ItemVisibility> {
+pub(super) fn visibility_modifier() -> impl NoirParser<ItemVisibility> {
     let is_pub_crate = (keyword(Keyword::Pub)
         .then_ignore(just(Token::LeftParen))
         .then_ignore(keyword(Keyword::Crate))
@@ -95,6 +96,39 @@ pub(super) fn generics() -> impl NoirParser<Vec<Ident>> {
         .map(|opt| opt.unwrap_or_default())
 }
 
+/// generic_parameter: ident
+///                  | ident ':' trait_bounds
+///
+/// generics_with_bounds: '<' generic_parameter (',' generic_parameter)* '>'
+///                     | %empty
+///
+/// Inline trait bounds are returned as trait constraints, so `<T: Eq>` is equivalent to `<T> where T: Eq`.
+pub(super) fn generics_with_bounds() -> impl NoirParser<(Vec<Ident>, Vec<UnresolvedTraitConstraint>)>
+{
+    let generic_parameter = ident().then(just(Token::Colon).ignore_then(trait_bounds()).or_not());
+
+    generic_parameter
+        .separated_by(just(Token::Comma))
+        .allow_trailing()
+        .at_least(1)
+        .delimited_by(just(Token::Less), just(Token::Greater))
+        .or_not()
+        .map(|opt| {
+            let mut generics = Vec::new();
+            let mut constraints = Vec::new();
+            for (generic, bounds) in opt.unwrap_or_default() {
+                let typ =
+                    UnresolvedTypeData::Named(Path::from_ident(generic.clone()), vec![], false)
+                        .with_span(generic.span());
+                for trait_bound in bounds.unwrap_or_default() {
+                    constraints.push(UnresolvedTraitConstraint { typ: typ.clone(), trait_bound });
+                }
+                generics.push(generic);
+            }
+            (generics, constraints)
+        })
+}
+
 #[deprecated = "Distinct keyword is now deprecated. Remove this function after the 0.30.0 release"]
 fn optional_distinctness() -> impl NoirParser<bool> {
     keyword(Keyword::Distinct).or_not().validate(|opt, span, emit| {
@@ -183,6 +217,8 @@ mod test {
                 "fn func_name(f: Field, y : pub Field, z : pub [u8;5],) {}",
                 "fn f(f: pub Field, y : Field, z : Field) -> u8 { x + a }",
                 "fn f<T>(f: pub Field, y : T, z : Field) -> u8 { x + a }",
+                "fn f<T: Eq + Ord, U>(f: pub Field, y : T, z : U) -> u8 { x + a }",
+                "fn f<T: Default>(f: pub Field, y : T, z : Field) -> u8 where T: Eq { x + a }",
                 "fn func_name(x: [Field], y : [Field;2],y : pub [Field;2], z : pub [u8;5])  {}",
                 "fn main(x: pub u8, y: pub u8) -> pub [u8; 2] { [x, y] }",
                 "fn f(f: pub Field, y : Field, z : Field) -> u8 { x + a }",
@@ -224,6 +260,8 @@ mod test {
                 // A leading plus is not allowed.
                 "fn func_name<T>(f: Field, y : T) where T: + SomeTrait {}",
                 "fn func_name<T>(f: Field, y : T) where T: TraitX + <Y> {}",
+                // An inline bound must name at least one trait.
+                "fn func_name<T:>(f: Field, y : T) {}",
                 // `distinct` is deprecated
                 "fn main(x: pub u8, y: pub u8) -> distinct pub [u8; 2] { [x, y] }",
             ],
@@ -1,7 +1,7 @@
 use chumsky::Parser;
 
 use crate::{
-    ast::ExpressionKind,
+    ast::{ExpressionKind, Literal},
     parser::NoirParser,
     token::{Token, TokenKind},
 };
@@ -9,12 +9,13 @@ use crate::{
 use super::primitives::token_kind;
 
 pub(super) fn literal() -> impl NoirParser<ExpressionKind> {
-    token_kind(TokenKind::Literal).map(|token| match token {
-        Token::Int(x) => ExpressionKind::integer(x),
+    token_kind(TokenKind::Literal).map_with_span(|token, span| match token {
+        Token::Int(x, repr) => ExpressionKind::Literal(Literal::Integer(x, false, repr)),
         Token::Bool(b) => ExpressionKind::boolean(b),
         Token::Str(s) => ExpressionKind::string(s),
         Token::RawStr(s, hashes) => ExpressionKind::raw_string(s, hashes),
         Token::FmtStr(s) => ExpressionKind::format_string(s),
+        Token::ByteStr(bytes) => ExpressionKind::byte_string(bytes, span),
         unexpected => unreachable!("Non-literal {} parsed as a literal", unexpected),
     })
 }
@@ -22,7 +23,6 @@ pub(super) fn literal() -> impl NoirParser<ExpressionKind> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::ast::Literal;
     use crate::parser::parser::{
         expression, expression_no_constructors, fresh_statement, term, test_helpers::*,
     };
@@ -40,7 +40,10 @@ mod test {
         let hex = parse_with(literal(), "0x05").unwrap();
 
         match (expr_to_lit(int), expr_to_lit(hex)) {
-            (Literal::Integer(int, false), Literal::Integer(hex, false)) => assert_eq!(int, hex),
+            (Literal::Integer(int, false, _), Literal::Integer(hex, false, repr)) => {
+                assert_eq!(int, hex);
+                assert_eq!(repr.written_form().as_deref(), Some("0x05"));
+            }
             _ => unreachable!(),
         }
     }
@@ -109,17 +112,17 @@ mod test {
             Case { source: r####"r###""###"####, expect: r####"r###""###"####, errors: 0 },
             // miscellaneous
             Case { source: r##" r#\"foo\"# "##, expect: "plain::r", errors: 2 },
-            Case { source: r#" r\"foo\" "#, expect: "plain::r", errors: 1 },
+            Case { source: r#" r\"foo\" "#, expect: "plain::r", errors: 2 },
             Case { source: r##" r##"foo"# "##, expect: "(none)", errors: 2 },
             // missing 'r' letter
             Case { source: r##" ##"foo"# "##, expect: r#""foo""#, errors: 2 },
             Case { source: r#" #"foo" "#, expect: "plain::foo", errors: 2 },
             // whitespace
-            Case { source: r##" r #"foo"# "##, expect: "plain::r", errors: 2 },
+            Case { source: r##" r #"foo"# "##, expect: "plain::r", errors: 3 },
             Case { source: r##" r# "foo"# "##, expect: "plain::r", errors: 3 },
             Case { source: r#" r#"foo" # "#, expect: "(none)", errors: 2 },
             // after identifier
-            Case { source: r##" bar#"foo"# "##, expect: "plain::bar", errors: 2 },
+            Case { source: r##" bar#"foo"# "##, expect: "plain::bar", errors: 3 },
             // nested
             Case {
                 source: r###"r##"foo r#"bar"# r"baz" ### bye"##"###,
@@ -14,9 +14,16 @@ pub(super) fn path() -> impl NoirParser<Path> {
     let prefix = |key| keyword(key).ignore_then(just(Token::DoubleColon));
     let path_kind = |key, kind| prefix(key).ignore_then(idents()).map_with_span(make_path(kind));
 
+    // `self` is not a keyword as it is also used as a variable name, so it is only a path prefix when followed by `::`.
+    let self_prefix = filter(|token| matches!(token, Token::Ident(word) if word == "self"))
+        .ignore_then(just(Token::DoubleColon));
+    let self_path =
+        self_prefix.ignore_then(idents()).map_with_span(make_path(PathKind::SelfModule));
+
     choice((
         path_kind(Keyword::Crate, PathKind::Crate),
         path_kind(Keyword::Dep, PathKind::Dep),
+        self_path,
         idents().map_with_span(make_path(PathKind::Plain)),
     ))
 }
@@ -63,6 +70,8 @@ mod test {
             ("std", PathKind::Plain),
             ("dep::hash::collections", PathKind::Dep),
             ("crate::std::hash", PathKind::Crate),
+            ("self::foo::bar", PathKind::SelfModule),
+            ("self", PathKind::Plain),
         ];
 
         for (src, expected_path_kind) in cases {
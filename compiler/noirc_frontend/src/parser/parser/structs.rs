@@ -1,10 +1,10 @@
 use chumsky::prelude::*;
 
-use crate::ast::{Ident, NoirStruct, UnresolvedType};
+use crate::ast::{NoirStruct, StructField};
 use crate::{
     parser::{
         parser::{
-            attributes::{attributes, validate_secondary_attributes},
+            attributes::{attributes, validate_secondary_attributes, AttributeTarget},
             function, parse_type,
             primitives::{ident, keyword},
         },
@@ -33,22 +33,28 @@ pub(super) fn struct_definition() -> impl NoirParser<TopLevelStatement> {
         .then(function::generics())
         .then(fields)
         .validate(|(((raw_attributes, name), generics), fields), span, emit| {
-            let attributes = validate_secondary_attributes(raw_attributes, span, emit);
+            let attributes =
+                validate_secondary_attributes(raw_attributes, AttributeTarget::Struct, emit);
             TopLevelStatement::Struct(NoirStruct { name, attributes, generics, fields, span })
         })
 }
 
-fn struct_fields() -> impl NoirParser<Vec<(Ident, UnresolvedType)>> {
-    ident()
+fn struct_fields() -> impl NoirParser<Vec<StructField>> {
+    function::visibility_modifier()
+        .then(ident())
         .then_ignore(just(Token::Colon))
         .then(parse_type())
+        .map(|((visibility, name), typ)| StructField { visibility, name, typ })
         .separated_by(just(Token::Comma))
         .allow_trailing()
 }
 
 #[cfg(test)]
 mod test {
+    use iter_extended::vecmap;
+
     use super::*;
+    use crate::ast::ItemVisibility;
     use crate::parser::parser::test_helpers::*;
 
     #[test]
@@ -59,6 +65,7 @@ mod test {
             "struct Bar { ident: Field, }",
             "struct Baz { ident: Field, other: Field }",
             "#[attribute] struct Baz { ident: Field, other: Field }",
+            "struct Baz { pub ident: Field, pub(crate) other: Field, private: Field }",
         ];
         parse_all(struct_definition(), cases);
 
@@ -70,4 +77,18 @@ mod test {
         ];
         parse_all_failing(struct_definition(), failing);
     }
+
+    #[test]
+    fn parse_struct_field_visibility() {
+        let src = "struct Foo { pub a: Field, pub(crate) b: Field, c: Field }";
+        let TopLevelStatement::Struct(noir_struct) = parse_with(struct_definition(), src).unwrap()
+        else {
+            panic!("Expected a struct definition");
+        };
+        let visibilities = vecmap(noir_struct.fields, |field| field.visibility);
+        assert_eq!(
+            visibilities,
+            vec![ItemVisibility::Public, ItemVisibility::PublicCrate, ItemVisibility::Private]
+        );
+    }
 }
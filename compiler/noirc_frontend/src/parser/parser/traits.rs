@@ -57,21 +57,37 @@ fn trait_constant_declaration() -> impl NoirParser<TraitItem> {
         })
 }
 
-/// trait_function_declaration: 'fn' ident generics '(' declaration_parameters ')' function_return_type
+/// trait_function_declaration: 'fn' ident generics_with_bounds '(' declaration_parameters ')' function_return_type
 fn trait_function_declaration() -> impl NoirParser<TraitItem> {
     let trait_function_body_or_semicolon =
         block(fresh_statement()).map(Option::from).or(just(Token::Semicolon).to(Option::None));
 
     keyword(Keyword::Fn)
         .ignore_then(ident())
-        .then(function::generics())
+        .then(function::generics_with_bounds())
         .then(parenthesized(function_declaration_parameters()))
         .then(function_return_type().map(|(_, typ)| typ))
         .then(where_clause())
         .then(trait_function_body_or_semicolon)
-        .map(|(((((name, generics), parameters), return_type), where_clause), body)| {
-            TraitItem::Function { name, generics, parameters, return_type, where_clause, body }
-        })
+        .map(
+            |(
+                (
+                    (((name, (generics, mut constraints)), parameters), return_type),
+                    mut where_clause,
+                ),
+                body,
+            )| {
+                constraints.append(&mut where_clause);
+                TraitItem::Function {
+                    name,
+                    generics,
+                    parameters,
+                    return_type,
+                    where_clause: constraints,
+                    body,
+                }
+            },
+        )
 }
 
 /// trait_type_declaration: 'type' ident generics
@@ -91,10 +107,10 @@ fn trait_type_declaration() -> impl NoirParser<TraitItem> {
 /// This has a similar syntax to `implementation`, but the `for type` clause is required,
 /// and an optional `where` clause is also useable.
 ///
-/// trait_implementation: 'impl' generics ident generic_args for type '{' trait_implementation_body '}'
+/// trait_implementation: 'impl' generics_with_bounds ident generic_args for type '{' trait_implementation_body '}'
 pub(super) fn trait_implementation() -> impl NoirParser<TopLevelStatement> {
     keyword(Keyword::Impl)
-        .ignore_then(function::generics())
+        .ignore_then(function::generics_with_bounds())
         .then(path())
         .then(generic_type_args(parse_type()))
         .then_ignore(keyword(Keyword::For))
@@ -104,8 +120,10 @@ pub(super) fn trait_implementation() -> impl NoirParser<TopLevelStatement> {
         .then(trait_implementation_body())
         .then_ignore(just(Token::RightBrace))
         .map(|args| {
-            let ((other_args, where_clause), items) = args;
-            let (((impl_generics, trait_name), trait_generics), object_type) = other_args;
+            let ((other_args, mut where_clause), items) = args;
+            let ((((impl_generics, mut constraints), trait_name), trait_generics), object_type) =
+                other_args;
+            constraints.append(&mut where_clause);
 
             TopLevelStatement::TraitImpl(NoirTraitImpl {
                 impl_generics,
@@ -113,7 +131,7 @@ pub(super) fn trait_implementation() -> impl NoirParser<TopLevelStatement> {
                 trait_generics,
                 object_type,
                 items,
-                where_clause,
+                where_clause: constraints,
             })
         })
 }
@@ -56,6 +56,29 @@ impl NodeInterner {
         })
     }
 
+    /// Returns the resolved [Type] of the expression found at the given [Location].
+    /// Returns [None] when there is no expression there or its type could not be resolved.
+    pub fn get_type_at_location(&self, location: Location) -> Option<Type> {
+        // Statements share the location of the expression they wrap but have no type, so only
+        // nodes with a type are considered.
+        let mut type_candidate: Option<(&Location, Type)> = None;
+        for (index, interned_location) in self.id_to_location.iter() {
+            if !interned_location.contains(&location) {
+                continue;
+            }
+            if let Some((current_location, _)) = &type_candidate {
+                if !interned_location.span.is_smaller(&current_location.span) {
+                    continue;
+                }
+            }
+            match self.id_type(*index).follow_bindings() {
+                Type::Error => (),
+                typ => type_candidate = Some((interned_location, typ)),
+            }
+        }
+        type_candidate.map(|(_, typ)| typ)
+    }
+
     /// For a given [Index] we return [Location] to which we resolved to
     /// We currently return None for features not yet implemented
     /// TODO(#3659): LSP goto def should error when Ident at Location could not resolve
@@ -12,7 +12,7 @@ use std::collections::BTreeMap;
 use fm::FileId;
 
 use iter_extended::vecmap;
-use noirc_errors::Location;
+use noirc_errors::{CustomDiagnostic, FileDiagnostic, Location, Span};
 
 use crate::hir::def_collector::dc_crate::CompilationError;
 use crate::hir::def_collector::errors::{DefCollectorErrorKind, DuplicateType};
@@ -26,6 +26,7 @@ use crate::node_interner::{NodeInterner, StmtId};
 use crate::hir::def_collector::dc_crate::DefCollector;
 use crate::hir_def::expr::HirExpression;
 use crate::hir_def::stmt::HirStatement;
+use crate::monomorphization::errors::MonomorphizationError;
 use crate::monomorphization::monomorphize;
 use crate::parser::ParserErrorReason;
 use crate::ParsedModule;
@@ -37,7 +38,7 @@ use fm::FileManager;
 use noirc_arena::Arena;
 
 pub(crate) fn has_parser_error(errors: &[(CompilationError, FileId)]) -> bool {
-    errors.iter().any(|(e, _f)| matches!(e, CompilationError::ParseError(_)))
+    errors.iter().any(|(e, _f)| matches!(e, CompilationError::ParseError(e) if !e.is_warning()))
 }
 
 pub(crate) fn remove_experimental_warnings(errors: &mut Vec<(CompilationError, FileId)>) {
@@ -853,6 +854,7 @@ fn find_lambda_captures(stmts: &[StmtId], interner: &NodeInterner, result: &mut
             HirStatement::Constrain(constr_stmt) => constr_stmt.0,
             HirStatement::Semi(semi_expr) => semi_expr,
             HirStatement::For(for_loop) => for_loop.block,
+            HirStatement::While(while_loop) => while_loop.block,
             HirStatement::Error => panic!("Invalid HirStatement!"),
             HirStatement::Break => panic!("Unexpected break"),
             HirStatement::Continue => panic!("Unexpected continue"),
@@ -1192,6 +1194,28 @@ fn resolve_fmt_strings() {
     }
 }
 
+#[test]
+fn fmt_string_hole_out_of_scope_in_assert_message() {
+    let src = r#"
+        fn main(x: Field) {
+            if x == 0 {
+                let y = 1;
+                assert(x != y);
+            }
+            assert(x == 1, f"expected {y} got {x}");
+        }
+    "#;
+
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "Expected 1 error, got: {:?}", errors);
+    match &errors[0].0 {
+        CompilationError::ResolverError(ResolverError::VariableNotDeclared { name, .. }) => {
+            assert_eq!(name, "y");
+        }
+        other => panic!("Expected a VariableNotDeclared error, got {other:?}"),
+    }
+}
+
 fn check_rewrite(src: &str, expected: &str) {
     let (_program, mut context, _errors) = get_program(src);
     let main_func_id = context.def_interner.find_function("main").unwrap();
@@ -1209,22 +1233,22 @@ fn simple_closure_with_no_captured_variables() {
     }
     "#;
 
-    let expected_rewrite = r#"fn main$f0() -> Field {
-    let x$0 = 1;
-    let closure$3 = {
-        let closure_variable$2 = {
-            let env$1 = (x$l0);
-            (env$l1, lambda$f1)
+    let expected_rewrite = r#"fn main#0() -> Field {
+    let x@0 = 1;
+    let closure@0 = {
+        let closure_variable@0 = {
+            let env@0 = (x@0);
+            (env@0, lambda#0)
         };
-        closure_variable$l2
+        closure_variable@0
     };
     {
-        let tmp$4 = closure$l3;
-        tmp$l4.1(tmp$l4.0)
+        let tmp@0 = closure@0;
+        tmp@0.1(tmp@0.0)
     }
 }
-fn lambda$f1(mut env$l1: (Field)) -> Field {
-    env$l1.0
+fn lambda#0(mut env@0: (Field)) -> Field {
+    env@0.0
 }
 "#;
     check_rewrite(src, expected_rewrite);
@@ -1283,6 +1307,80 @@ fn operators_in_global_used_in_type() {
     assert_eq!(get_program_errors(src).len(), 0);
 }
 
+#[test]
+fn global_used_as_loop_bound() {
+    let src = r#"
+        global N = 4;
+
+        fn main(x: Field) -> pub Field {
+            let mut sum = 0;
+            for i in 0..N {
+                sum += x * i as Field;
+            }
+            sum
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let program = monomorphize(main_func_id, &mut context.def_interner).unwrap();
+    let body = program.functions[0].body.to_string();
+    assert!(body.contains(" in 0 .. 4 {"), "{body}");
+}
+
+#[test]
+fn global_used_as_array_length_in_parameter_type() {
+    let src = r#"
+        global N = 3;
+
+        fn main(x: [Field; N]) -> pub Field {
+            sum(x)
+        }
+
+        fn sum(x: [Field; N]) -> Field {
+            x[0] + x[1] + x[2]
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let program = monomorphize(main_func_id, &mut context.def_interner).unwrap();
+    for function in &program.functions {
+        assert_eq!(function.parameters[0].3.to_string(), "[Field; 3]");
+    }
+}
+
+#[test]
+fn duplicate_global_names_are_rejected() {
+    let src = r#"
+        global N = 3;
+        global N = 4;
+
+        fn main() {}
+    "#;
+    // The resolver also reports the global scope being redefined, so only the error from the
+    // definition collector is checked.
+    let errors = get_program_errors(src);
+    let definition_errors: Vec<_> = errors
+        .iter()
+        .filter(|(error, _)| matches!(error, CompilationError::DefinitionError(_)))
+        .collect();
+    assert_eq!(definition_errors.len(), 1, "{errors:?}");
+    let CompilationError::DefinitionError(DefCollectorErrorKind::Duplicate {
+        typ,
+        first_def,
+        second_def,
+    }) = &definition_errors[0].0
+    else {
+        panic!("Expected a duplicate definition error, got {:?}", definition_errors[0].0);
+    };
+    assert_eq!(typ, &DuplicateType::Global);
+    assert_eq!(first_def, "N");
+    assert_eq!(second_def, "N");
+}
+
 #[test]
 fn break_and_continue_in_constrained_fn() {
     let src = r#"
@@ -1327,6 +1425,21 @@ fn for_loop_over_array() {
     assert_eq!(get_program_errors(src).len(), 0);
 }
 
+#[test]
+fn for_loop_over_non_iterable_value_is_an_error() {
+    let src = r#"
+        fn main(count: u32) {
+            for _ in count {}
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let CompilationError::TypeError(TypeCheckError::NotIterable { typ, .. }) = &errors[0].0 else {
+        panic!("Expected a NotIterable error, got {:?}", errors[0].0);
+    };
+    assert_eq!(typ.to_string(), "u32");
+}
+
 // Regression for #4545
 #[test]
 fn type_aliases_in_main() {
@@ -1443,3 +1556,1529 @@ fn specify_method_types_with_turbofish() {
     let errors = get_program_errors(src);
     assert_eq!(errors.len(), 0);
 }
+
+#[test]
+fn runtime_value_used_as_array_length() {
+    let src = r#"
+        fn main(n: u64) {
+            let _array: [Field; n] = [0; 3];
+            let _repeated = [0; n];
+        }
+    "#;
+    let errors = get_program_errors(src);
+    let non_constant_lengths: Vec<_> = errors
+        .iter()
+        .filter_map(|(error, _)| match error {
+            CompilationError::ResolverError(ResolverError::NonConstantArrayLength {
+                name, ..
+            }) => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(non_constant_lengths, vec!["n", "n"], "{errors:?}");
+}
+
+#[test]
+fn folded_constant_array_lengths_are_accepted() {
+    let src = r#"
+        global N = 2;
+        global M = N * 3 + 1;
+
+        fn main() {
+            let array: [Field; N * 3 + 1] = [0; M];
+            let _other: [Field; M - N] = [array[0]; 5];
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+}
+
+#[test]
+fn negative_array_lengths_are_rejected() {
+    let src = r#"
+        global N = 2;
+
+        fn main() {
+            let _array: [Field; -1] = [];
+            let _repeated = [0; N - 3];
+        }
+    "#;
+    let errors = get_program_errors(src);
+    let negative_lengths: Vec<_> = errors
+        .iter()
+        .filter_map(|(error, _)| match error {
+            CompilationError::ResolverError(ResolverError::NegativeArrayLength { span }) => {
+                Some(&src[span.start() as usize..span.end() as usize])
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(negative_lengths, vec!["-1", "N - 3"], "{errors:?}");
+}
+
+#[test]
+fn monomorphization_error_reports_generic_instantiations() {
+    let src = r#"
+        fn main() {
+            outer([1, 2, 3]);
+        }
+
+        fn outer<N>(x: [Field; N]) {
+            inner(x);
+        }
+
+        fn inner<T>(_x: T) {
+            let _empty = [];
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let error = monomorphize(main_func_id, &mut context.def_interner).unwrap_err();
+
+    let instantiations = match &error {
+        MonomorphizationError::InInstantiation { instantiations, .. } => instantiations.clone(),
+        _ => panic!("Expected the error to record its instantiations, got {error:?}"),
+    };
+    let call_lines = vecmap(&instantiations, |instantiation| {
+        src[..instantiation.location.span.start() as usize].lines().count()
+    });
+    assert_eq!(call_lines, vec![3, 7]);
+
+    let diagnostic = FileDiagnostic::from(error).diagnostic;
+    assert_eq!(
+        diagnostic.to_string(),
+        "Type annotations needed\n\
+         secondary: Could not infer the type of this expression, consider adding a type annotation\n\
+         note: while instantiating `inner` with T = [Field; 3]\n\
+         note: while instantiating `outer` with N = 3"
+    );
+}
+
+fn get_private_struct_field_errors(src: &str) -> Vec<(String, Location, CustomDiagnostic)> {
+    let errors = get_program_errors(src);
+    errors
+        .iter()
+        .filter_map(|(error, _)| match error {
+            CompilationError::ResolverError(ResolverError::PrivateStructField {
+                field,
+                declaration,
+                ..
+            })
+            | CompilationError::TypeError(TypeCheckError::ResolverError(
+                ResolverError::PrivateStructField { field, declaration, .. },
+            )) => Some((field.to_string(), *declaration, CustomDiagnostic::from(error))),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Asserts that each private field error points to the field's declaration,
+/// both in the error itself and as a secondary label of its diagnostic.
+fn assert_private_fields_declared_at(
+    src: &str,
+    errors: &[(String, Location, CustomDiagnostic)],
+    declaration: &str,
+) {
+    let declaration_start = src.find(declaration).unwrap() as u32;
+    for (_, location, diagnostic) in errors {
+        assert_eq!(location.span.start(), declaration_start);
+        assert!(
+            diagnostic.secondaries.iter().any(|label| {
+                label.message == "field declared here without `pub`" && label.span == location.span
+            }),
+            "{diagnostic:?}"
+        );
+    }
+}
+
+#[test]
+fn private_struct_fields_cannot_be_used_outside_their_module() {
+    let src = r#"
+        mod wallet {
+            struct Wallet {
+                pub owner: Field,
+                balance: Field,
+            }
+        }
+
+        fn main() {
+            let wallet = wallet::Wallet { owner: 1, balance: 100 };
+            let _ = wallet.owner;
+            let _ = wallet.balance;
+        }
+    "#;
+    let errors = get_private_struct_field_errors(src);
+    let fields = vecmap(&errors, |(field, ..)| field.as_str());
+    assert_eq!(fields, vec!["balance", "balance"]);
+    assert_private_fields_declared_at(src, &errors, "balance: Field");
+}
+
+#[test]
+fn private_struct_fields_can_be_used_within_their_module() {
+    let src = r#"
+        mod wallet {
+            struct Wallet {
+                owner: Field,
+                balance: Field,
+            }
+
+            impl Wallet {
+                pub fn new(owner: Field) -> Self {
+                    Wallet { owner, balance: 0 }
+                }
+
+                pub fn balance(self) -> Field {
+                    self.balance
+                }
+            }
+
+            mod audit {
+                pub fn is_empty(wallet: crate::wallet::Wallet) -> bool {
+                    let crate::wallet::Wallet { owner: _, balance } = wallet;
+                    balance == 0
+                }
+            }
+        }
+
+        fn main() {
+            let wallet = wallet::Wallet::new(1);
+            assert(wallet.balance() == 0);
+            assert(wallet::audit::is_empty(wallet));
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+}
+
+#[test]
+fn private_struct_fields_cannot_be_destructured_outside_their_module() {
+    let src = r#"
+        mod wallet {
+            struct Wallet {
+                pub owner: Field,
+                balance: Field,
+            }
+
+            pub fn new(owner: Field) -> Wallet {
+                Wallet { owner, balance: 0 }
+            }
+        }
+
+        fn main() {
+            let wallet::Wallet { owner, balance } = wallet::new(1);
+            assert(owner == balance);
+        }
+    "#;
+    let errors = get_private_struct_field_errors(src);
+    let fields = vecmap(&errors, |(field, ..)| field.as_str());
+    assert_eq!(fields, vec!["balance"]);
+    assert_private_fields_declared_at(src, &errors, "balance: Field");
+}
+
+#[test]
+fn private_struct_fields_cannot_be_accessed_by_globals_outside_their_module() {
+    let src = r#"
+        mod wallet {
+            struct Wallet {
+                pub owner: Field,
+                balance: Field,
+            }
+
+            global EMPTY = Wallet { owner: 0, balance: 0 };
+            global EMPTY_BALANCE = EMPTY.balance;
+        }
+
+        global EMPTY_OWNER = wallet::EMPTY.owner;
+        global EMPTY_BALANCE = wallet::EMPTY.balance;
+
+        fn main() {
+            assert(EMPTY_OWNER == EMPTY_BALANCE);
+            assert(wallet::EMPTY_BALANCE == 0);
+        }
+    "#;
+    let errors = get_private_struct_field_errors(src);
+    let fields = vecmap(&errors, |(field, ..)| field.as_str());
+    assert_eq!(fields, vec!["balance"]);
+    assert_private_fields_declared_at(src, &errors, "balance: Field");
+}
+
+#[test]
+fn constrain_and_assert_are_equivalent() {
+    let program = |assertion: fn(&str) -> String| {
+        format!(
+            r#"
+            fn main(x: Field, y: bool) {{
+                {};
+                if y {{
+                    {}
+                }} else {{
+                    {};
+                }}
+                let _z = {{
+                    {};
+                    x + 1
+                }};
+                {{
+                    {}
+                }}
+            }}
+            "#,
+            assertion("x != 0"),
+            assertion("y"),
+            assertion("!y"),
+            assertion("x == x"),
+            assertion("(x != 1) | y"),
+        )
+    };
+    let monomorphized = |src: &str| {
+        let (_program, mut context, errors) = get_program(src);
+        assert!(
+            errors.iter().all(|(error, _)| match error {
+                CompilationError::ParseError(error) => error.is_warning(),
+                _ => false,
+            }),
+            "{errors:?}"
+        );
+        let main_func_id = context.def_interner.find_function("main").unwrap();
+        monomorphize(main_func_id, &mut context.def_interner).unwrap().to_string()
+    };
+
+    let with_assert = monomorphized(&program(|condition| format!("assert({condition})")));
+    let with_constrain = monomorphized(&program(|condition| format!("constrain {condition}")));
+    assert_eq!(with_assert, with_constrain);
+}
+
+#[test]
+fn struct_fields_are_lowered_in_declaration_order() {
+    // Renaming fields changes their alphabetical order but must not change the struct's layout.
+    let lower = |first: &str, second: &str| {
+        let src = format!(
+            r#"
+            struct Foo {{
+                {first}: u8,
+                {second}: Field,
+            }}
+
+            fn main(foo: Foo) -> pub Field {{
+                let Foo {{ {second}: value, {first}: _ }} = foo;
+                let bar = Foo {{ {second}: foo.{second}, {first}: foo.{first} }};
+                value + bar.{second}
+            }}
+            "#
+        );
+        let (_program, mut context, errors) = get_program(&src);
+        assert_eq!(errors.len(), 0, "{errors:?}");
+
+        let main_func_id = context.def_interner.find_function("main").unwrap();
+        let program = monomorphize(main_func_id, &mut context.def_interner).unwrap();
+        let main = &program.functions[0];
+        (main.parameters[0].3.to_string(), main.body.to_string().replace(first, "FIRST"))
+    };
+
+    let (parameter_type, body) = lower("alpha", "middle");
+    assert_eq!(parameter_type, "(u8, Field)");
+    assert_eq!(lower("zeta", "middle"), (parameter_type, body));
+}
+
+const ORD_TRAIT_SRC: &str = "
+    trait Ord {
+        fn lt(self, other: Self) -> bool;
+    }
+
+    impl Ord for u32 {
+        fn lt(self, other: u32) -> bool { self < other }
+    }
+
+    fn max<T: Ord>(a: T, b: T) -> T {
+        if a.lt(b) { b } else { a }
+    }
+";
+
+#[test]
+fn generic_bound_is_satisfied_at_call_site() {
+    let src = format!(
+        "{ORD_TRAIT_SRC}
+        fn main(x: u32, y: u32) -> pub u32 {{
+            max(x, y)
+        }}"
+    );
+    let errors = get_program_errors(&src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+}
+
+#[test]
+fn unsatisfied_generic_bound_is_reported_at_call_site() {
+    let src = format!(
+        "{ORD_TRAIT_SRC}
+        fn main(x: Field, y: Field) -> pub Field {{
+            max(x, y)
+        }}"
+    );
+    let errors = get_program_errors(&src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+
+    let CompilationError::TypeError(TypeCheckError::NoMatchingImplFound { constraints, span }) =
+        &errors[0].0
+    else {
+        panic!("Expected a missing impl error, got {:?}", errors[0].0);
+    };
+    let constraints = vecmap(constraints, |(typ, trait_name)| format!("{typ}: {trait_name}"));
+    assert_eq!(constraints, vec!["Field: Ord"]);
+    // The error points at the call to `max` rather than at the `lt` call within its body.
+    let call_start = src.find("max(x, y)").unwrap() as u32;
+    assert_eq!(span.start(), call_start);
+}
+
+#[test]
+fn generic_bound_on_struct_generic_parameter() {
+    let program = |argument_type: &str| {
+        format!(
+            "{ORD_TRAIT_SRC}
+            struct Wrapper<T> {{
+                inner: T,
+            }}
+
+            impl<T: Ord> Ord for Wrapper<T> {{
+                fn lt(self, other: Self) -> bool {{ self.inner.lt(other.inner) }}
+            }}
+
+            fn main(x: {argument_type}, y: {argument_type}) {{
+                let _ = max(Wrapper {{ inner: x }}, Wrapper {{ inner: y }});
+            }}"
+        )
+    };
+
+    let errors = get_program_errors(&program("u32"));
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let errors = get_program_errors(&program("Field"));
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let CompilationError::TypeError(TypeCheckError::NoMatchingImplFound { constraints, .. }) =
+        &errors[0].0
+    else {
+        panic!("Expected a missing impl error, got {:?}", errors[0].0);
+    };
+    let constraints = vecmap(constraints, |(typ, trait_name)| format!("{typ}: {trait_name}"));
+    assert_eq!(constraints, vec!["Field: Ord", "Wrapper<Field>: Ord"]);
+}
+
+#[test]
+fn inline_module_items_are_accessible_via_path() {
+    let src = r#"
+    mod helpers {
+        pub fn double(x: Field) -> Field {
+            x * 2
+        }
+
+        mod nested {
+            pub fn triple(x: Field) -> Field {
+                x * 3
+            }
+        }
+
+        pub fn six_times(x: Field) -> Field {
+            nested::triple(double(x))
+        }
+    }
+
+    use self::helpers::double;
+    use crate::helpers::six_times;
+
+    fn main(x: Field) -> pub Field {
+        double(x) + helpers::double(x) + six_times(x)
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+}
+
+#[test]
+fn private_function_in_inline_module_is_private_outside_it() {
+    let src = r#"
+    mod helpers {
+        fn secret() -> Field {
+            1
+        }
+
+        pub fn reveal() -> Field {
+            secret()
+        }
+    }
+
+    fn main() -> pub Field {
+        helpers::reveal() + helpers::secret()
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let CompilationError::ResolverError(ResolverError::PathResolutionError(
+        PathResolutionError::Private(name),
+    )) = &errors[0].0
+    else {
+        panic!("Expected a private item error, got {:?}", errors[0].0);
+    };
+    assert_eq!(name.to_string(), "secret");
+}
+
+#[test]
+fn string_literal_length_must_match_declared_type() {
+    let src = r#"
+        fn greet(_message: str<5>) {}
+
+        fn main() {
+            let _message: str<5> = "hi";
+            greet("hello!");
+        }
+    "#;
+    let errors = get_program_errors(src);
+    let mismatches: Vec<_> = errors
+        .iter()
+        .map(|(error, _)| match error {
+            CompilationError::TypeError(TypeCheckError::TypeMismatch {
+                expected_typ,
+                expr_typ,
+                ..
+            }) => (expected_typ.as_str(), expr_typ.as_str()),
+            _ => panic!("Expected a type mismatch, got {error:?}"),
+        })
+        .collect();
+    assert_eq!(mismatches, vec![("str<5>", "str<2>"), ("str<5>", "str<6>")]);
+}
+
+fn get_array_length_mismatches(src: &str) -> Vec<(String, String, String)> {
+    let errors = get_program_errors(src);
+    vecmap(&errors, |(error, _)| match error {
+        CompilationError::TypeError(TypeCheckError::ArrayLengthMismatch {
+            expected_length,
+            found_length,
+            path,
+            ..
+        }) => (expected_length.to_string(), found_length.to_string(), path.clone()),
+        _ => panic!("Expected an array length mismatch, got {error:?}"),
+    })
+}
+
+#[test]
+fn array_length_mismatch_is_reported() {
+    let src = r#"
+        fn sum(_values: [Field; 3]) {}
+
+        fn main() {
+            sum([1, 2]);
+            let _nested: [[Field; 2]; 1] = [[1, 2, 3]];
+        }
+    "#;
+    let mismatches = get_array_length_mismatches(src);
+    assert_eq!(
+        mismatches,
+        vec![("3".into(), "2".into(), "".into()), ("2".into(), "3".into(), "[_]".into())]
+    );
+}
+
+#[test]
+fn array_length_mismatch_in_struct_reports_its_path() {
+    let src = r#"
+        struct Proof<N> {
+            root: Field,
+            hashpath: [Field; N],
+        }
+
+        fn verify(_proof: (Field, Proof<32>)) {}
+
+        fn main(proof: Proof<16>) {
+            verify((proof.root, proof));
+        }
+    "#;
+    let mismatches = get_array_length_mismatches(src);
+    assert_eq!(mismatches, vec![("32".into(), "16".into(), ".1.hashpath".into())]);
+}
+
+#[test]
+fn array_length_mismatch_against_generic_length() {
+    let src = r#"
+        fn first<N>(values: [Field; N]) -> Field {
+            head(values)
+        }
+
+        fn head(values: [Field; 4]) -> Field {
+            values[0]
+        }
+
+        fn main() -> pub Field {
+            first([1, 2, 3, 4])
+        }
+    "#;
+    let mismatches = get_array_length_mismatches(src);
+    assert_eq!(mismatches, vec![("4".into(), "N".into(), "".into())]);
+}
+
+#[test]
+fn array_length_mismatch_points_at_return_type() {
+    let src = r#"
+        fn pair() -> [Field; 2] {
+            [1, 2, 3]
+        }
+
+        fn main() {
+            let _ = pair();
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let CompilationError::TypeError(
+        error @ TypeCheckError::ArrayLengthMismatch {
+            span, expected_span: Some(expected_span), ..
+        },
+    ) = &errors[0].0
+    else {
+        panic!("Expected an array length mismatch, got {:?}", errors[0].0);
+    };
+    let source_at = |span: &Span| &src[span.start() as usize..span.end() as usize];
+    assert_eq!(source_at(span), "[1, 2, 3]");
+    assert_eq!(source_at(expected_span), "[Field; 2]");
+
+    let diagnostic = CustomDiagnostic::from(error);
+    assert_eq!(diagnostic.message, "Array length mismatch: expected 2, found 3");
+    assert!(diagnostic
+        .to_string()
+        .contains("note: expected type [Field; 2], found type [Field; 3]"));
+}
+
+#[test]
+fn array_length_mismatch_points_at_parameter() {
+    let src = r#"
+        fn sum(values: [Field; 3]) -> Field {
+            values[0]
+        }
+
+        fn main() {
+            let _ = sum([1, 2]);
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let CompilationError::TypeError(
+        error @ TypeCheckError::ArrayLengthMismatch {
+            span, expected_span: Some(expected_span), ..
+        },
+    ) = &errors[0].0
+    else {
+        panic!("Expected an array length mismatch, got {:?}", errors[0].0);
+    };
+    let source_at = |span: &Span| &src[span.start() as usize..span.end() as usize];
+    assert_eq!(source_at(span), "[1, 2]");
+    assert_eq!(source_at(expected_span), "values");
+
+    let diagnostic = CustomDiagnostic::from(error);
+    assert_eq!(diagnostic.secondaries[1].message, "expected length 3 due to this");
+}
+
+#[test]
+fn overflowing_literal_is_reported_as_written() {
+    let src = r#"
+        fn main() {
+            let _hex: u8 = 0x1_ff;
+            let _decimal: u8 = 300;
+        }
+    "#;
+    let errors = get_program_errors(src);
+    let messages: Vec<_> = errors
+        .iter()
+        .map(|(error, _)| match error {
+            CompilationError::TypeError(error @ TypeCheckError::OverflowingAssignment { .. }) => {
+                error.to_string()
+            }
+            _ => panic!("Expected an overflowing assignment, got {error:?}"),
+        })
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "The literal `0x1_ff` cannot fit into `u8` which has range `0..=255`",
+            "The literal `300` cannot fit into `u8` which has range `0..=255`",
+        ]
+    );
+}
+
+#[test]
+fn u128_literals_are_checked_against_the_full_range() {
+    let src = r#"
+        fn main() {
+            let _max: u128 = 340282366920938463463374607431768211455;
+            let _too_large: u128 = 0x1_00000000000000000000000000000000;
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let CompilationError::TypeError(error) = &errors[0].0 else {
+        panic!("Expected a type error, got {:?}", errors[0].0);
+    };
+    assert_eq!(
+        error.to_string(),
+        "The literal `0x1_00000000000000000000000000000000` cannot fit into `u128` which has range `0..=340282366920938463463374607431768211455`"
+    );
+}
+
+#[test]
+fn i128_is_not_supported() {
+    let src = r#"
+        fn main(x: u128) -> pub u128 {
+            let _y: i128 = 1;
+            x
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let CompilationError::ParseError(error) = &errors[0].0 else {
+        panic!("Expected a parse error, got {:?}", errors[0].0);
+    };
+    assert_eq!(error.reason(), Some(&ParserErrorReason::InvalidBitSize(128)));
+}
+
+#[test]
+fn printed_program_keeps_hex_literals() {
+    let src = r#"
+        fn main() -> pub Field {
+            0xff + 255
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let program = monomorphize(main_func_id, &mut context.def_interner).unwrap();
+    let printed = program.to_string();
+    assert!(printed.contains("0xff"), "{printed}");
+    assert!(printed.contains("255"), "{printed}");
+}
+
+#[test]
+fn type_at_location_is_the_resolved_type() {
+    let src = r#"
+        struct Foo { x: u32 }
+
+        fn main(foo: Foo) -> pub u32 {
+            let total = foo.x;
+            total
+        }
+    "#;
+    let (_program, context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let type_at = |needle: &str| {
+        let offset = src.rfind(needle).unwrap() as u32;
+        let location = Location::new(Span::single_char(offset), FileId::dummy());
+        context.def_interner.get_type_at_location(location).map(|typ| typ.to_string())
+    };
+    assert_eq!(type_at("foo.x").as_deref(), Some("Foo"));
+    assert_eq!(type_at("total\n").as_deref(), Some("u32"));
+    assert_eq!(type_at("struct"), None);
+}
+
+#[test]
+fn casts_between_primitive_types() {
+    let types = ["bool", "Field", "u8", "u64", "i8", "i64"];
+    for from in types {
+        for to in types {
+            let src = format!("fn main(x: {from}) -> pub {to} {{ x as {to} }}");
+            let errors = get_program_errors(&src);
+
+            // Only bool itself may be cast to bool.
+            if to == "bool" && from != "bool" {
+                assert_eq!(errors.len(), 1, "{from} as {to}: {errors:?}");
+                let CompilationError::TypeError(TypeCheckError::CastToBool { from: typ, .. }) =
+                    &errors[0].0
+                else {
+                    panic!("{from} as {to}: expected a CastToBool error, got {:?}", errors[0].0);
+                };
+                assert_eq!(typ.to_string(), from);
+            } else {
+                assert_eq!(errors.len(), 0, "{from} as {to}: {errors:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn cast_of_literal_to_bool_is_rejected() {
+    let src = "fn main() -> pub bool { 1 as bool }";
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(matches!(errors[0].0, CompilationError::TypeError(TypeCheckError::CastToBool { .. })));
+}
+
+#[test]
+fn unicode_identifiers_resolve_after_normalization() {
+    // `café` is declared with a precomposed `é` but used as `e` followed by a combining acute accent.
+    let src = "
+        fn main() {
+            let pr\u{e9}image = 1;
+            let caf\u{e9} = pr\u{e9}image;
+            assert(cafe\u{301} == pr\u{e9}image);
+        }
+    ";
+    assert_eq!(get_program_errors(src).len(), 0);
+}
+
+#[test]
+fn identifiers_mixing_scripts_are_warnings() {
+    // Both uses of `pаram` contain a Cyrillic `а`.
+    let src = "
+        fn main() {
+            let p\u{430}ram = 1;
+            assert(p\u{430}ram == 1);
+        }
+    ";
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 2);
+    for (error, _) in errors {
+        assert!(matches!(error, CompilationError::ParseError(error) if error.is_warning()));
+    }
+}
+
+fn get_unused_item_warnings(src: &str) -> Vec<(&'static str, String)> {
+    get_program_errors(src)
+        .into_iter()
+        .map(|(error, _)| match error {
+            CompilationError::ResolverError(ResolverError::UnusedImport { ident }) => {
+                ("import", ident.to_string())
+            }
+            CompilationError::ResolverError(ResolverError::UnusedModule { ident }) => {
+                ("module", ident.to_string())
+            }
+            error => panic!("Expected an unused item warning, got {error:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn unused_imports_are_warnings() {
+    let src = r#"
+    mod helpers {
+        pub fn double(x: Field) -> Field {
+            x * 2
+        }
+
+        pub fn triple(x: Field) -> Field {
+            x * 3
+        }
+    }
+
+    use helpers::triple;
+    use helpers::double as twice;
+
+    fn main(x: Field) -> pub Field {
+        helpers::double(x)
+    }
+    "#;
+    let warnings = get_unused_item_warnings(src);
+    assert_eq!(warnings, vec![("import", "triple".to_string()), ("import", "twice".to_string())]);
+}
+
+#[test]
+fn only_unused_names_of_list_imports_are_warnings() {
+    let src = r#"
+    mod helpers {
+        pub fn double(x: Field) -> Field {
+            x * 2
+        }
+
+        pub fn triple(x: Field) -> Field {
+            x * 3
+        }
+    }
+
+    use helpers::{double, triple};
+
+    fn main(x: Field) -> pub Field {
+        double(x)
+    }
+    "#;
+    let warnings = get_unused_item_warnings(src);
+    assert_eq!(warnings, vec![("import", "triple".to_string())]);
+}
+
+#[test]
+fn unused_modules_of_binary_crates_are_warnings() {
+    let src = r#"
+    mod used {
+        pub fn one() -> Field {
+            1
+        }
+    }
+
+    mod unused {
+        pub fn two() -> Field {
+            2
+        }
+    }
+
+    mod tests {
+        #[test]
+        fn test_one() {
+            assert(crate::used::one() == 1);
+        }
+    }
+
+    fn main() -> pub Field {
+        used::one()
+    }
+    "#;
+    let warnings = get_unused_item_warnings(src);
+    assert_eq!(warnings, vec![("module", "unused".to_string())]);
+}
+
+#[test]
+fn allow_unused_imports_silences_warnings_in_module_and_submodules() {
+    let src = r#"
+    mod helpers {
+        #![allow(unused_imports)]
+
+        use crate::NUMBER;
+
+        pub fn double(x: Field) -> Field {
+            x * 2
+        }
+
+        mod nested {
+            use crate::NUMBER;
+
+            pub fn triple(x: Field) -> Field {
+                x * 3
+            }
+        }
+    }
+
+    global NUMBER = 1;
+
+    use helpers::nested::triple;
+
+    fn main(x: Field) -> pub Field {
+        helpers::double(x) + helpers::nested::triple(NUMBER)
+    }
+    "#;
+    let warnings = get_unused_item_warnings(src);
+    assert_eq!(warnings, vec![("import", "triple".to_string())]);
+}
+
+fn get_aliased_argument_names(src: &str) -> Vec<String> {
+    get_program_errors(src)
+        .into_iter()
+        .filter_map(|(error, _)| match error {
+            CompilationError::TypeError(TypeCheckError::AliasedMutableReferenceArgument {
+                name,
+                ..
+            }) => Some(name),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn mutable_reference_aliasing_another_argument() {
+    let src = r#"
+    fn set_first(array: &mut [Field; 2], value: Field) {
+        array[0] = value;
+    }
+
+    fn main() {
+        let mut array = [1, 2];
+        set_first(&mut array, array[1]);
+        assert(array[0] == 2);
+    }
+    "#;
+    assert_eq!(get_aliased_argument_names(src), vec!["array".to_string()]);
+}
+
+#[test]
+fn mutable_reference_to_projection_aliasing_another_projection() {
+    let src = r#"
+    struct Pair {
+        a: [Field; 2],
+        b: Field,
+    }
+
+    fn set(value: &mut Field, other: Field) {
+        *value = other;
+    }
+
+    fn main() {
+        let mut pair = Pair { a: [1, 2], b: 3 };
+        set(&mut pair.b, pair.a[0]);
+        assert(pair.b == 1);
+    }
+    "#;
+    assert_eq!(get_aliased_argument_names(src), vec!["pair".to_string()]);
+}
+
+#[test]
+fn mutable_reference_with_argument_from_another_variable() {
+    let src = r#"
+    fn set_first(array: &mut [Field; 2], value: Field) {
+        array[0] = value;
+    }
+
+    fn main() {
+        let mut array = [1, 2];
+        let other = [3, 4];
+        set_first(&mut array, other[1]);
+        assert(array[0] == 4);
+    }
+    "#;
+    assert!(get_aliased_argument_names(src).is_empty());
+}
+
+#[test]
+fn function_used_as_value_is_monomorphized() {
+    let src = r#"
+    fn double(x: Field) -> Field {
+        x * 2
+    }
+
+    fn main(x: Field) -> pub Field {
+        let f = double;
+        f(x)
+    }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let program = monomorphize(main_func_id, &mut context.def_interner).unwrap();
+    assert!(program.to_string().contains("let f@0 = double#0"), "{program}");
+}
+
+#[test]
+fn inclusive_range_is_desugared_into_exclusive_range() {
+    let monomorphized = |range: &str| {
+        let src = format!(
+            "fn main(n: u32) -> pub u32 {{
+                let mut sum = 0;
+                for i in {range} {{
+                    sum += i;
+                }}
+                sum
+            }}"
+        );
+        let (_program, mut context, errors) = get_program(&src);
+        assert_eq!(errors.len(), 0, "{errors:?}");
+
+        let main_func_id = context.def_interner.find_function("main").unwrap();
+        monomorphize(main_func_id, &mut context.def_interner).unwrap().to_string()
+    };
+
+    assert_eq!(monomorphized("1..=n"), monomorphized("1..n + 1"));
+    assert_eq!(monomorphized("0..=n - 1"), monomorphized("0..n - 1 + 1"));
+}
+
+#[test]
+fn nested_while_loops_in_unconstrained_fn() {
+    let src = r#"
+        unconstrained fn main(n: u32) -> pub u32 {
+            let mut sum = 0;
+            let mut i = 0;
+            while i < n {
+                let mut j = 0;
+                while j < i {
+                    if j == 3 {
+                        break;
+                    }
+                    sum += j;
+                    j += 1;
+                }
+                i += 1;
+            }
+            sum
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let program = monomorphize(main_func_id, &mut context.def_interner).unwrap().to_string();
+    assert_eq!(program.matches("while ").count(), 2, "{program}");
+}
+
+#[test]
+fn while_loop_body_with_constrain() {
+    let src = r#"
+        unconstrained fn main(mut x: u32) {
+            while x != 0 {
+                assert(x < 100, "x is too large");
+                x -= 1;
+            }
+        }
+    "#;
+    assert_eq!(get_program_errors(src).len(), 0);
+}
+
+#[test]
+fn while_loop_in_constrained_fn() {
+    let src = r#"
+        fn main(mut x: u32) {
+            while x != 0 {
+                x -= 1;
+            }
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::ResolverError(ResolverError::WhileInConstrainedFn { .. })
+    ));
+}
+
+#[test]
+fn while_loop_condition_must_be_bool() {
+    let src = r#"
+        unconstrained fn main(mut x: u32) {
+            while x {
+                x -= 1;
+            }
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::TypeError(TypeCheckError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn modulo_on_integers() {
+    let src = r#"
+        fn main(x: u32, y: u8) -> pub u32 {
+            let z = (y % 7) as u32;
+            x % 10 + z
+        }
+    "#;
+    assert_eq!(get_program_errors(src).len(), 0);
+}
+
+#[test]
+fn modulo_on_fields() {
+    let src = r#"
+        fn main(x: Field, y: Field) -> pub Field {
+            x % y
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(matches!(errors[0].0, CompilationError::TypeError(TypeCheckError::FieldModulo { .. })));
+}
+
+/// Returns the expected and actual types of each mismatch between the branches of an if.
+fn get_if_branch_mismatches(src: &str) -> Vec<(String, String)> {
+    let errors = get_program_errors(src);
+    vecmap(&errors, |(error, _)| match error {
+        CompilationError::TypeError(TypeCheckError::Context { err, .. }) => match err.as_ref() {
+            TypeCheckError::TypeMismatch { expected_typ, expr_typ, .. } => {
+                (expected_typ.clone(), expr_typ.clone())
+            }
+            _ => panic!("Expected a type mismatch, got {err:?}"),
+        },
+        _ => panic!("Expected an error between the if branches, got {error:?}"),
+    })
+}
+
+#[test]
+fn if_branches_of_different_lengths_without_a_slice_target_are_an_error() {
+    let src = r#"
+        fn main(c: bool) {
+            let _ = if c { [1, 2] } else { [1, 2, 3] };
+        }
+    "#;
+    assert_eq!(
+        get_if_branch_mismatches(src),
+        vec![("[Field; 2]".to_string(), "[Field; 3]".to_string())]
+    );
+}
+
+#[test]
+fn if_branches_must_have_the_same_element_type() {
+    let src = r#"
+        fn main(c: bool) {
+            let _ = if c { [1, 2] } else { [true, false, true] };
+        }
+    "#;
+    assert_eq!(
+        get_if_branch_mismatches(src),
+        vec![("[Field; 2]".to_string(), "[bool; 3]".to_string())]
+    );
+}
+
+#[test]
+fn methods_on_generic_structs_are_lowered_to_function_calls() {
+    let src = r#"
+        struct Pair<T> {
+            first: T,
+            second: T,
+        }
+
+        impl<T> Pair<T> {
+            fn new(first: T, second: T) -> Self {
+                Pair { first, second }
+            }
+
+            fn swap(self) -> Self {
+                Pair { first: self.second, second: self.first }
+            }
+
+            fn first(self) -> T {
+                self.first
+            }
+        }
+
+        fn main(x: u8, y: Field) -> pub Field {
+            let a = Pair::new(x, 1).swap().first();
+            let b = Pair::new(y, 2).swap().first();
+            b + a as Field
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let program = monomorphize(main_func_id, &mut context.def_interner).unwrap();
+    // `main` along with `new`, `swap` and `first` for each of `u8` and `Field`
+    assert_eq!(program.functions.len(), 7);
+}
+
+#[test]
+fn unresolved_method_call_points_at_call_site() {
+    let src = r#"
+        struct Foo {}
+
+        impl Foo {
+            fn bar(self) -> Self { self }
+        }
+
+        fn main() {
+            let foo = Foo {};
+            foo.baz();
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let CompilationError::TypeError(TypeCheckError::UnresolvedMethodCall {
+        method_name,
+        object_type,
+        span,
+    }) = &errors[0].0
+    else {
+        panic!("Expected an unresolved method call, got {:?}", errors[0].0);
+    };
+    assert_eq!(method_name, "baz");
+    assert_eq!(object_type.to_string(), "Foo");
+    assert_eq!(&src[span.start() as usize..span.end() as usize], "foo.baz()");
+}
+
+#[test]
+fn unbound_type_is_reported_with_a_suggestion() {
+    let src = r#"
+        fn main() {
+            let _empty = [];
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let error = monomorphize(main_func_id, &mut context.def_interner).unwrap_err();
+    let MonomorphizationError::TypeAnnotationsNeeded { location } = &error else {
+        panic!("Expected type annotations to be needed, got {error:?}");
+    };
+    assert_eq!(&src[location.span.start() as usize..location.span.end() as usize], "[]");
+
+    let diagnostic = FileDiagnostic::from(error).diagnostic;
+    assert_eq!(diagnostic.message, "Type annotations needed");
+    assert!(diagnostic.secondaries[0].message.contains("consider adding a type annotation"));
+}
+
+#[test]
+fn polymorphic_recursion_is_reported_with_its_cycle() {
+    let src = r#"
+        fn main() {
+            ping(0);
+        }
+
+        fn ping<T>(x: T) {
+            pong([x]);
+        }
+
+        fn pong<T>(x: T) {
+            ping((x, x));
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let error = monomorphize(main_func_id, &mut context.def_interner).unwrap_err();
+    let MonomorphizationError::RecursiveInstantiation { cycle, location, first_location, .. } =
+        &error
+    else {
+        panic!("Expected a recursive instantiation, got {error:?}");
+    };
+    assert_eq!(cycle, &["ping", "pong", "ping"]);
+    // Instantiations are located at the name of the function being called.
+    let source_from = |span: Span| &src[span.start() as usize..];
+    assert!(source_from(location.span).starts_with("ping((x, x))"));
+    assert!(source_from(first_location.span).starts_with("ping(0)"));
+
+    let diagnostic = FileDiagnostic::from(error).diagnostic;
+    assert_eq!(
+        diagnostic.message,
+        "Recursive calls to `ping` instantiate it with a different type every time"
+    );
+    assert_eq!(diagnostic.secondaries.len(), 2);
+    assert!(diagnostic.secondaries[1].message.contains("is first instantiated here"));
+    let rendered = diagnostic.to_string();
+    assert!(rendered
+        .ends_with("note: the recursion `ping` -> `pong` -> `ping` never reaches a fixed type"));
+}
+
+#[test]
+fn monomorphic_recursion_is_monomorphized_once() {
+    let src = r#"
+        fn main() {
+            let _ = countdown(3, 0);
+        }
+
+        fn countdown<T>(n: u32, x: T) -> T {
+            if n == 0 { x } else { countdown(n - 1, x) }
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let program = monomorphize(main_func_id, &mut context.def_interner).unwrap();
+    assert_eq!(program.functions.len(), 2);
+}
+
+#[test]
+fn annotated_empty_array_is_monomorphized() {
+    let src = r#"
+        fn sum<N>(values: [Field; N]) -> Field {
+            let mut sum = 0;
+            for i in 0..N {
+                sum += values[i];
+            }
+            sum
+        }
+
+        fn main() -> pub Field {
+            let empty: [Field; 0] = [];
+            sum(empty)
+        }
+    "#;
+    let (_program, mut context, errors) = get_program(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+
+    let main_func_id = context.def_interner.find_function("main").unwrap();
+    let program = monomorphize(main_func_id, &mut context.def_interner).unwrap();
+    assert!(program.functions[0].body.to_string().contains("let empty$"), "{program}");
+}
+
+#[test]
+fn call_errors_cover_the_whole_call() {
+    let src = "
+    fn foo(x: Field) -> Field {
+        x
+    }
+
+    fn main() {
+        let _ = foo(1, 2) + 3;
+    }
+    ";
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+
+    let CompilationError::TypeError(TypeCheckError::ParameterCountMismatch { span, .. }) =
+        &errors[0].0
+    else {
+        panic!("Expected a parameter count mismatch, got {:?}", errors[0].0);
+    };
+    assert_eq!(&src[span.start() as usize..span.end() as usize], "foo(1, 2)");
+}
+
+#[test]
+fn grouped_imports_can_mix_functions_modules_and_aliases() {
+    let src = r#"
+        mod foo {
+            pub fn bar() -> Field {
+                1
+            }
+
+            mod baz {
+                pub fn qux() -> Field {
+                    2
+                }
+            }
+        }
+
+        use foo::{bar, baz, baz::{qux as q}};
+
+        fn main() {
+            assert(bar() + baz::qux() + q() == 5);
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+}
+
+#[test]
+fn import_alias_clashing_with_a_local_function_is_rejected() {
+    let src = r#"
+        mod foo {
+            pub fn bar() -> Field {
+                1
+            }
+        }
+
+        use foo::{bar as local};
+
+        fn local() -> Field {
+            2
+        }
+
+        fn main() {
+            let _ = local();
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+
+    let CompilationError::DefinitionError(DefCollectorErrorKind::Duplicate {
+        typ,
+        first_def,
+        second_def,
+    }) = &errors[0].0
+    else {
+        panic!("Expected a duplicate definition error, got {:?}", errors[0].0);
+    };
+    assert_eq!(typ, &DuplicateType::Import);
+
+    // The error points at both the local function and the alias that clashes with it
+    let function_start = src.find("fn local").unwrap() + "fn ".len();
+    let alias_start = src.find("as local").unwrap() + "as ".len();
+    assert_eq!(first_def.span().start() as usize, function_start);
+    assert_eq!(second_def.span().start() as usize, alias_start);
+    assert_eq!(first_def, "local");
+    assert_eq!(second_def, "local");
+}
+
+#[test]
+fn slices_nested_within_arrays_of_tuples_or_structs_are_rejected() {
+    let src = r#"
+        struct Foo {
+            a: [Field],
+            b: [[Field; 2]; 3],
+        }
+
+        fn main() {
+            let _tuples: [(Field, [[Field]; 2])] = &[];
+            let _structs: [(Field, [Foo; 2])] = &[];
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    for (error, _) in errors {
+        assert!(
+            matches!(error, CompilationError::ResolverError(ResolverError::NestedSlices { .. })),
+            "Expected a nested slice error, got {error:?}"
+        );
+    }
+}
+
+#[test]
+fn slice_next_to_an_array_of_arrays_is_not_nested() {
+    let src = r#"
+        struct Foo {
+            a: [Field],
+            b: [[Field; 2]; 3],
+        }
+
+        fn main() {
+            let foo = Foo { a: &[1], b: [[1, 2]; 3] };
+            assert(foo.a[0] == foo.b[2][0]);
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 0, "{errors:?}");
+}
+
+#[test]
+fn array_containing_slices_cannot_be_returned_from_unconstrained_to_constrained() {
+    let src = r#"
+        struct Wrapper {
+            values: [Field],
+        }
+
+        unconstrained fn slices() -> (Field, [Wrapper; 2]) {
+            (0, [Wrapper { values: &[1] }, Wrapper { values: &[2] }])
+        }
+
+        fn main() {
+            let _ = slices();
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        matches!(
+            errors[0].0,
+            CompilationError::TypeError(
+                TypeCheckError::UnconstrainedSliceReturnToConstrained { .. }
+            )
+        ),
+        "Expected an unconstrained slice return error, got {:?}",
+        errors[0].0
+    );
+}
+
+#[test]
+fn overflowing_octal_and_binary_literals_are_reported_as_written() {
+    let src = r#"
+        fn main() {
+            let _octal: u8 = 0o400;
+            let _binary: u8 = 0b1_0000_0000;
+            let _max: u8 = 0b1111_1111 + 0o377 - 0xff;
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    for ((error, _), literal) in errors.iter().zip(["0o400", "0b1_0000_0000"]) {
+        let CompilationError::TypeError(TypeCheckError::OverflowingAssignment {
+            expr, span, ..
+        }) = error
+        else {
+            panic!("Expected an overflowing assignment, got {error:?}");
+        };
+        assert_eq!(expr, literal);
+        assert_eq!(&src[span.start() as usize..span.end() as usize], literal);
+    }
+}
+
+#[test]
+fn byte_strings_are_arrays_of_u8() {
+    let src = r#"
+        fn main() {
+            let bytes: [u8; 3] = b"a\xff\"";
+            let _empty: [u8; 0] = b"";
+            let _fields: [Field; 3] = b"abc";
+            assert(bytes[1] == 255);
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        matches!(errors[0].0, CompilationError::TypeError(TypeCheckError::TypeMismatch { .. })),
+        "{errors:?}"
+    );
+}
@@ -5,6 +5,8 @@ use std::collections::HashSet;
 #[test]
 fn test_name_shadowing() {
     let src = "
+    #![allow(unused_imports)]
+
     trait Default {
         fn default() -> Self;
     }
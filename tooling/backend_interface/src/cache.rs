@@ -0,0 +1,315 @@
+//! A content-addressed cache for the outputs of expensive backend invocations.
+//!
+//! Entries are keyed by a SHA-256 hash of everything which can affect the backend's output:
+//! the command being run, the serialized program, the serialized witness (if any),
+//! the backend binary (path, size and modification time) and the arguments passed to it.
+//! Each entry is stored as a single file in the cache directory named after the hex encoding of its key,
+//! so keys remain stable across compiler versions and platforms.
+//! Once the total size of the cache exceeds its maximum, the least recently used entries are evicted.
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use acvm::acir::{circuit::Program, native_types::WitnessStack};
+use sha2::{Digest, Sha256};
+
+use crate::{Backend, BackendError};
+
+/// The default maximum size of the cache directory in bytes.
+pub const DEFAULT_MAX_CACHE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// The output of a backend command along with whether it was read from the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cached<T> {
+    pub value: T,
+    pub cache_hit: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct BackendCache {
+    directory: PathBuf,
+    max_size: u64,
+}
+
+impl BackendCache {
+    pub fn new(directory: PathBuf) -> BackendCache {
+        BackendCache { directory, max_size: DEFAULT_MAX_CACHE_SIZE }
+    }
+
+    pub fn with_max_size(mut self, max_size: u64) -> BackendCache {
+        self.max_size = max_size;
+        self
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.directory.join(key)
+    }
+
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let path = self.entry_path(key);
+        let bytes = std::fs::read(&path).ok()?;
+        // Rewrite the entry so that its modification time reflects this use for LRU eviction.
+        let _ = std::fs::write(&path, &bytes);
+        Some(bytes)
+    }
+
+    fn insert(&self, key: &str, bytes: &[u8]) -> Result<(), BackendError> {
+        std::fs::create_dir_all(&self.directory)?;
+        std::fs::write(self.entry_path(key), bytes)?;
+        self.evict()
+    }
+
+    /// Removes the least recently used entries until the cache fits within its maximum size.
+    ///
+    /// The cache may be shared with other threads or processes which evict concurrently,
+    /// so entries which disappear while evicting are treated as already evicted.
+    fn evict(&self) -> Result<(), BackendError> {
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(&self.directory)? {
+            let entry = entry?;
+            let metadata = match entry.metadata() {
+                Ok(metadata) => metadata,
+                Err(error) if error.kind() == ErrorKind::NotFound => continue,
+                Err(error) => return Err(error.into()),
+            };
+            if metadata.is_file() {
+                let last_used = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                entries.push((last_used, metadata.len(), entry.path()));
+            }
+        }
+
+        for path in entries_to_evict(entries, self.max_size) {
+            match std::fs::remove_file(path) {
+                Err(error) if error.kind() != ErrorKind::NotFound => return Err(error.into()),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the paths of the least recently used `entries` which must be removed
+/// for the remaining entries to fit within `max_size`.
+///
+/// Each entry is given as its last use time, its size in bytes and its path.
+fn entries_to_evict(mut entries: Vec<(SystemTime, u64, PathBuf)>, max_size: u64) -> Vec<PathBuf> {
+    let mut total_size: u64 = entries.iter().map(|(_, size, _)| size).sum();
+    entries.sort();
+
+    let mut evicted = Vec::new();
+    for (_, size, path) in entries {
+        if total_size <= max_size {
+            break;
+        }
+        evicted.push(path);
+        total_size -= size;
+    }
+    evicted
+}
+
+impl Backend {
+    /// Computes the cache key for running `command` with the given inputs on this backend.
+    ///
+    /// The key is the hex encoded SHA-256 hash of the inputs, each of which is length-prefixed
+    /// so that the boundaries between them are unambiguous.
+    fn cache_key(&self, command: &str, program: &Program, witness: Option<&[u8]>) -> String {
+        let (binary_size, binary_modified) =
+            binary_fingerprint(self.binary_path()).map_or((0, 0), |(size, modified)| {
+                let modified = modified
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_nanos());
+                (size, modified)
+            });
+
+        let mut hasher = Sha256::new();
+        let mut update = |input: &[u8]| {
+            hasher.update((input.len() as u64).to_le_bytes());
+            hasher.update(input);
+        };
+        update(command.as_bytes());
+        update(&Program::serialize_program(program));
+        update(&[u8::from(witness.is_some())]);
+        update(witness.unwrap_or_default());
+        update(self.binary_path().to_string_lossy().as_bytes());
+        update(&binary_size.to_le_bytes());
+        update(&binary_modified.to_le_bytes());
+        update(self.crs_directory().to_string_lossy().as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    /// The same as [`Backend::get_exact_circuit_size`] but the result is read from `cache` if available.
+    pub fn get_exact_circuit_size_cached(
+        &self,
+        program: &Program,
+        cache: &BackendCache,
+    ) -> Result<Cached<u32>, BackendError> {
+        let key = self.cache_key("gates", program, None);
+        if let Some(bytes) = cache.get(&key) {
+            if let Ok(bytes) = bytes.try_into() {
+                return Ok(Cached { value: u32::from_le_bytes(bytes), cache_hit: true });
+            }
+        }
+
+        let circuit_size = self.get_exact_circuit_size(program)?;
+        cache.insert(&key, &circuit_size.to_le_bytes())?;
+        Ok(Cached { value: circuit_size, cache_hit: false })
+    }
+
+    /// The same as [`Backend::prove`] but the proof is read from `cache` if available.
+    pub fn prove_cached(
+        &self,
+        program: &Program,
+        witness_stack: WitnessStack,
+        cache: &BackendCache,
+    ) -> Result<Cached<Vec<u8>>, BackendError> {
+        let serialized_witnesses: Vec<u8> =
+            witness_stack.clone().try_into().expect("could not serialize witness map");
+        let key = self.cache_key("prove", program, Some(&serialized_witnesses));
+        if let Some(proof) = cache.get(&key) {
+            return Ok(Cached { value: proof, cache_hit: true });
+        }
+
+        let proof = self.prove(program, witness_stack)?;
+        cache.insert(&key, &proof)?;
+        Ok(Cached { value: proof, cache_hit: false })
+    }
+}
+
+/// Identifies the version of the backend binary without having to invoke it.
+fn binary_fingerprint(binary_path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = std::fs::metadata(binary_path).ok()?;
+    Some((metadata.len(), metadata.modified().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::BackendCache;
+
+    /// Writes a backend script which records each invocation in `counter_path`
+    /// and reports a circuit size of zero.
+    #[cfg(unix)]
+    fn counting_backend(
+        directory: &std::path::Path,
+        counter_path: &std::path::Path,
+    ) -> crate::Backend {
+        use std::os::unix::fs::PermissionsExt;
+
+        let binary_path = directory.join("backend_binary");
+        let script = format!(
+            "#!/bin/sh\necho invoked >> {}\nprintf '\\000\\000\\000\\000\\000\\000\\000\\000'\n",
+            counter_path.display()
+        );
+        std::fs::write(&binary_path, script).unwrap();
+        std::fs::set_permissions(&binary_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        crate::Backend { name: "counting_backend".to_string(), binary_path }
+    }
+
+    #[cfg(unix)]
+    fn invocations(counter_path: &std::path::Path) -> usize {
+        std::fs::read_to_string(counter_path).map_or(0, |contents| contents.lines().count())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn gates_are_cached_between_runs() {
+        use acvm::acir::circuit::{Circuit, Program};
+
+        let temp_directory = tempdir().expect("could not create a temporary directory");
+        let counter_path = temp_directory.path().join("invocations");
+        let backend = counting_backend(temp_directory.path(), &counter_path);
+        let cache = BackendCache::new(temp_directory.path().join("cache"));
+
+        let program = Program { functions: vec![Circuit::default()], ..Program::default() };
+
+        let first = backend.get_exact_circuit_size_cached(&program, &cache).unwrap();
+        assert!(!first.cache_hit);
+        assert_eq!(invocations(&counter_path), 1);
+
+        let second = backend.get_exact_circuit_size_cached(&program, &cache).unwrap();
+        assert!(second.cache_hit);
+        assert_eq!(second.value, first.value);
+        assert_eq!(invocations(&counter_path), 1, "backend should not be invoked on a cache hit");
+
+        // A different circuit must not hit the cache.
+        let other_program = Program {
+            functions: vec![Circuit { current_witness_index: 1, ..Circuit::default() }],
+            ..Program::default()
+        };
+        let third = backend.get_exact_circuit_size_cached(&other_program, &cache).unwrap();
+        assert!(!third.cache_hit);
+        assert_eq!(invocations(&counter_path), 2);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_entries() {
+        use std::path::PathBuf;
+        use std::time::{Duration, SystemTime};
+
+        let at = |seconds| SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+        let entries = vec![
+            (at(30), 4, PathBuf::from("c")),
+            (at(10), 4, PathBuf::from("a")),
+            (at(20), 4, PathBuf::from("b")),
+        ];
+
+        assert_eq!(super::entries_to_evict(entries.clone(), 12), Vec::<PathBuf>::new());
+        assert_eq!(super::entries_to_evict(entries.clone(), 8), vec![PathBuf::from("a")]);
+        assert_eq!(
+            super::entries_to_evict(entries, 5),
+            vec![PathBuf::from("a"), PathBuf::from("b")]
+        );
+    }
+
+    #[test]
+    fn concurrent_evictions_of_the_same_entries_succeed() {
+        use std::sync::Barrier;
+
+        let temp_directory = tempdir().expect("could not create a temporary directory");
+        let cache = BackendCache::new(temp_directory.path().to_path_buf()).with_max_size(0);
+        let barrier = Barrier::new(2);
+
+        for round in 0..50 {
+            for entry in 0..8 {
+                std::fs::write(temp_directory.path().join(format!("{round}_{entry}")), [0; 16])
+                    .unwrap();
+            }
+
+            std::thread::scope(|scope| {
+                let evictions = [(); 2].map(|_| {
+                    scope.spawn(|| {
+                        barrier.wait();
+                        cache.evict()
+                    })
+                });
+                for eviction in evictions {
+                    eviction.join().unwrap().expect("eviction should tolerate missing entries");
+                }
+            });
+
+            let remaining = std::fs::read_dir(temp_directory.path()).unwrap().count();
+            assert_eq!(remaining, 0);
+        }
+    }
+
+    #[test]
+    fn cache_keys_are_stable_sha256_hashes() {
+        use acvm::acir::circuit::{Circuit, Program};
+
+        let backend = crate::Backend {
+            name: "missing_backend".to_string(),
+            binary_path: "/does/not/exist".into(),
+        };
+        let program = Program { functions: vec![Circuit::default()], ..Program::default() };
+
+        let key = backend.cache_key("gates", &program, None);
+        assert_eq!(key.len(), 64);
+        assert!(key.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(key, backend.cache_key("gates", &program, None));
+        assert_ne!(key, backend.cache_key("prove", &program, None));
+        assert_ne!(key, backend.cache_key("gates", &program, Some(&[])));
+    }
+}
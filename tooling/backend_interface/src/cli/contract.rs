@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use crate::BackendError;
 
-use super::string_from_stderr;
+use super::{execute_backend_cmd, string_from_stderr};
 
 /// VerifyCommand will call the barretenberg binary
 /// to return a solidity library with the verification key
@@ -29,7 +29,7 @@ impl ContractCommand {
             .arg("-o")
             .arg("-");
 
-        let output = command.output()?;
+        let output = execute_backend_cmd(&mut command, true)?;
 
         if output.status.success() {
             String::from_utf8(output.stdout)
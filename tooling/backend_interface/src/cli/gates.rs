@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use crate::BackendError;
 
-use super::string_from_stderr;
+use super::{execute_backend_cmd, string_from_stderr};
 
 /// GatesCommand will call the barretenberg binary
 /// to return the number of gates needed to create a proof
@@ -14,13 +14,11 @@ pub(crate) struct GatesCommand {
 
 impl GatesCommand {
     pub(crate) fn run(self, binary_path: &Path) -> Result<u32, BackendError> {
-        let output = std::process::Command::new(binary_path)
-            .arg("gates")
-            .arg("-c")
-            .arg(self.crs_path)
-            .arg("-b")
-            .arg(self.bytecode_path)
-            .output()?;
+        let mut command = std::process::Command::new(binary_path);
+
+        command.arg("gates").arg("-c").arg(self.crs_path).arg("-b").arg(self.bytecode_path);
+
+        let output = execute_backend_cmd(&mut command, true)?;
 
         if !output.status.success() {
             return Err(BackendError::CommandFailed(string_from_stderr(&output.stderr)));
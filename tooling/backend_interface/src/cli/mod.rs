@@ -18,6 +18,39 @@ pub(crate) use version::VersionCommand;
 pub(crate) use vk_as_fields::VkAsFieldsCommand;
 pub(crate) use write_vk::WriteVkCommand;
 
+use std::process::{Command, Output};
+
+use crate::BackendError;
+
+/// The tracing target under which any output of the backend binary is logged.
+pub const BACKEND_LOG_TARGET: &str = "backend";
+
+/// Runs a backend command to completion, capturing its output.
+///
+/// Human readable output is forwarded line by line to the logging layer at info level, so that it can be silenced
+/// while still being available to give context to errors. Commands which write their result to stdout
+/// (e.g. a proof) should set `stdout_is_data` so that only stderr is logged.
+pub(crate) fn execute_backend_cmd(
+    command: &mut Command,
+    stdout_is_data: bool,
+) -> Result<Output, BackendError> {
+    tracing::debug!(target: BACKEND_LOG_TARGET, "Running backend command {command:?}");
+    let output = command.output()?;
+
+    if !stdout_is_data {
+        log_backend_output(&output.stdout);
+    }
+    log_backend_output(&output.stderr);
+
+    Ok(output)
+}
+
+fn log_backend_output(output: &[u8]) {
+    for line in String::from_utf8_lossy(output).lines().filter(|line| !line.trim().is_empty()) {
+        tracing::info!(target: BACKEND_LOG_TARGET, "{line}");
+    }
+}
+
 #[test]
 fn no_command_provided_works() -> Result<(), crate::BackendError> {
     // This is a simple test to check that the binaries work
@@ -4,7 +4,7 @@ use acvm::FieldElement;
 
 use crate::BackendError;
 
-use super::string_from_stderr;
+use super::{execute_backend_cmd, string_from_stderr};
 
 /// `ProofAsFieldsCommand` will call the barretenberg binary
 /// to split a proof into a representation as [`FieldElement`]s.
@@ -26,7 +26,7 @@ impl ProofAsFieldsCommand {
             .arg("-o")
             .arg("-");
 
-        let output = command.output()?;
+        let output = execute_backend_cmd(&mut command, true)?;
         if output.status.success() {
             let string_output = String::from_utf8(output.stdout).unwrap();
             serde_json::from_str(&string_output)
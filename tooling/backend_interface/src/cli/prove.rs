@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use crate::BackendError;
 
-use super::string_from_stderr;
+use super::{execute_backend_cmd, string_from_stderr};
 
 /// ProveCommand will call the barretenberg binary
 /// to create a proof, given the witness and the bytecode.
@@ -32,7 +32,7 @@ impl ProveCommand {
             .arg("-o")
             .arg("-");
 
-        let output = command.output()?;
+        let output = execute_backend_cmd(&mut command, true)?;
         if output.status.success() {
             Ok(output.stdout)
         } else {
@@ -2,6 +2,8 @@ use std::path::{Path, PathBuf};
 
 use crate::BackendError;
 
+use super::execute_backend_cmd;
+
 /// VerifyCommand will call the barretenberg binary
 /// to verify a proof
 pub(crate) struct VerifyCommand {
@@ -23,7 +25,7 @@ impl VerifyCommand {
             .arg("-k")
             .arg(self.vk_path);
 
-        let output = command.output()?;
+        let output = execute_backend_cmd(&mut command, false)?;
 
         // We currently do not distinguish between an invalid proof and an error inside the backend.
         Ok(output.status.success())
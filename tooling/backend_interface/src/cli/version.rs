@@ -2,7 +2,7 @@ use std::path::Path;
 
 use crate::BackendError;
 
-use super::string_from_stderr;
+use super::{execute_backend_cmd, string_from_stderr};
 
 /// VersionCommand will call the backend binary
 /// to query installed version.
@@ -14,7 +14,7 @@ impl VersionCommand {
 
         command.arg("--version");
 
-        let output = command.output()?;
+        let output = execute_backend_cmd(&mut command, true)?;
         if output.status.success() {
             match String::from_utf8(output.stdout) {
                 Ok(result) => Ok(result),
@@ -4,7 +4,7 @@ use acvm::FieldElement;
 
 use crate::BackendError;
 
-use super::string_from_stderr;
+use super::{execute_backend_cmd, string_from_stderr};
 
 /// VkAsFieldsCommand will call the barretenberg binary
 /// to split a verification key into a representation as [`FieldElement`]s.
@@ -23,7 +23,7 @@ impl VkAsFieldsCommand {
 
         command.arg("vk_as_fields").arg("-k").arg(self.vk_path).arg("-o").arg("-");
 
-        let output = command.output()?;
+        let output = execute_backend_cmd(&mut command, true)?;
         if output.status.success() {
             let string_output = String::from_utf8(output.stdout).unwrap();
             let mut fields: Vec<FieldElement> = serde_json::from_str(&string_output)
@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use super::string_from_stderr;
+use super::{execute_backend_cmd, string_from_stderr};
 use crate::BackendError;
 
 /// WriteCommand will call the barretenberg binary
@@ -25,7 +25,7 @@ impl WriteVkCommand {
             .arg("-o")
             .arg(self.vk_path_output);
 
-        let output = command.output()?;
+        let output = execute_backend_cmd(&mut command, false)?;
         if output.status.success() {
             Ok(())
         } else {
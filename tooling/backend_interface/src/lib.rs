@@ -3,6 +3,7 @@
 
 use std::path::PathBuf;
 
+mod cache;
 mod cli;
 mod download;
 mod proof_system;
@@ -10,7 +11,9 @@ mod smart_contract;
 
 pub use bb_abstraction_leaks::ACVM_BACKEND_BARRETENBERG;
 use bb_abstraction_leaks::BB_VERSION;
+pub use cache::{BackendCache, Cached, DEFAULT_MAX_CACHE_SIZE};
 use cli::VersionCommand;
+pub use cli::BACKEND_LOG_TARGET;
 pub use download::download_backend;
 use tracing::warn;
 
@@ -345,10 +345,15 @@ impl<'a, B: BlackBoxFunctionSolver> DebugContext<'a, B> {
                 self.brillig_solver = Some(solver);
                 self.handle_foreign_call(foreign_call)
             }
-            Err(err) => DebugCommandResult::Error(NargoError::ExecutionError(
-                // TODO: debugger does not not handle multiple acir calls
-                ExecutionError::SolvingError(err, None),
-            )),
+            Err(err) => {
+                // Keep the solver so that the failing Brillig opcode and the VM memory can
+                // still be inspected.
+                self.brillig_solver = Some(solver);
+                DebugCommandResult::Error(NargoError::ExecutionError(
+                    // TODO: debugger does not not handle multiple acir calls
+                    ExecutionError::SolvingError(err, None),
+                ))
+            }
         }
     }
 
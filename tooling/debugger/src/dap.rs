@@ -26,6 +26,7 @@ use dap::types::{
     StoppedEventReason, Thread, Variable,
 };
 use nargo::artifacts::debug::DebugArtifact;
+use nargo::NargoError;
 
 use fm::FileId;
 use noirc_driver::CompiledProgram;
@@ -45,6 +46,7 @@ pub struct DapSession<'a, R: Read, W: Write, B: BlackBoxFunctionSolver> {
 enum ScopeReferences {
     Locals = 1,
     WitnessMap = 2,
+    BrilligMemory = 3,
     InvalidScope = 0,
 }
 
@@ -53,6 +55,7 @@ impl From<i64> for ScopeReferences {
         match value {
             1 => Self::Locals,
             2 => Self::WitnessMap,
+            3 => Self::BrilligMemory,
             _ => Self::InvalidScope,
         }
     }
@@ -377,10 +380,10 @@ impl<'a, R: Read, W: Write, B: BlackBoxFunctionSolver> DapSession<'a, R, W, B> {
             DebugCommandResult::Error(err) => {
                 self.server.send_event(Event::Stopped(StoppedEventBody {
                     reason: StoppedEventReason::Exception,
-                    description: Some(format!("{err:?}")),
+                    description: Some(String::from("Paused on unsatisfied constraint")),
                     thread_id: Some(0),
                     preserve_focus_hint: Some(false),
-                    text: None,
+                    text: Some(self.render_failure(&err)),
                     all_threads_stopped: Some(false),
                     hit_breakpoint_ids: None,
                 }))?;
@@ -389,6 +392,21 @@ impl<'a, R: Read, W: Write, B: BlackBoxFunctionSolver> DapSession<'a, R, W, B> {
         Ok(())
     }
 
+    /// Renders an execution failure along with the source location of the failing opcode.
+    fn render_failure(&self, err: &NargoError) -> String {
+        let Some(location) = self
+            .context
+            .get_current_source_location()
+            .and_then(|locations| locations.last().copied())
+        else {
+            return err.to_string();
+        };
+        let path = self.debug_artifact.file_map[&location.file].path.display();
+        let line_number = self.debug_artifact.location_line_number(location).unwrap();
+        let column_number = self.debug_artifact.location_column_number(location).unwrap();
+        format!("{err}\n  at {path}:{line_number}:{column_number}")
+    }
+
     fn get_next_breakpoint_id(&mut self) -> BreakpointId {
         let id = self.next_breakpoint_id;
         self.next_breakpoint_id += 1;
@@ -528,20 +546,26 @@ impl<'a, R: Read, W: Write, B: BlackBoxFunctionSolver> DapSession<'a, R, W, B> {
     }
 
     fn handle_scopes(&mut self, req: Request) -> Result<(), ServerError> {
-        self.server.respond(req.success(ResponseBody::Scopes(ScopesResponse {
-            scopes: vec![
-                Scope {
-                    name: String::from("Locals"),
-                    variables_reference: ScopeReferences::Locals as i64,
-                    ..Scope::default()
-                },
-                Scope {
-                    name: String::from("Witness Map"),
-                    variables_reference: ScopeReferences::WitnessMap as i64,
-                    ..Scope::default()
-                },
-            ],
-        })))?;
+        let mut scopes = vec![
+            Scope {
+                name: String::from("Locals"),
+                variables_reference: ScopeReferences::Locals as i64,
+                ..Scope::default()
+            },
+            Scope {
+                name: String::from("Witness Map"),
+                variables_reference: ScopeReferences::WitnessMap as i64,
+                ..Scope::default()
+            },
+        ];
+        if self.context.is_executing_brillig() {
+            scopes.push(Scope {
+                name: String::from("Brillig Memory"),
+                variables_reference: ScopeReferences::BrilligMemory as i64,
+                ..Scope::default()
+            });
+        }
+        self.server.respond(req.success(ResponseBody::Scopes(ScopesResponse { scopes })))?;
         Ok(())
     }
 
@@ -577,6 +601,23 @@ impl<'a, R: Read, W: Write, B: BlackBoxFunctionSolver> DapSession<'a, R, W, B> {
             .collect()
     }
 
+    /// The raw memory of the Brillig VM, which holds its registers. Unset cells are omitted.
+    fn build_brillig_memory(&self) -> Vec<Variable> {
+        let Some(memory) = self.context.get_brillig_memory() else {
+            return vec![];
+        };
+        memory
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| value.bit_size() > 0)
+            .map(|(index, value)| Variable {
+                name: format!("{index}"),
+                value: format!("{value}"),
+                ..Variable::default()
+            })
+            .collect()
+    }
+
     fn handle_variables(&mut self, req: Request) -> Result<(), ServerError> {
         let Command::Variables(ref args) = req.command else {
             unreachable!("handle_variables called on a different request");
@@ -585,6 +626,7 @@ impl<'a, R: Read, W: Write, B: BlackBoxFunctionSolver> DapSession<'a, R, W, B> {
         let variables: Vec<_> = match scope {
             ScopeReferences::Locals => self.build_local_variables(),
             ScopeReferences::WitnessMap => self.build_witness_map(),
+            ScopeReferences::BrilligMemory => self.build_brillig_memory(),
             _ => {
                 eprintln!(
                     "handle_variables with an unknown variables_reference {}",
@@ -0,0 +1,181 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+use assert_cmd::cargo::cargo_bin;
+use serde_json::{json, Value};
+
+/// A minimal Debug Adapter Protocol client talking to `nargo dap` over stdio.
+struct DapClient {
+    process: Child,
+    input: ChildStdin,
+    output: BufReader<ChildStdout>,
+    seq: i64,
+    /// Events received while waiting for a response, oldest first.
+    events: Vec<Value>,
+}
+
+impl DapClient {
+    fn start() -> Self {
+        let mut process = Command::new(cargo_bin("nargo"))
+            .arg("dap")
+            .env("NARGO_BACKEND_PATH", "/dev/null")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Could not start nargo dap");
+        let input = process.stdin.take().unwrap();
+        let output = BufReader::new(process.stdout.take().unwrap());
+        Self { process, input, output, seq: 0, events: Vec::new() }
+    }
+
+    fn send(&mut self, command: &str, arguments: Value) -> i64 {
+        self.seq += 1;
+        let message = json!({
+            "seq": self.seq,
+            "type": "request",
+            "command": command,
+            "arguments": arguments,
+        })
+        .to_string();
+        write!(self.input, "Content-Length: {}\r\n\r\n{message}", message.len()).unwrap();
+        self.input.flush().unwrap();
+        self.seq
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            self.output.read_line(&mut header).expect("Could not read message header");
+            let header = header.trim();
+            if header.is_empty() {
+                break;
+            }
+            if let Some(length) = header.strip_prefix("Content-Length: ") {
+                content_length = length.parse().unwrap();
+            }
+        }
+        assert!(content_length > 0, "nargo dap closed its output");
+        let mut content = vec![0; content_length];
+        self.output.read_exact(&mut content).unwrap();
+        serde_json::from_slice(&content).unwrap()
+    }
+
+    /// Sends a request and waits for its response, which must be successful.
+    fn request(&mut self, command: &str, arguments: Value) -> Value {
+        let seq = self.send(command, arguments);
+        loop {
+            let message = self.receive();
+            match message["type"].as_str() {
+                Some("response") if message["request_seq"] == seq => {
+                    assert_eq!(message["success"], true, "{command} failed: {message}");
+                    return message["body"].clone();
+                }
+                Some("event") => self.events.push(message),
+                _ => (),
+            }
+        }
+    }
+
+    /// Waits for the next event with the given name and returns its body.
+    fn event(&mut self, name: &str) -> Value {
+        if let Some(index) = self.events.iter().position(|event| event["event"] == name) {
+            return self.events.remove(index)["body"].clone();
+        }
+        loop {
+            let message = self.receive();
+            if message["type"] == "event" && message["event"] == name {
+                return message["body"].clone();
+            }
+        }
+    }
+
+    fn launch(&mut self, test_program_dir: &Path) {
+        self.request("initialize", json!({ "adapterID": "noir" }));
+        self.request("launch", json!({ "projectFolder": test_program_dir }));
+        self.event("initialized");
+        assert_eq!(self.event("stopped")["reason"], "entry");
+    }
+
+    fn variables(&mut self, scope: &str) -> Vec<Value> {
+        let scopes = self.request("scopes", json!({ "frameId": 0 }));
+        let scope = scopes["scopes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|candidate| candidate["name"] == scope)
+            .unwrap_or_else(|| panic!("Missing scope {scope} in {scopes}"))
+            .clone();
+        let variables =
+            self.request("variables", json!({ "variablesReference": scope["variablesReference"] }));
+        variables["variables"].as_array().unwrap().clone()
+    }
+
+    fn disconnect(mut self) {
+        self.request("disconnect", json!({}));
+        self.process.wait().expect("nargo dap did not exit");
+    }
+}
+
+fn test_program_dir(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_programs")
+        .join(path)
+        .canonicalize()
+        .unwrap()
+}
+
+#[test]
+fn dap_session_stops_at_source_breakpoint() {
+    let program_dir = test_program_dir("execution_success/1_mul");
+    let source = program_dir.join("src/main.nr");
+
+    let mut client = DapClient::start();
+    client.launch(&program_dir);
+
+    let breakpoints = client.request(
+        "setBreakpoints",
+        json!({ "source": { "path": source }, "breakpoints": [{ "line": 5 }] }),
+    );
+    assert_eq!(breakpoints["breakpoints"][0]["verified"], true, "{breakpoints}");
+
+    client.request("continue", json!({ "threadId": 0 }));
+    assert_eq!(client.event("stopped")["reason"], "breakpoint");
+
+    let stack_trace = client.request("stackTrace", json!({ "threadId": 0 }));
+    assert_eq!(stack_trace["stackFrames"][0]["line"], 5, "{stack_trace}");
+
+    // The parameters are already known when stopping in the body of `main`.
+    let locals = client.variables("Locals");
+    for name in ["x", "y", "z"] {
+        assert!(locals.iter().any(|local| local["name"] == name), "{name} missing in {locals:?}");
+    }
+
+    // Programs are debugged as Brillig, so the VM's memory can be inspected.
+    assert!(!client.variables("Brillig Memory").is_empty());
+
+    client.request("next", json!({ "threadId": 0 }));
+    assert_eq!(client.event("stopped")["reason"], "pause");
+    let stack_trace = client.request("stackTrace", json!({ "threadId": 0 }));
+    assert_eq!(stack_trace["stackFrames"][0]["line"], 6, "{stack_trace}");
+
+    client.disconnect();
+}
+
+#[test]
+fn dap_session_stops_on_failed_constraint() {
+    let program_dir = test_program_dir("execution_failure/brillig_assert_fail");
+
+    let mut client = DapClient::start();
+    client.launch(&program_dir);
+
+    client.request("continue", json!({ "threadId": 0 }));
+    let stopped = client.event("stopped");
+    assert_eq!(stopped["reason"], "exception");
+    let text = stopped["text"].as_str().unwrap();
+    assert!(text.contains("src/main.nr:9:"), "{text}");
+
+    client.disconnect();
+}
@@ -43,8 +43,8 @@ use notifications::{
 };
 use requests::{
     on_code_lens_request, on_formatting, on_goto_declaration_request, on_goto_definition_request,
-    on_goto_type_definition_request, on_initialize, on_profile_run_request, on_shutdown,
-    on_test_run_request, on_tests_request,
+    on_goto_type_definition_request, on_hover_request, on_initialize, on_profile_run_request,
+    on_shutdown, on_test_run_request, on_tests_request,
 };
 use serde_json::Value as JsonValue;
 use thiserror::Error;
@@ -113,6 +113,7 @@ impl NargoLspService {
             .request::<request::GotoDefinition, _>(on_goto_definition_request)
             .request::<request::GotoDeclaration, _>(on_goto_declaration_request)
             .request::<request::GotoTypeDefinition, _>(on_goto_type_definition_request)
+            .request::<request::HoverRequest, _>(on_hover_request)
             .notification::<notification::Initialized>(on_initialized)
             .notification::<notification::DidChangeConfiguration>(on_did_change_configuration)
             .notification::<notification::DidOpenTextDocument>(on_did_open_text_document)
@@ -194,6 +195,10 @@ fn byte_span_to_range<'a, F: files::Files<'a> + ?Sized>(
     file_id: F::FileId,
     span: ops::Range<usize>,
 ) -> Option<Range> {
+    // The first line of a file starts after any byte order mark so spans must not point into it.
+    let line_start = files.line_range(file_id, 0).map_or(0, |line_range| line_range.start);
+    let span = span.start.max(line_start)..span.end.max(line_start);
+
     if let Ok(codespan_range) = codespan_lsp::byte_span_to_range(files, file_id, span) {
         // We have to manually construct a Range because the codespan_lsp restricts lsp-types to the wrong version range
         // TODO: codespan is unmaintained and we should probably subsume it. Ref https://github.com/brendanzab/codespan/issues/345
@@ -241,6 +246,7 @@ pub(crate) fn resolve_workspace_for_source_path(file_path: &Path) -> Result<Work
             name: CrateName::from_str(parent_folder)
                 .map_err(|err| LspError::WorkspaceResolutionError(err.to_string()))?,
             dependencies: BTreeMap::new(),
+            fuzz_ranges: BTreeMap::new(),
         };
         let workspace = Workspace {
             root_dir: PathBuf::from(parent_folder),
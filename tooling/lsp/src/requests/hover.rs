@@ -0,0 +1,121 @@
+use std::future::{self, Future};
+
+use crate::types::HoverResult;
+use crate::LspState;
+use crate::{parse_diff, resolve_workspace_for_source_path};
+use async_lsp::{ErrorCode, ResponseError};
+
+use lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind};
+
+use nargo::insert_all_files_for_workspace_into_file_manager;
+use noirc_driver::file_manager_with_stdlib;
+
+use super::position_to_byte_index;
+
+pub(crate) fn on_hover_request(
+    state: &mut LspState,
+    params: HoverParams,
+) -> impl Future<Output = Result<HoverResult, ResponseError>> {
+    let result = on_hover_inner(state, params);
+    future::ready(result)
+}
+
+fn on_hover_inner(state: &mut LspState, params: HoverParams) -> Result<HoverResult, ResponseError> {
+    let file_path =
+        params.text_document_position_params.text_document.uri.to_file_path().map_err(|_| {
+            ResponseError::new(ErrorCode::REQUEST_FAILED, "URI is not a valid file path")
+        })?;
+
+    let workspace = resolve_workspace_for_source_path(file_path.as_path()).unwrap();
+    let package = workspace.members.first().unwrap();
+
+    let package_root_path: String = package.root_dir.as_os_str().to_string_lossy().into();
+
+    let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
+    let parsed_files = parse_diff(&workspace_file_manager, state);
+
+    let (mut context, crate_id) =
+        nargo::prepare_package(&workspace_file_manager, &parsed_files, package);
+
+    let interner;
+    if let Some(def_interner) = state.cached_definitions.get(&package_root_path) {
+        interner = def_interner;
+    } else {
+        // We ignore the warnings and errors produced by compilation while resolving the type
+        let _ = noirc_driver::check_crate(&mut context, crate_id, false, false, false);
+        interner = &context.def_interner;
+    }
+
+    let files = context.file_manager.as_file_map();
+    let file_id = context.file_manager.name_to_id(file_path.clone()).ok_or(ResponseError::new(
+        ErrorCode::REQUEST_FAILED,
+        format!("Could not find file in file manager. File path: {:?}", file_path),
+    ))?;
+    let byte_index =
+        position_to_byte_index(files, file_id, &params.text_document_position_params.position)
+            .map_err(|err| {
+                ResponseError::new(
+                    ErrorCode::REQUEST_FAILED,
+                    format!("Could not convert position to byte index. Error: {:?}", err),
+                )
+            })?;
+
+    let search_for_location = noirc_errors::Location {
+        file: file_id,
+        span: noirc_errors::Span::single_char(byte_index as u32),
+    };
+
+    let hover_response = interner.get_type_at_location(search_for_location).map(|typ| Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!("```noir\n{typ}\n```"),
+        }),
+        range: None,
+    });
+
+    Ok(hover_response)
+}
+
+#[cfg(test)]
+mod hover_tests {
+
+    use acvm::blackbox_solver::StubbedBlackBoxSolver;
+    use async_lsp::ClientSocket;
+    use lsp_types::{Position, Url};
+    use tokio::test;
+
+    use super::*;
+
+    #[test]
+    async fn test_on_hover() {
+        let client = ClientSocket::new_closed();
+        let mut state = LspState::new(&client, StubbedBlackBoxSolver);
+
+        let root_path = std::env::current_dir()
+            .unwrap()
+            .join("../../test_programs/execution_success/7_function")
+            .canonicalize()
+            .expect("Could not resolve root path");
+        let noir_text_document = Url::from_file_path(root_path.join("src/main.nr").as_path())
+            .expect("Could not convert text document path to URI");
+
+        let params = HoverParams {
+            text_document_position_params: lsp_types::TextDocumentPositionParams {
+                text_document: lsp_types::TextDocumentIdentifier { uri: noir_text_document },
+                // The argument `ss` in `test_multiple4(ss)`
+                position: Position { line: 94, character: 19 },
+            },
+            work_done_progress_params: Default::default(),
+        };
+
+        let response =
+            on_hover_request(&mut state, params).await.expect("Could execute on_hover_request");
+
+        let Some(Hover { contents: HoverContents::Markup(markup), .. }) = response else {
+            panic!("Expected a hover with markup contents, got {response:?}");
+        };
+        assert_eq!(markup.kind, MarkupKind::Markdown);
+        assert_eq!(markup.value, "```noir\nmy_struct\n```");
+    }
+}
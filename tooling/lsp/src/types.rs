@@ -1,6 +1,7 @@
 use fm::FileId;
 use lsp_types::{
-    DeclarationCapability, DefinitionOptions, OneOf, TypeDefinitionProviderCapability,
+    DeclarationCapability, DefinitionOptions, HoverProviderCapability, OneOf,
+    TypeDefinitionProviderCapability,
 };
 use noirc_driver::DebugFile;
 use noirc_errors::{debug_info::OpCodesCount, Location};
@@ -28,7 +29,7 @@ pub(crate) mod request {
     // Re-providing lsp_types that we don't need to override
     pub(crate) use lsp_types::request::{
         CodeLensRequest as CodeLens, Formatting, GotoDeclaration, GotoDefinition,
-        GotoTypeDefinition, Shutdown,
+        GotoTypeDefinition, HoverRequest, Shutdown,
     };
 
     #[derive(Debug)]
@@ -125,6 +126,10 @@ pub(crate) struct ServerCapabilities {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) type_definition_provider: Option<TypeDefinitionProviderCapability>,
 
+    /// The server provides hover support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hover_provider: Option<HoverProviderCapability>,
+
     /// The server provides code lens.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) code_lens_provider: Option<CodeLensOptions>,
@@ -234,3 +239,4 @@ pub(crate) struct NargoProfileRunResult {
 pub(crate) type CodeLensResult = Option<Vec<CodeLens>>;
 pub(crate) type GotoDefinitionResult = Option<lsp_types::GotoDefinitionResponse>;
 pub(crate) type GotoDeclarationResult = Option<lsp_types::request::GotoDeclarationResponse>;
+pub(crate) type HoverResult = Option<lsp_types::Hover>;
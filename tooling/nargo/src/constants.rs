@@ -9,6 +9,8 @@ pub const SRC_DIR: &str = "src";
 pub const TARGET_DIR: &str = "target";
 /// The directory to store serialized ACIR representations of exported library functions.
 pub const EXPORT_DIR: &str = "export";
+/// The directory within the target directory to store cached backend outputs.
+pub const CACHE_DIR: &str = "cache";
 
 // Files
 /// The file from which Nargo pulls prover inputs
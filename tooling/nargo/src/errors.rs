@@ -5,7 +5,7 @@ use acvm::{
         ErrorSelector, OpcodeLocation, RawAssertionPayload, ResolvedAssertionPayload,
         ResolvedOpcodeLocation,
     },
-    pwg::{ErrorLocation, OpcodeResolutionError},
+    pwg::{ErrorLocation, FailedOpcode, OpcodeResolutionError},
 };
 use noirc_abi::{display_abi_error, Abi, AbiErrorType};
 use noirc_errors::{
@@ -84,7 +84,7 @@ impl NargoError {
                 | OpcodeResolutionError::AcirMainCallAttempted { .. }
                 | OpcodeResolutionError::BrilligFunctionFailed { .. }
                 | OpcodeResolutionError::AcirCallOutputsMismatch { .. } => None,
-                OpcodeResolutionError::BlackBoxFunctionFailed(_, reason) => {
+                OpcodeResolutionError::BlackBoxFunctionFailed(_, reason, _) => {
                     Some(reason.to_string())
                 }
             },
@@ -112,6 +112,10 @@ fn extract_locations_from_error(
             acir_call_stack,
         ) => acir_call_stack.clone(),
         ExecutionError::AssertionFailed(_, call_stack) => Some(call_stack.clone()),
+        ExecutionError::SolvingError(
+            OpcodeResolutionError::BlackBoxFunctionFailed(..),
+            acir_call_stack,
+        ) => acir_call_stack.clone(),
         ExecutionError::SolvingError(
             OpcodeResolutionError::IndexOutOfBounds { opcode_location: error_location, .. },
             acir_call_stack,
@@ -211,9 +215,21 @@ pub fn try_to_diagnose_runtime_error(
     // of the call stack (the last item in the Vec).
     let location = source_locations.last()?;
     let message = extract_message_from_error(&abi.error_types, nargo_err);
-    Some(
-        CustomDiagnostic::simple_error(message, String::new(), location.span)
-            .in_file(location.file)
-            .with_call_stack(source_locations),
-    )
+    let mut diagnostic = CustomDiagnostic::simple_error(message, String::new(), location.span);
+    if let Some(failed_opcode) = extract_failed_opcode_from_error(nargo_err) {
+        diagnostic.add_note(format!("Failed to solve {failed_opcode}"));
+    }
+    Some(diagnostic.in_file(location.file).with_call_stack(source_locations))
+}
+
+/// Extracts the opcode which the ACVM failed to solve, along with its witness values, from a nargo error.
+fn extract_failed_opcode_from_error(nargo_err: &NargoError) -> Option<&FailedOpcode> {
+    match nargo_err {
+        NargoError::ExecutionError(ExecutionError::SolvingError(
+            OpcodeResolutionError::UnsatisfiedConstrain { failed_opcode, .. }
+            | OpcodeResolutionError::BlackBoxFunctionFailed(_, _, failed_opcode),
+            _,
+        )) => failed_opcode.as_deref(),
+        _ => None,
+    }
 }
@@ -0,0 +1,280 @@
+//! Static estimates of the number of gates a backend will produce for a program.
+//!
+//! The number of ACIR opcodes is a poor proxy for circuit size as black box functions expand
+//! into very different numbers of gates. Each [GateModel] assigns a weight to every kind of
+//! opcode for a family of proving systems so that a program's size can be estimated without
+//! invoking the backend. The weights are approximations and the estimate should only be used
+//! to compare programs, not to predict exact gate counts.
+
+use std::collections::{BTreeMap, HashMap};
+
+use acvm::acir::{
+    circuit::{opcodes::BlackBoxFuncCall, Circuit, Opcode, Program},
+    native_types::Expression,
+    BlackBoxFunc,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+/// The name of the family used when none is specified.
+pub const DEFAULT_GATE_MODEL_FAMILY: &str = "ultraplonk";
+
+/// The estimated cost of a single call to a black box function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct BlackBoxCost {
+    /// Gates used by every call.
+    #[serde(default)]
+    pub per_call: usize,
+    /// Gates used for each byte of input, so that hashes and bitwise operations scale with their inputs.
+    #[serde(default)]
+    pub per_input_byte: usize,
+}
+
+impl BlackBoxCost {
+    const fn new(per_call: usize, per_input_byte: usize) -> Self {
+        Self { per_call, per_input_byte }
+    }
+}
+
+/// Weights for each kind of opcode in a family of proving systems.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GateModel {
+    family: String,
+    /// The number of terms of an [Opcode::AssertZero] which fit into a single gate.
+    terms_per_gate: usize,
+    memory_init_per_element: usize,
+    memory_op: usize,
+    black_box: HashMap<BlackBoxFunc, BlackBoxCost>,
+}
+
+/// A gate model read from a backend profile, e.g.
+///
+/// ```toml
+/// family = "ultraplonk"
+///
+/// [black_box.sha256]
+/// per_call = 100
+/// per_input_byte = 50
+/// ```
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct GateModelConfig {
+    pub family: Option<String>,
+    /// Overrides of the family's costs, keyed by [BlackBoxFunc::name].
+    #[serde(default)]
+    pub black_box: BTreeMap<String, BlackBoxCost>,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GateModelError {
+    #[error("Unknown gate model family `{0}`. The only supported family is `{DEFAULT_GATE_MODEL_FAMILY}`")]
+    UnknownFamily(String),
+    #[error("Cannot override the cost of unknown black box function `{0}`")]
+    UnknownBlackBoxFunction(String),
+}
+
+impl Default for GateModel {
+    fn default() -> Self {
+        Self::ultraplonk()
+    }
+}
+
+impl GateModel {
+    /// Weights for UltraPlonk, with width 4 arithmetic gates and plookup tables.
+    pub fn ultraplonk() -> Self {
+        use BlackBoxFunc::*;
+
+        let black_box = HashMap::from([
+            (AES128Encrypt, BlackBoxCost::new(0, 1600)),
+            (AND, BlackBoxCost::new(1, 1)),
+            (XOR, BlackBoxCost::new(1, 1)),
+            (RANGE, BlackBoxCost::new(1, 1)),
+            (SHA256, BlackBoxCost::new(100, 50)),
+            (Blake2s, BlackBoxCost::new(50, 45)),
+            (Blake3, BlackBoxCost::new(50, 40)),
+            (SchnorrVerify, BlackBoxCost::new(5000, 0)),
+            (PedersenCommitment, BlackBoxCost::new(50, 8)),
+            (PedersenHash, BlackBoxCost::new(50, 8)),
+            (EcdsaSecp256k1, BlackBoxCost::new(36000, 0)),
+            (EcdsaSecp256r1, BlackBoxCost::new(72000, 0)),
+            (MultiScalarMul, BlackBoxCost::new(0, 20)),
+            (Keccak256, BlackBoxCost::new(100, 150)),
+            (Keccakf1600, BlackBoxCost::new(20000, 0)),
+            (RecursiveAggregation, BlackBoxCost::new(10000, 0)),
+            (EmbeddedCurveAdd, BlackBoxCost::new(10, 0)),
+            (BigIntAdd, BlackBoxCost::new(200, 0)),
+            (BigIntSub, BlackBoxCost::new(200, 0)),
+            (BigIntMul, BlackBoxCost::new(200, 0)),
+            (BigIntDiv, BlackBoxCost::new(200, 0)),
+            (BigIntFromLeBytes, BlackBoxCost::new(0, 2)),
+            (BigIntToLeBytes, BlackBoxCost::new(0, 2)),
+            (Poseidon2Permutation, BlackBoxCost::new(73, 0)),
+            (Sha256Compression, BlackBoxCost::new(3000, 0)),
+        ]);
+
+        Self {
+            family: DEFAULT_GATE_MODEL_FAMILY.to_string(),
+            terms_per_gate: 4,
+            memory_init_per_element: 1,
+            memory_op: 2,
+            black_box,
+        }
+    }
+
+    /// Returns the model for the given family of proving systems, if it is known.
+    pub fn from_family(family: &str) -> Option<Self> {
+        match family {
+            DEFAULT_GATE_MODEL_FAMILY => Some(Self::ultraplonk()),
+            _ => None,
+        }
+    }
+
+    /// Builds the model described by a backend profile, applying its overrides to the family's costs.
+    pub fn from_config(config: GateModelConfig) -> Result<Self, GateModelError> {
+        let family = config.family.as_deref().unwrap_or(DEFAULT_GATE_MODEL_FAMILY);
+        let mut model = Self::from_family(family)
+            .ok_or_else(|| GateModelError::UnknownFamily(family.to_string()))?;
+
+        for (name, cost) in config.black_box {
+            let func =
+                BlackBoxFunc::lookup(&name).ok_or(GateModelError::UnknownBlackBoxFunction(name))?;
+            model.black_box.insert(func, cost);
+        }
+        Ok(model)
+    }
+
+    pub fn family(&self) -> &str {
+        &self.family
+    }
+
+    /// Estimates the number of gates used by every ACIR function in the program.
+    ///
+    /// Unconstrained functions do not add any gates.
+    pub fn estimate_program(&self, program: &Program) -> usize {
+        program.functions.iter().map(|circuit| self.estimate_circuit(circuit)).sum()
+    }
+
+    pub fn estimate_circuit(&self, circuit: &Circuit) -> usize {
+        circuit.opcodes.iter().map(|opcode| self.estimate_opcode(opcode)).sum()
+    }
+
+    fn estimate_opcode(&self, opcode: &Opcode) -> usize {
+        match opcode {
+            Opcode::AssertZero(expression) => self.estimate_expression(expression),
+            Opcode::BlackBoxFuncCall(call) => self.estimate_black_box(call),
+            Opcode::MemoryInit { init, .. } => init.len() * self.memory_init_per_element,
+            Opcode::MemoryOp { .. } => self.memory_op,
+            // Brillig outputs and directives are unconstrained until used by another opcode,
+            // and the gates of a called function are counted with that function.
+            Opcode::Directive(_) | Opcode::BrilligCall { .. } | Opcode::Call { .. } => 0,
+        }
+    }
+
+    fn estimate_expression(&self, expression: &Expression) -> usize {
+        let terms = expression.mul_terms.len() + expression.linear_combinations.len();
+        terms.div_ceil(self.terms_per_gate).max(1)
+    }
+
+    fn estimate_black_box(&self, call: &BlackBoxFuncCall) -> usize {
+        let Some(cost) = self.black_box.get(&call.get_black_box_func()) else {
+            return 0;
+        };
+        let input_bits: usize =
+            call.get_inputs_vec().iter().map(|input| input.num_bits() as usize).sum();
+        cost.per_call + input_bits.div_ceil(8) * cost.per_input_byte
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::{
+        acir::{
+            circuit::{
+                opcodes::{BlackBoxFuncCall, FunctionInput},
+                Circuit, Opcode, Program,
+            },
+            native_types::{Expression, Witness},
+        },
+        FieldElement,
+    };
+    use iter_extended::vecmap;
+
+    use super::{BlackBoxCost, GateModel, GateModelConfig, GateModelError};
+
+    fn range(witness: u32, num_bits: u32) -> Opcode {
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+            input: FunctionInput::witness(Witness(witness), num_bits),
+        })
+    }
+
+    fn sha256(inputs: u32) -> Opcode {
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::SHA256 {
+            inputs: vecmap(0..inputs, |i| FunctionInput::witness(Witness(i), 8)),
+            outputs: Box::new(std::array::from_fn(|i| Witness(i as u32 + 100))),
+        })
+    }
+
+    /// `w0 * w1 + w2 + w3 + w4 + w5 = 0`, `w0 = w1` and two range checks.
+    fn fixture() -> Circuit {
+        let one = FieldElement::one();
+        let sum = Expression {
+            mul_terms: vec![(one, Witness(0), Witness(1))],
+            linear_combinations: (2..6).map(|i| (one, Witness(i))).collect(),
+            q_c: FieldElement::zero(),
+        };
+        let equal = Expression {
+            mul_terms: Vec::new(),
+            linear_combinations: vec![(one, Witness(0)), (-one, Witness(1))],
+            q_c: FieldElement::zero(),
+        };
+        let opcodes =
+            vec![Opcode::AssertZero(sum), Opcode::AssertZero(equal), range(0, 32), range(1, 8)];
+        Circuit { opcodes, ..Circuit::default() }
+    }
+
+    #[test]
+    fn estimates_fixture_circuit() {
+        let model = GateModel::default();
+        // 2 gates for the 5 term expression, 1 for the equality and 5 + 2 for the range checks.
+        assert_eq!(model.estimate_circuit(&fixture()), 10);
+
+        let mut circuit = fixture();
+        circuit.opcodes.push(sha256(64));
+        // 100 per call and 50 per byte of input.
+        assert_eq!(model.estimate_circuit(&circuit), 10 + 100 + 64 * 50);
+
+        let program = Program { functions: vec![fixture(), circuit], ..Program::default() };
+        assert_eq!(model.estimate_program(&program), 10 + 3310);
+    }
+
+    #[test]
+    fn config_overrides_black_box_costs() {
+        let config = GateModelConfig {
+            family: None,
+            black_box: [("sha256".to_string(), BlackBoxCost { per_call: 5, per_input_byte: 0 })]
+                .into(),
+        };
+        let model = GateModel::from_config(config).unwrap();
+        assert_eq!(model.family(), "ultraplonk");
+
+        let mut circuit = fixture();
+        circuit.opcodes.push(sha256(64));
+        assert_eq!(model.estimate_circuit(&circuit), 15);
+
+        let unknown_family =
+            GateModelConfig { family: Some("groth16".to_string()), ..Default::default() };
+        assert_eq!(
+            GateModel::from_config(unknown_family),
+            Err(GateModelError::UnknownFamily("groth16".to_string()))
+        );
+
+        let unknown_function = GateModelConfig {
+            family: None,
+            black_box: [("sha512".to_string(), BlackBoxCost { per_call: 5, per_input_byte: 0 })]
+                .into(),
+        };
+        assert_eq!(
+            GateModel::from_config(unknown_function),
+            Err(GateModelError::UnknownBlackBoxFunction("sha512".to_string()))
+        );
+    }
+}
@@ -10,6 +10,7 @@
 pub mod artifacts;
 pub mod constants;
 pub mod errors;
+pub mod gate_model;
 pub mod ops;
 pub mod package;
 pub mod workspace;
@@ -9,6 +9,7 @@ use crate::errors::ExecutionError;
 use crate::NargoError;
 
 use super::foreign_calls::ForeignCallExecutor;
+use super::profile::ExecutionProfile;
 
 struct ProgramExecutor<'a, B: BlackBoxFunctionSolver, F: ForeignCallExecutor> {
     functions: &'a [Circuit],
@@ -30,6 +31,9 @@ struct ProgramExecutor<'a, B: BlackBoxFunctionSolver, F: ForeignCallExecutor> {
     // This is used to fetch the function we want to execute
     // and to resolve call stack locations across many function calls.
     current_function_index: usize,
+
+    // Collects per-opcode solver timings when execution is being profiled.
+    profile: Option<ExecutionProfile>,
 }
 
 impl<'a, B: BlackBoxFunctionSolver, F: ForeignCallExecutor> ProgramExecutor<'a, B, F> {
@@ -38,6 +42,7 @@ impl<'a, B: BlackBoxFunctionSolver, F: ForeignCallExecutor> ProgramExecutor<'a,
         unconstrained_functions: &'a [BrilligBytecode],
         blackbox_solver: &'a B,
         foreign_call_executor: &'a mut F,
+        profile: Option<ExecutionProfile>,
     ) -> Self {
         ProgramExecutor {
             functions,
@@ -47,11 +52,12 @@ impl<'a, B: BlackBoxFunctionSolver, F: ForeignCallExecutor> ProgramExecutor<'a,
             foreign_call_executor,
             call_stack: Vec::default(),
             current_function_index: 0,
+            profile,
         }
     }
 
-    fn finalize(self) -> WitnessStack {
-        self.witness_stack
+    fn finalize(self) -> (WitnessStack, Option<ExecutionProfile>) {
+        (self.witness_stack, self.profile)
     }
 
     #[tracing::instrument(level = "trace", skip_all)]
@@ -66,7 +72,11 @@ impl<'a, B: BlackBoxFunctionSolver, F: ForeignCallExecutor> ProgramExecutor<'a,
         );
 
         loop {
-            let solver_status = acvm.solve();
+            // Profiling is opt-in so that regular execution doesn't pay for reading the clock on every opcode.
+            let solver_status = match &mut self.profile {
+                Some(profile) => profile.solve(&mut acvm, self.current_function_index),
+                None => acvm.solve(),
+            };
 
             match solver_status {
                 ACVMStatus::Solved => break,
@@ -90,6 +100,15 @@ impl<'a, B: BlackBoxFunctionSolver, F: ForeignCallExecutor> ProgramExecutor<'a,
                             self.call_stack.push(resolved_location);
                             Some(self.call_stack.clone())
                         }
+                        // Black box failures are not resolved to an opcode by the ACVM
+                        // but it has stopped on the opcode which failed.
+                        OpcodeResolutionError::BlackBoxFunctionFailed(..) => {
+                            self.call_stack.push(ResolvedOpcodeLocation {
+                                acir_function_index: self.current_function_index,
+                                opcode_location: OpcodeLocation::Acir(acvm.instruction_pointer()),
+                            });
+                            Some(self.call_stack.clone())
+                        }
                         OpcodeResolutionError::BrilligFunctionFailed { call_stack, .. } => {
                             let brillig_call_stack =
                                 call_stack.iter().map(|location| ResolvedOpcodeLocation {
@@ -172,11 +191,42 @@ pub fn execute_program<B: BlackBoxFunctionSolver, F: ForeignCallExecutor>(
     blackbox_solver: &B,
     foreign_call_executor: &mut F,
 ) -> Result<WitnessStack, NargoError> {
+    let (witness_stack, _) =
+        run_program(program, initial_witness, blackbox_solver, foreign_call_executor, None)?;
+    Ok(witness_stack)
+}
+
+/// Executes `program` as [`execute_program`] does while also recording how long each opcode took to solve.
+#[tracing::instrument(level = "trace", skip_all)]
+pub fn execute_program_with_profiling<B: BlackBoxFunctionSolver, F: ForeignCallExecutor>(
+    program: &Program,
+    initial_witness: WitnessMap,
+    blackbox_solver: &B,
+    foreign_call_executor: &mut F,
+) -> Result<(WitnessStack, ExecutionProfile), NargoError> {
+    let (witness_stack, profile) = run_program(
+        program,
+        initial_witness,
+        blackbox_solver,
+        foreign_call_executor,
+        Some(ExecutionProfile::default()),
+    )?;
+    Ok((witness_stack, profile.expect("profiling was enabled")))
+}
+
+fn run_program<B: BlackBoxFunctionSolver, F: ForeignCallExecutor>(
+    program: &Program,
+    initial_witness: WitnessMap,
+    blackbox_solver: &B,
+    foreign_call_executor: &mut F,
+    profile: Option<ExecutionProfile>,
+) -> Result<(WitnessStack, Option<ExecutionProfile>), NargoError> {
     let mut executor = ProgramExecutor::new(
         &program.functions,
         &program.unconstrained_functions,
         blackbox_solver,
         foreign_call_executor,
+        profile,
     );
     let main_witness = executor.execute_circuit(initial_witness)?;
     executor.witness_stack.push(0, main_witness);
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use acvm::acir::circuit::{Opcode, OpcodeLocation, ResolvedOpcodeLocation};
+use acvm::pwg::{ACVMStatus, ACVM};
+use acvm::BlackBoxFunctionSolver;
+
+/// The number of individual opcodes reported by [`ExecutionProfile::slowest_opcodes`].
+pub const SLOWEST_OPCODES_TO_REPORT: usize = 10;
+
+/// Aggregated solver timings for every opcode of a single kind.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OpcodeKindProfile {
+    pub count: usize,
+    pub total_time: Duration,
+}
+
+impl OpcodeKindProfile {
+    pub fn average_time(&self) -> Duration {
+        if self.count == 0 {
+            return Duration::ZERO;
+        }
+        let average_nanos = self.total_time.as_nanos() / self.count as u128;
+        Duration::from_nanos(average_nanos.try_into().unwrap_or(u64::MAX))
+    }
+}
+
+/// The time spent solving a single opcode.
+#[derive(Debug, Clone, Copy)]
+pub struct OpcodeSample {
+    pub location: ResolvedOpcodeLocation,
+    pub kind: &'static str,
+    pub time: Duration,
+}
+
+/// Solver timings collected while executing a program, grouped by opcode kind.
+///
+/// Black box function calls are grouped by [`BlackBoxFunc::name`][acvm::acir::BlackBoxFunc::name],
+/// all other opcodes by a fixed name for their variant (e.g. `arithmetic`, `memory` or `brillig`).
+#[derive(Debug, Default, Clone)]
+pub struct ExecutionProfile {
+    kinds: BTreeMap<&'static str, OpcodeKindProfile>,
+
+    /// The slowest opcodes seen so far, sorted from slowest to fastest.
+    slowest: Vec<OpcodeSample>,
+
+    /// Opcodes which have paused execution for a foreign or ACIR call along with the time spent on them so far.
+    ///
+    /// ACIR calls are resolved by executing the callee in between two calls to `solve`,
+    /// so this acts as a stack: the callee's opcodes are always completed before the caller resumes.
+    in_progress: Vec<(ResolvedOpcodeLocation, Duration)>,
+}
+
+impl ExecutionProfile {
+    /// Returns the aggregated timings of each opcode kind, sorted by name.
+    pub fn kinds(&self) -> impl Iterator<Item = (&'static str, &OpcodeKindProfile)> {
+        self.kinds.iter().map(|(kind, profile)| (*kind, profile))
+    }
+
+    /// Returns the slowest individual opcodes, sorted from slowest to fastest.
+    pub fn slowest_opcodes(&self) -> &[OpcodeSample] {
+        &self.slowest
+    }
+
+    /// Returns the total time spent solving opcodes.
+    pub fn total_time(&self) -> Duration {
+        self.kinds.values().map(|profile| profile.total_time).sum()
+    }
+
+    /// Equivalent to [`ACVM::solve`] except that the time spent on each opcode is recorded.
+    pub(crate) fn solve<B: BlackBoxFunctionSolver>(
+        &mut self,
+        acvm: &mut ACVM<B>,
+        acir_function_index: usize,
+    ) -> ACVMStatus {
+        while *acvm.get_status() == ACVMStatus::InProgress {
+            let opcode_index = acvm.instruction_pointer();
+            let location = ResolvedOpcodeLocation {
+                acir_function_index,
+                opcode_location: OpcodeLocation::Acir(opcode_index),
+            };
+            let kind = opcode_kind(&acvm.opcodes()[opcode_index]);
+
+            let start = Instant::now();
+            let status = acvm.solve_opcode();
+            let mut time = start.elapsed();
+
+            if self.in_progress.last().is_some_and(|(paused, _)| is_same_opcode(paused, &location))
+            {
+                let (_, paused_time) = self.in_progress.pop().expect("checked above");
+                time += paused_time;
+            }
+
+            match status {
+                ACVMStatus::RequiresForeignCall(_) | ACVMStatus::RequiresAcirCall(_) => {
+                    self.in_progress.push((location, time));
+                }
+                _ => self.record(OpcodeSample { location, kind, time }),
+            }
+        }
+        acvm.get_status().clone()
+    }
+
+    fn record(&mut self, sample: OpcodeSample) {
+        let kind = self.kinds.entry(sample.kind).or_default();
+        kind.count += 1;
+        kind.total_time += sample.time;
+
+        let position = self.slowest.partition_point(|slower| slower.time >= sample.time);
+        if position < SLOWEST_OPCODES_TO_REPORT {
+            self.slowest.insert(position, sample);
+            self.slowest.truncate(SLOWEST_OPCODES_TO_REPORT);
+        }
+    }
+}
+
+fn is_same_opcode(lhs: &ResolvedOpcodeLocation, rhs: &ResolvedOpcodeLocation) -> bool {
+    lhs.acir_function_index == rhs.acir_function_index && lhs.opcode_location == rhs.opcode_location
+}
+
+/// Returns the name under which the time spent solving `opcode` is aggregated.
+pub fn opcode_kind(opcode: &Opcode) -> &'static str {
+    match opcode {
+        Opcode::AssertZero(_) => "arithmetic",
+        Opcode::BlackBoxFuncCall(call) => call.get_black_box_func().name(),
+        Opcode::Directive(_) => "directive",
+        Opcode::MemoryOp { .. } | Opcode::MemoryInit { .. } => "memory",
+        Opcode::BrilligCall { .. } => "brillig",
+        Opcode::Call { .. } => "call",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use acvm::{
+        acir::{
+            circuit::{
+                opcodes::{BlackBoxFuncCall, FunctionInput},
+                Opcode, OpcodeLocation, ResolvedOpcodeLocation,
+            },
+            native_types::{Expression, Witness, WitnessMap},
+        },
+        blackbox_solver::StubbedBlackBoxSolver,
+        pwg::{ACVMStatus, ACVM},
+        FieldElement,
+    };
+
+    use super::{ExecutionProfile, OpcodeSample, SLOWEST_OPCODES_TO_REPORT};
+
+    fn sample(index: usize, kind: &'static str, millis: u64) -> OpcodeSample {
+        OpcodeSample {
+            location: ResolvedOpcodeLocation {
+                acir_function_index: 0,
+                opcode_location: OpcodeLocation::Acir(index),
+            },
+            kind,
+            time: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn aggregates_by_kind_and_keeps_slowest_opcodes() {
+        let mut profile = ExecutionProfile::default();
+        for index in 0..20 {
+            profile.record(sample(index, "arithmetic", index as u64));
+        }
+        profile.record(sample(20, "sha256", 100));
+
+        let kinds: Vec<_> = profile.kinds().collect();
+        assert_eq!(kinds.len(), 2);
+        assert_eq!(kinds[0].0, "arithmetic");
+        assert_eq!(kinds[0].1.count, 20);
+        assert_eq!(kinds[0].1.total_time, Duration::from_millis(190));
+        assert_eq!(kinds[0].1.average_time(), Duration::from_micros(9500));
+        assert_eq!(kinds[1].0, "sha256");
+        assert_eq!(profile.total_time(), Duration::from_millis(290));
+
+        let slowest = profile.slowest_opcodes();
+        assert_eq!(slowest.len(), SLOWEST_OPCODES_TO_REPORT);
+        assert_eq!(slowest[0].kind, "sha256");
+        let slowest_times: Vec<_> = slowest.iter().map(|sample| sample.time.as_millis()).collect();
+        assert_eq!(slowest_times, vec![100, 19, 18, 17, 16, 15, 14, 13, 12, 11]);
+    }
+
+    #[test]
+    fn profiles_black_box_calls_by_name() {
+        let input = Witness(0);
+        let outputs: [Witness; 32] = std::array::from_fn(|i| Witness(i as u32 + 1));
+        let opcodes = vec![
+            Opcode::BlackBoxFuncCall(BlackBoxFuncCall::SHA256 {
+                inputs: vec![FunctionInput::witness(input, 8)],
+                outputs: Box::new(outputs),
+            }),
+            // A trivially satisfied constraint so that more than one kind of opcode is profiled.
+            Opcode::AssertZero(Expression {
+                mul_terms: Vec::new(),
+                linear_combinations: vec![
+                    (FieldElement::one(), outputs[0]),
+                    (-FieldElement::one(), outputs[0]),
+                ],
+                q_c: FieldElement::zero(),
+            }),
+        ];
+        let initial_witness = WitnessMap::from(std::collections::BTreeMap::from([(
+            input,
+            FieldElement::from(1u128),
+        )]));
+
+        let mut acvm = ACVM::new(&StubbedBlackBoxSolver, &opcodes, initial_witness, &[], &[]);
+        let mut profile = ExecutionProfile::default();
+        assert_eq!(profile.solve(&mut acvm, 0), ACVMStatus::Solved);
+
+        let kinds: Vec<_> = profile.kinds().map(|(kind, profile)| (kind, profile.count)).collect();
+        assert_eq!(kinds, vec![("arithmetic", 1), ("sha256", 1)]);
+        assert_eq!(profile.slowest_opcodes().len(), 2);
+    }
+}
@@ -1,6 +1,7 @@
 use std::{collections::BTreeMap, fmt::Display, path::PathBuf};
 
 use noirc_frontend::graph::CrateName;
+use serde::Deserialize;
 
 use crate::constants::{PROVER_INPUT_FILE, VERIFIER_INPUT_FILE};
 
@@ -41,6 +42,14 @@ impl Dependency {
     }
 }
 
+/// Inclusive bounds on the numeric values `nargo fuzz` generates for a parameter of `main`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FuzzRange {
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+}
+
 #[derive(Clone)]
 pub struct Package {
     pub version: Option<String>,
@@ -51,6 +60,8 @@ pub struct Package {
     pub entry_path: PathBuf,
     pub name: CrateName,
     pub dependencies: BTreeMap<CrateName, Dependency>,
+    /// The ranges of values `nargo fuzz` generates for each parameter of `main`, from the `[fuzz]` table
+    pub fuzz_ranges: BTreeMap<String, FuzzRange>,
 }
 
 impl Package {
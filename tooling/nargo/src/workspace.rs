@@ -10,7 +10,7 @@ use std::{
 };
 
 use crate::{
-    constants::{CONTRACT_DIR, EXPORT_DIR, PROOFS_DIR, TARGET_DIR},
+    constants::{CACHE_DIR, CONTRACT_DIR, EXPORT_DIR, PROOFS_DIR, TARGET_DIR},
     package::Package,
 };
 
@@ -46,6 +46,10 @@ impl Workspace {
     pub fn export_directory_path(&self) -> PathBuf {
         self.root_dir.join(EXPORT_DIR)
     }
+
+    pub fn cache_directory_path(&self) -> PathBuf {
+        self.target_directory_path().join(CACHE_DIR)
+    }
 }
 
 pub enum IntoIter<'a, T> {
@@ -0,0 +1,67 @@
+use clap::Args;
+use nargo::ops::{compile_program, report_errors};
+use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_driver::{file_manager_with_stdlib, CompileOptions};
+use noirc_frontend::graph::CrateName;
+
+use super::NargoConfig;
+use crate::errors::CliError;
+
+/// Prints the ABI of a program as JSON
+#[derive(Debug, Clone, Args)]
+pub(crate) struct AbiCommand {
+    /// Print a JSON Schema (draft 2020-12) describing the program's inputs instead of the ABI
+    #[clap(long)]
+    json_schema: bool,
+
+    /// The name of the package to print the ABI of
+    #[clap(long, conflicts_with = "workspace")]
+    package: Option<CrateName>,
+
+    /// Print the ABI of all packages in the workspace
+    #[clap(long, conflicts_with = "package")]
+    workspace: bool,
+
+    #[clap(flatten)]
+    compile_options: CompileOptions,
+}
+
+pub(crate) fn run(args: AbiCommand, config: NargoConfig) -> Result<(), CliError> {
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    let default_selection =
+        if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
+    let selection = args.package.map_or(default_selection, PackageSelection::Selected);
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
+
+    let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
+    let parsed_files = parse_all(&workspace_file_manager);
+
+    let binary_packages = workspace.into_iter().filter(|package| package.is_binary());
+    for package in binary_packages {
+        let compilation_result = compile_program(
+            &workspace_file_manager,
+            &parsed_files,
+            package,
+            &args.compile_options,
+            None,
+        );
+
+        let compiled_program = report_errors(
+            compilation_result,
+            &workspace_file_manager,
+            args.compile_options.deny_warnings,
+            args.compile_options.silence_warnings,
+        )?;
+
+        let json = if args.json_schema {
+            serde_json::to_string_pretty(&compiled_program.abi.to_json_schema())
+        } else {
+            serde_json::to_string_pretty(&compiled_program.abi)
+        }
+        .expect("ABI should serialize to JSON");
+        println!("{json}");
+    }
+    Ok(())
+}
@@ -7,9 +7,11 @@ use clap::Args;
 use nargo::ops::{compile_program, report_errors};
 use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
-use noirc_driver::{file_manager_with_stdlib, CompileOptions, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_driver::{file_manager_with_stdlib, CompileOptions};
 use noirc_frontend::graph::CrateName;
 
+mod wrapper;
+
 /// Generates a Solidity verifier smart contract for the program
 #[derive(Debug, Clone, Args)]
 pub(crate) struct CodegenVerifierCommand {
@@ -34,11 +36,7 @@ pub(crate) fn run(
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
     let selection = args.package.map_or(default_selection, PackageSelection::Selected);
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
-    )?;
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
 
     let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
     insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
@@ -77,6 +75,13 @@ pub(crate) fn run(
 
         let path = write_to_file(smart_contract_string.as_bytes(), &contract_path);
         println!("[{}] Contract successfully created and located at {path}", package.name);
+
+        let wrapper_name = wrapper::contract_name(&package.name.to_string());
+        let wrapper_string = wrapper::render_wrapper(&wrapper_name, &program.abi);
+        let wrapper_path = contract_dir.join(&wrapper_name).with_extension("sol");
+
+        let path = write_to_file(wrapper_string.as_bytes(), &wrapper_path);
+        println!("[{}] Verifier wrapper successfully created and located at {path}", package.name);
     }
 
     Ok(())
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0
+// Generated by `nargo codegen-verifier`, do not edit.
+pragma solidity >=0.8.4;
+
+/// The verifier generated by the proving backend.
+interface IVerifier {
+    function verify(bytes calldata _proof, bytes32[] calldata _publicInputs) external view returns (bool);
+}
+
+contract FixtureVerifier {
+    struct ProofData {
+        bytes proof;
+    }
+
+    struct PublicInputs {
+        bytes32 amount;
+        bytes32 origin_x;
+        bytes32 origin_y;
+        bytes32[4] hash;
+        bytes32 path_0_x;
+        bytes32 path_0_y;
+        bytes32 path_1_x;
+        bytes32 path_1_y;
+        bytes32 address__0;
+        bytes32[3] address__1;
+        bytes32 return_value;
+    }
+
+    IVerifier public immutable verifier;
+
+    constructor(IVerifier _verifier) {
+        verifier = _verifier;
+    }
+
+    function verify(ProofData calldata proofData, PublicInputs calldata publicInputs) external view returns (bool) {
+        bytes32[] memory flattened = new bytes32[](16);
+        flattened[0] = publicInputs.amount;
+        flattened[1] = publicInputs.origin_x;
+        flattened[2] = publicInputs.origin_y;
+        for (uint256 i = 0; i < 4; i++) {
+            flattened[3 + i] = publicInputs.hash[i];
+        }
+        flattened[7] = publicInputs.path_0_x;
+        flattened[8] = publicInputs.path_0_y;
+        flattened[9] = publicInputs.path_1_x;
+        flattened[10] = publicInputs.path_1_y;
+        flattened[11] = publicInputs.address__0;
+        for (uint256 i = 0; i < 3; i++) {
+            flattened[12 + i] = publicInputs.address__1[i];
+        }
+        flattened[15] = publicInputs.return_value;
+        return verifier.verify(proofData.proof, flattened);
+    }
+}
@@ -0,0 +1,304 @@
+//! Renders a Solidity contract which wraps the backend's verifier with a `verify` function taking
+//! the program's public inputs as a struct, rather than as a flat list of field elements.
+//!
+//! Each public parameter of `main`, followed by its return value, becomes one or more members of
+//! the `PublicInputs` struct. These are flattened in the same order as the ABI encodes them:
+//! - fields, integers and booleans become a `bytes32` named after the parameter,
+//! - arrays of these and strings become a `bytes32[length]`,
+//! - struct fields, tuple elements and the elements of any other array become members named
+//!   `<parameter>_<field>`, `<parameter>_<index>` and `<parameter>_<index>` respectively.
+//!
+//! If two members end up with the same name, e.g. for a parameter `a_b` and the field `b` of a
+//! parameter `a`, the later member is suffixed with `_<n>` for the smallest `n` giving a new name.
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use noirc_abi::{Abi, AbiType, AbiVisibility};
+
+/// The name given to the member holding the return value of `main`.
+const RETURN_VALUE_NAME: &str = "return_value";
+
+/// Solidity keywords which may also be used as Noir identifiers.
+const SOLIDITY_KEYWORDS: &[&str] = &[
+    "address",
+    "bool",
+    "byte",
+    "bytes",
+    "bytes32",
+    "calldata",
+    "constant",
+    "constructor",
+    "contract",
+    "emit",
+    "error",
+    "event",
+    "external",
+    "function",
+    "interface",
+    "internal",
+    "library",
+    "mapping",
+    "memory",
+    "modifier",
+    "payable",
+    "private",
+    "public",
+    "pure",
+    "return",
+    "returns",
+    "storage",
+    "string",
+    "struct",
+    "uint",
+    "uint256",
+    "view",
+];
+
+/// A member of the `PublicInputs` struct.
+struct Member {
+    name: String,
+    /// The number of public inputs held by the member, or `None` if it holds a single `bytes32`.
+    length: Option<u64>,
+}
+
+/// Renders the wrapper contract for a program with the given ABI.
+pub(super) fn render_wrapper(contract_name: &str, abi: &Abi) -> String {
+    let mut members = Vec::new();
+    for parameter in abi.parameters.iter().filter(|parameter| parameter.is_public()) {
+        flatten(solidity_identifier(&parameter.name), &parameter.typ, &mut members);
+    }
+    if let Some(return_type) = &abi.return_type {
+        if return_type.visibility == AbiVisibility::Public {
+            flatten(RETURN_VALUE_NAME.to_string(), &return_type.abi_type, &mut members);
+        }
+    }
+    deduplicate_names(&mut members);
+    let public_input_count: u64 = members.iter().map(|member| member.length.unwrap_or(1)).sum();
+
+    let mut fields = String::new();
+    let mut assignments = String::new();
+    let mut index = 0;
+    for Member { name, length } in &members {
+        match length {
+            None => {
+                writeln!(fields, "        bytes32 {name};").unwrap();
+                writeln!(assignments, "        flattened[{index}] = publicInputs.{name};").unwrap();
+                index += 1;
+            }
+            Some(length) => {
+                writeln!(fields, "        bytes32[{length}] {name};").unwrap();
+                writeln!(assignments, "        for (uint256 i = 0; i < {length}; i++) {{").unwrap();
+                writeln!(
+                    assignments,
+                    "            flattened[{index} + i] = publicInputs.{name}[i];"
+                )
+                .unwrap();
+                writeln!(assignments, "        }}").unwrap();
+                index += length;
+            }
+        }
+    }
+
+    format!(
+        r#"// SPDX-License-Identifier: Apache-2.0
+// Generated by `nargo codegen-verifier`, do not edit.
+pragma solidity >=0.8.4;
+
+/// The verifier generated by the proving backend.
+interface IVerifier {{
+    function verify(bytes calldata _proof, bytes32[] calldata _publicInputs) external view returns (bool);
+}}
+
+contract {contract_name} {{
+    struct ProofData {{
+        bytes proof;
+    }}
+
+    struct PublicInputs {{
+{fields}    }}
+
+    IVerifier public immutable verifier;
+
+    constructor(IVerifier _verifier) {{
+        verifier = _verifier;
+    }}
+
+    function verify(ProofData calldata proofData, PublicInputs calldata publicInputs) external view returns (bool) {{
+        bytes32[] memory flattened = new bytes32[]({public_input_count});
+{assignments}        return verifier.verify(proofData.proof, flattened);
+    }}
+}}
+"#
+    )
+}
+
+/// Returns the name of the wrapper contract for a package, e.g. `HelloWorldVerifier` for `hello_world`.
+pub(super) fn contract_name(package_name: &str) -> String {
+    let mut name: String = package_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name + "Verifier"
+}
+
+/// Appends the members holding a value of type `typ` named `name` to `members`.
+fn flatten(name: String, typ: &AbiType, members: &mut Vec<Member>) {
+    match typ {
+        AbiType::Field | AbiType::Integer { .. } | AbiType::Boolean => {
+            members.push(Member { name, length: None });
+        }
+        AbiType::String { length } => {
+            if *length > 0 {
+                members.push(Member { name, length: Some(*length) });
+            }
+        }
+        AbiType::Array { length, typ } => match typ.as_ref() {
+            AbiType::Field | AbiType::Integer { .. } | AbiType::Boolean => {
+                if *length > 0 {
+                    members.push(Member { name, length: Some(*length) });
+                }
+            }
+            _ => {
+                for index in 0..*length {
+                    flatten(format!("{name}_{index}"), typ, members);
+                }
+            }
+        },
+        AbiType::Struct { fields, .. } => {
+            for (field_name, field_type) in fields {
+                flatten(format!("{name}_{field_name}"), field_type, members);
+            }
+        }
+        AbiType::Tuple { fields } => {
+            for (index, field_type) in fields.iter().enumerate() {
+                flatten(format!("{name}_{index}"), field_type, members);
+            }
+        }
+    }
+}
+
+/// Renames members whose name was already taken by an earlier member.
+fn deduplicate_names(members: &mut [Member]) {
+    let mut taken: HashSet<String> = members.iter().map(|member| member.name.clone()).collect();
+    let mut seen = HashSet::new();
+    for member in members {
+        if !seen.insert(member.name.clone()) {
+            let name = (1..)
+                .map(|suffix| format!("{}_{suffix}", member.name))
+                .find(|name| !taken.contains(name))
+                .expect("there are infinitely many suffixes");
+            taken.insert(name.clone());
+            seen.insert(name.clone());
+            member.name = name;
+        }
+    }
+}
+
+fn solidity_identifier(name: &str) -> String {
+    if SOLIDITY_KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use noirc_abi::{Abi, AbiParameter, AbiReturnType, AbiType, AbiVisibility, Sign};
+
+    use super::{contract_name, render_wrapper};
+
+    #[test]
+    fn contract_names_are_pascal_case() {
+        assert_eq!(contract_name("hello_world"), "HelloWorldVerifier");
+        assert_eq!(contract_name("1st-circuit"), "_1stCircuitVerifier");
+    }
+
+    #[test]
+    fn wrapper_matches_golden_file() {
+        let parameter =
+            |name: &str, typ, visibility| AbiParameter { name: name.to_string(), typ, visibility };
+        let point = AbiType::Struct {
+            path: "Point".to_string(),
+            fields: vec![("x".to_string(), AbiType::Field), ("y".to_string(), AbiType::Field)],
+        };
+        let abi = Abi {
+            parameters: vec![
+                parameter("secret", AbiType::Field, AbiVisibility::Private),
+                parameter(
+                    "amount",
+                    AbiType::Integer { sign: Sign::Unsigned, width: 64 },
+                    AbiVisibility::Public,
+                ),
+                parameter("origin", point.clone(), AbiVisibility::Public),
+                parameter(
+                    "hash",
+                    AbiType::Array {
+                        length: 4,
+                        typ: Box::new(AbiType::Integer { sign: Sign::Unsigned, width: 8 }),
+                    },
+                    AbiVisibility::Public,
+                ),
+                parameter(
+                    "path",
+                    AbiType::Array { length: 2, typ: Box::new(point) },
+                    AbiVisibility::Public,
+                ),
+                parameter(
+                    "address",
+                    AbiType::Tuple {
+                        fields: vec![AbiType::Boolean, AbiType::String { length: 3 }],
+                    },
+                    AbiVisibility::Public,
+                ),
+            ],
+            param_witnesses: BTreeMap::new(),
+            return_type: Some(AbiReturnType {
+                abi_type: AbiType::Field,
+                visibility: AbiVisibility::Public,
+            }),
+            return_witnesses: Vec::new(),
+            error_types: BTreeMap::new(),
+        };
+
+        let wrapper = render_wrapper("FixtureVerifier", &abi);
+        assert_eq!(wrapper, include_str!("test_data/FixtureVerifier.sol"));
+    }
+
+    #[test]
+    fn colliding_member_names_are_disambiguated() {
+        let parameter = |name: &str, typ| AbiParameter {
+            name: name.to_string(),
+            typ,
+            visibility: AbiVisibility::Public,
+        };
+        let abi = Abi {
+            parameters: vec![
+                parameter(
+                    "a",
+                    AbiType::Struct {
+                        path: "A".to_string(),
+                        fields: vec![("b".to_string(), AbiType::Field)],
+                    },
+                ),
+                parameter("a_b", AbiType::Field),
+                parameter("a_b_1", AbiType::Field),
+            ],
+            param_witnesses: BTreeMap::new(),
+            return_type: None,
+            return_witnesses: Vec::new(),
+            error_types: BTreeMap::new(),
+        };
+
+        let wrapper = render_wrapper("CollisionVerifier", &abi);
+        assert!(wrapper
+            .contains("        bytes32 a_b;\n        bytes32 a_b_2;\n        bytes32 a_b_1;\n"));
+        assert!(wrapper.contains("flattened[1] = publicInputs.a_b_2;"));
+    }
+}
@@ -1,9 +1,10 @@
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use fm::FileManager;
 use nargo::artifacts::program::ProgramArtifact;
+use nargo::gate_model::{GateModel, GateModelConfig};
 use nargo::ops::{collect_errors, compile_contract, compile_program, report_errors};
 use nargo::package::Package;
 use nargo::workspace::Workspace;
@@ -44,6 +45,10 @@ pub(crate) struct CompileCommand {
     /// Watch workspace and recompile on changes.
     #[clap(long, hide = true)]
     watch: bool,
+
+    /// A backend profile overriding the weights used to estimate the number of gates in each circuit.
+    #[clap(long)]
+    gate_model: Option<PathBuf>,
 }
 
 pub(crate) fn run(args: CompileCommand, config: NargoConfig) -> Result<(), CliError> {
@@ -52,23 +57,41 @@ pub(crate) fn run(args: CompileCommand, config: NargoConfig) -> Result<(), CliEr
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
     let selection = args.package.map_or(default_selection, PackageSelection::Selected);
 
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_owned()),
-    )?;
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
+
+    let gate_model = match &args.gate_model {
+        Some(path) => read_gate_model(path)?,
+        None => GateModel::default(),
+    };
+
+    // Gate estimates are informational, so they are not printed when running quietly.
+    let gate_model = (!config.quiet).then_some(&gate_model);
 
     if args.watch {
-        watch_workspace(&workspace, &args.compile_options)
+        watch_workspace(&workspace, &args.compile_options, gate_model)
             .map_err(|err| CliError::Generic(err.to_string()))?;
     } else {
-        compile_workspace_full(&workspace, &args.compile_options)?;
+        compile_workspace_full(&workspace, &args.compile_options, gate_model)?;
     }
 
     Ok(())
 }
 
-fn watch_workspace(workspace: &Workspace, compile_options: &CompileOptions) -> notify::Result<()> {
+fn read_gate_model(path: &Path) -> Result<GateModel, CliError> {
+    let contents = std::fs::read_to_string(path).map_err(|err| {
+        CliError::Generic(format!("Failed to read gate model {}: {err}", path.display()))
+    })?;
+    let config: GateModelConfig = toml::from_str(&contents).map_err(|err| {
+        CliError::Generic(format!("Failed to parse gate model {}: {err}", path.display()))
+    })?;
+    GateModel::from_config(config).map_err(|err| CliError::Generic(err.to_string()))
+}
+
+fn watch_workspace(
+    workspace: &Workspace,
+    compile_options: &CompileOptions,
+    gate_model: Option<&GateModel>,
+) -> notify::Result<()> {
     let (tx, rx) = std::sync::mpsc::channel();
 
     // No specific tickrate, max debounce time 1 seconds
@@ -81,7 +104,7 @@ fn watch_workspace(workspace: &Workspace, compile_options: &CompileOptions) -> n
     let mut screen = std::io::stdout();
     write!(screen, "{}", termion::cursor::Save).unwrap();
     screen.flush().unwrap();
-    let _ = compile_workspace_full(workspace, compile_options);
+    let _ = compile_workspace_full(workspace, compile_options, gate_model);
     for res in rx {
         let debounced_events = res.map_err(|mut err| err.remove(0))?;
 
@@ -102,7 +125,7 @@ fn watch_workspace(workspace: &Workspace, compile_options: &CompileOptions) -> n
         if noir_files_modified {
             write!(screen, "{}{}", termion::cursor::Restore, termion::clear::AfterCursor).unwrap();
             screen.flush().unwrap();
-            let _ = compile_workspace_full(workspace, compile_options);
+            let _ = compile_workspace_full(workspace, compile_options, gate_model);
         }
     }
 
@@ -114,6 +137,7 @@ fn watch_workspace(workspace: &Workspace, compile_options: &CompileOptions) -> n
 fn compile_workspace_full(
     workspace: &Workspace,
     compile_options: &CompileOptions,
+    gate_model: Option<&GateModel>,
 ) -> Result<(), CliError> {
     let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
     insert_all_files_for_workspace_into_file_manager(workspace, &mut workspace_file_manager);
@@ -139,11 +163,30 @@ fn compile_workspace_full(
     let only_acir = compile_options.only_acir;
     for (package, program) in binary_packages.into_iter().zip(compiled_programs) {
         let program = nargo::ops::transform_program(program, compile_options.expression_width);
+        if let Some(gate_model) = gate_model {
+            println!(
+                "[{}] estimated ~{} gates ({} model)",
+                package.name,
+                gate_model.estimate_program(&program.program),
+                gate_model.family()
+            );
+        }
         save_program(program.clone(), &package, &workspace.target_directory_path(), only_acir);
     }
     let circuit_dir = workspace.target_directory_path();
     for (package, contract) in contract_packages.into_iter().zip(compiled_contracts) {
         let contract = nargo::ops::transform_contract(contract, compile_options.expression_width);
+        if let Some(gate_model) = gate_model {
+            for function in &contract.functions {
+                println!(
+                    "[{}] {}: estimated ~{} gates ({} model)",
+                    package.name,
+                    function.name,
+                    gate_model.estimate_program(&function.bytecode),
+                    gate_model.family()
+                );
+            }
+        }
         save_contract(contract, &package, &circuit_dir);
     }
 
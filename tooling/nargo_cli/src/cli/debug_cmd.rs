@@ -15,9 +15,7 @@ use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::input_parser::{Format, InputValue};
 use noirc_abi::InputMap;
-use noirc_driver::{
-    file_manager_with_stdlib, CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING,
-};
+use noirc_driver::{file_manager_with_stdlib, CompileOptions, CompiledProgram};
 use noirc_frontend::debug::DebugInstrumenter;
 use noirc_frontend::graph::CrateName;
 use noirc_frontend::hir::ParsedFiles;
@@ -36,6 +34,10 @@ pub(crate) struct DebugCommand {
     #[clap(long, short, default_value = PROVER_INPUT_FILE)]
     prover_name: String,
 
+    /// Reject input files containing inputs which don't correspond to any parameter of the program
+    #[clap(long)]
+    strict_inputs: bool,
+
     /// The name of the package to execute
     #[clap(long)]
     package: Option<CrateName>,
@@ -58,11 +60,7 @@ pub(crate) fn run(args: DebugCommand, config: NargoConfig) -> Result<(), CliErro
 
     let toml_path = get_package_manifest(&config.program_dir)?;
     let selection = args.package.map_or(PackageSelection::DefaultOrAll, PackageSelection::Selected);
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
-    )?;
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
     let target_dir = &workspace.target_directory_path();
 
     let Some(package) = workspace.into_iter().find(|p| p.is_binary()) else {
@@ -83,7 +81,14 @@ pub(crate) fn run(args: DebugCommand, config: NargoConfig) -> Result<(), CliErro
     let compiled_program =
         nargo::ops::transform_program(compiled_program, args.compile_options.expression_width);
 
-    run_async(package, compiled_program, &args.prover_name, &args.witness_name, target_dir)
+    run_async(
+        package,
+        compiled_program,
+        &args.prover_name,
+        args.strict_inputs,
+        &args.witness_name,
+        target_dir,
+    )
 }
 
 pub(crate) fn compile_bin_package_for_debugging(
@@ -160,6 +165,7 @@ fn run_async(
     package: &Package,
     program: CompiledProgram,
     prover_name: &str,
+    strict_inputs: bool,
     witness_name: &Option<String>,
     target_dir: &PathBuf,
 ) -> Result<(), CliError> {
@@ -169,7 +175,7 @@ fn run_async(
     runtime.block_on(async {
         println!("[{}] Starting debugger", package.name);
         let (return_value, solved_witness) =
-            debug_program_and_decode(program, package, prover_name)?;
+            debug_program_and_decode(program, package, prover_name, strict_inputs)?;
 
         if let Some(solved_witness) = solved_witness {
             println!("[{}] Circuit witness successfully solved", package.name);
@@ -199,10 +205,16 @@ fn debug_program_and_decode(
     program: CompiledProgram,
     package: &Package,
     prover_name: &str,
+    strict_inputs: bool,
 ) -> Result<(Option<InputValue>, Option<WitnessMap>), CliError> {
     // Parse the initial witness values from Prover.toml
-    let (inputs_map, _) =
-        read_inputs_from_file(&package.root_dir, prover_name, Format::Toml, &program.abi)?;
+    let (inputs_map, _) = read_inputs_from_file(
+        &package.root_dir,
+        prover_name,
+        Format::Toml,
+        &program.abi,
+        strict_inputs,
+    )?;
     let solved_witness = debug_program(&program, &inputs_map)?;
     let public_abi = program.abi.public_abi();
 
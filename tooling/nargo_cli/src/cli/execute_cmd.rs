@@ -5,16 +5,16 @@ use clap::Args;
 use nargo::artifacts::debug::DebugArtifact;
 use nargo::constants::PROVER_INPUT_FILE;
 use nargo::errors::try_to_diagnose_runtime_error;
-use nargo::ops::{compile_program, report_errors, DefaultForeignCallExecutor};
+use nargo::ops::{compile_program, report_errors, DefaultForeignCallExecutor, ExecutionProfile};
 use nargo::package::Package;
+use nargo::NargoError;
 use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::input_parser::{Format, InputValue};
 use noirc_abi::InputMap;
-use noirc_driver::{
-    file_manager_with_stdlib, CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING,
-};
+use noirc_driver::{file_manager_with_stdlib, CompileOptions, CompiledProgram};
 use noirc_frontend::graph::CrateName;
+use prettytable::{row, table};
 
 use super::fs::{inputs::read_inputs_from_file, witness::save_witness_to_dir};
 use super::NargoConfig;
@@ -31,6 +31,10 @@ pub(crate) struct ExecuteCommand {
     #[clap(long, short, default_value = PROVER_INPUT_FILE)]
     prover_name: String,
 
+    /// Reject input files containing inputs which don't correspond to any parameter of the program
+    #[clap(long)]
+    strict_inputs: bool,
+
     /// The name of the package to execute
     #[clap(long, conflicts_with = "workspace")]
     package: Option<CrateName>,
@@ -45,6 +49,10 @@ pub(crate) struct ExecuteCommand {
     /// JSON RPC url to solve oracle calls
     #[clap(long)]
     oracle_resolver: Option<String>,
+
+    /// Time how long each opcode takes to solve and print a summary grouped by opcode kind
+    #[clap(long)]
+    profile_execution: bool,
 }
 
 pub(crate) fn run(args: ExecuteCommand, config: NargoConfig) -> Result<(), CliError> {
@@ -52,11 +60,7 @@ pub(crate) fn run(args: ExecuteCommand, config: NargoConfig) -> Result<(), CliEr
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
     let selection = args.package.map_or(default_selection, PackageSelection::Selected);
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
-    )?;
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
     let target_dir = &workspace.target_directory_path();
 
     let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
@@ -83,14 +87,19 @@ pub(crate) fn run(args: ExecuteCommand, config: NargoConfig) -> Result<(), CliEr
         let compiled_program =
             nargo::ops::transform_program(compiled_program, args.compile_options.expression_width);
 
-        let (return_value, witness_stack) = execute_program_and_decode(
-            compiled_program,
+        let (return_value, witness_stack, profile) = execute_program_and_decode(
+            &compiled_program,
             package,
             &args.prover_name,
+            args.strict_inputs,
             args.oracle_resolver.as_deref(),
+            args.profile_execution,
         )?;
 
         println!("[{}] Circuit witness successfully solved", package.name);
+        if let Some(profile) = profile {
+            print_execution_profile(&profile, &compiled_program);
+        }
         if let Some(return_value) = return_value {
             println!("[{}] Circuit output: {return_value:?}", package.name);
         }
@@ -104,22 +113,35 @@ pub(crate) fn run(args: ExecuteCommand, config: NargoConfig) -> Result<(), CliEr
 }
 
 fn execute_program_and_decode(
-    program: CompiledProgram,
+    program: &CompiledProgram,
     package: &Package,
     prover_name: &str,
+    strict_inputs: bool,
     foreign_call_resolver_url: Option<&str>,
-) -> Result<(Option<InputValue>, WitnessStack), CliError> {
+    profile_execution: bool,
+) -> Result<(Option<InputValue>, WitnessStack, Option<ExecutionProfile>), CliError> {
     // Parse the initial witness values from Prover.toml
-    let (inputs_map, _) =
-        read_inputs_from_file(&package.root_dir, prover_name, Format::Toml, &program.abi)?;
-    let witness_stack = execute_program(&program, &inputs_map, foreign_call_resolver_url)?;
+    let (inputs_map, _) = read_inputs_from_file(
+        &package.root_dir,
+        prover_name,
+        Format::Toml,
+        &program.abi,
+        strict_inputs,
+    )?;
+    let (witness_stack, profile) = if profile_execution {
+        let (witness_stack, profile) =
+            execute_program_with_profiling(program, &inputs_map, foreign_call_resolver_url)?;
+        (witness_stack, Some(profile))
+    } else {
+        (execute_program(program, &inputs_map, foreign_call_resolver_url)?, None)
+    };
     let public_abi = program.abi.public_abi();
     // Get the entry point witness for the ABI
     let main_witness =
         &witness_stack.peek().expect("Should have at least one witness on the stack").witness;
     let (_, return_value) = public_abi.decode(main_witness)?;
 
-    Ok((return_value, witness_stack))
+    Ok((return_value, witness_stack, profile))
 }
 
 pub(crate) fn execute_program(
@@ -137,8 +159,33 @@ pub(crate) fn execute_program(
         &blackbox_solver,
         &mut DefaultForeignCallExecutor::new(true, foreign_call_resolver_url),
     );
-    match solved_witness_stack_err {
-        Ok(solved_witness_stack) => Ok(solved_witness_stack),
+    report_execution_error(compiled_program, solved_witness_stack_err)
+}
+
+fn execute_program_with_profiling(
+    compiled_program: &CompiledProgram,
+    inputs_map: &InputMap,
+    foreign_call_resolver_url: Option<&str>,
+) -> Result<(WitnessStack, ExecutionProfile), CliError> {
+    let blackbox_solver = Bn254BlackBoxSolver::new();
+
+    let initial_witness = compiled_program.abi.encode(inputs_map, None)?;
+
+    let solved_witness_stack_err = nargo::ops::execute_program_with_profiling(
+        &compiled_program.program,
+        initial_witness,
+        &blackbox_solver,
+        &mut DefaultForeignCallExecutor::new(true, foreign_call_resolver_url),
+    );
+    report_execution_error(compiled_program, solved_witness_stack_err)
+}
+
+fn report_execution_error<T>(
+    compiled_program: &CompiledProgram,
+    execution_result: Result<T, NargoError>,
+) -> Result<T, CliError> {
+    match execution_result {
+        Ok(result) => Ok(result),
         Err(err) => {
             let debug_artifact = DebugArtifact {
                 debug_symbols: compiled_program.debug.clone(),
@@ -156,3 +203,63 @@ pub(crate) fn execute_program(
         }
     }
 }
+
+/// Prints the time spent solving each kind of opcode, slowest kind first,
+/// followed by the slowest individual opcodes and the source code they were generated from.
+fn print_execution_profile(profile: &ExecutionProfile, compiled_program: &CompiledProgram) {
+    let mut kinds: Vec<_> = profile.kinds().collect();
+    kinds.sort_by(|(_, a), (_, b)| b.total_time.cmp(&a.total_time));
+
+    let mut kinds_table = table!([
+        Fm->"Opcode Kind",
+        Fm->"Count",
+        Fm->"Total Time",
+        Fm->"Average Time"
+    ]);
+    for (kind, kind_profile) in kinds {
+        kinds_table.add_row(row![
+            Fc->kind,
+            Fc->format!("{}", kind_profile.count),
+            Fc->format!("{:?}", kind_profile.total_time),
+            Fc->format!("{:?}", kind_profile.average_time()),
+        ]);
+    }
+    kinds_table.add_row(row![Fc->"(total)", "", Fc->format!("{:?}", profile.total_time()), ""]);
+    kinds_table.printstd();
+
+    let debug_artifact = DebugArtifact {
+        debug_symbols: compiled_program.debug.clone(),
+        file_map: compiled_program.file_map.clone(),
+        warnings: Vec::new(),
+    };
+
+    let mut slowest_table = table!([
+        Fm->"Function",
+        Fm->"Opcode",
+        Fm->"Kind",
+        Fm->"Time",
+        Fm->"Location"
+    ]);
+    for sample in profile.slowest_opcodes() {
+        let function_index = sample.location.acir_function_index;
+        let location = compiled_program.debug[function_index]
+            .opcode_location(&sample.location.opcode_location)
+            .and_then(|call_stack| call_stack.last().copied())
+            .and_then(|location| {
+                let file = debug_artifact.file_map.get(&location.file)?;
+                let line = debug_artifact.location_line_number(location).ok()?;
+                let column = debug_artifact.location_column_number(location).ok()?;
+                Some(format!("{}:{line}:{column}", file.path.display()))
+            })
+            .unwrap_or_else(|| "unknown".to_string());
+
+        slowest_table.add_row(row![
+            Fc->compiled_program.names[function_index],
+            Fc->format!("{}", sample.location.opcode_location),
+            Fc->sample.kind,
+            Fc->format!("{:?}", sample.time),
+            location,
+        ]);
+    }
+    slowest_table.printstd();
+}
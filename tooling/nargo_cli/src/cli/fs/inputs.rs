@@ -9,17 +9,21 @@ use crate::errors::FilesystemError;
 use super::write_to_file;
 
 /// Returns the circuit's parameters and its return value, if one exists.
+///
+/// Inputs which don't correspond to any parameter of the circuit are reported as warnings,
+/// or as an error if `strict` is set.
 /// # Examples
 ///
 /// ```ignore
 /// let (input_map, return_value): (InputMap, Option<InputValue>) =
-///   read_inputs_from_file(path, "Verifier", Format::Toml, &abi)?;
+///   read_inputs_from_file(path, "Verifier", Format::Toml, &abi, false)?;
 /// ```
 pub(crate) fn read_inputs_from_file<P: AsRef<Path>>(
     path: P,
     file_name: &str,
     format: Format,
     abi: &Abi,
+    strict: bool,
 ) -> Result<(InputMap, Option<InputValue>), FilesystemError> {
     if abi.is_empty() {
         return Ok((BTreeMap::new(), None));
@@ -30,8 +34,18 @@ pub(crate) fn read_inputs_from_file<P: AsRef<Path>>(
         return Err(FilesystemError::MissingTomlFile(file_name.to_owned(), file_path));
     }
 
-    let input_string = std::fs::read_to_string(file_path).unwrap();
-    let mut input_map = format.parse(&input_string, abi)?;
+    let input_string = std::fs::read_to_string(&file_path).unwrap();
+    let (mut input_map, unknown_keys) = format
+        .parse_with_unknown_keys(&input_string, abi, path.as_ref())
+        .map_err(|error| FilesystemError::InvalidInputFile(file_path.clone(), error))?;
+
+    if strict && !unknown_keys.is_empty() {
+        return Err(FilesystemError::UnknownInputs(file_path, unknown_keys));
+    }
+    for key in unknown_keys {
+        tracing::warn!("unknown input `{key}` in {} is ignored", file_path.display());
+    }
+
     let return_value = input_map.remove(MAIN_RETURN_NAME);
 
     Ok((input_map, return_value))
@@ -70,7 +84,7 @@ mod tests {
     use std::{collections::BTreeMap, vec};
 
     use acvm::FieldElement;
-    use nargo::constants::VERIFIER_INPUT_FILE;
+    use nargo::constants::{PROVER_INPUT_FILE, VERIFIER_INPUT_FILE};
     use noirc_abi::{
         input_parser::{Format, InputValue},
         Abi, AbiParameter, AbiReturnType, AbiType, AbiVisibility,
@@ -78,6 +92,7 @@ mod tests {
     use tempfile::TempDir;
 
     use super::{read_inputs_from_file, write_inputs_to_file};
+    use crate::errors::FilesystemError;
 
     #[test]
     fn write_and_read_recovers_inputs_and_return_value() {
@@ -126,9 +141,64 @@ mod tests {
         .unwrap();
 
         let (loaded_inputs, loaded_return_value) =
-            read_inputs_from_file(input_dir, VERIFIER_INPUT_FILE, Format::Toml, &abi).unwrap();
+            read_inputs_from_file(input_dir, VERIFIER_INPUT_FILE, Format::Toml, &abi, true)
+                .unwrap();
 
         assert_eq!(loaded_inputs, input_map);
         assert_eq!(loaded_return_value, return_value);
     }
+
+    #[test]
+    fn unknown_inputs_are_only_rejected_when_strict() {
+        let input_dir = TempDir::new().unwrap().into_path();
+        let abi = Abi {
+            parameters: vec![AbiParameter {
+                name: "amount".into(),
+                typ: AbiType::Field,
+                visibility: AbiVisibility::Private,
+            }],
+            return_type: None,
+            param_witnesses: BTreeMap::new(),
+            return_witnesses: Vec::new(),
+            error_types: BTreeMap::new(),
+        };
+        let file_path = input_dir.join(PROVER_INPUT_FILE).with_extension("toml");
+        std::fs::write(&file_path, "amount = 1\nmemo = 2\n").unwrap();
+
+        let (loaded_inputs, _) =
+            read_inputs_from_file(&input_dir, PROVER_INPUT_FILE, Format::Toml, &abi, false)
+                .unwrap();
+        assert_eq!(loaded_inputs["amount"], InputValue::Field(FieldElement::one()));
+
+        let error = read_inputs_from_file(&input_dir, PROVER_INPUT_FILE, Format::Toml, &abi, true)
+            .unwrap_err();
+        assert!(matches!(
+            &error,
+            FilesystemError::UnknownInputs(path, keys) if *path == file_path && *keys == ["memo"]
+        ));
+    }
+
+    #[test]
+    fn misspelled_input_error_names_file() {
+        let input_dir = TempDir::new().unwrap().into_path();
+        let abi = Abi {
+            parameters: vec![AbiParameter {
+                name: "amount".into(),
+                typ: AbiType::Field,
+                visibility: AbiVisibility::Private,
+            }],
+            return_type: None,
+            param_witnesses: BTreeMap::new(),
+            return_witnesses: Vec::new(),
+            error_types: BTreeMap::new(),
+        };
+        let file_path = input_dir.join(PROVER_INPUT_FILE).with_extension("toml");
+        std::fs::write(&file_path, "amout = 1\n").unwrap();
+
+        let error = read_inputs_from_file(&input_dir, PROVER_INPUT_FILE, Format::Toml, &abi, false)
+            .unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains(&file_path.display().to_string()), "{message}");
+        assert!(message.contains("unknown input `amout`; did you mean `amount`?"), "{message}");
+    }
 }
@@ -0,0 +1,156 @@
+use bn254_blackbox_solver::Bn254BlackBoxSolver;
+use clap::Args;
+use nargo::ops::{compile_program, report_errors, DefaultForeignCallExecutor};
+use nargo::package::Package;
+use nargo::NargoError;
+use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_abi::input_parser::Format;
+use noirc_abi::InputMap;
+use noirc_driver::{file_manager_with_stdlib, CompileOptions, CompiledProgram};
+use noirc_frontend::graph::CrateName;
+
+use super::fs::inputs::write_inputs_to_file;
+use super::NargoConfig;
+use crate::errors::CliError;
+
+mod inputs;
+
+use self::inputs::{generate_inputs, shrink_inputs, Rng};
+
+/// The default name of the toml file which the smallest failing input found is written to
+const FUZZ_FAILURE_FILE: &str = "Prover-fuzz";
+
+/// Executes a circuit with randomly generated inputs, reporting how often it fails
+#[derive(Debug, Clone, Args)]
+pub(crate) struct FuzzCommand {
+    /// The number of times to execute the circuit
+    #[clap(long, default_value_t = 1000)]
+    runs: u32,
+
+    /// The seed from which inputs are generated, the same seed always generates the same inputs
+    #[clap(long, default_value_t = 0)]
+    seed: u64,
+
+    /// The name of the toml file to write the smallest failing input found to
+    #[clap(long, default_value = FUZZ_FAILURE_FILE)]
+    failure_name: String,
+
+    /// The name of the package to fuzz
+    #[clap(long, conflicts_with = "workspace")]
+    package: Option<CrateName>,
+
+    /// Fuzz all packages in the workspace
+    #[clap(long, conflicts_with = "package")]
+    workspace: bool,
+
+    #[clap(flatten)]
+    compile_options: CompileOptions,
+}
+
+pub(crate) fn run(args: FuzzCommand, config: NargoConfig) -> Result<(), CliError> {
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    let default_selection =
+        if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
+    let selection = args.package.clone().map_or(default_selection, PackageSelection::Selected);
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
+
+    let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
+    let parsed_files = parse_all(&workspace_file_manager);
+
+    let mut failing_packages = Vec::new();
+    let binary_packages = workspace.into_iter().filter(|package| package.is_binary());
+    for package in binary_packages {
+        let compilation_result = compile_program(
+            &workspace_file_manager,
+            &parsed_files,
+            package,
+            &args.compile_options,
+            None,
+        );
+        let compiled_program = report_errors(
+            compilation_result,
+            &workspace_file_manager,
+            args.compile_options.deny_warnings,
+            args.compile_options.silence_warnings,
+        )?;
+        let compiled_program =
+            nargo::ops::transform_program(compiled_program, args.compile_options.expression_width);
+
+        if !fuzz_package(package, &compiled_program, &args)? {
+            failing_packages.push(package.name.to_string());
+        }
+    }
+
+    if failing_packages.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::Generic(format!(
+            "Fuzzing found failing inputs for {}",
+            failing_packages.join(", ")
+        )))
+    }
+}
+
+/// Fuzzes a single package, returning whether every execution succeeded.
+fn fuzz_package(
+    package: &Package,
+    program: &CompiledProgram,
+    args: &FuzzCommand,
+) -> Result<bool, CliError> {
+    let ranges = &package.fuzz_ranges;
+    let mut rng = Rng::new(args.seed);
+
+    let mut failures = 0;
+    let mut first_failure = None;
+    for _ in 0..args.runs {
+        let inputs = generate_inputs(&program.abi, ranges, &mut rng);
+        if execute(program, &inputs)?.is_err() {
+            failures += 1;
+            first_failure.get_or_insert(inputs);
+        }
+    }
+
+    let rate = if args.runs == 0 { 0.0 } else { 100.0 * failures as f64 / args.runs as f64 };
+    println!("[{}] {failures} of {} executions failed ({rate:.1}%)", package.name, args.runs);
+
+    let Some(failing_inputs) = first_failure else {
+        return Ok(true);
+    };
+    let minimized = shrink_inputs(&program.abi, ranges, failing_inputs, |inputs| {
+        matches!(execute(program, inputs), Ok(Err(_)))
+    });
+    if let Ok(Err(error)) = execute(program, &minimized) {
+        println!("[{}] Smallest failing input found fails with: {error}", package.name);
+    }
+
+    let failure_path = package.root_dir.join(&args.failure_name).with_extension(Format::Toml.ext());
+    write_inputs_to_file(
+        &minimized,
+        &None,
+        &program.abi,
+        &package.root_dir,
+        &args.failure_name,
+        Format::Toml,
+    )?;
+    println!("[{}] Smallest failing input written to {}", package.name, failure_path.display());
+
+    Ok(false)
+}
+
+/// Executes `program` with `inputs`, returning the error it fails with if it does.
+/// Fails if the inputs can't be encoded, which is a bug in the input generator.
+fn execute(
+    program: &CompiledProgram,
+    inputs: &InputMap,
+) -> Result<Result<(), NargoError>, CliError> {
+    let initial_witness = program.abi.encode(inputs, None)?;
+    let result = nargo::ops::execute_program(
+        &program.program,
+        initial_witness,
+        &Bn254BlackBoxSolver::new(),
+        &mut DefaultForeignCallExecutor::new(false, None),
+    );
+    Ok(result.map(|_| ()))
+}
@@ -0,0 +1,335 @@
+//! Generates random inputs for a program from its ABI, and shrinks inputs for which it fails.
+//!
+//! Numeric values are generated uniformly: fields over the whole field, integers over the values
+//! of their type and booleans over `true` and `false`. The `[fuzz]` table of a package's
+//! `Nargo.toml` can narrow the values generated for each parameter of `main`, e.g.
+//!
+//! ```toml
+//! [fuzz]
+//! x = { min = 1, max = 100 }
+//! ```
+//!
+//! The range applies to every numeric value within the parameter, such as the elements of an array.
+use std::collections::BTreeMap;
+
+use acvm::FieldElement;
+use iter_extended::{btree_map, vecmap};
+use nargo::package::FuzzRange;
+use noirc_abi::input_parser::InputValue;
+use noirc_abi::{Abi, AbiType, InputMap};
+
+/// The smallest value within `range`.
+fn range_min(range: FuzzRange) -> FieldElement {
+    FieldElement::from(range.min.unwrap_or(0) as u128)
+}
+
+/// A SplitMix64 random number generator.
+///
+/// This is implemented here rather than taken from a crate so that the inputs generated for a
+/// seed stay the same across versions of nargo.
+pub(super) struct Rng(u64);
+
+impl Rng {
+    pub(super) fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        (self.next_u64() as u128) << 64 | self.next_u64() as u128
+    }
+
+    /// Returns a value between `min` and `max` inclusive.
+    fn between(&mut self, min: u128, max: u128) -> u128 {
+        match (max - min).checked_add(1) {
+            Some(count) => min + self.next_u128() % count,
+            None => self.next_u128(),
+        }
+    }
+
+    fn field(&mut self) -> FieldElement {
+        let bytes: Vec<u8> = (0..4).flat_map(|_| self.next_u64().to_be_bytes()).collect();
+        FieldElement::from_be_bytes_reduce(&bytes)
+    }
+}
+
+/// Generates a random value for each parameter of `abi`.
+pub(super) fn generate_inputs(
+    abi: &Abi,
+    ranges: &BTreeMap<String, FuzzRange>,
+    rng: &mut Rng,
+) -> InputMap {
+    btree_map(&abi.parameters, |parameter| {
+        let range = ranges.get(&parameter.name).copied().unwrap_or_default();
+        (parameter.name.clone(), generate_value(&parameter.typ, range, rng))
+    })
+}
+
+fn generate_value(typ: &AbiType, range: FuzzRange, rng: &mut Rng) -> InputValue {
+    match typ {
+        AbiType::Field if range == FuzzRange::default() => InputValue::Field(rng.field()),
+        AbiType::Field => {
+            let max = range.max.map_or(u128::MAX, |max| max as u128);
+            InputValue::Field(rng.between(range.min.unwrap_or(0) as u128, max).into())
+        }
+        AbiType::Integer { width, .. } => {
+            let type_max = if *width >= 128 { u128::MAX } else { (1 << width) - 1 };
+            let max = range.max.map_or(type_max, |max| type_max.min(max as u128));
+            let min = (range.min.unwrap_or(0) as u128).min(max);
+            InputValue::Field(rng.between(min, max).into())
+        }
+        AbiType::Boolean => InputValue::Field((rng.next_u64() & 1 == 1).into()),
+        AbiType::String { length } => {
+            // Printable ASCII characters
+            let string = (0..*length).map(|_| rng.between(0x20, 0x7e) as u8 as char).collect();
+            InputValue::String(string)
+        }
+        AbiType::Array { length, typ } => {
+            InputValue::Vec(vecmap(0..*length, |_| generate_value(typ, range, rng)))
+        }
+        AbiType::Tuple { fields } => {
+            InputValue::Vec(vecmap(fields, |typ| generate_value(typ, range, rng)))
+        }
+        AbiType::Struct { fields, .. } => InputValue::Struct(btree_map(fields, |(name, typ)| {
+            (name.clone(), generate_value(typ, range, rng))
+        })),
+    }
+}
+
+/// Shrinks `inputs`, for which `fails` returns true, by halving the numeric values within them and
+/// setting the elements of arrays within them to their smallest value, for as long as `fails`
+/// still returns true.
+pub(super) fn shrink_inputs(
+    abi: &Abi,
+    ranges: &BTreeMap<String, FuzzRange>,
+    mut inputs: InputMap,
+    mut fails: impl FnMut(&InputMap) -> bool,
+) -> InputMap {
+    'shrinking: loop {
+        for parameter in &abi.parameters {
+            let range = ranges.get(&parameter.name).copied().unwrap_or_default();
+            for candidate in shrink_value(&inputs[&parameter.name], &parameter.typ, range) {
+                let mut shrunk = inputs.clone();
+                shrunk.insert(parameter.name.clone(), candidate);
+                if fails(&shrunk) {
+                    inputs = shrunk;
+                    continue 'shrinking;
+                }
+            }
+        }
+        return inputs;
+    }
+}
+
+/// Returns the values smaller than `value` which it may shrink to, each differing from it in a
+/// single place.
+fn shrink_value(value: &InputValue, typ: &AbiType, range: FuzzRange) -> Vec<InputValue> {
+    match (value, typ) {
+        (InputValue::Field(field), AbiType::Boolean) => {
+            if field.is_zero() {
+                Vec::new()
+            } else {
+                vec![InputValue::Field(FieldElement::zero())]
+            }
+        }
+        (InputValue::Field(field), AbiType::Field | AbiType::Integer { .. }) => {
+            // Halve the distance to the smallest value in the range.
+            let min = range_min(range);
+            if *field <= min {
+                return Vec::new();
+            }
+            vec![InputValue::Field(min + halve(*field - min))]
+        }
+        (InputValue::Vec(elements), AbiType::Array { typ, .. }) => {
+            let smallest = smallest_value(typ, range);
+            let mut shrunk = Vec::new();
+            for (index, element) in elements.iter().enumerate() {
+                if *element != smallest {
+                    shrunk.push(replace_element(elements, index, smallest.clone()));
+                }
+            }
+            for (index, element) in elements.iter().enumerate() {
+                for candidate in shrink_value(element, typ, range) {
+                    shrunk.push(replace_element(elements, index, candidate));
+                }
+            }
+            shrunk
+        }
+        (InputValue::Vec(elements), AbiType::Tuple { fields }) => {
+            let mut shrunk = Vec::new();
+            for (index, (element, typ)) in elements.iter().zip(fields).enumerate() {
+                for candidate in shrink_value(element, typ, range) {
+                    shrunk.push(replace_element(elements, index, candidate));
+                }
+            }
+            shrunk
+        }
+        (InputValue::Struct(values), AbiType::Struct { fields, .. }) => {
+            let mut shrunk = Vec::new();
+            for (name, typ) in fields {
+                for candidate in shrink_value(&values[name], typ, range) {
+                    let mut values = values.clone();
+                    values.insert(name.clone(), candidate);
+                    shrunk.push(InputValue::Struct(values));
+                }
+            }
+            shrunk
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn replace_element(elements: &[InputValue], index: usize, element: InputValue) -> InputValue {
+    let mut elements = elements.to_vec();
+    elements[index] = element;
+    InputValue::Vec(elements)
+}
+
+/// The value which every numeric value within a value of type `typ` shrinks towards.
+fn smallest_value(typ: &AbiType, range: FuzzRange) -> InputValue {
+    match typ {
+        AbiType::Field | AbiType::Integer { .. } => InputValue::Field(range_min(range)),
+        AbiType::Boolean => InputValue::Field(FieldElement::zero()),
+        AbiType::String { length } => InputValue::String(" ".repeat(*length as usize)),
+        AbiType::Array { length, typ } => {
+            InputValue::Vec(vecmap(0..*length, |_| smallest_value(typ, range)))
+        }
+        AbiType::Tuple { fields } => {
+            InputValue::Vec(vecmap(fields, |typ| smallest_value(typ, range)))
+        }
+        AbiType::Struct { fields, .. } => InputValue::Struct(btree_map(fields, |(name, typ)| {
+            (name.clone(), smallest_value(typ, range))
+        })),
+    }
+}
+
+/// Divides `field`, taken as an integer, by two rounding down.
+fn halve(field: FieldElement) -> FieldElement {
+    let mut bytes = field.to_be_bytes();
+    let mut carry = 0;
+    for byte in &mut bytes {
+        let next_carry = *byte & 1;
+        *byte = (*byte >> 1) | (carry << 7);
+        carry = next_carry;
+    }
+    FieldElement::from_be_bytes_reduce(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use acvm::FieldElement;
+    use noirc_abi::input_parser::InputValue;
+    use noirc_abi::{Abi, AbiParameter, AbiType, AbiVisibility, InputMap, Sign};
+
+    use nargo::package::FuzzRange;
+
+    use super::{generate_inputs, shrink_inputs, Rng};
+
+    fn abi(parameters: Vec<(&str, AbiType)>) -> Abi {
+        Abi {
+            parameters: parameters
+                .into_iter()
+                .map(|(name, typ)| AbiParameter {
+                    name: name.to_string(),
+                    typ,
+                    visibility: AbiVisibility::Private,
+                })
+                .collect(),
+            param_witnesses: BTreeMap::new(),
+            return_type: None,
+            return_witnesses: Vec::new(),
+            error_types: BTreeMap::new(),
+        }
+    }
+
+    fn value(inputs: &InputMap, name: &str) -> u128 {
+        match &inputs[name] {
+            InputValue::Field(field) => field.to_u128(),
+            value => panic!("Expected a field, got {value:?}"),
+        }
+    }
+
+    #[test]
+    fn inputs_are_determined_by_the_seed() {
+        let abi = abi(vec![
+            ("x", AbiType::Field),
+            ("y", AbiType::Array { length: 3, typ: Box::new(AbiType::Boolean) }),
+        ]);
+        let ranges = BTreeMap::new();
+        let first = generate_inputs(&abi, &ranges, &mut Rng::new(7));
+        let second = generate_inputs(&abi, &ranges, &mut Rng::new(7));
+        let other_seed = generate_inputs(&abi, &ranges, &mut Rng::new(8));
+        assert_eq!(first, second);
+        assert_ne!(first, other_seed);
+    }
+
+    #[test]
+    fn generated_values_respect_types_and_ranges() {
+        let u8_type = AbiType::Integer { sign: Sign::Unsigned, width: 8 };
+        let abi = abi(vec![("byte", u8_type.clone()), ("bounded", u8_type)]);
+        let ranges =
+            BTreeMap::from([("bounded".to_string(), FuzzRange { min: Some(10), max: Some(20) })]);
+
+        let mut rng = Rng::new(0);
+        for _ in 0..100 {
+            let inputs = generate_inputs(&abi, &ranges, &mut rng);
+            assert!(value(&inputs, "byte") < 256);
+            assert!((10..=20).contains(&value(&inputs, "bounded")));
+        }
+    }
+
+    #[test]
+    fn shrinking_stops_at_the_failing_value() {
+        let abi = abi(vec![("x", AbiType::Field)]);
+        let inputs =
+            BTreeMap::from([("x".to_string(), InputValue::Field(FieldElement::from(42u128)))]);
+        let fails = |inputs: &InputMap| value(inputs, "x") == 42;
+        let shrunk = shrink_inputs(&abi, &BTreeMap::new(), inputs, fails);
+        assert_eq!(value(&shrunk, "x"), 42);
+    }
+
+    #[test]
+    fn shrinking_halves_values_and_zeroes_array_elements() {
+        let u32_type = AbiType::Integer { sign: Sign::Unsigned, width: 32 };
+        let abi = abi(vec![
+            ("x", u32_type.clone()),
+            ("ys", AbiType::Array { length: 3, typ: Box::new(u32_type) }),
+        ]);
+        let field = |value: u128| InputValue::Field(FieldElement::from(value));
+        let inputs = BTreeMap::from([
+            ("x".to_string(), field(1000)),
+            ("ys".to_string(), InputValue::Vec(vec![field(5), field(700), field(9)])),
+        ]);
+
+        // Fails whenever `x` is at least 10 and an element of `ys` is at least 100.
+        let fails = |inputs: &InputMap| {
+            let InputValue::Vec(ys) = &inputs["ys"] else { unreachable!() };
+            let large_y =
+                ys.iter().any(|y| matches!(y, InputValue::Field(y) if y.to_u128() >= 100));
+            value(inputs, "x") >= 10 && large_y
+        };
+        let shrunk = shrink_inputs(&abi, &BTreeMap::new(), inputs, fails);
+
+        assert_eq!(value(&shrunk, "x"), 15);
+        assert_eq!(shrunk["ys"], InputValue::Vec(vec![field(0), field(175), field(0)]));
+    }
+
+    #[test]
+    fn shrinking_respects_ranges() {
+        let abi = abi(vec![("x", AbiType::Field)]);
+        let ranges = BTreeMap::from([("x".to_string(), FuzzRange { min: Some(30), max: None })]);
+        let inputs =
+            BTreeMap::from([("x".to_string(), InputValue::Field(FieldElement::from(1000u128)))]);
+        let shrunk = shrink_inputs(&abi, &ranges, inputs, |_| true);
+        assert_eq!(value(&shrunk, "x"), 30);
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use acvm::acir::circuit::{ExpressionWidth, Program};
-use backend_interface::BackendError;
+use backend_interface::{BackendCache, BackendError, Cached};
 use clap::Args;
 use iter_extended::vecmap;
 use nargo::{
@@ -9,10 +9,7 @@ use nargo::{
     ops::report_errors, package::Package, parse_all,
 };
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
-use noirc_driver::{
-    file_manager_with_stdlib, CompileOptions, CompiledContract, CompiledProgram,
-    NOIR_ARTIFACT_VERSION_STRING,
-};
+use noirc_driver::{file_manager_with_stdlib, CompileOptions, CompiledContract, CompiledProgram};
 use noirc_errors::{debug_info::OpCodesCount, Location};
 use noirc_frontend::graph::CrateName;
 use prettytable::{row, table, Row};
@@ -29,6 +26,9 @@ use super::{compile_cmd::compile_workspace, NargoConfig};
 /// Current information provided per circuit:
 /// 1. The number of ACIR opcodes
 /// 2. Counts the final number gates in the circuit used by a backend
+///
+/// Circuit sizes are cached in the target directory and reused while the circuit and backend are unchanged.
+/// Pass `--no-backend-cache` to always invoke the backend.
 #[derive(Debug, Clone, Args)]
 #[clap(visible_alias = "i")]
 pub(crate) struct InfoCommand {
@@ -47,6 +47,10 @@ pub(crate) struct InfoCommand {
     #[clap(long, hide = true)]
     profile_info: bool,
 
+    /// Always invoke the backend instead of reusing cached results
+    #[clap(long)]
+    no_backend_cache: bool,
+
     #[clap(flatten)]
     compile_options: CompileOptions,
 }
@@ -60,11 +64,7 @@ pub(crate) fn run(
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
     let selection = args.package.map_or(default_selection, PackageSelection::Selected);
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
-    )?;
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
 
     let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
     insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
@@ -112,6 +112,9 @@ pub(crate) fn run(
         }
     }
 
+    let cache =
+        (!args.no_backend_cache).then(|| BackendCache::new(workspace.cache_directory_path()));
+
     let binary_packages =
         workspace.into_iter().filter(|package| package.is_binary()).zip(compiled_programs);
 
@@ -120,6 +123,7 @@ pub(crate) fn run(
         .map(|(package, program)| {
             count_opcodes_and_gates_in_program(
                 backend,
+                cache.as_ref(),
                 program,
                 package,
                 args.compile_options.expression_width,
@@ -132,6 +136,7 @@ pub(crate) fn run(
         .map(|contract| {
             count_opcodes_and_gates_in_contract(
                 backend,
+                cache.as_ref(),
                 contract,
                 args.compile_options.expression_width,
             )
@@ -243,15 +248,33 @@ struct ProgramInfo {
 
 impl From<ProgramInfo> for Vec<Row> {
     fn from(program_info: ProgramInfo) -> Self {
-        vecmap(program_info.functions, |function| {
+        let mut rows = vecmap(&program_info.functions, |function| {
             row![
                 Fm->format!("{}", program_info.package_name),
                 Fc->format!("{}", function.name),
                 format!("{:?}", program_info.expression_width),
                 Fc->format!("{}", function.acir_opcodes),
-                Fc->format!("{}", function.circuit_size),
+                Fc->function.circuit_size_display(),
             ]
-        })
+        });
+
+        // Programs with non-inlined functions are made up of multiple circuits,
+        // so we also report the total across all of them.
+        if program_info.functions.len() > 1 {
+            let acir_opcodes: usize =
+                program_info.functions.iter().map(|function| function.acir_opcodes).sum();
+            let circuit_size: u32 =
+                program_info.functions.iter().map(|function| function.circuit_size).sum();
+            rows.push(row![
+                Fm->format!("{}", program_info.package_name),
+                Fc->"(total)",
+                format!("{:?}", program_info.expression_width),
+                Fc->format!("{}", acir_opcodes),
+                Fc->format!("{}", circuit_size),
+            ]);
+        }
+
+        rows
     }
 }
 
@@ -269,6 +292,19 @@ struct FunctionInfo {
     name: String,
     acir_opcodes: usize,
     circuit_size: u32,
+    /// Whether the circuit size was read from the cache rather than computed by the backend.
+    #[serde(skip)]
+    cached: bool,
+}
+
+impl FunctionInfo {
+    fn circuit_size_display(&self) -> String {
+        if self.cached {
+            format!("{} (cached)", self.circuit_size)
+        } else {
+            format!("{}", self.circuit_size)
+        }
+    }
 }
 
 impl From<ContractInfo> for Vec<Row> {
@@ -279,14 +315,26 @@ impl From<ContractInfo> for Vec<Row> {
                 Fc->format!("{}", function.name),
                 format!("{:?}", contract_info.expression_width),
                 Fc->format!("{}", function.acir_opcodes),
-                Fc->format!("{}", function.circuit_size),
+                Fc->function.circuit_size_display(),
             ]
         })
     }
 }
 
+fn get_circuit_size(
+    backend: &Backend,
+    cache: Option<&BackendCache>,
+    program: &Program,
+) -> Result<Cached<u32>, BackendError> {
+    match cache {
+        Some(cache) => backend.get_exact_circuit_size_cached(program, cache),
+        None => Ok(Cached { value: backend.get_exact_circuit_size(program)?, cache_hit: false }),
+    }
+}
+
 fn count_opcodes_and_gates_in_program(
     backend: &Backend,
+    cache: Option<&BackendCache>,
     compiled_program: CompiledProgram,
     package: &Package,
     expression_width: ExpressionWidth,
@@ -297,14 +345,16 @@ fn count_opcodes_and_gates_in_program(
         .into_par_iter()
         .enumerate()
         .map(|(i, function)| -> Result<_, BackendError> {
+            let acir_opcodes = function.opcodes.len();
+            // Unconstrained functions do not matter to a backend circuit count so we pass nothing here
+            let program =
+                Program { functions: vec![function], unconstrained_functions: Vec::new() };
+            let circuit_size = get_circuit_size(backend, cache, &program)?;
             Ok(FunctionInfo {
                 name: compiled_program.names[i].clone(),
-                acir_opcodes: function.opcodes.len(),
-                // Unconstrained functions do not matter to a backend circuit count so we pass nothing here
-                circuit_size: backend.get_exact_circuit_size(&Program {
-                    functions: vec![function],
-                    unconstrained_functions: Vec::new(),
-                })?,
+                acir_opcodes,
+                circuit_size: circuit_size.value,
+                cached: circuit_size.cache_hit,
             })
         })
         .collect::<Result<_, _>>()?;
@@ -314,6 +364,7 @@ fn count_opcodes_and_gates_in_program(
 
 fn count_opcodes_and_gates_in_contract(
     backend: &Backend,
+    cache: Option<&BackendCache>,
     contract: CompiledContract,
     expression_width: ExpressionWidth,
 ) -> Result<ContractInfo, CliError> {
@@ -321,11 +372,13 @@ fn count_opcodes_and_gates_in_contract(
         .functions
         .into_par_iter()
         .map(|function| -> Result<_, BackendError> {
+            let circuit_size = get_circuit_size(backend, cache, &function.bytecode)?;
             Ok(FunctionInfo {
                 name: function.name,
                 // TODO(https://github.com/noir-lang/noir/issues/4720)
                 acir_opcodes: function.bytecode.functions[0].opcodes.len(),
-                circuit_size: backend.get_exact_circuit_size(&function.bytecode)?,
+                circuit_size: circuit_size.value,
+                cached: circuit_size.cache_hit,
             })
         })
         .collect::<Result<_, _>>()?;
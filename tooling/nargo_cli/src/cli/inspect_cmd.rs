@@ -0,0 +1,333 @@
+use std::path::{Path, PathBuf};
+
+use acvm::acir::circuit::{OpcodeLocation, Program};
+use clap::Args;
+use iter_extended::vecmap;
+use nargo::artifacts::debug::DebugArtifact;
+use nargo::ops::{compile_program, report_errors};
+use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_abi::{Abi, AbiVisibility};
+use noirc_driver::{
+    file_manager_with_stdlib, CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING,
+};
+use noirc_errors::{
+    debug_info::{DebugInfo, ProgramDebugInfo},
+    Location,
+};
+use noirc_frontend::graph::CrateName;
+use serde_json::Value;
+
+use super::NargoConfig;
+use crate::errors::{CliError, FilesystemError};
+
+/// Shows the ACIR of a program and how its opcodes were generated
+///
+/// Without `--opcode` every ACIR opcode is listed along with the source code it came from.
+/// With `--opcode` the program is compiled with `--full-debug` and the opcode is printed along
+/// with the SSA instruction and monomorphized expression it was generated from, and the source
+/// code they came from.
+///
+/// Given the path to a program artifact, that artifact is inspected instead of the current package,
+/// so no Nargo.toml or source code is needed.
+#[derive(Debug, Clone, Args)]
+pub(crate) struct InspectCommand {
+    /// A compiled program artifact to inspect instead of the current package
+    #[clap(conflicts_with_all = ["package", "workspace"])]
+    artifact: Option<PathBuf>,
+
+    /// The index of the ACIR opcode to inspect. If omitted, all ACIR opcodes are listed
+    #[clap(long)]
+    opcode: Option<usize>,
+
+    /// The name of the ACIR function to inspect. Defaults to `main` when inspecting an opcode,
+    /// and to all functions otherwise
+    #[clap(long)]
+    function: Option<String>,
+
+    /// The name of the package to inspect
+    #[clap(long, conflicts_with = "workspace")]
+    package: Option<CrateName>,
+
+    /// Inspect all packages in the workspace
+    #[clap(long, conflicts_with = "package")]
+    workspace: bool,
+
+    #[clap(flatten)]
+    compile_options: CompileOptions,
+}
+
+/// The parts of a compiled program which are shown by `nargo inspect`.
+///
+/// Artifacts may have been stripped of their ABI or debug info, in which case less is shown.
+struct InspectedProgram {
+    program: Program,
+    names: Vec<String>,
+    abi: Option<Abi>,
+    debug_artifact: DebugArtifact,
+}
+
+impl From<CompiledProgram> for InspectedProgram {
+    fn from(compiled_program: CompiledProgram) -> Self {
+        InspectedProgram {
+            program: compiled_program.program,
+            names: compiled_program.names,
+            abi: Some(compiled_program.abi),
+            debug_artifact: DebugArtifact {
+                debug_symbols: compiled_program.debug,
+                file_map: compiled_program.file_map,
+                warnings: compiled_program.warnings,
+            },
+        }
+    }
+}
+
+impl InspectedProgram {
+    fn debug_info(&self, function_index: usize) -> Option<&DebugInfo> {
+        self.debug_artifact.debug_symbols.get(function_index)
+    }
+
+    fn function_index(&self, name: &str) -> Result<usize, CliError> {
+        self.names.iter().position(|function| function == name).ok_or_else(|| {
+            CliError::Generic(format!("The program has no ACIR function named `{name}`"))
+        })
+    }
+}
+
+pub(crate) fn run(args: InspectCommand, config: NargoConfig) -> Result<(), CliError> {
+    if let Some(artifact_path) = &args.artifact {
+        let program = read_program_artifact(artifact_path)?;
+        return inspect(&program, args.function.as_deref(), args.opcode);
+    }
+
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    let default_selection =
+        if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
+    let selection = args.package.map_or(default_selection, PackageSelection::Selected);
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
+
+    let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
+    let parsed_files = parse_all(&workspace_file_manager);
+
+    let compile_options = CompileOptions { full_debug: true, ..args.compile_options };
+
+    let binary_packages = workspace.into_iter().filter(|package| package.is_binary());
+    for package in binary_packages {
+        let compilation_result = compile_program(
+            &workspace_file_manager,
+            &parsed_files,
+            package,
+            &compile_options,
+            None,
+        );
+
+        let compiled_program = report_errors(
+            compilation_result,
+            &workspace_file_manager,
+            compile_options.deny_warnings,
+            compile_options.silence_warnings,
+        )?;
+
+        let compiled_program =
+            nargo::ops::transform_program(compiled_program, compile_options.expression_width);
+
+        println!("[{}]", package.name);
+        let program = InspectedProgram::from(compiled_program);
+        inspect(&program, args.function.as_deref(), args.opcode)?;
+    }
+    Ok(())
+}
+
+/// Reads a program artifact, which may have been compiled on another machine.
+///
+/// Only the bytecode is required: a missing or unreadable ABI or debug info is skipped so that the
+/// opcodes can still be shown.
+fn read_program_artifact(path: &Path) -> Result<InspectedProgram, CliError> {
+    let contents =
+        std::fs::read(path).map_err(|_| FilesystemError::PathNotValid(path.to_path_buf()))?;
+    let invalid = FilesystemError::ProgramSerializationError;
+    let Value::Object(mut artifact) =
+        serde_json::from_slice(&contents).map_err(|error| invalid(error.to_string()))?
+    else {
+        return Err(invalid("expected a JSON object".to_string()).into());
+    };
+
+    let artifact_version =
+        artifact.get("noir_version").and_then(Value::as_str).map(ToString::to_string);
+    if let Some(artifact_version) = &artifact_version {
+        check_artifact_version(path, artifact_version)?;
+    }
+
+    let bytecode = artifact.remove("bytecode").ok_or_else(|| invalid("missing bytecode".into()))?;
+    let program = Program::deserialize_program_base64(bytecode).map_err(|error| {
+        // The bytecode format changes between versions, so blame the version when they differ.
+        match artifact_version {
+            Some(artifact_version) if artifact_version != NOIR_ARTIFACT_VERSION_STRING => {
+                CliError::OutdatedArtifactVersion {
+                    path: path.to_path_buf(),
+                    artifact_version,
+                    current: NOIR_ARTIFACT_VERSION_STRING.to_string(),
+                }
+            }
+            _ => invalid(error.to_string()).into(),
+        }
+    })?;
+
+    let abi = artifact.remove("abi").and_then(|abi| serde_json::from_value(abi).ok());
+    let debug_symbols = artifact
+        .remove("debug_symbols")
+        .and_then(|debug| ProgramDebugInfo::deserialize_compressed_base64_json(debug).ok())
+        .map(|debug| debug.debug_infos)
+        .unwrap_or_default();
+    let file_map = artifact
+        .remove("file_map")
+        .and_then(|file_map| serde_json::from_value(file_map).ok())
+        .unwrap_or_default();
+    let names = artifact
+        .remove("names")
+        .and_then(|names| serde_json::from_value::<Vec<String>>(names).ok())
+        .filter(|names| names.len() == program.functions.len())
+        .unwrap_or_else(|| vecmap(0..program.functions.len(), |index| format!("func {index}")));
+
+    let debug_artifact = DebugArtifact { debug_symbols, file_map, warnings: Vec::new() };
+    Ok(InspectedProgram { program, names, abi, debug_artifact })
+}
+
+/// Rejects artifacts compiled by a newer version of nargo, as their format may have changed.
+fn check_artifact_version(path: &Path, artifact_version: &str) -> Result<(), CliError> {
+    let current = NOIR_ARTIFACT_VERSION_STRING;
+    match (parse_version(artifact_version), parse_version(current)) {
+        (Some(artifact), Some(supported)) if artifact > supported => {
+            Err(CliError::IncompatibleArtifactVersion {
+                path: path.to_path_buf(),
+                artifact_version: artifact_version.to_string(),
+                current: current.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Parses the `major.minor.patch` part of a version such as `0.30.0+abcdef`.
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.split(['+', '-']).next()?;
+    let mut parts = version.split('.').map(|part| part.parse().ok());
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+fn inspect(
+    program: &InspectedProgram,
+    function: Option<&str>,
+    opcode: Option<usize>,
+) -> Result<(), CliError> {
+    match opcode {
+        Some(opcode_index) => print_provenance(program, function, opcode_index),
+        None => print_acir(program, function),
+    }
+}
+
+/// Lists the ACIR opcodes of the program, each with the source code it was generated from.
+fn print_acir(program: &InspectedProgram, function: Option<&str>) -> Result<(), CliError> {
+    match &program.abi {
+        Some(abi) => {
+            let parameters = vecmap(&abi.parameters, |parameter| {
+                let visibility = match parameter.visibility {
+                    AbiVisibility::Public => "public",
+                    AbiVisibility::Private => "private",
+                    AbiVisibility::DataBus => "databus",
+                };
+                format!("{} ({visibility})", parameter.name)
+            });
+            println!("Parameters: {}", parameters.join(", "));
+            println!("Return values: {}", abi.return_witnesses.len());
+        }
+        None => println!("The ABI is missing"),
+    }
+    if program.debug_artifact.debug_symbols.is_empty() {
+        println!("The debug info is missing, so source code locations are not shown");
+    }
+
+    let function_indices = match function {
+        Some(name) => vec![program.function_index(name)?],
+        None => (0..program.program.functions.len()).collect(),
+    };
+    for function_index in function_indices {
+        let opcodes = &program.program.functions[function_index].opcodes;
+        let function_name = &program.names[function_index];
+        println!("ACIR function `{function_name}` ({} opcodes):", opcodes.len());
+
+        let debug_info = program.debug_info(function_index);
+        for (opcode_index, opcode) in opcodes.iter().enumerate() {
+            let location = debug_info
+                .and_then(|debug| debug.opcode_location(&OpcodeLocation::Acir(opcode_index)))
+                .and_then(|locations| locations.last().copied());
+            match location {
+                Some(location) => {
+                    let location = render_location(&program.debug_artifact, location);
+                    println!("{opcode_index:>4}: {opcode}\n      at {location}");
+                }
+                None => println!("{opcode_index:>4}: {opcode}"),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_provenance(
+    program: &InspectedProgram,
+    function: Option<&str>,
+    opcode_index: usize,
+) -> Result<(), CliError> {
+    let function_index = match function {
+        Some(name) => program.function_index(name)?,
+        None => 0,
+    };
+    let function_name = &program.names[function_index];
+
+    let opcodes = &program.program.functions[function_index].opcodes;
+    let Some(opcode) = opcodes.get(opcode_index) else {
+        return Err(CliError::Generic(format!(
+            "`{function_name}` only has {} ACIR opcodes",
+            opcodes.len()
+        )));
+    };
+    println!("ACIR opcode {opcode_index} of `{function_name}`: {opcode}");
+
+    let Some(debug_info) = program.debug_info(function_index) else {
+        println!("The debug info is missing, so the opcode's origin is unknown");
+        return Ok(());
+    };
+    if debug_info.provenance_index.is_none() {
+        println!(
+            "The program was not compiled with `--full-debug`, so the opcode's origin is unknown"
+        );
+        return Ok(());
+    }
+    let Some(chain) = debug_info.provenance(opcode_index) else {
+        println!("The opcode was not generated from an SSA instruction");
+        return Ok(());
+    };
+    println!("SSA instruction in `{}`: {}", chain.ssa_function, chain.ssa_instruction);
+    match &chain.expression {
+        Some((id, expression)) => println!("Monomorphized expression $e{}: {expression}", id.0),
+        None => println!("Monomorphized expression: unknown"),
+    }
+
+    for location in chain.locations.iter().rev() {
+        println!("Source: {}", render_location(&program.debug_artifact, *location));
+    }
+    Ok(())
+}
+
+/// Renders a location as `path:line:column: code`.
+fn render_location(debug_artifact: &DebugArtifact, location: Location) -> String {
+    let Some(file) = debug_artifact.file_map.get(&location.file) else {
+        return format!("{location:?}");
+    };
+    let line = debug_artifact.location_line_number(location).unwrap_or_default();
+    let column = debug_artifact.location_column_number(location).unwrap_or_default();
+    let range = location.span.start() as usize..location.span.end() as usize;
+    let code = file.source.get(range).unwrap_or_default();
+    format!("{}:{line}:{column}: {code}", file.path.display())
+}
@@ -1,8 +1,12 @@
-use clap::{Args, Parser, Subcommand};
+use clap::{ArgAction, Args, Parser, Subcommand};
 use const_format::formatcp;
 use nargo_toml::find_package_root;
 use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
 use std::path::PathBuf;
+#[cfg(not(feature = "codegen-docs"))]
+use tracing_appender::rolling;
+#[cfg(not(feature = "codegen-docs"))]
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
 
 use color_eyre::eyre;
 
@@ -10,6 +14,7 @@ use crate::backends::get_active_backend;
 
 mod fs;
 
+mod abi_cmd;
 mod backend_cmd;
 mod check_cmd;
 mod codegen_verifier_cmd;
@@ -19,18 +24,26 @@ mod debug_cmd;
 mod execute_cmd;
 mod export_cmd;
 mod fmt_cmd;
+mod fuzz_cmd;
 mod info_cmd;
 mod init_cmd;
+mod inspect_cmd;
 mod lsp_cmd;
 mod new_cmd;
 mod prove_cmd;
 mod test_cmd;
+mod test_programs_cmd;
 mod verify_cmd;
 
 const GIT_HASH: &str = env!("GIT_COMMIT");
 const IS_DIRTY: &str = env!("GIT_DIRTY");
 const NARGO_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The filter used when no log level is given on the command line or through `NOIR_LOG`:
+/// warnings, along with any output of the backend.
+#[cfg(not(feature = "codegen-docs"))]
+const DEFAULT_LOG_DIRECTIVE: &str = "warn,backend=info";
+
 static VERSION_STRING: &str = formatcp!(
     "version = {}\nnoirc version = {}\n(git version hash: {}, is dirty: {})",
     NARGO_VERSION,
@@ -47,6 +60,9 @@ struct NargoCli {
 
     #[clap(flatten)]
     config: NargoConfig,
+
+    #[clap(flatten)]
+    log_level: LogLevelArgs,
 }
 
 #[non_exhaustive]
@@ -55,6 +71,47 @@ pub(crate) struct NargoConfig {
     // REMINDER: Also change this flag in the LSP test lens if renamed
     #[arg(long, hide = true, global = true, default_value = "./")]
     program_dir: PathBuf,
+
+    /// Ignore the `compiler_version` requirements of the packages being compiled
+    #[arg(long, global = true)]
+    ignore_version_check: bool,
+
+    /// Whether the global `--quiet` flag was passed, in which case commands should only print errors.
+    #[arg(skip)]
+    quiet: bool,
+}
+
+impl NargoConfig {
+    /// The compiler version which packages' `compiler_version` requirements are checked against,
+    /// or None if the check should be skipped.
+    pub(crate) fn compiler_version(&self) -> Option<String> {
+        (!self.ignore_version_check).then(|| NOIR_ARTIFACT_VERSION_STRING.to_string())
+    }
+}
+
+/// Global flags controlling how much is logged, including any output of the backend.
+#[derive(Args, Clone, Copy, Debug)]
+struct LogLevelArgs {
+    /// Only print errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Print debug logs, or trace logs if repeated
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[cfg(not(feature = "codegen-docs"))]
+impl LogLevelArgs {
+    /// Returns the tracing filter directive requested on the command line, if any.
+    fn filter_directive(self) -> Option<&'static str> {
+        match (self.quiet, self.verbose) {
+            (true, _) => Some("error"),
+            (false, 0) => None,
+            (false, 1) => Some("debug"),
+            (false, _) => Some("trace"),
+        }
+    }
 }
 
 #[non_exhaustive]
@@ -69,6 +126,7 @@ enum NargoCommand {
     New(new_cmd::NewCommand),
     Init(init_cmd::InitCommand),
     Execute(execute_cmd::ExecuteCommand),
+    Fuzz(fuzz_cmd::FuzzCommand),
     #[command(hide = true)] // Hidden while the feature is being built out
     Export(export_cmd::ExportCommand),
     #[command(hide = true)] // Hidden while the feature is being built out
@@ -76,7 +134,11 @@ enum NargoCommand {
     Prove(prove_cmd::ProveCommand),
     Verify(verify_cmd::VerifyCommand),
     Test(test_cmd::TestCommand),
+    #[command(hide = true)] // Used to run the compiler's own test programs
+    TestPrograms(test_programs_cmd::TestProgramsCommand),
     Info(info_cmd::InfoCommand),
+    Inspect(inspect_cmd::InspectCommand),
+    Abi(abi_cmd::AbiCommand),
     Lsp(lsp_cmd::LspCommand),
     #[command(hide = true)]
     Dap(dap_cmd::DapCommand),
@@ -84,7 +146,10 @@ enum NargoCommand {
 
 #[cfg(not(feature = "codegen-docs"))]
 pub(crate) fn start_cli() -> eyre::Result<()> {
-    let NargoCli { command, mut config } = NargoCli::parse();
+    let NargoCli { command, mut config, log_level } = NargoCli::parse();
+
+    setup_tracing(log_level.filter_directive());
+    config.quiet = log_level.quiet;
 
     // If the provided `program_dir` is relative, make it absolute by joining it to the current directory.
     if !config.program_dir.is_absolute() {
@@ -99,6 +164,7 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
             | NargoCommand::Lsp(_)
             | NargoCommand::Backend(_)
             | NargoCommand::Dap(_)
+            | NargoCommand::TestPrograms(_)
     ) {
         config.program_dir = find_package_root(&config.program_dir)?;
     }
@@ -113,11 +179,15 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
         NargoCommand::Compile(args) => compile_cmd::run(args, config),
         NargoCommand::Debug(args) => debug_cmd::run(args, config),
         NargoCommand::Execute(args) => execute_cmd::run(args, config),
+        NargoCommand::Fuzz(args) => fuzz_cmd::run(args, config),
         NargoCommand::Export(args) => export_cmd::run(args, config),
         NargoCommand::Prove(args) => prove_cmd::run(&backend, args, config),
         NargoCommand::Verify(args) => verify_cmd::run(&backend, args, config),
         NargoCommand::Test(args) => test_cmd::run(args, config),
+        NargoCommand::TestPrograms(args) => test_programs_cmd::run(args, config),
         NargoCommand::Info(args) => info_cmd::run(&backend, args, config),
+        NargoCommand::Inspect(args) => inspect_cmd::run(args, config),
+        NargoCommand::Abi(args) => abi_cmd::run(args, config),
         NargoCommand::CodegenVerifier(args) => codegen_verifier_cmd::run(&backend, args, config),
         NargoCommand::Backend(args) => backend_cmd::run(args),
         NargoCommand::Lsp(args) => lsp_cmd::run(args, config),
@@ -128,6 +198,36 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
     Ok(())
 }
 
+/// Installs the global tracing subscriber.
+///
+/// A `directive` requested on the command line takes precedence over the `NOIR_LOG` (or, when logging to
+/// `NARGO_LOG_DIR`, the `RUST_LOG`) environment variable.
+#[cfg(not(feature = "codegen-docs"))]
+fn setup_tracing(directive: Option<&str>) {
+    if let Ok(log_dir) = std::env::var("NARGO_LOG_DIR") {
+        let debug_file = rolling::daily(log_dir, "nargo-log");
+        let filter = directive.map_or_else(EnvFilter::from_default_env, EnvFilter::new);
+        tracing_subscriber::fmt()
+            .with_span_events(FmtSpan::ENTER | FmtSpan::CLOSE)
+            .with_writer(debug_file)
+            .with_ansi(false)
+            .with_env_filter(filter)
+            .init();
+    } else {
+        let filter = directive.map(EnvFilter::new).unwrap_or_else(|| {
+            EnvFilter::try_from_env("NOIR_LOG")
+                .unwrap_or_else(|_| EnvFilter::new(DEFAULT_LOG_DIRECTIVE))
+        });
+        // Logs are written to stderr so that they can be silenced without affecting command results.
+        tracing_subscriber::fmt()
+            .with_span_events(FmtSpan::ENTER | FmtSpan::CLOSE)
+            .with_writer(std::io::stderr)
+            .with_ansi(true)
+            .with_env_filter(filter)
+            .init();
+    }
+}
+
 #[cfg(feature = "codegen-docs")]
 pub(crate) fn start_cli() -> eyre::Result<()> {
     let markdown: String = clap_markdown::help_markdown::<NargoCli>();
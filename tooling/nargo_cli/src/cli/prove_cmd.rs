@@ -1,3 +1,4 @@
+use backend_interface::BackendCache;
 use clap::Args;
 use nargo::constants::{PROVER_INPUT_FILE, VERIFIER_INPUT_FILE};
 use nargo::ops::{compile_program, report_errors};
@@ -6,9 +7,7 @@ use nargo::workspace::Workspace;
 use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::input_parser::Format;
-use noirc_driver::{
-    file_manager_with_stdlib, CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING,
-};
+use noirc_driver::{file_manager_with_stdlib, CompileOptions, CompiledProgram};
 use noirc_frontend::graph::CrateName;
 
 use super::fs::{
@@ -19,6 +18,9 @@ use super::NargoConfig;
 use crate::{backends::Backend, cli::execute_cmd::execute_program, errors::CliError};
 
 /// Create proof for this program. The proof is returned as a hex encoded string.
+///
+/// Proofs are cached in the target directory and reused while the circuit, inputs and backend are unchanged.
+/// Pass `--no-backend-cache` to always invoke the backend.
 #[derive(Debug, Clone, Args)]
 #[clap(visible_alias = "p")]
 pub(crate) struct ProveCommand {
@@ -27,9 +29,13 @@ pub(crate) struct ProveCommand {
     prover_name: String,
 
     /// The name of the toml file which contains the inputs for the verifier
-    #[clap(long, short, default_value = VERIFIER_INPUT_FILE)]
+    #[clap(long, default_value = VERIFIER_INPUT_FILE)]
     verifier_name: String,
 
+    /// Reject input files containing inputs which don't correspond to any parameter of the program
+    #[clap(long)]
+    strict_inputs: bool,
+
     /// Verify proof after proving
     #[arg(long)]
     verify: bool,
@@ -42,6 +48,10 @@ pub(crate) struct ProveCommand {
     #[clap(long, conflicts_with = "package")]
     workspace: bool,
 
+    /// Always invoke the backend instead of reusing cached results
+    #[clap(long)]
+    no_backend_cache: bool,
+
     #[clap(flatten)]
     compile_options: CompileOptions,
 
@@ -59,16 +69,15 @@ pub(crate) fn run(
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
     let selection = args.package.map_or(default_selection, PackageSelection::Selected);
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
-    )?;
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
 
     let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
     insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
     let parsed_files = parse_all(&workspace_file_manager);
 
+    let cache =
+        (!args.no_backend_cache).then(|| BackendCache::new(workspace.cache_directory_path()));
+
     let binary_packages = workspace.into_iter().filter(|package| package.is_binary());
     for package in binary_packages {
         let compilation_result = compile_program(
@@ -91,11 +100,13 @@ pub(crate) fn run(
 
         prove_package(
             backend,
+            cache.as_ref(),
             &workspace,
             package,
             compiled_program,
             &args.prover_name,
             &args.verifier_name,
+            args.strict_inputs,
             args.verify,
             args.oracle_resolver.as_deref(),
         )?;
@@ -107,17 +118,24 @@ pub(crate) fn run(
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn prove_package(
     backend: &Backend,
+    cache: Option<&BackendCache>,
     workspace: &Workspace,
     package: &Package,
     compiled_program: CompiledProgram,
     prover_name: &str,
     verifier_name: &str,
+    strict_inputs: bool,
     check_proof: bool,
     foreign_call_resolver_url: Option<&str>,
 ) -> Result<(), CliError> {
     // Parse the initial witness values from Prover.toml
-    let (inputs_map, _) =
-        read_inputs_from_file(&package.root_dir, prover_name, Format::Toml, &compiled_program.abi)?;
+    let (inputs_map, _) = read_inputs_from_file(
+        &package.root_dir,
+        prover_name,
+        Format::Toml,
+        &compiled_program.abi,
+        strict_inputs,
+    )?;
 
     let witness_stack = execute_program(&compiled_program, &inputs_map, foreign_call_resolver_url)?;
 
@@ -137,7 +155,16 @@ pub(crate) fn prove_package(
         Format::Toml,
     )?;
 
-    let proof = backend.prove(&compiled_program.program, witness_stack)?;
+    let proof = match cache {
+        Some(cache) => {
+            let proof = backend.prove_cached(&compiled_program.program, witness_stack, cache)?;
+            if proof.cache_hit {
+                println!("[{}] Proof unchanged (cached)", package.name);
+            }
+            proof.value
+        }
+        None => backend.prove(&compiled_program.program, witness_stack)?,
+    };
 
     if check_proof {
         let public_inputs = public_abi.encode(&public_inputs, return_value)?;
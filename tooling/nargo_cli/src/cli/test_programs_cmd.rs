@@ -0,0 +1,288 @@
+use std::path::{Path, PathBuf};
+
+use acvm::acir::circuit::{Opcode, Program};
+use acvm::acir::native_types::WitnessStack;
+use bn254_blackbox_solver::Bn254BlackBoxSolver;
+use clap::Args;
+use nargo::constants::PROVER_INPUT_FILE;
+use nargo::errors::try_to_diagnose_runtime_error;
+use nargo::ops::{compile_program, DefaultForeignCallExecutor};
+use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_abi::input_parser::Format;
+use noirc_driver::{file_manager_with_stdlib, CompileOptions, CompiledProgram};
+use prettytable::{table, Cell, Row};
+use rayon::prelude::*;
+use serde::Deserialize;
+
+use super::fs::inputs::read_inputs_from_file;
+use super::NargoConfig;
+use crate::errors::CliError;
+
+/// The file in each test program declaring the outcome it is expected to have.
+const EXPECTATION_FILE: &str = "Expect.toml";
+
+/// Compiles and executes every test program in a directory, checking that each has the outcome
+/// declared in its `Expect.toml`
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TestProgramsCommand {
+    /// The directory containing a package for each test program
+    corpus_dir: PathBuf,
+
+    #[clap(flatten)]
+    compile_options: CompileOptions,
+}
+
+/// The contents of a test program's `Expect.toml`, for example:
+///
+/// ```toml
+/// expect = "constraint_failure"
+/// errors = ["attempt to add with overflow"]
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expectation {
+    expect: Outcome,
+    /// Substrings which must each appear in one of the errors reported for the program
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    /// The program compiles and its inputs in `Prover.toml` satisfy all of its constraints
+    Pass,
+    /// The program is rejected by the compiler
+    CompileError,
+    /// The program compiles but its inputs in `Prover.toml` fail to satisfy its constraints
+    ConstraintFailure,
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::CompileError => write!(f, "compile_error"),
+            Outcome::ConstraintFailure => write!(f, "constraint_failure"),
+        }
+    }
+}
+
+struct TestProgramReport {
+    name: String,
+    expected: Option<Outcome>,
+    actual: Option<Outcome>,
+    /// Why the program didn't have its expected outcome, if it didn't
+    failure: Option<String>,
+}
+
+pub(crate) fn run(args: TestProgramsCommand, config: NargoConfig) -> Result<(), CliError> {
+    let corpus_dir = config.program_dir.join(&args.corpus_dir);
+    let entries = std::fs::read_dir(&corpus_dir).map_err(|error| {
+        CliError::Generic(format!("Could not read {}: {error}", corpus_dir.display()))
+    })?;
+    let mut program_dirs: Vec<_> =
+        entries.flatten().map(|entry| entry.path()).filter(|path| path.is_dir()).collect();
+    program_dirs.sort();
+
+    let compiler_version = config.compiler_version();
+    let reports: Vec<_> = program_dirs
+        .par_iter()
+        .map(|program_dir| {
+            run_test_program(program_dir, compiler_version.as_deref(), &args.compile_options)
+        })
+        .collect();
+
+    let mut results_table = table!([Fm->"Program", Fm->"Expected", Fm->"Actual", Fm->"Result"]);
+    for report in &reports {
+        let display = |outcome: Option<Outcome>| outcome.map_or("-".into(), |o| o.to_string());
+        let (result, style) = if report.failure.is_some() { ("FAIL", "Fr") } else { ("ok", "Fg") };
+        results_table.add_row(Row::new(vec![
+            Cell::new(&report.name),
+            Cell::new(&display(report.expected)),
+            Cell::new(&display(report.actual)),
+            Cell::new(result).style_spec(style),
+        ]));
+    }
+    results_table.printstd();
+
+    let failures: Vec<_> = reports
+        .iter()
+        .filter_map(|report| Some((&report.name, report.failure.as_ref()?)))
+        .collect();
+    for (name, failure) in &failures {
+        eprintln!("[{name}] {failure}");
+    }
+
+    if failures.is_empty() {
+        println!("{} test programs had their expected outcome", reports.len());
+        Ok(())
+    } else {
+        Err(CliError::Generic(format!(
+            "{} of {} test programs did not have their expected outcome",
+            failures.len(),
+            reports.len()
+        )))
+    }
+}
+
+fn run_test_program(
+    program_dir: &Path,
+    compiler_version: Option<&str>,
+    compile_options: &CompileOptions,
+) -> TestProgramReport {
+    let name = program_dir.file_name().unwrap_or_default().to_string_lossy().into_owned();
+    let mut report = TestProgramReport { name, expected: None, actual: None, failure: None };
+
+    let expectation = match read_expectation(program_dir) {
+        Ok(expectation) => expectation,
+        Err(error) => {
+            report.failure = Some(error);
+            return report;
+        }
+    };
+    report.expected = Some(expectation.expect);
+
+    let (actual, errors) = match compile_and_execute(program_dir, compiler_version, compile_options)
+    {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            report.failure = Some(error.to_string());
+            return report;
+        }
+    };
+    report.actual = Some(actual);
+
+    report.failure = if actual != expectation.expect {
+        Some(format!("Expected {} but got {actual}\n{}", expectation.expect, errors.join("\n")))
+    } else {
+        expectation
+            .errors
+            .iter()
+            .find(|expected| !errors.iter().any(|error| error.contains(expected.as_str())))
+            .map(|missing| format!("No error contains `{missing}`\n{}", errors.join("\n")))
+    };
+    report
+}
+
+fn read_expectation(program_dir: &Path) -> Result<Expectation, String> {
+    let path = program_dir.join(EXPECTATION_FILE);
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|error| format!("Could not read {}: {error}", path.display()))?;
+    toml::from_str(&contents).map_err(|error| format!("Invalid {}: {error}", path.display()))
+}
+
+/// Compiles and executes the program in `program_dir` with the inputs in its `Prover.toml`,
+/// returning the outcome along with any errors reported for it.
+fn compile_and_execute(
+    program_dir: &Path,
+    compiler_version: Option<&str>,
+    compile_options: &CompileOptions,
+) -> Result<(Outcome, Vec<String>), CliError> {
+    let toml_path = get_package_manifest(program_dir)?;
+    let workspace = resolve_workspace_from_toml(
+        &toml_path,
+        PackageSelection::DefaultOrAll,
+        compiler_version.map(str::to_owned),
+    )?;
+
+    let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
+    let parsed_files = parse_all(&workspace_file_manager);
+
+    let Some(package) = workspace.into_iter().find(|package| package.is_binary()) else {
+        return Err(CliError::Generic("Test programs must be binary packages".into()));
+    };
+
+    let compiled_program = match compile_program(
+        &workspace_file_manager,
+        &parsed_files,
+        package,
+        compile_options,
+        None,
+    ) {
+        Ok((compiled_program, _warnings)) => compiled_program,
+        Err(errors) => {
+            let errors = errors
+                .into_iter()
+                .filter(|error| compile_options.deny_warnings || error.diagnostic.is_error())
+                .map(|error| error.diagnostic.to_string())
+                .collect();
+            return Ok((Outcome::CompileError, errors));
+        }
+    };
+    let compiled_program =
+        nargo::ops::transform_program(compiled_program, compile_options.expression_width);
+
+    let (inputs_map, expected_return) = read_inputs_from_file(
+        &package.root_dir,
+        PROVER_INPUT_FILE,
+        Format::Toml,
+        &compiled_program.abi,
+        true,
+    )?;
+    let initial_witness = compiled_program.abi.encode(&inputs_map, None)?;
+
+    let execution_result = nargo::ops::execute_program(
+        &compiled_program.program,
+        initial_witness,
+        &Bn254BlackBoxSolver::new(),
+        &mut DefaultForeignCallExecutor::new(false, None),
+    );
+    let witness_stack = match execution_result {
+        Ok(witness_stack) => witness_stack,
+        Err(error) => {
+            return Ok((Outcome::ConstraintFailure, execution_errors(&compiled_program, error)))
+        }
+    };
+
+    if expected_return.is_some() {
+        let main_witness =
+            &witness_stack.peek().expect("Should have at least one witness on the stack").witness;
+        let (_, return_value) = compiled_program.abi.clone().public_abi().decode(main_witness)?;
+        if return_value != expected_return {
+            return Err(CliError::Generic(format!(
+                "Returned {return_value:?} but {PROVER_INPUT_FILE}.toml expects {expected_return:?}"
+            )));
+        }
+    }
+
+    match check_constraints(&compiled_program.program, witness_stack) {
+        Ok(()) => Ok((Outcome::Pass, Vec::new())),
+        Err(error) => Ok((Outcome::ConstraintFailure, vec![error])),
+    }
+}
+
+/// Returns the messages describing a failed execution, including any user defined failure
+/// message and the diagnostic pointing at the failing source code.
+fn execution_errors(program: &CompiledProgram, error: nargo::NargoError) -> Vec<String> {
+    let mut errors = vec![error.to_string()];
+    errors.extend(error.user_defined_failure_message(&program.abi.error_types));
+    if let Some(diagnostic) = try_to_diagnose_runtime_error(&error, &program.abi, &program.debug) {
+        errors.push(diagnostic.diagnostic.to_string());
+    }
+    errors
+}
+
+/// Checks the solved witnesses against the arithmetic constraints of each circuit,
+/// independently of the checks made by the ACVM while solving them.
+fn check_constraints(program: &Program, mut witness_stack: WitnessStack) -> Result<(), String> {
+    while let Some(item) = witness_stack.pop() {
+        let circuit = &program.functions[item.index as usize];
+        for (opcode_index, opcode) in circuit.opcodes.iter().enumerate() {
+            let Opcode::AssertZero(expression) = opcode else {
+                continue;
+            };
+            let value = acvm::pwg::get_value(expression, &item.witness)
+                .map_err(|error| format!("Could not check opcode {opcode_index}: {error}"))?;
+            if !value.is_zero() {
+                return Err(format!(
+                    "The solved witness does not satisfy opcode {opcode_index} of circuit {}",
+                    item.index
+                ));
+            }
+        }
+    }
+    Ok(())
+}
@@ -10,9 +10,7 @@ use nargo::workspace::Workspace;
 use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::input_parser::Format;
-use noirc_driver::{
-    file_manager_with_stdlib, CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING,
-};
+use noirc_driver::{file_manager_with_stdlib, CompileOptions, CompiledProgram};
 use noirc_frontend::graph::CrateName;
 
 /// Given a proof and a program, verify whether the proof is valid
@@ -20,9 +18,13 @@ use noirc_frontend::graph::CrateName;
 #[clap(visible_alias = "v")]
 pub(crate) struct VerifyCommand {
     /// The name of the toml file which contains the inputs for the verifier
-    #[clap(long, short, default_value = VERIFIER_INPUT_FILE)]
+    #[clap(long, default_value = VERIFIER_INPUT_FILE)]
     verifier_name: String,
 
+    /// Reject input files containing inputs which don't correspond to any parameter of the program
+    #[clap(long)]
+    strict_inputs: bool,
+
     /// The name of the package verify
     #[clap(long, conflicts_with = "workspace")]
     package: Option<CrateName>,
@@ -44,11 +46,7 @@ pub(crate) fn run(
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
     let selection = args.package.map_or(default_selection, PackageSelection::Selected);
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
-    )?;
+    let workspace = resolve_workspace_from_toml(&toml_path, selection, config.compiler_version())?;
 
     let mut workspace_file_manager = file_manager_with_stdlib(&workspace.root_dir);
     insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
@@ -74,7 +72,14 @@ pub(crate) fn run(
         let compiled_program =
             nargo::ops::transform_program(compiled_program, args.compile_options.expression_width);
 
-        verify_package(backend, &workspace, package, compiled_program, &args.verifier_name)?;
+        verify_package(
+            backend,
+            &workspace,
+            package,
+            compiled_program,
+            &args.verifier_name,
+            args.strict_inputs,
+        )?;
     }
 
     Ok(())
@@ -86,11 +91,17 @@ fn verify_package(
     package: &Package,
     compiled_program: CompiledProgram,
     verifier_name: &str,
+    strict_inputs: bool,
 ) -> Result<(), CliError> {
     // Load public inputs (if any) from `verifier_name`.
     let public_abi = compiled_program.abi.public_abi();
-    let (public_inputs_map, return_value) =
-        read_inputs_from_file(&package.root_dir, verifier_name, Format::Toml, &public_abi)?;
+    let (public_inputs_map, return_value) = read_inputs_from_file(
+        &package.root_dir,
+        verifier_name,
+        Format::Toml,
+        &public_abi,
+        strict_inputs,
+    )?;
 
     let public_inputs = public_abi.encode(&public_inputs_map, return_value)?;
 
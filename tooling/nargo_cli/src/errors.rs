@@ -1,7 +1,7 @@
 use acvm::acir::native_types::WitnessStackError;
 use hex::FromHexError;
 use nargo::{errors::CompileError, NargoError};
-use nargo_toml::ManifestError;
+use nargo_toml::{ManifestError, SemverError};
 use noir_debugger::errors::DapError;
 use noirc_abi::errors::{AbiError, InputParserError};
 use std::path::PathBuf;
@@ -22,6 +22,11 @@ pub(crate) enum FilesystemError {
     #[error(transparent)]
     InputParserError(#[from] InputParserError),
 
+    #[error("Error: invalid input file {}: {1}", .0.display())]
+    InvalidInputFile(PathBuf, InputParserError),
+    #[error("Error: unknown inputs in {}: {}", .0.display(), .1.join(", "))]
+    UnknownInputs(PathBuf, Vec<String>),
+
     /// WitnessStack serialization error
     #[error(transparent)]
     WitnessStackSerialization(#[from] WitnessStackError),
@@ -63,7 +68,16 @@ pub(crate) enum CliError {
 
     /// Error from Manifest
     #[error(transparent)]
-    ManifestError(#[from] ManifestError),
+    ManifestError(ManifestError),
+
+    #[error("Package `{package}` requires compiler version {required} but this is version {current}\nEither install a compatible version of nargo, update the `compiler_version` field in the package's Nargo.toml or pass `--ignore-version-check` to compile anyway")]
+    IncompatibleCompilerVersion { package: String, required: String, current: String },
+
+    #[error("{} was compiled by nargo {artifact_version}, which is newer than this version {current}\nInstall a newer version of nargo to use this artifact", .path.display())]
+    IncompatibleArtifactVersion { path: PathBuf, artifact_version: String, current: String },
+
+    #[error("{} was compiled by nargo {artifact_version}, whose bytecode cannot be read by this version {current}\nRecompile the program with this version of nargo", .path.display())]
+    OutdatedArtifactVersion { path: PathBuf, artifact_version: String, current: String },
 
     /// Error from the compilation pipeline
     #[error(transparent)]
@@ -78,6 +92,23 @@ pub(crate) enum CliError {
     BackendCommunicationError(#[from] backend_interface::BackendError),
 }
 
+impl From<ManifestError> for CliError {
+    fn from(error: ManifestError) -> Self {
+        match error {
+            ManifestError::SemverError(SemverError::IncompatibleVersion {
+                package_name,
+                required_compiler_version,
+                compiler_version_found,
+            }) => CliError::IncompatibleCompilerVersion {
+                package: package_name.to_string(),
+                required: required_compiler_version,
+                current: compiler_version_found,
+            },
+            error => CliError::ManifestError(error),
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum BackendError {
     #[error("No backend is installed with the name {0}")]
@@ -11,33 +11,17 @@ mod backends;
 mod cli;
 mod errors;
 
-use std::env;
-
 use color_eyre::config::HookBuilder;
 
-use tracing_appender::rolling;
-use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+// Only used to set up tracing, which the docs generator doesn't do.
+#[cfg(feature = "codegen-docs")]
+use tracing_appender as _;
+#[cfg(feature = "codegen-docs")]
+use tracing_subscriber as _;
 
 const PANIC_MESSAGE: &str = "This is a bug. We may have already fixed this in newer versions of Nargo so try searching for similar issues at https://github.com/noir-lang/noir/issues/.\nIf there isn't an open issue for this bug, consider opening one at https://github.com/noir-lang/noir/issues/new?labels=bug&template=bug_report.yml";
 
 fn main() {
-    // Setup tracing
-    if let Ok(log_dir) = env::var("NARGO_LOG_DIR") {
-        let debug_file = rolling::daily(log_dir, "nargo-log");
-        tracing_subscriber::fmt()
-            .with_span_events(FmtSpan::ENTER | FmtSpan::CLOSE)
-            .with_writer(debug_file)
-            .with_ansi(false)
-            .with_env_filter(EnvFilter::from_default_env())
-            .init();
-    } else {
-        tracing_subscriber::fmt()
-            .with_span_events(FmtSpan::ENTER | FmtSpan::CLOSE)
-            .with_ansi(true)
-            .with_env_filter(EnvFilter::from_env("NOIR_LOG"))
-            .init();
-    }
-
     // Register a panic hook to display more readable panic messages to end-users
     let (panic_hook, _) =
         HookBuilder::default().display_env_section(false).panic_section(PANIC_MESSAGE).into_hooks();
@@ -0,0 +1,80 @@
+use noirc_driver::CompiledProgram;
+
+mod common;
+
+/// Compares each kind of composite value with `assert_eq`.
+const COMPOSITE_ASSERTIONS: &str = "
+    struct Point {
+        x: Field,
+        y: Field,
+    }
+
+    impl Eq for Point {
+        fn eq(self, other: Point) -> bool {
+            (self.x == other.x) & (self.y == other.y)
+        }
+    }
+
+    fn main(array: [Field; 2], tuple: (Field, u8), point: Point) {
+        assert_eq(array, [1, 2], \"arrays differ\");
+        assert_eq(tuple, (3, 4), \"tuples differ\");
+        assert_eq(point, Point { x: 5, y: 6 }, \"points differ\");
+    }
+";
+
+const VALID_INPUTS: [&str; 3] =
+    [r#"array = ["1", "2"]"#, r#"tuple = ["3", "4"]"#, r#"point = { x = "5", y = "6" }"#];
+
+/// Executes `program` with the inputs given in `prover_toml`, returning the failure message if
+/// an assertion fails.
+fn failure_message(program: &CompiledProgram, prover_toml: &str) -> Option<String> {
+    let error = common::execute(program, prover_toml).err()?;
+    let message = error.user_defined_failure_message(&program.abi.error_types);
+    Some(message.unwrap_or_else(|| panic!("the failure should have a message: {error:?}")))
+}
+
+/// Replaces the input at `index` of the valid inputs with `input`.
+fn inputs_with(index: usize, input: &str) -> String {
+    let mut inputs = VALID_INPUTS;
+    inputs[index] = input;
+    inputs.join("\n")
+}
+
+#[test]
+fn assert_eq_compares_composite_values_element_wise() {
+    let program = common::compile(COMPOSITE_ASSERTIONS);
+
+    assert_eq!(failure_message(&program, &VALID_INPUTS.join("\n")), None);
+
+    // Values which only differ in their last element still fail, with the assertion's message.
+    let cases = [
+        (0, r#"array = ["1", "3"]"#, "arrays differ"),
+        (1, r#"tuple = ["3", "5"]"#, "tuples differ"),
+        (2, r#"point = { x = "5", y = "7" }"#, "points differ"),
+    ];
+    for (index, input, expected_message) in cases {
+        let message = failure_message(&program, &inputs_with(index, input));
+        assert_eq!(message.as_deref(), Some(expected_message), "{input}");
+    }
+}
+
+#[test]
+fn assert_eq_on_arrays_of_different_lengths_is_a_type_error() {
+    let errors = common::try_compile(
+        "
+        fn main(x: Field) {
+            assert_eq([x, 2], [x, 2, 3], \"arrays differ\");
+        }
+        ",
+    )
+    .err()
+    .expect("comparing arrays of different lengths should not compile");
+
+    assert!(
+        errors.iter().any(|error| {
+            let message = &error.diagnostic.message;
+            message.contains("[Field; 2]") && message.contains("[Field; 3]")
+        }),
+        "{errors:?}"
+    );
+}
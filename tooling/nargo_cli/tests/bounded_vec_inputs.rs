@@ -0,0 +1,49 @@
+use acvm::FieldElement;
+use nargo::NargoError;
+use noirc_abi::input_parser::InputValue;
+use noirc_driver::CompiledProgram;
+
+mod common;
+
+/// Sums however many values the prover supplies, up to 5.
+const SUM_VALUES: &str = "
+    fn main(values: BoundedVec<Field, 5>) -> pub Field {
+        let mut sum = 0;
+        for i in 0..5 {
+            if i < values.len() {
+                sum += values.get_unchecked(i);
+            }
+        }
+        sum
+    }
+";
+
+/// Executes `program` with the inputs given in `prover_toml`, returning the value returned by `main`.
+fn execute(program: &CompiledProgram, prover_toml: &str) -> Result<InputValue, NargoError> {
+    common::execute(program, prover_toml).map(|value| value.expect("main returns a value"))
+}
+
+fn field(value: u128) -> InputValue {
+    InputValue::Field(FieldElement::from(value))
+}
+
+#[test]
+fn bounded_vec_inputs_of_different_lengths_run_on_the_same_circuit() {
+    let program = common::compile(SUM_VALUES);
+
+    assert_eq!(execute(&program, r#"values = ["1", "2"]"#).unwrap(), field(3));
+    assert_eq!(execute(&program, r#"values = ["1", "2", "3", "4", "5"]"#).unwrap(), field(15));
+    assert_eq!(execute(&program, "values = []").unwrap(), field(0));
+}
+
+#[test]
+fn bounded_vec_input_length_is_constrained() {
+    let program = common::compile(SUM_VALUES);
+
+    // Only a `len` of at most the maximum length of the `BoundedVec` is accepted.
+    let valid = r#"values = { storage = ["1", "2", "3", "4", "5"], len = "5" }"#;
+    assert_eq!(execute(&program, valid).unwrap(), field(15));
+
+    let too_long = r#"values = { storage = ["1", "2", "3", "4", "5"], len = "6" }"#;
+    assert!(execute(&program, too_long).is_err());
+}
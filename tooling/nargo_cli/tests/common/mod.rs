@@ -0,0 +1,62 @@
+//! Helpers shared by the integration tests which compile and execute programs in process.
+//!
+//! Each integration test is compiled as a separate crate using only some of these helpers.
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use bn254_blackbox_solver::Bn254BlackBoxSolver;
+use nargo::{
+    ops::{execute_program, DefaultForeignCallExecutor},
+    NargoError,
+};
+use noirc_abi::input_parser::{Format, InputValue};
+use noirc_driver::{
+    compile_main, file_manager_with_stdlib, prepare_crate, CompilationResult, CompileOptions,
+    CompiledProgram,
+};
+use noirc_frontend::hir::{def_map::parse_file, Context};
+
+pub(crate) fn try_compile(source: &str) -> CompilationResult<CompiledProgram> {
+    let root = Path::new("");
+    let file_name = Path::new("main.nr");
+    let mut file_manager = file_manager_with_stdlib(root);
+    file_manager
+        .add_file_with_source(file_name, source.to_owned())
+        .expect("Adding source buffer to file manager should never fail");
+    let parsed_files = file_manager
+        .as_file_map()
+        .all_file_ids()
+        .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+        .collect();
+
+    let mut context = Context::new(file_manager, parsed_files);
+    let crate_id = prepare_crate(&mut context, file_name);
+    compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+}
+
+pub(crate) fn compile(source: &str) -> CompiledProgram {
+    let (program, _) = try_compile(source)
+        .unwrap_or_else(|errors| panic!("program should compile: {errors:?}\n{source}"));
+    program
+}
+
+/// Executes `program` with the inputs given in `prover_toml`, returning the value returned by `main`.
+pub(crate) fn execute(
+    program: &CompiledProgram,
+    prover_toml: &str,
+) -> Result<Option<InputValue>, NargoError> {
+    let inputs = Format::Toml.parse(prover_toml, &program.abi).expect("inputs should parse");
+    let initial_witness = program.abi.encode(&inputs, None).expect("inputs should encode");
+
+    let witness_stack = execute_program(
+        &program.program,
+        initial_witness,
+        &Bn254BlackBoxSolver::new(),
+        &mut DefaultForeignCallExecutor::new(false, None),
+    )?;
+    let main_witness =
+        &witness_stack.peek().expect("the witness stack should not be empty").witness;
+    let (_, return_value) = program.abi.decode(main_witness).expect("witness should decode");
+    Ok(return_value)
+}
@@ -0,0 +1,62 @@
+//! Checks that `nargo fuzz` finds the input a circuit fails for and writes it, minimized, to a toml file.
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+use assert_fs::prelude::{FileWriteStr, PathChild};
+
+#[test]
+fn fuzzing_finds_and_minimizes_failing_input() {
+    let test_dir = assert_fs::TempDir::new().unwrap();
+    let project_dir = test_dir.child("fuzz_assert");
+
+    project_dir
+        .child("Nargo.toml")
+        .write_str(
+            r#"[package]
+name = "fuzz_assert"
+type = "bin"
+authors = [""]
+
+[dependencies]
+
+[fuzz]
+x = { min = 0, max = 100 }
+"#,
+        )
+        .unwrap();
+    project_dir
+        .child("src")
+        .child("main.nr")
+        .write_str(
+            r#"fn main(x: u8, y: Field) {
+    assert(x != 42);
+    assert(y == y);
+}
+"#,
+        )
+        .unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path());
+    cmd.arg("fuzz").arg("--runs").arg("2000").arg("--seed").arg("1");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("executions failed"))
+        .stdout(predicate::str::contains("Prover-fuzz.toml"))
+        .stderr(predicate::str::contains("Fuzzing found failing inputs for fuzz_assert"));
+
+    // `x` stays at 42 as halving it makes the circuit pass, while `y` is shrunk down to zero.
+    let failing_input = std::fs::read_to_string(project_dir.child("Prover-fuzz.toml")).unwrap();
+    let field = |value: &str| format!("\"0x{value:0>64}\"");
+    assert!(failing_input.contains(&format!("x = {}", field("2a"))), "{failing_input}");
+    assert!(failing_input.contains(&format!("y = {}", field("0"))), "{failing_input}");
+
+    // The failing input reproduces the failure with `nargo execute`.
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path());
+    cmd.arg("execute").arg("--prover-name").arg("Prover-fuzz");
+    cmd.assert().failure();
+}
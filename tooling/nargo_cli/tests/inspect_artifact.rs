@@ -0,0 +1,112 @@
+//! Tests that `nargo inspect` works on a bare program artifact, outside of any package.
+
+use assert_cmd::prelude::*;
+use assert_fs::prelude::{FileWriteStr, PathChild};
+use assert_fs::TempDir;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::process::Command;
+
+/// The artifact of the program below, compiled with `nargo compile --full-debug`:
+///
+/// ```noir
+/// fn main(x: Field, y: pub Field) {
+///     assert(x * y == 6);
+/// }
+/// ```
+const ARTIFACT: &str = include_str!("fixtures/inspected.json");
+
+fn fixture() -> Value {
+    serde_json::from_str(ARTIFACT).unwrap()
+}
+
+/// Runs `nargo inspect` on `artifact` from a directory containing nothing but the artifact.
+fn inspect(artifact: &Value, args: &[&str]) -> assert_cmd::assert::Assert {
+    let inspect_dir = TempDir::new().unwrap();
+    inspect_dir.child("artifact.json").write_str(&artifact.to_string()).unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.current_dir(&inspect_dir).arg("inspect").arg("artifact.json").args(args);
+    cmd.assert()
+}
+
+#[test]
+fn lists_opcodes_of_an_artifact_without_a_package() {
+    let artifact = fixture();
+
+    inspect(&artifact, &[])
+        .success()
+        .stdout(predicate::str::contains("Parameters: x (private), y (public)"))
+        .stdout(predicate::str::contains("ACIR function `main`"))
+        .stdout(predicate::str::contains("src/main.nr:2:"));
+}
+
+#[test]
+fn shows_where_an_opcode_of_an_artifact_came_from() {
+    let artifact = fixture();
+
+    inspect(&artifact, &["--opcode", "0"])
+        .success()
+        .stdout(predicate::str::contains("ACIR opcode 0 of `main`"))
+        .stdout(predicate::str::contains("SSA instruction in `main"))
+        .stdout(predicate::str::contains("Source: "));
+}
+
+#[test]
+fn inspects_artifacts_without_debug_info() {
+    let mut artifact = fixture();
+    let fields = artifact.as_object_mut().unwrap();
+    fields.remove("debug_symbols");
+    fields.remove("file_map");
+
+    inspect(&artifact, &[])
+        .success()
+        .stdout(predicate::str::contains("The debug info is missing"))
+        .stdout(predicate::str::contains("ACIR function `main`"))
+        .stdout(predicate::str::contains("src/main.nr").not());
+
+    inspect(&artifact, &["--opcode", "0"])
+        .success()
+        .stdout(predicate::str::contains("ACIR opcode 0 of `main`"))
+        .stdout(predicate::str::contains("the opcode's origin is unknown"));
+}
+
+#[test]
+fn inspects_artifacts_without_an_abi() {
+    let mut artifact = fixture();
+    artifact.as_object_mut().unwrap().remove("abi");
+
+    inspect(&artifact, &[])
+        .success()
+        .stdout(predicate::str::contains("The ABI is missing"))
+        .stdout(predicate::str::contains("ACIR function `main`"))
+        .stdout(predicate::str::contains("   0: "));
+}
+
+#[test]
+fn rejects_artifacts_from_a_newer_version() {
+    let mut artifact = fixture();
+    artifact["noir_version"] =
+        Value::String("999.0.0+0000000000000000000000000000000000000000".into());
+
+    inspect(&artifact, &[])
+        .failure()
+        .stderr(predicate::str::contains("which is newer than this version"));
+}
+
+#[test]
+fn rejects_artifacts_in_an_older_bytecode_format() {
+    // The `pedersen_circuit` from acir's serialization tests, as it was encoded before black box
+    // function inputs could be constants.
+    const OLD_BYTECODE: &str =
+        "H4sIAAAAAAAA/11KSQoAAAS0Hfz/wUIoTE2zIhQkiO1T9WVrT0Fe/dbZ/+/AASt8te5xAAAA";
+
+    let mut artifact = fixture();
+    artifact["bytecode"] = Value::String(OLD_BYTECODE.into());
+    artifact["noir_version"] =
+        Value::String("0.30.0+0000000000000000000000000000000000000000".into());
+
+    inspect(&artifact, &[])
+        .failure()
+        .stderr(predicate::str::contains("whose bytecode cannot be read by this version"));
+}
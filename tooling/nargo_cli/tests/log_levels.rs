@@ -0,0 +1,75 @@
+//! This integration test checks that the global `--quiet` and `--verbose` flags control what Nargo logs,
+//! including the output of the backend, without affecting the results printed by a command.
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+use assert_fs::prelude::PathChild;
+
+test_binary::build_test_binary_once!(mock_backend, "../backend_interface/test-binaries");
+
+const BACKEND_OUTPUT: &str = "mock_backend: counting gates";
+
+/// Runs `nargo info` on a fresh project against the mock backend, passing `flags` before the subcommand.
+fn nargo_info(flags: &[&str]) -> assert_cmd::assert::Assert {
+    let test_dir = assert_fs::TempDir::new().unwrap();
+    let project_name = "hello_world";
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("new").arg(test_dir.child(project_name).path());
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
+    cmd.env_remove("NOIR_LOG");
+    cmd.env_remove("NARGO_LOG_DIR");
+    cmd.arg("--program-dir").arg(test_dir.child(project_name).path());
+    cmd.args(flags);
+    // The circuit size cache is bypassed so that the backend is always run.
+    cmd.arg("info").arg("--force");
+
+    // The project must outlive the command.
+    let assert = cmd.assert().success();
+    drop(test_dir);
+    assert
+}
+
+#[test]
+fn default_level_shows_backend_output() {
+    nargo_info(&[])
+        .stdout(predicate::str::contains("hello_world"))
+        .stderr(predicate::str::contains(BACKEND_OUTPUT))
+        .stderr(predicate::str::contains("Running backend command").not());
+}
+
+#[test]
+fn quiet_silences_backend_output() {
+    nargo_info(&["--quiet"])
+        .stdout(predicate::str::contains("hello_world"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn verbose_shows_debug_logs() {
+    nargo_info(&["-v"])
+        .stdout(predicate::str::contains("hello_world"))
+        .stderr(predicate::str::contains(BACKEND_OUTPUT))
+        .stderr(predicate::str::contains("Running backend command"))
+        .stderr(predicate::str::contains("compile_no_check").not());
+}
+
+#[test]
+fn very_verbose_shows_trace_logs() {
+    nargo_info(&["-vv"])
+        .stdout(predicate::str::contains("hello_world"))
+        .stderr(predicate::str::contains("Running backend command"))
+        .stderr(predicate::str::contains("compile_no_check"));
+}
+
+#[test]
+fn quiet_and_verbose_conflict() {
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("info").arg("--quiet").arg("--verbose");
+    cmd.assert().failure().stderr(predicate::str::contains("cannot be used with"));
+}
@@ -0,0 +1,49 @@
+//! Checks that `nargo execute --profile-execution` reports solver timings for each kind of opcode.
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+use assert_fs::prelude::{FileWriteStr, PathChild};
+
+#[test]
+fn profile_execution_lists_black_box_functions() {
+    let test_dir = assert_fs::TempDir::new().unwrap();
+    let project_dir = test_dir.child("sha256_profile");
+
+    project_dir
+        .child("Nargo.toml")
+        .write_str(
+            r#"[package]
+name = "sha256_profile"
+type = "bin"
+authors = [""]
+
+[dependencies]
+"#,
+        )
+        .unwrap();
+    project_dir
+        .child("src")
+        .child("main.nr")
+        .write_str(
+            r#"use dep::std;
+
+fn main(x: u8) -> pub [u8; 32] {
+    std::hash::sha256([x])
+}
+"#,
+        )
+        .unwrap();
+    project_dir.child("Prover.toml").write_str("x = 1").unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path());
+    cmd.arg("execute").arg("--profile-execution");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Opcode Kind"))
+        .stdout(predicate::str::contains("sha256"))
+        .stdout(predicate::str::contains("src/main.nr"));
+}
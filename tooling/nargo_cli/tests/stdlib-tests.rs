@@ -24,6 +24,7 @@ fn run_stdlib_tests(use_elaborator: bool) {
         entry_path: PathBuf::from("main.nr"),
         name: "dummy".parse().unwrap(),
         dependencies: BTreeMap::new(),
+        fuzz_ranges: BTreeMap::new(),
     };
 
     let (mut context, dummy_crate_id) =
@@ -0,0 +1,40 @@
+//! This integration test runs `nargo test-programs` over the corpus in `test_programs/corpus`,
+//! checking that every program there compiles and executes with the outcome declared in its `Expect.toml`.
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn test_program_corpus() {
+    let corpus_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../test_programs/corpus");
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("test-programs").arg(corpus_dir);
+
+    cmd.assert().success().stdout(predicate::str::contains("had their expected outcome"));
+}
+
+#[test]
+fn test_program_with_unexpected_outcome_fails() {
+    let corpus_dir = assert_fs::TempDir::new().unwrap();
+    let program_dir = corpus_dir.path().join("unexpected_pass");
+    std::fs::create_dir_all(program_dir.join("src")).unwrap();
+    std::fs::write(
+        program_dir.join("Nargo.toml"),
+        "[package]\nname = \"unexpected_pass\"\ntype = \"bin\"\nauthors = [\"\"]\n\n[dependencies]\n",
+    )
+    .unwrap();
+    std::fs::write(program_dir.join("src/main.nr"), "fn main(x: Field) { assert(x == 1); }\n")
+        .unwrap();
+    std::fs::write(program_dir.join("Prover.toml"), "x = \"1\"\n").unwrap();
+    std::fs::write(program_dir.join("Expect.toml"), "expect = \"constraint_failure\"\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("test-programs").arg(corpus_dir.path());
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Expected constraint_failure but got pass"));
+}
@@ -111,7 +111,7 @@ pub(crate) fn rewrite(
             NewlineMode::Normal,
         ),
         ExpressionKind::Literal(literal) => match literal {
-            Literal::Integer(_, _)
+            Literal::Integer(..)
             | Literal::Bool(_)
             | Literal::Str(_)
             | Literal::RawStr(..)
@@ -122,6 +122,10 @@ pub(crate) fn rewrite(
 
                 format!("[{repeated}; {length}]")
             }
+            // Byte strings are parsed as arrays of their bytes, so they are kept as written
+            Literal::Array(ArrayLiteral::Standard(_)) if visitor.slice(span).starts_with("b\"") => {
+                visitor.slice(span).to_string()
+            }
             Literal::Array(ArrayLiteral::Standard(exprs)) => {
                 super::array(visitor.fork(), exprs, span, false)
             }
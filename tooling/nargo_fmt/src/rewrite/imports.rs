@@ -66,6 +66,7 @@ impl UseTree {
         match use_tree.prefix.kind {
             ast::PathKind::Crate => result.path.push(UseSegment::Crate),
             ast::PathKind::Dep => result.path.push(UseSegment::Dep),
+            ast::PathKind::SelfModule => result.path.push(UseSegment::Ident("self".into(), None)),
             ast::PathKind::Plain => {}
         };
 
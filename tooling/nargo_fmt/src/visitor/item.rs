@@ -42,7 +42,14 @@ impl super::FmtVisitor<'_> {
         let return_type = self.format_return_type(return_type_span, &func, func_span, params_end);
         let parameters = func.def.parameters;
 
-        if !func.def.generics.is_empty() {
+        // Inline trait bounds are parsed into the where clause so we keep them as written.
+        let has_inline_bounds = func.def.where_clause.iter().any(|constraint| {
+            constraint.typ.span.map_or(false, |span| span.start() < params_open)
+        });
+
+        if has_inline_bounds {
+            result.push_str(self.slice(name_span.end()..params_open).trim_end());
+        } else if !func.def.generics.is_empty() {
             let full_span = name_span.end()..params_open;
             let start = name_span.end();
             let end = self.span_after(full_span, Token::Greater).start();
@@ -221,7 +228,8 @@ impl super::FmtVisitor<'_> {
                 | ItemKind::TraitImpl(_)
                 | ItemKind::TypeAlias(_)
                 | ItemKind::Global(_)
-                | ItemKind::ModuleDecl(_) => {
+                | ItemKind::ModuleDecl(_)
+                | ItemKind::InnerAttribute(_) => {
                     self.push_rewrite(self.slice(span).to_string(), span);
                     self.last_position = span.end();
                 }
@@ -3,7 +3,8 @@ use std::iter::zip;
 use noirc_frontend::macros_api::Span;
 
 use noirc_frontend::ast::{
-    ConstrainKind, ConstrainStatement, ExpressionKind, ForRange, Statement, StatementKind,
+    ConstrainKind, ConstrainStatement, ExpressionKind, ForBounds, ForRange, Statement,
+    StatementKind,
 };
 
 use crate::{rewrite, visitor::expr::wrap_exprs};
@@ -83,18 +84,26 @@ impl super::FmtVisitor<'_> {
                 self.push_rewrite(constrain, span);
             }
             StatementKind::For(for_stmt) => {
-                let identifier = self.slice(for_stmt.identifier.span());
+                let pattern = self.slice(for_stmt.pattern.span());
                 let range = match for_stmt.range {
-                    ForRange::Range(start, end) => format!(
-                        "{}..{}",
+                    ForRange::Range(ForBounds { start, end, inclusive }) => format!(
+                        "{}{}{}",
                         rewrite::sub_expr(self, self.shape(), start),
+                        if inclusive { "..=" } else { ".." },
                         rewrite::sub_expr(self, self.shape(), end)
                     ),
                     ForRange::Array(array) => rewrite::sub_expr(self, self.shape(), array),
                 };
                 let block = rewrite::sub_expr(self, self.shape(), for_stmt.block);
 
-                let result = format!("for {identifier} in {range} {block}");
+                let result = format!("for {pattern} in {range} {block}");
+                self.push_rewrite(result, span);
+            }
+            StatementKind::While(while_stmt) => {
+                let condition = rewrite::sub_expr(self, self.shape(), while_stmt.condition);
+                let block = rewrite::sub_expr(self, self.shape(), while_stmt.block);
+
+                let result = format!("while {condition} {block}");
                 self.push_rewrite(result, span);
             }
             StatementKind::Assign(_) => {
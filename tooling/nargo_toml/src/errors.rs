@@ -61,6 +61,9 @@ pub enum ManifestError {
     #[error("Package `{0}` has type `bin` but you cannot depend on binary packages")]
     BinaryDependency(CrateName),
 
+    #[error("The `[fuzz]` range of `{parameter}` in {toml} has a minimum above its maximum")]
+    InvalidFuzzRange { toml: PathBuf, parameter: String },
+
     #[error("Missing `name` field in {toml}")]
     MissingNameField { toml: PathBuf },
 
@@ -8,10 +8,9 @@ use std::{
     path::{Component, Path, PathBuf},
 };
 
-use errors::SemverError;
 use fm::{NormalizePath, FILE_EXTENSION};
 use nargo::{
-    package::{Dependency, Package, PackageType},
+    package::{Dependency, FuzzRange, Package, PackageType},
     workspace::Workspace,
 };
 use noirc_frontend::graph::CrateName;
@@ -21,7 +20,7 @@ mod errors;
 mod git;
 mod semver;
 
-pub use errors::ManifestError;
+pub use errors::{ManifestError, SemverError};
 use git::clone_git_repo;
 
 /// Searches for a `Nargo.toml` file in the current directory and all parent directories.
@@ -117,6 +116,8 @@ struct PackageConfig {
     package: PackageMetadata,
     #[serde(default)]
     dependencies: BTreeMap<String, DependencyConfig>,
+    #[serde(default)]
+    fuzz: BTreeMap<String, FuzzRange>,
 }
 
 impl PackageConfig {
@@ -199,6 +200,17 @@ impl PackageConfig {
             })?;
         }
 
+        for (parameter, range) in &self.fuzz {
+            if let (Some(min), Some(max)) = (range.min, range.max) {
+                if min > max {
+                    return Err(ManifestError::InvalidFuzzRange {
+                        toml: root_dir.join("Nargo.toml"),
+                        parameter: parameter.clone(),
+                    });
+                }
+            }
+        }
+
         Ok(Package {
             version: self.package.version.clone(),
             compiler_required_version: self.package.compiler_version.clone(),
@@ -207,6 +219,7 @@ impl PackageConfig {
             package_type,
             name,
             dependencies,
+            fuzz_ranges: self.fuzz.clone(),
         })
     }
 }
@@ -531,3 +544,22 @@ fn parse_workspace_default_member_toml() {
     assert!(Config::try_from(String::from(src)).is_ok());
     assert!(Config::try_from(src).is_ok());
 }
+
+#[test]
+fn parse_fuzz_table_toml() {
+    let src = r#"
+        [package]
+        name = "test"
+        type = "bin"
+
+        [fuzz]
+        x = { min = 1, max = 100 }
+        y = { max = 5 }
+    "#;
+
+    let Config::Package { package_config } = Config::try_from(src).unwrap() else {
+        panic!("expected a package config");
+    };
+    assert_eq!(package_config.fuzz["x"], FuzzRange { min: Some(1), max: Some(100) });
+    assert_eq!(package_config.fuzz["y"], FuzzRange { min: None, max: Some(5) });
+}
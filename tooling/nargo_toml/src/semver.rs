@@ -88,6 +88,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            fuzz_ranges: BTreeMap::new(),
             version: Some("1.0".to_string()),
         };
         if let Err(err) = semver_check_package(&package, &compiler_version) {
@@ -119,6 +120,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            fuzz_ranges: BTreeMap::new(),
             version: Some("1.0".to_string()),
         };
 
@@ -129,6 +131,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("good_dependency").unwrap(),
             dependencies: BTreeMap::new(),
+            fuzz_ranges: BTreeMap::new(),
             version: Some("1.0".to_string()),
         };
         let invalid_dependency = Package {
@@ -138,6 +141,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("bad_dependency").unwrap(),
             dependencies: BTreeMap::new(),
+            fuzz_ranges: BTreeMap::new(),
             version: Some("1.0".to_string()),
         };
 
@@ -178,6 +182,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            fuzz_ranges: BTreeMap::new(),
             version: Some("1.0".to_string()),
         };
 
@@ -197,6 +202,7 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            fuzz_ranges: BTreeMap::new(),
             version: Some("1.0".to_string()),
         };
 
@@ -204,4 +210,38 @@ mod tests {
             panic!("semver check should have passed. compiler version is 0.1.0+build_data and required version from the package is 0.1.0\n The build data should be ignored\n error: {err:?}")
         };
     }
+
+    #[test]
+    fn test_semver_range() {
+        let package = |required: &str| Package {
+            compiler_required_version: Some(required.to_string()),
+            root_dir: PathBuf::new(),
+            package_type: PackageType::Library,
+            entry_path: PathBuf::new(),
+            name: CrateName::from_str("test").unwrap(),
+            dependencies: BTreeMap::new(),
+            fuzz_ranges: BTreeMap::new(),
+            version: Some("1.0".to_string()),
+        };
+        let range = package(">=0.30, <0.32");
+
+        let satisfied = Version::parse("0.31.0").unwrap();
+        assert_eq!(semver_check_package(&range, &satisfied), Ok(()));
+
+        let unsatisfied = Version::parse("0.32.0").unwrap();
+        assert_eq!(
+            semver_check_package(&range, &unsatisfied),
+            Err(SemverError::IncompatibleVersion {
+                package_name: CrateName::from_str("test").unwrap(),
+                required_compiler_version: ">=0.30, <0.32".to_string(),
+                compiler_version_found: "0.32.0".to_string(),
+            })
+        );
+
+        let malformed = package(">=0.30 <<0.32");
+        assert!(matches!(
+            semver_check_package(&malformed, &satisfied),
+            Err(SemverError::CouldNotParseRequiredVersion { package_name, .. }) if package_name == "test"
+        ));
+    }
 }
@@ -3,6 +3,7 @@ use crate::{
     AbiType,
 };
 use acvm::acir::native_types::Witness;
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -17,6 +18,20 @@ pub enum InputParserError {
     AbiTypeMismatch(AbiType),
     #[error("Expected argument `{0}`, but none was found")]
     MissingArgument(String),
+    #[error("unknown input `{key}`; did you mean `{suggestion}`?")]
+    UnknownInput { key: String, suggestion: String },
+    #[error("Could not read file {} for argument `{arg_name}`: {message}", .path.display())]
+    ExternalFileRead { arg_name: String, path: PathBuf, message: String },
+    #[error("Unknown format `{format}` for file {} for argument `{arg_name}`. Expected one of `bytes`, `hex` or `fields_le`", .path.display())]
+    UnknownExternalFileFormat { arg_name: String, path: PathBuf, format: String },
+    #[error("Argument `{arg_name}` expects {expected} elements but file {} contains {actual}", .path.display())]
+    ExternalFileLengthMismatch { arg_name: String, path: PathBuf, expected: usize, actual: usize },
+    #[error("Element {index} of file {} for argument `{arg_name}` is not less than the field modulus", .path.display())]
+    ExternalFileFieldOutOfRange { arg_name: String, path: PathBuf, index: usize },
+    #[error(
+        "Argument `{arg_name}` can hold at most {max_len} elements but {actual} were provided"
+    )]
+    BoundedVecTooLong { arg_name: String, max_len: u64, actual: usize },
 }
 
 impl From<toml::ser::Error> for InputParserError {
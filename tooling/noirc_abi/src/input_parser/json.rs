@@ -1,17 +1,28 @@
-use super::{parse_str_to_field, InputValue};
+use super::{parse_str_to_field, suggest_misspelling, unknown_keys, InputFileValue, InputValue};
 use crate::{errors::InputParserError, Abi, AbiType, MAIN_RETURN_NAME};
 use acvm::FieldElement;
 use iter_extended::{try_btree_map, try_vecmap};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+/// Parses a JSON input file, also returning the path of each key which isn't expected by the ABI.
 pub(crate) fn parse_json(
     input_string: &str,
     abi: &Abi,
-) -> Result<BTreeMap<String, InputValue>, InputParserError> {
+) -> Result<(BTreeMap<String, InputValue>, Vec<String>), InputParserError> {
     // Parse input.json into a BTreeMap.
     let data: BTreeMap<String, JsonTypes> = serde_json::from_str(input_string)?;
+    let unknown_keys = unknown_keys(&data, abi);
+
+    let inputs =
+        parse_json_data(&data, abi).map_err(|error| suggest_misspelling(error, &unknown_keys))?;
+    Ok((inputs, unknown_keys))
+}
 
+fn parse_json_data(
+    data: &BTreeMap<String, JsonTypes>,
+    abi: &Abi,
+) -> Result<BTreeMap<String, InputValue>, InputParserError> {
     // Convert arguments to field elements.
     let mut parsed_inputs = try_btree_map(abi.to_btree_map(), |(arg_name, abi_type)| {
         // Check that json contains a value for each argument in the ABI.
@@ -80,6 +91,22 @@ pub enum JsonTypes {
     Table(BTreeMap<String, JsonTypes>),
 }
 
+impl InputFileValue for JsonTypes {
+    fn as_table(&self) -> Option<&BTreeMap<String, Self>> {
+        match self {
+            JsonTypes::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Self]> {
+        match self {
+            JsonTypes::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+}
+
 impl JsonTypes {
     pub fn try_from_input_value(
         value: &InputValue,
@@ -165,6 +192,16 @@ impl InputValue {
                 InputValue::Vec(array_elements)
             }
 
+            // A `BoundedVec` may be given as an array of its elements.
+            (JsonTypes::Array(array), AbiType::Struct { .. }) => {
+                let (_, typ) = param_type
+                    .as_bounded_vec()
+                    .ok_or_else(|| InputParserError::AbiTypeMismatch(param_type.clone()))?;
+                let elements =
+                    try_vecmap(array, |value| InputValue::try_from_json(value, typ, arg_name))?;
+                InputValue::bounded_vec(elements, param_type, arg_name)?
+            }
+
             (JsonTypes::Table(table), AbiType::Struct { fields, .. }) => {
                 let native_table = try_btree_map(fields, |(field_name, abi_type)| {
                     // Check that json contains a value for each field of the struct.
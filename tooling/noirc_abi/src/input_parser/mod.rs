@@ -1,13 +1,15 @@
 use num_bigint::{BigInt, BigUint};
 use num_traits::{Num, Zero};
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
 use thiserror::Error;
 
 use acvm::FieldElement;
+use noirc_errors::edit_distance;
 use serde::Serialize;
 
 use crate::errors::InputParserError;
-use crate::{Abi, AbiType};
+use crate::{Abi, AbiType, MAIN_RETURN_NAME};
 
 pub mod json;
 mod toml;
@@ -182,6 +184,51 @@ impl InputValue {
     pub fn matches_abi(&self, abi_param: &AbiType) -> bool {
         self.find_type_mismatch(abi_param, String::new()).is_ok()
     }
+
+    /// Builds the value of a `BoundedVec` holding `elements`, padding its storage with zeroed
+    /// elements up to its maximum length.
+    pub(crate) fn bounded_vec(
+        mut elements: Vec<InputValue>,
+        bounded_vec_type: &AbiType,
+        arg_name: &str,
+    ) -> Result<InputValue, InputParserError> {
+        let (max_len, element_type) = bounded_vec_type
+            .as_bounded_vec()
+            .ok_or_else(|| InputParserError::AbiTypeMismatch(bounded_vec_type.clone()))?;
+        if elements.len() as u64 > max_len {
+            return Err(InputParserError::BoundedVecTooLong {
+                arg_name: arg_name.to_owned(),
+                max_len,
+                actual: elements.len(),
+            });
+        }
+
+        let len = InputValue::Field(FieldElement::from(elements.len() as u128));
+        elements.resize(max_len as usize, InputValue::zeroed(element_type));
+        Ok(InputValue::Struct(BTreeMap::from([
+            ("storage".to_owned(), InputValue::Vec(elements)),
+            ("len".to_owned(), len),
+        ])))
+    }
+
+    /// The value of type `abi_type` which is encoded as all zeroes.
+    fn zeroed(abi_type: &AbiType) -> InputValue {
+        match abi_type {
+            AbiType::Field | AbiType::Integer { .. } | AbiType::Boolean => {
+                InputValue::Field(FieldElement::zero())
+            }
+            AbiType::Array { length, typ } => {
+                InputValue::Vec(vec![InputValue::zeroed(typ); *length as usize])
+            }
+            AbiType::String { length } => InputValue::String("\0".repeat(*length as usize)),
+            AbiType::Struct { fields, .. } => InputValue::Struct(
+                fields.iter().map(|(name, typ)| (name.clone(), InputValue::zeroed(typ))).collect(),
+            ),
+            AbiType::Tuple { fields } => {
+                InputValue::Vec(fields.iter().map(InputValue::zeroed).collect())
+            }
+        }
+    }
 }
 
 /// The different formats that are supported when parsing
@@ -207,9 +254,33 @@ impl Format {
         input_string: &str,
         abi: &Abi,
     ) -> Result<BTreeMap<String, InputValue>, InputParserError> {
+        self.parse_relative_to(input_string, abi, Path::new(""))
+    }
+
+    /// Parses `input_string`, resolving any external file inputs relative to `root_dir`.
+    pub fn parse_relative_to(
+        &self,
+        input_string: &str,
+        abi: &Abi,
+        root_dir: &Path,
+    ) -> Result<BTreeMap<String, InputValue>, InputParserError> {
+        self.parse_with_unknown_keys(input_string, abi, root_dir).map(|(inputs, _)| inputs)
+    }
+
+    /// Parses `input_string` as [`Format::parse_relative_to`] does, additionally returning the path of each key
+    /// which doesn't correspond to a parameter of the ABI or to a field of a struct parameter.
+    ///
+    /// If an expected input is missing and one of these keys is a close misspelling of it, then
+    /// [`InputParserError::UnknownInput`] is returned rather than reporting the missing input.
+    pub fn parse_with_unknown_keys(
+        &self,
+        input_string: &str,
+        abi: &Abi,
+        root_dir: &Path,
+    ) -> Result<(BTreeMap<String, InputValue>, Vec<String>), InputParserError> {
         match self {
             Format::Json => json::parse_json(input_string, abi),
-            Format::Toml => toml::parse_toml(input_string, abi),
+            Format::Toml => toml::parse_toml(input_string, abi, root_dir),
         }
     }
 
@@ -225,11 +296,104 @@ impl Format {
     }
 }
 
+/// A value read from an input file, before it has been checked against the ABI.
+trait InputFileValue: Sized {
+    /// Returns the entries of the value if it is a table.
+    fn as_table(&self) -> Option<&BTreeMap<String, Self>>;
+
+    /// Returns the elements of the value if it is an array.
+    fn as_array(&self) -> Option<&[Self]>;
+}
+
+/// Returns the path of each key in `data` which isn't expected by `abi`.
+///
+/// Paths are formatted as in [`InputParserError::MissingArgument`], so a field of a struct within an array
+/// is reported without the index of the array element.
+fn unknown_keys<T: InputFileValue>(data: &BTreeMap<String, T>, abi: &Abi) -> Vec<String> {
+    let parameters = abi.to_btree_map();
+    let mut unknown_keys = BTreeSet::new();
+    for (key, value) in data {
+        match parameters.get(key) {
+            Some(typ) => collect_unknown_keys(value, typ, key, &mut unknown_keys),
+            None if key == MAIN_RETURN_NAME && abi.return_type.is_some() => (),
+            None => {
+                unknown_keys.insert(key.clone());
+            }
+        }
+    }
+    unknown_keys.into_iter().collect()
+}
+
+fn collect_unknown_keys<T: InputFileValue>(
+    value: &T,
+    typ: &AbiType,
+    path: &str,
+    unknown_keys: &mut BTreeSet<String>,
+) {
+    match typ {
+        AbiType::Struct { fields, .. } => {
+            let Some(table) = value.as_table() else { return };
+            for (key, value) in table {
+                let field_path = format!("{path}.{key}");
+                match fields.iter().find(|(name, _)| name == key) {
+                    Some((_, typ)) => collect_unknown_keys(value, typ, &field_path, unknown_keys),
+                    None => {
+                        unknown_keys.insert(field_path);
+                    }
+                }
+            }
+        }
+        AbiType::Array { typ, .. } => {
+            for element in value.as_array().unwrap_or_default() {
+                collect_unknown_keys(element, typ, path, unknown_keys);
+            }
+        }
+        AbiType::Tuple { fields } => {
+            for (element, typ) in value.as_array().unwrap_or_default().iter().zip(fields) {
+                collect_unknown_keys(element, typ, path, unknown_keys);
+            }
+        }
+        AbiType::Field | AbiType::Integer { .. } | AbiType::Boolean | AbiType::String { .. } => (),
+    }
+}
+
+/// Replaces an error for a missing input with one pointing at its misspelling, if any of `unknown_keys` is one.
+fn suggest_misspelling(error: InputParserError, unknown_keys: &[String]) -> InputParserError {
+    let InputParserError::MissingArgument(missing) = &error else {
+        return error;
+    };
+    let (parent, name) = split_key_path(missing);
+    // Allow one edit for every three characters of the expected name, so that swapping two characters of
+    // a five character name is still recognized.
+    let max_distance = name.len().div_ceil(3).max(1);
+
+    let misspelling = unknown_keys
+        .iter()
+        .filter_map(|key| {
+            let (key_parent, key_name) = split_key_path(key);
+            let distance = edit_distance(name, key_name);
+            (key_parent == parent && distance <= max_distance).then_some((distance, key))
+        })
+        .min_by_key(|(distance, _)| *distance);
+
+    match misspelling {
+        Some((_, key)) => {
+            InputParserError::UnknownInput { key: key.clone(), suggestion: missing.clone() }
+        }
+        None => error,
+    }
+}
+
+/// Splits a key path such as `foo.bar` into the path of its parent table and its own name.
+fn split_key_path(path: &str) -> (&str, &str) {
+    path.rsplit_once('.').unwrap_or(("", path))
+}
+
 #[cfg(test)]
 mod serialization_tests {
     use std::collections::BTreeMap;
 
-    use acvm::FieldElement;
+    use acvm::{acir::native_types::Witness, FieldElement};
     use strum::IntoEnumIterator;
 
     use crate::{
@@ -299,6 +463,35 @@ mod serialization_tests {
             assert_eq!(input_map, reconstructed_input_map);
         }
     }
+
+    #[test]
+    fn u128_inputs_are_parsed_from_decimal_and_hex_strings() {
+        let abi = Abi {
+            parameters: vec![AbiParameter {
+                name: "x".into(),
+                typ: AbiType::Integer { sign: Sign::Unsigned, width: 128 },
+                visibility: AbiVisibility::Private,
+            }],
+            return_type: None,
+            param_witnesses: BTreeMap::from([("x".to_string(), vec![(Witness(0)..Witness(1))])]),
+            return_witnesses: Vec::new(),
+            error_types: Default::default(),
+        };
+
+        let max = InputValue::Field(u128::MAX.into());
+        for value in
+            ["340282366920938463463374607431768211455", "0xffffffffffffffffffffffffffffffff"]
+        {
+            let inputs = Format::Toml.parse(&format!("x = \"{value}\""), &abi).unwrap();
+            assert_eq!(inputs["x"], max);
+            assert!(abi.encode(&inputs, None).is_ok());
+        }
+
+        // 2^128 is out of range.
+        let inputs =
+            Format::Toml.parse("x = \"340282366920938463463374607431768211456\"", &abi).unwrap();
+        assert!(abi.encode(&inputs, None).is_err());
+    }
 }
 
 fn parse_str_to_field(value: &str) -> Result<FieldElement, InputParserError> {
@@ -1,17 +1,37 @@
-use super::{parse_str_to_field, parse_str_to_signed, InputValue};
+use super::{
+    parse_str_to_field, parse_str_to_signed, suggest_misspelling, unknown_keys, InputFileValue,
+    InputValue,
+};
 use crate::{errors::InputParserError, Abi, AbiType, MAIN_RETURN_NAME};
 use acvm::FieldElement;
-use iter_extended::{try_btree_map, try_vecmap};
+use iter_extended::{try_btree_map, try_vecmap, vecmap};
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
+/// Parses a TOML input file, resolving any external file inputs relative to `root_dir`.
+///
+/// Also returns the path of each key which isn't expected by the ABI.
 pub(crate) fn parse_toml(
     input_string: &str,
     abi: &Abi,
-) -> Result<BTreeMap<String, InputValue>, InputParserError> {
+    root_dir: &Path,
+) -> Result<(BTreeMap<String, InputValue>, Vec<String>), InputParserError> {
     // Parse input.toml into a BTreeMap.
     let data: BTreeMap<String, TomlTypes> = toml::from_str(input_string)?;
+    let unknown_keys = unknown_keys(&data, abi);
+
+    let inputs = parse_toml_data(&data, abi, root_dir)
+        .map_err(|error| suggest_misspelling(error, &unknown_keys))?;
+    Ok((inputs, unknown_keys))
+}
 
+fn parse_toml_data(
+    data: &BTreeMap<String, TomlTypes>,
+    abi: &Abi,
+    root_dir: &Path,
+) -> Result<BTreeMap<String, InputValue>, InputParserError> {
     // Convert arguments to field elements.
     let mut parsed_inputs = try_btree_map(abi.to_btree_map(), |(arg_name, abi_type)| {
         // Check that toml contains a value for each argument in the ABI.
@@ -19,7 +39,7 @@ pub(crate) fn parse_toml(
             .get(&arg_name)
             .ok_or_else(|| InputParserError::MissingArgument(arg_name.clone()))?;
 
-        InputValue::try_from_toml(value.clone(), &abi_type, &arg_name)
+        InputValue::try_from_toml(value.clone(), &abi_type, &arg_name, root_dir)
             .map(|input_value| (arg_name, input_value))
     })?;
 
@@ -32,6 +52,7 @@ pub(crate) fn parse_toml(
             toml_return_value.clone(),
             &return_type.abi_type,
             MAIN_RETURN_NAME,
+            root_dir,
         )?;
         parsed_inputs.insert(MAIN_RETURN_NAME.to_owned(), return_value);
     }
@@ -77,6 +98,22 @@ enum TomlTypes {
     Table(BTreeMap<String, TomlTypes>),
 }
 
+impl InputFileValue for TomlTypes {
+    fn as_table(&self) -> Option<&BTreeMap<String, Self>> {
+        match self {
+            TomlTypes::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Self]> {
+        match self {
+            TomlTypes::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+}
+
 impl TomlTypes {
     fn try_from_input_value(
         value: &InputValue,
@@ -123,6 +160,7 @@ impl InputValue {
         value: TomlTypes,
         param_type: &AbiType,
         arg_name: &str,
+        root_dir: &Path,
     ) -> Result<InputValue, InputParserError> {
         let input_value = match (value, param_type) {
             (TomlTypes::String(string), AbiType::String { .. }) => InputValue::String(string),
@@ -147,11 +185,39 @@ impl InputValue {
             (TomlTypes::Bool(boolean), AbiType::Boolean) => InputValue::Field(boolean.into()),
 
             (TomlTypes::Array(array), AbiType::Array { typ, .. }) => {
-                let array_elements =
-                    try_vecmap(array, |value| InputValue::try_from_toml(value, typ, arg_name))?;
+                let array_elements = try_vecmap(array, |value| {
+                    InputValue::try_from_toml(value, typ, arg_name, root_dir)
+                })?;
                 InputValue::Vec(array_elements)
             }
 
+            // Large arrays may be provided as a reference to an external file,
+            // e.g. `{ file = "witness/input.bin", format = "bytes" }`.
+            (TomlTypes::Table(table), AbiType::Array { length, typ }) => {
+                let file = ExternalFile::try_from_table(&table, arg_name)?;
+                let elements = file.read(arg_name, typ, root_dir)?;
+                if elements.len() != *length as usize {
+                    return Err(InputParserError::ExternalFileLengthMismatch {
+                        arg_name: arg_name.to_owned(),
+                        path: file.path,
+                        expected: *length as usize,
+                        actual: elements.len(),
+                    });
+                }
+                InputValue::Vec(elements)
+            }
+
+            // A `BoundedVec` may be given as an array of its elements.
+            (TomlTypes::Array(array), AbiType::Struct { .. }) => {
+                let (_, typ) = param_type
+                    .as_bounded_vec()
+                    .ok_or_else(|| InputParserError::AbiTypeMismatch(param_type.clone()))?;
+                let elements = try_vecmap(array, |value| {
+                    InputValue::try_from_toml(value, typ, arg_name, root_dir)
+                })?;
+                InputValue::bounded_vec(elements, param_type, arg_name)?
+            }
+
             (TomlTypes::Table(table), AbiType::Struct { fields, .. }) => {
                 let native_table = try_btree_map(fields, |(field_name, abi_type)| {
                     // Check that json contains a value for each field of the struct.
@@ -159,7 +225,7 @@ impl InputValue {
                     let value = table
                         .get(field_name)
                         .ok_or_else(|| InputParserError::MissingArgument(field_id.clone()))?;
-                    InputValue::try_from_toml(value.clone(), abi_type, &field_id)
+                    InputValue::try_from_toml(value.clone(), abi_type, &field_id, root_dir)
                         .map(|input_value| (field_name.to_string(), input_value))
                 })?;
 
@@ -168,7 +234,7 @@ impl InputValue {
 
             (TomlTypes::Array(array), AbiType::Tuple { fields }) => {
                 let tuple_fields = try_vecmap(array.into_iter().zip(fields), |(value, typ)| {
-                    InputValue::try_from_toml(value, typ, arg_name)
+                    InputValue::try_from_toml(value, typ, arg_name, root_dir)
                 })?;
                 InputValue::Vec(tuple_fields)
             }
@@ -179,3 +245,338 @@ impl InputValue {
         Ok(input_value)
     }
 }
+
+/// An array input which is read from a file rather than written inline in the TOML file.
+struct ExternalFile {
+    path: PathBuf,
+    format: String,
+}
+
+impl ExternalFile {
+    fn try_from_table(
+        table: &BTreeMap<String, TomlTypes>,
+        arg_name: &str,
+    ) -> Result<ExternalFile, InputParserError> {
+        let get_string = |key: &str| match table.get(key) {
+            Some(TomlTypes::String(value)) => Ok(value.clone()),
+            _ => Err(InputParserError::MissingArgument(format!("{arg_name}.{key}"))),
+        };
+        Ok(ExternalFile { path: PathBuf::from(get_string("file")?), format: get_string("format")? })
+    }
+
+    /// Reads the file's contents as a list of values of type `typ`.
+    fn read(
+        &self,
+        arg_name: &str,
+        typ: &AbiType,
+        root_dir: &Path,
+    ) -> Result<Vec<InputValue>, InputParserError> {
+        let read_error = |message: String| InputParserError::ExternalFileRead {
+            arg_name: arg_name.to_owned(),
+            path: self.path.clone(),
+            message,
+        };
+
+        if !matches!(typ, AbiType::Field | AbiType::Integer { .. }) {
+            return Err(InputParserError::AbiTypeMismatch(typ.clone()));
+        }
+
+        let contents =
+            std::fs::read(root_dir.join(&self.path)).map_err(|err| read_error(err.to_string()))?;
+
+        let elements = match self.format.as_str() {
+            "bytes" => vecmap(contents, |byte| FieldElement::from(byte as u128)),
+            "hex" => {
+                let contents =
+                    String::from_utf8(contents).map_err(|err| read_error(err.to_string()))?;
+                let hex = contents.trim();
+                let hex = hex.strip_prefix("0x").unwrap_or(hex);
+                let bytes = decode_hex(hex).ok_or_else(|| {
+                    read_error("file does not contain a valid hex string".to_owned())
+                })?;
+                vecmap(bytes, |byte| FieldElement::from(byte as u128))
+            }
+            "fields_le" => {
+                let field_size = FieldElement::max_num_bytes() as usize;
+                if contents.len() % field_size != 0 {
+                    return Err(read_error(format!(
+                        "file length must be a multiple of {field_size} bytes"
+                    )));
+                }
+                let modulus = FieldElement::modulus();
+                try_vecmap(contents.chunks(field_size).enumerate(), |(index, chunk)| {
+                    if BigUint::from_bytes_le(chunk) >= modulus {
+                        return Err(InputParserError::ExternalFileFieldOutOfRange {
+                            arg_name: arg_name.to_owned(),
+                            path: self.path.clone(),
+                            index,
+                        });
+                    }
+                    let mut bytes = chunk.to_vec();
+                    bytes.reverse();
+                    Ok(FieldElement::from_be_bytes_reduce(&bytes))
+                })?
+            }
+            _ => {
+                return Err(InputParserError::UnknownExternalFileFormat {
+                    arg_name: arg_name.to_owned(),
+                    path: self.path.clone(),
+                    format: self.format.clone(),
+                })
+            }
+        };
+
+        Ok(vecmap(elements, InputValue::Field))
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| hex.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use acvm::FieldElement;
+    use tempfile::tempdir;
+
+    use crate::{
+        errors::InputParserError, input_parser::InputValue, Abi, AbiParameter, AbiType,
+        AbiVisibility, Sign,
+    };
+
+    use super::parse_toml;
+
+    fn bytes_abi(length: u64) -> Abi {
+        Abi {
+            parameters: vec![AbiParameter {
+                name: "input".into(),
+                typ: AbiType::Array {
+                    length,
+                    typ: Box::new(AbiType::Integer { sign: Sign::Unsigned, width: 8 }),
+                },
+                visibility: AbiVisibility::Private,
+            }],
+            return_type: None,
+            param_witnesses: BTreeMap::new(),
+            return_witnesses: Vec::new(),
+            error_types: Default::default(),
+        }
+    }
+
+    #[test]
+    fn reads_array_from_bytes_file() {
+        let root_dir = tempdir().unwrap();
+        std::fs::create_dir(root_dir.path().join("witness")).unwrap();
+        let bytes: Vec<u8> = (0..64).collect();
+        std::fs::write(root_dir.path().join("witness/input.bin"), &bytes).unwrap();
+
+        let toml = r#"input = { file = "witness/input.bin", format = "bytes" }"#;
+        let (inputs, _) = parse_toml(toml, &bytes_abi(64), root_dir.path()).unwrap();
+
+        let expected =
+            bytes.into_iter().map(|byte| InputValue::Field(FieldElement::from(byte as u128)));
+        assert_eq!(inputs["input"], InputValue::Vec(expected.collect()));
+    }
+
+    #[test]
+    fn reads_array_from_hex_file() {
+        let root_dir = tempdir().unwrap();
+        std::fs::write(root_dir.path().join("input.hex"), "0x00ff10\n").unwrap();
+
+        let toml = r#"input = { file = "input.hex", format = "hex" }"#;
+        let (inputs, _) = parse_toml(toml, &bytes_abi(3), root_dir.path()).unwrap();
+
+        let expected = [0u128, 255, 16].map(|byte| InputValue::Field(FieldElement::from(byte)));
+        assert_eq!(inputs["input"], InputValue::Vec(expected.to_vec()));
+    }
+
+    #[test]
+    fn errors_on_external_file_length_mismatch() {
+        let root_dir = tempdir().unwrap();
+        std::fs::write(root_dir.path().join("input.bin"), [0u8; 63]).unwrap();
+
+        let toml = r#"input = { file = "input.bin", format = "bytes" }"#;
+        let error = parse_toml(toml, &bytes_abi(64), root_dir.path()).unwrap_err();
+
+        assert!(matches!(
+            &error,
+            InputParserError::ExternalFileLengthMismatch { arg_name, expected: 64, actual: 63, .. }
+                if arg_name == "input"
+        ));
+        let message = error.to_string();
+        assert!(message.contains("`input`") && message.contains("input.bin"), "{message}");
+    }
+
+    #[test]
+    fn errors_on_field_elements_outside_the_field() {
+        let root_dir = tempdir().unwrap();
+        let modulus = FieldElement::modulus().to_bytes_le();
+        let mut contents = vec![0u8; 32];
+        contents.extend(&modulus);
+        contents.resize(64, 0);
+        std::fs::write(root_dir.path().join("input.bin"), contents).unwrap();
+
+        let abi = Abi {
+            parameters: vec![AbiParameter {
+                name: "input".into(),
+                typ: AbiType::Array { length: 2, typ: Box::new(AbiType::Field) },
+                visibility: AbiVisibility::Private,
+            }],
+            ..bytes_abi(2)
+        };
+        let toml = r#"input = { file = "input.bin", format = "fields_le" }"#;
+        let error = parse_toml(toml, &abi, root_dir.path()).unwrap_err();
+
+        assert!(matches!(
+            &error,
+            InputParserError::ExternalFileFieldOutOfRange { arg_name, index: 1, .. }
+                if arg_name == "input"
+        ));
+        let message = error.to_string();
+        assert!(message.contains("`input`") && message.contains("input.bin"), "{message}");
+    }
+
+    #[test]
+    fn errors_on_unknown_external_file_format() {
+        let root_dir = tempdir().unwrap();
+        std::fs::write(root_dir.path().join("input.bin"), [0u8; 64]).unwrap();
+
+        let toml = r#"input = { file = "input.bin", format = "base64" }"#;
+        let error = parse_toml(toml, &bytes_abi(64), root_dir.path()).unwrap_err();
+
+        assert!(matches!(error, InputParserError::UnknownExternalFileFormat { .. }));
+        let message = error.to_string();
+        assert!(message.contains("base64") && message.contains("input.bin"), "{message}");
+    }
+
+    fn transfer_abi() -> Abi {
+        let field = |name: &str| (name.to_owned(), AbiType::Field);
+        Abi {
+            parameters: vec![
+                AbiParameter {
+                    name: "amount".into(),
+                    typ: AbiType::Field,
+                    visibility: AbiVisibility::Private,
+                },
+                AbiParameter {
+                    name: "note".into(),
+                    typ: AbiType::Struct {
+                        path: "Note".into(),
+                        fields: vec![field("owner"), field("value")],
+                    },
+                    visibility: AbiVisibility::Private,
+                },
+            ],
+            return_type: None,
+            param_witnesses: BTreeMap::new(),
+            return_witnesses: Vec::new(),
+            error_types: Default::default(),
+        }
+    }
+
+    #[test]
+    fn suggests_parameter_for_misspelled_input() {
+        let root_dir = tempdir().unwrap();
+        let toml = "amout = 1\nnote = { owner = 2, value = 3 }";
+        let error = parse_toml(toml, &transfer_abi(), root_dir.path()).unwrap_err();
+
+        assert_eq!(error.to_string(), "unknown input `amout`; did you mean `amount`?");
+    }
+
+    #[test]
+    fn suggests_struct_field_for_misspelled_nested_input() {
+        let root_dir = tempdir().unwrap();
+        let toml = "amount = 1\n[note]\nowner = 2\nvaleu = 3";
+        let error = parse_toml(toml, &transfer_abi(), root_dir.path()).unwrap_err();
+
+        assert!(matches!(
+            &error,
+            InputParserError::UnknownInput { key, suggestion }
+                if key == "note.valeu" && suggestion == "note.value"
+        ));
+    }
+
+    #[test]
+    fn returns_unknown_inputs_without_near_match() {
+        let root_dir = tempdir().unwrap();
+        let toml = "amount = 1\nunrelated = 4\nnote = { owner = 2, value = 3, extra = 5 }";
+        let (inputs, unknown_keys) = parse_toml(toml, &transfer_abi(), root_dir.path()).unwrap();
+
+        assert_eq!(inputs["amount"], InputValue::Field(FieldElement::one()));
+        assert_eq!(unknown_keys, vec!["note.extra".to_owned(), "unrelated".to_owned()]);
+    }
+
+    #[test]
+    fn reports_missing_input_without_near_match() {
+        let root_dir = tempdir().unwrap();
+        let toml = "quantity = 1\nnote = { owner = 2, value = 3 }";
+        let error = parse_toml(toml, &transfer_abi(), root_dir.path()).unwrap_err();
+
+        assert!(matches!(error, InputParserError::MissingArgument(arg) if arg == "amount"));
+    }
+
+    fn bounded_vec_abi(max_len: u64) -> Abi {
+        Abi {
+            parameters: vec![AbiParameter {
+                name: "values".into(),
+                typ: AbiType::Struct {
+                    path: "std::collections::bounded_vec::BoundedVec".into(),
+                    fields: vec![
+                        (
+                            "storage".to_owned(),
+                            AbiType::Array { length: max_len, typ: Box::new(AbiType::Field) },
+                        ),
+                        ("len".to_owned(), AbiType::Integer { sign: Sign::Unsigned, width: 64 }),
+                    ],
+                },
+                visibility: AbiVisibility::Private,
+            }],
+            return_type: None,
+            param_witnesses: BTreeMap::new(),
+            return_witnesses: Vec::new(),
+            error_types: Default::default(),
+        }
+    }
+
+    #[test]
+    fn pads_bounded_vec_given_as_array() {
+        let root_dir = tempdir().unwrap();
+        let toml = r#"values = ["1", "2"]"#;
+        let (inputs, unknown_keys) =
+            parse_toml(toml, &bounded_vec_abi(4), root_dir.path()).unwrap();
+        assert!(unknown_keys.is_empty());
+
+        let field = |value: u128| InputValue::Field(FieldElement::from(value));
+        let expected = InputValue::Struct(BTreeMap::from([
+            ("storage".to_owned(), InputValue::Vec(vec![field(1), field(2), field(0), field(0)])),
+            ("len".to_owned(), field(2)),
+        ]));
+        assert_eq!(inputs["values"], expected);
+
+        // The full form of the `BoundedVec` is still accepted.
+        let toml = r#"values = { storage = ["1", "2", "0", "0"], len = "2" }"#;
+        let (inputs, _) = parse_toml(toml, &bounded_vec_abi(4), root_dir.path()).unwrap();
+        assert_eq!(inputs["values"], expected);
+    }
+
+    #[test]
+    fn errors_on_bounded_vec_longer_than_max_len() {
+        let root_dir = tempdir().unwrap();
+        let toml = r#"values = ["1", "2", "3"]"#;
+        let error = parse_toml(toml, &bounded_vec_abi(2), root_dir.path()).unwrap_err();
+
+        assert!(matches!(
+            &error,
+            InputParserError::BoundedVecTooLong { arg_name, max_len: 2, actual: 3 }
+                if arg_name == "values"
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+//! Generates a [JSON Schema](https://json-schema.org/draft/2020-12/json-schema-core) describing the
+//! inputs of a program, as they would be written in a JSON input file.
+//!
+//! This allows clients to generate types for a program's inputs rather than writing them by hand.
+use serde_json::{json, Map, Value};
+
+use crate::{Abi, AbiType, Sign, MAIN_RETURN_NAME};
+
+const SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Matches a non-negative decimal or hexadecimal integer, as accepted for fields and unsigned integers.
+const UNSIGNED_PATTERN: &str = "^(0x[0-9a-fA-F]+|[0-9]+)$";
+
+/// Matches a decimal or hexadecimal integer, where decimal integers may be negative.
+const SIGNED_PATTERN: &str = "^(0x[0-9a-fA-F]+|-?[0-9]+)$";
+
+impl Abi {
+    /// Returns a JSON Schema for the object holding the inputs to the program.
+    ///
+    /// Each parameter of the program is a required property of the object. The return value may
+    /// also be given under the optional [`MAIN_RETURN_NAME`] property.
+    pub fn to_json_schema(&self) -> Value {
+        let mut properties = Map::new();
+        for parameter in &self.parameters {
+            properties.insert(parameter.name.clone(), type_schema(&parameter.typ));
+        }
+        if let Some(return_type) = &self.return_type {
+            properties.insert(MAIN_RETURN_NAME.to_owned(), type_schema(&return_type.abi_type));
+        }
+
+        json!({
+            "$schema": SCHEMA_DRAFT,
+            "type": "object",
+            "properties": properties,
+            "required": self.parameter_names(),
+            "additionalProperties": false,
+        })
+    }
+}
+
+/// Returns a JSON Schema for the values of type `typ`.
+fn type_schema(typ: &AbiType) -> Value {
+    match typ {
+        AbiType::Field | AbiType::Integer { sign: Sign::Unsigned, .. } => {
+            json!({ "type": "string", "pattern": UNSIGNED_PATTERN })
+        }
+        AbiType::Integer { sign: Sign::Signed, .. } => {
+            json!({ "type": "string", "pattern": SIGNED_PATTERN })
+        }
+        AbiType::Boolean => json!({ "type": "boolean" }),
+        AbiType::String { length } => {
+            json!({ "type": "string", "minLength": length, "maxLength": length })
+        }
+        AbiType::Array { length, typ } => json!({
+            "type": "array",
+            "items": type_schema(typ),
+            "minItems": length,
+            "maxItems": length,
+        }),
+        AbiType::Tuple { fields } => json!({
+            "type": "array",
+            "prefixItems": fields.iter().map(type_schema).collect::<Vec<_>>(),
+            "items": false,
+            "minItems": fields.len(),
+            "maxItems": fields.len(),
+        }),
+        AbiType::Struct { path, fields } => {
+            let properties: Map<String, Value> =
+                fields.iter().map(|(name, typ)| (name.clone(), type_schema(typ))).collect();
+            let required: Vec<&String> = fields.iter().map(|(name, _)| name).collect();
+            json!({
+                "title": path,
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use jsonschema::{Draft, JSONSchema};
+    use serde_json::{json, Value};
+
+    use crate::{Abi, AbiParameter, AbiReturnType, AbiType, AbiVisibility, Sign};
+
+    fn parameter(name: &str, typ: AbiType) -> AbiParameter {
+        AbiParameter { name: name.to_string(), typ, visibility: AbiVisibility::Private }
+    }
+
+    fn sample_abi() -> Abi {
+        let point = AbiType::Struct {
+            path: "foo::Point".to_string(),
+            fields: vec![
+                ("x".to_string(), AbiType::Field),
+                ("y".to_string(), AbiType::Integer { sign: Sign::Unsigned, width: 32 }),
+            ],
+        };
+        Abi {
+            parameters: vec![
+                parameter("x", AbiType::Field),
+                parameter("offset", AbiType::Integer { sign: Sign::Signed, width: 8 }),
+                parameter("flags", AbiType::Array { length: 2, typ: Box::new(AbiType::Boolean) }),
+                parameter("point", point),
+                parameter(
+                    "pair",
+                    AbiType::Tuple { fields: vec![AbiType::Field, AbiType::String { length: 3 }] },
+                ),
+            ],
+            param_witnesses: BTreeMap::default(),
+            return_type: Some(AbiReturnType {
+                abi_type: AbiType::Integer { sign: Sign::Unsigned, width: 32 },
+                visibility: AbiVisibility::Public,
+            }),
+            return_witnesses: Vec::new(),
+            error_types: BTreeMap::default(),
+        }
+    }
+
+    fn valid_inputs() -> Value {
+        json!({
+            "x": "0x1f",
+            "offset": "-3",
+            "flags": [true, false],
+            "point": { "x": "1", "y": "2" },
+            "pair": ["3", "abc"],
+        })
+    }
+
+    fn is_valid(instance: &Value) -> bool {
+        let schema = sample_abi().to_json_schema();
+        let validator = JSONSchema::options()
+            .with_draft(Draft::Draft202012)
+            .compile(&schema)
+            .expect("the generated schema should be valid");
+        validator.is_valid(instance)
+    }
+
+    /// Returns `valid_inputs()` with the value at `pointer` replaced by `value`.
+    fn with_value(pointer: &str, value: Value) -> Value {
+        let mut inputs = valid_inputs();
+        *inputs.pointer_mut(pointer).expect("pointer should exist") = value;
+        inputs
+    }
+
+    #[test]
+    fn accepts_valid_inputs() {
+        assert!(is_valid(&valid_inputs()));
+
+        let mut with_return = valid_inputs();
+        with_return["return"] = json!("42");
+        assert!(is_valid(&with_return));
+    }
+
+    #[test]
+    fn integers_are_strings() {
+        assert!(!is_valid(&with_value("/x", json!(31))));
+        assert!(!is_valid(&with_value("/x", json!("thirty one"))));
+        assert!(!is_valid(&with_value("/x", json!("-1"))));
+        assert!(!is_valid(&with_value("/point/y", json!("-1"))));
+        assert!(is_valid(&with_value("/offset", json!("0x7f"))));
+    }
+
+    #[test]
+    fn arrays_and_strings_have_a_fixed_length() {
+        assert!(!is_valid(&with_value("/flags", json!([true]))));
+        assert!(!is_valid(&with_value("/flags", json!([true, false, true]))));
+        assert!(!is_valid(&with_value("/flags/0", json!("true"))));
+        assert!(!is_valid(&with_value("/pair", json!(["3"]))));
+        assert!(!is_valid(&with_value("/pair", json!(["abc", "3"]))));
+        assert!(!is_valid(&with_value("/pair/1", json!("abcd"))));
+    }
+
+    #[test]
+    fn objects_require_exactly_their_fields() {
+        let mut missing_parameter = valid_inputs();
+        missing_parameter.as_object_mut().unwrap().remove("x");
+        assert!(!is_valid(&missing_parameter));
+
+        assert!(!is_valid(&with_value("/point", json!({ "x": "1" }))));
+        assert!(!is_valid(&with_value("/point", json!({ "x": "1", "y": "2", "z": "3" }))));
+
+        let mut unknown_parameter = valid_inputs();
+        unknown_parameter["z"] = json!("1");
+        assert!(!is_valid(&unknown_parameter));
+    }
+
+    #[test]
+    fn structs_are_titled_with_their_path() {
+        let schema = sample_abi().to_json_schema();
+        assert_eq!(schema["properties"]["point"]["title"], "foo::Point");
+        assert_eq!(schema["required"], json!(["x", "offset", "flags", "point", "pair"]));
+    }
+}
@@ -29,6 +29,7 @@ use std::{collections::BTreeMap, str};
 
 pub mod errors;
 pub mod input_parser;
+mod json_schema;
 mod serialization;
 
 /// A map from the fields in an TOML/JSON file which correspond to some ABI to their values
@@ -36,6 +37,10 @@ pub type InputMap = BTreeMap<String, InputValue>;
 
 pub const MAIN_RETURN_NAME: &str = "return";
 
+/// The path of the standard library's `BoundedVec`, which inputs may provide as an array of any
+/// length up to the `BoundedVec`'s maximum length.
+const BOUNDED_VEC_PATH: &str = "std::collections::bounded_vec::BoundedVec";
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "lowercase")]
 /// Types that are allowed in the (main function in binary)
@@ -208,6 +213,21 @@ impl AbiType {
             AbiType::String { length } => *length as u32,
         }
     }
+
+    /// If this is the standard library's `BoundedVec`, returns its maximum length and the type of
+    /// its elements.
+    pub fn as_bounded_vec(&self) -> Option<(u64, &AbiType)> {
+        let AbiType::Struct { path, fields } = self else {
+            return None;
+        };
+        if path != BOUNDED_VEC_PATH {
+            return None;
+        }
+        fields.iter().find_map(|(name, typ)| match typ {
+            AbiType::Array { length, typ } if name == "storage" => Some((*length, typ.as_ref())),
+            _ => None,
+        })
+    }
 }
 
 impl From<&AbiType> for PrintableType {
@@ -294,6 +314,25 @@ impl Abi {
         self.return_type.is_some() || self.parameters.iter().any(|param| param.is_public())
     }
 
+    /// Returns the number of public inputs contributed by each public parameter and by the return value,
+    /// in the order in which they appear in the ABI.
+    ///
+    /// The return value is identified by [`MAIN_RETURN_NAME`] and does not count towards the public inputs
+    /// if it is returned through the databus.
+    pub fn public_input_contributors(&self) -> Vec<(&str, u32)> {
+        let parameters = self
+            .parameters
+            .iter()
+            .filter(|param| param.is_public())
+            .map(|param| (param.name.as_str(), param.typ.field_count()));
+        let return_value = self
+            .return_type
+            .as_ref()
+            .filter(|return_type| return_type.visibility != AbiVisibility::DataBus)
+            .map(|return_type| (MAIN_RETURN_NAME, return_type.abi_type.field_count()));
+        parameters.chain(return_value).collect()
+    }
+
     /// Returns `true` if the ABI contains no parameters or return value.
     pub fn is_empty(&self) -> bool {
         self.return_type.is_none() && self.parameters.is_empty()
@@ -655,8 +694,10 @@ mod test {
     use acvm::{acir::native_types::Witness, FieldElement};
 
     use crate::{
-        input_parser::InputValue, Abi, AbiParameter, AbiReturnType, AbiType, AbiVisibility,
-        InputMap,
+        display_abi_error,
+        errors::AbiError,
+        input_parser::{InputTypecheckingError, InputValue},
+        Abi, AbiErrorType, AbiParameter, AbiReturnType, AbiType, AbiVisibility, InputMap, Sign,
     };
 
     #[test]
@@ -709,4 +750,89 @@ mod test {
         // We also decode the return value (we can do this immediately as we know it shares a witness with an input).
         assert_eq!(return_value.unwrap(), reconstructed_inputs["thing2"]);
     }
+
+    #[test]
+    fn public_input_contributors_skip_private_and_databus_values() {
+        let parameter = |name: &str, length, visibility| AbiParameter {
+            name: name.to_string(),
+            typ: AbiType::Array { length, typ: Box::new(AbiType::Field) },
+            visibility,
+        };
+        let mut abi = Abi {
+            parameters: vec![
+                parameter("a", 2, AbiVisibility::Public),
+                parameter("b", 3, AbiVisibility::Private),
+                parameter("c", 1, AbiVisibility::Public),
+            ],
+            param_witnesses: BTreeMap::default(),
+            return_type: Some(AbiReturnType {
+                abi_type: AbiType::Array { length: 4, typ: Box::new(AbiType::Field) },
+                visibility: AbiVisibility::Public,
+            }),
+            return_witnesses: Vec::new(),
+            error_types: BTreeMap::default(),
+        };
+        assert_eq!(abi.public_input_contributors(), vec![("a", 2), ("c", 1), ("return", 4)]);
+
+        abi.return_type.as_mut().unwrap().visibility = AbiVisibility::DataBus;
+        assert_eq!(abi.public_input_contributors(), vec![("a", 2), ("c", 1)]);
+    }
+
+    #[test]
+    fn string_length_is_measured_in_utf8_bytes() {
+        let abi = Abi {
+            parameters: vec![AbiParameter {
+                name: "message".to_string(),
+                typ: AbiType::String { length: 5 },
+                visibility: AbiVisibility::Private,
+            }],
+            param_witnesses: BTreeMap::from([(
+                "message".to_string(),
+                vec![(Witness(0)..Witness(5))],
+            )]),
+            return_type: None,
+            return_witnesses: vec![],
+            error_types: BTreeMap::default(),
+        };
+        let inputs = |message: &str| -> InputMap {
+            BTreeMap::from([("message".to_string(), InputValue::String(message.to_string()))])
+        };
+
+        // "héllo" has five characters but six bytes.
+        let error = abi.encode(&inputs("héllo"), None).unwrap_err();
+        assert!(matches!(
+            error,
+            AbiError::TypeMismatch(InputTypecheckingError::LengthMismatch {
+                expected_length: 5,
+                actual_length: 6,
+                ..
+            })
+        ));
+
+        let witness_map = abi.encode(&inputs("héll"), None).unwrap();
+        let (decoded, _) = abi.decode(&witness_map).unwrap();
+        assert_eq!(decoded["message"], InputValue::String("héll".to_string()));
+    }
+
+    #[test]
+    fn fmt_string_assertion_message_renders_values() {
+        let template = "expected {y} got {x}";
+        let error_type = AbiErrorType::FmtString {
+            length: template.len() as u64,
+            item_types: vec![
+                AbiType::Integer { sign: Sign::Unsigned, width: 32 },
+                AbiType::Array { length: 2, typ: Box::new(AbiType::Field) },
+            ],
+        };
+
+        // The template's characters, followed by the number of items and then each item's fields.
+        let mut fields: Vec<FieldElement> =
+            template.bytes().map(|byte| FieldElement::from(byte as u128)).collect();
+        fields.push(FieldElement::from(2u128));
+        fields.push(FieldElement::from(2u128));
+        fields.extend([FieldElement::from(1u128), FieldElement::from(255u128)]);
+
+        let message = display_abi_error(&fields, error_type).to_string();
+        assert_eq!(message, "expected 2 got [0x01, 0xff]");
+    }
 }